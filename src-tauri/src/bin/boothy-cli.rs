@@ -0,0 +1,8 @@
+use std::io;
+
+fn main() {
+    let args = std::env::args().skip(1).collect();
+    let exit_code = boothy_lib::cli::run_cli(args, &mut io::stdout(), &mut io::stderr());
+
+    std::process::exit(exit_code);
+}
@@ -0,0 +1,223 @@
+//! Branch health reports. Each booth publishes a summary of its recent audit
+//! entries to the fleet store, and the machine that runs rollout plans pulls
+//! the reports of the branches in its open waves. Another booth's audit log
+//! never reaches the rollout host, so wave health is read from these reports.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    slice, thread,
+    time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    contracts::dto::{is_valid_branch_id, HostErrorEnvelope},
+    diagnostics::audit_log::read_operator_audit_entries_for_branches,
+    session::session_manifest::{current_timestamp, rfc3339_to_unix_seconds},
+};
+
+use super::{
+    fleet_store::{resolve_fleet_release_store, FleetReleaseStore},
+    load_branch_id_in_dir, map_fs_error,
+    rollout_plan::advance_branch_rollout_plans_with_store,
+    write_json_bytes_atomically,
+};
+
+pub const BRANCH_HEALTH_REPORT_SCHEMA_VERSION: &str = "branch-health-report/v1";
+pub const BRANCH_HEALTH_INTERVAL_ENV: &str = "BOOTHY_BRANCH_HEALTH_INTERVAL_SECONDS";
+
+const BRANCH_HEALTH_REPORT_WINDOW_SECONDS: u64 = 14 * 24 * 60 * 60;
+const MAX_BRANCH_HEALTH_REPORT_ENTRIES: usize = 5_000;
+const DEFAULT_BRANCH_HEALTH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BranchHealthReport {
+    schema_version: String,
+    branch_id: String,
+    generated_at: String,
+    #[serde(default)]
+    entries: Vec<BranchHealthEntry>,
+}
+
+/// The parts of one audit entry that wave health needs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BranchHealthEntry {
+    pub occurred_at: String,
+    #[serde(default)]
+    pub session_id: Option<String>,
+    pub event_category: String,
+    #[serde(default)]
+    pub reason_code: Option<String>,
+}
+
+/// Store object that holds the latest report of `branch_id`.
+pub fn resolve_branch_health_report_object_path(branch_id: &str) -> String {
+    format!("health/{branch_id}.json")
+}
+
+/// Local copy of the reports the rollout host pulled from the store.
+pub fn resolve_branch_health_dir(base_dir: &Path) -> PathBuf {
+    base_dir.join("branch-config").join("branch-health")
+}
+
+/// Publishes this booth's audit entries from the last 14 days. Returns the
+/// branch id it reported for, or `None` when the booth has no `branchId`.
+pub fn push_branch_health_report_in_dir(
+    base_dir: &Path,
+    store: &dyn FleetReleaseStore,
+) -> Result<Option<String>, HostErrorEnvelope> {
+    let Some(branch_id) = load_branch_id_in_dir(base_dir).filter(|id| is_valid_branch_id(id))
+    else {
+        return Ok(None);
+    };
+    let generated_at = current_timestamp(SystemTime::now())?;
+    let since_seconds =
+        rfc3339_to_unix_seconds(&generated_at)?.saturating_sub(BRANCH_HEALTH_REPORT_WINDOW_SECONDS);
+
+    let mut entries =
+        read_operator_audit_entries_for_branches(base_dir, slice::from_ref(&branch_id))?
+            .into_iter()
+            .filter(|entry| {
+                rfc3339_to_unix_seconds(&entry.occurred_at)
+                    .is_ok_and(|seconds| seconds >= since_seconds)
+            })
+            .map(|entry| BranchHealthEntry {
+                occurred_at: entry.occurred_at,
+                session_id: entry.session_id,
+                event_category: entry.event_category,
+                reason_code: entry.reason_code,
+            })
+            .collect::<Vec<_>>();
+    let overflow = entries
+        .len()
+        .saturating_sub(MAX_BRANCH_HEALTH_REPORT_ENTRIES);
+    entries.drain(..overflow);
+
+    let report = BranchHealthReport {
+        schema_version: BRANCH_HEALTH_REPORT_SCHEMA_VERSION.into(),
+        branch_id: branch_id.clone(),
+        generated_at,
+        entries,
+    };
+    let contents = serde_json::to_string_pretty(&report).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("지점 상태 보고를 직렬화하지 못했어요: {error}"))
+    })?;
+    store.publish(
+        &resolve_branch_health_report_object_path(&branch_id),
+        &contents,
+    )?;
+
+    Ok(Some(branch_id))
+}
+
+/// Copies the store's report of each branch into `branch-config/branch-health`.
+/// A branch that has not reported yet, or whose report is malformed, keeps
+/// its last good copy.
+pub(crate) fn pull_branch_health_reports(
+    base_dir: &Path,
+    store: &dyn FleetReleaseStore,
+    branch_ids: &[String],
+) -> Result<(), HostErrorEnvelope> {
+    let health_dir = resolve_branch_health_dir(base_dir);
+    fs::create_dir_all(&health_dir).map_err(map_fs_error)?;
+
+    for branch_id in branch_ids.iter().filter(|id| is_valid_branch_id(id)) {
+        let report = store
+            .fetch(&resolve_branch_health_report_object_path(branch_id))
+            .and_then(|bytes| {
+                serde_json::from_slice::<BranchHealthReport>(&bytes).map_err(|error| {
+                    HostErrorEnvelope::persistence(format!(
+                        "지점 상태 보고를 읽지 못했어요: {error}"
+                    ))
+                })
+            });
+        let report = match report {
+            Ok(report)
+                if report.schema_version == BRANCH_HEALTH_REPORT_SCHEMA_VERSION
+                    && report.branch_id == *branch_id =>
+            {
+                report
+            }
+            Ok(_) => {
+                log::warn!("branch_health_report_rejected branch={branch_id}");
+                continue;
+            }
+            Err(error) => {
+                log::info!(
+                    "branch_health_report_unavailable branch={} reason={}",
+                    branch_id,
+                    error.message
+                );
+                continue;
+            }
+        };
+        let bytes = serde_json::to_vec_pretty(&report).map_err(|error| {
+            HostErrorEnvelope::persistence(format!("지점 상태 보고를 직렬화하지 못했어요: {error}"))
+        })?;
+        write_json_bytes_atomically(&health_dir.join(format!("{branch_id}.json")), &bytes)?;
+    }
+
+    Ok(())
+}
+
+/// Every entry in the pulled reports of `branch_ids`.
+pub(crate) fn read_branch_health_entries(
+    base_dir: &Path,
+    branch_ids: &[String],
+) -> Vec<BranchHealthEntry> {
+    let health_dir = resolve_branch_health_dir(base_dir);
+
+    branch_ids
+        .iter()
+        .filter(|id| is_valid_branch_id(id))
+        .filter_map(|branch_id| {
+            let bytes = fs::read(health_dir.join(format!("{branch_id}.json"))).ok()?;
+            serde_json::from_slice::<BranchHealthReport>(&bytes)
+                .ok()
+                .filter(|report| report.branch_id == *branch_id)
+        })
+        .flat_map(|report| report.entries)
+        .collect()
+}
+
+/// Runs one health exchange now and then every interval on a background
+/// thread: the booth publishes its own report, and open rollout plans on
+/// this machine are evaluated against the reports in the store. The interval comes from
+/// `BOOTHY_BRANCH_HEALTH_INTERVAL_SECONDS` (default 5 minutes).
+pub fn spawn_branch_health_exchange_in_dir(base_dir: &Path) {
+    let base_dir = base_dir.to_path_buf();
+    let interval = env::var(BRANCH_HEALTH_INTERVAL_ENV)
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .filter(|seconds| *seconds > 0)
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_BRANCH_HEALTH_INTERVAL);
+
+    thread::spawn(move || loop {
+        run_branch_health_exchange(&base_dir);
+        thread::sleep(interval);
+    });
+}
+
+fn run_branch_health_exchange(base_dir: &Path) {
+    let store = match resolve_fleet_release_store(base_dir) {
+        Ok(store) => store,
+        Err(error) => {
+            log::warn!("branch_health_exchange_failed reason={}", error.message);
+            return;
+        }
+    };
+    if let Err(error) = push_branch_health_report_in_dir(base_dir, store.as_ref()) {
+        log::warn!("branch_health_report_push_failed reason={}", error.message);
+    }
+    if let Err(error) = advance_branch_rollout_plans_with_store(base_dir, store.as_ref()) {
+        log::warn!(
+            "branch_rollout_plan_evaluation_failed reason={}",
+            error.message
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    branch_http::{send_branch_http_request, BranchHttpError},
+    contracts::dto::HostErrorEnvelope,
+};
+
+use super::write_json_bytes_atomically;
+
+pub const FLEET_STORE_URL_ENV: &str = "BOOTHY_FLEET_STORE_URL";
+pub const FLEET_STORE_DIR_ENV: &str = "BOOTHY_FLEET_STORE_DIR";
+pub const LOCAL_DIRECTORY_FLEET_STORE_ID: &str = "local-directory";
+pub const HTTP_FLEET_STORE_ID: &str = "http";
+
+const FLEET_STORE_DEFAULT_DIR_NAME: &str = "fleet-store";
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_FLEET_OBJECT_BYTES: u64 = 64 * 1024 * 1024;
+
+/// The HQ release store. Booths read signed releases from it and publish
+/// their health reports to it. Objects are addressed by `/`-separated paths
+/// relative to the store root; integrity and signature checks happen in the
+/// sync client so every store is held to them.
+pub trait FleetReleaseStore: Send + Sync {
+    fn store_id(&self) -> &'static str;
+
+    fn fetch(&self, relative_path: &str) -> Result<Vec<u8>, HostErrorEnvelope>;
+
+    /// Replaces the JSON object at `relative_path`.
+    fn publish(&self, relative_path: &str, contents: &str) -> Result<(), HostErrorEnvelope>;
+}
+
+/// A mirrored copy of the HQ store, e.g. a mounted share or a USB drive.
+#[derive(Debug, Clone)]
+pub struct LocalDirectoryFleetStore {
+    root: PathBuf,
+}
+
+impl LocalDirectoryFleetStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl FleetReleaseStore for LocalDirectoryFleetStore {
+    fn store_id(&self) -> &'static str {
+        LOCAL_DIRECTORY_FLEET_STORE_ID
+    }
+
+    fn fetch(&self, relative_path: &str) -> Result<Vec<u8>, HostErrorEnvelope> {
+        if !is_safe_fleet_object_path(relative_path) {
+            return Err(HostErrorEnvelope::validation_message(
+                "HQ 저장소 경로를 다시 확인해 주세요.",
+            ));
+        }
+        let path = relative_path
+            .split('/')
+            .fold(self.root.clone(), |path, segment| path.join(segment));
+
+        fs::read(path).map_err(|error| {
+            HostErrorEnvelope::persistence(format!(
+                "HQ 저장소에서 {relative_path}을(를) 읽지 못했어요: {error}"
+            ))
+        })
+    }
+
+    fn publish(&self, relative_path: &str, contents: &str) -> Result<(), HostErrorEnvelope> {
+        if !is_safe_fleet_object_path(relative_path) || !relative_path.ends_with(".json") {
+            return Err(HostErrorEnvelope::validation_message(
+                "HQ 저장소 경로를 다시 확인해 주세요.",
+            ));
+        }
+        let path = relative_path
+            .split('/')
+            .fold(self.root.clone(), |path, segment| path.join(segment));
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|error| {
+                HostErrorEnvelope::persistence(format!(
+                    "HQ 저장소에 {relative_path}을(를) 쓰지 못했어요: {error}"
+                ))
+            })?;
+        }
+
+        write_json_bytes_atomically(&path, contents.as_bytes())
+    }
+}
+
+/// `GET` and `PUT <base>/<path>` against an HQ release server over plain
+/// HTTP on the branch network.
+#[derive(Debug, Clone)]
+pub struct HttpFleetStore {
+    host: String,
+    port: u16,
+    path_prefix: String,
+}
+
+impl HttpFleetStore {
+    pub fn new(base_url: &str) -> Result<Self, HostErrorEnvelope> {
+        let invalid =
+            || HostErrorEnvelope::validation_message("HQ 저장소 주소는 http:// 로 시작해야 해요.");
+        let rest = base_url
+            .trim()
+            .strip_prefix("http://")
+            .ok_or_else(invalid)?;
+        let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse::<u16>().map_err(|_| invalid())?),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            host: host.into(),
+            port,
+            path_prefix: path.trim_end_matches('/').into(),
+        })
+    }
+}
+
+impl FleetReleaseStore for HttpFleetStore {
+    fn store_id(&self) -> &'static str {
+        HTTP_FLEET_STORE_ID
+    }
+
+    fn fetch(&self, relative_path: &str) -> Result<Vec<u8>, HostErrorEnvelope> {
+        if !is_safe_fleet_object_path(relative_path) {
+            return Err(HostErrorEnvelope::validation_message(
+                "HQ 저장소 경로를 다시 확인해 주세요.",
+            ));
+        }
+        let response = send_branch_http_request(
+            "GET",
+            &format!(
+                "http://{}:{}{}/{relative_path}",
+                self.host, self.port, self.path_prefix
+            ),
+            "application/octet-stream",
+            None,
+            HTTP_TIMEOUT,
+            MAX_FLEET_OBJECT_BYTES,
+        )
+        .map_err(|error| match error {
+            BranchHttpError::TooLarge { limit_bytes } => HostErrorEnvelope::persistence(format!(
+                "HQ 저장소의 {relative_path}이(가) 너무 커서 받지 않았어요. (최대 {}MB)",
+                limit_bytes / (1024 * 1024)
+            )),
+            BranchHttpError::Transport(error) => {
+                HostErrorEnvelope::persistence(format!("HQ 저장소에 연결하지 못했어요: {error}"))
+            }
+        })?;
+
+        match response.status {
+            200 => Ok(response.body),
+            status => Err(HostErrorEnvelope::persistence(format!(
+                "HQ 저장소가 {relative_path} 요청을 거절했어요. (HTTP {status})"
+            ))),
+        }
+    }
+
+    fn publish(&self, relative_path: &str, contents: &str) -> Result<(), HostErrorEnvelope> {
+        if !is_safe_fleet_object_path(relative_path) {
+            return Err(HostErrorEnvelope::validation_message(
+                "HQ 저장소 경로를 다시 확인해 주세요.",
+            ));
+        }
+        let response = send_branch_http_request(
+            "PUT",
+            &format!(
+                "http://{}:{}{}/{relative_path}",
+                self.host, self.port, self.path_prefix
+            ),
+            "application/json",
+            Some(contents),
+            HTTP_TIMEOUT,
+            MAX_FLEET_OBJECT_BYTES,
+        )
+        .map_err(|error| match error {
+            BranchHttpError::TooLarge { .. } => {
+                HostErrorEnvelope::persistence("HQ 저장소 응답이 너무 커서 받지 않았어요.")
+            }
+            BranchHttpError::Transport(error) => {
+                HostErrorEnvelope::persistence(format!("HQ 저장소에 연결하지 못했어요: {error}"))
+            }
+        })?;
+
+        match response.status {
+            200 | 201 | 204 => Ok(()),
+            status => Err(HostErrorEnvelope::persistence(format!(
+                "HQ 저장소가 {relative_path} 저장을 거절했어요. (HTTP {status})"
+            ))),
+        }
+    }
+}
+
+/// Relative, `/`-separated and free of `.`/`..` segments, so no object can
+/// resolve outside the store root or a staged bundle directory.
+pub fn is_safe_fleet_object_path(relative_path: &str) -> bool {
+    !relative_path.is_empty()
+        && relative_path.len() <= 200
+        && relative_path.split('/').all(|segment| {
+            !segment.is_empty()
+                && segment != "."
+                && segment != ".."
+                && segment
+                    .chars()
+                    .all(|char| char.is_ascii_alphanumeric() || matches!(char, '-' | '_' | '.'))
+        })
+}
+
+pub fn resolve_fleet_store_dir(base_dir: &Path) -> PathBuf {
+    match env::var(FLEET_STORE_DIR_ENV) {
+        Ok(dir) if !dir.trim().is_empty() => PathBuf::from(dir.trim()),
+        _ => base_dir.join(FLEET_STORE_DEFAULT_DIR_NAME),
+    }
+}
+
+/// Uses the HQ server when `BOOTHY_FLEET_STORE_URL` is set, otherwise the
+/// mirrored directory from `BOOTHY_FLEET_STORE_DIR` or `fleet-store/`.
+pub fn resolve_fleet_release_store(
+    base_dir: &Path,
+) -> Result<Box<dyn FleetReleaseStore>, HostErrorEnvelope> {
+    match env::var(FLEET_STORE_URL_ENV) {
+        Ok(url) if !url.trim().is_empty() => Ok(Box::new(HttpFleetStore::new(&url)?)),
+        _ => Ok(Box::new(LocalDirectoryFleetStore::new(
+            resolve_fleet_store_dir(base_dir),
+        ))),
+    }
+}
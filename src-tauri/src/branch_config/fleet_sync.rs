@@ -0,0 +1,727 @@
+//! Pulls signed HQ releases into the booth. Approved baselines join the
+//! rollout store and published preset bundles are verified in a staging
+//! directory, then moved into `preset-catalog/published` together or not at
+//! all. Every sync also publishes the booth's branch health report.
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    contracts::dto::{
+        is_non_blank, is_valid_actor_id, is_valid_build_version, is_valid_preset_id,
+        is_valid_preset_stack_version, is_valid_published_version, validate_fleet_sync_input,
+        BranchReleaseBaselineDto, CapabilitySnapshotDto, FleetSyncBundleOutcomeDto,
+        FleetSyncInputDto, FleetSyncResultDto, HostErrorEnvelope,
+    },
+    diagnostics::audit_log::{try_append_operator_audit_record, OperatorAuditRecordInput},
+    integrity::{decode_hex, ed25519_verify, sha256_hex},
+    preset::{
+        bundle_signature::{check_published_bundle_signature, verify_preset_bundle_signature},
+        preset_bundle::load_published_preset_summary,
+        preset_catalog::resolve_published_preset_catalog_dir,
+    },
+    session::session_manifest::{current_timestamp, rfc3339_to_unix_seconds},
+};
+
+use super::{
+    acquire_branch_config_lock, acquire_branch_rollout_store_lock,
+    branch_health::push_branch_health_report_in_dir,
+    ensure_settings_access,
+    fleet_store::{is_safe_fleet_object_path, resolve_fleet_release_store, FleetReleaseStore},
+    load_branch_rollout_store, map_fs_error, persist_branch_rollout_store,
+    preset_signing_keys::{load_preset_bundle_trust_in_dir, PresetBundleTrust},
+    write_json_bytes_atomically,
+};
+
+pub const FLEET_RELEASE_MANIFEST_PATH: &str = "release-manifest.json";
+pub const FLEET_RELEASE_SIGNATURE_PATH: &str = "release-manifest.sig.json";
+pub const FLEET_RELEASE_MANIFEST_SCHEMA_VERSION: &str = "fleet-release-manifest/v1";
+pub const FLEET_RELEASE_SIGNATURE_SCHEMA_VERSION: &str = "fleet-release-signature/v1";
+pub const FLEET_TRUSTED_KEYS_SCHEMA_VERSION: &str = "fleet-trusted-keys/v1";
+pub const FLEET_SYNC_STATE_SCHEMA_VERSION: &str = "fleet-sync-state/v1";
+
+const FLEET_SYNC_RESULT_SCHEMA_VERSION: &str = "fleet-sync-result/v1";
+const FLEET_SIGNATURE_ALGORITHM: &str = "ed25519";
+const FLEET_STAGING_DIR_NAME: &str = "fleet-staging";
+const MAX_FLEET_RELEASE_ID_CHARS: usize = 80;
+const MAX_FLEET_BUNDLE_FILES: usize = 64;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FleetReleaseManifest {
+    schema_version: String,
+    release_id: String,
+    issued_at: String,
+    #[serde(default)]
+    approved_baselines: Vec<BranchReleaseBaselineDto>,
+    #[serde(default)]
+    preset_bundles: Vec<FleetPresetBundleEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FleetPresetBundleEntry {
+    preset_id: String,
+    published_version: String,
+    files: Vec<FleetBundleFileEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FleetBundleFileEntry {
+    path: String,
+    sha256: String,
+    size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FleetReleaseSignature {
+    schema_version: String,
+    key_id: String,
+    algorithm: String,
+    signature: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FleetTrustedKeys {
+    schema_version: String,
+    #[serde(default)]
+    keys: Vec<FleetTrustedKey>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FleetTrustedKey {
+    key_id: String,
+    public_key: String,
+}
+
+/// The last release this booth installed. Later syncs only accept releases
+/// issued after it, so an old signed release cannot be replayed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FleetSyncState {
+    schema_version: String,
+    last_release_id: String,
+    last_issued_at: String,
+    applied_at: String,
+}
+
+#[derive(Debug)]
+struct FleetSyncRejection {
+    code: &'static str,
+    message: String,
+}
+
+/// A verified bundle waiting in the staging directory for promotion.
+struct StagedFleetBundle {
+    staged_dir: PathBuf,
+    published_dir: PathBuf,
+}
+
+struct InstalledFleetRelease {
+    added_baselines: Vec<BranchReleaseBaselineDto>,
+    preset_bundles: Vec<FleetSyncBundleOutcomeDto>,
+}
+
+pub fn sync_fleet_release_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: FleetSyncInputDto,
+) -> Result<FleetSyncResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    let store = resolve_fleet_release_store(base_dir)?;
+
+    sync_fleet_release_with_store(base_dir, capability_snapshot, input, store.as_ref())
+}
+
+/// Integrity and signature failures come back as a `rejected` result with
+/// nothing installed; only access and input problems are errors.
+pub fn sync_fleet_release_with_store(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: FleetSyncInputDto,
+    store: &dyn FleetReleaseStore,
+) -> Result<FleetSyncResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    validate_fleet_sync_input(&input)?;
+    let _lock = acquire_branch_config_lock(resolve_fleet_sync_lock_path(base_dir))?;
+    let synced_at = current_timestamp(SystemTime::now())?;
+
+    let (release_id, outcome) = match fetch_verified_manifest(base_dir, store) {
+        Ok(manifest) => (
+            Some(manifest.release_id.clone()),
+            ensure_release_is_not_stale(base_dir, &manifest).and_then(|last_applied| {
+                install_release(base_dir, store, &manifest, last_applied, &synced_at)
+            }),
+        ),
+        Err(rejection) => (None, Err(rejection)),
+    };
+    let result = match outcome {
+        Ok(installed) => {
+            let new_bundle_count = installed
+                .preset_bundles
+                .iter()
+                .filter(|bundle| bundle.result == "installed")
+                .count();
+            let up_to_date = installed.added_baselines.is_empty() && new_bundle_count == 0;
+            let message = if up_to_date {
+                "HQ 릴리스가 이미 모두 반영돼 있어요.".to_string()
+            } else {
+                format!(
+                    "HQ 릴리스를 받아 승인 baseline {}개와 preset bundle {}개를 반영했어요.",
+                    installed.added_baselines.len(),
+                    new_bundle_count
+                )
+            };
+
+            FleetSyncResultDto {
+                schema_version: FLEET_SYNC_RESULT_SCHEMA_VERSION.into(),
+                status: if up_to_date { "up-to-date" } else { "synced" }.into(),
+                store_id: store.store_id().into(),
+                release_id,
+                synced_at,
+                added_baselines: installed.added_baselines,
+                preset_bundles: installed.preset_bundles,
+                rejection_code: None,
+                message,
+            }
+        }
+        Err(rejection) => FleetSyncResultDto {
+            schema_version: FLEET_SYNC_RESULT_SCHEMA_VERSION.into(),
+            status: "rejected".into(),
+            store_id: store.store_id().into(),
+            release_id,
+            synced_at,
+            added_baselines: Vec::new(),
+            preset_bundles: Vec::new(),
+            rejection_code: Some(rejection.code.into()),
+            message: rejection.message,
+        },
+    };
+
+    append_fleet_sync_audit_record(base_dir, &input, &result);
+    if let Err(error) = push_branch_health_report_in_dir(base_dir, store) {
+        log::warn!("branch_health_report_push_failed reason={}", error.message);
+    }
+
+    Ok(result)
+}
+
+pub fn resolve_fleet_trusted_keys_path(base_dir: &Path) -> PathBuf {
+    base_dir
+        .join("branch-config")
+        .join("fleet-trusted-keys.json")
+}
+
+pub fn resolve_fleet_sync_state_path(base_dir: &Path) -> PathBuf {
+    base_dir.join("branch-config").join("fleet-sync-state.json")
+}
+
+fn resolve_fleet_sync_lock_path(base_dir: &Path) -> PathBuf {
+    base_dir.join("branch-config").join("fleet-sync.lock")
+}
+
+fn resolve_fleet_staging_dir(base_dir: &Path) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let catalog_root = resolve_published_preset_catalog_dir(base_dir);
+    let staging_parent = catalog_root
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or(catalog_root);
+
+    staging_parent
+        .join(FLEET_STAGING_DIR_NAME)
+        .join(stamp.to_string())
+}
+
+/// Missing or unreadable key files trust nothing, so an unconfigured booth
+/// rejects every release instead of accepting unsigned content.
+fn load_fleet_trusted_keys(base_dir: &Path) -> Vec<FleetTrustedKey> {
+    fs::read_to_string(resolve_fleet_trusted_keys_path(base_dir))
+        .ok()
+        .and_then(|contents| serde_json::from_str::<FleetTrustedKeys>(&contents).ok())
+        .filter(|trusted_keys| trusted_keys.schema_version == FLEET_TRUSTED_KEYS_SCHEMA_VERSION)
+        .map(|trusted_keys| trusted_keys.keys)
+        .unwrap_or_default()
+}
+
+fn fetch_verified_manifest(
+    base_dir: &Path,
+    store: &dyn FleetReleaseStore,
+) -> Result<FleetReleaseManifest, FleetSyncRejection> {
+    let manifest_bytes = store
+        .fetch(FLEET_RELEASE_MANIFEST_PATH)
+        .map_err(|error| rejection("store-unreachable", error.message))?;
+    let signature = store
+        .fetch(FLEET_RELEASE_SIGNATURE_PATH)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<FleetReleaseSignature>(&bytes).ok())
+        .filter(|signature| {
+            signature.schema_version == FLEET_RELEASE_SIGNATURE_SCHEMA_VERSION
+                && signature.algorithm == FLEET_SIGNATURE_ALGORITHM
+        })
+        .ok_or_else(|| {
+            rejection(
+                "signature-missing",
+                "HQ 릴리스 서명을 찾지 못해 받지 않았어요.",
+            )
+        })?;
+
+    let public_key = load_fleet_trusted_keys(base_dir)
+        .into_iter()
+        .find(|key| key.key_id == signature.key_id)
+        .and_then(|key| decode_hex::<32>(&key.public_key))
+        .ok_or_else(|| {
+            rejection(
+                "untrusted-signing-key",
+                format!(
+                    "신뢰 목록에 없는 키({})로 서명된 릴리스라 받지 않았어요.",
+                    signature.key_id
+                ),
+            )
+        })?;
+    decode_hex::<64>(&signature.signature)
+        .filter(|signature| ed25519_verify(&public_key, &manifest_bytes, signature))
+        .ok_or_else(|| {
+            rejection(
+                "signature-invalid",
+                "HQ 릴리스 서명이 맞지 않아 받지 않았어요.",
+            )
+        })?;
+
+    let manifest: FleetReleaseManifest =
+        serde_json::from_slice(&manifest_bytes).map_err(|error| {
+            rejection(
+                "manifest-invalid",
+                format!("HQ 릴리스 매니페스트를 읽지 못했어요: {error}"),
+            )
+        })?;
+    validate_release_manifest(&manifest)?;
+
+    Ok(manifest)
+}
+
+fn load_fleet_sync_state(base_dir: &Path) -> Result<Option<FleetSyncState>, FleetSyncRejection> {
+    let state_path = resolve_fleet_sync_state_path(base_dir);
+    let backup_path = state_path.with_extension("json.bak");
+    let contents = match fs::read_to_string(&state_path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            match fs::read_to_string(&backup_path) {
+                Ok(contents) => contents,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(error) => return Err(unreadable_sync_state(error)),
+            }
+        }
+        Err(error) => return Err(unreadable_sync_state(error)),
+    };
+
+    serde_json::from_str::<FleetSyncState>(&contents)
+        .ok()
+        .filter(|state| {
+            state.schema_version == FLEET_SYNC_STATE_SCHEMA_VERSION
+                && rfc3339_to_unix_seconds(&state.last_issued_at).is_ok()
+        })
+        .map(Some)
+        .ok_or_else(|| unreadable_sync_state("형식이 올바르지 않아요"))
+}
+
+fn persist_fleet_sync_state(
+    base_dir: &Path,
+    state: &FleetSyncState,
+) -> Result<(), HostErrorEnvelope> {
+    let state_path = resolve_fleet_sync_state_path(base_dir);
+    if let Some(state_dir) = state_path.parent() {
+        fs::create_dir_all(state_dir).map_err(map_fs_error)?;
+    }
+    let bytes = serde_json::to_vec_pretty(state).map_err(|error| {
+        HostErrorEnvelope::persistence(format!(
+            "HQ 릴리스 적용 기록을 직렬화하지 못했어요: {error}"
+        ))
+    })?;
+
+    write_json_bytes_atomically(&state_path, &bytes)
+}
+
+fn unreadable_sync_state(error: impl std::fmt::Display) -> FleetSyncRejection {
+    rejection(
+        "staging-failed",
+        format!("HQ 릴리스 적용 기록을 읽지 못했어요: {error}"),
+    )
+}
+
+/// Accepts a release issued after the last applied one. Re-reading the last
+/// applied release itself is allowed and installs nothing new; any other
+/// release that is not newer is refused. Returns whether `manifest` is the
+/// last applied release.
+fn ensure_release_is_not_stale(
+    base_dir: &Path,
+    manifest: &FleetReleaseManifest,
+) -> Result<bool, FleetSyncRejection> {
+    let Some(state) = load_fleet_sync_state(base_dir)? else {
+        return Ok(false);
+    };
+    let issued_at = rfc3339_to_unix_seconds(&manifest.issued_at).unwrap_or_default();
+    let last_issued_at = rfc3339_to_unix_seconds(&state.last_issued_at).unwrap_or_default();
+
+    if manifest.release_id == state.last_release_id && manifest.issued_at == state.last_issued_at {
+        return Ok(true);
+    }
+    if issued_at < last_issued_at {
+        return Err(rejection(
+            "release-rollback",
+            format!(
+                "이미 적용한 릴리스 {}보다 오래된 릴리스라 받지 않았어요.",
+                state.last_release_id
+            ),
+        ));
+    }
+    if issued_at == last_issued_at || manifest.release_id == state.last_release_id {
+        return Err(rejection(
+            "release-replay",
+            format!(
+                "이미 적용한 릴리스 {}보다 새로운 릴리스가 아니라 받지 않았어요.",
+                state.last_release_id
+            ),
+        ));
+    }
+
+    Ok(false)
+}
+
+fn validate_release_manifest(manifest: &FleetReleaseManifest) -> Result<(), FleetSyncRejection> {
+    let invalid = |detail: &str| {
+        rejection(
+            "manifest-invalid",
+            format!("HQ 릴리스 매니페스트가 올바르지 않아요: {detail}"),
+        )
+    };
+
+    if manifest.schema_version != FLEET_RELEASE_MANIFEST_SCHEMA_VERSION {
+        return Err(invalid("schemaVersion"));
+    }
+    if !is_non_blank(&manifest.release_id)
+        || manifest.release_id.chars().count() > MAX_FLEET_RELEASE_ID_CHARS
+        || rfc3339_to_unix_seconds(&manifest.issued_at).is_err()
+    {
+        return Err(invalid("releaseId/issuedAt"));
+    }
+
+    let mut baseline_keys = HashSet::new();
+    for baseline in &manifest.approved_baselines {
+        if !is_valid_build_version(&baseline.build_version)
+            || !is_valid_preset_stack_version(&baseline.preset_stack_version)
+            || rfc3339_to_unix_seconds(&baseline.approved_at).is_err()
+            || !is_valid_actor_id(&baseline.actor_id)
+            || !is_non_blank(&baseline.actor_label)
+            || !baseline_keys.insert((&baseline.build_version, &baseline.preset_stack_version))
+        {
+            return Err(invalid("approvedBaselines"));
+        }
+    }
+
+    let mut bundle_keys = HashSet::new();
+    for bundle in &manifest.preset_bundles {
+        if !is_valid_preset_id(&bundle.preset_id)
+            || !is_valid_published_version(&bundle.published_version)
+            || !bundle_keys.insert((&bundle.preset_id, &bundle.published_version))
+        {
+            return Err(invalid("presetBundles"));
+        }
+
+        let mut paths = HashSet::new();
+        let files_valid = !bundle.files.is_empty()
+            && bundle.files.len() <= MAX_FLEET_BUNDLE_FILES
+            && bundle.files.iter().all(|file| {
+                is_safe_fleet_object_path(&file.path)
+                    && decode_hex::<32>(&file.sha256).is_some()
+                    && paths.insert(file.path.as_str())
+            })
+            && paths.contains("bundle.json");
+        if !files_valid {
+            return Err(invalid(&format!(
+                "{}@{} files",
+                bundle.preset_id, bundle.published_version
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn install_release(
+    base_dir: &Path,
+    store: &dyn FleetReleaseStore,
+    manifest: &FleetReleaseManifest,
+    last_applied: bool,
+    synced_at: &str,
+) -> Result<InstalledFleetRelease, FleetSyncRejection> {
+    let catalog_root = resolve_published_preset_catalog_dir(base_dir);
+    let staging_dir = resolve_fleet_staging_dir(base_dir);
+
+    let trust = load_preset_bundle_trust_in_dir(base_dir);
+    let installed = stage_release_bundles(store, manifest, &catalog_root, &staging_dir, &trust)
+        .and_then(|(preset_bundles, staged_bundles)| {
+            let promoted = promote_staged_bundles(&staged_bundles)?;
+
+            let added_baselines =
+                match merge_approved_baselines(base_dir, &manifest.approved_baselines) {
+                    Ok(added_baselines) => added_baselines,
+                    Err(error) => {
+                        remove_promoted_bundles(&promoted);
+                        return Err(rejection("staging-failed", error.message));
+                    }
+                };
+            if !last_applied {
+                let state = FleetSyncState {
+                    schema_version: FLEET_SYNC_STATE_SCHEMA_VERSION.into(),
+                    last_release_id: manifest.release_id.clone(),
+                    last_issued_at: manifest.issued_at.clone(),
+                    applied_at: synced_at.into(),
+                };
+                if let Err(error) = persist_fleet_sync_state(base_dir, &state) {
+                    remove_promoted_bundles(&promoted);
+                    return Err(rejection("staging-failed", error.message));
+                }
+            }
+            // Verified once at install; later loads reuse the cached verdict.
+            for published_dir in &promoted {
+                let _ = check_published_bundle_signature(published_dir);
+            }
+
+            Ok(InstalledFleetRelease {
+                added_baselines,
+                preset_bundles,
+            })
+        });
+    let _ = fs::remove_dir_all(&staging_dir);
+
+    installed
+}
+
+/// Downloads every bundle that is not already published into `staging_dir`
+/// and checks it against the manifest and the branch's bundle signing trust
+/// before anything becomes visible.
+fn stage_release_bundles(
+    store: &dyn FleetReleaseStore,
+    manifest: &FleetReleaseManifest,
+    catalog_root: &Path,
+    staging_dir: &Path,
+    trust: &PresetBundleTrust,
+) -> Result<(Vec<FleetSyncBundleOutcomeDto>, Vec<StagedFleetBundle>), FleetSyncRejection> {
+    let mut outcomes = Vec::new();
+    let mut staged_bundles = Vec::new();
+
+    for bundle in &manifest.preset_bundles {
+        let label = format!("{}@{}", bundle.preset_id, bundle.published_version);
+        let published_dir = catalog_root
+            .join(&bundle.preset_id)
+            .join(&bundle.published_version);
+
+        if published_dir.exists() {
+            let matches = bundle.files.iter().all(|file| {
+                fs::read(join_object_path(&published_dir, &file.path))
+                    .is_ok_and(|bytes| file_matches_manifest(&bytes, file))
+            });
+            if !matches {
+                return Err(rejection(
+                    "bundle-conflict",
+                    format!("{label} 버전이 이미 다른 내용으로 게시돼 있어 받지 않았어요."),
+                ));
+            }
+            outcomes.push(bundle_outcome(bundle, "already-present"));
+            continue;
+        }
+
+        let staged_dir = staging_dir
+            .join(&bundle.preset_id)
+            .join(&bundle.published_version);
+        for file in &bundle.files {
+            let bytes = store
+                .fetch(&format!(
+                    "presets/{}/{}/{}",
+                    bundle.preset_id, bundle.published_version, file.path
+                ))
+                .map_err(|error| rejection("store-unreachable", error.message))?;
+            if !file_matches_manifest(&bytes, file) {
+                return Err(rejection(
+                    "integrity-mismatch",
+                    format!(
+                        "{label}의 {} 파일이 릴리스 매니페스트와 달라 받지 않았어요.",
+                        file.path
+                    ),
+                ));
+            }
+
+            let staged_path = join_object_path(&staged_dir, &file.path);
+            staged_path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(&staged_path, &bytes))
+                .map_err(|error| {
+                    rejection(
+                        "staging-failed",
+                        format!("{label} bundle을 임시 폴더에 저장하지 못했어요: {error}"),
+                    )
+                })?;
+        }
+
+        if load_published_preset_summary(&staged_dir).is_none() {
+            return Err(rejection(
+                "bundle-invalid",
+                format!("{label} bundle이 게시 규칙을 만족하지 않아 받지 않았어요."),
+            ));
+        }
+        verify_preset_bundle_signature(
+            &staged_dir,
+            &bundle.preset_id,
+            &bundle.published_version,
+            trust,
+        )
+        .map_err(|finding| rejection("bundle-invalid", finding.message))?;
+        outcomes.push(bundle_outcome(bundle, "installed"));
+        staged_bundles.push(StagedFleetBundle {
+            staged_dir,
+            published_dir,
+        });
+    }
+
+    Ok((outcomes, staged_bundles))
+}
+
+fn promote_staged_bundles(
+    staged_bundles: &[StagedFleetBundle],
+) -> Result<Vec<PathBuf>, FleetSyncRejection> {
+    let mut promoted = Vec::new();
+
+    for bundle in staged_bundles {
+        let moved = bundle
+            .published_dir
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::rename(&bundle.staged_dir, &bundle.published_dir));
+        if let Err(error) = moved {
+            remove_promoted_bundles(&promoted);
+            return Err(rejection(
+                "staging-failed",
+                format!("preset bundle을 게시 폴더로 옮기지 못했어요: {error}"),
+            ));
+        }
+        promoted.push(bundle.published_dir.clone());
+    }
+
+    Ok(promoted)
+}
+
+fn remove_promoted_bundles(promoted: &[PathBuf]) {
+    for published_dir in promoted {
+        let _ = fs::remove_dir_all(published_dir);
+    }
+}
+
+fn merge_approved_baselines(
+    base_dir: &Path,
+    baselines: &[BranchReleaseBaselineDto],
+) -> Result<Vec<BranchReleaseBaselineDto>, HostErrorEnvelope> {
+    if baselines.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let _lock = acquire_branch_rollout_store_lock(base_dir)?;
+    let mut store = load_branch_rollout_store(base_dir)?;
+    let added = baselines
+        .iter()
+        .filter(|baseline| {
+            !store.approved_baselines.iter().any(|existing| {
+                existing.build_version == baseline.build_version
+                    && existing.preset_stack_version == baseline.preset_stack_version
+            })
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if !added.is_empty() {
+        store.approved_baselines.extend(added.iter().cloned());
+        persist_branch_rollout_store(base_dir, &store)?;
+    }
+
+    Ok(added)
+}
+
+fn append_fleet_sync_audit_record(
+    base_dir: &Path,
+    input: &FleetSyncInputDto,
+    result: &FleetSyncResultDto,
+) {
+    let rejected = result.status == "rejected";
+    let release_label = result.release_id.as_deref().unwrap_or("알 수 없음");
+
+    try_append_operator_audit_record(
+        base_dir,
+        OperatorAuditRecordInput {
+            occurred_at: result.synced_at.clone(),
+            session_id: None,
+            event_category: "release-governance",
+            event_type: if rejected {
+                "fleet-sync-rejected"
+            } else {
+                "fleet-sync-completed"
+            },
+            summary: if rejected {
+                "HQ 릴리스 동기화를 거절했어요.".into()
+            } else {
+                "HQ 릴리스 동기화를 마쳤어요.".into()
+            },
+            detail: format!(
+                "{} ({} 저장소, 릴리스 {release_label}, 요청: {})",
+                result.message, result.store_id, input.actor_label
+            ),
+            actor_id: Some(input.actor_id.clone()),
+            source: "branch-config",
+            capture_id: None,
+            preset_id: None,
+            published_version: None,
+            reason_code: result
+                .rejection_code
+                .clone()
+                .or_else(|| Some(result.status.clone())),
+        },
+    );
+}
+
+fn join_object_path(root: &Path, relative_path: &str) -> PathBuf {
+    relative_path
+        .split('/')
+        .fold(root.to_path_buf(), |path, segment| path.join(segment))
+}
+
+fn file_matches_manifest(bytes: &[u8], file: &FleetBundleFileEntry) -> bool {
+    bytes.len() as u64 == file.size_bytes && sha256_hex(bytes) == file.sha256.to_ascii_lowercase()
+}
+
+fn bundle_outcome(bundle: &FleetPresetBundleEntry, result: &str) -> FleetSyncBundleOutcomeDto {
+    FleetSyncBundleOutcomeDto {
+        preset_id: bundle.preset_id.clone(),
+        published_version: bundle.published_version.clone(),
+        result: result.into(),
+    }
+}
+
+fn rejection(code: &'static str, message: impl Into<String>) -> FleetSyncRejection {
+    FleetSyncRejection {
+        code,
+        message: message.into(),
+    }
+}
@@ -0,0 +1,386 @@
+//! Editing of branch-owned settings. Every saved change becomes a numbered
+//! revision in `branch-config/settings-history.json`, and the booth's own
+//! branch contact feeds customer-facing support copy.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    contracts::dto::{
+        validate_branch_local_settings_query_input, validate_branch_local_settings_update_input,
+        BranchLocalSettingsChangeDto, BranchLocalSettingsDiffDto, BranchLocalSettingsDiffInputDto,
+        BranchLocalSettingsDto, BranchLocalSettingsHistoryEntryDto,
+        BranchLocalSettingsQueryInputDto, BranchLocalSettingsResultDto,
+        BranchLocalSettingsUpdateInputDto, CapabilitySnapshotDto, HostErrorEnvelope,
+        HostFieldErrors,
+    },
+    diagnostics::audit_log::{try_append_operator_audit_record, OperatorAuditRecordInput},
+    session::session_manifest::current_timestamp,
+};
+
+use super::{
+    acquire_branch_rollout_store_lock, ensure_settings_access, load_branch_rollout_store,
+    map_fs_error, operational_toggles::unsupported_operational_toggles,
+    persist_branch_rollout_store, read_branch_profile, write_json_bytes_atomically,
+    BranchLocalSettingsRecord,
+};
+
+const BRANCH_SETTINGS_HISTORY_STORE_SCHEMA_VERSION: &str = "branch-settings-history-store/v1";
+const BRANCH_SETTINGS_HISTORY_ENTRY_SCHEMA_VERSION: &str = "branch-settings-history-entry/v1";
+const BRANCH_LOCAL_SETTINGS_RESULT_SCHEMA_VERSION: &str = "branch-local-settings/v1";
+const BRANCH_LOCAL_SETTINGS_DIFF_SCHEMA_VERSION: &str = "branch-local-settings-diff/v1";
+const BRANCH_SETTINGS_HISTORY_PAGE_SIZE: usize = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BranchSettingsHistoryStore {
+    schema_version: String,
+    #[serde(default)]
+    entries: Vec<BranchLocalSettingsHistoryEntryDto>,
+}
+
+pub fn load_branch_local_settings_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: BranchLocalSettingsQueryInputDto,
+) -> Result<BranchLocalSettingsResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    validate_branch_local_settings_query_input(&input)?;
+    let _lock = acquire_branch_rollout_store_lock(base_dir)?;
+
+    let store = load_branch_rollout_store(base_dir)?;
+    let branch = store
+        .branches
+        .iter()
+        .find(|branch| branch.branch_id == input.branch_id)
+        .ok_or_else(branch_not_found)?;
+    let history = load_branch_settings_history(base_dir)?;
+
+    Ok(build_result(
+        &branch.branch_id,
+        &branch.display_name,
+        settings_from_record(&branch.local_settings),
+        &history,
+    ))
+}
+
+pub fn update_branch_local_settings_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: BranchLocalSettingsUpdateInputDto,
+) -> Result<BranchLocalSettingsResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    let settings = validate_branch_local_settings_update_input(&input)?;
+    let _lock = acquire_branch_rollout_store_lock(base_dir)?;
+
+    let previous_store = load_branch_rollout_store(base_dir)?;
+    let mut next_store = previous_store.clone();
+    let branch = next_store
+        .branches
+        .iter_mut()
+        .find(|branch| branch.branch_id == input.branch_id)
+        .ok_or_else(branch_not_found)?;
+    let mut history = load_branch_settings_history(base_dir)?;
+    let revision = current_revision(&history, &branch.branch_id);
+
+    if input
+        .expected_revision
+        .is_some_and(|expected| expected != revision)
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "다른 곳에서 지점 설정이 먼저 바뀌었어요. 새로고침한 뒤 다시 저장해 주세요.",
+        ));
+    }
+
+    let unsupported_toggles = unsupported_operational_toggles(
+        &settings.operational_toggles,
+        &branch.deployment_baseline.build_version,
+    );
+    if !unsupported_toggles.is_empty() {
+        return Err(HostErrorEnvelope::validation(HostFieldErrors {
+            operational_toggles: Some(format!(
+                "현재 빌드가 지원하지 않는 운영 토글이에요: {}",
+                unsupported_toggles.join(", ")
+            )),
+            ..HostFieldErrors::default()
+        }));
+    }
+
+    let previous_settings = settings_from_record(&branch.local_settings);
+    let changes = diff_settings(&previous_settings, &settings);
+    if changes.is_empty() {
+        return Ok(build_result(
+            &branch.branch_id,
+            &branch.display_name,
+            previous_settings,
+            &history,
+        ));
+    }
+
+    let changed_at = current_timestamp(SystemTime::now())?;
+    branch.local_settings = record_from_settings(&settings);
+    let branch_id = branch.branch_id.clone();
+    let display_name = branch.display_name.clone();
+    history.push(BranchLocalSettingsHistoryEntryDto {
+        schema_version: BRANCH_SETTINGS_HISTORY_ENTRY_SCHEMA_VERSION.into(),
+        branch_id: branch_id.clone(),
+        revision: revision + 1,
+        changed_at: changed_at.clone(),
+        actor_id: input.actor_id.clone(),
+        actor_label: input.actor_label.trim().into(),
+        changes: changes.clone(),
+        previous_settings,
+        settings: settings.clone(),
+    });
+
+    persist_branch_rollout_store(base_dir, &next_store)?;
+    if let Err(error) = persist_branch_settings_history(base_dir, &history) {
+        let _ = persist_branch_rollout_store(base_dir, &previous_store);
+        return Err(error);
+    }
+
+    try_append_operator_audit_record(
+        base_dir,
+        OperatorAuditRecordInput {
+            occurred_at: changed_at,
+            session_id: None,
+            event_category: "operator-intervention",
+            event_type: "branch-settings-updated",
+            summary: format!("{display_name} 지점 설정을 바꿨어요."),
+            detail: changes
+                .iter()
+                .map(|change| change.field.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            actor_id: Some(input.actor_id),
+            source: "branch-config",
+            capture_id: None,
+            preset_id: None,
+            published_version: None,
+            reason_code: None,
+        },
+    );
+
+    Ok(build_result(&branch_id, &display_name, settings, &history))
+}
+
+/// Revision 0 is the settings before the first recorded edit.
+pub fn load_branch_local_settings_diff_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: BranchLocalSettingsDiffInputDto,
+) -> Result<BranchLocalSettingsDiffDto, HostErrorEnvelope> {
+    let current = load_branch_local_settings_in_dir(
+        base_dir,
+        capability_snapshot,
+        BranchLocalSettingsQueryInputDto {
+            branch_id: input.branch_id.clone(),
+        },
+    )?;
+    let history = load_branch_settings_history(base_dir)?;
+    let to_revision = input.to_revision.unwrap_or(current.revision);
+    if input.from_revision > current.revision || to_revision > current.revision {
+        return Err(HostErrorEnvelope::validation_message(
+            "비교할 지점 설정 버전을 다시 확인해 주세요.",
+        ));
+    }
+
+    let settings_at = |revision: u32| {
+        let mut entries = history
+            .iter()
+            .filter(|entry| entry.branch_id == current.branch_id);
+        if revision == 0 {
+            entries
+                .next()
+                .map(|entry| entry.previous_settings.clone())
+                .unwrap_or_else(|| current.settings.clone())
+        } else {
+            entries
+                .find(|entry| entry.revision == revision)
+                .map(|entry| entry.settings.clone())
+                .unwrap_or_else(|| current.settings.clone())
+        }
+    };
+
+    Ok(BranchLocalSettingsDiffDto {
+        schema_version: BRANCH_LOCAL_SETTINGS_DIFF_SCHEMA_VERSION.into(),
+        branch_id: current.branch_id.clone(),
+        from_revision: input.from_revision,
+        to_revision,
+        changes: diff_settings(&settings_at(input.from_revision), &settings_at(to_revision)),
+    })
+}
+
+/// Settings of the branch named by `branchId` in the booth's branch profile.
+pub(crate) fn load_booth_branch_local_settings_in_dir(
+    base_dir: &Path,
+) -> Option<BranchLocalSettingsDto> {
+    let branch_id = read_branch_profile(base_dir)?.branch_id?;
+    let store = load_branch_rollout_store(base_dir).ok()?;
+
+    store
+        .branches
+        .iter()
+        .find(|branch| branch.branch_id == branch_id)
+        .map(|branch| settings_from_record(&branch.local_settings))
+}
+
+/// Customer-facing support line for the booth's branch, e.g.
+/// `지점 문의: 02-555-0101 (운영 10:00-22:00)`.
+pub(crate) fn load_branch_support_contact_label_in_dir(base_dir: &Path) -> Option<String> {
+    let settings = load_booth_branch_local_settings_in_dir(base_dir)?;
+    let contact = settings
+        .contact_phone
+        .or_else(|| {
+            settings
+                .contact_kakao
+                .map(|kakao| format!("카카오톡 {kakao}"))
+        })
+        .or(settings.contact_email)?;
+
+    Some(match settings.support_hours {
+        Some(hours) => format!("지점 문의: {contact} (운영 {hours})"),
+        None => format!("지점 문의: {contact}"),
+    })
+}
+
+fn build_result(
+    branch_id: &str,
+    display_name: &str,
+    settings: BranchLocalSettingsDto,
+    history: &[BranchLocalSettingsHistoryEntryDto],
+) -> BranchLocalSettingsResultDto {
+    BranchLocalSettingsResultDto {
+        schema_version: BRANCH_LOCAL_SETTINGS_RESULT_SCHEMA_VERSION.into(),
+        branch_id: branch_id.into(),
+        display_name: display_name.into(),
+        revision: current_revision(history, branch_id),
+        settings,
+        history: history
+            .iter()
+            .rev()
+            .filter(|entry| entry.branch_id == branch_id)
+            .take(BRANCH_SETTINGS_HISTORY_PAGE_SIZE)
+            .cloned()
+            .collect(),
+    }
+}
+
+fn current_revision(history: &[BranchLocalSettingsHistoryEntryDto], branch_id: &str) -> u32 {
+    history
+        .iter()
+        .filter(|entry| entry.branch_id == branch_id)
+        .map(|entry| entry.revision)
+        .max()
+        .unwrap_or(0)
+}
+
+fn diff_settings(
+    before: &BranchLocalSettingsDto,
+    after: &BranchLocalSettingsDto,
+) -> Vec<BranchLocalSettingsChangeDto> {
+    let toggles = |settings: &BranchLocalSettingsDto| {
+        (!settings.operational_toggles.is_empty()).then(|| settings.operational_toggles.join(", "))
+    };
+
+    [
+        ("contact-phone", &before.contact_phone, &after.contact_phone),
+        ("contact-email", &before.contact_email, &after.contact_email),
+        ("contact-kakao", &before.contact_kakao, &after.contact_kakao),
+        ("support-hours", &before.support_hours, &after.support_hours),
+        (
+            "bounded-operational-toggle",
+            &toggles(before),
+            &toggles(after),
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, before, after)| before != after)
+    .map(|(field, before, after)| BranchLocalSettingsChangeDto {
+        field: field.into(),
+        before: before.clone(),
+        after: after.clone(),
+    })
+    .collect()
+}
+
+fn settings_from_record(record: &BranchLocalSettingsRecord) -> BranchLocalSettingsDto {
+    let present = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+
+    BranchLocalSettingsDto {
+        contact_phone: present(&record.contact_phone),
+        contact_email: present(&record.contact_email),
+        contact_kakao: present(&record.contact_kakao),
+        support_hours: present(&record.support_hours),
+        operational_toggles: record.operational_toggles.clone(),
+    }
+}
+
+fn record_from_settings(settings: &BranchLocalSettingsDto) -> BranchLocalSettingsRecord {
+    BranchLocalSettingsRecord {
+        contact_phone: settings.contact_phone.clone(),
+        contact_email: settings.contact_email.clone(),
+        contact_kakao: settings.contact_kakao.clone(),
+        support_hours: settings.support_hours.clone(),
+        operational_toggles: settings.operational_toggles.clone(),
+    }
+}
+
+pub(super) fn branch_not_found() -> HostErrorEnvelope {
+    HostErrorEnvelope::validation_message("승인된 지점 목록에 없는 식별자예요.")
+}
+
+fn load_branch_settings_history(
+    base_dir: &Path,
+) -> Result<Vec<BranchLocalSettingsHistoryEntryDto>, HostErrorEnvelope> {
+    let history_path = resolve_branch_settings_history_path(base_dir);
+    let backup_path = history_path.with_extension("json.bak");
+    let read_path = if history_path.exists() {
+        history_path
+    } else if backup_path.is_file() {
+        backup_path
+    } else {
+        return Ok(Vec::new());
+    };
+
+    let bytes = fs::read_to_string(read_path).map_err(map_fs_error)?;
+    let store = serde_json::from_str::<BranchSettingsHistoryStore>(&bytes).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("지점 설정 이력을 읽지 못했어요: {error}"))
+    })?;
+
+    Ok(store.entries)
+}
+
+fn persist_branch_settings_history(
+    base_dir: &Path,
+    entries: &[BranchLocalSettingsHistoryEntryDto],
+) -> Result<(), HostErrorEnvelope> {
+    let history_path = resolve_branch_settings_history_path(base_dir);
+    if let Some(history_dir) = history_path.parent() {
+        fs::create_dir_all(history_dir).map_err(map_fs_error)?;
+    }
+    let bytes = serde_json::to_vec_pretty(&BranchSettingsHistoryStore {
+        schema_version: BRANCH_SETTINGS_HISTORY_STORE_SCHEMA_VERSION.into(),
+        entries: entries.to_vec(),
+    })
+    .map_err(|error| {
+        HostErrorEnvelope::persistence(format!("지점 설정 이력을 직렬화하지 못했어요: {error}"))
+    })?;
+
+    write_json_bytes_atomically(&history_path, &bytes)
+}
+
+fn resolve_branch_settings_history_path(base_dir: &Path) -> PathBuf {
+    base_dir.join("branch-config").join("settings-history.json")
+}
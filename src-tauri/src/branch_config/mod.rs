@@ -1,3 +1,12 @@
+pub mod branch_health;
+pub mod fleet_store;
+pub mod fleet_sync;
+pub mod local_settings;
+pub mod operational_toggles;
+pub mod preset_signing_keys;
+pub mod rollout_plan;
+pub mod session_packages;
+
 use std::{
     collections::HashSet,
     fs,
@@ -38,6 +47,7 @@ const BRANCH_ROLLOUT_ACTION_RESULT_SCHEMA_VERSION: &str = "branch-rollout-action
 const BRANCH_ROLLOUT_LOCK_RETRY_DELAY_MS: u64 = 10;
 const BRANCH_ROLLOUT_LOCK_MAX_ATTEMPTS: u32 = 500;
 const BRANCH_ROLLOUT_LOCK_STALE_AFTER_MS: u64 = 30_000;
+const BRANCH_PROFILE_SCHEMA_VERSION: &str = "branch-profile/v1";
 
 static BRANCH_ROLLOUT_AUDIT_COUNTER: AtomicU64 = AtomicU64::new(0);
 
@@ -86,6 +96,17 @@ struct BranchRolloutHistoryStore {
     entries: Vec<BranchRolloutAuditEntryDto>,
 }
 
+/// Identity of the branch this booth is installed at.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BranchProfile {
+    schema_version: String,
+    display_name: String,
+    /// Links the booth to its record in the rollout store.
+    #[serde(default)]
+    branch_id: Option<String>,
+}
+
 struct BranchRolloutStoreLock {
     lock_path: PathBuf,
 }
@@ -103,12 +124,7 @@ pub fn load_branch_rollout_overview_in_dir(
     capability_snapshot: &CapabilitySnapshotDto,
 ) -> Result<BranchRolloutOverviewResultDto, HostErrorEnvelope> {
     ensure_settings_access(capability_snapshot)?;
-    let _lock = acquire_branch_rollout_store_lock(base_dir)?;
-
-    let mut store = load_branch_rollout_store(base_dir)?;
-    if sync_branch_runtime_state(base_dir, &mut store)? {
-        persist_branch_rollout_store(base_dir, &store)?;
-    }
+    let store = load_synced_branch_rollout_store(base_dir)?;
 
     Ok(BranchRolloutOverviewResultDto {
         schema_version: BRANCH_ROLLOUT_OVERVIEW_SCHEMA_VERSION.into(),
@@ -122,9 +138,47 @@ pub fn load_branch_rollout_overview_in_dir(
             .into_iter()
             .rev()
             .collect(),
+        rollout_plans: rollout_plan::load_branch_rollout_plans(base_dir)?,
     })
 }
 
+/// Loads the rollout store after applying deferred baselines whose session
+/// has ended.
+fn load_synced_branch_rollout_store(
+    base_dir: &Path,
+) -> Result<BranchRolloutStore, HostErrorEnvelope> {
+    let _lock = acquire_branch_rollout_store_lock(base_dir)?;
+
+    let mut store = load_branch_rollout_store(base_dir)?;
+    if sync_branch_runtime_state(base_dir, &mut store)? {
+        persist_branch_rollout_store(base_dir, &store)?;
+    }
+
+    Ok(store)
+}
+
+/// Display name from `branch-config/branch-profile.json`, or `None` when the
+/// booth has no readable branch profile.
+pub fn load_branch_display_name_in_dir(base_dir: &Path) -> Option<String> {
+    let profile = read_branch_profile(base_dir)?;
+    let display_name = profile.display_name.trim();
+
+    (!display_name.is_empty()).then(|| display_name.to_string())
+}
+
+/// `branchId` from `branch-config/branch-profile.json`, or `None` when the
+/// booth is not linked to a rollout store record.
+pub(crate) fn load_branch_id_in_dir(base_dir: &Path) -> Option<String> {
+    read_branch_profile(base_dir)?.branch_id
+}
+
+fn read_branch_profile(base_dir: &Path) -> Option<BranchProfile> {
+    let bytes = fs::read_to_string(resolve_branch_profile_path(base_dir)).ok()?;
+    let profile = serde_json::from_str::<BranchProfile>(&bytes).ok()?;
+
+    (profile.schema_version == BRANCH_PROFILE_SCHEMA_VERSION).then_some(profile)
+}
+
 pub fn apply_branch_rollout_in_dir(
     base_dir: &Path,
     capability_snapshot: &CapabilitySnapshotDto,
@@ -396,6 +450,30 @@ fn resolve_rollout_outcome(
         return outcome;
     }
 
+    let unsupported_toggles = operational_toggles::unsupported_operational_toggles(
+        &branch.local_settings.operational_toggles,
+        &target_baseline.build_version,
+    );
+    if !unsupported_toggles.is_empty() {
+        return rejected_outcome(
+            &branch.branch_id,
+            &branch.display_name,
+            "rejected",
+            branch.deployment_baseline.clone(),
+            branch.pending_baseline.clone(),
+            local_settings,
+            incompatible_verdict("대상 빌드가 이 지점의 운영 토글을 지원하지 않아요."),
+            Some(rejection(
+                "unsupported-operational-toggle",
+                &format!(
+                    "대상 빌드가 켜 둔 운영 토글({})을 지원하지 않아요.",
+                    unsupported_toggles.join(", ")
+                ),
+                "토글을 끄거나 해당 토글을 지원하는 baseline을 승인한 뒤 다시 시도해 주세요.",
+            )),
+        );
+    }
+
     if let Some(existing_pending) = branch.pending_baseline.clone() {
         if existing_pending == *target_baseline {
             return deferred_outcome(
@@ -1038,6 +1116,10 @@ fn resolve_branch_rollout_history_path(base_dir: &Path) -> PathBuf {
     base_dir.join("branch-config").join("rollout-history.json")
 }
 
+fn resolve_branch_profile_path(base_dir: &Path) -> PathBuf {
+    base_dir.join("branch-config").join("branch-profile.json")
+}
+
 fn resolve_branch_rollout_lock_path(base_dir: &Path) -> PathBuf {
     base_dir.join("branch-config").join("governance.lock")
 }
@@ -1045,7 +1127,14 @@ fn resolve_branch_rollout_lock_path(base_dir: &Path) -> PathBuf {
 fn acquire_branch_rollout_store_lock(
     base_dir: &Path,
 ) -> Result<BranchRolloutStoreLock, HostErrorEnvelope> {
-    let lock_path = resolve_branch_rollout_lock_path(base_dir);
+    acquire_branch_config_lock(resolve_branch_rollout_lock_path(base_dir))
+}
+
+/// Callers holding more than one lock take the rollout plan lock before the
+/// governance lock.
+fn acquire_branch_config_lock(
+    lock_path: PathBuf,
+) -> Result<BranchRolloutStoreLock, HostErrorEnvelope> {
     let lock_dir = lock_path.parent().ok_or_else(|| {
         HostErrorEnvelope::persistence("branch rollout lock 경로를 준비하지 못했어요.")
     })?;
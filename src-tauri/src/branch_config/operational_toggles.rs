@@ -0,0 +1,360 @@
+//! Typed registry for branch operational toggles. Branch settings store each
+//! toggle as `name` (a bool switched on) or `name=value`; runtime code reads
+//! the resolved values for the booth's own branch instead of the raw list.
+
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    time::SystemTime,
+};
+
+use crate::contracts::dto::{
+    validate_branch_local_settings_query_input, BranchLocalSettingsQueryInputDto,
+    BranchOperationalToggleStateDto, BranchOperationalTogglesResultDto, CapabilitySnapshotDto,
+    HostErrorEnvelope, OperationalToggleDefinitionDto,
+};
+
+use super::{
+    acquire_branch_rollout_store_lock, ensure_settings_access, load_branch_rollout_store,
+    local_settings::{branch_not_found, load_booth_branch_local_settings_in_dir},
+    resolve_branch_profile_path, resolve_branch_rollout_store_path,
+};
+
+const BRANCH_OPERATIONAL_TOGGLES_RESULT_SCHEMA_VERSION: &str = "branch-operational-toggles/v1";
+
+/// Booth toggles per base dir, reused until the branch profile or the
+/// rollout store changes on disk.
+static BOOTH_OPERATIONAL_TOGGLES: OnceLock<Mutex<HashMap<PathBuf, CachedBoothToggles>>> =
+    OnceLock::new();
+
+struct CachedBoothToggles {
+    source_stamps: [Option<(u64, SystemTime)>; 2],
+    toggles: ResolvedOperationalToggles,
+}
+
+pub const CAPTURE_COUNTDOWN_SECONDS: &str = "capture-countdown-seconds";
+pub const RENDER_FINAL_JPEG_QUALITY: &str = "render-final-jpeg-quality";
+pub const HANDOFF_DOWNLOAD_TTL_MINUTES: &str = "handoff-download-ttl-minutes";
+pub const TIMING_WARNING_PHASE_ENABLED: &str = "timing-warning-phase-enabled";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationalToggleKind {
+    Bool,
+    Int { min: i64, max: i64 },
+    Enum { values: &'static [&'static str] },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationalToggleValue {
+    Bool(bool),
+    Int(i64),
+    Enum(&'static str),
+}
+
+impl OperationalToggleValue {
+    /// Same encoding the branch settings store uses after `=`.
+    pub fn as_setting_value(&self) -> String {
+        match self {
+            Self::Bool(value) => value.to_string(),
+            Self::Int(value) => value.to_string(),
+            Self::Enum(value) => (*value).into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperationalToggleDefinition {
+    pub name: &'static str,
+    pub kind: OperationalToggleKind,
+    pub default: OperationalToggleValue,
+    pub description: &'static str,
+    /// Oldest `boothy-YYYY.MM.DD.N` build that reads this toggle.
+    pub min_build_version: &'static str,
+}
+
+impl OperationalToggleDefinition {
+    fn parse_value(&self, raw: Option<&str>) -> Option<OperationalToggleValue> {
+        match (self.kind, raw) {
+            (OperationalToggleKind::Bool, None) => Some(OperationalToggleValue::Bool(true)),
+            (OperationalToggleKind::Bool, Some(raw)) => {
+                raw.parse().ok().map(OperationalToggleValue::Bool)
+            }
+            (OperationalToggleKind::Int { min, max }, Some(raw)) => raw
+                .parse::<i64>()
+                .ok()
+                .filter(|value| (min..=max).contains(value))
+                .map(OperationalToggleValue::Int),
+            (OperationalToggleKind::Enum { values }, Some(raw)) => values
+                .iter()
+                .find(|value| **value == raw)
+                .map(|value| OperationalToggleValue::Enum(value)),
+            (_, None) => None,
+        }
+    }
+}
+
+pub static OPERATIONAL_TOGGLE_REGISTRY: [OperationalToggleDefinition; 4] = [
+    OperationalToggleDefinition {
+        name: CAPTURE_COUNTDOWN_SECONDS,
+        kind: OperationalToggleKind::Int { min: 0, max: 10 },
+        default: OperationalToggleValue::Int(3),
+        description: "카운트다운 촬영의 기본 대기 초예요.",
+        min_build_version: "boothy-2026.03.20.1",
+    },
+    OperationalToggleDefinition {
+        name: RENDER_FINAL_JPEG_QUALITY,
+        kind: OperationalToggleKind::Enum {
+            values: &["standard", "high"],
+        },
+        default: OperationalToggleValue::Enum("standard"),
+        description: "완성 사진 JPEG 품질이에요. high는 파일이 더 커져요.",
+        min_build_version: "boothy-2026.03.27.1",
+    },
+    OperationalToggleDefinition {
+        name: HANDOFF_DOWNLOAD_TTL_MINUTES,
+        kind: OperationalToggleKind::Int { min: 5, max: 120 },
+        default: OperationalToggleValue::Int(30),
+        description: "다운로드 QR이 유효한 시간(분)이에요.",
+        min_build_version: "boothy-2026.03.20.1",
+    },
+    OperationalToggleDefinition {
+        name: TIMING_WARNING_PHASE_ENABLED,
+        kind: OperationalToggleKind::Bool,
+        default: OperationalToggleValue::Bool(true),
+        description: "세션 종료 전 경고 구간을 사용해요.",
+        min_build_version: "boothy-2026.03.27.1",
+    },
+];
+
+pub fn find_operational_toggle(name: &str) -> Option<&'static OperationalToggleDefinition> {
+    OPERATIONAL_TOGGLE_REGISTRY
+        .iter()
+        .find(|definition| definition.name == name)
+}
+
+/// Parses one stored `name` / `name=value` entry against the registry.
+pub fn parse_operational_toggle_entry(
+    entry: &str,
+) -> Result<(&'static OperationalToggleDefinition, OperationalToggleValue), String> {
+    let (name, raw_value) = match entry.split_once('=') {
+        Some((name, value)) => (name, Some(value)),
+        None => (entry, None),
+    };
+    let definition = find_operational_toggle(name)
+        .ok_or_else(|| format!("알 수 없는 운영 토글이에요: {name}"))?;
+    let value = definition
+        .parse_value(raw_value)
+        .ok_or_else(|| format!("{name} 토글 값을 다시 확인해 주세요."))?;
+
+    Ok((definition, value))
+}
+
+/// Toggles configured in `entries` that `build_version` is too old to read.
+/// Entries the registry does not know are left to settings validation.
+pub fn unsupported_operational_toggles(
+    entries: &[String],
+    build_version: &str,
+) -> Vec<&'static str> {
+    entries
+        .iter()
+        .filter_map(|entry| parse_operational_toggle_entry(entry).ok())
+        .map(|(definition, _)| definition)
+        .filter(|definition| {
+            compare_build_versions(build_version, definition.min_build_version)
+                .is_none_or(|ordering| ordering == Ordering::Less)
+        })
+        .map(|definition| definition.name)
+        .collect()
+}
+
+/// Orders two `boothy-YYYY.MM.DD.N` versions; `None` when either is malformed.
+pub fn compare_build_versions(left: &str, right: &str) -> Option<Ordering> {
+    let parse = |version: &str| -> Option<[u32; 4]> {
+        let mut segments = version.strip_prefix("boothy-")?.split('.');
+        let mut parsed = [0; 4];
+        for slot in &mut parsed {
+            *slot = segments.next()?.parse().ok()?;
+        }
+
+        segments.next().is_none().then_some(parsed)
+    };
+
+    Some(parse(left)?.cmp(&parse(right)?))
+}
+
+/// Registry values for one branch, with anything unset or unreadable left at
+/// its default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedOperationalToggles {
+    values: Vec<(
+        &'static OperationalToggleDefinition,
+        OperationalToggleValue,
+        bool,
+    )>,
+}
+
+impl ResolvedOperationalToggles {
+    pub fn from_entries(entries: &[String]) -> Self {
+        let configured: Vec<_> = entries
+            .iter()
+            .filter_map(|entry| parse_operational_toggle_entry(entry).ok())
+            .collect();
+        let values = OPERATIONAL_TOGGLE_REGISTRY
+            .iter()
+            .map(|definition| {
+                match configured
+                    .iter()
+                    .find(|(configured, _)| configured.name == definition.name)
+                {
+                    Some((_, value)) => (definition, *value, true),
+                    None => (definition, definition.default, false),
+                }
+            })
+            .collect();
+
+        Self { values }
+    }
+
+    pub fn value(&self, name: &str) -> Option<OperationalToggleValue> {
+        self.values
+            .iter()
+            .find(|(definition, _, _)| definition.name == name)
+            .map(|(_, value, _)| *value)
+    }
+
+    pub fn bool_value(&self, name: &str) -> bool {
+        matches!(self.value(name), Some(OperationalToggleValue::Bool(true)))
+    }
+
+    pub fn int_value(&self, name: &str) -> Option<i64> {
+        match self.value(name) {
+            Some(OperationalToggleValue::Int(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn enum_value(&self, name: &str) -> Option<&'static str> {
+        match self.value(name) {
+            Some(OperationalToggleValue::Enum(value)) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Toggles for the branch this booth is installed at. Booths without a
+/// linked branch run on registry defaults. The result is cached, so hot
+/// paths only pay for two `stat` calls while the settings are unchanged.
+pub fn resolve_booth_operational_toggles_in_dir(base_dir: &Path) -> ResolvedOperationalToggles {
+    let source_stamps = [
+        read_source_stamp(&resolve_branch_profile_path(base_dir)),
+        read_source_stamp(&resolve_branch_rollout_store_path(base_dir)),
+    ];
+    let Ok(mut cache) = BOOTH_OPERATIONAL_TOGGLES
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+    else {
+        return load_booth_operational_toggles(base_dir);
+    };
+    if let Some(cached) = cache
+        .get(base_dir)
+        .filter(|cached| cached.source_stamps == source_stamps)
+    {
+        return cached.toggles.clone();
+    }
+
+    let toggles = load_booth_operational_toggles(base_dir);
+    cache.insert(
+        base_dir.to_path_buf(),
+        CachedBoothToggles {
+            source_stamps,
+            toggles: toggles.clone(),
+        },
+    );
+
+    toggles
+}
+
+fn load_booth_operational_toggles(base_dir: &Path) -> ResolvedOperationalToggles {
+    let entries = load_booth_branch_local_settings_in_dir(base_dir)
+        .map(|settings| settings.operational_toggles)
+        .unwrap_or_default();
+
+    ResolvedOperationalToggles::from_entries(&entries)
+}
+
+fn read_source_stamp(path: &Path) -> Option<(u64, SystemTime)> {
+    let metadata = fs::metadata(path).ok()?;
+
+    Some((metadata.len(), metadata.modified().ok()?))
+}
+
+pub fn load_branch_operational_toggles_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: BranchLocalSettingsQueryInputDto,
+) -> Result<BranchOperationalTogglesResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    validate_branch_local_settings_query_input(&input)?;
+    let _lock = acquire_branch_rollout_store_lock(base_dir)?;
+
+    let store = load_branch_rollout_store(base_dir)?;
+    let branch = store
+        .branches
+        .iter()
+        .find(|branch| branch.branch_id == input.branch_id)
+        .ok_or_else(branch_not_found)?;
+    let entries = &branch.local_settings.operational_toggles;
+    let build_version = &branch.deployment_baseline.build_version;
+    let unsupported = unsupported_operational_toggles(entries, build_version);
+    let resolved = ResolvedOperationalToggles::from_entries(entries);
+
+    Ok(BranchOperationalTogglesResultDto {
+        schema_version: BRANCH_OPERATIONAL_TOGGLES_RESULT_SCHEMA_VERSION.into(),
+        branch_id: branch.branch_id.clone(),
+        display_name: branch.display_name.clone(),
+        build_version: build_version.clone(),
+        registry: OPERATIONAL_TOGGLE_REGISTRY
+            .iter()
+            .map(definition_dto)
+            .collect(),
+        toggles: resolved
+            .values
+            .iter()
+            .map(
+                |(definition, value, configured)| BranchOperationalToggleStateDto {
+                    name: definition.name.into(),
+                    value: value.as_setting_value(),
+                    source: if *configured { "branch" } else { "default" }.into(),
+                    supported: !unsupported.contains(&definition.name),
+                },
+            )
+            .collect(),
+    })
+}
+
+fn definition_dto(definition: &OperationalToggleDefinition) -> OperationalToggleDefinitionDto {
+    let (kind, min_value, max_value, allowed_values) = match definition.kind {
+        OperationalToggleKind::Bool => ("bool", None, None, Vec::new()),
+        OperationalToggleKind::Int { min, max } => ("int", Some(min), Some(max), Vec::new()),
+        OperationalToggleKind::Enum { values } => (
+            "enum",
+            None,
+            None,
+            values.iter().map(|value| (*value).into()).collect(),
+        ),
+    };
+
+    OperationalToggleDefinitionDto {
+        name: definition.name.into(),
+        kind: kind.into(),
+        default_value: definition.default.as_setting_value(),
+        min_value,
+        max_value,
+        allowed_values,
+        description: definition.description.into(),
+        min_build_version: definition.min_build_version.into(),
+    }
+}
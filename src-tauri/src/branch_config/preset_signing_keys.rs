@@ -0,0 +1,578 @@
+//! Per-branch Ed25519 keys for published preset bundles. The booth data
+//! directory only holds public keys: the branch's own current and rotated
+//! keys plus keys imported from HQ or other branches. Private keys stay in the
+//! folder named by `BOOTHY_PRESET_SIGNING_KEY_DIR`, which must live outside
+//! the booth data directory; booths without it can verify but not sign.
+
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    contracts::dto::{
+        validate_preset_bundle_resign_input, validate_preset_signing_key_generate_input,
+        validate_preset_signing_key_revoke_input, validate_preset_signing_key_trust_input,
+        CapabilitySnapshotDto, HostErrorEnvelope, PresetBundleRefDto, PresetBundleResignInputDto,
+        PresetBundleResignResultDto, PresetSigningKeyDto, PresetSigningKeyGenerateInputDto,
+        PresetSigningKeyRevokeInputDto, PresetSigningKeyTrustInputDto, PresetSigningKeysResultDto,
+    },
+    diagnostics::audit_log::{try_append_operator_audit_record, OperatorAuditRecordInput},
+    integrity::{decode_hex, ed25519_public_key, encode_hex, generate_seed},
+    preset::{
+        bundle_signature::{sign_preset_bundle, PRESET_BUNDLE_SIGNATURE_FILE_NAME},
+        default_catalog::default_preset_bundle_files,
+        preset_bundle::load_published_preset_summary,
+        preset_catalog::resolve_published_preset_catalog_dir,
+    },
+    session::session_manifest::current_timestamp,
+};
+
+use super::{
+    acquire_branch_config_lock, ensure_settings_access, map_fs_error, read_branch_profile,
+    write_json_bytes_atomically,
+};
+
+pub const PRESET_SIGNING_KEY_DIR_ENV: &str = "BOOTHY_PRESET_SIGNING_KEY_DIR";
+
+const PRESET_SIGNING_KEYS_SCHEMA_VERSION: &str = "preset-signing-keys/v1";
+const PRESET_SIGNING_KEYS_RESULT_SCHEMA_VERSION: &str = "preset-signing-keys-result/v1";
+const PRESET_BUNDLE_RESIGN_RESULT_SCHEMA_VERSION: &str = "preset-bundle-resign-result/v1";
+const BRANCH_KEY_ORIGIN: &str = "branch";
+const IMPORTED_KEY_ORIGIN: &str = "imported";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PresetSigningKeyStore {
+    schema_version: String,
+    #[serde(default)]
+    active_key_id: Option<String>,
+    #[serde(default)]
+    keys: Vec<PresetSigningKeyRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PresetSigningKeyRecord {
+    key_id: String,
+    public_key: String,
+    origin: String,
+    added_at: String,
+    actor_id: String,
+    #[serde(default)]
+    revoked_at: Option<String>,
+}
+
+/// Public keys this booth accepts preset bundle signatures from. An empty
+/// trust list accepts no signature.
+#[derive(Debug, Clone, Default)]
+pub struct PresetBundleTrust {
+    keys: Vec<(String, [u8; 32])>,
+}
+
+impl PresetBundleTrust {
+    pub fn public_key(&self, key_id: &str) -> Option<&[u8; 32]> {
+        self.keys
+            .iter()
+            .find(|(trusted_key_id, _)| trusted_key_id == key_id)
+            .map(|(_, public_key)| public_key)
+    }
+}
+
+/// The key this booth signs newly published bundles with.
+#[derive(Debug, Clone)]
+pub struct PresetSigningKey {
+    pub key_id: String,
+    pub seed: [u8; 32],
+}
+
+pub fn resolve_preset_signing_keys_path(base_dir: &Path) -> PathBuf {
+    base_dir
+        .join("branch-config")
+        .join("preset-signing-keys.json")
+}
+
+fn resolve_preset_signing_keys_lock_path(base_dir: &Path) -> PathBuf {
+    base_dir
+        .join("branch-config")
+        .join("preset-signing-keys.lock")
+}
+
+/// A missing, empty or unreadable key file trusts nothing, so every bundle
+/// that needs a signature is rejected.
+pub fn load_preset_bundle_trust_in_dir(base_dir: &Path) -> PresetBundleTrust {
+    let keys = read_preset_signing_key_store(base_dir)
+        .map(|store| {
+            store
+                .keys
+                .iter()
+                .filter(|key| key.revoked_at.is_none())
+                .filter_map(|key| {
+                    decode_hex::<32>(&key.public_key)
+                        .map(|public_key| (key.key_id.clone(), public_key))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    PresetBundleTrust { keys }
+}
+
+/// Returns the booth's active signing key, generating the branch's first key
+/// when it has none. Publishing calls this so every new bundle is signed, and
+/// fails when no private key folder is configured.
+pub(crate) fn ensure_active_preset_signing_key_in_dir(
+    base_dir: &Path,
+    actor_id: &str,
+    occurred_at: &str,
+) -> Result<PresetSigningKey, HostErrorEnvelope> {
+    let key_dir = resolve_preset_private_key_dir(base_dir)?;
+    let _lock = acquire_branch_config_lock(resolve_preset_signing_keys_lock_path(base_dir))?;
+    let mut store = read_preset_signing_key_store(base_dir)?;
+    if let Some(key) = active_signing_key(&key_dir, &store)? {
+        return Ok(key);
+    }
+
+    create_branch_signing_key(base_dir, &key_dir, &mut store, actor_id, occurred_at)
+}
+
+pub fn load_preset_signing_keys_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+) -> Result<PresetSigningKeysResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    let store = read_preset_signing_key_store(base_dir)?;
+
+    Ok(build_result(base_dir, &store))
+}
+
+/// Rotates to a new branch key. Earlier keys stay trusted so bundles they
+/// signed keep loading until they are revoked.
+pub fn generate_preset_signing_key_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: PresetSigningKeyGenerateInputDto,
+) -> Result<PresetSigningKeysResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    validate_preset_signing_key_generate_input(&input)?;
+    let key_dir = resolve_preset_private_key_dir(base_dir)?;
+    let occurred_at = current_timestamp(SystemTime::now())?;
+    let _lock = acquire_branch_config_lock(resolve_preset_signing_keys_lock_path(base_dir))?;
+    let mut store = read_preset_signing_key_store(base_dir)?;
+
+    create_branch_signing_key(
+        base_dir,
+        &key_dir,
+        &mut store,
+        &input.actor_id,
+        &occurred_at,
+    )?;
+
+    Ok(build_result(base_dir, &store))
+}
+
+pub fn trust_preset_signing_key_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: PresetSigningKeyTrustInputDto,
+) -> Result<PresetSigningKeysResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    validate_preset_signing_key_trust_input(&input)?;
+    let occurred_at = current_timestamp(SystemTime::now())?;
+    let _lock = acquire_branch_config_lock(resolve_preset_signing_keys_lock_path(base_dir))?;
+    let mut store = read_preset_signing_key_store(base_dir)?;
+
+    if store.keys.iter().any(|key| key.key_id == input.key_id) {
+        return Err(HostErrorEnvelope::validation_message(
+            "이미 등록된 서명 키 ID예요. 다른 ID로 등록해 주세요.",
+        ));
+    }
+
+    store.keys.push(PresetSigningKeyRecord {
+        key_id: input.key_id.clone(),
+        public_key: input.public_key.trim().to_ascii_lowercase(),
+        origin: IMPORTED_KEY_ORIGIN.into(),
+        added_at: occurred_at.clone(),
+        actor_id: input.actor_id.clone(),
+        revoked_at: None,
+    });
+    persist_preset_signing_key_store(base_dir, &store)?;
+    append_signing_key_audit_record(
+        base_dir,
+        "preset-signing-key-trusted",
+        "preset 서명 키를 신뢰 목록에 추가했어요.",
+        format!(
+            "{} 키로 서명된 preset bundle을 받을 수 있어요. (요청: {})",
+            input.key_id, input.actor_label
+        ),
+        &input.actor_id,
+        &occurred_at,
+    );
+
+    Ok(build_result(base_dir, &store))
+}
+
+/// Bundles signed by a revoked key are rejected from the next load on.
+pub fn revoke_preset_signing_key_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: PresetSigningKeyRevokeInputDto,
+) -> Result<PresetSigningKeysResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    validate_preset_signing_key_revoke_input(&input)?;
+    let occurred_at = current_timestamp(SystemTime::now())?;
+    let _lock = acquire_branch_config_lock(resolve_preset_signing_keys_lock_path(base_dir))?;
+    let mut store = read_preset_signing_key_store(base_dir)?;
+
+    let key = store
+        .keys
+        .iter_mut()
+        .find(|key| key.key_id == input.key_id)
+        .ok_or_else(|| HostErrorEnvelope::validation_message("등록되지 않은 서명 키예요."))?;
+    if key.revoked_at.is_some() {
+        return Ok(build_result(base_dir, &store));
+    }
+    key.revoked_at = Some(occurred_at.clone());
+    if store.active_key_id.as_deref() == Some(input.key_id.as_str()) {
+        store.active_key_id = None;
+    }
+    persist_preset_signing_key_store(base_dir, &store)?;
+    append_signing_key_audit_record(
+        base_dir,
+        "preset-signing-key-revoked",
+        "preset 서명 키를 폐기했어요.",
+        format!(
+            "{} 키로 서명된 preset bundle은 더 이상 불러오지 않아요. (요청: {})",
+            input.key_id, input.actor_label
+        ),
+        &input.actor_id,
+        &occurred_at,
+    );
+
+    Ok(build_result(base_dir, &store))
+}
+
+/// Signs published bundles that predate bundle signing with the active key,
+/// so they load again now that signatures are always checked. Only bundles
+/// without `bundle-signature.json` are touched: a bundle whose signature no
+/// longer matches stays rejected, and seeded defaults keep matching their
+/// built-in copies.
+pub fn resign_unsigned_preset_bundles_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: PresetBundleResignInputDto,
+) -> Result<PresetBundleResignResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    validate_preset_bundle_resign_input(&input)?;
+    let occurred_at = current_timestamp(SystemTime::now())?;
+    let signing_key =
+        ensure_active_preset_signing_key_in_dir(base_dir, &input.actor_id, &occurred_at)?;
+
+    let mut signed_bundles = Vec::new();
+    for (bundle_dir, bundle) in list_unsigned_published_bundles(base_dir)? {
+        sign_preset_bundle(
+            &bundle_dir,
+            &bundle.preset_id,
+            &bundle.published_version,
+            &signing_key,
+            &occurred_at,
+        )?;
+        signed_bundles.push(bundle);
+    }
+
+    let message = if signed_bundles.is_empty() {
+        "서명이 필요한 게시 bundle이 없어요.".to_string()
+    } else {
+        let message = format!(
+            "서명이 없던 게시 bundle {}개에 {} 키로 서명했어요.",
+            signed_bundles.len(),
+            signing_key.key_id
+        );
+        append_signing_key_audit_record(
+            base_dir,
+            "preset-bundles-resigned",
+            "서명이 없던 게시 preset bundle에 서명했어요.",
+            format!(
+                "{} (요청: {})",
+                signed_bundles
+                    .iter()
+                    .map(|bundle| format!("{}@{}", bundle.preset_id, bundle.published_version))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                input.actor_label
+            ),
+            &input.actor_id,
+            &occurred_at,
+        );
+        message
+    };
+
+    Ok(PresetBundleResignResultDto {
+        schema_version: PRESET_BUNDLE_RESIGN_RESULT_SCHEMA_VERSION.into(),
+        key_id: signing_key.key_id,
+        signed_bundles,
+        message,
+    })
+}
+
+/// Valid published bundles with no signature file, skipping seeded defaults.
+fn list_unsigned_published_bundles(
+    base_dir: &Path,
+) -> Result<Vec<(PathBuf, PresetBundleRefDto)>, HostErrorEnvelope> {
+    let catalog_root = resolve_published_preset_catalog_dir(base_dir);
+    let sorted_dirs = |dir: &Path| -> Result<Vec<PathBuf>, HostErrorEnvelope> {
+        let mut dirs = fs::read_dir(dir)
+            .map_err(map_fs_error)?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect::<Vec<_>>();
+        dirs.sort();
+        Ok(dirs)
+    };
+    if !catalog_root.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut bundles = Vec::new();
+    for preset_dir in sorted_dirs(&catalog_root)? {
+        for bundle_dir in sorted_dirs(&preset_dir)? {
+            if bundle_dir.join(PRESET_BUNDLE_SIGNATURE_FILE_NAME).exists() {
+                continue;
+            }
+            let Some(summary) = load_published_preset_summary(&bundle_dir) else {
+                continue;
+            };
+            let folder_name = |path: &Path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map(str::to_string)
+            };
+            let in_place = folder_name(&bundle_dir).as_ref() == Some(&summary.published_version)
+                && folder_name(&preset_dir).as_ref() == Some(&summary.preset_id);
+            if !in_place
+                || default_preset_bundle_files(&summary.preset_id, &summary.published_version)
+                    .is_some()
+            {
+                continue;
+            }
+            bundles.push((
+                bundle_dir,
+                PresetBundleRefDto {
+                    preset_id: summary.preset_id,
+                    published_version: summary.published_version,
+                },
+            ));
+        }
+    }
+
+    Ok(bundles)
+}
+
+/// Adds and activates a new branch key. The private key goes to `key_dir`;
+/// the booth data directory only records the public key.
+fn create_branch_signing_key(
+    base_dir: &Path,
+    key_dir: &Path,
+    store: &mut PresetSigningKeyStore,
+    actor_id: &str,
+    occurred_at: &str,
+) -> Result<PresetSigningKey, HostErrorEnvelope> {
+    let seed = generate_seed()?;
+    let public_key = encode_hex(&ed25519_public_key(&seed));
+    let branch_label = read_branch_profile(base_dir)
+        .and_then(|profile| profile.branch_id)
+        .unwrap_or_else(|| "booth".into());
+    let key_id = format!("{branch_label}-{}", &public_key[..8]);
+
+    write_private_key(key_dir, &key_id, &seed)?;
+    store.keys.push(PresetSigningKeyRecord {
+        key_id: key_id.clone(),
+        public_key,
+        origin: BRANCH_KEY_ORIGIN.into(),
+        added_at: occurred_at.into(),
+        actor_id: actor_id.into(),
+        revoked_at: None,
+    });
+    store.active_key_id = Some(key_id.clone());
+    persist_preset_signing_key_store(base_dir, store)?;
+
+    append_signing_key_audit_record(
+        base_dir,
+        "preset-signing-key-generated",
+        "새 preset 서명 키를 만들었어요.",
+        format!("{key_id} 키로 새 게시 bundle에 서명해요."),
+        actor_id,
+        occurred_at,
+    );
+
+    Ok(PresetSigningKey { key_id, seed })
+}
+
+/// The active key with its private half from `key_dir`. A private key that is
+/// missing or does not match the recorded public key is an error rather than
+/// a reason to mint a new key.
+fn active_signing_key(
+    key_dir: &Path,
+    store: &PresetSigningKeyStore,
+) -> Result<Option<PresetSigningKey>, HostErrorEnvelope> {
+    let Some(record) = store.active_key_id.as_deref().and_then(|active_key_id| {
+        store
+            .keys
+            .iter()
+            .find(|key| key.key_id == active_key_id && key.revoked_at.is_none())
+    }) else {
+        return Ok(None);
+    };
+    let seed = fs::read_to_string(resolve_private_key_path(key_dir, &record.key_id))
+        .ok()
+        .and_then(|contents| decode_hex::<32>(contents.trim()))
+        .filter(|seed| encode_hex(&ed25519_public_key(seed)) == record.public_key)
+        .ok_or_else(|| {
+            HostErrorEnvelope::validation_message(format!(
+                "{} 키의 개인 키를 서명 키 보관 폴더에서 찾지 못했어요.",
+                record.key_id
+            ))
+        })?;
+
+    Ok(Some(PresetSigningKey {
+        key_id: record.key_id.clone(),
+        seed,
+    }))
+}
+
+/// The folder named by `BOOTHY_PRESET_SIGNING_KEY_DIR`. It must not sit inside
+/// `base_dir`, so booth data exports and copies never carry private keys.
+fn resolve_preset_private_key_dir(base_dir: &Path) -> Result<PathBuf, HostErrorEnvelope> {
+    let key_dir = match env::var(PRESET_SIGNING_KEY_DIR_ENV) {
+        Ok(dir) if !dir.trim().is_empty() => PathBuf::from(dir.trim()),
+        _ => {
+            return Err(HostErrorEnvelope::validation_message(
+                "preset 서명 키 보관 폴더가 설정되지 않아 서명할 수 없어요.",
+            ))
+        }
+    };
+    let canonical = |path: &Path| fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    if canonical(&key_dir).starts_with(canonical(base_dir)) {
+        return Err(HostErrorEnvelope::validation_message(
+            "preset 서명 키 보관 폴더는 부스 데이터 폴더 밖에 있어야 해요.",
+        ));
+    }
+
+    Ok(key_dir)
+}
+
+fn resolve_private_key_path(key_dir: &Path, key_id: &str) -> PathBuf {
+    key_dir.join(format!("{key_id}.key"))
+}
+
+/// Never overwrites an existing private key.
+fn write_private_key(
+    key_dir: &Path,
+    key_id: &str,
+    seed: &[u8; 32],
+) -> Result<(), HostErrorEnvelope> {
+    fs::create_dir_all(key_dir).map_err(map_fs_error)?;
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(resolve_private_key_path(key_dir, key_id))
+        .and_then(|mut file| {
+            file.write_all(encode_hex(seed).as_bytes())?;
+            file.sync_all()
+        })
+        .map_err(|error| {
+            HostErrorEnvelope::persistence(format!(
+                "{key_id} 개인 키를 보관 폴더에 저장하지 못했어요: {error}"
+            ))
+        })
+}
+
+fn build_result(base_dir: &Path, store: &PresetSigningKeyStore) -> PresetSigningKeysResultDto {
+    PresetSigningKeysResultDto {
+        schema_version: PRESET_SIGNING_KEYS_RESULT_SCHEMA_VERSION.into(),
+        branch_id: read_branch_profile(base_dir).and_then(|profile| profile.branch_id),
+        active_key_id: store.active_key_id.clone(),
+        keys: store
+            .keys
+            .iter()
+            .map(|key| PresetSigningKeyDto {
+                key_id: key.key_id.clone(),
+                public_key: key.public_key.clone(),
+                origin: key.origin.clone(),
+                added_at: key.added_at.clone(),
+                actor_id: key.actor_id.clone(),
+                revoked_at: key.revoked_at.clone(),
+                active: store.active_key_id.as_deref() == Some(key.key_id.as_str()),
+            })
+            .collect(),
+    }
+}
+
+fn read_preset_signing_key_store(
+    base_dir: &Path,
+) -> Result<PresetSigningKeyStore, HostErrorEnvelope> {
+    let contents = match fs::read_to_string(resolve_preset_signing_keys_path(base_dir)) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(PresetSigningKeyStore {
+                schema_version: PRESET_SIGNING_KEYS_SCHEMA_VERSION.into(),
+                active_key_id: None,
+                keys: Vec::new(),
+            })
+        }
+        Err(error) => return Err(map_fs_error(error)),
+    };
+    let store = serde_json::from_str::<PresetSigningKeyStore>(&contents)
+        .ok()
+        .filter(|store| store.schema_version == PRESET_SIGNING_KEYS_SCHEMA_VERSION)
+        .ok_or_else(|| HostErrorEnvelope::persistence("preset 서명 키 파일이 손상되었어요."))?;
+
+    Ok(store)
+}
+
+fn persist_preset_signing_key_store(
+    base_dir: &Path,
+    store: &PresetSigningKeyStore,
+) -> Result<(), HostErrorEnvelope> {
+    let path = resolve_preset_signing_keys_path(base_dir);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(map_fs_error)?;
+    }
+    let bytes = serde_json::to_vec_pretty(store).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("preset 서명 키를 직렬화하지 못했어요: {error}"))
+    })?;
+
+    write_json_bytes_atomically(&path, &bytes)
+}
+
+fn append_signing_key_audit_record(
+    base_dir: &Path,
+    event_type: &'static str,
+    summary: &str,
+    detail: String,
+    actor_id: &str,
+    occurred_at: &str,
+) {
+    try_append_operator_audit_record(
+        base_dir,
+        OperatorAuditRecordInput {
+            occurred_at: occurred_at.into(),
+            session_id: None,
+            event_category: "release-governance",
+            event_type,
+            summary: summary.into(),
+            detail,
+            actor_id: Some(actor_id.into()),
+            source: "branch-config",
+            capture_id: None,
+            preset_id: None,
+            published_version: None,
+            reason_code: None,
+        },
+    );
+}
@@ -0,0 +1,494 @@
+//! Staged rollouts: a canary wave first, then cumulative percentage waves of
+//! the remaining branches. Evaluation runs on the branch health schedule and
+//! on demand; it promotes a wave only after its soak window passes, the
+//! health reports of its branches cover enough sessions, and the failures in
+//! those reports stay under the plan's thresholds. Otherwise the plan pauses
+//! and waits for a rollback.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    contracts::dto::{
+        validate_branch_rollout_plan_input, validate_branch_rollout_plan_rollback_input,
+        BranchReleaseBaselineDto, BranchRolloutActionResultDto, BranchRolloutApprovalDto,
+        BranchRolloutHealthSignalsDto, BranchRolloutOverviewResultDto, BranchRolloutPlanDto,
+        BranchRolloutPlanInputDto, BranchRolloutPlanResultDto, BranchRolloutPlanRollbackInputDto,
+        BranchRolloutWaveDto, CapabilitySnapshotDto, HostErrorEnvelope,
+    },
+    session::session_manifest::{current_timestamp, rfc3339_to_unix_seconds},
+};
+
+use super::{
+    acquire_branch_config_lock, apply_action,
+    branch_health::{pull_branch_health_reports, read_branch_health_entries},
+    ensure_settings_access,
+    fleet_store::{resolve_fleet_release_store, FleetReleaseStore},
+    load_branch_rollout_overview_in_dir, load_branch_rollout_store,
+    load_synced_branch_rollout_store, map_fs_error, write_json_bytes_atomically,
+};
+
+const BRANCH_ROLLOUT_PLAN_STORE_SCHEMA_VERSION: &str = "branch-rollout-plan-store/v1";
+const BRANCH_ROLLOUT_PLAN_SCHEMA_VERSION: &str = "branch-rollout-plan/v1";
+const BRANCH_ROLLOUT_PLAN_RESULT_SCHEMA_VERSION: &str = "branch-rollout-plan-result/v1";
+const DEFAULT_SOAK_MINUTES: u32 = 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BranchRolloutPlanStore {
+    schema_version: String,
+    #[serde(default)]
+    plans: Vec<BranchRolloutPlanDto>,
+}
+
+pub fn start_branch_rollout_plan_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: BranchRolloutPlanInputDto,
+) -> Result<BranchRolloutPlanResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    validate_branch_rollout_plan_input(&input)?;
+    let _lock = acquire_branch_config_lock(resolve_branch_rollout_plan_lock_path(base_dir))?;
+
+    let rollout_store = load_branch_rollout_store(base_dir)?;
+    let baseline_is_approved = rollout_store.approved_baselines.iter().any(|baseline| {
+        baseline.build_version == input.target_build_version
+            && baseline.preset_stack_version == input.target_preset_stack_version
+    });
+    if !baseline_is_approved {
+        return Err(HostErrorEnvelope::validation_message(
+            "승인된 release baseline만 단계별로 배포할 수 있어요.",
+        ));
+    }
+
+    let mut plans = load_branch_rollout_plans(base_dir)?;
+    let overlaps_open_plan = plans.iter().filter(|plan| is_open(plan)).any(|plan| {
+        plan.waves.iter().any(|wave| {
+            wave.branch_ids.iter().any(|branch_id| {
+                input.canary_branch_ids.contains(branch_id) || input.branch_ids.contains(branch_id)
+            })
+        })
+    });
+    if overlaps_open_plan {
+        return Err(HostErrorEnvelope::validation_message(
+            "진행 중인 단계별 배포에 이미 포함된 지점이 있어요.",
+        ));
+    }
+
+    let now = current_timestamp(SystemTime::now())?;
+    let approval = BranchRolloutApprovalDto {
+        approved_at: now.clone(),
+        actor_id: input.actor_id.clone(),
+        actor_label: input.actor_label.clone(),
+    };
+    let mut plan = BranchRolloutPlanDto {
+        schema_version: BRANCH_ROLLOUT_PLAN_SCHEMA_VERSION.into(),
+        plan_id: build_plan_id(&now, plans.len()),
+        target_baseline: BranchReleaseBaselineDto {
+            build_version: input.target_build_version.clone(),
+            preset_stack_version: input.target_preset_stack_version.clone(),
+            approved_at: now.clone(),
+            actor_id: input.actor_id.clone(),
+            actor_label: input.actor_label.clone(),
+        },
+        approval,
+        status: "in-progress".into(),
+        soak_minutes: input.soak_minutes.unwrap_or(DEFAULT_SOAK_MINUTES),
+        health_thresholds: input.health_thresholds.clone().unwrap_or_default(),
+        waves: build_waves(&input),
+        pause_reason: None,
+        created_at: now.clone(),
+        updated_at: now,
+    };
+
+    let action_result = start_wave(base_dir, &mut plan, 0)?;
+    plans.push(plan.clone());
+    persist_branch_rollout_plans(base_dir, &plans)?;
+
+    Ok(BranchRolloutPlanResultDto {
+        schema_version: BRANCH_ROLLOUT_PLAN_RESULT_SCHEMA_VERSION.into(),
+        message: if plan.status == "paused" {
+            "canary 지점에 적용하지 못해 단계별 배포를 멈췄어요.".into()
+        } else {
+            "canary 지점부터 단계별 배포를 시작했어요.".into()
+        },
+        plan,
+        action_result: Some(action_result),
+    })
+}
+
+pub fn rollback_branch_rollout_plan_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: BranchRolloutPlanRollbackInputDto,
+) -> Result<BranchRolloutPlanResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    validate_branch_rollout_plan_rollback_input(&input)?;
+    let _lock = acquire_branch_config_lock(resolve_branch_rollout_plan_lock_path(base_dir))?;
+
+    let mut plans = load_branch_rollout_plans(base_dir)?;
+    let Some(plan) = plans.iter_mut().find(|plan| plan.plan_id == input.plan_id) else {
+        return Err(HostErrorEnvelope::validation_message(
+            "롤백할 단계별 배포를 찾지 못했어요.",
+        ));
+    };
+    if plan.status == "rolled-back" {
+        return Err(HostErrorEnvelope::validation_message(
+            "이미 롤백한 단계별 배포예요.",
+        ));
+    }
+
+    // Branches whose rollout was rejected never left their baseline, so
+    // rolling them back would step past it.
+    let branch_ids = plan
+        .waves
+        .iter()
+        .flat_map(|wave| &wave.outcomes)
+        .filter(|outcome| outcome.result != "rejected")
+        .map(|outcome| outcome.branch_id.clone())
+        .collect::<Vec<_>>();
+    let now = current_timestamp(SystemTime::now())?;
+    let action_result = if branch_ids.is_empty() {
+        None
+    } else {
+        Some(apply_action(
+            base_dir,
+            "rollback",
+            &branch_ids,
+            None,
+            BranchRolloutApprovalDto {
+                approved_at: now.clone(),
+                actor_id: input.actor_id.clone(),
+                actor_label: input.actor_label.clone(),
+            },
+        )?)
+    };
+
+    for wave in &mut plan.waves {
+        if wave.started_at.is_some() {
+            wave.status = "rolled-back".into();
+            wave.completed_at = Some(now.clone());
+        }
+    }
+    plan.status = "rolled-back".into();
+    plan.updated_at = now;
+    let plan = plan.clone();
+    persist_branch_rollout_plans(base_dir, &plans)?;
+
+    Ok(BranchRolloutPlanResultDto {
+        schema_version: BRANCH_ROLLOUT_PLAN_RESULT_SCHEMA_VERSION.into(),
+        plan,
+        action_result,
+        message: "단계별 배포를 적용한 지점을 이전 baseline으로 되돌렸어요.".into(),
+    })
+}
+
+/// Manual trigger for the scheduled evaluation. Returns the refreshed
+/// overview; loading the overview never promotes on its own.
+pub fn evaluate_branch_rollout_plans_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+) -> Result<BranchRolloutOverviewResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    let store = resolve_fleet_release_store(base_dir)?;
+
+    evaluate_branch_rollout_plans_with_store(base_dir, capability_snapshot, store.as_ref())
+}
+
+pub fn evaluate_branch_rollout_plans_with_store(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    store: &dyn FleetReleaseStore,
+) -> Result<BranchRolloutOverviewResultDto, HostErrorEnvelope> {
+    ensure_settings_access(capability_snapshot)?;
+    advance_branch_rollout_plans_with_store(base_dir, store)?;
+
+    load_branch_rollout_overview_in_dir(base_dir, capability_snapshot)
+}
+
+/// Pulls the health reports of every open wave's branches from `store`, then
+/// promotes or pauses the current wave of each in-progress plan.
+pub(crate) fn advance_branch_rollout_plans_with_store(
+    base_dir: &Path,
+    store: &dyn FleetReleaseStore,
+) -> Result<(), HostErrorEnvelope> {
+    let open_branch_ids = load_branch_rollout_plans(base_dir)?
+        .iter()
+        .filter(|plan| plan.status == "in-progress")
+        .flat_map(|plan| &plan.waves)
+        .filter(|wave| wave.status == "in-progress")
+        .flat_map(|wave| wave.branch_ids.clone())
+        .collect::<Vec<_>>();
+    if open_branch_ids.is_empty() {
+        return Ok(());
+    }
+    pull_branch_health_reports(base_dir, store, &open_branch_ids)?;
+
+    advance_branch_rollout_plans(base_dir)
+}
+
+fn advance_branch_rollout_plans(base_dir: &Path) -> Result<(), HostErrorEnvelope> {
+    let _lock = acquire_branch_config_lock(resolve_branch_rollout_plan_lock_path(base_dir))?;
+    let mut plans = load_branch_rollout_plans(base_dir)?;
+    if !plans.iter().any(|plan| plan.status == "in-progress") {
+        return Ok(());
+    }
+
+    let rollout_store = load_synced_branch_rollout_store(base_dir)?;
+    let now = SystemTime::now();
+    let now_at = current_timestamp(now)?;
+    let now_seconds = rfc3339_to_unix_seconds(&now_at)?;
+
+    for plan in plans.iter_mut().filter(|plan| plan.status == "in-progress") {
+        let Some(wave_index) = plan
+            .waves
+            .iter()
+            .position(|wave| wave.status == "in-progress")
+        else {
+            continue;
+        };
+        let wave = &mut plan.waves[wave_index];
+        let started_seconds = wave
+            .started_at
+            .as_deref()
+            .map(rfc3339_to_unix_seconds)
+            .transpose()?
+            .unwrap_or(now_seconds);
+
+        let health = collect_health_signals(base_dir, &wave.branch_ids, started_seconds, &now_at);
+
+        let thresholds = &plan.health_thresholds;
+        let enough_sessions =
+            health.observed_session_ids.len() >= thresholds.min_observed_sessions as usize;
+        let breach = if health.critical_failures > thresholds.max_critical_failures {
+            Some("critical-failure")
+        } else if health.render_failures > thresholds.max_render_failures {
+            Some("render-failure")
+        } else if health.capture_timeouts > thresholds.max_capture_timeouts {
+            Some("capture-timeout")
+        } else {
+            None
+        };
+        wave.health = Some(health);
+        plan.updated_at = now_at.clone();
+
+        if let Some(breach) = breach {
+            wave.status = "paused".into();
+            plan.status = "paused".into();
+            plan.pause_reason = Some(format!(
+                "{} 단계에서 {breach} 기준을 넘어 단계별 배포를 멈췄어요.",
+                wave.label
+            ));
+            continue;
+        }
+
+        let soak_elapsed = now_seconds >= started_seconds + u64::from(plan.soak_minutes) * 60;
+        let target = &plan.target_baseline;
+        let wave_reached_target = wave.branch_ids.iter().all(|branch_id| {
+            rollout_store.branches.iter().any(|branch| {
+                branch.branch_id == *branch_id
+                    && branch.deployment_baseline.build_version == target.build_version
+                    && branch.deployment_baseline.preset_stack_version
+                        == target.preset_stack_version
+            })
+        });
+        if !soak_elapsed || !wave_reached_target || !enough_sessions {
+            continue;
+        }
+
+        wave.status = "promoted".into();
+        wave.completed_at = Some(now_at.clone());
+        if wave_index + 1 < plan.waves.len() {
+            start_wave(base_dir, plan, wave_index + 1)?;
+        } else {
+            plan.status = "completed".into();
+        }
+    }
+
+    persist_branch_rollout_plans(base_dir, &plans)
+}
+
+pub(crate) fn load_branch_rollout_plans(
+    base_dir: &Path,
+) -> Result<Vec<BranchRolloutPlanDto>, HostErrorEnvelope> {
+    let plan_path = resolve_branch_rollout_plan_path(base_dir);
+    let backup_path = plan_path.with_extension("json.bak");
+    let read_path = if plan_path.exists() {
+        plan_path
+    } else if backup_path.is_file() {
+        backup_path
+    } else {
+        return Ok(Vec::new());
+    };
+
+    let bytes = fs::read_to_string(read_path).map_err(map_fs_error)?;
+    let store = serde_json::from_str::<BranchRolloutPlanStore>(&bytes).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("단계별 배포 계획을 읽지 못했어요: {error}"))
+    })?;
+
+    Ok(store.plans)
+}
+
+fn persist_branch_rollout_plans(
+    base_dir: &Path,
+    plans: &[BranchRolloutPlanDto],
+) -> Result<(), HostErrorEnvelope> {
+    let plan_path = resolve_branch_rollout_plan_path(base_dir);
+    if let Some(plan_dir) = plan_path.parent() {
+        fs::create_dir_all(plan_dir).map_err(map_fs_error)?;
+    }
+    let bytes = serde_json::to_vec_pretty(&BranchRolloutPlanStore {
+        schema_version: BRANCH_ROLLOUT_PLAN_STORE_SCHEMA_VERSION.into(),
+        plans: plans.to_vec(),
+    })
+    .map_err(|error| {
+        HostErrorEnvelope::persistence(format!("단계별 배포 계획을 직렬화하지 못했어요: {error}"))
+    })?;
+
+    write_json_bytes_atomically(&plan_path, &bytes)
+}
+
+fn start_wave(
+    base_dir: &Path,
+    plan: &mut BranchRolloutPlanDto,
+    wave_index: usize,
+) -> Result<BranchRolloutActionResultDto, HostErrorEnvelope> {
+    let now = current_timestamp(SystemTime::now())?;
+    let branch_ids = plan.waves[wave_index].branch_ids.clone();
+    let action_result = apply_action(
+        base_dir,
+        "rollout",
+        &branch_ids,
+        Some(plan.target_baseline.clone()),
+        plan.approval.clone(),
+    )?;
+
+    let wave = &mut plan.waves[wave_index];
+    wave.started_at = Some(now.clone());
+    wave.outcomes = action_result.outcomes.clone();
+    let rejected = wave
+        .outcomes
+        .iter()
+        .filter(|outcome| outcome.result == "rejected")
+        .map(|outcome| outcome.display_name.as_str())
+        .collect::<Vec<_>>();
+    if rejected.is_empty() {
+        wave.status = "in-progress".into();
+    } else {
+        wave.status = "paused".into();
+        plan.pause_reason = Some(format!(
+            "{} 단계에서 {} 지점에 적용하지 못해 단계별 배포를 멈췄어요.",
+            wave.label,
+            rejected.join(", ")
+        ));
+        plan.status = "paused".into();
+    }
+    plan.updated_at = now;
+
+    Ok(action_result)
+}
+
+/// Tallies every entry the wave's branches reported since `since_seconds`,
+/// whichever session it belongs to.
+fn collect_health_signals(
+    base_dir: &Path,
+    branch_ids: &[String],
+    since_seconds: u64,
+    evaluated_at: &str,
+) -> BranchRolloutHealthSignalsDto {
+    let mut health = BranchRolloutHealthSignalsDto {
+        evaluated_at: evaluated_at.into(),
+        ..Default::default()
+    };
+
+    for entry in read_branch_health_entries(base_dir, branch_ids) {
+        let in_window = rfc3339_to_unix_seconds(&entry.occurred_at)
+            .is_ok_and(|seconds| seconds >= since_seconds);
+        if !in_window {
+            continue;
+        }
+        if let Some(session_id) = entry.session_id {
+            if !health.observed_session_ids.contains(&session_id) {
+                health.observed_session_ids.push(session_id);
+            }
+        }
+        if entry.event_category != "critical-failure" {
+            continue;
+        }
+
+        match entry.reason_code.as_deref() {
+            Some("render-failed") => health.render_failures += 1,
+            Some("capture-timeout") => health.capture_timeouts += 1,
+            _ => health.critical_failures += 1,
+        }
+    }
+
+    health
+}
+
+/// Wave 0 is the canary; each later wave adds branches up to its cumulative
+/// share of `branch_ids`, and waves that would add nothing are dropped.
+fn build_waves(input: &BranchRolloutPlanInputDto) -> Vec<BranchRolloutWaveDto> {
+    let pending_wave = |label: String, branch_ids: Vec<String>| BranchRolloutWaveDto {
+        wave_index: 0,
+        label,
+        branch_ids,
+        status: "pending".into(),
+        started_at: None,
+        completed_at: None,
+        health: None,
+        outcomes: Vec::new(),
+    };
+    let mut waves = vec![pending_wave(
+        "canary".into(),
+        input.canary_branch_ids.clone(),
+    )];
+    let total = input.branch_ids.len();
+    let mut assigned = 0;
+
+    for percentage in &input.wave_percentages {
+        let cumulative = (total * *percentage as usize).div_ceil(100);
+        if cumulative > assigned {
+            waves.push(pending_wave(
+                format!("{percentage}%"),
+                input.branch_ids[assigned..cumulative].to_vec(),
+            ));
+            assigned = cumulative;
+        }
+    }
+
+    for (index, wave) in waves.iter_mut().enumerate() {
+        wave.wave_index = index as u32;
+    }
+
+    waves
+}
+
+fn is_open(plan: &BranchRolloutPlanDto) -> bool {
+    plan.status == "in-progress" || plan.status == "paused"
+}
+
+fn build_plan_id(created_at: &str, existing_plans: usize) -> String {
+    let safe_timestamp = created_at
+        .chars()
+        .filter(|character| character.is_ascii_alphanumeric())
+        .collect::<String>();
+
+    format!(
+        "rollout-plan-{}-{existing_plans:04}",
+        &safe_timestamp[..safe_timestamp.len().min(14)]
+    )
+}
+
+fn resolve_branch_rollout_plan_path(base_dir: &Path) -> PathBuf {
+    base_dir.join("branch-config").join("rollout-plans.json")
+}
+
+fn resolve_branch_rollout_plan_lock_path(base_dir: &Path) -> PathBuf {
+    base_dir.join("branch-config").join("rollout-plans.lock")
+}
@@ -0,0 +1,178 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    contracts::dto::HostErrorEnvelope,
+    session::session_manifest::{DEFAULT_SESSION_DURATION_SECONDS, WARNING_LEAD_SECONDS},
+};
+
+pub const SESSION_PACKAGE_CONFIG_SCHEMA_VERSION: &str = "session-package-config/v1";
+pub const DEFAULT_SESSION_PACKAGE_ID: &str = "standard";
+
+const MAX_SESSION_DURATION_MINUTES: u32 = 240;
+const MAX_EXTENSION_MINUTES: u32 = 120;
+const DEFAULT_MAX_EXTENSION_MINUTES: u32 = 5;
+const MAX_PACKAGE_ID_LENGTH: usize = 40;
+const MAX_PACKAGE_DISPLAY_NAME_LENGTH: usize = 40;
+const MAX_SELECTED_CAPTURES: u32 = 99;
+
+/// A sellable session length. The chosen tier is copied into `SessionTiming`
+/// so later audits see what the customer bought, even if the branch edits it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionPackageTier {
+    pub package_id: String,
+    pub display_name: String,
+    pub duration_minutes: u32,
+    pub warning_lead_minutes: u32,
+    #[serde(default)]
+    pub max_extension_minutes: u32,
+    /// Cap on how many captures the customer may select for delivery.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_selected_captures: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionPackageConfig {
+    pub schema_version: String,
+    #[serde(default)]
+    pub default_package_id: Option<String>,
+    #[serde(default)]
+    pub packages: Vec<SessionPackageTier>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionPackageCatalogDto {
+    pub schema_version: String,
+    pub default_package_id: String,
+    pub packages: Vec<SessionPackageTier>,
+}
+
+pub fn default_session_package() -> SessionPackageTier {
+    SessionPackageTier {
+        package_id: DEFAULT_SESSION_PACKAGE_ID.into(),
+        display_name: "기본 촬영".into(),
+        duration_minutes: (DEFAULT_SESSION_DURATION_SECONDS / 60) as u32,
+        warning_lead_minutes: (WARNING_LEAD_SECONDS / 60) as u32,
+        max_extension_minutes: DEFAULT_MAX_EXTENSION_MINUTES,
+        max_selected_captures: None,
+    }
+}
+
+/// Merges `branch-config/session-packages.json` over the built-in tier. Invalid
+/// tiers are skipped so one typo does not stop the booth from starting sessions.
+pub fn load_session_packages_in_dir(
+    base_dir: &Path,
+) -> Result<SessionPackageCatalogDto, HostErrorEnvelope> {
+    let mut packages = vec![default_session_package()];
+    let config_path = resolve_session_package_config_path(base_dir);
+
+    if !config_path.is_file() {
+        return Ok(build_catalog(packages, None));
+    }
+
+    let bytes = fs::read_to_string(&config_path).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("이용권 설정을 읽지 못했어요: {error}"))
+    })?;
+    let config: SessionPackageConfig = serde_json::from_str(&bytes).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("이용권 설정을 읽지 못했어요: {error}"))
+    })?;
+
+    for package in config.packages {
+        if let Err(reason) = validate_session_package(&package) {
+            log::warn!(
+                "session_package_skipped package_id={} reason={reason}",
+                package.package_id
+            );
+            continue;
+        }
+
+        match packages
+            .iter_mut()
+            .find(|existing| existing.package_id == package.package_id)
+        {
+            Some(existing) => *existing = package,
+            None => packages.push(package),
+        }
+    }
+
+    Ok(build_catalog(packages, config.default_package_id))
+}
+
+/// Picks the requested tier, or the branch default when the booth did not ask
+/// for one.
+pub fn resolve_session_package_in_dir(
+    base_dir: &Path,
+    package_id: Option<&str>,
+) -> Result<SessionPackageTier, HostErrorEnvelope> {
+    let catalog = load_session_packages_in_dir(base_dir)?;
+    let package_id = package_id.unwrap_or(catalog.default_package_id.as_str());
+
+    catalog
+        .packages
+        .into_iter()
+        .find(|package| package.package_id == package_id)
+        .ok_or_else(|| HostErrorEnvelope::validation_message("선택한 이용권을 찾지 못했어요."))
+}
+
+pub fn resolve_session_package_config_path(base_dir: &Path) -> PathBuf {
+    base_dir.join("branch-config").join("session-packages.json")
+}
+
+fn build_catalog(
+    packages: Vec<SessionPackageTier>,
+    default_package_id: Option<String>,
+) -> SessionPackageCatalogDto {
+    let default_package_id = default_package_id
+        .filter(|package_id| {
+            packages
+                .iter()
+                .any(|package| &package.package_id == package_id)
+        })
+        .unwrap_or_else(|| DEFAULT_SESSION_PACKAGE_ID.into());
+
+    SessionPackageCatalogDto {
+        schema_version: SESSION_PACKAGE_CONFIG_SCHEMA_VERSION.into(),
+        default_package_id,
+        packages,
+    }
+}
+
+fn validate_session_package(package: &SessionPackageTier) -> Result<(), &'static str> {
+    if package.package_id.is_empty()
+        || package.package_id.len() > MAX_PACKAGE_ID_LENGTH
+        || !package
+            .package_id
+            .bytes()
+            .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-')
+    {
+        return Err("invalid-package-id");
+    }
+    let display_name = package.display_name.trim();
+    if display_name.is_empty() || display_name.chars().count() > MAX_PACKAGE_DISPLAY_NAME_LENGTH {
+        return Err("invalid-display-name");
+    }
+    if !(1..=MAX_SESSION_DURATION_MINUTES).contains(&package.duration_minutes) {
+        return Err("duration-out-of-range");
+    }
+    if package.warning_lead_minutes >= package.duration_minutes {
+        return Err("warning-lead-exceeds-duration");
+    }
+    if package.max_extension_minutes > MAX_EXTENSION_MINUTES {
+        return Err("extension-out-of-range");
+    }
+    if package
+        .max_selected_captures
+        .is_some_and(|limit| !(1..=MAX_SELECTED_CAPTURES).contains(&limit))
+    {
+        return Err("selection-limit-out-of-range");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! Plain-HTTP client for services on the branch network, such as the booking
+//! service and the HQ release store. Callers share one `ureq` agent and get
+//! the same deadline and response size rules.
+
+use std::{io::Read, sync::OnceLock, time::Duration};
+
+static BRANCH_HTTP_AGENT: OnceLock<ureq::Agent> = OnceLock::new();
+
+pub(crate) struct BranchHttpResponse {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub(crate) enum BranchHttpError {
+    /// Connecting, sending or reading failed, or `timeout` ran out.
+    Transport(String),
+    /// The response body is larger than the caller's limit.
+    TooLarge { limit_bytes: u64 },
+}
+
+/// Sends one request and returns the status and body for every HTTP status.
+/// `timeout` bounds the whole exchange, including writing the request.
+pub(crate) fn send_branch_http_request(
+    method: &str,
+    url: &str,
+    accept: &str,
+    body: Option<&str>,
+    timeout: Duration,
+    max_body_bytes: u64,
+) -> Result<BranchHttpResponse, BranchHttpError> {
+    let request = BRANCH_HTTP_AGENT
+        .get_or_init(|| ureq::AgentBuilder::new().build())
+        .request(method, url)
+        .timeout(timeout)
+        .set("Accept", accept);
+    let sent = match body {
+        Some(body) => request
+            .set("Content-Type", "application/json")
+            .send_string(body),
+        None => request.call(),
+    };
+    let response = match sent {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(error) => return Err(BranchHttpError::Transport(error.to_string())),
+    };
+
+    let status = response.status();
+    let too_large = BranchHttpError::TooLarge {
+        limit_bytes: max_body_bytes,
+    };
+    if response
+        .header("Content-Length")
+        .and_then(|length| length.trim().parse::<u64>().ok())
+        .is_some_and(|length| length > max_body_bytes)
+    {
+        return Err(too_large);
+    }
+    let mut body = Vec::new();
+    response
+        .into_reader()
+        .take(max_body_bytes.saturating_add(1))
+        .read_to_end(&mut body)
+        .map_err(|error| BranchHttpError::Transport(error.to_string()))?;
+    if body.len() as u64 > max_body_bytes {
+        return Err(too_large);
+    }
+
+    Ok(BranchHttpResponse { status, body })
+}
@@ -0,0 +1,240 @@
+use std::{env, path::Path};
+
+use crate::capture::{
+    helper_supervisor::{shutdown_helper_process, try_ensure_helper_running},
+    hot_folder_backend::{HOT_FOLDER_CAMERA_BACKEND, HOT_FOLDER_CAMERA_BACKEND_ID},
+    sidecar_client::{
+        is_retryable_capture_helper_error, read_capture_event_count, read_capture_request_messages,
+        read_latest_helper_error_message, read_latest_status_message,
+        read_processed_capture_request_ids, wait_for_capture_round_trip,
+        write_capture_request_message, CanonHelperCaptureRequestMessage, CompletedCaptureRoundTrip,
+        SidecarClientError, CANON_HELPER_CAPTURE_REQUEST_SCHEMA_VERSION,
+    },
+};
+
+pub const CAMERA_BACKEND_ENV: &str = "BOOTHY_CAMERA_BACKEND";
+pub const CANON_HELPER_CAMERA_BACKEND_ID: &str = "canon-helper";
+
+/// Latest camera/helper health as reported by the active backend.
+#[derive(Debug, Clone)]
+pub struct CameraStatusSnapshot {
+    pub session_id: String,
+    pub observed_at: String,
+    pub camera_state: String,
+    pub helper_state: String,
+    pub sequence: Option<u64>,
+    pub detail_code: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CameraCaptureRequest {
+    pub session_id: String,
+    pub request_id: String,
+    pub requested_at: String,
+    pub active_preset_id: String,
+    pub active_preset_version: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CameraFastPreviewReady {
+    pub capture_id: String,
+    pub fast_preview_path: String,
+    pub fast_preview_kind: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CameraBackendFault {
+    pub detail_code: String,
+    pub message: Option<String>,
+    pub retryable: bool,
+}
+
+/// Host-side contract for a camera body. The capture pipeline only talks to the
+/// camera through this trait, so every backend shares the same readiness, audit
+/// and persistence rules. Failures use the `SidecarClientError` vocabulary.
+pub trait CameraBackend: Send + Sync {
+    fn backend_id(&self) -> &'static str;
+
+    /// Value reported as `liveCaptureTruth.source` to the booth UI.
+    fn live_truth_source(&self) -> &'static str;
+
+    fn ensure_running(&self, base_dir: &Path, session_id: &str);
+
+    fn shutdown(&self) {}
+
+    fn read_status(
+        &self,
+        base_dir: &Path,
+        session_id: &str,
+    ) -> Result<Option<CameraStatusSnapshot>, SidecarClientError>;
+
+    fn is_capture_request_known(
+        &self,
+        base_dir: &Path,
+        session_id: &str,
+        request_id: &str,
+    ) -> Result<bool, SidecarClientError>;
+
+    /// Position in the backend event stream that `wait_for_capture` should read after.
+    fn capture_event_cursor(
+        &self,
+        base_dir: &Path,
+        session_id: &str,
+    ) -> Result<usize, SidecarClientError>;
+
+    fn submit_capture_request(
+        &self,
+        base_dir: &Path,
+        request: &CameraCaptureRequest,
+    ) -> Result<(), SidecarClientError>;
+
+    fn wait_for_capture(
+        &self,
+        base_dir: &Path,
+        request: &CameraCaptureRequest,
+        event_cursor: usize,
+        on_fast_preview_ready: &mut dyn FnMut(&CameraFastPreviewReady),
+    ) -> Result<CompletedCaptureRoundTrip, SidecarClientError>;
+
+    fn latest_fault(&self, base_dir: &Path, session_id: &str) -> Option<CameraBackendFault>;
+}
+
+pub struct CanonHelperCameraBackend;
+
+static CANON_HELPER_CAMERA_BACKEND: CanonHelperCameraBackend = CanonHelperCameraBackend;
+
+impl CameraBackend for CanonHelperCameraBackend {
+    fn backend_id(&self) -> &'static str {
+        CANON_HELPER_CAMERA_BACKEND_ID
+    }
+
+    fn live_truth_source(&self) -> &'static str {
+        "canon-helper-sidecar"
+    }
+
+    fn ensure_running(&self, base_dir: &Path, session_id: &str) {
+        try_ensure_helper_running(base_dir, session_id);
+    }
+
+    fn shutdown(&self) {
+        shutdown_helper_process();
+    }
+
+    fn read_status(
+        &self,
+        base_dir: &Path,
+        session_id: &str,
+    ) -> Result<Option<CameraStatusSnapshot>, SidecarClientError> {
+        Ok(
+            read_latest_status_message(base_dir, session_id)?.map(|status| CameraStatusSnapshot {
+                session_id: status.session_id,
+                observed_at: status.observed_at,
+                camera_state: status.camera_state,
+                helper_state: status.helper_state,
+                sequence: status.sequence,
+                detail_code: status.detail_code,
+            }),
+        )
+    }
+
+    fn is_capture_request_known(
+        &self,
+        base_dir: &Path,
+        session_id: &str,
+        request_id: &str,
+    ) -> Result<bool, SidecarClientError> {
+        let existing_requests = read_capture_request_messages(base_dir, session_id)?;
+        let processed_request_ids = read_processed_capture_request_ids(base_dir, session_id)?;
+
+        Ok(existing_requests
+            .iter()
+            .any(|request| request.request_id == request_id)
+            || processed_request_ids
+                .iter()
+                .any(|processed| processed == request_id))
+    }
+
+    fn capture_event_cursor(
+        &self,
+        base_dir: &Path,
+        session_id: &str,
+    ) -> Result<usize, SidecarClientError> {
+        read_capture_event_count(base_dir, session_id)
+    }
+
+    fn submit_capture_request(
+        &self,
+        base_dir: &Path,
+        request: &CameraCaptureRequest,
+    ) -> Result<(), SidecarClientError> {
+        write_capture_request_message(
+            base_dir,
+            &CanonHelperCaptureRequestMessage {
+                schema_version: CANON_HELPER_CAPTURE_REQUEST_SCHEMA_VERSION.into(),
+                message_type: "request-capture".into(),
+                session_id: request.session_id.clone(),
+                request_id: request.request_id.clone(),
+                requested_at: request.requested_at.clone(),
+                active_preset_id: request.active_preset_id.clone(),
+                active_preset_version: request.active_preset_version.clone(),
+            },
+        )
+    }
+
+    fn wait_for_capture(
+        &self,
+        base_dir: &Path,
+        request: &CameraCaptureRequest,
+        event_cursor: usize,
+        on_fast_preview_ready: &mut dyn FnMut(&CameraFastPreviewReady),
+    ) -> Result<CompletedCaptureRoundTrip, SidecarClientError> {
+        wait_for_capture_round_trip(
+            base_dir,
+            &request.session_id,
+            &request.request_id,
+            event_cursor,
+            |message| {
+                on_fast_preview_ready(&CameraFastPreviewReady {
+                    capture_id: message.capture_id.clone(),
+                    fast_preview_path: message.fast_preview_path.clone(),
+                    fast_preview_kind: message.fast_preview_kind.clone(),
+                })
+            },
+        )
+    }
+
+    fn latest_fault(&self, base_dir: &Path, session_id: &str) -> Option<CameraBackendFault> {
+        let message = read_latest_helper_error_message(base_dir, session_id)
+            .ok()
+            .flatten()?;
+
+        Some(CameraBackendFault {
+            retryable: is_retryable_capture_helper_error(&message),
+            detail_code: message.detail_code,
+            message: message.message,
+        })
+    }
+}
+
+/// Resolves the backend for this booth from `BOOTHY_CAMERA_BACKEND`, defaulting
+/// to the Canon helper so existing branches keep their current wiring.
+pub fn resolve_camera_backend() -> &'static dyn CameraBackend {
+    let backend_id = env::var(CAMERA_BACKEND_ENV).unwrap_or_default();
+
+    match backend_id.trim() {
+        "" | CANON_HELPER_CAMERA_BACKEND_ID => &CANON_HELPER_CAMERA_BACKEND,
+        HOT_FOLDER_CAMERA_BACKEND_ID => &HOT_FOLDER_CAMERA_BACKEND,
+        unknown => {
+            log::warn!("camera_backend_unknown backend={unknown} fallback=canon-helper");
+            &CANON_HELPER_CAMERA_BACKEND
+        }
+    }
+}
+
+pub fn try_ensure_camera_backend_running(base_dir: &Path, session_id: &str) {
+    resolve_camera_backend().ensure_running(base_dir, session_id);
+}
+
+pub fn shutdown_camera_backend() {
+    resolve_camera_backend().shutdown();
+}
@@ -0,0 +1,330 @@
+//! Host-timed capture programs: a countdown before a single shot, or a burst
+//! of shots at a fixed interval. The program holds the in-flight capture
+//! guard from the first tick to the last shot, so nothing else can trigger
+//! the camera in between.
+
+use std::{
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    branch_config::operational_toggles::{
+        resolve_booth_operational_toggles_in_dir, CAPTURE_COUNTDOWN_SECONDS,
+    },
+    capture::{
+        normalized_state::{
+            acquire_in_flight_capture_guard, get_capture_readiness_in_dir,
+            normalize_capture_readiness, run_capture_round_trip,
+        },
+        sidecar_client::FastPreviewReadyUpdate,
+    },
+    contracts::dto::{
+        CaptureProgramInputDto, CaptureProgramResultDto, CaptureProgramTickDto,
+        CaptureReadinessInputDto, CaptureRequestInputDto, HostErrorEnvelope,
+    },
+    session::{
+        session_manifest::{
+            rfc3339_to_unix_seconds, CaptureProgramShot, SessionManifest, CAPTURE_BUDGET_MS,
+            CAPTURE_PROGRAM_MODE_BURST, CAPTURE_PROGRAM_MODE_COUNTDOWN,
+        },
+        session_paths::SessionPaths,
+        session_repository::read_session_manifest,
+    },
+    timing::{evaluate_phase, TimingPhase},
+};
+
+const CAPTURE_PROGRAM_RESULT_SCHEMA_VERSION: &str = "capture-program-result/v1";
+const CAPTURE_PROGRAM_TICK_SCHEMA_VERSION: &str = "capture-program-tick/v1";
+const DEFAULT_COUNTDOWN_SECONDS: u32 = 3;
+const MAX_COUNTDOWN_SECONDS: u32 = 10;
+const DEFAULT_BURST_SHOT_COUNT: u32 = 4;
+const MAX_BURST_SHOT_COUNT: u32 = 8;
+const DEFAULT_BURST_INTERVAL_MS: u64 = 1_500;
+const MIN_BURST_INTERVAL_MS: u64 = 500;
+const MAX_BURST_INTERVAL_MS: u64 = 10_000;
+const COUNTDOWN_TICK: Duration = Duration::from_secs(1);
+
+static CAPTURE_PROGRAM_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CaptureProgramPlan {
+    mode: &'static str,
+    countdown_seconds: u32,
+    shot_count: u32,
+    shot_interval_ms: u64,
+}
+
+impl CaptureProgramPlan {
+    /// Time from the first tick until the last shot is due back from the
+    /// helper.
+    fn budget_ms(&self) -> u64 {
+        u64::from(self.countdown_seconds) * 1_000
+            + u64::from(self.shot_count - 1) * self.shot_interval_ms
+            + CAPTURE_BUDGET_MS
+    }
+}
+
+pub fn run_capture_program_in_dir<F, T>(
+    base_dir: &Path,
+    input: CaptureProgramInputDto,
+    mut on_fast_preview_ready: F,
+    mut on_tick: T,
+) -> Result<CaptureProgramResultDto, HostErrorEnvelope>
+where
+    F: FnMut(FastPreviewReadyUpdate),
+    T: FnMut(CaptureProgramTickDto),
+{
+    let default_countdown_seconds = resolve_booth_operational_toggles_in_dir(base_dir)
+        .int_value(CAPTURE_COUNTDOWN_SECONDS)
+        .and_then(|seconds| u32::try_from(seconds).ok())
+        .unwrap_or(DEFAULT_COUNTDOWN_SECONDS);
+    let plan = resolve_capture_program_plan(&input, default_countdown_seconds)?;
+    let paths = SessionPaths::try_new(base_dir, &input.session_id)?;
+    let readiness = get_capture_readiness_in_dir(
+        base_dir,
+        CaptureReadinessInputDto {
+            session_id: input.session_id.clone(),
+        },
+    )?;
+
+    if !readiness.can_capture {
+        return Err(HostErrorEnvelope::capture_not_ready(
+            "지금은 촬영할 수 없어요.",
+            readiness,
+        ));
+    }
+
+    let mut in_flight_guard = acquire_in_flight_capture_guard(base_dir, &input.session_id)?;
+    if !program_fits_session(&read_session_manifest(&paths.manifest_path)?, &plan)? {
+        return Err(HostErrorEnvelope::capture_not_ready(
+            "남은 촬영 시간 안에 끝낼 수 없어요. 한 장씩 찍어 주세요.",
+            readiness,
+        ));
+    }
+
+    let program_id = generate_capture_program_id();
+    log::info!(
+        "capture_program_started session={} program_id={} mode={} countdown_seconds={} shot_count={} interval_ms={}",
+        input.session_id,
+        program_id,
+        plan.mode,
+        plan.countdown_seconds,
+        plan.shot_count,
+        plan.shot_interval_ms
+    );
+    let mut captures = Vec::with_capacity(plan.shot_count as usize);
+    let mut stopped = false;
+
+    for remaining_seconds in (1..=plan.countdown_seconds).rev() {
+        let manifest = read_session_manifest(&paths.manifest_path)?;
+        if !is_capture_allowed_now(&manifest)? {
+            stopped = true;
+            break;
+        }
+        on_tick(build_tick(
+            base_dir,
+            &manifest,
+            &program_id,
+            "countdown",
+            remaining_seconds,
+            1,
+            &plan,
+        ));
+        thread::sleep(COUNTDOWN_TICK);
+    }
+
+    for shot_index in 1..=plan.shot_count {
+        if stopped {
+            break;
+        }
+        let shot_started_at = Instant::now();
+        let manifest = read_session_manifest(&paths.manifest_path)?;
+        if !is_capture_allowed_now(&manifest)? {
+            stopped = true;
+            break;
+        }
+        on_tick(build_tick(
+            base_dir,
+            &manifest,
+            &program_id,
+            "shot",
+            0,
+            shot_index,
+            &plan,
+        ));
+
+        let shot = CaptureProgramShot {
+            program_id: program_id.clone(),
+            mode: plan.mode.into(),
+            shot_index,
+            shot_count: plan.shot_count,
+        };
+        let (guard, _, capture) = run_capture_round_trip(
+            base_dir,
+            &CaptureRequestInputDto {
+                session_id: input.session_id.clone(),
+                request_id: None,
+            },
+            &readiness,
+            in_flight_guard,
+            Some(&shot),
+            &mut on_fast_preview_ready,
+        )
+        .inspect_err(|error| {
+            log::warn!(
+                "capture_program_shot_failed session={} program_id={} shot={}/{} code={}",
+                input.session_id,
+                program_id,
+                shot_index,
+                plan.shot_count,
+                error.code
+            );
+        })?;
+        in_flight_guard = guard;
+        captures.push(capture);
+
+        if shot_index < plan.shot_count {
+            thread::sleep(
+                Duration::from_millis(plan.shot_interval_ms)
+                    .saturating_sub(shot_started_at.elapsed()),
+            );
+        }
+    }
+
+    drop(in_flight_guard);
+    let manifest = read_session_manifest(&paths.manifest_path)?;
+    let readiness = normalize_capture_readiness(base_dir, &manifest);
+    if captures.is_empty() {
+        return Err(HostErrorEnvelope::capture_not_ready(
+            "촬영 시간이 끝나 촬영을 멈췄어요.",
+            readiness,
+        ));
+    }
+
+    log::info!(
+        "capture_program_finished session={} program_id={} shots={}/{} stopped={}",
+        input.session_id,
+        program_id,
+        captures.len(),
+        plan.shot_count,
+        stopped
+    );
+
+    Ok(CaptureProgramResultDto {
+        schema_version: CAPTURE_PROGRAM_RESULT_SCHEMA_VERSION.into(),
+        session_id: input.session_id,
+        program_id,
+        mode: plan.mode.into(),
+        status: if stopped {
+            "program-stopped"
+        } else {
+            "program-completed"
+        }
+        .into(),
+        captures,
+        readiness,
+    })
+}
+
+/// `default_countdown_seconds` comes from the branch's
+/// `capture-countdown-seconds` toggle.
+fn resolve_capture_program_plan(
+    input: &CaptureProgramInputDto,
+    default_countdown_seconds: u32,
+) -> Result<CaptureProgramPlan, HostErrorEnvelope> {
+    let plan = match input.mode.as_str() {
+        CAPTURE_PROGRAM_MODE_COUNTDOWN => CaptureProgramPlan {
+            mode: CAPTURE_PROGRAM_MODE_COUNTDOWN,
+            countdown_seconds: input.countdown_seconds.unwrap_or(default_countdown_seconds),
+            shot_count: input.shot_count.unwrap_or(1),
+            shot_interval_ms: 0,
+        },
+        CAPTURE_PROGRAM_MODE_BURST => CaptureProgramPlan {
+            mode: CAPTURE_PROGRAM_MODE_BURST,
+            countdown_seconds: input.countdown_seconds.unwrap_or(0),
+            shot_count: input.shot_count.unwrap_or(DEFAULT_BURST_SHOT_COUNT),
+            shot_interval_ms: input.shot_interval_ms.unwrap_or(DEFAULT_BURST_INTERVAL_MS),
+        },
+        _ => {
+            return Err(HostErrorEnvelope::validation_message(
+                "지원하지 않는 촬영 방식이에요.",
+            ))
+        }
+    };
+
+    let is_valid = match plan.mode {
+        CAPTURE_PROGRAM_MODE_COUNTDOWN => {
+            (1..=MAX_COUNTDOWN_SECONDS).contains(&plan.countdown_seconds) && plan.shot_count == 1
+        }
+        _ => {
+            plan.countdown_seconds <= MAX_COUNTDOWN_SECONDS
+                && (2..=MAX_BURST_SHOT_COUNT).contains(&plan.shot_count)
+                && (MIN_BURST_INTERVAL_MS..=MAX_BURST_INTERVAL_MS).contains(&plan.shot_interval_ms)
+        }
+    };
+    if !is_valid {
+        return Err(HostErrorEnvelope::validation_message(
+            "촬영 방식 설정이 허용 범위를 벗어났어요.",
+        ));
+    }
+
+    Ok(plan)
+}
+
+fn program_fits_session(
+    manifest: &SessionManifest,
+    plan: &CaptureProgramPlan,
+) -> Result<bool, HostErrorEnvelope> {
+    let Some(timing) = manifest.timing.as_ref() else {
+        return Ok(true);
+    };
+    let adjusted_end_at_ms = rfc3339_to_unix_seconds(&timing.adjusted_end_at)? * 1_000;
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    Ok(now_ms + plan.budget_ms() <= adjusted_end_at_ms)
+}
+
+fn is_capture_allowed_now(manifest: &SessionManifest) -> Result<bool, HostErrorEnvelope> {
+    let Some(timing) = manifest.timing.as_ref() else {
+        return Ok(true);
+    };
+
+    Ok(timing.capture_allowed && evaluate_phase(timing, SystemTime::now())? != TimingPhase::Ended)
+}
+
+fn build_tick(
+    base_dir: &Path,
+    manifest: &SessionManifest,
+    program_id: &str,
+    phase: &str,
+    remaining_seconds: u32,
+    shot_index: u32,
+    plan: &CaptureProgramPlan,
+) -> CaptureProgramTickDto {
+    CaptureProgramTickDto {
+        schema_version: CAPTURE_PROGRAM_TICK_SCHEMA_VERSION.into(),
+        session_id: manifest.session_id.clone(),
+        program_id: program_id.into(),
+        phase: phase.into(),
+        remaining_seconds,
+        shot_index,
+        shot_count: plan.shot_count,
+        readiness: normalize_capture_readiness(base_dir, manifest),
+    }
+}
+
+fn generate_capture_program_id() -> String {
+    let unix_nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let counter = CAPTURE_PROGRAM_COUNTER.fetch_add(1, Ordering::Relaxed) as u128;
+
+    format!("program_{:026x}", unix_nanos ^ (counter << 16))
+}
@@ -0,0 +1,158 @@
+//! Customer selection and favourites. The selected set decides which
+//! captures get finals and what post-end waits on.
+
+use std::{path::Path, time::SystemTime};
+
+use crate::{
+    capture::{normalized_state::normalize_capture_readiness, CAPTURE_PIPELINE_LOCK},
+    contracts::dto::{
+        CaptureSelectionInputDto, CaptureSelectionResultDto, CaptureSelectionSummaryDto,
+        HostErrorEnvelope,
+    },
+    session::{
+        session_journal::{commit_session_manifest, SessionJournalEvent},
+        session_manifest::{current_timestamp, SessionManifest},
+        session_paths::SessionPaths,
+        session_repository::read_session_manifest,
+    },
+    timing::sync_session_timing_in_dir,
+};
+
+const CAPTURE_SELECTION_RESULT_SCHEMA_VERSION: &str = "capture-selection-result/v1";
+
+pub fn update_capture_selection_in_dir(
+    base_dir: &Path,
+    input: CaptureSelectionInputDto,
+) -> Result<CaptureSelectionResultDto, HostErrorEnvelope> {
+    if input.selected.is_none() && input.favorite.is_none() {
+        return Err(HostErrorEnvelope::validation_message(
+            "바꿀 선택 항목이 없어요.",
+        ));
+    }
+
+    let paths = SessionPaths::try_new(base_dir, &input.session_id)?;
+    let _pipeline_guard = CAPTURE_PIPELINE_LOCK.lock().map_err(|_| {
+        HostErrorEnvelope::persistence("촬영 상태를 잠그지 못했어요. 잠시 후 다시 시도해 주세요.")
+    })?;
+    let mut manifest = read_session_manifest(&paths.manifest_path)?;
+    manifest =
+        sync_session_timing_in_dir(base_dir, &paths.manifest_path, manifest, SystemTime::now())?;
+
+    let is_ended = manifest
+        .timing
+        .as_ref()
+        .is_some_and(|timing| timing.phase == "ended")
+        || manifest.post_end.is_some();
+    if is_ended {
+        return Err(HostErrorEnvelope::validation_message(
+            "촬영 시간이 끝나 사진 선택을 바꿀 수 없어요.",
+        ));
+    }
+    if manifest.shot_layout.is_some() {
+        return Err(HostErrorEnvelope::validation_message(
+            "레이아웃 세션은 모든 칸이 함께 전달돼요. 바꾸려면 해당 칸을 다시 찍어 주세요.",
+        ));
+    }
+
+    let capture = manifest
+        .captures
+        .iter()
+        .find(|capture| capture.capture_id == input.capture_id)
+        .ok_or_else(|| HostErrorEnvelope::validation_message("선택할 사진을 찾지 못했어요."))?;
+    if input.selected == Some(true)
+        && !matches!(
+            capture.render_status.as_str(),
+            "previewReady" | "finalReady"
+        )
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "사진이 준비된 뒤에 고를 수 있어요.",
+        ));
+    }
+
+    let max_selected_captures = manifest.max_selected_captures();
+    let selection = manifest.selection.get_or_insert_with(Default::default);
+    if let Some(selected) = input.selected {
+        let already_selected = selection.selected_capture_ids.contains(&input.capture_id);
+        if selected && !already_selected {
+            if let Some(limit) = max_selected_captures {
+                if selection.selected_capture_ids.len() >= limit as usize {
+                    return Err(HostErrorEnvelope::validation_message(format!(
+                        "이 이용권은 사진을 최대 {limit}장까지 고를 수 있어요."
+                    )));
+                }
+            }
+            selection
+                .selected_capture_ids
+                .push(input.capture_id.clone());
+        } else if !selected {
+            selection
+                .selected_capture_ids
+                .retain(|capture_id| capture_id != &input.capture_id);
+        }
+    }
+    if let Some(favorite) = input.favorite {
+        let already_favorite = selection.favorite_capture_ids.contains(&input.capture_id);
+        if favorite && !already_favorite {
+            selection
+                .favorite_capture_ids
+                .push(input.capture_id.clone());
+        } else if !favorite {
+            selection
+                .favorite_capture_ids
+                .retain(|capture_id| capture_id != &input.capture_id);
+        }
+    }
+
+    log::info!(
+        "capture_selection_updated session={} capture_id={} selected={} favorites={}",
+        manifest.session_id,
+        input.capture_id,
+        selection.selected_capture_ids.len(),
+        selection.favorite_capture_ids.len()
+    );
+    manifest.updated_at = current_timestamp(SystemTime::now())?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::manifest_updated("capture-selection-updated", &manifest),
+    )?;
+
+    Ok(CaptureSelectionResultDto {
+        schema_version: CAPTURE_SELECTION_RESULT_SCHEMA_VERSION.into(),
+        session_id: input.session_id,
+        capture_id: input.capture_id,
+        status: "selection-updated".into(),
+        readiness: normalize_capture_readiness(base_dir, &manifest),
+        manifest,
+    })
+}
+
+/// Drops a deleted capture from the selection and favourites.
+pub fn release_capture_selection(manifest: &mut SessionManifest, capture_id: &str) {
+    if let Some(selection) = manifest.selection.as_mut() {
+        selection
+            .selected_capture_ids
+            .retain(|selected_id| selected_id != capture_id);
+        selection
+            .favorite_capture_ids
+            .retain(|favorite_id| favorite_id != capture_id);
+    }
+}
+
+/// Only sessions that use selection, or whose package caps it, report it.
+pub fn project_capture_selection(manifest: &SessionManifest) -> Option<CaptureSelectionSummaryDto> {
+    let max_selected_captures = manifest.max_selected_captures();
+    if manifest.shot_layout.is_some()
+        || (manifest.selection.is_none() && max_selected_captures.is_none())
+    {
+        return None;
+    }
+    let selection = manifest.selection.clone().unwrap_or_default();
+
+    Some(CaptureSelectionSummaryDto {
+        selected_capture_ids: selection.selected_capture_ids,
+        favorite_capture_ids: selection.favorite_capture_ids,
+        max_selected_captures,
+    })
+}
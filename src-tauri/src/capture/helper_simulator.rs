@@ -0,0 +1,518 @@
+use std::{
+    collections::HashSet,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    capture::sidecar_client::{
+        append_json_line, current_time_ms, read_capture_request_messages,
+        read_processed_capture_request_ids, CanonHelperCaptureAcceptedMessage,
+        CanonHelperCaptureRequestMessage, CanonHelperErrorMessage,
+        CanonHelperFastPreviewReadyMessage, CanonHelperFileArrivedMessage, CanonHelperReadyMessage,
+        CanonHelperRecoveryStatusMessage, CanonHelperStatusMessage, CAMERA_HELPER_EVENTS_FILE_NAME,
+        CAMERA_HELPER_PROCESSED_REQUEST_IDS_FILE_NAME, CAMERA_HELPER_STATUS_FILE_NAME,
+        CANON_HELPER_CAPTURE_ACCEPTED_SCHEMA_VERSION, CANON_HELPER_ERROR_SCHEMA_VERSION,
+        CANON_HELPER_FAST_PREVIEW_READY_SCHEMA_VERSION, CANON_HELPER_FILE_ARRIVED_SCHEMA_VERSION,
+        CANON_HELPER_READY_SCHEMA_VERSION, CANON_HELPER_RECOVERY_STATUS_SCHEMA_VERSION,
+        CANON_HELPER_STATUS_SCHEMA_VERSION,
+    },
+    session::{session_manifest::current_timestamp, session_paths::SessionPaths},
+};
+
+pub const SIMULATED_HELPER_SCRIPT_FILE_NAME: &str = ".camera-helper-simulator.json";
+pub const SIMULATED_HELPER_SCRIPT_SCHEMA_VERSION: &str = "camera-helper-simulator-script/v1";
+pub const SIMULATED_HELPER_CAMERA_MODEL: &str = "Simulated EOS";
+pub const SIMULATED_HELPER_FAST_PREVIEW_KIND: &str = "simulated-thumbnail";
+
+const SIMULATED_HELPER_POLL_INTERVAL_MS: u64 = 20;
+const SIMULATED_HELPER_STATUS_INTERVAL_MS: u64 = 250;
+const SIMULATED_RAW_PAYLOAD: &[u8] = b"simulated-canon-raw";
+// Smallest payload `is_valid_render_preview_asset` accepts as a JPEG.
+const SIMULATED_PREVIEW_JPEG: [u8; 4] = [0xFF, 0xD8, 0xFF, 0xD9];
+
+/// One scripted outcome, consumed in order by successive capture requests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum SimulatedHelperFault {
+    /// Accepts the request and then never reports `file-arrived`.
+    Timeout,
+    /// Accepts the request and fails with the retryable focus error.
+    FocusFailure,
+    /// Drops the camera mid-capture and reports it disconnected for `durationMs`.
+    #[serde(rename_all = "camelCase")]
+    Disconnect { duration_ms: u64 },
+    /// Holds the RAW handoff back for `delayMs` after the fast preview.
+    #[serde(rename_all = "camelCase")]
+    SlowRawHandoff { delay_ms: u64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedHelperScript {
+    #[serde(default = "default_script_schema_version")]
+    pub schema_version: String,
+    #[serde(default = "default_emit_fast_preview")]
+    pub emit_fast_preview: bool,
+    #[serde(default)]
+    pub capture_faults: Vec<SimulatedHelperFault>,
+}
+
+impl Default for SimulatedHelperScript {
+    fn default() -> Self {
+        Self {
+            schema_version: default_script_schema_version(),
+            emit_fast_preview: default_emit_fast_preview(),
+            capture_faults: Vec::new(),
+        }
+    }
+}
+
+/// Running simulator thread. Dropping the handle stops it.
+pub struct SimulatedHelperHandle {
+    session_id: String,
+    stop: Arc<AtomicBool>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl SimulatedHelperHandle {
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.worker
+            .as_ref()
+            .map(|worker| !worker.is_finished())
+            .unwrap_or(false)
+    }
+
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Drop for SimulatedHelperHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+struct PendingRawHandoff {
+    request: CanonHelperCaptureRequestMessage,
+    capture_id: String,
+    fast_preview_path: Option<String>,
+    due_at_ms: u64,
+}
+
+struct SimulatedHelperState {
+    base_dir: PathBuf,
+    session_id: String,
+    paths: SessionPaths,
+    processed_request_ids: HashSet<String>,
+    captures_handled: usize,
+    status_sequence: u64,
+    next_status_at_ms: u64,
+    disconnected_until_ms: Option<u64>,
+    pending_raw_handoff: Option<PendingRawHandoff>,
+}
+
+pub fn resolve_simulated_helper_script_path(base_dir: &Path) -> PathBuf {
+    base_dir.join(SIMULATED_HELPER_SCRIPT_FILE_NAME)
+}
+
+pub fn save_simulated_helper_script_in_dir(
+    base_dir: &Path,
+    script: &SimulatedHelperScript,
+) -> Result<(), std::io::Error> {
+    fs::create_dir_all(base_dir)?;
+    let bytes = serde_json::to_vec_pretty(script)
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+
+    fs::write(resolve_simulated_helper_script_path(base_dir), bytes)
+}
+
+/// Starts an in-process helper that speaks the `canon-helper-*/v1` file protocol
+/// for one session. It re-reads the script file on every request, so tests and
+/// demo operators can change the next outcome while the booth is running.
+pub fn start_simulated_helper(
+    base_dir: &Path,
+    session_id: &str,
+) -> Result<SimulatedHelperHandle, std::io::Error> {
+    let paths = SessionPaths::try_new(base_dir, session_id)
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidInput, error.message))?;
+    fs::create_dir_all(&paths.diagnostics_dir)?;
+    fs::create_dir_all(&paths.captures_originals_dir)?;
+
+    let processed_request_ids = read_processed_capture_request_ids(base_dir, session_id)
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    let mut state = SimulatedHelperState {
+        base_dir: base_dir.to_path_buf(),
+        session_id: session_id.to_string(),
+        paths,
+        processed_request_ids,
+        captures_handled: 0,
+        status_sequence: 0,
+        next_status_at_ms: 0,
+        disconnected_until_ms: None,
+        pending_raw_handoff: None,
+    };
+    let ready_message = CanonHelperReadyMessage {
+        schema_version: CANON_HELPER_READY_SCHEMA_VERSION.into(),
+        message_type: "helper-ready".into(),
+        helper_version: Some(env!("CARGO_PKG_VERSION").into()),
+        protocol_version: Some("v1".into()),
+        runtime_platform: Some(std::env::consts::OS.into()),
+        sdk_family: Some("simulated".into()),
+        sdk_version: None,
+    };
+    log::info!(
+        "camera_helper_simulator_ready session={} message={}",
+        session_id,
+        serde_json::to_string(&ready_message).unwrap_or_default()
+    );
+    state.write_status()?;
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let worker_stop = Arc::clone(&stop);
+    let worker = thread::Builder::new()
+        .name(format!("camera-helper-simulator-{session_id}"))
+        .spawn(move || {
+            while !worker_stop.load(Ordering::SeqCst) {
+                if let Err(error) = state.tick() {
+                    log::warn!(
+                        "camera_helper_simulator_tick_failed session={} error={}",
+                        state.session_id,
+                        error
+                    );
+                }
+
+                thread::sleep(Duration::from_millis(SIMULATED_HELPER_POLL_INTERVAL_MS));
+            }
+        })?;
+
+    Ok(SimulatedHelperHandle {
+        session_id: session_id.to_string(),
+        stop,
+        worker: Some(worker),
+    })
+}
+
+impl SimulatedHelperState {
+    fn tick(&mut self) -> Result<(), std::io::Error> {
+        let now_ms = now_ms()?;
+
+        if self
+            .disconnected_until_ms
+            .map(|until| now_ms >= until)
+            .unwrap_or(false)
+        {
+            self.disconnected_until_ms = None;
+            self.next_status_at_ms = 0;
+        }
+
+        self.complete_pending_raw_handoff(now_ms)?;
+
+        if self.pending_raw_handoff.is_none() {
+            self.consume_next_request(now_ms)?;
+        }
+
+        if now_ms >= self.next_status_at_ms {
+            self.write_status()?;
+        }
+
+        Ok(())
+    }
+
+    fn consume_next_request(&mut self, now_ms: u64) -> Result<(), std::io::Error> {
+        let session_id = self.session_id.clone();
+        let requests = read_capture_request_messages(&self.base_dir, &session_id)
+            .map_err(|_| invalid_data("capture request log is unreadable"))?;
+        let Some(request) = requests
+            .into_iter()
+            .find(|request| !self.processed_request_ids.contains(&request.request_id))
+        else {
+            return Ok(());
+        };
+
+        self.mark_processed(&request.request_id)?;
+
+        if request.session_id != session_id {
+            return self.append_helper_error(
+                Some(request.session_id.clone()),
+                "session-mismatch",
+                "현재 helper가 바인딩된 세션과 요청 세션이 다릅니다.",
+            );
+        }
+
+        if self.disconnected_until_ms.is_some() {
+            return self.append_helper_error(
+                Some(session_id),
+                "camera-not-ready",
+                "카메라가 아직 촬영 가능한 상태가 아니에요.",
+            );
+        }
+
+        let fault = self.next_scripted_fault();
+        self.captures_handled += 1;
+        self.append_event(&CanonHelperCaptureAcceptedMessage {
+            schema_version: CANON_HELPER_CAPTURE_ACCEPTED_SCHEMA_VERSION.into(),
+            message_type: "capture-accepted".into(),
+            session_id: session_id.clone(),
+            request_id: request.request_id.clone(),
+            detail_code: Some("capture-in-flight".into()),
+        })?;
+
+        match fault {
+            Some(SimulatedHelperFault::Timeout) => Ok(()),
+            Some(SimulatedHelperFault::FocusFailure) => self.append_helper_error(
+                Some(session_id),
+                "capture-focus-not-locked",
+                "초점을 맞추지 못해 셔터가 열리지 않았어요.",
+            ),
+            Some(SimulatedHelperFault::Disconnect { duration_ms }) => {
+                self.disconnected_until_ms = Some(now_ms.saturating_add(duration_ms));
+                self.next_status_at_ms = 0;
+                self.append_event(&CanonHelperRecoveryStatusMessage {
+                    schema_version: CANON_HELPER_RECOVERY_STATUS_SCHEMA_VERSION.into(),
+                    message_type: "recovery-status".into(),
+                    session_id: session_id.clone(),
+                    recovery_state: "recovering".into(),
+                    observed_at: timestamp()?,
+                    detail_code: Some("camera-disconnected".into()),
+                })?;
+                self.append_helper_error(
+                    Some(session_id),
+                    "camera-disconnected",
+                    "촬영 중 카메라 연결이 끊어졌어요.",
+                )
+            }
+            Some(SimulatedHelperFault::SlowRawHandoff { delay_ms }) => {
+                self.begin_capture(request, now_ms.saturating_add(delay_ms))
+            }
+            None => self.begin_capture(request, now_ms),
+        }
+    }
+
+    fn begin_capture(
+        &mut self,
+        request: CanonHelperCaptureRequestMessage,
+        due_at_ms: u64,
+    ) -> Result<(), std::io::Error> {
+        let capture_id = format!(
+            "capture_sim_{}",
+            request
+                .request_id
+                .strip_prefix("request_")
+                .unwrap_or(&request.request_id)
+        );
+        let fast_preview_path = if self.read_script().emit_fast_preview {
+            let preview_path = self
+                .paths
+                .handoff_dir
+                .join("fast-preview")
+                .join(format!("{capture_id}.jpg"));
+            write_file(&preview_path, &SIMULATED_PREVIEW_JPEG)?;
+            self.append_event(&CanonHelperFastPreviewReadyMessage {
+                schema_version: CANON_HELPER_FAST_PREVIEW_READY_SCHEMA_VERSION.into(),
+                message_type: "fast-preview-ready".into(),
+                session_id: request.session_id.clone(),
+                request_id: request.request_id.clone(),
+                capture_id: capture_id.clone(),
+                observed_at: timestamp()?,
+                fast_preview_path: preview_path.to_string_lossy().into_owned(),
+                fast_preview_kind: Some(SIMULATED_HELPER_FAST_PREVIEW_KIND.into()),
+            })?;
+
+            Some(preview_path.to_string_lossy().into_owned())
+        } else {
+            None
+        };
+
+        self.pending_raw_handoff = Some(PendingRawHandoff {
+            request,
+            capture_id,
+            fast_preview_path,
+            due_at_ms,
+        });
+        self.next_status_at_ms = 0;
+
+        Ok(())
+    }
+
+    fn complete_pending_raw_handoff(&mut self, now_ms: u64) -> Result<(), std::io::Error> {
+        if !self
+            .pending_raw_handoff
+            .as_ref()
+            .map(|pending| now_ms >= pending.due_at_ms)
+            .unwrap_or(false)
+        {
+            return Ok(());
+        }
+
+        let Some(pending) = self.pending_raw_handoff.take() else {
+            return Ok(());
+        };
+        let raw_path = self
+            .paths
+            .captures_originals_dir
+            .join(format!("{}.cr3", pending.capture_id));
+        write_file(&raw_path, SIMULATED_RAW_PAYLOAD)?;
+        self.next_status_at_ms = 0;
+
+        self.append_event(&CanonHelperFileArrivedMessage {
+            schema_version: CANON_HELPER_FILE_ARRIVED_SCHEMA_VERSION.into(),
+            message_type: "file-arrived".into(),
+            session_id: pending.request.session_id,
+            request_id: pending.request.request_id,
+            capture_id: pending.capture_id,
+            arrived_at: timestamp()?,
+            raw_path: raw_path.to_string_lossy().into_owned(),
+            fast_preview_kind: pending
+                .fast_preview_path
+                .as_ref()
+                .map(|_| SIMULATED_HELPER_FAST_PREVIEW_KIND.into()),
+            fast_preview_path: pending.fast_preview_path,
+        })
+    }
+
+    fn next_scripted_fault(&self) -> Option<SimulatedHelperFault> {
+        self.read_script()
+            .capture_faults
+            .get(self.captures_handled)
+            .cloned()
+    }
+
+    fn read_script(&self) -> SimulatedHelperScript {
+        fs::read_to_string(resolve_simulated_helper_script_path(&self.base_dir))
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn mark_processed(&mut self, request_id: &str) -> Result<(), std::io::Error> {
+        let mut file = OpenOptions::new().create(true).append(true).open(
+            self.paths
+                .diagnostics_dir
+                .join(CAMERA_HELPER_PROCESSED_REQUEST_IDS_FILE_NAME),
+        )?;
+        writeln!(file, "{request_id}")?;
+        self.processed_request_ids.insert(request_id.to_string());
+
+        Ok(())
+    }
+
+    fn append_helper_error(
+        &self,
+        session_id: Option<String>,
+        detail_code: &str,
+        message: &str,
+    ) -> Result<(), std::io::Error> {
+        self.append_event(&CanonHelperErrorMessage {
+            schema_version: CANON_HELPER_ERROR_SCHEMA_VERSION.into(),
+            message_type: "helper-error".into(),
+            session_id,
+            observed_at: Some(timestamp()?),
+            detail_code: detail_code.into(),
+            message: Some(message.into()),
+        })
+    }
+
+    fn append_event<T: Serialize>(&self, event: &T) -> Result<(), std::io::Error> {
+        append_json_line(
+            &self
+                .paths
+                .diagnostics_dir
+                .join(CAMERA_HELPER_EVENTS_FILE_NAME),
+            event,
+        )
+    }
+
+    fn write_status(&mut self) -> Result<(), std::io::Error> {
+        let (camera_state, helper_state, detail_code) = if self.disconnected_until_ms.is_some() {
+            ("disconnected", "recovering", Some("camera-disconnected"))
+        } else if self.pending_raw_handoff.is_some() {
+            ("capturing", "healthy", None)
+        } else {
+            ("ready", "healthy", None)
+        };
+        self.status_sequence += 1;
+        let status = CanonHelperStatusMessage {
+            schema_version: CANON_HELPER_STATUS_SCHEMA_VERSION.into(),
+            message_type: Some("camera-status".into()),
+            session_id: self.session_id.clone(),
+            sequence: Some(self.status_sequence),
+            observed_at: timestamp()?,
+            camera_state: camera_state.into(),
+            helper_state: helper_state.into(),
+            camera_model: Some(SIMULATED_HELPER_CAMERA_MODEL.into()),
+            request_id: self
+                .pending_raw_handoff
+                .as_ref()
+                .map(|pending| pending.request.request_id.clone()),
+            detail_code: detail_code.map(str::to_string),
+        };
+        let status_path = self
+            .paths
+            .diagnostics_dir
+            .join(CAMERA_HELPER_STATUS_FILE_NAME);
+        let temp_path = status_path.with_extension("json.tmp");
+        let bytes = serde_json::to_vec_pretty(&status)
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+
+        // Swap the snapshot in whole so the host never parses a half-written file.
+        fs::write(&temp_path, bytes)?;
+        fs::rename(&temp_path, &status_path)?;
+        self.next_status_at_ms = now_ms()?.saturating_add(SIMULATED_HELPER_STATUS_INTERVAL_MS);
+
+        Ok(())
+    }
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> Result<(), std::io::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, bytes)
+}
+
+fn timestamp() -> Result<String, std::io::Error> {
+    current_timestamp(SystemTime::now())
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::Other, error.message))
+}
+
+fn now_ms() -> Result<u64, std::io::Error> {
+    current_time_ms().map_err(|error| std::io::Error::new(std::io::ErrorKind::Other, error))
+}
+
+fn invalid_data(message: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn default_script_schema_version() -> String {
+    SIMULATED_HELPER_SCRIPT_SCHEMA_VERSION.into()
+}
+
+fn default_emit_fast_preview() -> bool {
+    true
+}
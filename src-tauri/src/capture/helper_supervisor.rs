@@ -10,18 +10,24 @@ use std::{
 use serde_json::json;
 
 use crate::{
-    capture::sidecar_client::{
-        bundled_helper_dir, CAMERA_HELPER_STATUS_FILE_NAME, CANON_HELPER_STATUS_SCHEMA_VERSION,
+    capture::{
+        helper_simulator::{start_simulated_helper, SimulatedHelperHandle},
+        sidecar_client::{
+            bundled_helper_dir, CAMERA_HELPER_STATUS_FILE_NAME, CANON_HELPER_STATUS_SCHEMA_VERSION,
+        },
     },
     session::{session_manifest::current_timestamp, session_paths::SessionPaths},
 };
 
 static HELPER_PROCESS: LazyLock<Mutex<Option<TrackedHelperProcess>>> =
     LazyLock::new(|| Mutex::new(None));
+static SIMULATED_HELPER: LazyLock<Mutex<Option<SimulatedHelperHandle>>> =
+    LazyLock::new(|| Mutex::new(None));
 const HELPER_POLL_INTERVAL_MS: &str = "250";
 const HELPER_STATUS_INTERVAL_MS: &str = "250";
 const HELPER_STARTUP_PROBE_DELAY_MS: Duration = Duration::from_millis(200);
 const CANON_SDK_ROOT_ENV: &str = "BOOTHY_CANON_SDK_ROOT";
+pub const SIMULATED_HELPER_ENV: &str = "BOOTHY_CANON_HELPER_SIMULATED";
 
 enum HelperLaunchTarget {
     Executable(PathBuf),
@@ -41,12 +47,22 @@ struct HelperLaunchFailure {
 }
 
 pub fn try_ensure_helper_running(base_dir: &Path, session_id: &str) {
-    if let Err(error) = ensure_helper_running(base_dir, session_id) {
+    let result = if simulated_helper_requested() {
+        ensure_simulated_helper_running(base_dir, session_id)
+    } else {
+        ensure_helper_running(base_dir, session_id)
+    };
+
+    if let Err(error) = result {
         let _ = write_supervisor_failure_status(base_dir, session_id, error.detail_code);
     }
 }
 
 pub fn shutdown_helper_process() {
+    if let Ok(mut guard) = SIMULATED_HELPER.lock() {
+        guard.take();
+    }
+
     let Ok(mut guard) = HELPER_PROCESS.lock() else {
         return;
     };
@@ -56,6 +72,45 @@ pub fn shutdown_helper_process() {
     }
 }
 
+fn simulated_helper_requested() -> bool {
+    env::var(SIMULATED_HELPER_ENV)
+        .map(|value| {
+            matches!(
+                value.trim().to_ascii_lowercase().as_str(),
+                "1" | "true" | "yes"
+            )
+        })
+        .unwrap_or(false)
+}
+
+fn ensure_simulated_helper_running(
+    base_dir: &Path,
+    session_id: &str,
+) -> Result<(), HelperLaunchFailure> {
+    let mut guard = SIMULATED_HELPER.lock().map_err(|_| HelperLaunchFailure {
+        detail_code: "helper-supervisor-unavailable",
+    })?;
+
+    if guard
+        .as_ref()
+        .map(|handle| handle.session_id() == session_id && handle.is_running())
+        .unwrap_or(false)
+    {
+        return Ok(());
+    }
+
+    // Dropping the previous handle joins its thread before the next session binds.
+    guard.take();
+    *guard =
+        Some(
+            start_simulated_helper(base_dir, session_id).map_err(|_| HelperLaunchFailure {
+                detail_code: "helper-launch-failed",
+            })?,
+        );
+
+    Ok(())
+}
+
 fn ensure_helper_running(base_dir: &Path, session_id: &str) -> Result<(), HelperLaunchFailure> {
     let helper_launch_target = resolve_helper_launch_target().ok_or(HelperLaunchFailure {
         detail_code: "helper-binary-missing",
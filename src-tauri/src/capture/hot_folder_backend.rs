@@ -0,0 +1,470 @@
+use std::{
+    collections::{HashMap, HashSet},
+    env, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    capture::{
+        camera_backend::{
+            CameraBackend, CameraBackendFault, CameraCaptureRequest, CameraFastPreviewReady,
+            CameraStatusSnapshot,
+        },
+        sidecar_client::{
+            append_json_line, capture_round_trip_timeout_ms, current_time_ms, read_json_lines,
+            validate_session_raw_path, CompletedCaptureFastPreview, CompletedCaptureRoundTrip,
+            SidecarClientError,
+        },
+    },
+    session::{
+        session_manifest::{current_timestamp, rfc3339_to_unix_seconds},
+        session_paths::SessionPaths,
+    },
+};
+
+pub const HOT_FOLDER_CAMERA_BACKEND_ID: &str = "hot-folder";
+pub const HOT_FOLDER_DIR_ENV: &str = "BOOTHY_HOT_FOLDER_DIR";
+pub const HOT_FOLDER_DEFAULT_DIR_NAME: &str = "hot-folder";
+pub const HOT_FOLDER_REQUESTS_FILE_NAME: &str = "hot-folder-requests.jsonl";
+pub const HOT_FOLDER_INGEST_LOG_FILE_NAME: &str = "hot-folder-ingest.jsonl";
+pub const HOT_FOLDER_REQUEST_SCHEMA_VERSION: &str = "hot-folder-request/v1";
+pub const HOT_FOLDER_INGEST_SCHEMA_VERSION: &str = "hot-folder-ingest/v1";
+pub const HOT_FOLDER_FAST_PREVIEW_KIND: &str = "hot-folder-jpeg";
+
+const HOT_FOLDER_RAW_EXTENSIONS: [&str; 8] =
+    ["cr2", "cr3", "nef", "arw", "raf", "dng", "orf", "rw2"];
+const HOT_FOLDER_JPEG_EXTENSIONS: [&str; 2] = ["jpg", "jpeg"];
+const HOT_FOLDER_POLL_INTERVAL_MS: u64 = 50;
+// Tethering tools often stamp files with the camera clock or copy them with the
+// original mtime preserved, so accept files written slightly before the request.
+const HOT_FOLDER_REQUEST_CLOCK_SKEW_MS: u64 = 2_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HotFolderCaptureRequestRecord {
+    pub schema_version: String,
+    pub session_id: String,
+    pub request_id: String,
+    pub requested_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HotFolderIngestRecord {
+    pub schema_version: String,
+    pub session_id: String,
+    pub request_id: String,
+    pub capture_id: String,
+    pub source_path: String,
+    pub raw_path: String,
+    #[serde(default)]
+    pub fast_preview_path: Option<String>,
+    pub arrived_at: String,
+}
+
+#[derive(Debug, Clone)]
+struct HotFolderCandidate {
+    primary_path: PathBuf,
+    primary_extension: String,
+    companion_jpeg_path: Option<PathBuf>,
+    size: u64,
+    modified_at_ms: u64,
+}
+
+/// Camera backend for branches whose vendor tethering software just writes
+/// RAW/JPEG files into a folder. Each request claims the next settled file
+/// written after the request and moves it into the session like a Canon
+/// `file-arrived` event.
+pub struct HotFolderCameraBackend;
+
+pub(crate) static HOT_FOLDER_CAMERA_BACKEND: HotFolderCameraBackend = HotFolderCameraBackend;
+
+pub fn resolve_hot_folder_dir(base_dir: &Path) -> PathBuf {
+    env::var_os(HOT_FOLDER_DIR_ENV)
+        .map(PathBuf::from)
+        .filter(|path| !path.as_os_str().is_empty())
+        .unwrap_or_else(|| base_dir.join(HOT_FOLDER_DEFAULT_DIR_NAME))
+}
+
+pub fn read_hot_folder_ingest_records(
+    base_dir: &Path,
+    session_id: &str,
+) -> Result<Vec<HotFolderIngestRecord>, SidecarClientError> {
+    let ingest_path = SessionPaths::try_new(base_dir, session_id)
+        .map(|paths| paths.diagnostics_dir.join(HOT_FOLDER_INGEST_LOG_FILE_NAME))
+        .map_err(|_| SidecarClientError::EventsUnreadable)?;
+
+    read_json_lines::<HotFolderIngestRecord>(&ingest_path)
+        .map_err(|_| SidecarClientError::InvalidEvents)
+}
+
+impl CameraBackend for HotFolderCameraBackend {
+    fn backend_id(&self) -> &'static str {
+        HOT_FOLDER_CAMERA_BACKEND_ID
+    }
+
+    fn live_truth_source(&self) -> &'static str {
+        "hot-folder"
+    }
+
+    fn ensure_running(&self, base_dir: &Path, session_id: &str) {
+        let hot_folder_dir = resolve_hot_folder_dir(base_dir);
+
+        if let Err(error) = fs::create_dir_all(&hot_folder_dir) {
+            log::warn!(
+                "hot_folder_unavailable session={} path={} error={}",
+                session_id,
+                hot_folder_dir.to_string_lossy(),
+                error
+            );
+        }
+    }
+
+    fn read_status(
+        &self,
+        base_dir: &Path,
+        session_id: &str,
+    ) -> Result<Option<CameraStatusSnapshot>, SidecarClientError> {
+        let observed_at =
+            current_timestamp(SystemTime::now()).map_err(|_| SidecarClientError::InvalidStatus)?;
+        let hot_folder_ready = resolve_hot_folder_dir(base_dir).is_dir();
+
+        // The folder itself is the only liveness signal a passive tether gives us.
+        Ok(Some(CameraStatusSnapshot {
+            session_id: session_id.to_string(),
+            observed_at,
+            camera_state: if hot_folder_ready {
+                "ready".into()
+            } else {
+                "disconnected".into()
+            },
+            helper_state: "healthy".into(),
+            sequence: None,
+            detail_code: (!hot_folder_ready).then(|| "hot-folder-missing".into()),
+        }))
+    }
+
+    fn is_capture_request_known(
+        &self,
+        base_dir: &Path,
+        session_id: &str,
+        request_id: &str,
+    ) -> Result<bool, SidecarClientError> {
+        let requests_path = SessionPaths::try_new(base_dir, session_id)
+            .map(|paths| paths.diagnostics_dir.join(HOT_FOLDER_REQUESTS_FILE_NAME))
+            .map_err(|_| SidecarClientError::EventsUnreadable)?;
+        let requests = read_json_lines::<HotFolderCaptureRequestRecord>(&requests_path)
+            .map_err(|_| SidecarClientError::InvalidEvents)?;
+
+        Ok(requests
+            .iter()
+            .any(|request| request.request_id == request_id))
+    }
+
+    fn capture_event_cursor(
+        &self,
+        base_dir: &Path,
+        session_id: &str,
+    ) -> Result<usize, SidecarClientError> {
+        Ok(read_hot_folder_ingest_records(base_dir, session_id)?.len())
+    }
+
+    fn submit_capture_request(
+        &self,
+        base_dir: &Path,
+        request: &CameraCaptureRequest,
+    ) -> Result<(), SidecarClientError> {
+        let requests_path = SessionPaths::try_new(base_dir, &request.session_id)
+            .map(|paths| paths.diagnostics_dir.join(HOT_FOLDER_REQUESTS_FILE_NAME))
+            .map_err(|_| SidecarClientError::RequestWriteFailed)?;
+
+        append_json_line(
+            &requests_path,
+            &HotFolderCaptureRequestRecord {
+                schema_version: HOT_FOLDER_REQUEST_SCHEMA_VERSION.into(),
+                session_id: request.session_id.clone(),
+                request_id: request.request_id.clone(),
+                requested_at: request.requested_at.clone(),
+            },
+        )
+        .map_err(|_| SidecarClientError::RequestWriteFailed)
+    }
+
+    fn wait_for_capture(
+        &self,
+        base_dir: &Path,
+        request: &CameraCaptureRequest,
+        _event_cursor: usize,
+        on_fast_preview_ready: &mut dyn FnMut(&CameraFastPreviewReady),
+    ) -> Result<CompletedCaptureRoundTrip, SidecarClientError> {
+        let hot_folder_dir = resolve_hot_folder_dir(base_dir);
+        let timeout_deadline = current_time_ms()
+            .map_err(|_| SidecarClientError::CaptureTimedOut)?
+            .saturating_add(capture_round_trip_timeout_ms(base_dir));
+        let claim_after_ms = rfc3339_to_unix_seconds(&request.requested_at)
+            .map(|seconds| seconds.saturating_mul(1_000))
+            .unwrap_or_default()
+            .saturating_sub(HOT_FOLDER_REQUEST_CLOCK_SKEW_MS);
+        // A late JPEG sidecar for an already claimed RAW must not become the next shot.
+        let claimed_stems: HashSet<String> =
+            read_hot_folder_ingest_records(base_dir, &request.session_id)?
+                .iter()
+                .filter_map(|record| file_stem_key(Path::new(&record.source_path)))
+                .collect();
+        let mut observed_sizes: HashMap<PathBuf, u64> = HashMap::new();
+        let mut first_seen_at_ms: HashMap<PathBuf, u64> = HashMap::new();
+
+        loop {
+            let now_ms = current_time_ms().map_err(|_| SidecarClientError::CaptureTimedOut)?;
+            let candidates =
+                scan_hot_folder_candidates(&hot_folder_dir, claim_after_ms, &claimed_stems)?;
+
+            for candidate in &candidates {
+                first_seen_at_ms
+                    .entry(candidate.primary_path.clone())
+                    .or_insert(now_ms);
+            }
+
+            // A file is only claimed once its size has held still for a full poll,
+            // so half-written RAWs from slow tethering tools are never moved.
+            let settled = candidates.iter().find(|candidate| {
+                candidate.size > 0
+                    && observed_sizes.get(&candidate.primary_path) == Some(&candidate.size)
+            });
+
+            if let Some(candidate) = settled {
+                let accepted_at_ms = first_seen_at_ms
+                    .get(&candidate.primary_path)
+                    .copied()
+                    .unwrap_or(now_ms);
+
+                return claim_hot_folder_candidate(
+                    base_dir,
+                    request,
+                    candidate,
+                    accepted_at_ms,
+                    on_fast_preview_ready,
+                );
+            }
+
+            observed_sizes = candidates
+                .into_iter()
+                .map(|candidate| (candidate.primary_path, candidate.size))
+                .collect();
+
+            if now_ms >= timeout_deadline {
+                return Err(SidecarClientError::CaptureTimedOut);
+            }
+
+            thread::sleep(Duration::from_millis(HOT_FOLDER_POLL_INTERVAL_MS));
+        }
+    }
+
+    fn latest_fault(&self, _base_dir: &Path, _session_id: &str) -> Option<CameraBackendFault> {
+        // Claim failures surface directly from `wait_for_capture`; a passive folder
+        // has no out-of-band error channel to replay.
+        None
+    }
+}
+
+fn scan_hot_folder_candidates(
+    hot_folder_dir: &Path,
+    claim_after_ms: u64,
+    claimed_stems: &HashSet<String>,
+) -> Result<Vec<HotFolderCandidate>, SidecarClientError> {
+    if !hot_folder_dir.is_dir() {
+        return Err(SidecarClientError::StatusUnreadable);
+    }
+
+    let entries = fs::read_dir(hot_folder_dir).map_err(|_| SidecarClientError::EventsUnreadable)?;
+    let mut raws: HashMap<String, (PathBuf, String, u64, u64)> = HashMap::new();
+    let mut jpegs: HashMap<String, (PathBuf, u64, u64)> = HashMap::new();
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+
+        let Some(extension) = path
+            .extension()
+            .and_then(|value| value.to_str())
+            .map(|value| value.to_ascii_lowercase())
+        else {
+            continue;
+        };
+        let Some(stem) = file_stem_key(&path) else {
+            continue;
+        };
+        if claimed_stems.contains(&stem) {
+            continue;
+        }
+        let modified_at_ms = metadata
+            .modified()
+            .ok()
+            .and_then(|value| value.duration_since(UNIX_EPOCH).ok())
+            .map(|value| value.as_millis() as u64)
+            .unwrap_or_default();
+
+        if modified_at_ms < claim_after_ms {
+            continue;
+        }
+
+        if HOT_FOLDER_RAW_EXTENSIONS.contains(&extension.as_str()) {
+            raws.insert(stem, (path, extension, metadata.len(), modified_at_ms));
+        } else if HOT_FOLDER_JPEG_EXTENSIONS.contains(&extension.as_str()) {
+            jpegs.insert(stem, (path, metadata.len(), modified_at_ms));
+        }
+    }
+
+    let mut candidates = Vec::new();
+
+    for (stem, (path, extension, size, modified_at_ms)) in &raws {
+        candidates.push(HotFolderCandidate {
+            primary_path: path.clone(),
+            primary_extension: extension.clone(),
+            companion_jpeg_path: jpegs.get(stem).map(|(jpeg_path, _, _)| jpeg_path.clone()),
+            size: *size,
+            modified_at_ms: *modified_at_ms,
+        });
+    }
+
+    for (stem, (path, size, modified_at_ms)) in &jpegs {
+        if raws.contains_key(stem) {
+            continue;
+        }
+
+        candidates.push(HotFolderCandidate {
+            primary_path: path.clone(),
+            primary_extension: "jpg".into(),
+            companion_jpeg_path: Some(path.clone()),
+            size: *size,
+            modified_at_ms: *modified_at_ms,
+        });
+    }
+
+    candidates.sort_by(|left, right| {
+        left.modified_at_ms
+            .cmp(&right.modified_at_ms)
+            .then_with(|| left.primary_path.cmp(&right.primary_path))
+    });
+
+    Ok(candidates)
+}
+
+fn claim_hot_folder_candidate(
+    base_dir: &Path,
+    request: &CameraCaptureRequest,
+    candidate: &HotFolderCandidate,
+    accepted_at_ms: u64,
+    on_fast_preview_ready: &mut dyn FnMut(&CameraFastPreviewReady),
+) -> Result<CompletedCaptureRoundTrip, SidecarClientError> {
+    let paths = SessionPaths::try_new(base_dir, &request.session_id)
+        .map_err(|_| SidecarClientError::CaptureFileUnscoped)?;
+    let capture_id = format!(
+        "capture_hotfolder_{}",
+        request
+            .request_id
+            .strip_prefix("request_")
+            .unwrap_or(&request.request_id)
+    );
+    let raw_destination = paths
+        .captures_originals_dir
+        .join(format!("{capture_id}.{}", candidate.primary_extension));
+    let fast_preview_destination = paths
+        .handoff_dir
+        .join("fast-preview")
+        .join(format!("{capture_id}.jpg"));
+
+    // Copy the preview first: when the primary is itself a JPEG, the move below
+    // would otherwise take the only source away.
+    let fast_preview_path = candidate
+        .companion_jpeg_path
+        .as_ref()
+        .and_then(|jpeg_path| copy_into(jpeg_path, &fast_preview_destination).ok())
+        .map(|_| fast_preview_destination.to_string_lossy().into_owned());
+
+    move_into(&candidate.primary_path, &raw_destination)
+        .map_err(|_| SidecarClientError::CaptureFileMissing)?;
+    if let Some(jpeg_path) = candidate.companion_jpeg_path.as_ref() {
+        if jpeg_path != &candidate.primary_path {
+            let _ = fs::remove_file(jpeg_path);
+        }
+    }
+
+    if let Some(fast_preview_path) = fast_preview_path.as_ref() {
+        on_fast_preview_ready(&CameraFastPreviewReady {
+            capture_id: capture_id.clone(),
+            fast_preview_path: fast_preview_path.clone(),
+            fast_preview_kind: Some(HOT_FOLDER_FAST_PREVIEW_KIND.into()),
+        });
+    }
+
+    let raw_path = validate_session_raw_path(
+        base_dir,
+        &request.session_id,
+        &raw_destination.to_string_lossy(),
+    )?;
+    let persisted_at_ms = current_time_ms().map_err(|_| SidecarClientError::CaptureTimedOut)?;
+    let arrived_at =
+        current_timestamp(SystemTime::now()).map_err(|_| SidecarClientError::CaptureTimedOut)?;
+    let _ = append_json_line(
+        &paths.diagnostics_dir.join(HOT_FOLDER_INGEST_LOG_FILE_NAME),
+        &HotFolderIngestRecord {
+            schema_version: HOT_FOLDER_INGEST_SCHEMA_VERSION.into(),
+            session_id: request.session_id.clone(),
+            request_id: request.request_id.clone(),
+            capture_id: capture_id.clone(),
+            source_path: candidate.primary_path.to_string_lossy().into_owned(),
+            raw_path: raw_path.clone(),
+            fast_preview_path: fast_preview_path.clone(),
+            arrived_at,
+        },
+    );
+
+    Ok(CompletedCaptureRoundTrip {
+        capture_id,
+        raw_path,
+        fast_preview: fast_preview_path.map(|asset_path| CompletedCaptureFastPreview {
+            asset_path,
+            kind: Some(HOT_FOLDER_FAST_PREVIEW_KIND.into()),
+        }),
+        capture_accepted_at_ms: accepted_at_ms,
+        persisted_at_ms,
+    })
+}
+
+fn file_stem_key(path: &Path) -> Option<String> {
+    path.file_stem()
+        .and_then(|value| value.to_str())
+        .map(|value| value.to_ascii_lowercase())
+}
+
+fn copy_into(source: &Path, destination: &Path) -> Result<(), std::io::Error> {
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::copy(source, destination).map(|_| ())
+}
+
+fn move_into(source: &Path, destination: &Path) -> Result<(), std::io::Error> {
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // The watched folder is often on another volume than the booth data root.
+    if fs::rename(source, destination).is_ok() {
+        return Ok(());
+    }
+
+    fs::copy(source, destination)?;
+    fs::remove_file(source)
+}
@@ -9,24 +9,36 @@ use std::{
 
 use crate::{
     capture::{
+        shot_layout::assign_capture_to_shot_layout,
         sidecar_client::{CompletedCaptureFastPreview, FastPreviewReadyUpdate},
         CAPTURE_PIPELINE_LOCK, IN_FLIGHT_CAPTURE_SESSIONS,
     },
     contracts::dto::{CaptureRequestInputDto, HostErrorEnvelope},
     render::{
         is_valid_render_preview_asset, log_render_failure_in_dir, log_render_ready_in_dir,
-        log_render_start_in_dir, promote_preview_render_output,
+        log_render_start_in_dir, promote_preview_render_output, render_backend_from_detail,
         render_capture_asset_from_raw_in_dir, render_capture_asset_in_dir,
-        render_preview_asset_to_path_in_dir, RenderIntent,
+        render_final_output_variants_in_dir,
+        render_job_queue::{
+            claim_render_job_in_dir, enqueue_render_job_in_dir, ensure_render_job_workers_in_dir,
+            resume_render_jobs_in_dir, run_render_job, RenderJobKind, RenderJobRecord,
+            RenderJobRequest,
+        },
+        render_preview_asset_to_path_in_dir,
+        shot_layout_composer::render_shot_layout_final_in_dir,
+        RenderIntent, RenderWorkerError,
     },
     session::{
+        session_journal::{commit_session_manifest, SessionJournalEvent},
         session_manifest::{
-            current_timestamp, ActivePresetBinding, CaptureTimingMetrics, FinalCaptureAsset,
-            PreviewCaptureAsset, RawCaptureAsset, SessionCaptureRecord, SessionManifest,
-            CAPTURE_BUDGET_MS, PREVIEW_BUDGET_MS, SESSION_CAPTURE_SCHEMA_VERSION,
+            current_timestamp, ActivePresetBinding, CaptureProgramShot, CaptureTimingMetrics,
+            FinalCaptureAsset, FinalCaptureVariant, PreviewCaptureAsset, RawCaptureAsset,
+            SessionCaptureRecord, SessionManifest, CAPTURE_BUDGET_MS, PREVIEW_BUDGET_MS,
+            SESSION_CAPTURE_SCHEMA_VERSION, SHOT_LAYOUT_STATUS_COMPOSED,
+            SHOT_LAYOUT_STATUS_COMPOSE_FAILED, SHOT_LAYOUT_STATUS_FILLED,
         },
         session_paths::SessionPaths,
-        session_repository::{read_session_manifest, write_session_manifest},
+        session_repository::read_session_manifest,
     },
     timing::sync_session_timing_in_dir,
 };
@@ -60,6 +72,7 @@ pub fn persist_capture_in_dir(
     fast_preview: Option<CompletedCaptureFastPreview>,
     acknowledged_at_ms: u64,
     persisted_at_ms: u64,
+    program_shot: Option<&CaptureProgramShot>,
 ) -> Result<
     (
         SessionManifest,
@@ -88,6 +101,7 @@ pub fn persist_capture_in_dir(
         acknowledged_at_ms,
         persisted_at_ms,
     );
+    capture.capture_program = program_shot.cloned();
     let promoted_fast_preview = fast_preview.as_ref().and_then(|handoff| {
         promote_fast_preview_asset(
             &paths,
@@ -123,11 +137,16 @@ pub fn persist_capture_in_dir(
                 visible_at_ms: promoted.visible_at_ms.unwrap_or(persisted_at_ms),
             });
 
+    assign_capture_to_shot_layout(base_dir, &mut manifest, &capture);
     manifest.captures.push(capture.clone());
     manifest.updated_at = current_timestamp(SystemTime::now())?;
     manifest.lifecycle.stage = "preview-waiting".into();
 
-    write_session_manifest(&paths.manifest_path, &manifest)?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::capture_saved(&manifest, &capture),
+    )?;
 
     Ok((manifest, capture, fast_preview_update))
 }
@@ -187,7 +206,6 @@ pub fn start_speculative_preview_render_in_dir(
 
     let speculative_output_path = speculative_preview_output_path(&paths, capture_id);
     let speculative_lock_path = speculative_preview_lock_path(&paths, capture_id, request_id);
-    let speculative_detail_path = speculative_preview_detail_path(&paths, capture_id, request_id);
 
     if is_valid_render_preview_asset(&speculative_output_path) || speculative_lock_path.exists() {
         return;
@@ -203,54 +221,25 @@ pub fn start_speculative_preview_render_in_dir(
         return;
     }
 
-    let base_dir = base_dir.to_path_buf();
-    let session_id = session_id.to_string();
-    let request_id = request_id.to_string();
-    let capture_id = capture_id.to_string();
-    let preset_id = preset_id.to_string();
-    let preset_version = preset_version.to_string();
-    let source_path = source_path;
-
-    thread::spawn(move || {
-        log_render_start_in_dir(
-            &base_dir,
-            &session_id,
-            &capture_id,
-            &request_id,
-            RenderIntent::Preview,
-        );
-
-        let render_result = render_preview_asset_to_path_in_dir(
-            &base_dir,
-            &session_id,
-            &request_id,
-            &capture_id,
-            &preset_id,
-            &preset_version,
-            &source_path,
-            &speculative_output_path,
-        );
-
-        match render_result {
-            Ok(prepared_render) => {
-                let _ = fs::write(&speculative_detail_path, prepared_render.detail);
-            }
-            Err(error) => {
-                log::warn!(
-                    "speculative_preview_render_failed session={} capture_id={} request_id={} reason_code={} detail={}",
-                    session_id,
-                    capture_id,
-                    request_id,
-                    error.reason_code,
-                    error.operator_detail
-                );
-                let _ = fs::remove_file(&speculative_output_path);
-                let _ = fs::remove_file(&speculative_detail_path);
-            }
-        }
-
+    let enqueued = enqueue_render_job_in_dir(
+        base_dir,
+        RenderJobRequest {
+            kind: RenderJobKind::FastPreview,
+            session_id: session_id.into(),
+            capture_id: capture_id.into(),
+            request_id: request_id.into(),
+            preset_id: Some(preset_id.into()),
+            preset_version: Some(preset_version.into()),
+            source_asset_path: Some(source_path.to_string_lossy().into_owned()),
+        },
+        current_time_ms().unwrap_or_default(),
+    );
+    if enqueued.is_err() {
         let _ = fs::remove_file(&speculative_lock_path);
-    });
+        return;
+    }
+
+    ensure_render_job_workers_in_dir(base_dir, execute_render_job_in_dir);
 }
 
 pub fn complete_preview_render_in_dir(
@@ -292,11 +281,7 @@ pub fn complete_preview_render_in_dir(
             &mut manifest,
             capture_index,
         )? {
-            spawn_preview_raw_refinement_in_dir(
-                base_dir.to_path_buf(),
-                session_id.to_string(),
-                capture_id.to_string(),
-            );
+            enqueue_preview_raw_refinement_in_dir(base_dir, &speculative_capture);
             return Ok(speculative_capture);
         }
 
@@ -432,6 +417,8 @@ fn complete_speculative_preview_render_in_dir(
                     Some(canonical_preview_path.to_string_lossy().into_owned());
                 capture.preview.ready_at_ms = Some(preview_visible_at_ms);
                 capture.render_status = "previewReady".into();
+                capture.render_backend =
+                    render_backend_from_detail(&render_detail).map(str::to_string);
                 capture.timing.preview_visible_at_ms = Some(preview_visible_at_ms);
                 capture.timing.xmp_preview_ready_at_ms = None;
                 capture.timing.preview_budget_state = if preview_visible_at_ms
@@ -448,7 +435,11 @@ fn complete_speculative_preview_render_in_dir(
 
             manifest.updated_at = current_timestamp(SystemTime::now())?;
             manifest.lifecycle.stage = derive_capture_lifecycle_stage(manifest);
-            write_session_manifest(&paths.manifest_path, manifest)?;
+            commit_session_manifest(
+                &paths.manifest_path,
+                manifest,
+                SessionJournalEvent::preview_ready(&capture),
+            )?;
 
             let _ = fs::remove_file(speculative_preview_detail_path(
                 paths,
@@ -481,86 +472,244 @@ fn complete_speculative_preview_render_in_dir(
     Ok(None)
 }
 
-fn spawn_preview_raw_refinement_in_dir(base_dir: PathBuf, session_id: String, capture_id: String) {
-    thread::spawn(move || {
-        let paths = match SessionPaths::try_new(&base_dir, &session_id) {
-            Ok(paths) => paths,
-            Err(_) => return,
-        };
+fn enqueue_preview_raw_refinement_in_dir(base_dir: &Path, capture: &SessionCaptureRecord) {
+    let enqueued = enqueue_render_job_in_dir(
+        base_dir,
+        RenderJobRequest {
+            kind: RenderJobKind::PreviewRefinement,
+            session_id: capture.session_id.clone(),
+            capture_id: capture.capture_id.clone(),
+            request_id: capture.request_id.clone(),
+            preset_id: None,
+            preset_version: None,
+            source_asset_path: None,
+        },
+        current_time_ms().unwrap_or_default(),
+    );
+
+    match enqueued {
+        Ok(_) => ensure_render_job_workers_in_dir(base_dir, execute_render_job_in_dir),
+        Err(error) => log::warn!(
+            "capture_preview_refinement_enqueue_failed session={} capture_id={} detail={}",
+            capture.session_id,
+            capture.capture_id,
+            error.message
+        ),
+    }
+}
 
-        wait_for_capture_round_trip_idle(&base_dir);
+/// Requeues render jobs interrupted by a previous shutdown and starts workers
+/// for them. Called once at startup.
+pub fn resume_pending_render_jobs_in_dir(base_dir: &Path) -> Result<usize, HostErrorEnvelope> {
+    let pending = resume_render_jobs_in_dir(base_dir, current_time_ms().unwrap_or_default())?;
 
-        let capture_snapshot = {
-            let _pipeline_guard = match CAPTURE_PIPELINE_LOCK.lock() {
-                Ok(guard) => guard,
-                Err(_) => return,
-            };
-            let manifest = match read_session_manifest(&paths.manifest_path) {
-                Ok(manifest) => manifest,
-                Err(_) => return,
-            };
-            let Some(capture) = manifest
-                .captures
-                .iter()
-                .find(|capture| capture.capture_id == capture_id)
-                .cloned()
-            else {
-                return;
+    if pending > 0 {
+        log::info!("render_jobs_resumed pending={pending}");
+        ensure_render_job_workers_in_dir(base_dir, execute_render_job_in_dir);
+    }
+
+    Ok(pending)
+}
+
+/// Runs the capture's final render through the durable queue, inline when the
+/// job is due. A job that is backing off or running elsewhere is left to the
+/// workers, and the caller sees the capture unchanged until it lands.
+pub fn run_final_render_job_in_dir(
+    base_dir: &Path,
+    capture: &SessionCaptureRecord,
+) -> Result<(), HostErrorEnvelope> {
+    let now_ms = current_time_ms().unwrap_or_default();
+    let job = enqueue_render_job_in_dir(
+        base_dir,
+        RenderJobRequest {
+            kind: RenderJobKind::Final,
+            session_id: capture.session_id.clone(),
+            capture_id: capture.capture_id.clone(),
+            request_id: capture.request_id.clone(),
+            preset_id: None,
+            preset_version: None,
+            source_asset_path: None,
+        },
+        now_ms,
+    )?;
+
+    if let Some(claimed) = claim_render_job_in_dir(base_dir, &job.job_id, now_ms)? {
+        run_render_job(base_dir, &claimed, execute_render_job_in_dir)?;
+    }
+    ensure_render_job_workers_in_dir(base_dir, execute_render_job_in_dir);
+
+    Ok(())
+}
+
+fn execute_render_job_in_dir(
+    base_dir: &Path,
+    job: &RenderJobRecord,
+) -> Result<(), RenderWorkerError> {
+    match job.kind {
+        RenderJobKind::FastPreview => {
+            execute_speculative_preview_job_in_dir(base_dir, job);
+            Ok(())
+        }
+        RenderJobKind::PreviewRefinement => execute_preview_refinement_job_in_dir(base_dir, job),
+        RenderJobKind::Final => {
+            let result = complete_final_render_in_dir(base_dir, &job.session_id, &job.capture_id);
+            let Err(error) = result else {
+                return Ok(());
             };
 
-            if !matches!(
-                capture.render_status.as_str(),
-                "previewReady" | "finalReady"
-            ) || capture.timing.xmp_preview_ready_at_ms.is_some()
-            {
-                return;
+            if job.is_last_attempt() {
+                let _ = mark_final_render_failed_in_dir(base_dir, &job.session_id, &job.capture_id);
             }
 
-            capture
-        };
+            Err(RenderWorkerError {
+                reason_code: "final-render-failed",
+                customer_message: error.message.clone(),
+                operator_detail: error.message,
+            })
+        }
+    }
+}
 
-        log_render_start_in_dir(
-            &base_dir,
-            &session_id,
-            &capture_id,
-            &capture_snapshot.request_id,
-            RenderIntent::Preview,
-        );
-        let rendered_preview = match render_refined_preview_when_queue_allows(
-            &base_dir,
-            &session_id,
-            &capture_snapshot,
-        ) {
-            Ok(value) => value,
-            Err(error) => {
-                log::warn!(
-                        "capture_preview_refinement_failed session={} capture_id={} reason_code={} detail={}",
-                        session_id,
-                        capture_id,
-                        error.reason_code,
-                        error.operator_detail
-                    );
-                log_render_failure_in_dir(
-                    &base_dir,
-                    &session_id,
-                    &capture_id,
-                    Some(&capture_snapshot.request_id),
-                    RenderIntent::Preview,
-                    error.reason_code,
-                );
-                return;
-            }
+// Speculative output is only adopted within a short wait budget, so a failed
+// attempt is cleaned up rather than retried.
+fn execute_speculative_preview_job_in_dir(base_dir: &Path, job: &RenderJobRecord) {
+    let Ok(paths) = SessionPaths::try_new(base_dir, &job.session_id) else {
+        return;
+    };
+    let speculative_output_path = speculative_preview_output_path(&paths, &job.capture_id);
+    let speculative_lock_path =
+        speculative_preview_lock_path(&paths, &job.capture_id, &job.request_id);
+    let speculative_detail_path =
+        speculative_preview_detail_path(&paths, &job.capture_id, &job.request_id);
+    let (Some(preset_id), Some(preset_version), Some(source_path)) = (
+        job.preset_id.as_deref(),
+        job.preset_version.as_deref(),
+        job.source_asset_path.as_deref(),
+    ) else {
+        let _ = fs::remove_file(&speculative_lock_path);
+        return;
+    };
+
+    log_render_start_in_dir(
+        base_dir,
+        &job.session_id,
+        &job.capture_id,
+        &job.request_id,
+        RenderIntent::Preview,
+    );
+
+    let render_result = render_preview_asset_to_path_in_dir(
+        base_dir,
+        &job.session_id,
+        &job.request_id,
+        &job.capture_id,
+        preset_id,
+        preset_version,
+        Path::new(source_path),
+        &speculative_output_path,
+    );
+
+    match render_result {
+        Ok(prepared_render) => {
+            let _ = fs::write(&speculative_detail_path, prepared_render.detail);
+        }
+        Err(error) => {
+            log::warn!(
+                "speculative_preview_render_failed session={} capture_id={} request_id={} reason_code={} detail={}",
+                job.session_id,
+                job.capture_id,
+                job.request_id,
+                error.reason_code,
+                error.operator_detail
+            );
+            let _ = fs::remove_file(&speculative_output_path);
+            let _ = fs::remove_file(&speculative_detail_path);
+        }
+    }
+
+    let _ = fs::remove_file(&speculative_lock_path);
+}
+
+fn execute_preview_refinement_job_in_dir(
+    base_dir: &Path,
+    job: &RenderJobRecord,
+) -> Result<(), RenderWorkerError> {
+    let Ok(paths) = SessionPaths::try_new(base_dir, &job.session_id) else {
+        return Ok(());
+    };
+
+    wait_for_capture_round_trip_idle(base_dir);
+
+    let capture_snapshot = {
+        let Ok(_pipeline_guard) = CAPTURE_PIPELINE_LOCK.lock() else {
+            return Ok(());
+        };
+        let Ok(manifest) = read_session_manifest(&paths.manifest_path) else {
+            return Ok(());
+        };
+        let Some(capture) = manifest
+            .captures
+            .iter()
+            .find(|capture| capture.capture_id == job.capture_id)
+            .cloned()
+        else {
+            return Ok(());
         };
 
-        let _ = finish_preview_render_in_dir(
-            &base_dir,
-            &paths,
-            &session_id,
-            &capture_id,
-            rendered_preview,
-            true,
-        );
-    });
+        if !matches!(
+            capture.render_status.as_str(),
+            "previewReady" | "finalReady"
+        ) || capture.timing.xmp_preview_ready_at_ms.is_some()
+        {
+            return Ok(());
+        }
+
+        capture
+    };
+
+    log_render_start_in_dir(
+        base_dir,
+        &job.session_id,
+        &job.capture_id,
+        &capture_snapshot.request_id,
+        RenderIntent::Preview,
+    );
+    let rendered_preview = match render_refined_preview_when_queue_allows(
+        base_dir,
+        &job.session_id,
+        &capture_snapshot,
+    ) {
+        Ok(value) => value,
+        Err(error) => {
+            log::warn!(
+                "capture_preview_refinement_failed session={} capture_id={} reason_code={} detail={}",
+                job.session_id,
+                job.capture_id,
+                error.reason_code,
+                error.operator_detail
+            );
+            log_render_failure_in_dir(
+                base_dir,
+                &job.session_id,
+                &job.capture_id,
+                Some(&capture_snapshot.request_id),
+                RenderIntent::Preview,
+                error.reason_code,
+            );
+            return Err(error);
+        }
+    };
+
+    let _ = finish_preview_render_in_dir(
+        base_dir,
+        &paths,
+        &job.session_id,
+        &job.capture_id,
+        rendered_preview,
+        true,
+    );
+
+    Ok(())
 }
 
 fn wait_for_capture_round_trip_idle(base_dir: &Path) {
@@ -651,6 +800,7 @@ fn finish_preview_render_in_dir(
         capture.preview.ready_at_ms = Some(preview_ready_at_ms);
         if capture.render_status != "finalReady" {
             capture.render_status = "previewReady".into();
+            capture.render_backend = Some(rendered_preview.render_backend.into());
         }
         capture.timing.preview_visible_at_ms = Some(preview_visible_at_ms);
         capture.timing.xmp_preview_ready_at_ms = Some(preview_ready_at_ms);
@@ -668,7 +818,11 @@ fn finish_preview_render_in_dir(
 
     manifest.updated_at = current_timestamp(SystemTime::now())?;
     manifest.lifecycle.stage = derive_capture_lifecycle_stage(&manifest);
-    write_session_manifest(&paths.manifest_path, &manifest)?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::preview_ready(&capture),
+    )?;
 
     if preserve_first_visible_at_ms {
         log::info!(
@@ -708,8 +862,9 @@ fn sync_helper_fast_preview_before_render(
             if capture.timing.fast_preview_visible_at_ms.is_none() {
                 capture.timing.fast_preview_visible_at_ms = promoted_fast_preview.visible_at_ms;
             }
+            let event = SessionJournalEvent::preview_ready(capture);
             manifest.updated_at = current_timestamp(SystemTime::now())?;
-            write_session_manifest(&paths.manifest_path, manifest)?;
+            commit_session_manifest(&paths.manifest_path, manifest, event)?;
             return Ok(());
         }
 
@@ -801,6 +956,18 @@ pub fn complete_final_render_in_dir(
         }
     };
 
+    let variants = render_final_output_variants_in_dir(base_dir, session_id, &capture_snapshot)
+        .into_iter()
+        .map(|variant| FinalCaptureVariant {
+            variant_id: variant.variant_id,
+            asset_path: variant.asset_path,
+            format: variant.format.into(),
+            color_space: variant.color_space,
+            purposes: variant.purposes,
+            ready_at_ms: variant.ready_at_ms,
+        })
+        .collect();
+
     let capture = {
         let capture = manifest
             .captures
@@ -809,7 +976,9 @@ pub fn complete_final_render_in_dir(
 
         capture.final_asset.asset_path = Some(rendered_final.asset_path);
         capture.final_asset.ready_at_ms = Some(rendered_final.ready_at_ms);
+        capture.final_asset.variants = variants;
         capture.render_status = "finalReady".into();
+        capture.render_backend = Some(rendered_final.render_backend.into());
         capture.post_end_state = "handoffReady".into();
 
         capture.clone()
@@ -817,11 +986,80 @@ pub fn complete_final_render_in_dir(
 
     manifest.updated_at = current_timestamp(SystemTime::now())?;
     manifest.lifecycle.stage = "capture-ready".into();
-    write_session_manifest(&paths.manifest_path, &manifest)?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::final_ready(&capture),
+    )?;
 
     Ok(capture)
 }
 
+/// Composes the shot layout final once every slot capture has its final.
+/// Before that, or after a composition attempt, the manifest is returned
+/// unchanged.
+pub fn complete_shot_layout_final_in_dir(
+    base_dir: &Path,
+    session_id: &str,
+) -> Result<SessionManifest, HostErrorEnvelope> {
+    let paths = SessionPaths::try_new(base_dir, session_id)?;
+    let _pipeline_guard = CAPTURE_PIPELINE_LOCK.lock().map_err(|_| {
+        HostErrorEnvelope::persistence(
+            "최종 결과 상태를 잠그지 못했어요. 잠시 후 다시 시도해 주세요.",
+        )
+    })?;
+    let mut manifest = read_session_manifest(&paths.manifest_path)?;
+    let Some(layout) = manifest
+        .shot_layout
+        .clone()
+        .filter(|layout| layout.status == SHOT_LAYOUT_STATUS_FILLED)
+    else {
+        return Ok(manifest);
+    };
+    let slot_finals_ready = layout.slot_capture_ids().all(|capture_id| {
+        manifest.captures.iter().any(|capture| {
+            capture.capture_id == capture_id
+                && capture.render_status == "finalReady"
+                && capture.final_asset.asset_path.is_some()
+        })
+    });
+    if !slot_finals_ready {
+        return Ok(manifest);
+    }
+
+    let composed =
+        render_shot_layout_final_in_dir(base_dir, session_id, &layout, &manifest.captures);
+    let layout = manifest
+        .shot_layout
+        .as_mut()
+        .expect("shot layout already resolved");
+    match composed {
+        Ok(composed_final) => {
+            layout.status = SHOT_LAYOUT_STATUS_COMPOSED.into();
+            layout.composed_final = Some(composed_final);
+        }
+        Err(error) => {
+            log::warn!(
+                "shot_layout_compose_failed session={} layout_id={} reason_code={} detail={}",
+                session_id,
+                layout.layout_id,
+                error.reason_code,
+                error.operator_detail
+            );
+            layout.status = SHOT_LAYOUT_STATUS_COMPOSE_FAILED.into();
+            layout.composed_final = None;
+        }
+    }
+    manifest.updated_at = current_timestamp(SystemTime::now())?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::manifest_updated("shot-layout-composed", &manifest),
+    )?;
+
+    Ok(manifest)
+}
+
 pub fn mark_preview_render_failed_in_dir(
     base_dir: &Path,
     session_id: &str,
@@ -861,7 +1099,20 @@ fn mark_render_failed_in_dir(
         return Ok(manifest);
     };
 
-    if latest_capture.capture_id != capture_id {
+    // Every shot layout slot and every selected capture is delivered, so a
+    // failed final matters even when it is not the latest capture.
+    let is_shot_layout_slot = manifest.shot_layout.as_ref().is_some_and(|layout| {
+        layout
+            .slot_capture_ids()
+            .any(|slot_capture_id| slot_capture_id == capture_id)
+    });
+    let is_selected = manifest
+        .selected_captures()
+        .iter()
+        .any(|capture| capture.capture_id == capture_id);
+    if latest_capture.capture_id != capture_id
+        && !(intent == RenderIntent::Final && (is_shot_layout_slot || is_selected))
+    {
         return Ok(manifest);
     }
 
@@ -891,7 +1142,17 @@ fn mark_render_failed_in_dir(
         },
     );
 
-    write_session_manifest(&paths.manifest_path, &manifest)?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::render_failed(
+            match intent {
+                RenderIntent::Preview => "preview",
+                RenderIntent::Final => "final",
+            },
+            &manifest.captures[capture_index],
+        ),
+    )?;
 
     Ok(manifest)
 }
@@ -926,8 +1187,10 @@ fn build_saved_capture_record(
         final_asset: FinalCaptureAsset {
             asset_path: None,
             ready_at_ms: None,
+            variants: Vec::new(),
         },
         render_status: "previewWaiting".into(),
+        render_backend: None,
         post_end_state: "activeSession".into(),
         timing: CaptureTimingMetrics {
             capture_acknowledged_at_ms: acknowledged_at_ms,
@@ -938,6 +1201,7 @@ fn build_saved_capture_record(
             preview_budget_ms: PREVIEW_BUDGET_MS,
             preview_budget_state: "pending".into(),
         },
+        capture_program: None,
     }
 }
 
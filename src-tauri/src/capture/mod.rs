@@ -3,9 +3,15 @@ use std::{
     sync::{LazyLock, Mutex},
 };
 
+pub mod camera_backend;
+pub mod capture_program;
+pub mod capture_selection;
+pub mod helper_simulator;
 pub mod helper_supervisor;
+pub mod hot_folder_backend;
 pub mod ingest_pipeline;
 pub mod normalized_state;
+pub mod shot_layout;
 pub mod sidecar_client;
 
 pub(crate) static CAPTURE_PIPELINE_LOCK: Mutex<()> = Mutex::new(());
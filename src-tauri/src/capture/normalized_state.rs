@@ -7,18 +7,18 @@ use std::{
 
 use crate::{
     capture::{
+        camera_backend::{
+            resolve_camera_backend, CameraBackend, CameraBackendFault, CameraCaptureRequest,
+            CameraStatusSnapshot,
+        },
+        capture_selection::{project_capture_selection, release_capture_selection},
         ingest_pipeline::{
             complete_preview_render_in_dir, persist_capture_in_dir,
             promote_pending_fast_preview_in_dir,
         },
+        shot_layout::release_shot_layout_capture,
         sidecar_client::{
-            is_retryable_capture_helper_error, map_capture_round_trip_error,
-            read_capture_event_count, read_capture_request_messages,
-            read_latest_helper_error_message, read_latest_status_message,
-            read_processed_capture_request_ids, wait_for_capture_round_trip,
-            write_capture_request_message, CanonHelperCaptureRequestMessage,
-            CanonHelperStatusMessage, FastPreviewReadyUpdate, SidecarClientError,
-            CANON_HELPER_CAPTURE_REQUEST_SCHEMA_VERSION,
+            map_capture_round_trip_error, FastPreviewReadyUpdate, SidecarClientError,
         },
         CAPTURE_PIPELINE_LOCK, IN_FLIGHT_CAPTURE_SESSIONS,
     },
@@ -32,12 +32,14 @@ use crate::{
     preset::preset_catalog::{find_published_preset_summary, resolve_published_preset_catalog_dir},
     render::is_valid_render_preview_asset,
     session::{
+        session_journal::{commit_session_manifest, SessionJournalEvent},
         session_manifest::{
-            current_timestamp, rfc3339_to_unix_seconds, ActivePresetBinding, SessionCaptureRecord,
-            SessionManifest, SESSION_POST_END_COMPLETED, SESSION_POST_END_PHONE_REQUIRED,
+            current_timestamp, rfc3339_to_unix_seconds, ActivePresetBinding, CaptureProgramShot,
+            SessionCaptureRecord, SessionManifest, SESSION_POST_END_COMPLETED,
+            SESSION_POST_END_PHONE_REQUIRED,
         },
         session_paths::SessionPaths,
-        session_repository::{read_session_manifest, write_session_manifest},
+        session_repository::read_session_manifest,
     },
     timing::{
         append_session_timing_event_in_dir, sync_session_timing_in_dir, SessionTimingEventInput,
@@ -111,6 +113,41 @@ where
     }
 
     let in_flight_guard = acquire_in_flight_capture_guard(base_dir, &input.session_id)?;
+    let (_in_flight_guard, manifest, capture) = run_capture_round_trip(
+        base_dir,
+        &input,
+        &readiness,
+        in_flight_guard,
+        None,
+        &mut on_fast_preview_ready,
+    )?;
+
+    Ok(CaptureRequestResultDto {
+        schema_version: "capture-request-result/v1".into(),
+        session_id: input.session_id,
+        status: "capture-saved".into(),
+        capture: capture.clone(),
+        readiness: CaptureReadinessDto::capture_saved(manifest.session_id.clone(), capture)
+            .with_timing(manifest.timing.clone())
+            .with_live_capture_truth(project_live_capture_truth(base_dir, &manifest).dto),
+    })
+}
+
+/// One helper round trip: submit the request, wait for the RAW and persist
+/// the capture. The guard is released before failure readiness is projected
+/// and handed back on success so capture programs can keep holding it.
+pub(crate) fn run_capture_round_trip<F>(
+    base_dir: &Path,
+    input: &CaptureRequestInputDto,
+    readiness: &CaptureReadinessDto,
+    in_flight_guard: InFlightCaptureGuard,
+    program_shot: Option<&CaptureProgramShot>,
+    on_fast_preview_ready: &mut F,
+) -> Result<(InFlightCaptureGuard, SessionManifest, SessionCaptureRecord), HostErrorEnvelope>
+where
+    F: FnMut(FastPreviewReadyUpdate),
+{
+    let camera_backend = resolve_camera_backend();
     let manifest = read_session_manifest_with_timing(base_dir, &input.session_id)?;
     let active_preset = manifest.active_preset.clone().ok_or_else(|| {
         HostErrorEnvelope::preset_not_available("촬영 전에 룩을 다시 골라 주세요.")
@@ -122,25 +159,31 @@ where
         .filter(|value| !value.is_empty())
         .map(str::to_string)
         .unwrap_or_else(generate_capture_request_id);
-    ensure_capture_request_id_is_fresh(base_dir, &input.session_id, &request_id, &readiness)?;
+    ensure_capture_request_id_is_fresh(
+        camera_backend,
+        base_dir,
+        &input.session_id,
+        &request_id,
+        readiness,
+    )?;
     let requested_at = current_timestamp(SystemTime::now())?;
-    let request_message = CanonHelperCaptureRequestMessage {
-        schema_version: CANON_HELPER_CAPTURE_REQUEST_SCHEMA_VERSION.into(),
-        message_type: "request-capture".into(),
+    let capture_request = CameraCaptureRequest {
         session_id: input.session_id.clone(),
         request_id: request_id.clone(),
         requested_at,
         active_preset_id: active_preset.preset_id.clone(),
         active_preset_version: active_preset.published_version.clone(),
     };
-    let starting_event_count = read_capture_event_count(base_dir, &input.session_id)
+    let starting_event_cursor = camera_backend
+        .capture_event_cursor(base_dir, &input.session_id)
         .map_err(|error| map_capture_round_trip_error(&input.session_id, error))?;
     let fast_preview_base_dir = base_dir.to_path_buf();
     let fast_preview_session_id = input.session_id.clone();
     let fast_preview_request_id = request_id.clone();
     let mut early_fast_preview_update = None;
 
-    write_capture_request_message(base_dir, &request_message)
+    camera_backend
+        .submit_capture_request(base_dir, &capture_request)
         .map_err(|error| map_capture_round_trip_error(&input.session_id, error))?;
     let _ = append_session_timing_event_in_dir(
         base_dir,
@@ -156,12 +199,11 @@ where
         },
     );
 
-    let round_trip = match wait_for_capture_round_trip(
+    let round_trip = match camera_backend.wait_for_capture(
         base_dir,
-        &input.session_id,
-        &request_id,
-        starting_event_count,
-        |fast_preview| {
+        &capture_request,
+        starting_event_cursor,
+        &mut |fast_preview| {
             if early_fast_preview_update.is_none() {
                 if let Some(update) = promote_pending_fast_preview_in_dir(
                     &fast_preview_base_dir,
@@ -235,13 +277,14 @@ where
     );
     let (manifest, capture, fast_preview_update) = persist_capture_in_dir(
         base_dir,
-        &input,
+        input,
         round_trip.capture_id,
         request_id.clone(),
         round_trip.raw_path,
         round_trip.fast_preview,
         round_trip.capture_accepted_at_ms,
         round_trip.persisted_at_ms,
+        program_shot,
     )
     .map_err(|error| {
         log::warn!(
@@ -273,15 +316,7 @@ where
         }
     }
 
-    Ok(CaptureRequestResultDto {
-        schema_version: "capture-request-result/v1".into(),
-        session_id: input.session_id,
-        status: "capture-saved".into(),
-        capture: capture.clone(),
-        readiness: CaptureReadinessDto::capture_saved(manifest.session_id.clone(), capture)
-            .with_timing(manifest.timing.clone())
-            .with_live_capture_truth(project_live_capture_truth(base_dir, &manifest).dto),
-    })
+    Ok((in_flight_guard, manifest, capture))
 }
 
 fn should_persist_capture_round_trip_failure(error: &SidecarClientError) -> bool {
@@ -299,30 +334,21 @@ fn should_persist_capture_round_trip_failure(error: &SidecarClientError) -> bool
 }
 
 fn ensure_capture_request_id_is_fresh(
+    camera_backend: &dyn CameraBackend,
     base_dir: &Path,
     session_id: &str,
     request_id: &str,
     readiness: &CaptureReadinessDto,
 ) -> Result<(), HostErrorEnvelope> {
-    let existing_requests = read_capture_request_messages(base_dir, session_id).map_err(|_| {
-        HostErrorEnvelope::persistence(
-            "촬영 요청 기록을 확인하지 못했어요. 가까운 직원에게 알려 주세요.",
-        )
-    })?;
-    let processed_request_ids =
-        read_processed_capture_request_ids(base_dir, session_id).map_err(|_| {
+    let is_known = camera_backend
+        .is_capture_request_known(base_dir, session_id, request_id)
+        .map_err(|_| {
             HostErrorEnvelope::persistence(
                 "촬영 요청 기록을 확인하지 못했어요. 가까운 직원에게 알려 주세요.",
             )
         })?;
 
-    if existing_requests
-        .iter()
-        .any(|request| request.request_id == request_id)
-        || processed_request_ids
-            .iter()
-            .any(|processed| processed == request_id)
-    {
+    if is_known {
         return Err(HostErrorEnvelope::capture_not_ready(
             "촬영 요청을 다시 보내 주세요.",
             readiness.clone(),
@@ -427,7 +453,11 @@ fn persist_capture_round_trip_failure(
     manifest.lifecycle.stage = "phone-required".into();
     manifest.post_end = None;
     manifest.updated_at = occurred_at.clone();
-    write_session_manifest(&paths.manifest_path, &manifest)?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::manifest_updated("capture-round-trip-failed", &manifest),
+    )?;
 
     try_append_operator_audit_record(
         base_dir,
@@ -506,15 +536,12 @@ fn sync_retryable_capture_failure_recovery_in_manifest(
         return Ok(());
     }
 
-    let Some(latest_helper_error) =
-        read_latest_helper_error_message(base_dir, &manifest.session_id)
-            .ok()
-            .flatten()
+    let Some(latest_fault) = resolve_camera_backend().latest_fault(base_dir, &manifest.session_id)
     else {
         return Ok(());
     };
 
-    if !helper_error_allows_session_recovery_after_ready(&latest_helper_error) {
+    if !fault_allows_session_recovery_after_ready(&latest_fault) {
         return Ok(());
     }
 
@@ -527,27 +554,29 @@ fn sync_retryable_capture_failure_recovery_in_manifest(
     let paths = SessionPaths::try_new(base_dir, &manifest.session_id)?;
     manifest.lifecycle.stage = next_stage.clone();
     manifest.updated_at = current_timestamp(SystemTime::now())?;
-    write_session_manifest(&paths.manifest_path, manifest)?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        manifest,
+        SessionJournalEvent::manifest_updated("capture-retry-recovered", manifest),
+    )?;
 
     log::info!(
         "capture_retry_recovered session={} previous_stage={} next_stage={} detail_code={} live_truth=fresh:matched:ready:healthy",
         manifest.session_id,
         previous_stage,
         next_stage,
-        latest_helper_error.detail_code
+        latest_fault.detail_code
     );
 
     Ok(())
 }
 
-fn helper_error_allows_session_recovery_after_ready(
-    message: &crate::capture::sidecar_client::CanonHelperErrorMessage,
-) -> bool {
-    if is_retryable_capture_helper_error(message) {
+fn fault_allows_session_recovery_after_ready(fault: &CameraBackendFault) -> bool {
+    if fault.retryable {
         return true;
     }
 
-    matches!(message.detail_code.as_str(), "capture-download-timeout")
+    matches!(fault.detail_code.as_str(), "capture-download-timeout")
 }
 
 fn sync_recoverable_render_failure_in_manifest(
@@ -646,10 +675,16 @@ pub fn delete_capture_in_dir(
     let staged_assets = stage_capture_asset_deletions(&paths, &capture)?;
 
     manifest.captures.remove(capture_index);
+    release_shot_layout_capture(&mut manifest, &capture.capture_id);
+    release_capture_selection(&mut manifest, &capture.capture_id);
     manifest.lifecycle.stage = derive_capture_lifecycle_stage(&manifest);
     manifest.updated_at =
         crate::session::session_manifest::current_timestamp(std::time::SystemTime::now())?;
-    if let Err(error) = write_session_manifest(&paths.manifest_path, &manifest) {
+    if let Err(error) = commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::capture_deleted(&manifest, &capture.capture_id),
+    ) {
         rollback_staged_asset_deletions(&staged_assets);
         return Err(error);
     }
@@ -669,6 +704,11 @@ pub fn normalize_capture_readiness(
     base_dir: &Path,
     manifest: &SessionManifest,
 ) -> CaptureReadinessDto {
+    project_capture_readiness(base_dir, manifest)
+        .with_selection(project_capture_selection(manifest))
+}
+
+fn project_capture_readiness(base_dir: &Path, manifest: &SessionManifest) -> CaptureReadinessDto {
     let timing = manifest.timing.clone();
     let latest_capture = manifest.captures.last().cloned();
     let timing_phase = timing_phase(timing.as_ref());
@@ -933,11 +973,14 @@ fn project_live_capture_truth(
     base_dir: &Path,
     manifest: &SessionManifest,
 ) -> ProjectedLiveCaptureTruth {
-    match read_latest_status_message(base_dir, &manifest.session_id) {
-        Ok(Some(status)) => project_live_capture_truth_from_status(manifest, status),
+    let camera_backend = resolve_camera_backend();
+    let source = camera_backend.live_truth_source();
+
+    match camera_backend.read_status(base_dir, &manifest.session_id) {
+        Ok(Some(status)) => project_live_capture_truth_from_status(source, manifest, status),
         Ok(None) => ProjectedLiveCaptureTruth {
             dto: LiveCaptureTruthDto {
-                source: "canon-helper-sidecar".into(),
+                source: source.into(),
                 freshness: "missing".into(),
                 session_match: "unknown".into(),
                 camera_state: "unknown".into(),
@@ -950,7 +993,7 @@ fn project_live_capture_truth(
         },
         Err(SidecarClientError::InvalidStatus) => ProjectedLiveCaptureTruth {
             dto: LiveCaptureTruthDto {
-                source: "canon-helper-sidecar".into(),
+                source: source.into(),
                 freshness: "missing".into(),
                 session_match: "unknown".into(),
                 camera_state: "unknown".into(),
@@ -963,7 +1006,7 @@ fn project_live_capture_truth(
         },
         Err(SidecarClientError::StatusUnreadable) => ProjectedLiveCaptureTruth {
             dto: LiveCaptureTruthDto {
-                source: "canon-helper-sidecar".into(),
+                source: source.into(),
                 freshness: "missing".into(),
                 session_match: "unknown".into(),
                 camera_state: "unknown".into(),
@@ -976,7 +1019,7 @@ fn project_live_capture_truth(
         },
         Err(_) => ProjectedLiveCaptureTruth {
             dto: LiveCaptureTruthDto {
-                source: "canon-helper-sidecar".into(),
+                source: source.into(),
                 freshness: "missing".into(),
                 session_match: "unknown".into(),
                 camera_state: "unknown".into(),
@@ -991,8 +1034,9 @@ fn project_live_capture_truth(
 }
 
 fn project_live_capture_truth_from_status(
+    source: &str,
     manifest: &SessionManifest,
-    status: CanonHelperStatusMessage,
+    status: CameraStatusSnapshot,
 ) -> ProjectedLiveCaptureTruth {
     let freshness = if is_fresh_helper_status(&status) {
         "fresh"
@@ -1014,7 +1058,7 @@ fn project_live_capture_truth_from_status(
 
     ProjectedLiveCaptureTruth {
         dto: LiveCaptureTruthDto {
-            source: "canon-helper-sidecar".into(),
+            source: source.into(),
             freshness: freshness.into(),
             session_match: session_match.into(),
             camera_state: camera_state.into(),
@@ -1027,7 +1071,7 @@ fn project_live_capture_truth_from_status(
     }
 }
 
-fn is_fresh_helper_status(status: &CanonHelperStatusMessage) -> bool {
+fn is_fresh_helper_status(status: &CameraStatusSnapshot) -> bool {
     let Ok(observed_at_seconds) = rfc3339_to_unix_seconds(&status.observed_at) else {
         return false;
     };
@@ -1174,6 +1218,16 @@ fn stage_capture_asset_deletions(
         )?;
     }
 
+    for variant in &capture.final_asset.variants {
+        stage_session_scoped_asset_if_present(
+            paths,
+            &variant.asset_path,
+            &capture.capture_id,
+            &format!("final-{}", variant.variant_id),
+            &mut staged_assets,
+        )?;
+    }
+
     Ok(staged_assets)
 }
 
@@ -1355,7 +1409,11 @@ fn sync_better_preview_assets_in_manifest(
 
     if updated {
         manifest.updated_at = current_timestamp(SystemTime::now())?;
-        write_session_manifest(&paths.manifest_path, manifest)?;
+        commit_session_manifest(
+            &paths.manifest_path,
+            manifest,
+            SessionJournalEvent::manifest_updated("better-preview-promoted", manifest),
+        )?;
     }
 
     Ok(())
@@ -1438,8 +1496,7 @@ fn sync_invalid_preview_truth_in_manifest(
         else {
             capture.preview.asset_path = None;
             capture.preview.ready_at_ms = None;
-            capture.final_asset.asset_path = None;
-            capture.final_asset.ready_at_ms = None;
+            capture.final_asset.clear();
             capture.render_status = "previewWaiting".into();
             updated = true;
             repair_targets.push(capture.capture_id.clone());
@@ -1456,7 +1513,11 @@ fn sync_invalid_preview_truth_in_manifest(
 
     manifest.lifecycle.stage = derive_capture_lifecycle_stage(manifest);
     manifest.updated_at = current_timestamp(SystemTime::now())?;
-    write_session_manifest(&paths.manifest_path, manifest)?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        manifest,
+        SessionJournalEvent::manifest_updated("invalid-preview-reset", manifest),
+    )?;
 
     for capture_id in repair_targets {
         match complete_preview_render_in_dir(base_dir, &manifest.session_id, &capture_id) {
@@ -1510,7 +1571,7 @@ fn has_in_flight_capture(base_dir: &Path) -> bool {
         .unwrap_or(false)
 }
 
-fn acquire_in_flight_capture_guard(
+pub(crate) fn acquire_in_flight_capture_guard(
     base_dir: &Path,
     session_id: &str,
 ) -> Result<InFlightCaptureGuard, HostErrorEnvelope> {
@@ -1534,7 +1595,7 @@ fn acquire_in_flight_capture_guard(
     })
 }
 
-struct InFlightCaptureGuard {
+pub(crate) struct InFlightCaptureGuard {
     runtime_key: String,
     session_id: String,
 }
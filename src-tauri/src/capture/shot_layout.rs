@@ -0,0 +1,166 @@
+//! Slot bookkeeping for multi-shot layout sessions (photo strips, grids).
+//! Callers mutating a manifest here already hold `CAPTURE_PIPELINE_LOCK`.
+
+use std::{path::Path, time::SystemTime};
+
+use crate::{
+    capture::CAPTURE_PIPELINE_LOCK,
+    contracts::dto::{HostErrorEnvelope, ShotLayoutRetakeInputDto, ShotLayoutRetakeResultDto},
+    preset::preset_catalog::{
+        find_published_preset_runtime_bundle, resolve_published_preset_catalog_dir,
+    },
+    session::{
+        session_journal::{commit_session_manifest, SessionJournalEvent},
+        session_manifest::{
+            current_timestamp, SessionCaptureRecord, SessionManifest, SessionShotLayout,
+            SessionShotSlot, SHOT_LAYOUT_STATUS_FILLING,
+        },
+        session_paths::SessionPaths,
+        session_repository::read_session_manifest,
+    },
+    timing::sync_session_timing_in_dir,
+};
+
+const SHOT_LAYOUT_RETAKE_RESULT_SCHEMA_VERSION: &str = "shot-layout-retake-result/v1";
+
+/// Puts a freshly saved capture into the armed retake slot or the first empty
+/// one. The first capture of a session whose preset declares a shot layout
+/// starts the layout. Returns the slot the capture landed in, if any.
+pub fn assign_capture_to_shot_layout(
+    base_dir: &Path,
+    manifest: &mut SessionManifest,
+    capture: &SessionCaptureRecord,
+) -> Option<String> {
+    if manifest.shot_layout.is_none() {
+        manifest.shot_layout = start_shot_layout(base_dir, capture);
+    }
+    let layout = manifest.shot_layout.as_mut()?;
+
+    let retake_slot_id = layout.retake_slot_id.take();
+    let slot = match retake_slot_id {
+        Some(slot_id) => layout.slots.iter_mut().find(|slot| slot.slot_id == slot_id),
+        None => layout
+            .slots
+            .iter_mut()
+            .find(|slot| slot.capture_id.is_none()),
+    }?;
+    slot.capture_id = Some(capture.capture_id.clone());
+    let slot_id = slot.slot_id.clone();
+    layout.reset_composition();
+
+    Some(slot_id)
+}
+
+/// Empties the slot a deleted capture was filling.
+pub fn release_shot_layout_capture(manifest: &mut SessionManifest, capture_id: &str) {
+    let Some(layout) = manifest.shot_layout.as_mut() else {
+        return;
+    };
+    let Some(slot) = layout
+        .slots
+        .iter_mut()
+        .find(|slot| slot.capture_id.as_deref() == Some(capture_id))
+    else {
+        return;
+    };
+
+    slot.capture_id = None;
+    if layout.retake_slot_id.as_deref() == Some(slot.slot_id.as_str()) {
+        layout.retake_slot_id = None;
+    }
+    layout.reset_composition();
+}
+
+/// Arms `slot_id` so the next capture replaces it. The replaced capture stays
+/// in the session until the new one lands or the customer deletes it.
+pub fn retake_shot_layout_slot_in_dir(
+    base_dir: &Path,
+    input: ShotLayoutRetakeInputDto,
+) -> Result<ShotLayoutRetakeResultDto, HostErrorEnvelope> {
+    let paths = SessionPaths::try_new(base_dir, &input.session_id)?;
+    let _pipeline_guard = CAPTURE_PIPELINE_LOCK.lock().map_err(|_| {
+        HostErrorEnvelope::persistence("촬영 상태를 잠그지 못했어요. 잠시 후 다시 시도해 주세요.")
+    })?;
+    let mut manifest = read_session_manifest(&paths.manifest_path)?;
+    manifest =
+        sync_session_timing_in_dir(base_dir, &paths.manifest_path, manifest, SystemTime::now())?;
+
+    let is_ended = manifest
+        .timing
+        .as_ref()
+        .is_some_and(|timing| timing.phase == "ended")
+        || manifest.post_end.is_some();
+    if is_ended {
+        return Err(HostErrorEnvelope::validation_message(
+            "촬영 시간이 끝나 다시 찍을 수 없어요.",
+        ));
+    }
+
+    let layout = manifest.shot_layout.as_mut().ok_or_else(|| {
+        HostErrorEnvelope::validation_message("이 세션은 여러 컷 레이아웃을 쓰지 않아요.")
+    })?;
+    let slot = layout
+        .slots
+        .iter()
+        .find(|slot| slot.slot_id == input.slot_id)
+        .ok_or_else(|| {
+            HostErrorEnvelope::validation_message("다시 찍을 칸을 레이아웃에서 찾지 못했어요.")
+        })?;
+    if slot.capture_id.is_none() {
+        return Err(HostErrorEnvelope::validation_message(
+            "아직 찍지 않은 칸이에요. 이어서 촬영해 주세요.",
+        ));
+    }
+
+    layout.retake_slot_id = Some(input.slot_id.clone());
+    layout.reset_composition();
+    manifest.updated_at = current_timestamp(SystemTime::now())?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::manifest_updated("shot-layout-retake-requested", &manifest),
+    )?;
+
+    Ok(ShotLayoutRetakeResultDto {
+        schema_version: SHOT_LAYOUT_RETAKE_RESULT_SCHEMA_VERSION.into(),
+        session_id: input.session_id,
+        slot_id: input.slot_id,
+        status: "retake-armed".into(),
+        manifest,
+    })
+}
+
+fn start_shot_layout(base_dir: &Path, capture: &SessionCaptureRecord) -> Option<SessionShotLayout> {
+    let preset_id = capture.active_preset_id.as_deref()?;
+    let bundle = find_published_preset_runtime_bundle(
+        &resolve_published_preset_catalog_dir(base_dir),
+        preset_id,
+        &capture.active_preset_version,
+    )?;
+    let layout = bundle.shot_layout?;
+    log::info!(
+        "shot_layout_started session={} layout_id={} slots={} preset={} version={}",
+        capture.session_id,
+        layout.layout_id,
+        layout.slots.len(),
+        bundle.preset_id,
+        bundle.published_version
+    );
+
+    Some(SessionShotLayout {
+        layout_id: layout.layout_id,
+        preset_id: bundle.preset_id,
+        published_version: bundle.published_version,
+        status: SHOT_LAYOUT_STATUS_FILLING.into(),
+        slots: layout
+            .slots
+            .into_iter()
+            .map(|slot| SessionShotSlot {
+                slot_id: slot.slot_id,
+                capture_id: None,
+            })
+            .collect(),
+        retake_slot_id: None,
+        composed_final: None,
+    })
+}
@@ -437,7 +437,8 @@ where
 
                     let accepted_at_ms =
                         accepted_at_ms.ok_or(SidecarClientError::CaptureProtocolViolation)?;
-                    let raw_path = validate_arrived_raw_path(base_dir, session_id, message)?;
+                    let raw_path =
+                        validate_session_raw_path(base_dir, session_id, &message.raw_path)?;
                     let fast_preview = extract_fast_preview_metadata(message);
                     let persisted_at_ms =
                         current_time_ms().map_err(|_| SidecarClientError::CaptureTimedOut)?;
@@ -610,14 +611,16 @@ fn parse_capture_event(line: &str) -> Result<CanonHelperEvent, SidecarClientErro
     }
 }
 
-fn validate_arrived_raw_path(
+/// Confirms an arrived RAW sits inside the session originals folder and is a
+/// non-empty file before any backend hands it to the ingest pipeline.
+pub(crate) fn validate_session_raw_path(
     base_dir: &Path,
     session_id: &str,
-    message: &CanonHelperFileArrivedMessage,
+    raw_path: &str,
 ) -> Result<String, SidecarClientError> {
     let paths = SessionPaths::try_new(base_dir, session_id)
         .map_err(|_| SidecarClientError::CaptureFileUnscoped)?;
-    let raw_path = PathBuf::from(&message.raw_path);
+    let raw_path = PathBuf::from(raw_path);
 
     if !raw_path.is_absolute() {
         return Err(SidecarClientError::CaptureFileUnscoped);
@@ -669,7 +672,7 @@ fn extract_fast_preview_metadata(
     Some(CompletedCaptureFastPreview { asset_path, kind })
 }
 
-fn append_json_line<T: Serialize>(path: &Path, value: &T) -> Result<(), std::io::Error> {
+pub(crate) fn append_json_line<T: Serialize>(path: &Path, value: &T) -> Result<(), std::io::Error> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
@@ -685,7 +688,7 @@ fn append_json_line<T: Serialize>(path: &Path, value: &T) -> Result<(), std::io:
     Ok(())
 }
 
-fn read_json_lines<T>(path: &Path) -> Result<Vec<T>, std::io::Error>
+pub(crate) fn read_json_lines<T>(path: &Path) -> Result<Vec<T>, std::io::Error>
 where
     T: for<'de> Deserialize<'de>,
 {
@@ -705,7 +708,7 @@ where
     Ok(messages)
 }
 
-fn capture_round_trip_timeout_ms(base_dir: &Path) -> u64 {
+pub(crate) fn capture_round_trip_timeout_ms(base_dir: &Path) -> u64 {
     let override_path = base_dir.join(CAPTURE_ROUND_TRIP_TIMEOUT_OVERRIDE_FILE_NAME);
 
     if let Ok(value) = fs::read_to_string(&override_path) {
@@ -723,7 +726,7 @@ fn capture_round_trip_timeout_ms(base_dir: &Path) -> u64 {
         .unwrap_or(DEFAULT_CAPTURE_ROUND_TRIP_TIMEOUT_MS)
 }
 
-fn current_time_ms() -> Result<u64, std::time::SystemTimeError> {
+pub(crate) fn current_time_ms() -> Result<u64, std::time::SystemTimeError> {
     Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64)
 }
 
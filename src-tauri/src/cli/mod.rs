@@ -0,0 +1,248 @@
+use std::{
+    env, fs,
+    io::{Read, Write},
+    path::PathBuf,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    branch_config::apply_branch_rollout_in_dir,
+    commands::runtime_commands::resolve_runtime_capability_snapshot,
+    contracts::dto::{
+        BranchRolloutInputDto, HostErrorEnvelope, OperatorAuditQueryFilterDto,
+        OperatorRecoveryActionInputDto, ReservationLookupInputDto, RollbackPresetCatalogInputDto,
+        SessionStartInputDto,
+    },
+    diagnostics::{
+        audit_log::load_operator_audit_history_in_dir, load_operator_session_summary_in_dir,
+        recovery::execute_operator_recovery_action_in_dir,
+    },
+    preset::preset_catalog_state::rollback_preset_catalog_in_dir,
+    reservation::start_reserved_session_in_dir,
+    session::session_repository::{resolve_app_session_base_dir, start_session_in_dir},
+};
+
+pub const CLI_EXIT_SUCCESS: i32 = 0;
+pub const CLI_EXIT_USAGE: i32 = 2;
+pub const CLI_EXIT_VALIDATION: i32 = 3;
+pub const CLI_EXIT_CAPABILITY_DENIED: i32 = 4;
+pub const CLI_EXIT_SESSION_NOT_FOUND: i32 = 5;
+pub const CLI_EXIT_PERSISTENCE: i32 = 6;
+pub const CLI_EXIT_HOST_REJECTED: i32 = 7;
+
+const BASE_DIR_ENV: &str = "BOOTHY_BASE_DIR";
+const USAGE: &str = "usage: boothy-cli [--base-dir <path>] <command> [--input <json>|-]
+
+commands:
+  start-session       SessionStartInputDto 입력으로 새 세션을 시작해요.
+  start-reserved-session
+                      ReservationLookupInputDto 입력으로 예약 세션을 시작해요.
+  session-summary     현재 세션의 operator 진단 요약을 보여 줘요.
+  recovery-action     OperatorRecoveryActionInputDto 입력으로 복구 액션을 실행해요.
+  audit-history       OperatorAuditQueryFilterDto 입력으로 audit 이력을 조회해요.
+  branch-rollout      BranchRolloutInputDto 입력으로 지점 rollout을 적용해요.
+  preset-rollback     RollbackPresetCatalogInputDto 입력으로 preset catalog를 되돌려요.
+
+exit codes:
+  0 success, 2 usage, 3 validation-error, 4 capability-denied,
+  5 session-not-found, 6 persistence failure, 7 other host rejection";
+
+#[derive(Debug, Default)]
+struct CliArguments {
+    base_dir: Option<PathBuf>,
+    command: Option<String>,
+    input: Option<String>,
+}
+
+/// Runs one CLI invocation and returns the process exit code. Results and host
+/// errors are both written to `stdout` as JSON so remote scripts only parse one stream.
+pub fn run_cli(args: Vec<String>, stdout: &mut dyn Write, stderr: &mut dyn Write) -> i32 {
+    let arguments = match parse_arguments(args) {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            let _ = writeln!(stderr, "{message}\n\n{USAGE}");
+            return CLI_EXIT_USAGE;
+        }
+    };
+    let Some(command) = arguments.command.as_deref() else {
+        let _ = writeln!(stderr, "{USAGE}");
+        return CLI_EXIT_USAGE;
+    };
+    let Some(base_dir) = arguments.base_dir.clone().or_else(resolve_default_base_dir) else {
+        let _ = writeln!(
+            stderr,
+            "--base-dir 또는 {BASE_DIR_ENV} 로 booth 데이터 경로를 지정해 주세요.\n\n{USAGE}"
+        );
+        return CLI_EXIT_USAGE;
+    };
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+    let input = arguments.input.as_deref();
+
+    let outcome = match command {
+        "start-session" => parse_input::<SessionStartInputDto>(input)
+            .map(|input| to_json_result(start_session_in_dir(&base_dir, input))),
+        "start-reserved-session" => parse_input::<ReservationLookupInputDto>(input)
+            .map(|input| to_json_result(start_reserved_session_in_dir(&base_dir, input))),
+        "session-summary" => Ok(to_json_result(load_operator_session_summary_in_dir(
+            &base_dir,
+            &capability_snapshot,
+        ))),
+        "recovery-action" => parse_input::<OperatorRecoveryActionInputDto>(input).map(|input| {
+            to_json_result(execute_operator_recovery_action_in_dir(
+                &base_dir,
+                &capability_snapshot,
+                input,
+            ))
+        }),
+        "audit-history" => parse_optional_input(input, || OperatorAuditQueryFilterDto {
+            session_id: None,
+            event_categories: Vec::new(),
+            limit: None,
+        })
+        .map(|input| {
+            to_json_result(load_operator_audit_history_in_dir(
+                &base_dir,
+                &capability_snapshot,
+                input,
+            ))
+        }),
+        "branch-rollout" => parse_input::<BranchRolloutInputDto>(input).map(|input| {
+            to_json_result(apply_branch_rollout_in_dir(
+                &base_dir,
+                &capability_snapshot,
+                input,
+            ))
+        }),
+        "preset-rollback" => parse_input::<RollbackPresetCatalogInputDto>(input).map(|input| {
+            to_json_result(rollback_preset_catalog_in_dir(
+                &base_dir,
+                &capability_snapshot,
+                input,
+            ))
+        }),
+        _ => Err(format!("알 수 없는 명령이에요: {command}")),
+    };
+
+    match outcome {
+        Ok(Ok(value)) => {
+            let _ = writeln!(stdout, "{value}");
+            CLI_EXIT_SUCCESS
+        }
+        Ok(Err((exit_code, value))) => {
+            let _ = writeln!(stdout, "{value}");
+            exit_code
+        }
+        Err(message) => {
+            let _ = writeln!(stderr, "{message}\n\n{USAGE}");
+            CLI_EXIT_USAGE
+        }
+    }
+}
+
+pub fn exit_code_for_host_error(error: &HostErrorEnvelope) -> i32 {
+    match error.code.as_str() {
+        "validation-error" => CLI_EXIT_VALIDATION,
+        "capability-denied" => CLI_EXIT_CAPABILITY_DENIED,
+        "session-not-found" => CLI_EXIT_SESSION_NOT_FOUND,
+        "session-persistence-failed" => CLI_EXIT_PERSISTENCE,
+        _ => CLI_EXIT_HOST_REJECTED,
+    }
+}
+
+fn parse_arguments(args: Vec<String>) -> Result<CliArguments, String> {
+    let mut arguments = CliArguments::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--base-dir" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "--base-dir 값이 비어 있어요.".to_string())?;
+                arguments.base_dir = Some(PathBuf::from(value));
+            }
+            "--input" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "--input 값이 비어 있어요.".to_string())?;
+                arguments.input = Some(value);
+            }
+            "--input-file" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "--input-file 값이 비어 있어요.".to_string())?;
+                arguments.input = Some(
+                    fs::read_to_string(&value)
+                        .map_err(|error| format!("입력 파일을 읽지 못했어요: {error}"))?,
+                );
+            }
+            "-h" | "--help" => return Ok(CliArguments::default()),
+            _ if arg.starts_with("--") => return Err(format!("알 수 없는 옵션이에요: {arg}")),
+            _ if arguments.command.is_none() => arguments.command = Some(arg),
+            _ => return Err(format!("명령은 하나만 지정할 수 있어요: {arg}")),
+        }
+    }
+
+    Ok(arguments)
+}
+
+fn resolve_default_base_dir() -> Option<PathBuf> {
+    if let Some(base_dir) = env::var_os(BASE_DIR_ENV) {
+        return Some(PathBuf::from(base_dir));
+    }
+
+    env::var_os("USERPROFILE").map(|_| resolve_app_session_base_dir(PathBuf::new()))
+}
+
+fn read_input_source(input: &str) -> Result<String, String> {
+    if input != "-" {
+        return Ok(input.to_string());
+    }
+
+    let mut buffer = String::new();
+    std::io::stdin()
+        .read_to_string(&mut buffer)
+        .map_err(|error| format!("표준 입력을 읽지 못했어요: {error}"))?;
+
+    Ok(buffer)
+}
+
+fn parse_input<T: DeserializeOwned>(input: Option<&str>) -> Result<T, String> {
+    let input = input.ok_or_else(|| "이 명령은 --input JSON이 필요해요.".to_string())?;
+    let source = read_input_source(input)?;
+
+    serde_json::from_str(&source).map_err(|error| format!("입력 JSON을 읽지 못했어요: {error}"))
+}
+
+fn parse_optional_input<T: DeserializeOwned>(
+    input: Option<&str>,
+    default: impl FnOnce() -> T,
+) -> Result<T, String> {
+    match input {
+        Some(_) => parse_input(input),
+        None => Ok(default()),
+    }
+}
+
+fn to_json_result<T: Serialize>(
+    result: Result<T, HostErrorEnvelope>,
+) -> Result<String, (i32, String)> {
+    match result {
+        Ok(value) => serde_json::to_string_pretty(&value)
+            .map_err(|error| (CLI_EXIT_PERSISTENCE, serialization_failure(error))),
+        Err(error) => Err((
+            exit_code_for_host_error(&error),
+            serde_json::to_string_pretty(&error)
+                .unwrap_or_else(|serialize_error| serialization_failure(serialize_error)),
+        )),
+    }
+}
+
+fn serialization_failure(error: serde_json::Error) -> String {
+    serde_json::json!({
+        "code": "session-persistence-failed",
+        "message": format!("결과를 JSON으로 만들지 못했어요: {error}"),
+    })
+    .to_string()
+}
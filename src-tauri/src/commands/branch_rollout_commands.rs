@@ -3,12 +3,34 @@ use tauri::Manager;
 use crate::{
     branch_config::{
         apply_branch_rollback_in_dir, apply_branch_rollout_in_dir,
+        fleet_sync::sync_fleet_release_in_dir,
         load_branch_rollout_overview_in_dir,
+        local_settings::{
+            load_branch_local_settings_diff_in_dir, load_branch_local_settings_in_dir,
+            update_branch_local_settings_in_dir,
+        },
+        operational_toggles::load_branch_operational_toggles_in_dir,
+        preset_signing_keys::{
+            generate_preset_signing_key_in_dir, load_preset_signing_keys_in_dir,
+            resign_unsigned_preset_bundles_in_dir, revoke_preset_signing_key_in_dir,
+            trust_preset_signing_key_in_dir,
+        },
+        rollout_plan::{
+            evaluate_branch_rollout_plans_in_dir, rollback_branch_rollout_plan_in_dir,
+            start_branch_rollout_plan_in_dir,
+        },
     },
     commands::runtime_commands::resolve_runtime_capability_snapshot,
     contracts::dto::{
+        BranchLocalSettingsDiffDto, BranchLocalSettingsDiffInputDto,
+        BranchLocalSettingsQueryInputDto, BranchLocalSettingsResultDto,
+        BranchLocalSettingsUpdateInputDto, BranchOperationalTogglesResultDto,
         BranchRollbackInputDto, BranchRolloutActionResultDto, BranchRolloutInputDto,
-        BranchRolloutOverviewResultDto, HostErrorEnvelope,
+        BranchRolloutOverviewResultDto, BranchRolloutPlanInputDto, BranchRolloutPlanResultDto,
+        BranchRolloutPlanRollbackInputDto, FleetSyncInputDto, FleetSyncResultDto,
+        HostErrorEnvelope, PresetBundleResignInputDto, PresetBundleResignResultDto,
+        PresetSigningKeyGenerateInputDto, PresetSigningKeyRevokeInputDto,
+        PresetSigningKeyTrustInputDto, PresetSigningKeysResultDto,
     },
     session::session_repository::resolve_app_session_base_dir,
 };
@@ -53,3 +75,183 @@ pub fn apply_branch_rollback(
 
     apply_branch_rollback_in_dir(&base_dir, &capability_snapshot, input)
 }
+
+#[tauri::command]
+pub fn start_branch_rollout_plan(
+    app: tauri::AppHandle,
+    input: BranchRolloutPlanInputDto,
+) -> Result<BranchRolloutPlanResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    start_branch_rollout_plan_in_dir(&base_dir, &capability_snapshot, input)
+}
+
+#[tauri::command]
+pub fn evaluate_branch_rollout_plans(
+    app: tauri::AppHandle,
+) -> Result<BranchRolloutOverviewResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    evaluate_branch_rollout_plans_in_dir(&base_dir, &capability_snapshot)
+}
+
+#[tauri::command]
+pub fn rollback_branch_rollout_plan(
+    app: tauri::AppHandle,
+    input: BranchRolloutPlanRollbackInputDto,
+) -> Result<BranchRolloutPlanResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    rollback_branch_rollout_plan_in_dir(&base_dir, &capability_snapshot, input)
+}
+
+#[tauri::command]
+pub fn load_branch_local_settings(
+    app: tauri::AppHandle,
+    input: BranchLocalSettingsQueryInputDto,
+) -> Result<BranchLocalSettingsResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    load_branch_local_settings_in_dir(&base_dir, &capability_snapshot, input)
+}
+
+#[tauri::command]
+pub fn update_branch_local_settings(
+    app: tauri::AppHandle,
+    input: BranchLocalSettingsUpdateInputDto,
+) -> Result<BranchLocalSettingsResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    update_branch_local_settings_in_dir(&base_dir, &capability_snapshot, input)
+}
+
+#[tauri::command]
+pub fn load_branch_local_settings_diff(
+    app: tauri::AppHandle,
+    input: BranchLocalSettingsDiffInputDto,
+) -> Result<BranchLocalSettingsDiffDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    load_branch_local_settings_diff_in_dir(&base_dir, &capability_snapshot, input)
+}
+
+#[tauri::command]
+pub fn load_branch_operational_toggles(
+    app: tauri::AppHandle,
+    input: BranchLocalSettingsQueryInputDto,
+) -> Result<BranchOperationalTogglesResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    load_branch_operational_toggles_in_dir(&base_dir, &capability_snapshot, input)
+}
+
+#[tauri::command]
+pub fn sync_fleet_release(
+    app: tauri::AppHandle,
+    input: FleetSyncInputDto,
+) -> Result<FleetSyncResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    sync_fleet_release_in_dir(&base_dir, &capability_snapshot, input)
+}
+
+#[tauri::command]
+pub fn load_preset_signing_keys(
+    app: tauri::AppHandle,
+) -> Result<PresetSigningKeysResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    load_preset_signing_keys_in_dir(&base_dir, &capability_snapshot)
+}
+
+#[tauri::command]
+pub fn generate_preset_signing_key(
+    app: tauri::AppHandle,
+    input: PresetSigningKeyGenerateInputDto,
+) -> Result<PresetSigningKeysResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    generate_preset_signing_key_in_dir(&base_dir, &capability_snapshot, input)
+}
+
+#[tauri::command]
+pub fn trust_preset_signing_key(
+    app: tauri::AppHandle,
+    input: PresetSigningKeyTrustInputDto,
+) -> Result<PresetSigningKeysResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    trust_preset_signing_key_in_dir(&base_dir, &capability_snapshot, input)
+}
+
+#[tauri::command]
+pub fn revoke_preset_signing_key(
+    app: tauri::AppHandle,
+    input: PresetSigningKeyRevokeInputDto,
+) -> Result<PresetSigningKeysResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    revoke_preset_signing_key_in_dir(&base_dir, &capability_snapshot, input)
+}
+
+#[tauri::command]
+pub fn resign_unsigned_preset_bundles(
+    app: tauri::AppHandle,
+    input: PresetBundleResignInputDto,
+) -> Result<PresetBundleResignResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+
+    resign_unsigned_preset_bundles_in_dir(&base_dir, &capability_snapshot, input)
+}
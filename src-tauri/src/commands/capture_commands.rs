@@ -4,23 +4,29 @@ use tauri::{Emitter, Manager};
 
 use crate::{
     capture::{
-        helper_supervisor::try_ensure_helper_running,
+        camera_backend::try_ensure_camera_backend_running,
+        capture_program::run_capture_program_in_dir,
+        capture_selection::update_capture_selection_in_dir,
         ingest_pipeline::{complete_preview_render_in_dir, mark_preview_render_failed_in_dir},
         normalized_state::{
             delete_capture_in_dir, get_capture_readiness_in_dir,
             request_capture_in_dir_with_fast_preview,
         },
+        shot_layout::retake_shot_layout_slot_in_dir,
     },
     contracts::dto::{
         CaptureDeleteInputDto, CaptureDeleteResultDto, CaptureFastPreviewUpdateDto,
-        CaptureReadinessDto, CaptureReadinessInputDto, CaptureReadinessUpdateDto,
-        CaptureRequestInputDto, CaptureRequestResultDto, HostErrorEnvelope,
+        CaptureProgramInputDto, CaptureProgramResultDto, CaptureReadinessDto,
+        CaptureReadinessInputDto, CaptureReadinessUpdateDto, CaptureRequestInputDto,
+        CaptureRequestResultDto, CaptureSelectionInputDto, CaptureSelectionResultDto,
+        HostErrorEnvelope, ShotLayoutRetakeInputDto, ShotLayoutRetakeResultDto,
     },
     session::session_repository::resolve_app_session_base_dir,
 };
 
 const CAPTURE_READINESS_UPDATE_EVENT: &str = "capture-readiness-update";
 const CAPTURE_FAST_PREVIEW_UPDATE_EVENT: &str = "capture-fast-preview-update";
+const CAPTURE_PROGRAM_TICK_EVENT: &str = "capture-program-tick";
 const PREVIEW_REFINEMENT_WAIT_MS: u64 = 2000;
 const PREVIEW_REFINEMENT_POLL_MS: u64 = 40;
 
@@ -33,7 +39,7 @@ pub fn get_capture_readiness(
         HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
     })?;
     let base_dir = resolve_app_session_base_dir(app_local_data_dir);
-    try_ensure_helper_running(&base_dir, &input.session_id);
+    try_ensure_camera_backend_running(&base_dir, &input.session_id);
     let session_id = input.session_id.clone();
 
     match get_capture_readiness_in_dir(&base_dir, input) {
@@ -86,6 +92,32 @@ pub fn delete_capture(
     delete_capture_in_dir(&base_dir, input)
 }
 
+#[tauri::command]
+pub fn update_capture_selection(
+    app: tauri::AppHandle,
+    input: CaptureSelectionInputDto,
+) -> Result<CaptureSelectionResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+
+    update_capture_selection_in_dir(&base_dir, input)
+}
+
+#[tauri::command]
+pub fn retake_shot_layout_slot(
+    app: tauri::AppHandle,
+    input: ShotLayoutRetakeInputDto,
+) -> Result<ShotLayoutRetakeResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+
+    retake_shot_layout_slot_in_dir(&base_dir, input)
+}
+
 #[tauri::command]
 pub fn request_capture(
     app: tauri::AppHandle,
@@ -95,7 +127,7 @@ pub fn request_capture(
         HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
     })?;
     let base_dir = resolve_app_session_base_dir(app_local_data_dir);
-    try_ensure_helper_running(&base_dir, &input.session_id);
+    try_ensure_camera_backend_running(&base_dir, &input.session_id);
     let session_id = input.session_id.clone();
     let preview_session_id = session_id.clone();
     let preview_app = app.clone();
@@ -132,9 +164,83 @@ pub fn request_capture(
             return Err(error);
         }
     };
-    let preview_base_dir = base_dir.clone();
-    let preview_session_id = result.session_id.clone();
-    let preview_capture_id = result.capture.capture_id.clone();
+    spawn_preview_render(
+        &app,
+        base_dir,
+        result.session_id.clone(),
+        result.capture.capture_id.clone(),
+    );
+
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn run_capture_program(
+    app: tauri::AppHandle,
+    input: CaptureProgramInputDto,
+) -> Result<CaptureProgramResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    try_ensure_camera_backend_running(&base_dir, &input.session_id);
+    let session_id = input.session_id.clone();
+    let preview_session_id = session_id.clone();
+    let preview_app = app.clone();
+    let tick_app = app.clone();
+    let result = run_capture_program_in_dir(
+        &base_dir,
+        input,
+        move |update| {
+            let _ = preview_app.emit(
+                CAPTURE_FAST_PREVIEW_UPDATE_EVENT,
+                CaptureFastPreviewUpdateDto::new(
+                    preview_session_id.clone(),
+                    update.request_id,
+                    update.capture_id,
+                    update.asset_path,
+                    update.visible_at_ms,
+                    update.kind,
+                ),
+            );
+        },
+        move |tick| {
+            let _ = tick_app.emit(
+                CAPTURE_READINESS_UPDATE_EVENT,
+                CaptureReadinessUpdateDto::new(tick.session_id.clone(), tick.readiness.clone()),
+            );
+            let _ = tick_app.emit(CAPTURE_PROGRAM_TICK_EVENT, tick);
+        },
+    )
+    .inspect_err(|error| {
+        log::warn!(
+            "capture_program_failed session={} code={} message={}",
+            session_id,
+            error.code,
+            error.message
+        );
+    })?;
+
+    for capture in &result.captures {
+        spawn_preview_render(
+            &app,
+            base_dir.clone(),
+            result.session_id.clone(),
+            capture.capture_id.clone(),
+        );
+    }
+
+    Ok(result)
+}
+
+/// Renders the preview off the command thread and reports readiness as it
+/// lands.
+fn spawn_preview_render(
+    app: &tauri::AppHandle,
+    preview_base_dir: std::path::PathBuf,
+    preview_session_id: String,
+    preview_capture_id: String,
+) {
     let preview_app = app.clone();
 
     thread::spawn(move || {
@@ -227,8 +333,6 @@ pub fn request_capture(
             );
         }
     });
-
-    Ok(result)
 }
 
 fn read_current_capture_readiness(
@@ -2,5 +2,7 @@ pub mod branch_rollout_commands;
 pub mod capture_commands;
 pub mod operator_commands;
 pub mod preset_commands;
+pub mod print_commands;
+pub mod reservation_commands;
 pub mod runtime_commands;
 pub mod session_commands;
@@ -1,10 +1,11 @@
 use crate::{
-    capture::helper_supervisor::try_ensure_helper_running,
+    capture::camera_backend::try_ensure_camera_backend_running,
     commands::runtime_commands::resolve_runtime_capability_snapshot,
     contracts::dto::{
         HostErrorEnvelope, OperatorAuditQueryFilterDto, OperatorAuditQueryResultDto,
         OperatorRecoveryActionInputDto, OperatorRecoveryActionResultDto,
-        OperatorRecoverySummaryDto, OperatorSessionSummaryDto,
+        OperatorRecoverySummaryDto, OperatorSessionSummaryDto, OperatorSessionTimelineDto,
+        OperatorSessionTimelineInputDto,
     },
     diagnostics::{
         audit_log::load_operator_audit_history_in_dir,
@@ -13,6 +14,7 @@ use crate::{
         recovery::{
             execute_operator_recovery_action_in_dir, load_operator_recovery_summary_in_dir,
         },
+        session_timeline::load_operator_session_timeline_in_dir,
     },
     session::session_repository::resolve_app_session_base_dir,
 };
@@ -30,7 +32,7 @@ pub fn load_operator_session_summary(
     let capability_snapshot = resolve_runtime_capability_snapshot();
     ensure_operator_window_label(window.label())?;
     if let Some(session_id) = find_current_operator_session_id_in_dir(&base_dir)? {
-        try_ensure_helper_running(&base_dir, &session_id);
+        try_ensure_camera_backend_running(&base_dir, &session_id);
     }
 
     load_operator_session_summary_in_dir(&base_dir, &capability_snapshot)
@@ -48,7 +50,7 @@ pub fn load_operator_recovery_summary(
     let capability_snapshot = resolve_runtime_capability_snapshot();
     ensure_operator_window_label(window.label())?;
     if let Some(session_id) = find_current_operator_session_id_in_dir(&base_dir)? {
-        try_ensure_helper_running(&base_dir, &session_id);
+        try_ensure_camera_backend_running(&base_dir, &session_id);
     }
 
     load_operator_recovery_summary_in_dir(&base_dir, &capability_snapshot)
@@ -70,6 +72,22 @@ pub fn load_operator_audit_history(
     load_operator_audit_history_in_dir(&base_dir, &capability_snapshot, input)
 }
 
+#[tauri::command]
+pub fn load_operator_session_timeline(
+    app: tauri::AppHandle,
+    window: tauri::Window,
+    input: OperatorSessionTimelineInputDto,
+) -> Result<OperatorSessionTimelineDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    let capability_snapshot = resolve_runtime_capability_snapshot();
+    ensure_operator_window_label(window.label())?;
+
+    load_operator_session_timeline_in_dir(&base_dir, &capability_snapshot, input)
+}
+
 #[tauri::command]
 pub fn run_operator_recovery_action(
     app: tauri::AppHandle,
@@ -82,7 +100,7 @@ pub fn run_operator_recovery_action(
     let base_dir = resolve_app_session_base_dir(app_local_data_dir);
     let capability_snapshot = resolve_runtime_capability_snapshot();
     ensure_operator_window_label(window.label())?;
-    try_ensure_helper_running(&base_dir, &input.session_id);
+    try_ensure_camera_backend_running(&base_dir, &input.session_id);
 
     execute_operator_recovery_action_in_dir(&base_dir, &capability_snapshot, input)
 }
@@ -1,7 +1,7 @@
 use tauri::Manager;
 
 use crate::{
-    capture::helper_supervisor::try_ensure_helper_running,
+    capture::camera_backend::try_ensure_camera_backend_running,
     commands::runtime_commands::resolve_runtime_capability_snapshot,
     contracts::dto::{
         AuthoringWorkspaceResultDto, DraftPresetEditPayloadDto, DraftPresetSummaryDto,
@@ -54,7 +54,7 @@ pub fn select_active_preset(
         &result.active_preset.preset_id,
         &result.active_preset.published_version,
     );
-    try_ensure_helper_running(&base_dir, &session_id);
+    try_ensure_camera_backend_running(&base_dir, &session_id);
 
     Ok(result)
 }
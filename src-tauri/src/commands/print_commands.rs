@@ -0,0 +1,51 @@
+use tauri::Manager;
+
+use crate::{
+    contracts::dto::{
+        HostErrorEnvelope, PrintJobInputDto, PrintJobListDto, PrintJobResultDto,
+        SessionPrintJobsInputDto,
+    },
+    print::{
+        load_print_templates_in_dir, refresh_print_jobs_in_dir, submit_print_job_in_dir,
+        PrintTemplate,
+    },
+    session::session_repository::resolve_app_session_base_dir,
+};
+
+#[tauri::command]
+pub fn load_print_templates(
+    app: tauri::AppHandle,
+) -> Result<Vec<PrintTemplate>, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+
+    load_print_templates_in_dir(&base_dir)
+}
+
+#[tauri::command]
+pub fn submit_print_job(
+    app: tauri::AppHandle,
+    input: PrintJobInputDto,
+) -> Result<PrintJobResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+
+    submit_print_job_in_dir(&base_dir, input)
+}
+
+#[tauri::command]
+pub fn refresh_print_jobs(
+    app: tauri::AppHandle,
+    input: SessionPrintJobsInputDto,
+) -> Result<PrintJobListDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+
+    refresh_print_jobs_in_dir(&base_dir, &input.session_id)
+}
@@ -0,0 +1,39 @@
+use tauri::Manager;
+
+use crate::{
+    capture::camera_backend::try_ensure_camera_backend_running,
+    contracts::dto::{HostErrorEnvelope, ReservationLookupInputDto},
+    reservation::{
+        lookup_reservation_in_dir, start_reserved_session_in_dir, ReservationSummaryDto,
+    },
+    session::session_repository::{resolve_app_session_base_dir, SessionStartResultDto},
+};
+
+#[tauri::command]
+pub fn lookup_reservation(
+    app: tauri::AppHandle,
+    input: ReservationLookupInputDto,
+) -> Result<ReservationSummaryDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+
+    lookup_reservation_in_dir(&base_dir, input)
+}
+
+#[tauri::command]
+pub fn start_reserved_session(
+    app: tauri::AppHandle,
+    input: ReservationLookupInputDto,
+) -> Result<SessionStartResultDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+
+    let result = start_reserved_session_in_dir(&base_dir, input)?;
+    try_ensure_camera_backend_running(&base_dir, &result.session_id);
+
+    Ok(result)
+}
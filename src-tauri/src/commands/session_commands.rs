@@ -1,10 +1,24 @@
+use std::time::SystemTime;
+
 use tauri::Manager;
 
 use crate::{
-    capture::helper_supervisor::try_ensure_helper_running,
+    branch_config::{
+        ensure_settings_access,
+        session_packages::{load_session_packages_in_dir, SessionPackageCatalogDto},
+    },
+    capture::camera_backend::try_ensure_camera_backend_running,
+    commands::runtime_commands::resolve_runtime_capability_snapshot,
     contracts::dto::{HostErrorEnvelope, SessionStartInputDto},
-    session::session_repository::{
-        resolve_app_session_base_dir, start_session_in_dir, SessionStartResultDto,
+    session::{
+        session_repository::{
+            resolve_app_session_base_dir, start_session_in_dir, SessionStartResultDto,
+        },
+        session_retention::{
+            apply_session_retention_in_dir, load_session_retention_policy_in_dir,
+            save_session_retention_policy_in_dir, SessionRetentionPolicy,
+            SessionRetentionReportDto,
+        },
     },
 };
 
@@ -19,7 +33,59 @@ pub fn start_session(
     let base_dir = resolve_app_session_base_dir(app_local_data_dir);
 
     let result = start_session_in_dir(&base_dir, input)?;
-    try_ensure_helper_running(&base_dir, &result.session_id);
+    try_ensure_camera_backend_running(&base_dir, &result.session_id);
 
     Ok(result)
 }
+
+#[tauri::command]
+pub fn load_session_packages(
+    app: tauri::AppHandle,
+) -> Result<SessionPackageCatalogDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+
+    load_session_packages_in_dir(&base_dir)
+}
+
+#[tauri::command]
+pub fn load_session_retention_policy(
+    app: tauri::AppHandle,
+) -> Result<SessionRetentionPolicy, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    ensure_settings_access(&resolve_runtime_capability_snapshot())?;
+
+    load_session_retention_policy_in_dir(&base_dir)
+}
+
+#[tauri::command]
+pub fn save_session_retention_policy(
+    app: tauri::AppHandle,
+    input: SessionRetentionPolicy,
+) -> Result<SessionRetentionPolicy, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    ensure_settings_access(&resolve_runtime_capability_snapshot())?;
+
+    save_session_retention_policy_in_dir(&base_dir, input)
+}
+
+#[tauri::command]
+pub fn run_session_retention(
+    app: tauri::AppHandle,
+) -> Result<SessionRetentionReportDto, HostErrorEnvelope> {
+    let app_local_data_dir = app.path().app_local_data_dir().map_err(|error| {
+        HostErrorEnvelope::persistence(format!("앱 데이터 경로를 확인하지 못했어요: {error}"))
+    })?;
+    let base_dir = resolve_app_session_base_dir(app_local_data_dir);
+    ensure_settings_access(&resolve_runtime_capability_snapshot())?;
+
+    apply_session_retention_in_dir(&base_dir, SystemTime::now())
+}
@@ -2,8 +2,13 @@ use std::path::{Component, Path};
 
 use serde::{Deserialize, Serialize};
 
-use crate::session::session_manifest::{
-    ActivePresetBinding, SessionCaptureRecord, SessionManifest, SessionPostEnd, SessionTiming,
+use crate::{
+    branch_config::operational_toggles::parse_operational_toggle_entry,
+    integrity::decode_hex,
+    session::session_manifest::{
+        ActivePresetBinding, SessionCaptureRecord, SessionManifest, SessionPostEnd,
+        SessionPrintJob, SessionTiming,
+    },
 };
 
 const SESSION_ID_PREFIX: &str = "session_";
@@ -237,6 +242,10 @@ pub fn validate_draft_preset_edit_input(
 
     if !is_safe_workspace_reference(&input.darktable_project_path)
         || !is_safe_workspace_reference(&input.xmp_template_path)
+        || input
+            .overlay_layout_path
+            .as_deref()
+            .is_some_and(|path| !is_safe_workspace_reference(path))
         || !is_non_blank(&input.preview_profile.profile_id)
         || !is_non_blank(&input.preview_profile.display_name)
         || !is_non_blank(&input.preview_profile.output_color_space)
@@ -483,11 +492,360 @@ pub fn validate_branch_rollback_input(
     Ok(())
 }
 
+pub const BRANCH_ROLLOUT_PLAN_MAX_CANARY_BRANCHES: usize = 5;
+pub const BRANCH_ROLLOUT_PLAN_MAX_WAVES: usize = 5;
+pub const BRANCH_ROLLOUT_PLAN_MAX_SOAK_MINUTES: u32 = 1_440;
+
+/// Canary branches must not repeat in `branch_ids`, and `wave_percentages`
+/// must rise strictly to 100 when branches remain after the canary.
+pub fn validate_branch_rollout_plan_input(
+    input: &BranchRolloutPlanInputDto,
+) -> Result<(), HostErrorEnvelope> {
+    validate_branch_rollout_input(&BranchRolloutInputDto {
+        branch_ids: input
+            .canary_branch_ids
+            .iter()
+            .chain(&input.branch_ids)
+            .cloned()
+            .collect(),
+        target_build_version: input.target_build_version.clone(),
+        target_preset_stack_version: input.target_preset_stack_version.clone(),
+        actor_id: input.actor_id.clone(),
+        actor_label: input.actor_label.clone(),
+    })?;
+
+    if input.canary_branch_ids.is_empty()
+        || input.canary_branch_ids.len() > BRANCH_ROLLOUT_PLAN_MAX_CANARY_BRANCHES
+        || input
+            .branch_ids
+            .iter()
+            .any(|branch_id| input.canary_branch_ids.contains(branch_id))
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "canary 지점 구성을 다시 확인해 주세요.",
+        ));
+    }
+
+    let percentages_are_valid = if input.branch_ids.is_empty() {
+        input.wave_percentages.is_empty()
+    } else {
+        input.wave_percentages.len() <= BRANCH_ROLLOUT_PLAN_MAX_WAVES
+            && input
+                .wave_percentages
+                .first()
+                .is_some_and(|first| *first > 0)
+            && input.wave_percentages.last() == Some(&100)
+            && input
+                .wave_percentages
+                .windows(2)
+                .all(|pair| pair[0] < pair[1])
+    };
+    if !percentages_are_valid {
+        return Err(HostErrorEnvelope::validation_message(
+            "단계별 배포 비율을 다시 확인해 주세요.",
+        ));
+    }
+
+    if input
+        .soak_minutes
+        .is_some_and(|minutes| minutes > BRANCH_ROLLOUT_PLAN_MAX_SOAK_MINUTES)
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "단계별 관찰 시간을 다시 확인해 주세요.",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_branch_rollout_plan_rollback_input(
+    input: &BranchRolloutPlanRollbackInputDto,
+) -> Result<(), HostErrorEnvelope> {
+    if input.plan_id.trim().is_empty()
+        || !is_valid_actor_id(&input.actor_id)
+        || !is_non_blank(&input.actor_label)
+        || !is_trimmed_length_within(&input.actor_label, ACTOR_LABEL_MAX_CHARS)
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "롤백할 단계별 배포와 승인자를 다시 확인해 주세요.",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_fleet_sync_input(input: &FleetSyncInputDto) -> Result<(), HostErrorEnvelope> {
+    if !is_valid_actor_id(&input.actor_id)
+        || !is_non_blank(&input.actor_label)
+        || !is_trimmed_length_within(&input.actor_label, ACTOR_LABEL_MAX_CHARS)
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "동기화를 요청한 승인자를 다시 확인해 주세요.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Key ids name the issuing branch or HQ, e.g. `gangnam-01-3f9a1c2e`.
+pub fn is_valid_preset_signing_key_id(key_id: &str) -> bool {
+    (2..=64).contains(&key_id.len())
+        && key_id.starts_with(|char: char| char.is_ascii_alphanumeric())
+        && key_id
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '-')
+}
+
+pub fn validate_preset_signing_key_generate_input(
+    input: &PresetSigningKeyGenerateInputDto,
+) -> Result<(), HostErrorEnvelope> {
+    if !is_valid_actor_id(&input.actor_id)
+        || !is_non_blank(&input.actor_label)
+        || !is_trimmed_length_within(&input.actor_label, ACTOR_LABEL_MAX_CHARS)
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "서명 키를 만드는 승인자를 다시 확인해 주세요.",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_preset_signing_key_trust_input(
+    input: &PresetSigningKeyTrustInputDto,
+) -> Result<(), HostErrorEnvelope> {
+    if !is_valid_preset_signing_key_id(&input.key_id)
+        || decode_hex::<32>(input.public_key.trim()).is_none()
+        || !is_valid_actor_id(&input.actor_id)
+        || !is_non_blank(&input.actor_label)
+        || !is_trimmed_length_within(&input.actor_label, ACTOR_LABEL_MAX_CHARS)
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "신뢰할 서명 키 ID, 공개 키와 승인자를 다시 확인해 주세요.",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_preset_signing_key_revoke_input(
+    input: &PresetSigningKeyRevokeInputDto,
+) -> Result<(), HostErrorEnvelope> {
+    if !is_valid_preset_signing_key_id(&input.key_id)
+        || !is_valid_actor_id(&input.actor_id)
+        || !is_non_blank(&input.actor_label)
+        || !is_trimmed_length_within(&input.actor_label, ACTOR_LABEL_MAX_CHARS)
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "폐기할 서명 키와 승인자를 다시 확인해 주세요.",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_preset_bundle_resign_input(
+    input: &PresetBundleResignInputDto,
+) -> Result<(), HostErrorEnvelope> {
+    if !is_valid_actor_id(&input.actor_id)
+        || !is_non_blank(&input.actor_label)
+        || !is_trimmed_length_within(&input.actor_label, ACTOR_LABEL_MAX_CHARS)
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "bundle에 서명하는 승인자를 다시 확인해 주세요.",
+        ));
+    }
+
+    Ok(())
+}
+
+pub const BRANCH_OPERATIONAL_TOGGLES_MAX: usize = 10;
+
+/// Trims every field, drops blank values and reports each invalid field
+/// separately so the editor can mark it.
+pub fn validate_branch_local_settings_update_input(
+    input: &BranchLocalSettingsUpdateInputDto,
+) -> Result<BranchLocalSettingsDto, HostErrorEnvelope> {
+    if !is_valid_branch_id(&input.branch_id) {
+        return Err(HostErrorEnvelope::validation_message(
+            "지점 식별자를 다시 확인해 주세요.",
+        ));
+    }
+
+    if !is_valid_actor_id(&input.actor_id)
+        || !is_non_blank(&input.actor_label)
+        || !is_trimmed_length_within(&input.actor_label, ACTOR_LABEL_MAX_CHARS)
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "설정 변경 승인자를 다시 확인해 주세요.",
+        ));
+    }
+
+    let normalize = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    let settings = BranchLocalSettingsDto {
+        contact_phone: normalize(&input.settings.contact_phone),
+        contact_email: normalize(&input.settings.contact_email),
+        contact_kakao: normalize(&input.settings.contact_kakao),
+        support_hours: normalize(&input.settings.support_hours),
+        operational_toggles: input
+            .settings
+            .operational_toggles
+            .iter()
+            .map(|toggle| toggle.trim().to_string())
+            .collect(),
+    };
+    let mut field_errors = HostFieldErrors::default();
+
+    if settings
+        .contact_phone
+        .as_deref()
+        .is_some_and(|value| !is_valid_branch_contact_phone(value))
+    {
+        field_errors.contact_phone = Some("지점 전화번호는 02-555-0101처럼 입력해 주세요.".into());
+    }
+    if settings
+        .contact_email
+        .as_deref()
+        .is_some_and(|value| !is_valid_branch_contact_email(value))
+    {
+        field_errors.contact_email = Some("지점 이메일 형식을 다시 확인해 주세요.".into());
+    }
+    if settings
+        .contact_kakao
+        .as_deref()
+        .is_some_and(|value| !is_valid_branch_contact_kakao(value))
+    {
+        field_errors.contact_kakao =
+            Some("카카오톡 채널 ID는 영문, 숫자, `-`, `_`, `.`로 40자까지 입력해 주세요.".into());
+    }
+    if settings
+        .support_hours
+        .as_deref()
+        .is_some_and(|value| !is_valid_branch_support_hours(value))
+    {
+        field_errors.support_hours = Some("운영 시간은 10:00-22:00처럼 입력해 주세요.".into());
+    }
+    let toggle_names: Vec<&str> = settings
+        .operational_toggles
+        .iter()
+        .map(|toggle| {
+            toggle
+                .split_once('=')
+                .map_or(toggle.as_str(), |(name, _)| name)
+        })
+        .collect();
+    let has_duplicate_toggles = toggle_names
+        .iter()
+        .enumerate()
+        .any(|(index, name)| toggle_names[..index].contains(name));
+    if settings.operational_toggles.len() > BRANCH_OPERATIONAL_TOGGLES_MAX || has_duplicate_toggles
+    {
+        field_errors.operational_toggles = Some("운영 토글 목록을 다시 확인해 주세요.".into());
+    } else if let Some(message) = settings
+        .operational_toggles
+        .iter()
+        .find_map(|toggle| parse_operational_toggle_entry(toggle).err())
+    {
+        field_errors.operational_toggles = Some(message);
+    }
+
+    if field_errors.has_errors() {
+        return Err(HostErrorEnvelope::validation(field_errors));
+    }
+
+    Ok(settings)
+}
+
+pub fn validate_branch_local_settings_query_input(
+    input: &BranchLocalSettingsQueryInputDto,
+) -> Result<(), HostErrorEnvelope> {
+    if !is_valid_branch_id(&input.branch_id) {
+        return Err(HostErrorEnvelope::validation_message(
+            "지점 식별자를 다시 확인해 주세요.",
+        ));
+    }
+
+    Ok(())
+}
+
+fn is_valid_branch_contact_phone(value: &str) -> bool {
+    let digits = value.chars().filter(char::is_ascii_digit).count();
+
+    value.starts_with('0')
+        && (9..=11).contains(&digits)
+        && value
+            .chars()
+            .all(|char| char.is_ascii_digit() || char == '-')
+        && !value.ends_with('-')
+        && !value.contains("--")
+}
+
+fn is_valid_branch_contact_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+
+    value.len() <= 120
+        && !local.is_empty()
+        && !domain.contains('@')
+        && domain
+            .split('.')
+            .all(|label| !label.is_empty() && !label.starts_with('-'))
+        && domain.contains('.')
+        && !value.chars().any(char::is_whitespace)
+}
+
+fn is_valid_branch_contact_kakao(value: &str) -> bool {
+    let channel_id = value.strip_prefix('@').unwrap_or(value);
+
+    !channel_id.is_empty()
+        && channel_id.len() <= 40
+        && channel_id
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || matches!(char, '-' | '_' | '.'))
+}
+
+fn is_valid_branch_support_hours(value: &str) -> bool {
+    let parse_minutes = |time: &str| {
+        let (hours, minutes) = time.split_once(':')?;
+        if hours.len() != 2 || minutes.len() != 2 {
+            return None;
+        }
+        let hours = hours.parse::<u32>().ok()?;
+        let minutes = minutes.parse::<u32>().ok()?;
+
+        (hours <= 24 && minutes < 60 && hours * 60 + minutes <= 24 * 60)
+            .then_some(hours * 60 + minutes)
+    };
+    let Some((opens, closes)) = value.split_once('-') else {
+        return false;
+    };
+
+    matches!(
+        (parse_minutes(opens), parse_minutes(closes)),
+        (Some(opens), Some(closes)) if opens < closes
+    )
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SessionStartInputDto {
     pub name: String,
     pub phone_last_four: String,
+    #[serde(default)]
+    pub package_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservationLookupInputDto {
+    pub reservation_id: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -594,6 +952,9 @@ pub struct DraftPresetSummaryDto {
     pub darktable_version: String,
     pub darktable_project_path: String,
     pub xmp_template_path: String,
+    /// Optional `overlay-layout/v1` JSON composited onto booth outputs.
+    #[serde(default)]
+    pub overlay_layout_path: Option<String>,
     pub preview_profile: DraftRenderProfileDto,
     pub final_profile: DraftRenderProfileDto,
     pub noise_policy: DraftNoisePolicyDto,
@@ -616,6 +977,9 @@ pub struct DraftPresetEditPayloadDto {
     pub darktable_version: String,
     pub darktable_project_path: String,
     pub xmp_template_path: String,
+    /// Optional `overlay-layout/v1` JSON composited onto booth outputs.
+    #[serde(default)]
+    pub overlay_layout_path: Option<String>,
     pub preview_profile: DraftRenderProfileDto,
     pub final_profile: DraftRenderProfileDto,
     pub noise_policy: DraftNoisePolicyDto,
@@ -851,6 +1215,9 @@ pub struct OperatorAuditEntryDto {
     pub event_id: String,
     pub occurred_at: String,
     pub session_id: Option<String>,
+    /// Branch the booth was linked to when the entry was written.
+    #[serde(default)]
+    pub branch_id: Option<String>,
     pub event_category: String,
     pub event_type: String,
     pub summary: String,
@@ -904,6 +1271,55 @@ pub struct OperatorAuditQueryResultDto {
     pub summary: OperatorAuditQuerySummaryDto,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperatorSessionTimelineInputDto {
+    /// Defaults to the session the operator console is currently showing.
+    #[serde(default)]
+    pub session_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperatorTimelineEntryDto {
+    pub occurred_at: String,
+    /// `operator-audit`, `session-journal`, `timing-log`, `render`,
+    /// `fast-preview` or `camera-helper`.
+    pub source: String,
+    pub event: String,
+    pub capture_id: Option<String>,
+    pub request_id: Option<String>,
+    pub stage: Option<String>,
+    pub reason_code: Option<String>,
+    pub detail: Option<String>,
+}
+
+/// Milliseconds from capture acknowledgement to each later milestone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperatorCaptureLatencyDto {
+    pub capture_id: String,
+    pub request_id: String,
+    pub render_status: String,
+    pub capture_acknowledged_at_ms: u64,
+    pub raw_persisted_ms: Option<u64>,
+    pub fast_preview_visible_ms: Option<u64>,
+    pub xmp_preview_ready_ms: Option<u64>,
+    pub preview_visible_ms: Option<u64>,
+    pub final_ready_ms: Option<u64>,
+    pub preview_budget_ms: u64,
+    pub preview_budget_state: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperatorSessionTimelineDto {
+    pub schema_version: String,
+    pub session_id: String,
+    pub entries: Vec<OperatorTimelineEntryDto>,
+    pub capture_latencies: Vec<OperatorCaptureLatencyDto>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OperatorRecentFailureSummaryDto {
@@ -942,6 +1358,7 @@ pub struct OperatorSessionSummaryDto {
     pub capture_boundary: OperatorBoundarySummaryDto,
     pub preview_render_boundary: OperatorBoundarySummaryDto,
     pub completion_boundary: OperatorBoundarySummaryDto,
+    pub print_boundary: OperatorBoundarySummaryDto,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub live_capture_truth: Option<LiveCaptureTruthDto>,
 }
@@ -1100,6 +1517,348 @@ pub struct BranchRolloutOverviewResultDto {
     pub approved_baselines: Vec<BranchReleaseBaselineDto>,
     pub branches: Vec<BranchRolloutBranchStateDto>,
     pub recent_history: Vec<BranchRolloutAuditEntryDto>,
+    #[serde(default)]
+    pub rollout_plans: Vec<BranchRolloutPlanDto>,
+}
+
+/// Counts tolerated per wave before it pauses instead of promoting, and the
+/// sessions a wave must see before it may promote.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchRolloutHealthThresholdsDto {
+    pub max_critical_failures: u32,
+    pub max_render_failures: u32,
+    pub max_capture_timeouts: u32,
+    #[serde(default = "default_min_observed_sessions")]
+    pub min_observed_sessions: u32,
+}
+
+impl Default for BranchRolloutHealthThresholdsDto {
+    fn default() -> Self {
+        Self {
+            max_critical_failures: 0,
+            max_render_failures: 1,
+            max_capture_timeouts: 1,
+            min_observed_sessions: default_min_observed_sessions(),
+        }
+    }
+}
+
+fn default_min_observed_sessions() -> u32 {
+    3
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchRolloutHealthSignalsDto {
+    pub evaluated_at: String,
+    /// Sessions with audit entries from the wave's branches since the wave
+    /// started.
+    pub observed_session_ids: Vec<String>,
+    /// `critical-failure` audit events other than render failures and
+    /// capture timeouts.
+    pub critical_failures: u32,
+    pub render_failures: u32,
+    pub capture_timeouts: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchRolloutWaveDto {
+    pub wave_index: u32,
+    /// `canary` or the cumulative share of the remaining branches, e.g. `50%`.
+    pub label: String,
+    pub branch_ids: Vec<String>,
+    /// `pending`, `in-progress`, `promoted`, `paused` or `rolled-back`.
+    pub status: String,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub health: Option<BranchRolloutHealthSignalsDto>,
+    #[serde(default)]
+    pub outcomes: Vec<BranchRolloutBranchResultDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchRolloutPlanDto {
+    pub schema_version: String,
+    pub plan_id: String,
+    pub target_baseline: BranchReleaseBaselineDto,
+    pub approval: BranchRolloutApprovalDto,
+    /// `in-progress`, `paused`, `completed` or `rolled-back`.
+    pub status: String,
+    pub soak_minutes: u32,
+    pub health_thresholds: BranchRolloutHealthThresholdsDto,
+    pub waves: Vec<BranchRolloutWaveDto>,
+    pub pause_reason: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchRolloutPlanInputDto {
+    pub canary_branch_ids: Vec<String>,
+    /// Branches after the canary, split by `wave_percentages`.
+    #[serde(default)]
+    pub branch_ids: Vec<String>,
+    /// Cumulative shares of `branch_ids`, e.g. `[25, 50, 100]`.
+    #[serde(default)]
+    pub wave_percentages: Vec<u32>,
+    pub target_build_version: String,
+    pub target_preset_stack_version: String,
+    pub actor_id: String,
+    pub actor_label: String,
+    #[serde(default)]
+    pub soak_minutes: Option<u32>,
+    #[serde(default)]
+    pub health_thresholds: Option<BranchRolloutHealthThresholdsDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchRolloutPlanRollbackInputDto {
+    pub plan_id: String,
+    pub actor_id: String,
+    pub actor_label: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchRolloutPlanResultDto {
+    pub schema_version: String,
+    pub plan: BranchRolloutPlanDto,
+    pub action_result: Option<BranchRolloutActionResultDto>,
+    pub message: String,
+}
+
+/// Branch-owned settings that rollouts preserve and operators may edit.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchLocalSettingsDto {
+    pub contact_phone: Option<String>,
+    pub contact_email: Option<String>,
+    pub contact_kakao: Option<String>,
+    /// `HH:MM-HH:MM` in booth local time.
+    pub support_hours: Option<String>,
+    #[serde(default)]
+    pub operational_toggles: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchLocalSettingsQueryInputDto {
+    pub branch_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchLocalSettingsUpdateInputDto {
+    pub branch_id: String,
+    pub settings: BranchLocalSettingsDto,
+    /// Revision the editor started from; a stale value is rejected.
+    #[serde(default)]
+    pub expected_revision: Option<u32>,
+    pub actor_id: String,
+    pub actor_label: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchLocalSettingsDiffInputDto {
+    pub branch_id: String,
+    pub from_revision: u32,
+    /// Defaults to the current revision.
+    #[serde(default)]
+    pub to_revision: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchLocalSettingsChangeDto {
+    /// One of the `BranchLocalSettingsPreservationDto::preserved_fields` names.
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchLocalSettingsHistoryEntryDto {
+    pub schema_version: String,
+    pub branch_id: String,
+    pub revision: u32,
+    pub changed_at: String,
+    pub actor_id: String,
+    pub actor_label: String,
+    pub changes: Vec<BranchLocalSettingsChangeDto>,
+    pub previous_settings: BranchLocalSettingsDto,
+    pub settings: BranchLocalSettingsDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchLocalSettingsResultDto {
+    pub schema_version: String,
+    pub branch_id: String,
+    pub display_name: String,
+    pub revision: u32,
+    pub settings: BranchLocalSettingsDto,
+    /// Newest first.
+    pub history: Vec<BranchLocalSettingsHistoryEntryDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchLocalSettingsDiffDto {
+    pub schema_version: String,
+    pub branch_id: String,
+    pub from_revision: u32,
+    pub to_revision: u32,
+    pub changes: Vec<BranchLocalSettingsChangeDto>,
+}
+
+/// One entry of the typed operational toggle registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationalToggleDefinitionDto {
+    pub name: String,
+    /// `bool`, `int` or `enum`.
+    pub kind: String,
+    pub default_value: String,
+    pub min_value: Option<i64>,
+    pub max_value: Option<i64>,
+    #[serde(default)]
+    pub allowed_values: Vec<String>,
+    pub description: String,
+    pub min_build_version: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchOperationalToggleStateDto {
+    pub name: String,
+    pub value: String,
+    /// `branch` when the branch sets it, otherwise `default`.
+    pub source: String,
+    /// Whether the branch's deployed build reads this toggle.
+    pub supported: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchOperationalTogglesResultDto {
+    pub schema_version: String,
+    pub branch_id: String,
+    pub display_name: String,
+    pub build_version: String,
+    pub registry: Vec<OperationalToggleDefinitionDto>,
+    pub toggles: Vec<BranchOperationalToggleStateDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetSyncInputDto {
+    pub actor_id: String,
+    pub actor_label: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetSyncBundleOutcomeDto {
+    pub preset_id: String,
+    pub published_version: String,
+    /// `installed` or `already-present`.
+    pub result: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetSyncResultDto {
+    pub schema_version: String,
+    /// `synced`, `up-to-date` or `rejected`.
+    pub status: String,
+    pub store_id: String,
+    pub release_id: Option<String>,
+    pub synced_at: String,
+    pub added_baselines: Vec<BranchReleaseBaselineDto>,
+    pub preset_bundles: Vec<FleetSyncBundleOutcomeDto>,
+    /// Set when `status` is `rejected`; nothing was installed.
+    pub rejection_code: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetSigningKeyGenerateInputDto {
+    pub actor_id: String,
+    pub actor_label: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetSigningKeyTrustInputDto {
+    pub key_id: String,
+    /// Hex-encoded Ed25519 public key.
+    pub public_key: String,
+    pub actor_id: String,
+    pub actor_label: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetSigningKeyRevokeInputDto {
+    pub key_id: String,
+    pub actor_id: String,
+    pub actor_label: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetBundleResignInputDto {
+    pub actor_id: String,
+    pub actor_label: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetBundleRefDto {
+    pub preset_id: String,
+    pub published_version: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetBundleResignResultDto {
+    pub schema_version: String,
+    pub key_id: String,
+    /// Published bundles that had no `bundle-signature.json` and are now
+    /// signed with `key_id`.
+    pub signed_bundles: Vec<PresetBundleRefDto>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetSigningKeyDto {
+    pub key_id: String,
+    pub public_key: String,
+    /// `branch` for keys generated on this booth, `imported` for trusted
+    /// keys from HQ or other branches.
+    pub origin: String,
+    pub added_at: String,
+    pub actor_id: String,
+    pub revoked_at: Option<String>,
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetSigningKeysResultDto {
+    pub schema_version: String,
+    pub branch_id: Option<String>,
+    pub active_key_id: Option<String>,
+    pub keys: Vec<PresetSigningKeyDto>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1157,6 +1916,43 @@ pub struct PresetSelectionResultDto {
     pub manifest: SessionManifest,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintJobInputDto {
+    pub session_id: String,
+    pub template_id: String,
+    #[serde(default)]
+    pub capture_ids: Vec<String>,
+    #[serde(default = "default_print_copies")]
+    pub copies: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintJobResultDto {
+    pub schema_version: String,
+    pub session_id: String,
+    pub job: SessionPrintJob,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionPrintJobsInputDto {
+    pub session_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintJobListDto {
+    pub schema_version: String,
+    pub session_id: String,
+    pub jobs: Vec<SessionPrintJob>,
+}
+
+fn default_print_copies() -> u32 {
+    1
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CaptureReadinessInputDto {
@@ -1178,6 +1974,48 @@ pub struct CaptureDeleteInputDto {
     pub capture_id: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShotLayoutRetakeInputDto {
+    pub session_id: String,
+    pub slot_id: String,
+}
+
+/// Host-timed capture program. `countdown` takes one shot after the
+/// countdown; `burst` takes `shot_count` shots `shot_interval_ms` apart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureProgramInputDto {
+    pub session_id: String,
+    pub mode: String,
+    #[serde(default)]
+    pub countdown_seconds: Option<u32>,
+    #[serde(default)]
+    pub shot_count: Option<u32>,
+    #[serde(default)]
+    pub shot_interval_ms: Option<u64>,
+}
+
+/// Omitted flags keep their current value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureSelectionInputDto {
+    pub session_id: String,
+    pub capture_id: String,
+    #[serde(default)]
+    pub selected: Option<bool>,
+    #[serde(default)]
+    pub favorite: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureSelectionSummaryDto {
+    pub selected_capture_ids: Vec<String>,
+    pub favorite_capture_ids: Vec<String>,
+    pub max_selected_captures: Option<u32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CaptureReadinessDto {
@@ -1197,6 +2035,8 @@ pub struct CaptureReadinessDto {
     pub post_end: Option<SessionPostEnd>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timing: Option<SessionTiming>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selection: Option<CaptureSelectionSummaryDto>,
 }
 
 impl CaptureReadinessDto {
@@ -1225,6 +2065,7 @@ impl CaptureReadinessDto {
             live_capture_truth: None,
             post_end: None,
             timing: None,
+            selection: None,
         }
     }
 
@@ -1238,6 +2079,11 @@ impl CaptureReadinessDto {
         self
     }
 
+    pub fn with_selection(mut self, selection: Option<CaptureSelectionSummaryDto>) -> Self {
+        self.selection = selection;
+        self
+    }
+
     pub fn with_latest_capture(mut self, latest_capture: Option<SessionCaptureRecord>) -> Self {
         self.latest_capture = latest_capture;
         self
@@ -1535,6 +2381,20 @@ impl CaptureReadinessUpdateDto {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureProgramTickDto {
+    pub schema_version: String,
+    pub session_id: String,
+    pub program_id: String,
+    /// `countdown` while counting down, `shot` right before each shot.
+    pub phase: String,
+    pub remaining_seconds: u32,
+    pub shot_index: u32,
+    pub shot_count: u32,
+    pub readiness: CaptureReadinessDto,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CaptureFastPreviewUpdateDto {
@@ -1592,11 +2452,72 @@ pub struct CaptureDeleteResultDto {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct CaptureProgramResultDto {
+    pub schema_version: String,
+    pub session_id: String,
+    pub program_id: String,
+    pub mode: String,
+    /// `program-completed`, or `program-stopped` when the session ended
+    /// before every shot was taken.
+    pub status: String,
+    pub captures: Vec<SessionCaptureRecord>,
+    pub readiness: CaptureReadinessDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureSelectionResultDto {
+    pub schema_version: String,
+    pub session_id: String,
+    pub capture_id: String,
+    pub status: String,
+    pub manifest: SessionManifest,
+    pub readiness: CaptureReadinessDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShotLayoutRetakeResultDto {
+    pub schema_version: String,
+    pub session_id: String,
+    pub slot_id: String,
+    pub status: String,
+    pub manifest: SessionManifest,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct HostFieldErrors {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phone_last_four: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact_phone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact_email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact_kakao: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub support_hours: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operational_toggles: Option<String>,
+}
+
+impl HostFieldErrors {
+    pub fn has_errors(&self) -> bool {
+        [
+            &self.name,
+            &self.phone_last_four,
+            &self.contact_phone,
+            &self.contact_email,
+            &self.contact_kakao,
+            &self.support_hours,
+            &self.operational_toggles,
+        ]
+        .iter()
+        .any(|field| field.is_some())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,10 +13,13 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 
-use crate::contracts::dto::{
-    validate_operator_audit_query_filter, CapabilitySnapshotDto, HostErrorEnvelope,
-    OperatorAuditEntryDto, OperatorAuditLatestOutcomeDto, OperatorAuditQueryFilterDto,
-    OperatorAuditQueryResultDto, OperatorAuditQuerySummaryDto,
+use crate::{
+    branch_config::load_branch_id_in_dir,
+    contracts::dto::{
+        validate_operator_audit_query_filter, CapabilitySnapshotDto, HostErrorEnvelope,
+        OperatorAuditEntryDto, OperatorAuditLatestOutcomeDto, OperatorAuditQueryFilterDto,
+        OperatorAuditQueryResultDto, OperatorAuditQuerySummaryDto,
+    },
 };
 
 const OPERATOR_AUDIT_ENTRY_SCHEMA_VERSION: &str = "operator-audit-entry/v1";
@@ -128,6 +131,7 @@ pub fn append_operator_audit_record(
         ),
         occurred_at: input.occurred_at,
         session_id: input.session_id,
+        branch_id: load_branch_id_in_dir(base_dir),
         event_category: input.event_category.into(),
         event_type: input.event_type.into(),
         summary: input.summary,
@@ -142,6 +146,40 @@ pub fn append_operator_audit_record(
     persist_audit_store(base_dir, &store)
 }
 
+/// Every stored entry for `session_id`, in the order it was appended.
+pub(crate) fn read_operator_audit_entries_for_session(
+    base_dir: &Path,
+    session_id: &str,
+) -> Result<Vec<OperatorAuditEntryDto>, HostErrorEnvelope> {
+    wait_for_audit_store_idle(base_dir)?;
+
+    Ok(read_audit_store(base_dir)?
+        .entries
+        .into_iter()
+        .filter(|entry| entry.session_id.as_deref() == Some(session_id))
+        .collect())
+}
+
+/// Every stored entry written by a booth linked to one of `branch_ids`, in
+/// the order it was appended.
+pub(crate) fn read_operator_audit_entries_for_branches(
+    base_dir: &Path,
+    branch_ids: &[String],
+) -> Result<Vec<OperatorAuditEntryDto>, HostErrorEnvelope> {
+    wait_for_audit_store_idle(base_dir)?;
+
+    Ok(read_audit_store(base_dir)?
+        .entries
+        .into_iter()
+        .filter(|entry| {
+            entry
+                .branch_id
+                .as_ref()
+                .is_some_and(|branch_id| branch_ids.contains(branch_id))
+        })
+        .collect())
+}
+
 pub fn try_append_operator_audit_record(base_dir: &Path, input: OperatorAuditRecordInput) {
     let _ = append_operator_audit_record(base_dir, input);
 }
@@ -1,5 +1,6 @@
 pub mod audit_log;
 pub mod recovery;
+pub mod session_timeline;
 
 use std::{
     cmp::Ordering,
@@ -16,9 +17,15 @@ use crate::{
         OperatorSessionSummaryDto,
     },
     handoff::project_post_end_state_in_dir,
+    print::{
+        print_queue::resolve_print_queue, project_print_job_states, PRINT_JOB_STATUS_FAILED,
+        PRINT_JOB_STATUS_PRINTED, PRINT_JOB_STATUS_SPOOLED,
+    },
+    render::render_backend::FALLBACK_RENDER_BACKEND_ID,
     session::{
+        session_journal::session_last_modified_at,
         session_manifest::{
-            rfc3339_to_unix_seconds, SessionManifest, SESSION_POST_END_COMPLETED,
+            rfc3339_to_unix_seconds, SessionManifest, SessionPrintJob, SESSION_POST_END_COMPLETED,
             SESSION_POST_END_EXPORT_WAITING, SESSION_POST_END_PHONE_REQUIRED,
         },
         session_paths::SessionPaths,
@@ -70,11 +77,20 @@ pub fn load_operator_session_summary_in_dir(
         .as_ref()
         .map(|capture| capture.render_status.as_str());
     let capture_boundary = build_capture_boundary(&manifest, readiness.reason_code.as_str());
-    let preview_render_boundary = build_preview_render_boundary(render_status);
+    let render_backend = readiness
+        .latest_capture
+        .as_ref()
+        .and_then(|capture| capture.render_backend.as_deref());
+    let preview_render_boundary = build_preview_render_boundary(render_status, render_backend);
     let completion_boundary = build_completion_boundary(
         manifest.post_end.as_ref().map(|post_end| post_end.state()),
         manifest.timing.as_ref().map(|timing| timing.phase.as_str()),
     );
+    let print_boundary = build_print_boundary(&project_print_job_states(
+        &manifest.print_jobs,
+        resolve_print_queue(base_dir).as_ref(),
+        &manifest.updated_at,
+    ));
     let camera_connection = build_camera_connection_summary(
         &manifest,
         readiness.reason_code.as_str(),
@@ -118,6 +134,7 @@ pub fn load_operator_session_summary_in_dir(
         capture_boundary,
         preview_render_boundary,
         completion_boundary,
+        print_boundary,
         live_capture_truth,
     })
 }
@@ -196,6 +213,10 @@ fn build_no_session_summary() -> OperatorSessionSummaryDto {
             "후처리 경계 비어 있음",
             "현재 세션이 시작되면 completion 경계 진단을 함께 보여 드릴게요.",
         ),
+        print_boundary: clear_boundary(
+            "인화 경계 비어 있음",
+            "현재 세션이 시작되면 인화 작업 상태를 함께 보여 드릴게요.",
+        ),
         live_capture_truth: None,
     }
 }
@@ -406,9 +427,7 @@ fn load_current_session_manifest(
             continue;
         }
 
-        let modified_at = fs::metadata(&manifest_path)
-            .ok()
-            .and_then(|metadata| metadata.modified().ok());
+        let modified_at = session_last_modified_at(&manifest_path);
         candidates.push(SessionManifestCandidate {
             session_name,
             manifest_path,
@@ -526,7 +545,10 @@ fn build_capture_boundary(
     }
 }
 
-fn build_preview_render_boundary(render_status: Option<&str>) -> OperatorBoundarySummaryDto {
+fn build_preview_render_boundary(
+    render_status: Option<&str>,
+    render_backend: Option<&str>,
+) -> OperatorBoundarySummaryDto {
     match render_status {
         Some("captureSaved") | Some("previewWaiting") => blocked_boundary(
             "프리뷰/렌더 결과 준비 지연",
@@ -536,6 +558,14 @@ fn build_preview_render_boundary(render_status: Option<&str>) -> OperatorBoundar
             "프리뷰/렌더 결과 준비 실패",
             "가장 최근 촬영본의 결과를 만들지 못해 직원 확인이 필요해요.",
         ),
+        Some("previewReady") | Some("finalReady")
+            if render_backend == Some(FALLBACK_RENDER_BACKEND_ID) =>
+        {
+            clear_boundary(
+                "프리뷰/렌더 간이 모드",
+                "darktable-cli를 찾지 못해 기본 보정만 적용한 간이 결과를 보여 주고 있어요.",
+            )
+        }
         Some("previewReady") | Some("finalReady") => clear_boundary(
             "프리뷰/렌더 경계 정상",
             "가장 최근 촬영본의 결과 준비가 끝나 있어요.",
@@ -712,6 +742,43 @@ fn build_completion_recent_failure(
     }
 }
 
+fn build_print_boundary(print_jobs: &[SessionPrintJob]) -> OperatorBoundarySummaryDto {
+    let Some(latest_job) = print_jobs.last() else {
+        return clear_boundary(
+            "인화 요청 없음",
+            "이 세션에서는 아직 인화를 요청하지 않았어요.",
+        );
+    };
+
+    if latest_job.status == PRINT_JOB_STATUS_FAILED {
+        return blocked_boundary(
+            "인화 실패",
+            "최근 인화 작업이 프린터까지 전달되지 않았어요. 인화 대기열과 프린터 상태를 확인해 주세요.",
+        );
+    }
+
+    let spooled_count = print_jobs
+        .iter()
+        .filter(|job| job.status == PRINT_JOB_STATUS_SPOOLED)
+        .count();
+    if spooled_count > 0 {
+        return clear_boundary(
+            "인화 진행 중",
+            &format!("인화 작업 {spooled_count}건이 프린터 대기열에 있어요."),
+        );
+    }
+
+    let printed_count = print_jobs
+        .iter()
+        .filter(|job| job.status == PRINT_JOB_STATUS_PRINTED)
+        .count();
+
+    clear_boundary(
+        "인화 완료",
+        &format!("요청한 인화 작업 {printed_count}건이 출력됐어요."),
+    )
+}
+
 fn clear_boundary(title: &str, detail: &str) -> OperatorBoundarySummaryDto {
     OperatorBoundarySummaryDto {
         status: "clear".into(),
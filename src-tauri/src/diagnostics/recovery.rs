@@ -5,6 +5,7 @@ use std::{
 };
 
 use crate::{
+    branch_config::session_packages::default_session_package,
     capture::{
         ingest_pipeline::complete_preview_render_in_dir,
         normalized_state::get_capture_readiness_in_dir,
@@ -21,19 +22,21 @@ use crate::{
     },
     handoff::sync_post_end_state_in_dir,
     session::{
+        session_journal::{commit_session_manifest, session_last_modified_at, SessionJournalEvent},
         session_manifest::{
             current_timestamp, rfc3339_to_unix_seconds, unix_seconds_to_rfc3339, SessionManifest,
-            SessionPostEnd, SESSION_POST_END_PHONE_REQUIRED, WARNING_LEAD_SECONDS,
+            SessionPostEnd, SESSION_POST_END_PHONE_REQUIRED,
         },
         session_paths::SessionPaths,
-        session_repository::{read_session_manifest, write_session_manifest},
+        session_repository::read_session_manifest,
     },
     timing::{evaluate_phase, sync_session_timing_in_dir, TimingPhase},
 };
 
 const OPERATOR_RECOVERY_SUMMARY_SCHEMA_VERSION: &str = "operator-recovery-summary/v1";
 const OPERATOR_RECOVERY_ACTION_RESULT_SCHEMA_VERSION: &str = "operator-recovery-action-result/v1";
-const APPROVED_EXTENSION_MINUTES: u32 = 5;
+/// One operator approval adds at most this much, within the package cap.
+const APPROVED_EXTENSION_STEP_MINUTES: u32 = 5;
 const ROUTE_PHONE_REQUIRED_PRIMARY_ACTION: &str = "가까운 직원에게 알려 주세요.";
 const ROUTE_PHONE_REQUIRED_SUPPORT_ACTION: &str = "직원에게 도움을 요청해 주세요.";
 const ROUTE_PHONE_REQUIRED_WARNING: &str = "다시 찍기나 기기 조작은 잠시 멈춰 주세요.";
@@ -227,7 +230,11 @@ fn execute_boundary_restart(
             manifest.post_end = None;
             manifest.lifecycle.stage = derive_active_lifecycle_stage(&manifest);
             manifest.updated_at = current_timestamp(SystemTime::now())?;
-            write_session_manifest(&paths.manifest_path, &manifest)?;
+            commit_session_manifest(
+                &paths.manifest_path,
+                &manifest,
+                SessionJournalEvent::manifest_updated("operator-boundary-restart", &manifest),
+            )?;
 
             let refreshed_summary =
                 load_operator_recovery_summary_in_dir(base_dir, capability_snapshot)?;
@@ -256,8 +263,7 @@ fn execute_boundary_restart(
 
             latest_capture.preview.asset_path = None;
             latest_capture.preview.ready_at_ms = None;
-            latest_capture.final_asset.asset_path = None;
-            latest_capture.final_asset.ready_at_ms = None;
+            latest_capture.final_asset.clear();
             latest_capture.render_status = "previewWaiting".into();
             latest_capture.post_end_state = "activeSession".into();
             latest_capture.timing.preview_visible_at_ms = None;
@@ -267,7 +273,11 @@ fn execute_boundary_restart(
             manifest.post_end = None;
             manifest.lifecycle.stage = "preview-waiting".into();
             manifest.updated_at = current_timestamp(SystemTime::now())?;
-            write_session_manifest(&paths.manifest_path, &manifest)?;
+            commit_session_manifest(
+                &paths.manifest_path,
+                &manifest,
+                SessionJournalEvent::manifest_updated("operator-render-restart", &manifest),
+            )?;
 
             let refreshed_summary =
                 load_operator_recovery_summary_in_dir(base_dir, capability_snapshot)?;
@@ -333,30 +343,46 @@ fn execute_time_extension(
         );
     };
 
-    if timing.approved_extension_minutes >= APPROVED_EXTENSION_MINUTES {
+    // Sessions recorded before package tiers carry no package; they get the
+    // built-in tier's cap and warning lead.
+    let package = timing
+        .package
+        .clone()
+        .unwrap_or_else(default_session_package);
+    let extension_minutes = package
+        .max_extension_minutes
+        .saturating_sub(timing.approved_extension_minutes)
+        .min(APPROVED_EXTENSION_STEP_MINUTES);
+
+    if extension_minutes == 0 {
         return build_rejected_result(
             base_dir,
             input.session_id,
             input.action,
             "extension-limit-reached",
-            "이 세션에는 이미 허용된 최대 시간 연장이 적용되어 추가 연장을 열지 않았어요.",
+            if package.max_extension_minutes == 0 {
+                "이 세션의 이용권은 시간 연장을 허용하지 않아요."
+            } else {
+                "이 세션에는 이미 허용된 최대 시간 연장이 적용되어 추가 연장을 열지 않았어요."
+            },
             current_summary,
         );
     }
 
     let new_adjusted_end_at = unix_seconds_to_rfc3339(
         rfc3339_to_unix_seconds(&timing.adjusted_end_at)?
-            .saturating_add((APPROVED_EXTENSION_MINUTES as u64) * 60),
+            .saturating_add(u64::from(extension_minutes) * 60),
     );
     let new_warning_at = unix_seconds_to_rfc3339(
-        rfc3339_to_unix_seconds(&new_adjusted_end_at)?.saturating_sub(WARNING_LEAD_SECONDS),
+        rfc3339_to_unix_seconds(&new_adjusted_end_at)?
+            .saturating_sub(u64::from(package.warning_lead_minutes) * 60),
     );
     let event_timestamp = current_timestamp(SystemTime::now())?;
     let mut next_timing = timing.clone();
 
     next_timing.adjusted_end_at = new_adjusted_end_at.clone();
     next_timing.warning_at = new_warning_at.clone();
-    next_timing.approved_extension_minutes += APPROVED_EXTENSION_MINUTES;
+    next_timing.approved_extension_minutes += extension_minutes;
     next_timing.approved_extension_audit_ref = Some(format!(
         "operator-recovery:{}:approved-time-extension",
         audit_session_id
@@ -398,7 +424,11 @@ fn execute_time_extension(
 
     manifest.lifecycle.stage = derive_active_lifecycle_stage(&manifest);
     manifest.updated_at = event_timestamp;
-    write_session_manifest(&paths.manifest_path, &manifest)?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::manifest_updated("operator-time-extension", &manifest),
+    )?;
 
     let refreshed_summary = load_operator_recovery_summary_in_dir(base_dir, capability_snapshot)?;
 
@@ -430,7 +460,11 @@ fn execute_phone_required_route(
     ));
     manifest.lifecycle.stage = "phone-required".into();
     manifest.updated_at = event_timestamp;
-    write_session_manifest(&paths.manifest_path, &manifest)?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::manifest_updated("operator-phone-required-route", &manifest),
+    )?;
 
     let refreshed_summary = load_operator_recovery_summary_in_dir(base_dir, capability_snapshot)?;
 
@@ -821,9 +855,7 @@ fn load_current_session_manifest(
             continue;
         }
 
-        let modified_at = fs::metadata(&manifest_path)
-            .ok()
-            .and_then(|metadata| metadata.modified().ok());
+        let modified_at = session_last_modified_at(&manifest_path);
         candidates.push(SessionManifestCandidate {
             session_name,
             manifest_path,
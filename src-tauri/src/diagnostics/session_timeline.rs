@@ -0,0 +1,235 @@
+//! Full chronology of one session for the operator console. Merges the
+//! camera helper events, `timing-events.log` (timing, render and fast
+//! preview lines), the session journal and the operator audit store, and
+//! derives per-capture latency from `CaptureTimingMetrics`.
+
+use std::{fs, path::Path};
+
+use serde_json::Value;
+
+use crate::{
+    capture::sidecar_client::CAMERA_HELPER_EVENTS_FILE_NAME,
+    contracts::dto::{
+        CapabilitySnapshotDto, HostErrorEnvelope, OperatorCaptureLatencyDto,
+        OperatorSessionTimelineDto, OperatorSessionTimelineInputDto, OperatorTimelineEntryDto,
+    },
+    session::{
+        session_journal::read_session_journal_in_dir,
+        session_manifest::{rfc3339_to_unix_seconds, SessionCaptureRecord},
+        session_paths::SessionPaths,
+        session_repository::read_session_manifest,
+    },
+};
+
+use super::{
+    audit_log::read_operator_audit_entries_for_session, ensure_operator_access,
+    find_current_operator_session_id_in_dir,
+};
+
+const OPERATOR_SESSION_TIMELINE_SCHEMA_VERSION: &str = "operator-session-timeline/v1";
+const TIMING_EVENTS_FILE_NAME: &str = "timing-events.log";
+
+pub fn load_operator_session_timeline_in_dir(
+    base_dir: &Path,
+    capability_snapshot: &CapabilitySnapshotDto,
+    input: OperatorSessionTimelineInputDto,
+) -> Result<OperatorSessionTimelineDto, HostErrorEnvelope> {
+    ensure_operator_access(capability_snapshot)?;
+
+    let session_id = match input.session_id {
+        Some(session_id) => session_id,
+        None => find_current_operator_session_id_in_dir(base_dir)?.ok_or_else(|| {
+            HostErrorEnvelope::session_not_found("타임라인을 볼 진행 중인 세션이 없어요.")
+        })?,
+    };
+    let paths = SessionPaths::try_new(base_dir, &session_id)?;
+    let manifest = read_session_manifest(&paths.manifest_path)?;
+
+    // Sources are concatenated in causal order so the stable sort below keeps
+    // helper, host log, journal and audit lines from the same second in that
+    // order.
+    let mut entries = read_helper_timeline_entries(&paths);
+    entries.extend(read_timing_log_timeline_entries(&paths));
+    entries.extend(
+        read_session_journal_in_dir(base_dir, &session_id)?
+            .into_iter()
+            .map(|entry| OperatorTimelineEntryDto {
+                occurred_at: entry.occurred_at,
+                source: "session-journal".into(),
+                event: entry.event.name().into(),
+                capture_id: entry.event.capture_id().map(str::to_string),
+                request_id: None,
+                stage: Some(entry.lifecycle_stage),
+                reason_code: entry.event.reason().map(str::to_string),
+                detail: None,
+            }),
+    );
+    entries.extend(
+        read_operator_audit_entries_for_session(base_dir, &session_id)?
+            .into_iter()
+            .map(|entry| OperatorTimelineEntryDto {
+                occurred_at: entry.occurred_at,
+                source: "operator-audit".into(),
+                event: entry.event_type,
+                capture_id: entry.capture_id,
+                request_id: None,
+                stage: Some(entry.event_category),
+                reason_code: entry.reason_code,
+                detail: Some(entry.summary),
+            }),
+    );
+
+    let mut entries = entries
+        .into_iter()
+        .filter_map(|entry| {
+            rfc3339_to_unix_seconds(&entry.occurred_at)
+                .ok()
+                .map(|seconds| (seconds, entry))
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by_key(|(seconds, _)| *seconds);
+
+    Ok(OperatorSessionTimelineDto {
+        schema_version: OPERATOR_SESSION_TIMELINE_SCHEMA_VERSION.into(),
+        session_id,
+        entries: entries.into_iter().map(|(_, entry)| entry).collect(),
+        capture_latencies: manifest
+            .captures
+            .iter()
+            .map(build_capture_latency)
+            .collect(),
+    })
+}
+
+fn build_capture_latency(capture: &SessionCaptureRecord) -> OperatorCaptureLatencyDto {
+    let acknowledged_at_ms = capture.timing.capture_acknowledged_at_ms;
+    let since_acknowledged =
+        |at_ms: Option<u64>| at_ms.map(|at_ms| at_ms.saturating_sub(acknowledged_at_ms));
+
+    OperatorCaptureLatencyDto {
+        capture_id: capture.capture_id.clone(),
+        request_id: capture.request_id.clone(),
+        render_status: capture.render_status.clone(),
+        capture_acknowledged_at_ms: acknowledged_at_ms,
+        raw_persisted_ms: since_acknowledged(Some(capture.raw.persisted_at_ms)),
+        fast_preview_visible_ms: since_acknowledged(capture.timing.fast_preview_visible_at_ms),
+        xmp_preview_ready_ms: since_acknowledged(capture.timing.xmp_preview_ready_at_ms),
+        preview_visible_ms: since_acknowledged(capture.timing.preview_visible_at_ms),
+        final_ready_ms: since_acknowledged(capture.final_asset.ready_at_ms),
+        preview_budget_ms: capture.timing.preview_budget_ms,
+        preview_budget_state: capture.timing.preview_budget_state.clone(),
+    }
+}
+
+/// Parses the tab-separated `key=value` lines written by the timing policy,
+/// render worker, fast preview promotion and post-end evaluator. Lines with a
+/// `stage` are render events and lines with a `kind` are fast preview events.
+fn read_timing_log_timeline_entries(paths: &SessionPaths) -> Vec<OperatorTimelineEntryDto> {
+    let Ok(contents) = fs::read_to_string(paths.diagnostics_dir.join(TIMING_EVENTS_FILE_NAME))
+    else {
+        return Vec::new();
+    };
+
+    contents.lines().filter_map(parse_timing_log_line).collect()
+}
+
+fn parse_timing_log_line(line: &str) -> Option<OperatorTimelineEntryDto> {
+    let mut parts = line.split('\t');
+    let occurred_at = parts.next()?.trim();
+    if occurred_at.is_empty() {
+        return None;
+    }
+
+    let mut entry = OperatorTimelineEntryDto {
+        occurred_at: occurred_at.into(),
+        source: "timing-log".into(),
+        event: String::new(),
+        capture_id: None,
+        request_id: None,
+        stage: None,
+        reason_code: None,
+        detail: None,
+    };
+    let mut extra = Vec::new();
+
+    for part in parts {
+        let (key, value) = part.split_once('=')?;
+        let value = (value != "none").then(|| value.to_string());
+        match key {
+            "event" => entry.event = value?,
+            "capture" => entry.capture_id = value,
+            "request" => entry.request_id = value,
+            "stage" => {
+                entry.source = "render".into();
+                entry.stage = value;
+            }
+            "reason" => entry.reason_code = value,
+            "detail" => entry.detail = value,
+            "kind" => {
+                entry.source = "fast-preview".into();
+                if let Some(value) = value {
+                    extra.push(format!("kind={value}"));
+                }
+            }
+            "session" => {}
+            _ => {
+                if let Some(value) = value {
+                    extra.push(format!("{key}={value}"));
+                }
+            }
+        }
+    }
+
+    if entry.event.is_empty() {
+        return None;
+    }
+    if !extra.is_empty() {
+        extra.extend(entry.detail.take());
+        entry.detail = Some(extra.join(" "));
+    }
+
+    Some(entry)
+}
+
+/// Helper events without their own timestamp, such as `capture-accepted`,
+/// take the time of the helper event before them.
+fn read_helper_timeline_entries(paths: &SessionPaths) -> Vec<OperatorTimelineEntryDto> {
+    let Ok(contents) =
+        fs::read_to_string(paths.diagnostics_dir.join(CAMERA_HELPER_EVENTS_FILE_NAME))
+    else {
+        return Vec::new();
+    };
+    let mut last_occurred_at: Option<String> = None;
+    let mut entries = Vec::new();
+
+    for line in contents.lines() {
+        let Ok(event) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        let field = |key: &str| event.get(key).and_then(Value::as_str).map(str::to_string);
+        let Some(event_type) = field("type") else {
+            continue;
+        };
+        let occurred_at = field("observedAt")
+            .or_else(|| field("arrivedAt"))
+            .or_else(|| field("requestedAt"))
+            .or_else(|| last_occurred_at.clone());
+        let Some(occurred_at) = occurred_at else {
+            continue;
+        };
+        last_occurred_at = Some(occurred_at.clone());
+
+        entries.push(OperatorTimelineEntryDto {
+            occurred_at,
+            source: "camera-helper".into(),
+            event: event_type,
+            capture_id: field("captureId"),
+            request_id: field("requestId"),
+            stage: field("cameraState"),
+            reason_code: field("detailCode"),
+            detail: None,
+        });
+    }
+
+    entries
+}
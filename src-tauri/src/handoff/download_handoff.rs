@@ -0,0 +1,881 @@
+use std::{
+    collections::HashSet,
+    env, fs,
+    fs::File,
+    io::{self, BufWriter, ErrorKind, Read, Write},
+    net::{IpAddr, Ipv4Addr, Shutdown, SocketAddr, TcpListener, TcpStream, UdpSocket},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, LazyLock, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    branch_config::operational_toggles::{
+        resolve_booth_operational_toggles_in_dir, HANDOFF_DOWNLOAD_TTL_MINUTES,
+    },
+    contracts::dto::{is_valid_session_id, HostErrorEnvelope},
+    handoff::qr_code::QrCode,
+    integrity::encode_hex,
+    session::{
+        session_manifest::{
+            current_timestamp, rfc3339_to_unix_seconds, SessionManifest, SessionPostEnd,
+            SESSION_POST_END_HANDOFF_READY,
+        },
+        session_paths::SessionPaths,
+        session_repository::read_session_manifest,
+    },
+};
+
+pub const DOWNLOAD_HANDOFF_SCHEMA_VERSION: &str = "download-handoff/v1";
+pub const DOWNLOAD_HANDOFF_KIND: &str = "download-qr";
+pub const DOWNLOAD_HANDOFF_ADDR_ENV: &str = "BOOTHY_DOWNLOAD_HANDOFF_ADDR";
+pub const DOWNLOAD_HANDOFF_PUBLIC_URL_ENV: &str = "BOOTHY_DOWNLOAD_HANDOFF_PUBLIC_URL";
+pub const DOWNLOAD_HANDOFF_TTL: Duration = Duration::from_secs(30 * 60);
+
+const DOWNLOAD_TICKET_SCHEMA_VERSION: &str = "download-handoff-ticket/v1";
+const DOWNLOAD_DIR: &str = "download";
+const DOWNLOAD_TICKET_FILE: &str = "ticket.json";
+const DOWNLOAD_ROUTE_PREFIX: &str = "/handoff/";
+const DOWNLOAD_FILE_NAME: &str = "boothy-photos.zip";
+const TOKEN_BYTES: usize = 16;
+const TOKEN_HEX_LENGTH: usize = TOKEN_BYTES * 2;
+const SERVER_POLL_INTERVAL: Duration = Duration::from_millis(50);
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(5);
+const RESPONSE_WRITE_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
+const MAX_CONCURRENT_DOWNLOADS: usize = 8;
+
+static DOWNLOAD_HANDOFF_SERVER: LazyLock<Mutex<Option<DownloadHandoffServerHandle>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadHandoffTicket {
+    pub schema_version: String,
+    pub session_id: String,
+    pub token: String,
+    pub bundle_path: String,
+    pub file_count: u32,
+    pub created_at: String,
+    pub expires_at: String,
+    #[serde(default)]
+    pub consumed_at: Option<String>,
+}
+
+/// Value written into `CompletedPostEnd.handoff` for the booth screen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadHandoffDto {
+    pub schema_version: String,
+    pub kind: String,
+    pub url: String,
+    pub expires_at: String,
+    pub file_count: u32,
+    pub qr_svg: String,
+}
+
+/// Packages the session finals into a one-time bundle under `handoff/download`
+/// and returns the QR/URL payload. An unexpired, unused ticket is reused so
+/// repeated post-end evaluations keep showing the same code.
+pub fn prepare_download_handoff_in_dir(
+    base_dir: &Path,
+    session_id: &str,
+    public_base_url: &str,
+    now: SystemTime,
+) -> Result<DownloadHandoffDto, HostErrorEnvelope> {
+    let paths = SessionPaths::try_new(base_dir, session_id)?;
+    let manifest = read_session_manifest(&paths.manifest_path)?;
+
+    prepare_download_handoff(
+        &paths,
+        &manifest,
+        public_base_url,
+        now,
+        download_handoff_ttl_in_dir(base_dir),
+    )
+}
+
+/// Attaches a download QR to a freshly completed handoff-ready post-end when
+/// the local download server is running. Failures only cost the QR; the text
+/// guidance still reaches the customer.
+pub(crate) fn attach_download_handoff(
+    base_dir: &Path,
+    manifest: &mut SessionManifest,
+    now: SystemTime,
+) {
+    let Some(public_base_url) = running_download_handoff_base_url() else {
+        return;
+    };
+    let needs_handoff = matches!(
+        manifest.post_end.as_ref(),
+        Some(SessionPostEnd::Completed(completed))
+            if completed.completion_variant == SESSION_POST_END_HANDOFF_READY
+                && completed.handoff.is_none()
+    );
+    if !needs_handoff {
+        return;
+    }
+
+    let handoff = SessionPaths::try_new(base_dir, &manifest.session_id)
+        .and_then(|paths| {
+            prepare_download_handoff(
+                &paths,
+                manifest,
+                &public_base_url,
+                now,
+                download_handoff_ttl_in_dir(base_dir),
+            )
+        })
+        .and_then(|handoff| {
+            serde_json::to_value(handoff).map_err(|error| {
+                HostErrorEnvelope::persistence(format!(
+                    "다운로드 안내를 직렬화하지 못했어요: {error}"
+                ))
+            })
+        });
+
+    match handoff {
+        Ok(handoff) => {
+            if let Some(SessionPostEnd::Completed(completed)) = manifest.post_end.as_mut() {
+                completed.handoff = Some(handoff);
+            }
+        }
+        Err(error) => log::warn!(
+            "download_handoff_skipped session={} reason={}",
+            manifest.session_id,
+            error.message
+        ),
+    }
+}
+
+/// Ticket lifetime from the branch's `handoff-download-ttl-minutes` toggle.
+fn download_handoff_ttl_in_dir(base_dir: &Path) -> Duration {
+    resolve_booth_operational_toggles_in_dir(base_dir)
+        .int_value(HANDOFF_DOWNLOAD_TTL_MINUTES)
+        .and_then(|minutes| u64::try_from(minutes).ok())
+        .map_or(DOWNLOAD_HANDOFF_TTL, |minutes| {
+            Duration::from_secs(minutes * 60)
+        })
+}
+
+fn prepare_download_handoff(
+    paths: &SessionPaths,
+    manifest: &SessionManifest,
+    public_base_url: &str,
+    now: SystemTime,
+    ttl: Duration,
+) -> Result<DownloadHandoffDto, HostErrorEnvelope> {
+    let download_dir = paths.handoff_dir.join(DOWNLOAD_DIR);
+    let ticket_path = download_dir.join(DOWNLOAD_TICKET_FILE);
+
+    if let Some(ticket) = read_download_ticket(&ticket_path) {
+        if is_ticket_servable(&ticket, now) && Path::new(&ticket.bundle_path).is_file() {
+            return build_download_handoff_dto(&ticket, public_base_url);
+        }
+    }
+
+    let finals = collect_final_assets(paths, manifest);
+    if finals.is_empty() {
+        return Err(HostErrorEnvelope::validation_message(
+            "내려받을 완성 사진이 아직 없어요.",
+        ));
+    }
+
+    fs::create_dir_all(&download_dir).map_err(map_download_error)?;
+    remove_stale_bundles(&download_dir);
+
+    let token = generate_download_token()?;
+    let bundle_path = download_dir.join(format!("{token}.zip"));
+    write_stored_zip(&bundle_path, &finals)?;
+
+    let ticket = DownloadHandoffTicket {
+        schema_version: DOWNLOAD_TICKET_SCHEMA_VERSION.into(),
+        session_id: manifest.session_id.clone(),
+        token,
+        bundle_path: bundle_path.to_string_lossy().into_owned(),
+        file_count: finals.len() as u32,
+        created_at: current_timestamp(now)?,
+        expires_at: current_timestamp(now + ttl)?,
+        consumed_at: None,
+    };
+    write_download_ticket(&ticket_path, &ticket)?;
+    log::info!(
+        "download_handoff_prepared session={} files={} expires_at={}",
+        manifest.session_id,
+        ticket.file_count,
+        ticket.expires_at
+    );
+
+    build_download_handoff_dto(&ticket, public_base_url)
+}
+
+pub struct DownloadHandoffServerHandle {
+    local_addr: SocketAddr,
+    public_base_url: String,
+    stop: Arc<AtomicBool>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl DownloadHandoffServerHandle {
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    pub fn public_base_url(&self) -> &str {
+        &self.public_base_url
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.worker
+            .as_ref()
+            .map(|worker| !worker.is_finished())
+            .unwrap_or(false)
+    }
+
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Drop for DownloadHandoffServerHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Serves `GET /handoff/<token>` from a background thread. Each connection gets
+/// its own thread, up to `MAX_CONCURRENT_DOWNLOADS`, and a token is claimed
+/// for the length of its delivery so it can never be consumed twice.
+pub fn start_download_handoff_server(
+    base_dir: &Path,
+    bind_addr: &str,
+    public_base_url: Option<String>,
+) -> Result<DownloadHandoffServerHandle, HostErrorEnvelope> {
+    let listener = TcpListener::bind(bind_addr).map_err(map_download_error)?;
+    listener.set_nonblocking(true).map_err(map_download_error)?;
+    let local_addr = listener.local_addr().map_err(map_download_error)?;
+    let public_base_url = public_base_url
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty())
+        .unwrap_or_else(|| default_public_base_url(local_addr));
+    let stop = Arc::new(AtomicBool::new(false));
+    let worker_stop = Arc::clone(&stop);
+    let state = Arc::new(DownloadServerState {
+        base_dir: base_dir.to_path_buf(),
+        active_connections: AtomicUsize::new(0),
+        claimed_tokens: Mutex::new(HashSet::new()),
+    });
+
+    let worker = thread::Builder::new()
+        .name("boothy-download-handoff".into())
+        .spawn(move || {
+            while !worker_stop.load(Ordering::SeqCst) {
+                match listener.accept() {
+                    Ok((stream, _)) => dispatch_connection(&state, stream),
+                    Err(error) if error.kind() == ErrorKind::WouldBlock => {
+                        thread::sleep(SERVER_POLL_INTERVAL)
+                    }
+                    Err(error) => {
+                        log::warn!("download_handoff_accept_failed reason={error}");
+                        thread::sleep(SERVER_POLL_INTERVAL);
+                    }
+                }
+            }
+        })
+        .map_err(map_download_error)?;
+    log::info!("download_handoff_server_started addr={local_addr} public_url={public_base_url}");
+
+    Ok(DownloadHandoffServerHandle {
+        local_addr,
+        public_base_url,
+        stop,
+        worker: Some(worker),
+    })
+}
+
+/// Starts the booth-wide server when `BOOTHY_DOWNLOAD_HANDOFF_ADDR` is set.
+pub fn try_start_download_handoff_server(base_dir: &Path) {
+    let Ok(bind_addr) = env::var(DOWNLOAD_HANDOFF_ADDR_ENV) else {
+        return;
+    };
+    if bind_addr.trim().is_empty() {
+        return;
+    }
+    let Ok(mut guard) = DOWNLOAD_HANDOFF_SERVER.lock() else {
+        return;
+    };
+    if guard
+        .as_ref()
+        .map(DownloadHandoffServerHandle::is_running)
+        .unwrap_or(false)
+    {
+        return;
+    }
+
+    match start_download_handoff_server(
+        base_dir,
+        bind_addr.trim(),
+        env::var(DOWNLOAD_HANDOFF_PUBLIC_URL_ENV).ok(),
+    ) {
+        Ok(handle) => *guard = Some(handle),
+        Err(error) => log::warn!("download_handoff_server_failed reason={}", error.message),
+    }
+}
+
+pub fn shutdown_download_handoff_server() {
+    if let Ok(mut guard) = DOWNLOAD_HANDOFF_SERVER.lock() {
+        guard.take();
+    }
+}
+
+fn running_download_handoff_base_url() -> Option<String> {
+    DOWNLOAD_HANDOFF_SERVER.lock().ok().and_then(|guard| {
+        guard
+            .as_ref()
+            .filter(|handle| handle.is_running())
+            .map(|handle| handle.public_base_url.clone())
+    })
+}
+
+struct DownloadServerState {
+    base_dir: PathBuf,
+    active_connections: AtomicUsize,
+    claimed_tokens: Mutex<HashSet<String>>,
+}
+
+/// Removes the token from the in-flight set when its delivery ends.
+struct DownloadTokenClaim<'a> {
+    state: &'a DownloadServerState,
+    token: String,
+}
+
+impl Drop for DownloadTokenClaim<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut claimed_tokens) = self.state.claimed_tokens.lock() {
+            claimed_tokens.remove(&self.token);
+        }
+    }
+}
+
+fn dispatch_connection(state: &Arc<DownloadServerState>, mut stream: TcpStream) {
+    let _ = stream.set_nonblocking(false);
+    let _ = stream.set_read_timeout(Some(REQUEST_READ_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(RESPONSE_WRITE_TIMEOUT));
+
+    if state.active_connections.fetch_add(1, Ordering::SeqCst) >= MAX_CONCURRENT_DOWNLOADS {
+        state.active_connections.fetch_sub(1, Ordering::SeqCst);
+        let _ = write_text_response(
+            &mut stream,
+            "503 Service Unavailable",
+            "내려받는 사람이 많아요. 잠시 후 다시 시도해 주세요.",
+        );
+        return;
+    }
+
+    let connection_state = Arc::clone(state);
+    let spawned = thread::Builder::new()
+        .name("boothy-download-connection".into())
+        .spawn(move || {
+            serve_connection(&connection_state, stream);
+            connection_state
+                .active_connections
+                .fetch_sub(1, Ordering::SeqCst);
+        });
+    if let Err(error) = spawned {
+        state.active_connections.fetch_sub(1, Ordering::SeqCst);
+        log::warn!("download_handoff_spawn_failed reason={error}");
+    }
+}
+
+fn serve_connection(state: &DownloadServerState, mut stream: TcpStream) {
+    let base_dir = state.base_dir.as_path();
+    let Some(request_line) = read_request_line(&mut stream) else {
+        let _ = write_text_response(&mut stream, "400 Bad Request", "잘못된 요청이에요.");
+        return;
+    };
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let target = parts.next().unwrap_or_default();
+    if method != "GET" {
+        let _ = write_text_response(
+            &mut stream,
+            "405 Method Not Allowed",
+            "내려받기만 지원해요.",
+        );
+        return;
+    }
+
+    let token = target
+        .strip_prefix(DOWNLOAD_ROUTE_PREFIX)
+        .map(|token| token.split(['?', '#']).next().unwrap_or_default())
+        .filter(|token| is_valid_download_token(token));
+    let Some((ticket_path, ticket)) = token.and_then(|token| find_download_ticket(base_dir, token))
+    else {
+        let _ = write_text_response(
+            &mut stream,
+            "404 Not Found",
+            "내려받을 사진을 찾지 못했어요.",
+        );
+        return;
+    };
+
+    let Some(_claim) = claim_download_token(state, &ticket.token) else {
+        let _ = write_text_response(
+            &mut stream,
+            "409 Conflict",
+            "다른 기기에서 이미 내려받는 중이에요.",
+        );
+        return;
+    };
+    // Re-read under the claim: a delivery that finished between lookup and
+    // claim has already marked the ticket consumed.
+    let ticket = read_download_ticket(&ticket_path).unwrap_or(ticket);
+    if !is_ticket_servable(&ticket, SystemTime::now()) {
+        let _ = write_text_response(
+            &mut stream,
+            "410 Gone",
+            "이미 사용했거나 만료된 내려받기 링크예요. 직원에게 문의해 주세요.",
+        );
+        return;
+    }
+
+    let Some((bundle, bundle_length)) = File::open(&ticket.bundle_path).ok().and_then(|file| {
+        let length = file.metadata().ok()?.len();
+        Some((file, length))
+    }) else {
+        let _ = write_text_response(
+            &mut stream,
+            "404 Not Found",
+            "내려받을 사진을 찾지 못했어요.",
+        );
+        return;
+    };
+
+    if let Err(error) = deliver_bundle(&mut stream, bundle, bundle_length) {
+        log::warn!(
+            "download_handoff_delivery_failed session={} reason={error}",
+            ticket.session_id
+        );
+        return;
+    }
+
+    let consumed = DownloadHandoffTicket {
+        consumed_at: current_timestamp(SystemTime::now()).ok(),
+        ..ticket
+    };
+    if let Err(error) = write_download_ticket(&ticket_path, &consumed) {
+        log::warn!(
+            "download_handoff_consume_failed session={} reason={}",
+            consumed.session_id,
+            error.message
+        );
+    }
+    log::info!("download_handoff_delivered session={}", consumed.session_id);
+}
+
+/// Copies the bundle to the socket in chunks, then waits for the client to
+/// close its side. A link preview that drops the connection after the head
+/// resets it instead, so the caller leaves the token unconsumed.
+fn deliver_bundle(stream: &mut TcpStream, mut bundle: File, length: u64) -> io::Result<()> {
+    let head = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/zip\r\nContent-Length: {length}\r\nContent-Disposition: attachment; filename=\"{DOWNLOAD_FILE_NAME}\"\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n"
+    );
+    stream.write_all(head.as_bytes())?;
+    let copied = io::copy(&mut bundle, stream)?;
+    if copied != length {
+        return Err(io::Error::new(
+            ErrorKind::UnexpectedEof,
+            format!("bundle changed while sending: {copied} of {length} bytes"),
+        ));
+    }
+    stream.flush()?;
+    stream.shutdown(Shutdown::Write)?;
+
+    let mut trailing = [0u8; 1024];
+    while stream.read(&mut trailing)? > 0 {}
+
+    Ok(())
+}
+
+fn claim_download_token<'a>(
+    state: &'a DownloadServerState,
+    token: &str,
+) -> Option<DownloadTokenClaim<'a>> {
+    let mut claimed_tokens = state.claimed_tokens.lock().ok()?;
+
+    claimed_tokens
+        .insert(token.to_string())
+        .then(|| DownloadTokenClaim {
+            state,
+            token: token.to_string(),
+        })
+}
+
+fn read_request_line(stream: &mut TcpStream) -> Option<String> {
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        if head.len() > MAX_REQUEST_HEAD_BYTES {
+            return None;
+        }
+        let read = stream.read(&mut buffer).ok()?;
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+
+    String::from_utf8_lossy(&head)
+        .lines()
+        .next()
+        .map(str::to_string)
+        .filter(|line| !line.is_empty())
+}
+
+fn write_text_response(stream: &mut TcpStream, status: &str, body: &str) -> std::io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+
+    stream.write_all(response.as_bytes())
+}
+
+fn find_download_ticket(base_dir: &Path, token: &str) -> Option<(PathBuf, DownloadHandoffTicket)> {
+    let entries = fs::read_dir(base_dir.join("sessions")).ok()?;
+
+    entries.flatten().find_map(|entry| {
+        let session_id = entry.file_name().to_string_lossy().into_owned();
+        if !is_valid_session_id(&session_id) {
+            return None;
+        }
+
+        let ticket_path = SessionPaths::new(base_dir, &session_id)
+            .handoff_dir
+            .join(DOWNLOAD_DIR)
+            .join(DOWNLOAD_TICKET_FILE);
+        read_download_ticket(&ticket_path)
+            .filter(|ticket| ticket.token == token && ticket.session_id == session_id)
+            .map(|ticket| (ticket_path, ticket))
+    })
+}
+
+fn is_ticket_servable(ticket: &DownloadHandoffTicket, now: SystemTime) -> bool {
+    if ticket.consumed_at.is_some() {
+        return false;
+    }
+
+    let now_seconds = now
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+
+    rfc3339_to_unix_seconds(&ticket.expires_at)
+        .map(|expires_at| now_seconds < expires_at)
+        .unwrap_or(false)
+}
+
+fn build_download_handoff_dto(
+    ticket: &DownloadHandoffTicket,
+    public_base_url: &str,
+) -> Result<DownloadHandoffDto, HostErrorEnvelope> {
+    let url = format!(
+        "{}{DOWNLOAD_ROUTE_PREFIX}{}",
+        public_base_url.trim_end_matches('/'),
+        ticket.token
+    );
+    let qr_code = QrCode::encode(url.as_bytes()).ok_or_else(|| {
+        HostErrorEnvelope::validation_message("다운로드 주소가 너무 길어 QR 코드로 만들 수 없어요.")
+    })?;
+
+    Ok(DownloadHandoffDto {
+        schema_version: DOWNLOAD_HANDOFF_SCHEMA_VERSION.into(),
+        kind: DOWNLOAD_HANDOFF_KIND.into(),
+        url,
+        expires_at: ticket.expires_at.clone(),
+        file_count: ticket.file_count,
+        qr_svg: qr_code.to_svg(),
+    })
+}
+
+/// A composed shot layout leads the bundle, followed by the per-capture
+/// finals it was built from.
+fn collect_final_assets(
+    paths: &SessionPaths,
+    manifest: &SessionManifest,
+) -> Vec<(String, PathBuf)> {
+    let composed_layout = manifest.shot_layout.as_ref().and_then(|layout| {
+        let composed = layout.composed_final.as_ref()?;
+        Some((
+            format!("layout-{}.jpg", layout.layout_id),
+            PathBuf::from(&composed.asset_path),
+        ))
+    });
+
+    composed_layout
+        .into_iter()
+        .filter(|(_, asset_path)| {
+            asset_path.starts_with(&paths.renders_finals_dir) && asset_path.is_file()
+        })
+        .chain(manifest.captures.iter().filter_map(|capture| {
+            let asset_path = PathBuf::from(
+                capture
+                    .final_asset
+                    .variant_for("download", &["jpeg"])
+                    .map(|variant| variant.asset_path.as_str())
+                    .or(capture.final_asset.asset_path.as_deref())?,
+            );
+            if !asset_path.starts_with(&paths.renders_finals_dir) || !asset_path.is_file() {
+                return None;
+            }
+
+            let extension = asset_path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .unwrap_or("jpg")
+                .to_ascii_lowercase();
+
+            Some((format!("{}.{extension}", capture.capture_id), asset_path))
+        }))
+        .enumerate()
+        .map(|(index, (name, path))| (format!("{:02}-{name}", index + 1), path))
+        .collect()
+}
+
+/// Writes an uncompressed ZIP archive. Finals are already JPEG-compressed, and
+/// ZIP opens natively on both iOS and Android. Each final is streamed into a
+/// temp file rather than held in memory. Plain ZIP offsets are 32-bit, so
+/// bundles that would pass 4 GiB are refused rather than written corrupt.
+fn write_stored_zip(
+    bundle_path: &Path,
+    entries: &[(String, PathBuf)],
+) -> Result<(), HostErrorEnvelope> {
+    let mut temp_name = bundle_path.as_os_str().to_owned();
+    temp_name.push(".tmp");
+    let temp_path = PathBuf::from(temp_name);
+
+    let written = File::create(&temp_path)
+        .map_err(map_download_error)
+        .and_then(|file| {
+            let mut archive = BufWriter::new(file);
+            write_stored_zip_entries(&mut archive, entries)?;
+            archive
+                .into_inner()
+                .map_err(|error| map_download_error(error.into_error()))?
+                .sync_all()
+                .map_err(map_download_error)
+        })
+        .and_then(|_| fs::rename(&temp_path, bundle_path).map_err(map_download_error));
+    if written.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+
+    written
+}
+
+fn write_stored_zip_entries(
+    archive: &mut impl Write,
+    entries: &[(String, PathBuf)],
+) -> Result<(), HostErrorEnvelope> {
+    const DOS_DATE_1980_01_01: u16 = (1 << 5) | 1;
+    const UTF8_NAME_FLAG: u16 = 1 << 11;
+    let too_large =
+        || HostErrorEnvelope::validation_message("완성 사진이 너무 커서 묶을 수 없어요.");
+    let entry_count = u16::try_from(entries.len()).map_err(|_| too_large())?;
+    let mut offset = 0u64;
+    let mut central_directory = Vec::new();
+
+    for (name, path) in entries {
+        let (crc, size) = stored_entry_checksum(path)?;
+        let size = u32::try_from(size).map_err(|_| too_large())?;
+        let local_header_offset = u32::try_from(offset).map_err(|_| too_large())?;
+        let name = name.as_bytes();
+        let name_length = u16::try_from(name.len()).map_err(|_| too_large())?;
+
+        let mut local_header = Vec::with_capacity(30 + name.len());
+        local_header.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        local_header.extend_from_slice(&20u16.to_le_bytes());
+        local_header.extend_from_slice(&UTF8_NAME_FLAG.to_le_bytes());
+        local_header.extend_from_slice(&0u16.to_le_bytes());
+        local_header.extend_from_slice(&0u16.to_le_bytes());
+        local_header.extend_from_slice(&DOS_DATE_1980_01_01.to_le_bytes());
+        local_header.extend_from_slice(&crc.to_le_bytes());
+        local_header.extend_from_slice(&size.to_le_bytes());
+        local_header.extend_from_slice(&size.to_le_bytes());
+        local_header.extend_from_slice(&name_length.to_le_bytes());
+        local_header.extend_from_slice(&0u16.to_le_bytes());
+        local_header.extend_from_slice(name);
+        archive
+            .write_all(&local_header)
+            .map_err(map_download_error)?;
+
+        // The final could change between the checksum pass and the copy; a
+        // size mismatch would leave every later offset wrong.
+        let copied = File::open(path)
+            .and_then(|file| io::copy(&mut file.take(u64::from(size) + 1), archive))
+            .map_err(map_download_error)?;
+        if copied != u64::from(size) {
+            return Err(HostErrorEnvelope::persistence(
+                "완성 사진이 묶는 도중에 바뀌었어요. 다시 시도해 주세요.",
+            ));
+        }
+        offset += local_header.len() as u64 + copied;
+
+        central_directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        central_directory.extend_from_slice(&20u16.to_le_bytes());
+        central_directory.extend_from_slice(&20u16.to_le_bytes());
+        central_directory.extend_from_slice(&UTF8_NAME_FLAG.to_le_bytes());
+        central_directory.extend_from_slice(&0u16.to_le_bytes());
+        central_directory.extend_from_slice(&0u16.to_le_bytes());
+        central_directory.extend_from_slice(&DOS_DATE_1980_01_01.to_le_bytes());
+        central_directory.extend_from_slice(&crc.to_le_bytes());
+        central_directory.extend_from_slice(&size.to_le_bytes());
+        central_directory.extend_from_slice(&size.to_le_bytes());
+        central_directory.extend_from_slice(&name_length.to_le_bytes());
+        central_directory.extend_from_slice(&[0u8; 12]);
+        central_directory.extend_from_slice(&local_header_offset.to_le_bytes());
+        central_directory.extend_from_slice(name);
+    }
+
+    let central_directory_offset = u32::try_from(offset).map_err(|_| too_large())?;
+    let central_directory_size = u32::try_from(central_directory.len()).map_err(|_| too_large())?;
+    if u32::try_from(offset + central_directory.len() as u64 + 22).is_err() {
+        return Err(too_large());
+    }
+    central_directory.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    central_directory.extend_from_slice(&[0u8; 4]);
+    central_directory.extend_from_slice(&entry_count.to_le_bytes());
+    central_directory.extend_from_slice(&entry_count.to_le_bytes());
+    central_directory.extend_from_slice(&central_directory_size.to_le_bytes());
+    central_directory.extend_from_slice(&central_directory_offset.to_le_bytes());
+    central_directory.extend_from_slice(&0u16.to_le_bytes());
+
+    archive
+        .write_all(&central_directory)
+        .map_err(map_download_error)
+}
+
+/// CRC-32 and length of a final, read in chunks.
+fn stored_entry_checksum(path: &Path) -> Result<(u32, u64), HostErrorEnvelope> {
+    let mut file = File::open(path).map_err(map_download_error)?;
+    let mut crc = flate2::Crc::new();
+    let mut buffer = [0u8; 64 * 1024];
+    let mut length = 0u64;
+
+    loop {
+        let read = file.read(&mut buffer).map_err(map_download_error)?;
+        if read == 0 {
+            break;
+        }
+        crc.update(&buffer[..read]);
+        length += read as u64;
+    }
+
+    Ok((crc.sum(), length))
+}
+
+fn read_download_ticket(ticket_path: &Path) -> Option<DownloadHandoffTicket> {
+    let bytes = fs::read_to_string(ticket_path).ok()?;
+
+    serde_json::from_str(&bytes).ok()
+}
+
+fn write_download_ticket(
+    ticket_path: &Path,
+    ticket: &DownloadHandoffTicket,
+) -> Result<(), HostErrorEnvelope> {
+    let bytes = serde_json::to_vec_pretty(ticket).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("다운로드 티켓을 직렬화하지 못했어요: {error}"))
+    })?;
+
+    write_bytes_atomically(ticket_path, &bytes)
+}
+
+fn write_bytes_atomically(path: &Path, bytes: &[u8]) -> Result<(), HostErrorEnvelope> {
+    let mut temp_name = path.as_os_str().to_owned();
+    temp_name.push(".tmp");
+    let temp_path = PathBuf::from(temp_name);
+
+    fs::write(&temp_path, bytes).map_err(map_download_error)?;
+    if let Err(error) = fs::rename(&temp_path, path) {
+        let _ = fs::remove_file(&temp_path);
+
+        return Err(map_download_error(error));
+    }
+
+    Ok(())
+}
+
+fn remove_stale_bundles(download_dir: &Path) {
+    let Ok(entries) = fs::read_dir(download_dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|extension| extension.to_str()) == Some("zip") {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// 128 bits from the OS CSPRNG; the token is the only thing guarding the
+/// customer's photos on the booth LAN.
+fn generate_download_token() -> Result<String, HostErrorEnvelope> {
+    let mut token = [0u8; TOKEN_BYTES];
+    getrandom::getrandom(&mut token).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("다운로드 링크를 만들지 못했어요: {error}"))
+    })?;
+
+    Ok(encode_hex(&token))
+}
+
+fn is_valid_download_token(token: &str) -> bool {
+    token.len() == TOKEN_HEX_LENGTH
+        && token
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+}
+
+fn default_public_base_url(local_addr: SocketAddr) -> String {
+    let host = if local_addr.ip().is_unspecified() {
+        detect_lan_address().unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST))
+    } else {
+        local_addr.ip()
+    };
+
+    format!("http://{}", SocketAddr::new(host, local_addr.port()))
+}
+
+/// Picks the interface the OS would route outward with. Connecting a UDP socket
+/// sends nothing, so this works on an offline booth LAN too.
+fn detect_lan_address() -> Option<IpAddr> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).ok()?;
+    socket.connect((Ipv4Addr::new(10, 255, 255, 255), 1)).ok()?;
+
+    socket
+        .local_addr()
+        .ok()
+        .map(|address| address.ip())
+        .filter(|address| !address.is_unspecified())
+}
+
+fn map_download_error(error: std::io::Error) -> HostErrorEnvelope {
+    HostErrorEnvelope::persistence(format!("다운로드 안내를 준비하지 못했어요: {error}"))
+}
@@ -1,3 +1,6 @@
+pub mod download_handoff;
+pub mod qr_code;
+
 use std::{
     fs::{self, OpenOptions},
     io::Write,
@@ -5,21 +8,26 @@ use std::{
     time::SystemTime,
 };
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    capture::ingest_pipeline::{complete_final_render_in_dir, mark_final_render_failed_in_dir},
+    branch_config::local_settings::load_branch_support_contact_label_in_dir,
+    capture::ingest_pipeline::{complete_shot_layout_final_in_dir, run_final_render_job_in_dir},
     contracts::dto::HostErrorEnvelope,
     diagnostics::audit_log::{try_append_operator_audit_record, OperatorAuditRecordInput},
+    handoff::download_handoff::attach_download_handoff,
     session::{
+        session_journal::{commit_session_manifest, SessionJournalEvent},
         session_manifest::{
             current_timestamp, CompletedPostEnd, ExportWaitingPostEnd, PhoneRequiredPostEnd,
-            SessionManifest, SessionPostEnd, SESSION_POST_END_COMPLETED,
+            SessionManifest, SessionPostEnd, SessionShotLayout, SESSION_POST_END_COMPLETED,
             SESSION_POST_END_EXPORT_WAITING, SESSION_POST_END_HANDOFF_READY,
             SESSION_POST_END_LOCAL_DELIVERABLE_READY, SESSION_POST_END_PHONE_REQUIRED,
+            SHOT_LAYOUT_STATUS_COMPOSED, SHOT_LAYOUT_STATUS_COMPOSE_FAILED,
+            SHOT_LAYOUT_STATUS_FILLED,
         },
         session_paths::SessionPaths,
-        session_repository::{read_session_manifest, write_session_manifest},
+        session_repository::read_session_manifest,
     },
 };
 
@@ -32,18 +40,18 @@ struct PostEndEvaluation {
     completion_variant: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct HandoffGuidanceFile {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     approved_recipient_label: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     next_location_label: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     primary_action_label: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     support_action_label: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     show_booth_alias: Option<bool>,
 }
 
@@ -154,13 +162,21 @@ pub fn sync_post_end_state_in_dir(
         existing_phone_required,
     )?);
     manifest.lifecycle.stage = evaluation.state.clone();
+    attach_download_handoff(base_dir, &mut manifest, now);
 
     for capture in &mut manifest.captures {
         capture.post_end_state = next_capture_state.to_string();
     }
 
     manifest.updated_at = evaluated_at.clone();
-    write_session_manifest(manifest_path, &manifest)?;
+    let event = match manifest.post_end.clone() {
+        Some(post_end) => SessionJournalEvent::PostEndEvaluated {
+            post_end,
+            capture_post_end_state: next_capture_state.to_string(),
+        },
+        None => SessionJournalEvent::manifest_updated("post-end-cleared", &manifest),
+    };
+    commit_session_manifest(manifest_path, &manifest, event)?;
     append_post_end_log(base_dir, &manifest.session_id, &evaluation, &evaluated_at)?;
     append_post_end_audit_record(base_dir, &manifest, &evaluation, &evaluated_at);
 
@@ -277,34 +293,74 @@ fn resolve_locked_post_end(
 }
 
 fn evaluate_post_end(manifest: &SessionManifest) -> PostEndEvaluation {
-    let Some(latest_capture) = manifest.captures.last() else {
-        return PostEndEvaluation {
-            state: SESSION_POST_END_EXPORT_WAITING.into(),
-            completion_variant: None,
-        };
-    };
+    if let Some(layout) = manifest.shot_layout.as_ref() {
+        return evaluate_shot_layout_post_end(manifest, layout);
+    }
 
-    match latest_capture.render_status.as_str() {
-        "previewWaiting" | "captureSaved" => PostEndEvaluation {
+    let selected_captures = manifest.selected_captures();
+    let render_statuses = selected_captures
+        .iter()
+        .map(|capture| capture.render_status.as_str());
+
+    if selected_captures.is_empty() {
+        PostEndEvaluation {
             state: SESSION_POST_END_EXPORT_WAITING.into(),
             completion_variant: None,
-        },
-        "previewReady" => PostEndEvaluation {
+        }
+    } else if render_statuses
+        .clone()
+        .any(|status| status == "renderFailed")
+    {
+        PostEndEvaluation {
+            state: SESSION_POST_END_PHONE_REQUIRED.into(),
+            completion_variant: None,
+        }
+    } else if render_statuses.clone().all(|status| status == "finalReady") {
+        PostEndEvaluation {
+            state: SESSION_POST_END_COMPLETED.into(),
+            completion_variant: Some(SESSION_POST_END_HANDOFF_READY.into()),
+        }
+    } else {
+        PostEndEvaluation {
             state: SESSION_POST_END_EXPORT_WAITING.into(),
             completion_variant: None,
-        },
-        "finalReady" => PostEndEvaluation {
+        }
+    }
+}
+
+/// Layout sessions deliver the composed final, so they wait on it instead
+/// of the latest capture. Unfilled slots cannot be filled after the session
+/// ends and need staff help.
+fn evaluate_shot_layout_post_end(
+    manifest: &SessionManifest,
+    layout: &SessionShotLayout,
+) -> PostEndEvaluation {
+    let slot_render_failed = layout.slot_capture_ids().any(|capture_id| {
+        manifest
+            .captures
+            .iter()
+            .find(|capture| capture.capture_id == capture_id)
+            .is_none_or(|capture| capture.render_status == "renderFailed")
+    });
+
+    if layout.status == SHOT_LAYOUT_STATUS_COMPOSED && layout.composed_final.is_some() {
+        PostEndEvaluation {
             state: SESSION_POST_END_COMPLETED.into(),
             completion_variant: Some(SESSION_POST_END_HANDOFF_READY.into()),
-        },
-        "renderFailed" => PostEndEvaluation {
+        }
+    } else if layout.status == SHOT_LAYOUT_STATUS_COMPOSE_FAILED
+        || !layout.is_filled()
+        || slot_render_failed
+    {
+        PostEndEvaluation {
             state: SESSION_POST_END_PHONE_REQUIRED.into(),
             completion_variant: None,
-        },
-        _ => PostEndEvaluation {
+        }
+    } else {
+        PostEndEvaluation {
             state: SESSION_POST_END_EXPORT_WAITING.into(),
             completion_variant: None,
-        },
+        }
     }
 }
 
@@ -313,24 +369,76 @@ fn attempt_final_render_if_needed(
     manifest_path: &Path,
     manifest: SessionManifest,
 ) -> Result<SessionManifest, HostErrorEnvelope> {
-    let Some(latest_capture) = manifest.captures.last() else {
+    if manifest.shot_layout.is_some() {
+        return attempt_shot_layout_final_if_needed(base_dir, manifest_path, manifest);
+    }
+
+    let mut rendered_any = false;
+    for capture in manifest.selected_captures().into_iter().filter(|capture| {
+        capture.render_status == "previewReady" && capture.final_asset.asset_path.is_none()
+    }) {
+        match run_final_render_job_in_dir(base_dir, capture) {
+            Ok(()) => rendered_any = true,
+            Err(error) => log::warn!(
+                "final_render_job_unavailable session={} capture_id={} detail={}",
+                manifest.session_id,
+                capture.capture_id,
+                error.message
+            ),
+        }
+    }
+
+    if rendered_any {
+        read_session_manifest(manifest_path)
+    } else {
+        Ok(manifest)
+    }
+}
+
+fn attempt_shot_layout_final_if_needed(
+    base_dir: &Path,
+    manifest_path: &Path,
+    manifest: SessionManifest,
+) -> Result<SessionManifest, HostErrorEnvelope> {
+    let Some(layout) = manifest.shot_layout.as_ref() else {
         return Ok(manifest);
     };
-
-    if latest_capture.render_status != "previewReady"
-        || latest_capture.final_asset.asset_path.is_some()
-    {
+    if layout.status != SHOT_LAYOUT_STATUS_FILLED {
         return Ok(manifest);
     }
 
-    let capture_id = latest_capture.capture_id.clone();
-    let session_id = manifest.session_id.clone();
+    let mut rendered_any = false;
+    for capture in manifest.captures.iter().filter(|capture| {
+        layout
+            .slot_capture_ids()
+            .any(|capture_id| capture_id == capture.capture_id)
+            && capture.render_status == "previewReady"
+            && capture.final_asset.asset_path.is_none()
+    }) {
+        rendered_any = true;
+        if let Err(error) = run_final_render_job_in_dir(base_dir, capture) {
+            log::warn!(
+                "final_render_job_unavailable session={} capture_id={} detail={}",
+                manifest.session_id,
+                capture.capture_id,
+                error.message
+            );
+        }
+    }
 
-    match complete_final_render_in_dir(base_dir, &session_id, &capture_id) {
-        Ok(_) => read_session_manifest(manifest_path),
-        Err(_) => {
-            let _ = mark_final_render_failed_in_dir(base_dir, &session_id, &capture_id);
-            read_session_manifest(manifest_path)
+    match complete_shot_layout_final_in_dir(base_dir, &manifest.session_id) {
+        Ok(manifest) => Ok(manifest),
+        Err(error) => {
+            log::warn!(
+                "shot_layout_final_unavailable session={} detail={}",
+                manifest.session_id,
+                error.message
+            );
+            if rendered_any {
+                read_session_manifest(manifest_path)
+            } else {
+                Ok(manifest)
+            }
         }
     }
 }
@@ -371,7 +479,8 @@ fn build_post_end_record(
                 state: SESSION_POST_END_PHONE_REQUIRED.into(),
                 evaluated_at: evaluated_at.into(),
                 primary_action_label: "가까운 직원에게 알려 주세요.".into(),
-                support_action_label: Some("직원에게 도움을 요청해 주세요.".into()),
+                support_action_label: load_branch_support_contact_label_in_dir(base_dir)
+                    .or_else(|| Some("직원에게 도움을 요청해 주세요.".into())),
                 unsafe_action_warning: "다시 찍기나 기기 조작은 잠시 멈춰 주세요.".into(),
                 show_booth_alias: false,
             }),
@@ -472,7 +581,7 @@ fn read_handoff_guidance_file(
         .join(HANDOFF_GUIDANCE_FILE);
 
     if !guidance_path.is_file() {
-        return Ok(None);
+        return Ok(write_branch_handoff_guidance_file(base_dir, &guidance_path));
     }
 
     let guidance_bytes = fs::read_to_string(&guidance_path).map_err(|error| {
@@ -485,11 +594,33 @@ fn read_handoff_guidance_file(
     guidance.approved_recipient_label = normalize_optional_label(guidance.approved_recipient_label);
     guidance.next_location_label = normalize_optional_label(guidance.next_location_label);
     guidance.primary_action_label = normalize_optional_label(guidance.primary_action_label);
-    guidance.support_action_label = normalize_optional_label(guidance.support_action_label);
+    guidance.support_action_label = normalize_optional_label(guidance.support_action_label)
+        .or_else(|| load_branch_support_contact_label_in_dir(base_dir));
 
     Ok(Some(guidance))
 }
 
+/// Seeds `customer-guidance.json` with the branch support contact when the
+/// operator has not written one. Writing is best-effort; the guidance is
+/// still used for this evaluation.
+fn write_branch_handoff_guidance_file(
+    base_dir: &Path,
+    guidance_path: &Path,
+) -> Option<HandoffGuidanceFile> {
+    let guidance = HandoffGuidanceFile {
+        support_action_label: Some(load_branch_support_contact_label_in_dir(base_dir)?),
+        ..HandoffGuidanceFile::default()
+    };
+
+    if let (Some(handoff_dir), Ok(bytes)) =
+        (guidance_path.parent(), serde_json::to_vec_pretty(&guidance))
+    {
+        let _ = fs::create_dir_all(handoff_dir).and_then(|_| fs::write(guidance_path, bytes));
+    }
+
+    Some(guidance)
+}
+
 fn append_post_end_log(
     base_dir: &Path,
     session_id: &str,
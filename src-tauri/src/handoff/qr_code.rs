@@ -0,0 +1,494 @@
+//! Minimal QR Code encoder for handoff URLs: byte mode, error correction level
+//! M, versions 1 through 10 (up to 213 bytes). That covers every URL the local
+//! download server hands out without pulling in an imaging dependency.
+
+const MAX_VERSION: usize = 10;
+const QUIET_ZONE_MODULES: usize = 4;
+const ECC_LEVEL_M_FORMAT_BITS: u32 = 0;
+
+/// (EC codewords per block, [(block count, data codewords per block)]) at level M.
+const LEVEL_M_BLOCKS: [(usize, [(usize, usize); 2]); MAX_VERSION] = [
+    (10, [(1, 16), (0, 0)]),
+    (16, [(1, 28), (0, 0)]),
+    (26, [(1, 44), (0, 0)]),
+    (18, [(2, 32), (0, 0)]),
+    (24, [(2, 43), (0, 0)]),
+    (16, [(4, 27), (0, 0)]),
+    (18, [(4, 31), (0, 0)]),
+    (22, [(2, 38), (2, 39)]),
+    (22, [(3, 36), (2, 37)]),
+    (26, [(4, 43), (1, 44)]),
+];
+
+const ALIGNMENT_CENTERS: [&[usize]; MAX_VERSION] = [
+    &[],
+    &[6, 18],
+    &[6, 22],
+    &[6, 26],
+    &[6, 30],
+    &[6, 34],
+    &[6, 22, 38],
+    &[6, 24, 42],
+    &[6, 26, 46],
+    &[6, 28, 50],
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrCode {
+    version: usize,
+    size: usize,
+    modules: Vec<bool>,
+}
+
+impl QrCode {
+    /// Encodes `data` with the smallest version that fits, or `None` when it is
+    /// longer than a version 10-M symbol can carry.
+    pub fn encode(data: &[u8]) -> Option<Self> {
+        let version = (1..=MAX_VERSION).find(|version| {
+            let capacity_bits = data_codeword_count(*version) * 8;
+            4 + char_count_bits(*version) + data.len() * 8 <= capacity_bits
+        })?;
+        let codewords = add_error_correction(version, &encode_data_codewords(version, data));
+
+        let mut symbol = SymbolBuilder::new(version);
+        symbol.draw_function_patterns();
+        symbol.draw_codewords(&codewords);
+
+        let mask = (0..8)
+            .min_by_key(|mask| {
+                symbol.apply_mask(*mask);
+                symbol.draw_format_bits(*mask);
+                let penalty = symbol.penalty_score();
+                symbol.apply_mask(*mask);
+                penalty
+            })
+            .unwrap_or(0);
+        symbol.apply_mask(mask);
+        symbol.draw_format_bits(mask);
+
+        Some(Self {
+            version,
+            size: symbol.size,
+            modules: symbol.modules,
+        })
+    }
+
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.size && y < self.size && self.modules[y * self.size + x]
+    }
+
+    /// Renders the symbol as a standalone SVG with the standard quiet zone, one
+    /// user unit per module so the booth UI can scale it freely.
+    pub fn to_svg(&self) -> String {
+        let extent = self.size + QUIET_ZONE_MODULES * 2;
+        let mut path = String::new();
+
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if self.is_dark(x, y) {
+                    path.push_str(&format!(
+                        "M{},{}h1v1h-1z",
+                        x + QUIET_ZONE_MODULES,
+                        y + QUIET_ZONE_MODULES
+                    ));
+                }
+            }
+        }
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {extent} {extent}\" shape-rendering=\"crispEdges\"><rect width=\"{extent}\" height=\"{extent}\" fill=\"#FFFFFF\"/><path d=\"{path}\" fill=\"#000000\"/></svg>"
+        )
+    }
+}
+
+struct SymbolBuilder {
+    version: usize,
+    size: usize,
+    modules: Vec<bool>,
+    is_function: Vec<bool>,
+}
+
+impl SymbolBuilder {
+    fn new(version: usize) -> Self {
+        let size = version * 4 + 17;
+
+        Self {
+            version,
+            size,
+            modules: vec![false; size * size],
+            is_function: vec![false; size * size],
+        }
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        let index = y * self.size + x;
+        self.modules[index] = dark;
+        self.is_function[index] = true;
+    }
+
+    fn draw_function_patterns(&mut self) {
+        for index in 0..self.size {
+            self.set_function(6, index, index % 2 == 0);
+            self.set_function(index, 6, index % 2 == 0);
+        }
+
+        let far = self.size - 4;
+        for (x, y) in [(3, 3), (far, 3), (3, far)] {
+            self.draw_finder_pattern(x, y);
+        }
+
+        let centers = ALIGNMENT_CENTERS[self.version - 1];
+        let last = centers.len().saturating_sub(1);
+        for (row, y) in centers.iter().enumerate() {
+            for (column, x) in centers.iter().enumerate() {
+                let overlaps_finder = (row == 0 && column == 0)
+                    || (row == 0 && column == last)
+                    || (row == last && column == 0);
+                if !overlaps_finder {
+                    self.draw_alignment_pattern(*x, *y);
+                }
+            }
+        }
+
+        self.draw_format_bits(0);
+        self.draw_version_bits();
+    }
+
+    fn draw_finder_pattern(&mut self, center_x: usize, center_y: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let x = center_x as i32 + dx;
+                let y = center_y as i32 + dy;
+                if x < 0 || y < 0 || x >= self.size as i32 || y >= self.size as i32 {
+                    continue;
+                }
+
+                let distance = dx.abs().max(dy.abs());
+                self.set_function(x as usize, y as usize, distance != 2 && distance != 4);
+            }
+        }
+    }
+
+    fn draw_alignment_pattern(&mut self, center_x: usize, center_y: usize) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                self.set_function(
+                    (center_x as i32 + dx) as usize,
+                    (center_y as i32 + dy) as usize,
+                    dx.abs().max(dy.abs()) != 1,
+                );
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, mask: u32) {
+        let data = (ECC_LEVEL_M_FORMAT_BITS << 3) | mask;
+        let mut remainder = data;
+        for _ in 0..10 {
+            remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+        }
+        let bits = ((data << 10) | remainder) ^ 0x5412;
+        let bit = |index: usize| (bits >> index) & 1 != 0;
+
+        for index in 0..=5 {
+            self.set_function(8, index, bit(index));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for index in 9..15 {
+            self.set_function(14 - index, 8, bit(index));
+        }
+
+        for index in 0..8 {
+            self.set_function(self.size - 1 - index, 8, bit(index));
+        }
+        for index in 8..15 {
+            self.set_function(8, self.size - 15 + index, bit(index));
+        }
+        self.set_function(8, self.size - 8, true);
+    }
+
+    fn draw_version_bits(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+
+        let mut remainder = self.version as u32;
+        for _ in 0..12 {
+            remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1F25);
+        }
+        let bits = ((self.version as u32) << 12) | remainder;
+
+        for index in 0..18 {
+            let dark = (bits >> index) & 1 != 0;
+            let a = self.size - 11 + index % 3;
+            let b = index / 3;
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let total_bits = codewords.len() * 8;
+        let mut bit_index = 0;
+        let mut right = self.size as i32 - 1;
+
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+
+            let upward = (right + 1) & 2 == 0;
+            for vertical in 0..self.size {
+                for offset in 0..2 {
+                    let x = (right - offset) as usize;
+                    let y = if upward {
+                        self.size - 1 - vertical
+                    } else {
+                        vertical
+                    };
+                    let index = y * self.size + x;
+                    if !self.is_function[index] && bit_index < total_bits {
+                        self.modules[index] =
+                            (codewords[bit_index >> 3] >> (7 - (bit_index & 7))) & 1 != 0;
+                        bit_index += 1;
+                    }
+                }
+            }
+
+            right -= 2;
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => (x * y) % 2 + (x * y) % 3 == 0,
+                    6 => ((x * y) % 2 + (x * y) % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + (x * y) % 3) % 2 == 0,
+                };
+                let index = y * self.size + x;
+                if invert && !self.is_function[index] {
+                    self.modules[index] = !self.modules[index];
+                }
+            }
+        }
+    }
+
+    fn module(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    fn penalty_score(&self) -> usize {
+        let mut penalty = 0;
+
+        for primary in 0..self.size {
+            let row: Vec<bool> = (0..self.size).map(|x| self.module(x, primary)).collect();
+            let column: Vec<bool> = (0..self.size).map(|y| self.module(primary, y)).collect();
+            penalty += line_penalty(&row) + line_penalty(&column);
+        }
+
+        for y in 0..self.size - 1 {
+            for x in 0..self.size - 1 {
+                let color = self.module(x, y);
+                if color == self.module(x + 1, y)
+                    && color == self.module(x, y + 1)
+                    && color == self.module(x + 1, y + 1)
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        let total = self.size * self.size;
+        let dark = self.modules.iter().filter(|module| **module).count();
+        let deviation = (dark * 20).abs_diff(total * 10);
+        penalty += deviation.div_ceil(total).saturating_sub(1) * 10;
+
+        penalty
+    }
+}
+
+fn line_penalty(line: &[bool]) -> usize {
+    const FINDER_LIKE: [bool; 11] = [
+        true, false, true, true, true, false, true, false, false, false, false,
+    ];
+    let mut penalty = 0;
+    let mut run_length = 1;
+
+    for index in 1..=line.len() {
+        if index < line.len() && line[index] == line[index - 1] {
+            run_length += 1;
+            continue;
+        }
+        if run_length >= 5 {
+            penalty += 3 + (run_length - 5);
+        }
+        run_length = 1;
+    }
+
+    for window in line.windows(FINDER_LIKE.len()) {
+        let forward = window.iter().zip(FINDER_LIKE.iter()).all(|(a, b)| a == b);
+        let backward = window
+            .iter()
+            .zip(FINDER_LIKE.iter().rev())
+            .all(|(a, b)| a == b);
+        if forward || backward {
+            penalty += 40;
+        }
+    }
+
+    penalty
+}
+
+fn char_count_bits(version: usize) -> usize {
+    if version < 10 {
+        8
+    } else {
+        16
+    }
+}
+
+fn data_codeword_count(version: usize) -> usize {
+    LEVEL_M_BLOCKS[version - 1]
+        .1
+        .iter()
+        .map(|(count, length)| count * length)
+        .sum()
+}
+
+fn encode_data_codewords(version: usize, data: &[u8]) -> Vec<u8> {
+    let capacity_bits = data_codeword_count(version) * 8;
+    let mut bits: Vec<bool> = Vec::with_capacity(capacity_bits);
+
+    push_bits(&mut bits, 0b0100, 4);
+    push_bits(&mut bits, data.len(), char_count_bits(version));
+    for byte in data {
+        push_bits(&mut bits, usize::from(*byte), 8);
+    }
+
+    let terminator = (capacity_bits - bits.len()).min(4);
+    push_bits(&mut bits, 0, terminator);
+    while bits.len() % 8 != 0 {
+        bits.push(false);
+    }
+
+    let mut codewords: Vec<u8> = bits
+        .chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(0u8, |byte, bit| (byte << 1) | u8::from(*bit))
+        })
+        .collect();
+    for pad in [0xEC, 0x11].into_iter().cycle() {
+        if codewords.len() * 8 >= capacity_bits {
+            break;
+        }
+        codewords.push(pad);
+    }
+
+    codewords
+}
+
+fn push_bits(bits: &mut Vec<bool>, value: usize, length: usize) {
+    for shift in (0..length).rev() {
+        bits.push((value >> shift) & 1 != 0);
+    }
+}
+
+fn add_error_correction(version: usize, data: &[u8]) -> Vec<u8> {
+    let (ec_length, groups) = LEVEL_M_BLOCKS[version - 1];
+    let divisor = reed_solomon_divisor(ec_length);
+    let mut data_blocks = Vec::new();
+    let mut ec_blocks = Vec::new();
+    let mut offset = 0;
+
+    for (count, length) in groups {
+        for _ in 0..count {
+            let block = &data[offset..offset + length];
+            ec_blocks.push(reed_solomon_remainder(block, &divisor));
+            data_blocks.push(block);
+            offset += length;
+        }
+    }
+
+    let longest_block = data_blocks
+        .iter()
+        .map(|block| block.len())
+        .max()
+        .unwrap_or(0);
+    let mut interleaved = Vec::with_capacity(data.len() + ec_length * ec_blocks.len());
+    for index in 0..longest_block {
+        for block in &data_blocks {
+            if let Some(byte) = block.get(index) {
+                interleaved.push(*byte);
+            }
+        }
+    }
+    for index in 0..ec_length {
+        for block in &ec_blocks {
+            interleaved.push(block[index]);
+        }
+    }
+
+    interleaved
+}
+
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0u8; degree];
+    result[degree - 1] = 1;
+    let mut root = 1u8;
+
+    for _ in 0..degree {
+        for index in 0..degree {
+            result[index] = gf_multiply(result[index], root);
+            if index + 1 < degree {
+                result[index] ^= result[index + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+
+    result
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0u8; divisor.len()];
+
+    for byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (value, coefficient) in result.iter_mut().zip(divisor) {
+            *value ^= gf_multiply(*coefficient, factor);
+        }
+    }
+
+    result
+}
+
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut product: u32 = 0;
+
+    for shift in (0..8).rev() {
+        product = (product << 1) ^ ((product >> 7) * 0x11D);
+        product ^= ((u32::from(y) >> shift) & 1) * u32::from(x);
+    }
+
+    product as u8
+}
@@ -0,0 +1,60 @@
+//! Content hashes and signatures for artifacts the booth accepts from
+//! outside its own authoring pipeline, backed by the `sha2` and
+//! `ed25519-dalek` crates. Ed25519 keys are the 32-byte seed and the 32-byte
+//! compressed public point; signatures are `R || S`.
+
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+use crate::contracts::dto::HostErrorEnvelope;
+
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Lowercase or uppercase hex of exactly `N` bytes.
+pub fn decode_hex<const N: usize>(value: &str) -> Option<[u8; N]> {
+    let value = value.as_bytes();
+    if value.len() != N * 2 || !value.iter().all(u8::is_ascii_hexdigit) {
+        return None;
+    }
+
+    let mut decoded = [0u8; N];
+    for (byte, pair) in decoded.iter_mut().zip(value.chunks_exact(2)) {
+        let text = std::str::from_utf8(pair).ok()?;
+        *byte = u8::from_str_radix(text, 16).ok()?;
+    }
+
+    Some(decoded)
+}
+
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    encode_hex(&Sha256::digest(bytes))
+}
+
+pub fn ed25519_public_key(seed: &[u8; 32]) -> [u8; 32] {
+    SigningKey::from_bytes(seed).verifying_key().to_bytes()
+}
+
+pub fn ed25519_sign(seed: &[u8; 32], message: &[u8]) -> [u8; 64] {
+    SigningKey::from_bytes(seed).sign(message).to_bytes()
+}
+
+/// Strict RFC 8032 verification: small-order keys and non-canonical
+/// signatures are rejected.
+pub fn ed25519_verify(public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+    VerifyingKey::from_bytes(public_key).is_ok_and(|key| {
+        key.verify_strict(message, &Signature::from_bytes(signature))
+            .is_ok()
+    })
+}
+
+/// 32 bytes of key material from the OS CSPRNG.
+pub fn generate_seed() -> Result<[u8; 32], HostErrorEnvelope> {
+    let mut seed = [0u8; 32];
+    getrandom::getrandom(&mut seed).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("서명 키를 만들 난수를 얻지 못했어요: {error}"))
+    })?;
+
+    Ok(seed)
+}
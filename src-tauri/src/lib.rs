@@ -1,13 +1,18 @@
 use tauri::{Manager, RunEvent, WebviewUrl, WebviewWindowBuilder};
 
 pub mod branch_config;
+mod branch_http;
 pub mod capture;
+pub mod cli;
 pub mod commands;
 pub mod contracts;
 pub mod diagnostics;
 pub mod handoff;
+pub mod integrity;
 pub mod preset;
+pub mod print;
 pub mod render;
+pub mod reservation;
 pub mod session;
 pub mod timing;
 
@@ -31,6 +36,16 @@ pub fn run() {
                 session::session_repository::resolve_app_session_base_dir(app_local_data_dir);
             preset::default_catalog::ensure_default_preset_catalog_in_dir(&runtime_base_dir)
                 .map_err(|error| error.message.clone())?;
+            handoff::download_handoff::try_start_download_handoff_server(&runtime_base_dir);
+            session::session_retention::spawn_session_retention_in_dir(&runtime_base_dir);
+            branch_config::branch_health::spawn_branch_health_exchange_in_dir(
+                &runtime_base_dir,
+            );
+            if let Err(error) =
+                capture::ingest_pipeline::resume_pending_render_jobs_in_dir(&runtime_base_dir)
+            {
+                log::warn!("render_job_resume_failed reason={}", error.message);
+            }
 
             let capability_snapshot =
                 commands::runtime_commands::resolve_runtime_capability_snapshot();
@@ -76,12 +91,29 @@ pub fn run() {
             commands::branch_rollout_commands::load_branch_rollout_overview,
             commands::branch_rollout_commands::apply_branch_rollout,
             commands::branch_rollout_commands::apply_branch_rollback,
+            commands::branch_rollout_commands::start_branch_rollout_plan,
+            commands::branch_rollout_commands::evaluate_branch_rollout_plans,
+            commands::branch_rollout_commands::rollback_branch_rollout_plan,
+            commands::branch_rollout_commands::load_branch_local_settings,
+            commands::branch_rollout_commands::update_branch_local_settings,
+            commands::branch_rollout_commands::load_branch_local_settings_diff,
+            commands::branch_rollout_commands::load_branch_operational_toggles,
+            commands::branch_rollout_commands::sync_fleet_release,
+            commands::branch_rollout_commands::load_preset_signing_keys,
+            commands::branch_rollout_commands::generate_preset_signing_key,
+            commands::branch_rollout_commands::trust_preset_signing_key,
+            commands::branch_rollout_commands::revoke_preset_signing_key,
+            commands::branch_rollout_commands::resign_unsigned_preset_bundles,
             commands::capture_commands::get_capture_readiness,
             commands::capture_commands::delete_capture,
+            commands::capture_commands::update_capture_selection,
+            commands::capture_commands::retake_shot_layout_slot,
             commands::capture_commands::request_capture,
+            commands::capture_commands::run_capture_program,
             commands::operator_commands::load_operator_session_summary,
             commands::operator_commands::load_operator_recovery_summary,
             commands::operator_commands::load_operator_audit_history,
+            commands::operator_commands::load_operator_session_timeline,
             commands::operator_commands::run_operator_recovery_action,
             commands::runtime_commands::get_capability_snapshot,
             commands::runtime_commands::log_capture_client_state,
@@ -95,14 +127,24 @@ pub fn run() {
             commands::preset_commands::load_preset_catalog_state,
             commands::preset_commands::rollback_preset_catalog,
             commands::preset_commands::select_active_preset,
-            commands::session_commands::start_session
+            commands::print_commands::load_print_templates,
+            commands::print_commands::submit_print_job,
+            commands::print_commands::refresh_print_jobs,
+            commands::reservation_commands::lookup_reservation,
+            commands::reservation_commands::start_reserved_session,
+            commands::session_commands::start_session,
+            commands::session_commands::load_session_packages,
+            commands::session_commands::load_session_retention_policy,
+            commands::session_commands::save_session_retention_policy,
+            commands::session_commands::run_session_retention
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application");
 
     app.run(|_app_handle, event| {
         if matches!(event, RunEvent::ExitRequested { .. } | RunEvent::Exit) {
-            capture::helper_supervisor::shutdown_helper_process();
+            capture::camera_backend::shutdown_camera_backend();
+            handoff::download_handoff::shutdown_download_handoff_server();
         }
     });
 }
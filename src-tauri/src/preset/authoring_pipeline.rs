@@ -5,6 +5,7 @@ use std::{
 };
 
 use crate::{
+    branch_config::preset_signing_keys::ensure_active_preset_signing_key_in_dir,
     contracts::dto::{
         validate_draft_preset_edit_input, validate_draft_validation_input,
         validate_publish_validated_preset_input, validate_repair_invalid_draft_input,
@@ -18,6 +19,8 @@ use crate::{
     },
     diagnostics::audit_log::{try_append_operator_audit_record, OperatorAuditRecordInput},
     preset::{
+        bundle_signature::{check_published_bundle_signature, sign_preset_bundle},
+        overlay_layout::{inspect_overlay_layout, overlay_layout_asset_paths},
         preset_catalog::resolve_published_preset_catalog_dir,
         preset_catalog_state::publish_preset_to_live_catalog,
     },
@@ -421,6 +424,29 @@ pub fn publish_validated_preset_in_dir(
         }
     };
 
+    let overlay_layout_source = match existing_draft
+        .overlay_layout_path
+        .as_deref()
+        .map(|layout_path| {
+            resolve_workspace_file_for_publication(draft_dir, layout_path, "overlayLayoutPath")
+        })
+        .transpose()
+    {
+        Ok(path) => path,
+        Err((reason_code, message, guidance)) => {
+            return reject_publication(
+                base_dir,
+                &draft_path,
+                existing_draft,
+                &input,
+                reason_code,
+                message,
+                guidance,
+                noted_at,
+            )
+        }
+    };
+
     let catalog_root = resolve_published_preset_catalog_dir(base_dir);
     let final_bundle_dir = catalog_root
         .join(&existing_draft.preset_id)
@@ -439,6 +465,8 @@ pub fn publish_validated_preset_in_dir(
         );
     }
 
+    let signing_key =
+        ensure_active_preset_signing_key_in_dir(base_dir, &input.actor_id, &noted_at)?;
     let temp_bundle_dir = resolve_temp_bundle_dir(&final_bundle_dir);
     let created_bundle = create_published_bundle_from_draft(
         &temp_bundle_dir,
@@ -449,7 +477,17 @@ pub fn publish_validated_preset_in_dir(
         &sample_cut_source,
         &darktable_source,
         &xmp_source,
-    );
+        overlay_layout_source.as_deref(),
+    )
+    .and_then(|_| {
+        sign_preset_bundle(
+            &temp_bundle_dir,
+            &existing_draft.preset_id,
+            &input.published_version,
+            &signing_key,
+            &noted_at,
+        )
+    });
 
     if let Err(error) = created_bundle {
         let _ = fs::remove_dir_all(&temp_bundle_dir);
@@ -465,6 +503,8 @@ pub fn publish_validated_preset_in_dir(
         let _ = fs::remove_dir_all(&temp_bundle_dir);
         return Err(map_fs_error(error));
     }
+    // Verified once at install; later loads reuse the cached verdict.
+    let _ = check_published_bundle_signature(&final_bundle_dir);
 
     let preview_asset_path =
         build_absolute_asset_path(&final_bundle_dir, "preview", &preview_source)?;
@@ -881,6 +921,7 @@ fn create_published_bundle_from_draft(
     sample_cut_source: &Path,
     darktable_source: &Path,
     xmp_source: &Path,
+    overlay_layout_source: Option<&Path>,
 ) -> Result<(), HostErrorEnvelope> {
     if bundle_dir.exists() {
         fs::remove_dir_all(bundle_dir).map_err(map_fs_error)?;
@@ -891,7 +932,10 @@ fn create_published_bundle_from_draft(
     let sample_cut_relative = copy_bundle_asset(bundle_dir, "sample-cut", sample_cut_source)?;
     let darktable_relative = copy_bundle_asset(bundle_dir, "darktable", darktable_source)?;
     let xmp_relative = copy_bundle_asset(bundle_dir, "xmp", xmp_source)?;
-    let bundle_value = serde_json::json!({
+    let overlay_layout_relative = overlay_layout_source
+        .map(|layout_source| copy_overlay_layout(bundle_dir, layout_source))
+        .transpose()?;
+    let mut bundle_value = serde_json::json!({
         "schemaVersion": PUBLISHED_PRESET_BUNDLE_SCHEMA_VERSION,
         "presetId": draft.preset_id,
         "displayName": draft.display_name,
@@ -928,6 +972,9 @@ fn create_published_bundle_from_draft(
         "darktableProjectPath": darktable_relative,
         "xmpTemplatePath": xmp_relative,
     });
+    if let Some(overlay_layout_relative) = overlay_layout_relative {
+        bundle_value["overlayLayoutPath"] = serde_json::Value::String(overlay_layout_relative);
+    }
     let bundle_bytes = serde_json::to_vec_pretty(&bundle_value).map_err(|error| {
         HostErrorEnvelope::persistence(format!("published bundle을 직렬화하지 못했어요: {error}"))
     })?;
@@ -950,6 +997,26 @@ fn copy_bundle_asset(
     Ok(format!("{subdir}/{}", file_name.to_string_lossy()))
 }
 
+/// Copies the layout and the PNGs it references into the bundle's flat
+/// `overlay/` folder, which is how the runtime loader expects to find them.
+fn copy_overlay_layout(
+    bundle_dir: &Path,
+    layout_source: &Path,
+) -> Result<String, HostErrorEnvelope> {
+    let layout = inspect_overlay_layout(layout_source)
+        .layout
+        .ok_or_else(|| {
+            HostErrorEnvelope::validation_message(
+                "overlay layout이 validation 이후에 바뀌었어요. 다시 검증한 뒤 게시해 주세요.",
+            )
+        })?;
+    for asset_path in overlay_layout_asset_paths(&layout) {
+        copy_bundle_asset(bundle_dir, "overlay", asset_path)?;
+    }
+
+    copy_bundle_asset(bundle_dir, "overlay", layout_source)
+}
+
 fn build_absolute_asset_path(
     bundle_dir: &Path,
     subdir: &str,
@@ -1173,6 +1240,10 @@ fn is_valid_draft_summary(draft_path: &Path, summary: &DraftPresetSummaryDto) ->
         || !crate::contracts::dto::is_valid_darktable_version(&summary.darktable_version)
         || !crate::contracts::dto::is_safe_workspace_reference(&summary.darktable_project_path)
         || !crate::contracts::dto::is_safe_workspace_reference(&summary.xmp_template_path)
+        || summary
+            .overlay_layout_path
+            .as_deref()
+            .is_some_and(|path| !crate::contracts::dto::is_safe_workspace_reference(path))
         || !is_valid_render_profile(&summary.preview_profile)
         || !is_valid_render_profile(&summary.final_profile)
         || !is_valid_noise_policy(&summary.noise_policy)
@@ -1348,6 +1419,7 @@ fn build_draft_summary(
         darktable_version: input.darktable_version.trim().to_string(),
         darktable_project_path: input.darktable_project_path.trim().to_string(),
         xmp_template_path: input.xmp_template_path.trim().to_string(),
+        overlay_layout_path: normalize_optional_text(input.overlay_layout_path.as_deref()),
         preview_profile: normalize_render_profile(&input.preview_profile),
         final_profile: normalize_render_profile(&input.final_profile),
         noise_policy: normalize_noise_policy(&input.noise_policy),
@@ -1523,6 +1595,10 @@ fn build_validation_report(
         }
     }
 
+    if let Some(layout_path) = draft.overlay_layout_path.as_deref() {
+        validate_overlay_layout(draft_dir, layout_path, &mut findings);
+    }
+
     let status = if findings.iter().any(|finding| finding.severity == "error") {
         "failed"
     } else {
@@ -1581,6 +1657,43 @@ fn validate_required_file(
     ));
 }
 
+fn validate_overlay_layout(
+    draft_dir: &Path,
+    layout_path: &str,
+    findings: &mut Vec<DraftValidationFindingDto>,
+) {
+    let Some(resolved) = resolve_existing_workspace_file(draft_dir, layout_path).filter(|path| {
+        path.to_string_lossy()
+            .to_ascii_lowercase()
+            .ends_with(".json")
+    }) else {
+        findings.push(validation_error(
+            "overlay-layout-missing",
+            Some("overlayLayoutPath"),
+            "overlay layout 파일을 찾지 못했어요.",
+            "overlayLayoutPath에 draft 작업공간 안의 overlay layout .json 파일을 연결해 주세요.",
+        ));
+        return;
+    };
+
+    for issue in inspect_overlay_layout(&resolved).issues {
+        let field_path = if issue.field_path.is_empty() {
+            "overlayLayoutPath".to_string()
+        } else {
+            format!("overlayLayoutPath.{}", issue.field_path)
+        };
+        findings.push(DraftValidationFindingDto {
+            severity: if issue.is_error { "error" } else { "warning" }.into(),
+            ..validation_error(
+                issue.rule_code,
+                Some(&field_path),
+                &issue.message,
+                &issue.guidance,
+            )
+        });
+    }
+}
+
 fn resolve_existing_workspace_file(draft_dir: &Path, relative_path: &str) -> Option<PathBuf> {
     let draft_root = fs::canonicalize(draft_dir).ok()?;
     let resolved = fs::canonicalize(draft_dir.join(relative_path)).ok()?;
@@ -0,0 +1,437 @@
+//! Signed content manifests for published preset bundles. Every bundle
+//! directory carries `bundle-signature.json`: the SHA-256 of each file in the
+//! bundle (`bundle.json`, the XMP template, previews and layout assets) and
+//! an Ed25519 signature over that list by a branch or HQ key. Seeded default
+//! bundles are the exception and are checked against the copies built into
+//! the app instead.
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    time::SystemTime,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    branch_config::preset_signing_keys::{
+        load_preset_bundle_trust_in_dir, resolve_preset_signing_keys_path, PresetBundleTrust,
+        PresetSigningKey,
+    },
+    contracts::dto::HostErrorEnvelope,
+    diagnostics::audit_log::{try_append_operator_audit_record, OperatorAuditRecordInput},
+    integrity::{decode_hex, ed25519_sign, ed25519_verify, encode_hex, sha256_hex},
+    preset::{
+        default_catalog::default_preset_bundle_files,
+        preset_catalog::resolve_published_preset_catalog_dir,
+    },
+    session::session_manifest::current_timestamp,
+};
+
+pub const PRESET_BUNDLE_SIGNATURE_FILE_NAME: &str = "bundle-signature.json";
+
+const PRESET_BUNDLE_SIGNATURE_SCHEMA_VERSION: &str = "preset-bundle-signature/v1";
+const PRESET_BUNDLE_SIGNATURE_ALGORITHM: &str = "ed25519";
+const MAX_SIGNED_BUNDLE_FILES: usize = 64;
+
+/// Catalog bundle verdicts, reused while the bundle's files, its signature
+/// and the key file hash the same, so repeat loads skip the trust lookup and
+/// the Ed25519 check.
+static CATALOG_BUNDLE_VERDICTS: OnceLock<Mutex<HashMap<PathBuf, CachedBundleVerdict>>> =
+    OnceLock::new();
+
+struct CachedBundleVerdict {
+    fingerprint: BundleFingerprint,
+    verdict: Result<(), PresetBundleSignatureFinding>,
+    /// Set once a rejection has been written to the audit log.
+    reported: bool,
+}
+
+/// SHA-256 of the key file, of `bundle-signature.json` and of every other
+/// bundle file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BundleFingerprint {
+    trust_store: Option<String>,
+    signature: Option<String>,
+    files: Vec<SignedBundleFile>,
+}
+
+/// The signed part of `bundle-signature.json`; the signature covers its
+/// compact JSON encoding in field order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignedBundleContent {
+    schema_version: String,
+    preset_id: String,
+    published_version: String,
+    key_id: String,
+    algorithm: String,
+    signed_at: String,
+    files: Vec<SignedBundleFile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignedBundleFile {
+    path: String,
+    sha256: String,
+    size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PresetBundleSignature {
+    #[serde(flatten)]
+    content: SignedBundleContent,
+    signature: String,
+}
+
+/// Why a bundle failed its signature check, in operator-facing terms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PresetBundleSignatureFinding {
+    pub rule_code: &'static str,
+    pub message: String,
+}
+
+/// Writes `bundle-signature.json` covering every other file in `bundle_dir`.
+pub fn sign_preset_bundle(
+    bundle_dir: &Path,
+    preset_id: &str,
+    published_version: &str,
+    signing_key: &PresetSigningKey,
+    signed_at: &str,
+) -> Result<(), HostErrorEnvelope> {
+    let files = collect_bundle_files(bundle_dir).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("preset bundle 파일을 읽지 못했어요: {error}"))
+    })?;
+    if files.len() > MAX_SIGNED_BUNDLE_FILES {
+        return Err(HostErrorEnvelope::persistence(
+            "preset bundle 파일이 너무 많아 서명할 수 없어요.",
+        ));
+    }
+
+    let content = SignedBundleContent {
+        schema_version: PRESET_BUNDLE_SIGNATURE_SCHEMA_VERSION.into(),
+        preset_id: preset_id.into(),
+        published_version: published_version.into(),
+        key_id: signing_key.key_id.clone(),
+        algorithm: PRESET_BUNDLE_SIGNATURE_ALGORITHM.into(),
+        signed_at: signed_at.into(),
+        files,
+    };
+    let signature = ed25519_sign(&signing_key.seed, &signed_payload(&content)?);
+    let bytes = serde_json::to_vec_pretty(&PresetBundleSignature {
+        content,
+        signature: encode_hex(&signature),
+    })
+    .map_err(|error| {
+        HostErrorEnvelope::persistence(format!("preset bundle 서명을 직렬화하지 못했어요: {error}"))
+    })?;
+
+    fs::write(bundle_dir.join(PRESET_BUNDLE_SIGNATURE_FILE_NAME), bytes).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("preset bundle 서명을 저장하지 못했어요: {error}"))
+    })
+}
+
+/// The bundle must be signed for this preset version by a key in `trust` and
+/// its files must match the signed list exactly.
+pub fn verify_preset_bundle_signature(
+    bundle_dir: &Path,
+    preset_id: &str,
+    published_version: &str,
+    trust: &PresetBundleTrust,
+) -> Result<(), PresetBundleSignatureFinding> {
+    let current_files = collect_bundle_files(bundle_dir).map_err(|_| {
+        finding(
+            "bundle-signature-invalid",
+            format!("{preset_id}@{published_version} bundle 서명이 맞지 않아 사용할 수 없어요."),
+        )
+    })?;
+
+    verify_signed_bundle_files(
+        bundle_dir,
+        preset_id,
+        published_version,
+        trust,
+        &current_files,
+    )
+}
+
+/// Checks the signature in `bundle_dir` against files that were already
+/// collected from it.
+fn verify_signed_bundle_files(
+    bundle_dir: &Path,
+    preset_id: &str,
+    published_version: &str,
+    trust: &PresetBundleTrust,
+    current_files: &[SignedBundleFile],
+) -> Result<(), PresetBundleSignatureFinding> {
+    let label = format!("{preset_id}@{published_version}");
+    let signature_bytes =
+        fs::read(bundle_dir.join(PRESET_BUNDLE_SIGNATURE_FILE_NAME)).map_err(|_| {
+            finding(
+                "bundle-signature-missing",
+                format!("{label} bundle에 서명이 없어 사용할 수 없어요."),
+            )
+        })?;
+    let invalid_signature = || {
+        finding(
+            "bundle-signature-invalid",
+            format!("{label} bundle 서명이 맞지 않아 사용할 수 없어요."),
+        )
+    };
+    let bundle_signature = serde_json::from_slice::<PresetBundleSignature>(&signature_bytes)
+        .ok()
+        .filter(|bundle_signature| {
+            let content = &bundle_signature.content;
+            content.schema_version == PRESET_BUNDLE_SIGNATURE_SCHEMA_VERSION
+                && content.algorithm == PRESET_BUNDLE_SIGNATURE_ALGORITHM
+                && content.preset_id == preset_id
+                && content.published_version == published_version
+        })
+        .ok_or_else(invalid_signature)?;
+
+    let public_key = trust
+        .public_key(&bundle_signature.content.key_id)
+        .ok_or_else(|| {
+            finding(
+                "bundle-signing-key-untrusted",
+                format!(
+                    "{label} bundle이 신뢰하지 않는 키({})로 서명되어 사용할 수 없어요.",
+                    bundle_signature.content.key_id
+                ),
+            )
+        })?;
+    let payload = signed_payload(&bundle_signature.content).map_err(|_| invalid_signature())?;
+    decode_hex::<64>(&bundle_signature.signature)
+        .filter(|signature| ed25519_verify(public_key, &payload, signature))
+        .ok_or_else(invalid_signature)?;
+
+    if current_files != bundle_signature.content.files {
+        let changed_path = current_files
+            .iter()
+            .filter(|file| !bundle_signature.content.files.contains(file))
+            .chain(
+                bundle_signature
+                    .content
+                    .files
+                    .iter()
+                    .filter(|file| !current_files.contains(file)),
+            )
+            .map(|file| file.path.as_str())
+            .next()
+            .unwrap_or("bundle.json");
+        return Err(finding(
+            "bundle-content-mismatch",
+            format!("{label} bundle의 {changed_path} 파일이 서명 이후 바뀌어 사용할 수 없어요."),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks a bundle under `<base>/preset-catalog/published/<presetId>/<version>`
+/// against its booth's trust list. Bundles outside a published catalog are
+/// not gated. The files are hashed on every call; the verdict is cached until
+/// a hash of the bundle or the key file changes.
+pub fn check_published_bundle_signature(
+    bundle_dir: &Path,
+) -> Result<(), PresetBundleSignatureFinding> {
+    match resolve_catalog_bundle(bundle_dir) {
+        Some(catalog_bundle) => check_catalog_bundle(bundle_dir, &catalog_bundle, false).0,
+        None => Ok(()),
+    }
+}
+
+/// Like [`check_published_bundle_signature`], but records a rejected bundle
+/// in the operator audit log the first time it is seen.
+pub(crate) fn ensure_published_bundle_trusted(bundle_dir: &Path) -> bool {
+    let Some(catalog_bundle) = resolve_catalog_bundle(bundle_dir) else {
+        return true;
+    };
+    let (verdict, first_report) = check_catalog_bundle(bundle_dir, &catalog_bundle, true);
+    let Err(finding) = verdict else {
+        return true;
+    };
+    if !first_report {
+        return false;
+    }
+    let (base_dir, preset_id, published_version) = catalog_bundle;
+
+    log::warn!(
+        "preset_bundle_rejected preset={} version={} rule_code={}",
+        preset_id,
+        published_version,
+        finding.rule_code
+    );
+    if let Ok(occurred_at) = current_timestamp(SystemTime::now()) {
+        try_append_operator_audit_record(
+            &base_dir,
+            OperatorAuditRecordInput {
+                occurred_at,
+                session_id: None,
+                event_category: "publication-recovery",
+                event_type: "preset-bundle-rejected",
+                summary: "서명 검증에 실패한 preset bundle을 막았어요.".into(),
+                detail: finding.message,
+                actor_id: None,
+                source: "preset-catalog",
+                capture_id: None,
+                preset_id: Some(preset_id),
+                published_version: Some(published_version),
+                reason_code: Some(finding.rule_code.into()),
+            },
+        );
+    }
+
+    false
+}
+
+/// Returns the verdict and, when `report` is set, whether this is the first
+/// time the rejection is reported for the bundle's current files.
+fn check_catalog_bundle(
+    bundle_dir: &Path,
+    (base_dir, preset_id, published_version): &(PathBuf, String, String),
+    report: bool,
+) -> (Result<(), PresetBundleSignatureFinding>, bool) {
+    let Ok(fingerprint) = bundle_fingerprint(base_dir, bundle_dir) else {
+        let verdict = verify_preset_bundle_signature(
+            bundle_dir,
+            preset_id,
+            published_version,
+            &load_preset_bundle_trust_in_dir(base_dir),
+        );
+        return (verdict.clone(), report && verdict.is_err());
+    };
+    let verify = |files: &[SignedBundleFile]| {
+        verify_catalog_bundle(bundle_dir, base_dir, preset_id, published_version, files)
+    };
+    let verdicts = CATALOG_BUNDLE_VERDICTS.get_or_init(|| Mutex::new(HashMap::new()));
+    let Ok(mut verdicts) = verdicts.lock() else {
+        let verdict = verify(&fingerprint.files);
+        return (verdict.clone(), report && verdict.is_err());
+    };
+
+    let fresh = |fingerprint: BundleFingerprint| CachedBundleVerdict {
+        verdict: verify(&fingerprint.files),
+        fingerprint,
+        reported: false,
+    };
+    let cached = match verdicts.entry(bundle_dir.to_path_buf()) {
+        Entry::Occupied(entry) if entry.get().fingerprint == fingerprint => entry.into_mut(),
+        Entry::Occupied(mut entry) => {
+            entry.insert(fresh(fingerprint));
+            entry.into_mut()
+        }
+        Entry::Vacant(entry) => entry.insert(fresh(fingerprint)),
+    };
+    let first_report = report && cached.verdict.is_err() && !cached.reported;
+    cached.reported |= first_report;
+
+    (cached.verdict.clone(), first_report)
+}
+
+/// Seeded default bundles pass when they still match the built-in copy; all
+/// others need a signature from a trusted key.
+fn verify_catalog_bundle(
+    bundle_dir: &Path,
+    base_dir: &Path,
+    preset_id: &str,
+    published_version: &str,
+    files: &[SignedBundleFile],
+) -> Result<(), PresetBundleSignatureFinding> {
+    if matches_default_preset_bundle(files, preset_id, published_version) {
+        return Ok(());
+    }
+
+    verify_signed_bundle_files(
+        bundle_dir,
+        preset_id,
+        published_version,
+        &load_preset_bundle_trust_in_dir(base_dir),
+        files,
+    )
+}
+
+fn matches_default_preset_bundle(
+    files: &[SignedBundleFile],
+    preset_id: &str,
+    published_version: &str,
+) -> bool {
+    let Some(default_files) = default_preset_bundle_files(preset_id, published_version) else {
+        return false;
+    };
+    let mut expected = default_files
+        .into_iter()
+        .map(|(path, bytes)| SignedBundleFile {
+            path,
+            sha256: sha256_hex(&bytes),
+            size_bytes: bytes.len() as u64,
+        })
+        .collect::<Vec<_>>();
+    expected.sort_by(|left, right| left.path.cmp(&right.path));
+
+    files == expected
+}
+
+fn bundle_fingerprint(base_dir: &Path, bundle_dir: &Path) -> std::io::Result<BundleFingerprint> {
+    let hash_file = |path: PathBuf| fs::read(path).ok().map(|bytes| sha256_hex(&bytes));
+
+    Ok(BundleFingerprint {
+        trust_store: hash_file(resolve_preset_signing_keys_path(base_dir)),
+        signature: hash_file(bundle_dir.join(PRESET_BUNDLE_SIGNATURE_FILE_NAME)),
+        files: collect_bundle_files(bundle_dir)?,
+    })
+}
+
+fn resolve_catalog_bundle(bundle_dir: &Path) -> Option<(PathBuf, String, String)> {
+    let published_version = bundle_dir.file_name()?.to_str()?.to_string();
+    let preset_dir = bundle_dir.parent()?;
+    let preset_id = preset_dir.file_name()?.to_str()?.to_string();
+    let catalog_root = preset_dir.parent()?;
+    let base_dir = catalog_root.parent()?.parent()?;
+
+    (resolve_published_preset_catalog_dir(base_dir) == catalog_root)
+        .then(|| (base_dir.to_path_buf(), preset_id, published_version))
+}
+
+/// Every file under `bundle_dir` except the signature itself, by `/`-joined
+/// relative path in sorted order.
+fn collect_bundle_files(bundle_dir: &Path) -> std::io::Result<Vec<SignedBundleFile>> {
+    let mut files = Vec::new();
+    let mut pending = vec![(bundle_dir.to_path_buf(), String::new())];
+
+    while let Some((dir, prefix)) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let relative_path = format!("{prefix}{name}");
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                pending.push((entry.path(), format!("{relative_path}/")));
+            } else if relative_path != PRESET_BUNDLE_SIGNATURE_FILE_NAME {
+                let bytes = fs::read(entry.path())?;
+                files.push(SignedBundleFile {
+                    path: relative_path,
+                    sha256: sha256_hex(&bytes),
+                    size_bytes: bytes.len() as u64,
+                });
+            }
+        }
+    }
+    files.sort_by(|left, right| left.path.cmp(&right.path));
+
+    Ok(files)
+}
+
+fn signed_payload(content: &SignedBundleContent) -> Result<Vec<u8>, HostErrorEnvelope> {
+    serde_json::to_vec(content).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("preset bundle 서명을 준비하지 못했어요: {error}"))
+    })
+}
+
+fn finding(rule_code: &'static str, message: String) -> PresetBundleSignatureFinding {
+    PresetBundleSignatureFinding { rule_code, message }
+}
@@ -1,10 +1,11 @@
-use std::{fs, path::Path};
+use std::{fs, io::ErrorKind, path::Path};
 
 use serde_json::json;
 
 use crate::{
     contracts::dto::HostErrorEnvelope,
     preset::{
+        bundle_signature::PRESET_BUNDLE_SIGNATURE_FILE_NAME,
         preset_bundle::{load_published_preset_runtime_bundle, load_published_preset_summary},
         preset_catalog::resolve_published_preset_catalog_dir,
     },
@@ -35,6 +36,8 @@ const DEFAULT_PRESET_SEEDS: [DefaultPresetSeed; 3] = [
     ),
 ];
 
+/// Seeded bundles carry no signature; the runtime accepts them only while
+/// their files match [`default_preset_bundle_files`].
 pub fn ensure_default_preset_catalog_in_dir(base_dir: &Path) -> Result<(), HostErrorEnvelope> {
     let catalog_root = resolve_published_preset_catalog_dir(base_dir);
     let has_any_bundle = contains_any_bundle_json(&catalog_root)?;
@@ -52,59 +55,105 @@ pub fn ensure_default_preset_catalog_in_dir(base_dir: &Path) -> Result<(), HostE
         return Ok(());
     }
 
-    for (preset_id, published_version, display_name, preview_svg) in DEFAULT_PRESET_SEEDS {
+    for (preset_id, published_version, _, _) in DEFAULT_PRESET_SEEDS {
         let bundle_dir = catalog_root.join(preset_id).join(published_version);
         if has_any_bundle && !bundle_requires_runtime_backfill(&bundle_dir) {
             continue;
         }
 
-        fs::create_dir_all(&bundle_dir).map_err(map_fs_error)?;
-        fs::create_dir_all(bundle_dir.join("xmp")).map_err(map_fs_error)?;
-        fs::write(bundle_dir.join("preview.svg"), preview_svg).map_err(map_fs_error)?;
-        fs::write(
-            bundle_dir.join("xmp").join("template.xmp"),
-            DEFAULT_RENDER_TEMPLATE,
-        )
-        .map_err(map_fs_error)?;
-
-        let bundle = json!({
-            "schemaVersion": "published-preset-bundle/v1",
-            "presetId": preset_id,
-            "displayName": display_name,
-            "publishedVersion": published_version,
-            "lifecycleStatus": "published",
-            "boothStatus": "booth-safe",
-            "darktableVersion": "5.4.1",
-            "xmpTemplatePath": "xmp/template.xmp",
-            "previewProfile": {
-                "profileId": "preview-jpeg",
-                "displayName": "Booth Preview JPEG",
-                "outputColorSpace": "sRGB",
-            },
-            "finalProfile": {
-                "profileId": "final-jpeg",
-                "displayName": "Booth Final JPEG",
-                "outputColorSpace": "sRGB",
-            },
-            "preview": {
-                "kind": "preview-tile",
-                "assetPath": "preview.svg",
-                "altText": format!("{display_name} preview"),
+        let files = default_preset_bundle_files(preset_id, published_version)
+            .ok_or_else(|| HostErrorEnvelope::persistence("기본 프리셋 번들을 만들지 못했어요."))?;
+        // A seed is trusted by its exact files, so a signature left by an
+        // older copy would only make the refreshed seed look tampered.
+        match fs::remove_file(bundle_dir.join(PRESET_BUNDLE_SIGNATURE_FILE_NAME)) {
+            Ok(()) => {}
+            Err(error) if error.kind() == ErrorKind::NotFound => {}
+            Err(error) => return Err(map_fs_error(error)),
+        }
+        // bundle.json comes last, so a half-written seed is never loadable.
+        for (relative_path, bytes) in files.iter().rev() {
+            let path = bundle_dir.join(relative_path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(map_fs_error)?;
             }
-        });
-
-        let bundle_bytes = serde_json::to_vec_pretty(&bundle).map_err(|error| {
-            HostErrorEnvelope::persistence(format!(
-                "기본 프리셋 번들을 직렬화하지 못했어요: {error}"
-            ))
-        })?;
-
-        fs::write(bundle_dir.join("bundle.json"), bundle_bytes).map_err(map_fs_error)?;
+            fs::write(path, bytes).map_err(map_fs_error)?;
+        }
     }
 
     Ok(())
 }
 
+/// The exact files of a seeded bundle, `bundle.json` first, by `/`-joined
+/// path. `None` for anything that is not a seeded preset version.
+pub(crate) fn default_preset_bundle_files(
+    preset_id: &str,
+    published_version: &str,
+) -> Option<Vec<(String, Vec<u8>)>> {
+    let (_, _, display_name, preview_svg) =
+        DEFAULT_PRESET_SEEDS
+            .iter()
+            .find(|(seed_id, seed_version, _, _)| {
+                *seed_id == preset_id && *seed_version == published_version
+            })?;
+    let bundle = json!({
+        "schemaVersion": "published-preset-bundle/v1",
+        "presetId": preset_id,
+        "displayName": display_name,
+        "publishedVersion": published_version,
+        "lifecycleStatus": "published",
+        "boothStatus": "booth-safe",
+        "darktableVersion": "5.4.1",
+        "xmpTemplatePath": "xmp/template.xmp",
+        "previewProfile": {
+            "profileId": "preview-jpeg",
+            "displayName": "Booth Preview JPEG",
+            "outputColorSpace": "sRGB",
+        },
+        "finalProfile": {
+            "profileId": "final-jpeg",
+            "displayName": "Booth Final JPEG",
+            "outputColorSpace": "sRGB",
+        },
+        "preview": {
+            "kind": "preview-tile",
+            "assetPath": "preview.svg",
+            "altText": format!("{display_name} preview"),
+        },
+        "fallbackLook": default_fallback_look(preset_id),
+    });
+
+    Some(vec![
+        (
+            "bundle.json".into(),
+            serde_json::to_vec_pretty(&bundle).ok()?,
+        ),
+        ("preview.svg".into(), preview_svg.as_bytes().to_vec()),
+        (
+            "xmp/template.xmp".into(),
+            DEFAULT_RENDER_TEMPLATE.as_bytes().to_vec(),
+        ),
+    ])
+}
+
+/// Approximations of the seeded looks for booths running without darktable.
+fn default_fallback_look(preset_id: &str) -> serde_json::Value {
+    match preset_id {
+        "preset_soft-glow" => json!({
+            "exposureEv": 0.25,
+            "toneCurve": [[0.0, 0.06], [0.5, 0.56], [1.0, 1.0]],
+        }),
+        "preset_mono-pop" => json!({
+            "toneCurve": [[0.0, 0.0], [0.25, 0.18], [0.75, 0.84], [1.0, 1.0]],
+            "monochrome": true,
+        }),
+        "preset_daylight" => json!({
+            "exposureEv": 0.1,
+            "whiteBalance": { "red": 1.06, "green": 1.0, "blue": 0.92 },
+        }),
+        _ => json!({}),
+    }
+}
+
 fn bundle_requires_runtime_backfill(bundle_dir: &Path) -> bool {
     if !bundle_dir.join("bundle.json").is_file() {
         return true;
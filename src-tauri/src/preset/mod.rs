@@ -1,5 +1,7 @@
 pub mod authoring_pipeline;
+pub mod bundle_signature;
 pub mod default_catalog;
+pub mod overlay_layout;
 pub mod preset_bundle;
 pub mod preset_catalog;
 pub mod preset_catalog_state;
@@ -0,0 +1,419 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::render::{overlay_compositor::supports_overlay_text_char, png_codec};
+
+pub const OVERLAY_LAYOUT_SCHEMA_VERSION: &str = "overlay-layout/v1";
+pub const OVERLAY_TEXT_TOKENS: [&str; 3] = ["{boothAlias}", "{date}", "{branchName}"];
+
+const MAX_OVERLAY_ELEMENTS: usize = 32;
+const MAX_OVERLAY_TEXT_LENGTH: usize = 64;
+const MAX_UTC_OFFSET_MINUTES: i32 = 14 * 60;
+const BOUNDS_EPSILON: f32 = 0.0001;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OverlayTextAnchor {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+/// Normalised to the rendered output: `0.0..=1.0` on both axes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverlayRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OverlayElement {
+    Image {
+        asset_path: PathBuf,
+        rect: OverlayRect,
+        opacity: f32,
+    },
+    /// `template` may contain the `OVERLAY_TEXT_TOKENS`; `height` is the cap
+    /// height as a fraction of the output height.
+    Text {
+        template: String,
+        x: f32,
+        y: f32,
+        height: f32,
+        color: [u8; 3],
+        anchor: OverlayTextAnchor,
+    },
+}
+
+/// Frames, stamps and logos composited onto rendered outputs, in paint order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayLayout {
+    pub applies_to_preview: bool,
+    pub applies_to_final: bool,
+    /// Offset used to turn the capture time into the `{date}` stamp.
+    pub utc_offset_minutes: i32,
+    pub elements: Vec<OverlayElement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverlayLayoutIssue {
+    pub rule_code: &'static str,
+    pub is_error: bool,
+    pub field_path: String,
+    pub message: String,
+    pub guidance: String,
+}
+
+/// `layout` is only set when no issue is an error; warnings ride along.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayLayoutInspection {
+    pub layout: Option<OverlayLayout>,
+    pub issues: Vec<OverlayLayoutIssue>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OverlayLayoutFile {
+    schema_version: String,
+    #[serde(default)]
+    applies_to: Vec<String>,
+    #[serde(default)]
+    utc_offset_minutes: i32,
+    elements: Vec<OverlayElementFile>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+enum OverlayElementFile {
+    #[serde(rename_all = "camelCase")]
+    Image {
+        asset_path: String,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        #[serde(default = "full_opacity")]
+        opacity: f32,
+    },
+    #[serde(rename_all = "camelCase")]
+    Text {
+        template: String,
+        x: f32,
+        y: f32,
+        height: f32,
+        #[serde(default)]
+        color: Option<String>,
+        #[serde(default)]
+        anchor: OverlayTextAnchor,
+    },
+}
+
+/// Overlay assets must sit next to the layout file so a published bundle can
+/// carry the layout and its PNGs as one flat `overlay/` folder.
+pub fn inspect_overlay_layout(layout_path: &Path) -> OverlayLayoutInspection {
+    let mut issues = Vec::new();
+    let layout_file = fs::read_to_string(layout_path)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<OverlayLayoutFile>(&contents).ok());
+    let Some(layout_file) = layout_file else {
+        issues.push(issue(
+            "overlay-layout-invalid",
+            "",
+            "overlay layout JSON을 읽지 못했어요.",
+            "overlay-layout/v1 형식의 JSON인지, elements 배열이 있는지 확인해 주세요.",
+        ));
+        return OverlayLayoutInspection {
+            layout: None,
+            issues,
+        };
+    };
+
+    if layout_file.schema_version != OVERLAY_LAYOUT_SCHEMA_VERSION {
+        issues.push(issue(
+            "overlay-layout-invalid",
+            "schemaVersion",
+            "지원하지 않는 overlay layout 버전이에요.",
+            "schemaVersion을 overlay-layout/v1로 맞춰 주세요.",
+        ));
+    }
+
+    let (applies_to_preview, applies_to_final) = if layout_file.applies_to.is_empty() {
+        (true, true)
+    } else {
+        if layout_file
+            .applies_to
+            .iter()
+            .any(|stage| !matches!(stage.as_str(), "preview" | "final"))
+        {
+            issues.push(issue(
+                "overlay-layout-invalid",
+                "appliesTo",
+                "appliesTo에는 preview와 final만 넣을 수 있어요.",
+                "appliesTo를 [\"preview\", \"final\"] 중에서 골라 주세요.",
+            ));
+        }
+        (
+            layout_file
+                .applies_to
+                .iter()
+                .any(|stage| stage == "preview"),
+            layout_file.applies_to.iter().any(|stage| stage == "final"),
+        )
+    };
+
+    if layout_file.utc_offset_minutes.abs() > MAX_UTC_OFFSET_MINUTES {
+        issues.push(issue(
+            "overlay-layout-invalid",
+            "utcOffsetMinutes",
+            "날짜 stamp에 쓸 UTC offset이 범위를 벗어났어요.",
+            "utcOffsetMinutes를 -840에서 840 사이로 맞춰 주세요. 한국 지점은 540이에요.",
+        ));
+    }
+
+    if layout_file.elements.is_empty() || layout_file.elements.len() > MAX_OVERLAY_ELEMENTS {
+        issues.push(issue(
+            "overlay-layout-invalid",
+            "elements",
+            "overlay element 수가 올바르지 않아요.",
+            "elements에 1개 이상 32개 이하의 image/text element를 넣어 주세요.",
+        ));
+    }
+
+    let layout_dir = layout_path.parent().unwrap_or_else(|| Path::new("."));
+    let mut elements = Vec::with_capacity(layout_file.elements.len());
+    for (index, element) in layout_file.elements.into_iter().enumerate() {
+        let field_path = format!("elements[{index}]");
+        match element {
+            OverlayElementFile::Image {
+                asset_path,
+                x,
+                y,
+                width,
+                height,
+                opacity,
+            } => {
+                let rect = OverlayRect {
+                    x,
+                    y,
+                    width,
+                    height,
+                };
+                if !is_within_unit_square(x, y, width, height) || width <= 0.0 {
+                    issues.push(bounds_issue(&field_path));
+                }
+                if !(0.0..=1.0).contains(&opacity) {
+                    issues.push(issue(
+                        "overlay-element-bounds",
+                        &format!("{field_path}.opacity"),
+                        "overlay 불투명도가 0과 1 사이가 아니에요.",
+                        "opacity를 0.0에서 1.0 사이로 맞춰 주세요.",
+                    ));
+                }
+                if let Some(asset_path) =
+                    inspect_overlay_asset(layout_dir, &asset_path, &field_path, &mut issues)
+                {
+                    elements.push(OverlayElement::Image {
+                        asset_path,
+                        rect,
+                        opacity,
+                    });
+                }
+            }
+            OverlayElementFile::Text {
+                template,
+                x,
+                y,
+                height,
+                color,
+                anchor,
+            } => {
+                if !(0.01..=0.25).contains(&height) || !is_within_unit_square(x, y, 0.0, height) {
+                    issues.push(bounds_issue(&field_path));
+                }
+                inspect_overlay_text(&template, &field_path, &mut issues);
+                let color = match color.as_deref().map(parse_hex_color) {
+                    None => Some([255, 255, 255]),
+                    Some(color) => color,
+                };
+                let Some(color) = color else {
+                    issues.push(issue(
+                        "overlay-layout-invalid",
+                        &format!("{field_path}.color"),
+                        "text 색상 형식이 올바르지 않아요.",
+                        "color를 #RRGGBB 형식으로 적어 주세요.",
+                    ));
+                    continue;
+                };
+                elements.push(OverlayElement::Text {
+                    template,
+                    x,
+                    y,
+                    height,
+                    color,
+                    anchor,
+                });
+            }
+        }
+    }
+
+    let layout = (!issues.iter().any(|issue| issue.is_error)).then_some(OverlayLayout {
+        applies_to_preview,
+        applies_to_final,
+        utc_offset_minutes: layout_file.utc_offset_minutes,
+        elements,
+    });
+
+    OverlayLayoutInspection { layout, issues }
+}
+
+/// Asset file names referenced by a layout, for copying into a bundle.
+pub fn overlay_layout_asset_paths(layout: &OverlayLayout) -> Vec<&Path> {
+    layout
+        .elements
+        .iter()
+        .filter_map(|element| match element {
+            OverlayElement::Image { asset_path, .. } => Some(asset_path.as_path()),
+            OverlayElement::Text { .. } => None,
+        })
+        .collect()
+}
+
+fn inspect_overlay_asset(
+    layout_dir: &Path,
+    asset_path: &str,
+    field_path: &str,
+    issues: &mut Vec<OverlayLayoutIssue>,
+) -> Option<PathBuf> {
+    let field_path = format!("{field_path}.assetPath");
+    let is_bare_png = Path::new(asset_path)
+        .file_name()
+        .and_then(|name| name.to_str())
+        == Some(asset_path)
+        && asset_path.to_ascii_lowercase().ends_with(".png");
+    if !is_bare_png {
+        issues.push(issue(
+            "overlay-asset-unsupported",
+            &field_path,
+            "overlay 자산은 layout 파일과 같은 폴더의 PNG여야 해요.",
+            "assetPath에 하위 폴더 없이 .png 파일 이름만 적어 주세요.",
+        ));
+        return None;
+    }
+
+    let resolved = layout_dir.join(asset_path);
+    let Ok(bytes) = fs::read(&resolved) else {
+        issues.push(issue(
+            "overlay-asset-missing",
+            &field_path,
+            "overlay PNG 자산을 찾지 못했어요.",
+            "layout 파일 옆에 assetPath와 같은 이름의 PNG를 넣어 주세요.",
+        ));
+        return None;
+    };
+
+    if let Err(detail) = png_codec::decode_png(&bytes) {
+        issues.push(issue(
+            "overlay-asset-unsupported",
+            &field_path,
+            &format!("overlay PNG를 읽지 못했어요: {detail}"),
+            "8-bit, interlace 없는 PNG로 다시 내보내 주세요.",
+        ));
+        return None;
+    }
+
+    Some(resolved)
+}
+
+fn inspect_overlay_text(template: &str, field_path: &str, issues: &mut Vec<OverlayLayoutIssue>) {
+    let field_path = format!("{field_path}.template");
+    if template.trim().is_empty() || template.chars().count() > MAX_OVERLAY_TEXT_LENGTH {
+        issues.push(issue(
+            "overlay-layout-invalid",
+            &field_path,
+            "overlay text가 비어 있거나 너무 길어요.",
+            "template를 1자 이상 64자 이하로 적어 주세요.",
+        ));
+        return;
+    }
+
+    let mut literal = template.to_string();
+    for token in OVERLAY_TEXT_TOKENS {
+        literal = literal.replace(token, "");
+    }
+    if literal.contains('{') || literal.contains('}') {
+        issues.push(issue(
+            "overlay-text-token",
+            &field_path,
+            "지원하지 않는 overlay text token이 있어요.",
+            "{boothAlias}, {date}, {branchName} token만 사용할 수 있어요.",
+        ));
+    }
+
+    if literal.chars().any(|ch| !supports_overlay_text_char(ch)) {
+        issues.push(OverlayLayoutIssue {
+            is_error: false,
+            ..issue(
+                "overlay-text-glyphs",
+                &field_path,
+                "기본 overlay 글꼴에 없는 글자는 인쇄되지 않아요.",
+                "영문, 숫자와 기본 문장부호만 쓰거나 글자를 PNG overlay로 넣어 주세요.",
+            )
+        });
+    }
+}
+
+fn is_within_unit_square(x: f32, y: f32, width: f32, height: f32) -> bool {
+    [x, y, width, height].iter().all(|value| value.is_finite())
+        && x >= 0.0
+        && y >= 0.0
+        && width >= 0.0
+        && height > 0.0
+        && x + width <= 1.0 + BOUNDS_EPSILON
+        && y + height <= 1.0 + BOUNDS_EPSILON
+}
+
+/// Parses `#RRGGBB`; shared with the bundle's shot layout background.
+pub fn parse_hex_color(value: &str) -> Option<[u8; 3]> {
+    let hex = value.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+fn bounds_issue(field_path: &str) -> OverlayLayoutIssue {
+    issue(
+        "overlay-element-bounds",
+        field_path,
+        "overlay element가 사진 범위를 벗어났어요.",
+        "x, y, width, height를 0.0에서 1.0 사이 비율로 적고, text height는 0.01에서 0.25 사이로 맞춰 주세요.",
+    )
+}
+
+fn issue(
+    rule_code: &'static str,
+    field_path: &str,
+    message: &str,
+    guidance: &str,
+) -> OverlayLayoutIssue {
+    OverlayLayoutIssue {
+        rule_code,
+        is_error: true,
+        field_path: field_path.into(),
+        message: message.into(),
+        guidance: guidance.into(),
+    }
+}
+
+fn full_opacity() -> f32 {
+    1.0
+}
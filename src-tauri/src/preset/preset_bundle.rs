@@ -5,9 +5,15 @@ use std::{
 
 use serde::Deserialize;
 
-use crate::contracts::dto::{
-    is_non_blank, is_valid_preset_id, is_valid_published_version, PresetPreviewAssetDto,
-    PublishedPresetSummaryDto,
+use crate::{
+    contracts::dto::{
+        is_non_blank, is_valid_preset_id, is_valid_published_version, PresetPreviewAssetDto,
+        PublishedPresetSummaryDto,
+    },
+    preset::{
+        bundle_signature::ensure_published_bundle_trusted,
+        overlay_layout::{inspect_overlay_layout, parse_hex_color, OverlayLayout, OverlayRect},
+    },
 };
 
 const PUBLISHED_PRESET_BUNDLE_SCHEMA_VERSION: &str = "published-preset-bundle/v1";
@@ -19,7 +25,118 @@ pub struct PublishedPresetRenderProfile {
     pub output_color_space: String,
 }
 
+/// Per-channel gains applied in linear light by the built-in fallback renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FallbackWhiteBalance {
+    #[serde(default = "neutral_gain")]
+    pub red: f32,
+    #[serde(default = "neutral_gain")]
+    pub green: f32,
+    #[serde(default = "neutral_gain")]
+    pub blue: f32,
+}
+
+impl Default for FallbackWhiteBalance {
+    fn default() -> Self {
+        Self {
+            red: 1.0,
+            green: 1.0,
+            blue: 1.0,
+        }
+    }
+}
+
+/// The subset of a look the built-in renderer can reproduce without
+/// darktable: exposure, white balance, a point tone curve and monochrome.
+/// Bundles without `fallbackLook` render neutral in degraded mode.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishedPresetFallbackLook {
+    #[serde(default)]
+    pub exposure_ev: f32,
+    #[serde(default)]
+    pub white_balance: FallbackWhiteBalance,
+    #[serde(default)]
+    pub tone_curve: Vec<[f32; 2]>,
+    #[serde(default)]
+    pub monochrome: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputVariantFormat {
+    Jpeg,
+    Tiff,
+}
+
+impl OutputVariantFormat {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpeg",
+            Self::Tiff => "tiff",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpg",
+            Self::Tiff => "tif",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputVariantCrop {
+    #[default]
+    None,
+    Square,
+}
+
+/// An extra final output rendered next to the canonical final, e.g. a
+/// web-sized JPEG for download or a 16-bit TIFF for an external print lab.
 #[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishedPresetOutputVariant {
+    pub variant_id: String,
+    pub display_name: String,
+    pub format: OutputVariantFormat,
+    pub bit_depth: u8,
+    pub max_width: Option<u32>,
+    pub max_height: Option<u32>,
+    pub quality: u8,
+    pub color_space: String,
+    pub crop: OutputVariantCrop,
+    /// Consumers that may pick this variant: `download`, `print`, `social`.
+    pub purposes: Vec<String>,
+}
+
+impl PublishedPresetOutputVariant {
+    pub fn serves(&self, purpose: &str) -> bool {
+        self.purposes.iter().any(|value| value == purpose)
+    }
+}
+
+/// Multi-shot layout (photo strip or grid): each slot is filled by one
+/// capture's final and the slots are composed onto one canvas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublishedPresetShotLayout {
+    pub layout_id: String,
+    pub display_name: String,
+    pub canvas_width: u32,
+    pub canvas_height: u32,
+    pub background_color: [u8; 3],
+    pub slots: Vec<PublishedPresetShotSlot>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublishedPresetShotSlot {
+    pub slot_id: String,
+    /// Normalised to the canvas, like overlay rects.
+    pub rect: OverlayRect,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct PublishedPresetRuntimeBundle {
     pub preset_id: String,
     pub display_name: String,
@@ -28,6 +145,10 @@ pub struct PublishedPresetRuntimeBundle {
     pub xmp_template_path: PathBuf,
     pub preview_profile: PublishedPresetRenderProfile,
     pub final_profile: PublishedPresetRenderProfile,
+    pub fallback_look: PublishedPresetFallbackLook,
+    pub output_variants: Vec<PublishedPresetOutputVariant>,
+    pub overlay_layout: Option<OverlayLayout>,
+    pub shot_layout: Option<PublishedPresetShotLayout>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -48,6 +169,14 @@ struct PublishedPresetBundle {
     preview_profile: Option<BundleRenderProfile>,
     #[serde(default)]
     final_profile: Option<BundleRenderProfile>,
+    #[serde(default)]
+    fallback_look: Option<PublishedPresetFallbackLook>,
+    #[serde(default)]
+    output_variants: Vec<BundleOutputVariant>,
+    #[serde(default)]
+    overlay_layout_path: Option<String>,
+    #[serde(default)]
+    shot_layout: Option<BundleShotLayout>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -58,6 +187,50 @@ struct BundlePreviewAsset {
     alt_text: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BundleOutputVariant {
+    variant_id: String,
+    display_name: String,
+    format: OutputVariantFormat,
+    #[serde(default)]
+    bit_depth: Option<u8>,
+    #[serde(default)]
+    max_width: Option<u32>,
+    #[serde(default)]
+    max_height: Option<u32>,
+    #[serde(default)]
+    quality: Option<u8>,
+    #[serde(default)]
+    color_space: Option<String>,
+    #[serde(default)]
+    crop: OutputVariantCrop,
+    #[serde(default)]
+    purposes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BundleShotLayout {
+    layout_id: String,
+    display_name: String,
+    canvas_width: u32,
+    canvas_height: u32,
+    #[serde(default)]
+    background_color: Option<String>,
+    slots: Vec<BundleShotSlot>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BundleShotSlot {
+    slot_id: String,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct BundleRenderProfile {
@@ -117,9 +290,17 @@ pub fn load_published_preset_summary(bundle_dir: &Path) -> Option<PublishedPrese
     })
 }
 
+/// Signatures are always checked. A bundle that fails is rejected, and the
+/// first rejection of its current files is audited.
 pub fn load_published_preset_runtime_bundle(
     bundle_dir: &Path,
 ) -> Option<PublishedPresetRuntimeBundle> {
+    let runtime_bundle = read_published_preset_runtime_bundle(bundle_dir)?;
+
+    ensure_published_bundle_trusted(bundle_dir).then_some(runtime_bundle)
+}
+
+fn read_published_preset_runtime_bundle(bundle_dir: &Path) -> Option<PublishedPresetRuntimeBundle> {
     let bundle_path = bundle_dir.join("bundle.json");
     let bundle_bytes = fs::read_to_string(bundle_path).ok()?;
     let bundle: PublishedPresetBundle = serde_json::from_str(&bundle_bytes).ok()?;
@@ -169,6 +350,49 @@ pub fn load_published_preset_runtime_bundle(
         &bundle.display_name,
         "final",
     )?;
+    let fallback_look = bundle
+        .fallback_look
+        .map(|look| {
+            normalize_fallback_look(look).unwrap_or_else(|| {
+                log::warn!(
+                    "preset_fallback_look_ignored preset={} version={}",
+                    bundle.preset_id,
+                    bundle.published_version
+                );
+                PublishedPresetFallbackLook::default()
+            })
+        })
+        .unwrap_or_default();
+    let output_variants = normalize_output_variants(
+        bundle.output_variants,
+        &bundle.preset_id,
+        &bundle.published_version,
+    );
+    let overlay_layout = bundle
+        .overlay_layout_path
+        .as_deref()
+        .and_then(|layout_path| {
+            resolve_overlay_layout(
+                bundle_dir,
+                layout_path,
+                &bundle.preset_id,
+                &bundle.published_version,
+            )
+        });
+
+    let shot_layout = bundle.shot_layout.and_then(|layout| {
+        let layout_id = layout.layout_id.clone();
+        let normalized = normalize_shot_layout(layout);
+        if normalized.is_none() {
+            log::warn!(
+                "preset_shot_layout_ignored preset={} version={} layout_id={}",
+                bundle.preset_id,
+                bundle.published_version,
+                layout_id
+            );
+        }
+        normalized
+    });
 
     Some(PublishedPresetRuntimeBundle {
         preset_id: bundle.preset_id,
@@ -178,9 +402,46 @@ pub fn load_published_preset_runtime_bundle(
         xmp_template_path,
         preview_profile,
         final_profile,
+        fallback_look,
+        output_variants,
+        overlay_layout,
+        shot_layout,
     })
 }
 
+/// A broken layout leaves outputs un-composited rather than blocking the
+/// preset; authoring validation is where layouts are rejected.
+fn resolve_overlay_layout(
+    bundle_dir: &Path,
+    layout_path: &str,
+    preset_id: &str,
+    published_version: &str,
+) -> Option<OverlayLayout> {
+    let inspection = resolve_bundle_asset_path(bundle_dir, layout_path)
+        .map(|resolved| inspect_overlay_layout(&resolved));
+    let layout = inspection
+        .as_ref()
+        .and_then(|inspection| inspection.layout.clone());
+    if layout.is_none() {
+        log::warn!(
+            "preset_overlay_layout_ignored preset={} version={} rule_codes={}",
+            preset_id,
+            published_version,
+            inspection
+                .map(|inspection| inspection
+                    .issues
+                    .iter()
+                    .filter(|issue| issue.is_error)
+                    .map(|issue| issue.rule_code)
+                    .collect::<Vec<_>>()
+                    .join(","))
+                .unwrap_or_else(|| "overlay-layout-missing".into())
+        );
+    }
+
+    layout
+}
+
 fn resolve_render_profile(
     profile: Option<BundleRenderProfile>,
     preset_id: &str,
@@ -234,3 +495,191 @@ fn normalize_render_profile(profile: BundleRenderProfile) -> Option<PublishedPre
         output_color_space: profile.output_color_space,
     })
 }
+
+fn normalize_fallback_look(
+    look: PublishedPresetFallbackLook,
+) -> Option<PublishedPresetFallbackLook> {
+    let gains = [
+        look.white_balance.red,
+        look.white_balance.green,
+        look.white_balance.blue,
+    ];
+    if !(-4.0..=4.0).contains(&look.exposure_ev)
+        || gains.iter().any(|gain| !(0.25..=4.0).contains(gain))
+    {
+        return None;
+    }
+
+    if !look.tone_curve.is_empty() {
+        let in_range = look
+            .tone_curve
+            .iter()
+            .all(|point| point.iter().all(|value| (0.0..=1.0).contains(value)));
+        let increasing = look
+            .tone_curve
+            .windows(2)
+            .all(|pair| pair[0][0] < pair[1][0]);
+        if !(2..=16).contains(&look.tone_curve.len()) || !in_range || !increasing {
+            return None;
+        }
+    }
+
+    Some(look)
+}
+
+/// Drops malformed or duplicate variants instead of rejecting the bundle; the
+/// canonical final is always rendered regardless.
+fn normalize_output_variants(
+    variants: Vec<BundleOutputVariant>,
+    preset_id: &str,
+    published_version: &str,
+) -> Vec<PublishedPresetOutputVariant> {
+    let mut normalized: Vec<PublishedPresetOutputVariant> = Vec::new();
+
+    for variant in variants {
+        let variant_id = variant.variant_id.clone();
+        match normalize_output_variant(variant) {
+            Some(variant)
+                if !normalized
+                    .iter()
+                    .any(|existing| existing.variant_id == variant.variant_id) =>
+            {
+                normalized.push(variant)
+            }
+            _ => log::warn!(
+                "preset_output_variant_ignored preset={} version={} variant_id={}",
+                preset_id,
+                published_version,
+                variant_id
+            ),
+        }
+    }
+
+    normalized
+}
+
+fn normalize_output_variant(variant: BundleOutputVariant) -> Option<PublishedPresetOutputVariant> {
+    if !is_bundle_slug(&variant.variant_id) || !is_non_blank(&variant.display_name) {
+        return None;
+    }
+
+    let bit_depth = variant.bit_depth.unwrap_or(8);
+    let quality = variant.quality.unwrap_or(92);
+    match variant.format {
+        OutputVariantFormat::Jpeg if bit_depth != 8 || !(1..=100).contains(&quality) => {
+            return None
+        }
+        OutputVariantFormat::Tiff
+            if !matches!(bit_depth, 8 | 16) || variant.crop != OutputVariantCrop::None =>
+        {
+            return None
+        }
+        _ => {}
+    }
+
+    let color_space = match variant.color_space.as_deref().map(str::trim) {
+        None | Some("sRGB") => "sRGB",
+        Some("AdobeRGB") => "AdobeRGB",
+        Some(_) => return None,
+    };
+    let valid_dimension = |value: Option<u32>| {
+        value
+            .filter(|value| !(64..=20_000).contains(value))
+            .is_none()
+    };
+    if !valid_dimension(variant.max_width) || !valid_dimension(variant.max_height) {
+        return None;
+    }
+
+    let purposes = if variant.purposes.is_empty() {
+        vec!["download".into()]
+    } else {
+        variant.purposes
+    };
+    if purposes
+        .iter()
+        .any(|purpose| !matches!(purpose.as_str(), "download" | "print" | "social"))
+    {
+        return None;
+    }
+
+    Some(PublishedPresetOutputVariant {
+        variant_id: variant.variant_id,
+        display_name: variant.display_name,
+        format: variant.format,
+        bit_depth,
+        max_width: variant.max_width,
+        max_height: variant.max_height,
+        quality,
+        color_space: color_space.into(),
+        crop: variant.crop,
+        purposes,
+    })
+}
+
+/// A broken layout drops the preset back to single-shot sessions.
+fn normalize_shot_layout(layout: BundleShotLayout) -> Option<PublishedPresetShotLayout> {
+    let canvas_range = 256..=12_000;
+    if !is_bundle_slug(&layout.layout_id)
+        || !is_non_blank(&layout.display_name)
+        || !canvas_range.contains(&layout.canvas_width)
+        || !canvas_range.contains(&layout.canvas_height)
+        || !(2..=12).contains(&layout.slots.len())
+    {
+        return None;
+    }
+
+    let background_color = match layout.background_color.as_deref() {
+        None => [255, 255, 255],
+        Some(value) => parse_hex_color(value)?,
+    };
+    let mut slots: Vec<PublishedPresetShotSlot> = Vec::with_capacity(layout.slots.len());
+    for slot in layout.slots {
+        let in_canvas = [slot.x, slot.y, slot.width, slot.height]
+            .iter()
+            .all(|value| value.is_finite() && *value >= 0.0)
+            && slot.width > 0.0
+            && slot.height > 0.0
+            && slot.x + slot.width <= 1.0
+            && slot.y + slot.height <= 1.0;
+        if !is_bundle_slug(&slot.slot_id)
+            || !in_canvas
+            || slots
+                .iter()
+                .any(|existing| existing.slot_id == slot.slot_id)
+        {
+            return None;
+        }
+
+        slots.push(PublishedPresetShotSlot {
+            slot_id: slot.slot_id,
+            rect: OverlayRect {
+                x: slot.x,
+                y: slot.y,
+                width: slot.width,
+                height: slot.height,
+            },
+        });
+    }
+
+    Some(PublishedPresetShotLayout {
+        layout_id: layout.layout_id,
+        display_name: layout.display_name,
+        canvas_width: layout.canvas_width,
+        canvas_height: layout.canvas_height,
+        background_color,
+        slots,
+    })
+}
+
+fn is_bundle_slug(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= 32
+        && value.chars().all(|character| {
+            character.is_ascii_lowercase() || character.is_ascii_digit() || character == '-'
+        })
+}
+
+fn neutral_gain() -> f32 {
+    1.0
+}
@@ -6,13 +6,15 @@ use crate::{
         LoadPresetCatalogInputDto, PresetCatalogResultDto, PublishedPresetSummaryDto,
     },
     session::{
-        session_manifest::{ActivePresetBinding, SessionManifest},
+        session_journal::{commit_session_manifest, SessionJournalEvent},
+        session_manifest::{is_preset_allowed_for_session, ActivePresetBinding, SessionManifest},
         session_paths::SessionPaths,
-        session_repository::{read_session_manifest, write_session_manifest},
+        session_repository::read_session_manifest,
     },
 };
 
 use super::{
+    bundle_signature::{check_published_bundle_signature, ensure_published_bundle_trusted},
     preset_bundle::{
         load_published_preset_runtime_bundle, load_published_preset_summary,
         PublishedPresetRuntimeBundle,
@@ -42,10 +44,13 @@ pub fn load_preset_catalog_in_dir(
         });
     }
 
-    let presets = load_selectable_published_presets_for_snapshot(
+    let presets: Vec<_> = load_selectable_published_presets_for_snapshot(
         &catalog_root,
         manifest.catalog_snapshot.as_deref().unwrap_or(&[]),
-    );
+    )
+    .into_iter()
+    .filter(|preset| is_preset_allowed_for_session(&manifest, &preset.preset_id))
+    .collect();
 
     Ok(PresetCatalogResultDto {
         session_id: input.session_id,
@@ -106,9 +111,13 @@ pub fn find_selectable_published_preset_summary_in_snapshot(
         return None;
     }
 
-    load_published_preset_summary(&catalog_root.join(preset_id).join(published_version))
+    let bundle_dir = catalog_root.join(preset_id).join(published_version);
+    load_published_preset_summary(&bundle_dir)
+        .filter(|_| ensure_published_bundle_trusted(&bundle_dir))
 }
 
+/// Bundles failing their signature check are left out without an audit
+/// entry; selecting one is what gets recorded.
 pub fn load_selectable_published_presets_for_snapshot(
     catalog_root: &Path,
     catalog_snapshot: &[ActivePresetBinding],
@@ -116,11 +125,11 @@ pub fn load_selectable_published_presets_for_snapshot(
     let mut presets = catalog_snapshot
         .iter()
         .filter_map(|binding| {
-            load_published_preset_summary(
-                &catalog_root
-                    .join(&binding.preset_id)
-                    .join(&binding.published_version),
-            )
+            let bundle_dir = catalog_root
+                .join(&binding.preset_id)
+                .join(&binding.published_version);
+            load_published_preset_summary(&bundle_dir)
+                .filter(|_| check_published_bundle_signature(&bundle_dir).is_ok())
         })
         .collect::<Vec<_>>();
     presets.sort_by(|left, right| {
@@ -151,7 +160,11 @@ fn ensure_catalog_snapshot_pinned(
     let (catalog_revision, catalog_snapshot) = capture_live_catalog_snapshot(base_dir)?;
     manifest.catalog_revision = Some(catalog_revision);
     manifest.catalog_snapshot = Some(catalog_snapshot);
-    write_session_manifest(manifest_path, &manifest)?;
+    commit_session_manifest(
+        manifest_path,
+        &manifest,
+        SessionJournalEvent::manifest_updated("catalog-pinned", &manifest),
+    )?;
 
     Ok(manifest)
 }
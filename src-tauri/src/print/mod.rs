@@ -0,0 +1,433 @@
+pub mod print_document;
+pub mod print_queue;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    capture::CAPTURE_PIPELINE_LOCK,
+    contracts::dto::{
+        validate_session_id, HostErrorEnvelope, PrintJobInputDto, PrintJobListDto,
+        PrintJobResultDto,
+    },
+    print::{
+        print_document::render_print_document,
+        print_queue::{resolve_print_queue, PrintQueue, PrintQueueJobState, PrintQueueSubmission},
+    },
+    session::{
+        session_journal::{commit_session_manifest, SessionJournalEvent},
+        session_manifest::{
+            current_timestamp, SessionCaptureRecord, SessionPrintJob,
+            SESSION_PRINT_JOB_SCHEMA_VERSION,
+        },
+        session_paths::SessionPaths,
+        session_repository::read_session_manifest,
+    },
+};
+
+pub const PRINT_TEMPLATE_CONFIG_SCHEMA_VERSION: &str = "print-template-config/v1";
+pub const PRINT_JOB_STATUS_SPOOLED: &str = "spooled";
+pub const PRINT_JOB_STATUS_PRINTED: &str = "printed";
+pub const PRINT_JOB_STATUS_FAILED: &str = "failed";
+pub const MAX_PRINT_COPIES: u32 = 10;
+
+const PRINT_JOB_RESULT_SCHEMA_VERSION: &str = "print-job-result/v1";
+const PRINT_JOB_LIST_SCHEMA_VERSION: &str = "print-job-list/v1";
+
+static PRINT_JOB_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintTemplateSlot {
+    pub x_mm: f64,
+    pub y_mm: f64,
+    pub width_mm: f64,
+    pub height_mm: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintTemplate {
+    pub template_id: String,
+    pub display_name: String,
+    pub paper_width_mm: f64,
+    pub paper_height_mm: f64,
+    pub slots: Vec<PrintTemplateSlot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintTemplateConfig {
+    pub schema_version: String,
+    #[serde(default)]
+    pub templates: Vec<PrintTemplate>,
+}
+
+/// Templates every booth ships with. Slot coordinates are measured from the
+/// top-left corner of the paper.
+pub fn builtin_print_templates() -> Vec<PrintTemplate> {
+    vec![
+        PrintTemplate {
+            template_id: "4x6".into(),
+            display_name: "4x6 한 장".into(),
+            paper_width_mm: 152.4,
+            paper_height_mm: 101.6,
+            slots: vec![slot(0.0, 0.0, 152.4, 101.6)],
+        },
+        PrintTemplate {
+            template_id: "strip".into(),
+            display_name: "2x6 포토 스트립".into(),
+            paper_width_mm: 50.8,
+            paper_height_mm: 152.4,
+            slots: (0..4)
+                .map(|index| slot(3.0, 3.0 + f64::from(index) * 37.35, 44.8, 34.35))
+                .collect(),
+        },
+        PrintTemplate {
+            template_id: "2-up".into(),
+            display_name: "4x6 두 컷".into(),
+            paper_width_mm: 152.4,
+            paper_height_mm: 101.6,
+            slots: vec![slot(4.0, 4.0, 70.2, 93.6), slot(78.2, 4.0, 70.2, 93.6)],
+        },
+    ]
+}
+
+/// Built-in templates merged with `print-config/templates.json`. Branch entries
+/// replace a built-in with the same id; invalid entries are skipped and logged.
+pub fn load_print_templates_in_dir(
+    base_dir: &Path,
+) -> Result<Vec<PrintTemplate>, HostErrorEnvelope> {
+    let mut templates = builtin_print_templates();
+    let config_path = resolve_print_template_config_path(base_dir);
+
+    if !config_path.is_file() {
+        return Ok(templates);
+    }
+
+    let bytes = fs::read_to_string(&config_path).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("인화 템플릿 설정을 읽지 못했어요: {error}"))
+    })?;
+    let config: PrintTemplateConfig = serde_json::from_str(&bytes).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("인화 템플릿 설정을 읽지 못했어요: {error}"))
+    })?;
+
+    for template in config.templates {
+        if let Err(reason) = validate_print_template(&template) {
+            log::warn!(
+                "print_template_skipped template_id={} reason={reason}",
+                template.template_id
+            );
+            continue;
+        }
+
+        match templates
+            .iter_mut()
+            .find(|existing| existing.template_id == template.template_id)
+        {
+            Some(existing) => *existing = template,
+            None => templates.push(template),
+        }
+    }
+
+    Ok(templates)
+}
+
+pub fn resolve_print_template_config_path(base_dir: &Path) -> PathBuf {
+    base_dir.join("print-config").join("templates.json")
+}
+
+/// Lays out the session's finished captures on a template, renders the PDF under
+/// `renders/prints`, and hands it to the print queue. Failures after validation
+/// are recorded on the manifest so the operator sees them in the print boundary.
+pub fn submit_print_job_in_dir(
+    base_dir: &Path,
+    input: PrintJobInputDto,
+) -> Result<PrintJobResultDto, HostErrorEnvelope> {
+    submit_print_job_with_queue(base_dir, input, resolve_print_queue(base_dir).as_ref())
+}
+
+pub fn submit_print_job_with_queue(
+    base_dir: &Path,
+    input: PrintJobInputDto,
+    queue: &dyn PrintQueue,
+) -> Result<PrintJobResultDto, HostErrorEnvelope> {
+    validate_session_id(&input.session_id)?;
+    if !(1..=MAX_PRINT_COPIES).contains(&input.copies) {
+        return Err(HostErrorEnvelope::validation_message(
+            "인화 매수는 1장에서 10장 사이여야 해요.",
+        ));
+    }
+
+    let template = load_print_templates_in_dir(base_dir)?
+        .into_iter()
+        .find(|template| template.template_id == input.template_id)
+        .ok_or_else(|| {
+            HostErrorEnvelope::validation_message("선택한 인화 템플릿을 찾지 못했어요.")
+        })?;
+    let paths = SessionPaths::try_new(base_dir, &input.session_id)?;
+    let manifest = read_session_manifest(&paths.manifest_path)?;
+    let (capture_ids, image_paths) = select_final_assets(&paths, &manifest.captures, &input)?;
+
+    let job_id = generate_print_job_id();
+    let submitted_at = current_timestamp(SystemTime::now())?;
+    let document_path = paths.renders_prints_dir.join(format!("{job_id}.pdf"));
+    let mut job = SessionPrintJob {
+        schema_version: SESSION_PRINT_JOB_SCHEMA_VERSION.into(),
+        job_id: job_id.clone(),
+        template_id: template.template_id.clone(),
+        capture_ids,
+        copies: input.copies,
+        page_count: 0,
+        status: PRINT_JOB_STATUS_SPOOLED.into(),
+        queue_id: queue.queue_id().into(),
+        document_path: None,
+        submitted_at: submitted_at.clone(),
+        updated_at: submitted_at.clone(),
+        detail_code: None,
+    };
+
+    let outcome = render_print_document(&template, &image_paths, &document_path)
+        .map_err(|error| ("print-render-failed", error))
+        .and_then(|page_count| {
+            job.page_count = page_count;
+            job.document_path = Some(document_path.to_string_lossy().into_owned());
+            queue
+                .submit(&PrintQueueSubmission {
+                    job_id: &job_id,
+                    session_id: &input.session_id,
+                    template_id: &template.template_id,
+                    copies: input.copies,
+                    paper_width_mm: template.paper_width_mm,
+                    paper_height_mm: template.paper_height_mm,
+                    document_path: &document_path,
+                    submitted_at: &submitted_at,
+                })
+                .map_err(|error| ("print-spool-failed", error))
+        });
+
+    if let Err((detail_code, _)) = &outcome {
+        job.status = PRINT_JOB_STATUS_FAILED.into();
+        job.detail_code = Some((*detail_code).into());
+    }
+
+    log::info!(
+        "print_job_submitted session={} job={} template={} status={}",
+        input.session_id,
+        job.job_id,
+        job.template_id,
+        job.status
+    );
+    record_print_job(&paths, job.clone())?;
+
+    if let Err((_, error)) = outcome {
+        return Err(error);
+    }
+
+    Ok(PrintJobResultDto {
+        schema_version: PRINT_JOB_RESULT_SCHEMA_VERSION.into(),
+        session_id: input.session_id,
+        job,
+    })
+}
+
+/// Pulls the latest queue outcome for each spooled job and persists any change.
+pub fn refresh_print_jobs_in_dir(
+    base_dir: &Path,
+    session_id: &str,
+) -> Result<PrintJobListDto, HostErrorEnvelope> {
+    refresh_print_jobs_with_queue(base_dir, session_id, resolve_print_queue(base_dir).as_ref())
+}
+
+pub fn refresh_print_jobs_with_queue(
+    base_dir: &Path,
+    session_id: &str,
+    queue: &dyn PrintQueue,
+) -> Result<PrintJobListDto, HostErrorEnvelope> {
+    let paths = SessionPaths::try_new(base_dir, session_id)?;
+    let _pipeline_guard = CAPTURE_PIPELINE_LOCK.lock().map_err(|_| {
+        HostErrorEnvelope::persistence("인화 상태를 잠그지 못했어요. 잠시 후 다시 시도해 주세요.")
+    })?;
+    let mut manifest = read_session_manifest(&paths.manifest_path)?;
+    let now = current_timestamp(SystemTime::now())?;
+    let jobs = project_print_job_states(&manifest.print_jobs, queue, &now);
+
+    if jobs
+        .iter()
+        .zip(manifest.print_jobs.iter())
+        .any(|(projected, stored)| projected.status != stored.status)
+    {
+        manifest.print_jobs = jobs.clone();
+        manifest.updated_at = now;
+        commit_session_manifest(
+            &paths.manifest_path,
+            &manifest,
+            SessionJournalEvent::manifest_updated("print-jobs-synced", &manifest),
+        )?;
+    }
+
+    Ok(PrintJobListDto {
+        schema_version: PRINT_JOB_LIST_SCHEMA_VERSION.into(),
+        session_id: session_id.into(),
+        jobs,
+    })
+}
+
+pub(crate) fn project_print_job_states(
+    jobs: &[SessionPrintJob],
+    queue: &dyn PrintQueue,
+    observed_at: &str,
+) -> Vec<SessionPrintJob> {
+    jobs.iter()
+        .cloned()
+        .map(|mut job| {
+            if job.status != PRINT_JOB_STATUS_SPOOLED || job.queue_id != queue.queue_id() {
+                return job;
+            }
+
+            match queue.job_state(&job.job_id) {
+                PrintQueueJobState::Pending => {}
+                PrintQueueJobState::Printed => {
+                    job.status = PRINT_JOB_STATUS_PRINTED.into();
+                    job.updated_at = observed_at.into();
+                }
+                PrintQueueJobState::Failed(reason) => {
+                    job.status = PRINT_JOB_STATUS_FAILED.into();
+                    job.detail_code = Some(reason.unwrap_or_else(|| "printer-rejected".into()));
+                    job.updated_at = observed_at.into();
+                }
+                PrintQueueJobState::Missing => {
+                    job.status = PRINT_JOB_STATUS_FAILED.into();
+                    job.detail_code = Some("spool-ticket-missing".into());
+                    job.updated_at = observed_at.into();
+                }
+            }
+
+            job
+        })
+        .collect()
+}
+
+fn select_final_assets(
+    paths: &SessionPaths,
+    captures: &[SessionCaptureRecord],
+    input: &PrintJobInputDto,
+) -> Result<(Vec<String>, Vec<PathBuf>), HostErrorEnvelope> {
+    let selected: Vec<_> = if input.capture_ids.is_empty() {
+        captures
+            .iter()
+            .filter(|capture| capture.final_asset.asset_path.is_some())
+            .collect()
+    } else {
+        input
+            .capture_ids
+            .iter()
+            .map(|capture_id| {
+                captures
+                    .iter()
+                    .find(|capture| &capture.capture_id == capture_id)
+                    .ok_or_else(|| {
+                        HostErrorEnvelope::validation_message(
+                            "인화할 사진을 이 세션에서 찾지 못했어요.",
+                        )
+                    })
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    if selected.is_empty() {
+        return Err(HostErrorEnvelope::validation_message(
+            "아직 인화할 수 있는 완성 사진이 없어요.",
+        ));
+    }
+
+    let mut capture_ids = Vec::with_capacity(selected.len());
+    let mut image_paths = Vec::with_capacity(selected.len());
+    for capture in selected {
+        // Print documents embed JPEG only, so a TIFF print variant is left
+        // for external labs.
+        let asset_path = capture
+            .final_asset
+            .variant_for("print", &["jpeg"])
+            .map(|variant| variant.asset_path.as_str())
+            .or(capture.final_asset.asset_path.as_deref())
+            .map(PathBuf::from)
+            .filter(|path| path.starts_with(&paths.renders_finals_dir) && path.is_file())
+            .ok_or_else(|| {
+                HostErrorEnvelope::validation_message(
+                    "완성 사진이 준비된 뒤에 인화를 요청해 주세요.",
+                )
+            })?;
+        capture_ids.push(capture.capture_id.clone());
+        image_paths.push(asset_path);
+    }
+
+    Ok((capture_ids, image_paths))
+}
+
+fn record_print_job(paths: &SessionPaths, job: SessionPrintJob) -> Result<(), HostErrorEnvelope> {
+    let _pipeline_guard = CAPTURE_PIPELINE_LOCK.lock().map_err(|_| {
+        HostErrorEnvelope::persistence("인화 상태를 잠그지 못했어요. 잠시 후 다시 시도해 주세요.")
+    })?;
+    let mut manifest = read_session_manifest(&paths.manifest_path)?;
+    manifest.updated_at = job.updated_at.clone();
+    manifest.print_jobs.push(job);
+
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::manifest_updated("print-job-recorded", &manifest),
+    )
+}
+
+fn validate_print_template(template: &PrintTemplate) -> Result<(), &'static str> {
+    if template.template_id.trim().is_empty() {
+        return Err("empty-template-id");
+    }
+    if !(template.paper_width_mm > 0.0 && template.paper_height_mm > 0.0) {
+        return Err("invalid-paper-size");
+    }
+    if template.slots.is_empty() {
+        return Err("no-slots");
+    }
+
+    let fits_paper = template.slots.iter().all(|slot| {
+        slot.x_mm >= 0.0
+            && slot.y_mm >= 0.0
+            && slot.width_mm > 0.0
+            && slot.height_mm > 0.0
+            && slot.x_mm + slot.width_mm <= template.paper_width_mm + f64::EPSILON
+            && slot.y_mm + slot.height_mm <= template.paper_height_mm + f64::EPSILON
+    });
+    if !fits_paper {
+        return Err("slot-outside-paper");
+    }
+
+    Ok(())
+}
+
+fn slot(x_mm: f64, y_mm: f64, width_mm: f64, height_mm: f64) -> PrintTemplateSlot {
+    PrintTemplateSlot {
+        x_mm,
+        y_mm,
+        width_mm,
+        height_mm,
+    }
+}
+
+fn generate_print_job_id() -> String {
+    let unix_nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let counter = PRINT_JOB_COUNTER.fetch_add(1, Ordering::Relaxed) as u128;
+    let value = unix_nanos ^ (counter << 16);
+
+    format!("print_{value:026x}")
+}
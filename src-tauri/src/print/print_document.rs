@@ -0,0 +1,276 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::{contracts::dto::HostErrorEnvelope, print::PrintTemplate};
+
+const POINTS_PER_MM: f64 = 72.0 / 25.4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JpegDimensions {
+    pub width: u32,
+    pub height: u32,
+    pub components: u8,
+}
+
+struct EmbeddedImage {
+    bytes: Vec<u8>,
+    dimensions: JpegDimensions,
+}
+
+/// Reads the frame header of a baseline or progressive JPEG without decoding it.
+pub fn read_jpeg_dimensions(bytes: &[u8]) -> Option<JpegDimensions> {
+    if bytes.len() < 4 || bytes[0] != 0xFF || bytes[1] != 0xD8 {
+        return None;
+    }
+
+    let mut index = 2;
+    while index + 3 < bytes.len() {
+        if bytes[index] != 0xFF {
+            return None;
+        }
+
+        let marker = bytes[index + 1];
+        if marker == 0xFF {
+            index += 1;
+            continue;
+        }
+        if marker == 0x01 || (0xD0..=0xD7).contains(&marker) {
+            index += 2;
+            continue;
+        }
+        if marker == 0xD9 || marker == 0xDA {
+            return None;
+        }
+
+        let segment_length = u16::from_be_bytes([bytes[index + 2], bytes[index + 3]]) as usize;
+        if segment_length < 2 {
+            return None;
+        }
+
+        let is_frame_header =
+            matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC);
+        if is_frame_header {
+            let frame = bytes.get(index + 4..index + 2 + segment_length)?;
+            if frame.len() < 6 {
+                return None;
+            }
+
+            let height = u16::from_be_bytes([frame[1], frame[2]]) as u32;
+            let width = u16::from_be_bytes([frame[3], frame[4]]) as u32;
+            if width == 0 || height == 0 {
+                return None;
+            }
+
+            return Some(JpegDimensions {
+                width,
+                height,
+                components: frame[5],
+            });
+        }
+
+        index += 2 + segment_length;
+    }
+
+    None
+}
+
+/// Lays the images out on the template slots in order, adding pages until every
+/// image is placed, and writes a PDF that embeds the JPEG bytes untouched.
+/// Returns the number of pages written.
+pub(crate) fn render_print_document(
+    template: &PrintTemplate,
+    image_paths: &[PathBuf],
+    output_path: &Path,
+) -> Result<u32, HostErrorEnvelope> {
+    if image_paths.is_empty() || template.slots.is_empty() {
+        return Err(HostErrorEnvelope::validation_message(
+            "인화할 사진이나 배치 칸이 비어 있어요.",
+        ));
+    }
+
+    let mut image_indices = HashMap::new();
+    let mut images = Vec::new();
+    let mut placements = Vec::with_capacity(image_paths.len());
+    for path in image_paths {
+        let image_index = match image_indices.get(path) {
+            Some(image_index) => *image_index,
+            None => {
+                images.push(load_embedded_image(path)?);
+                image_indices.insert(path.clone(), images.len() - 1);
+                images.len() - 1
+            }
+        };
+        placements.push(image_index);
+    }
+
+    let page_count = placements.len().div_ceil(template.slots.len());
+    let page_width = template.paper_width_mm * POINTS_PER_MM;
+    let page_height = template.paper_height_mm * POINTS_PER_MM;
+
+    // Object layout: 1 catalog, 2 page tree, then images, then a page/content pair per page.
+    let first_image_object = 3;
+    let first_page_object = first_image_object + images.len();
+    let page_object_ids: Vec<usize> = (0..page_count)
+        .map(|page| first_page_object + page * 2)
+        .collect();
+
+    let mut writer = PdfWriter::new();
+    writer.write_object(1, b"<< /Type /Catalog /Pages 2 0 R >>");
+    let kids = page_object_ids
+        .iter()
+        .map(|id| format!("{id} 0 R"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    writer.write_object(
+        2,
+        format!("<< /Type /Pages /Kids [{kids}] /Count {page_count} >>").as_bytes(),
+    );
+
+    for (offset, image) in images.iter().enumerate() {
+        let color_space = match image.dimensions.components {
+            1 => "/DeviceGray",
+            // Adobe-tagged CMYK JPEGs store inverted channels.
+            4 => "/DeviceCMYK /Decode [1 0 1 0 1 0 1 0]",
+            _ => "/DeviceRGB",
+        };
+        let dictionary = format!(
+            "<< /Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace {color_space} /BitsPerComponent 8 /Filter /DCTDecode /Length {} >>",
+            image.dimensions.width,
+            image.dimensions.height,
+            image.bytes.len()
+        );
+        writer.write_stream(first_image_object + offset, &dictionary, &image.bytes);
+    }
+
+    let image_resources = (0..images.len())
+        .map(|offset| format!("/Im{offset} {} 0 R", first_image_object + offset))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    for (page, page_object_id) in page_object_ids.iter().enumerate() {
+        let content_object_id = page_object_id + 1;
+        let mut content = String::new();
+
+        for (slot, image_index) in template
+            .slots
+            .iter()
+            .zip(placements.iter().skip(page * template.slots.len()))
+        {
+            let dimensions = images[*image_index].dimensions;
+            let slot_x = slot.x_mm * POINTS_PER_MM;
+            let slot_width = slot.width_mm * POINTS_PER_MM;
+            let slot_height = slot.height_mm * POINTS_PER_MM;
+            let slot_y = page_height - slot.y_mm * POINTS_PER_MM - slot_height;
+            let scale =
+                (slot_width / dimensions.width as f64).max(slot_height / dimensions.height as f64);
+            let draw_width = dimensions.width as f64 * scale;
+            let draw_height = dimensions.height as f64 * scale;
+            let draw_x = slot_x + (slot_width - draw_width) / 2.0;
+            let draw_y = slot_y + (slot_height - draw_height) / 2.0;
+
+            content.push_str(&format!(
+                "q {slot_x:.2} {slot_y:.2} {slot_width:.2} {slot_height:.2} re W n {draw_width:.2} 0 0 {draw_height:.2} {draw_x:.2} {draw_y:.2} cm /Im{image_index} Do Q\n"
+            ));
+        }
+
+        writer.write_object(
+            *page_object_id,
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {page_width:.2} {page_height:.2}] /Resources << /XObject << {image_resources} >> >> /Contents {content_object_id} 0 R >>"
+            )
+            .as_bytes(),
+        );
+        writer.write_stream(
+            content_object_id,
+            &format!("<< /Length {} >>", content.len()),
+            content.as_bytes(),
+        );
+    }
+
+    let document = writer.finish();
+    write_document_atomically(output_path, &document)?;
+
+    Ok(page_count as u32)
+}
+
+fn load_embedded_image(path: &Path) -> Result<EmbeddedImage, HostErrorEnvelope> {
+    let bytes = fs::read(path).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("인화할 사진을 읽지 못했어요: {error}"))
+    })?;
+    let dimensions = read_jpeg_dimensions(&bytes).ok_or_else(|| {
+        HostErrorEnvelope::validation_message("인화할 사진이 올바른 JPEG 파일이 아니에요.")
+    })?;
+
+    Ok(EmbeddedImage { bytes, dimensions })
+}
+
+fn write_document_atomically(path: &Path, bytes: &[u8]) -> Result<(), HostErrorEnvelope> {
+    let map_error = |error: std::io::Error| {
+        HostErrorEnvelope::persistence(format!("인화 문서를 저장하지 못했어요: {error}"))
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(map_error)?;
+    }
+
+    let temp_path = path.with_extension("pdf.tmp");
+    fs::write(&temp_path, bytes).map_err(map_error)?;
+    if let Err(error) = fs::rename(&temp_path, path) {
+        let _ = fs::remove_file(&temp_path);
+
+        return Err(map_error(error));
+    }
+
+    Ok(())
+}
+
+struct PdfWriter {
+    buffer: Vec<u8>,
+    offsets: Vec<(usize, usize)>,
+}
+
+impl PdfWriter {
+    fn new() -> Self {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
+
+        Self {
+            buffer,
+            offsets: Vec::new(),
+        }
+    }
+
+    fn write_object(&mut self, object_id: usize, body: &[u8]) {
+        self.offsets.push((object_id, self.buffer.len()));
+        let _ = writeln!(self.buffer, "{object_id} 0 obj");
+        self.buffer.extend_from_slice(body);
+        self.buffer.extend_from_slice(b"\nendobj\n");
+    }
+
+    fn write_stream(&mut self, object_id: usize, dictionary: &str, data: &[u8]) {
+        self.offsets.push((object_id, self.buffer.len()));
+        let _ = writeln!(self.buffer, "{object_id} 0 obj\n{dictionary}\nstream");
+        self.buffer.extend_from_slice(data);
+        self.buffer.extend_from_slice(b"\nendstream\nendobj\n");
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        self.offsets.sort_by_key(|(object_id, _)| *object_id);
+        let xref_offset = self.buffer.len();
+        let object_count = self.offsets.len() + 1;
+
+        let _ = writeln!(self.buffer, "xref\n0 {object_count}\n0000000000 65535 f ");
+        for (_, offset) in &self.offsets {
+            let _ = writeln!(self.buffer, "{offset:010} 00000 n ");
+        }
+        let _ = writeln!(
+            self.buffer,
+            "trailer\n<< /Size {object_count} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF"
+        );
+
+        self.buffer
+    }
+}
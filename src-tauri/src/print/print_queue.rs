@@ -0,0 +1,164 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::contracts::dto::HostErrorEnvelope;
+
+pub const PRINT_SPOOL_DIR_ENV: &str = "BOOTHY_PRINT_SPOOL_DIR";
+pub const SPOOL_DIRECTORY_PRINT_QUEUE_ID: &str = "spool-directory";
+pub const PRINT_SPOOL_TICKET_SCHEMA_VERSION: &str = "print-spool-ticket/v1";
+
+#[derive(Debug, Clone)]
+pub struct PrintQueueSubmission<'a> {
+    pub job_id: &'a str,
+    pub session_id: &'a str,
+    pub template_id: &'a str,
+    pub copies: u32,
+    pub paper_width_mm: f64,
+    pub paper_height_mm: f64,
+    pub document_path: &'a Path,
+    pub submitted_at: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrintQueueJobState {
+    Pending,
+    Printed,
+    Failed(Option<String>),
+    Missing,
+}
+
+/// Host-side contract for whatever actually drives the printer. The booth only
+/// hands over a finished document and later polls for the outcome.
+pub trait PrintQueue: Send + Sync {
+    fn queue_id(&self) -> &'static str;
+
+    fn submit(&self, submission: &PrintQueueSubmission<'_>) -> Result<(), HostErrorEnvelope>;
+
+    fn job_state(&self, job_id: &str) -> PrintQueueJobState;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintSpoolTicket {
+    pub schema_version: String,
+    pub job_id: String,
+    pub session_id: String,
+    pub template_id: String,
+    pub copies: u32,
+    pub paper_width_mm: f64,
+    pub paper_height_mm: f64,
+    pub document_file: String,
+    pub submitted_at: String,
+}
+
+/// Drops `<job_id>.pdf` plus a `<job_id>.json` ticket into a folder watched by
+/// the printer driver or vendor print tool. The ticket is written last, so a
+/// watcher that keys on tickets never sees a half-copied document. The watcher
+/// reports back by writing `<job_id>.done` or `<job_id>.failed` (reason inside).
+#[derive(Debug, Clone)]
+pub struct SpoolDirectoryPrintQueue {
+    spool_dir: PathBuf,
+}
+
+impl SpoolDirectoryPrintQueue {
+    pub fn new(spool_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            spool_dir: spool_dir.into(),
+        }
+    }
+
+    pub fn spool_dir(&self) -> &Path {
+        &self.spool_dir
+    }
+}
+
+impl PrintQueue for SpoolDirectoryPrintQueue {
+    fn queue_id(&self) -> &'static str {
+        SPOOL_DIRECTORY_PRINT_QUEUE_ID
+    }
+
+    fn submit(&self, submission: &PrintQueueSubmission<'_>) -> Result<(), HostErrorEnvelope> {
+        fs::create_dir_all(&self.spool_dir).map_err(map_spool_error)?;
+
+        let document_file = format!("{}.pdf", submission.job_id);
+        let document_temp_path = self.spool_dir.join(format!("{document_file}.tmp"));
+        fs::copy(submission.document_path, &document_temp_path).map_err(map_spool_error)?;
+        rename_into_place(&document_temp_path, &self.spool_dir.join(&document_file))?;
+
+        let ticket = PrintSpoolTicket {
+            schema_version: PRINT_SPOOL_TICKET_SCHEMA_VERSION.into(),
+            job_id: submission.job_id.into(),
+            session_id: submission.session_id.into(),
+            template_id: submission.template_id.into(),
+            copies: submission.copies,
+            paper_width_mm: submission.paper_width_mm,
+            paper_height_mm: submission.paper_height_mm,
+            document_file,
+            submitted_at: submission.submitted_at.into(),
+        };
+        let ticket_bytes = serde_json::to_vec_pretty(&ticket).map_err(|error| {
+            HostErrorEnvelope::persistence(format!("인화 요청서를 직렬화하지 못했어요: {error}"))
+        })?;
+        let ticket_temp_path = self
+            .spool_dir
+            .join(format!("{}.json.tmp", submission.job_id));
+        fs::write(&ticket_temp_path, ticket_bytes).map_err(map_spool_error)?;
+        rename_into_place(
+            &ticket_temp_path,
+            &self.spool_dir.join(format!("{}.json", submission.job_id)),
+        )
+    }
+
+    fn job_state(&self, job_id: &str) -> PrintQueueJobState {
+        if self.spool_dir.join(format!("{job_id}.done")).is_file() {
+            return PrintQueueJobState::Printed;
+        }
+
+        let failed_path = self.spool_dir.join(format!("{job_id}.failed"));
+        if failed_path.is_file() {
+            let reason = fs::read_to_string(&failed_path)
+                .ok()
+                .map(|reason| reason.trim().to_string())
+                .filter(|reason| !reason.is_empty());
+
+            return PrintQueueJobState::Failed(reason);
+        }
+
+        if self.spool_dir.join(format!("{job_id}.json")).is_file() {
+            return PrintQueueJobState::Pending;
+        }
+
+        PrintQueueJobState::Missing
+    }
+}
+
+pub fn resolve_print_spool_dir(base_dir: &Path) -> PathBuf {
+    env::var_os(PRINT_SPOOL_DIR_ENV)
+        .map(PathBuf::from)
+        .filter(|path| !path.as_os_str().is_empty())
+        .unwrap_or_else(|| base_dir.join("print-spool"))
+}
+
+pub fn resolve_print_queue(base_dir: &Path) -> Box<dyn PrintQueue> {
+    Box::new(SpoolDirectoryPrintQueue::new(resolve_print_spool_dir(
+        base_dir,
+    )))
+}
+
+fn rename_into_place(temp_path: &Path, path: &Path) -> Result<(), HostErrorEnvelope> {
+    if let Err(error) = fs::rename(temp_path, path) {
+        let _ = fs::remove_file(temp_path);
+
+        return Err(map_spool_error(error));
+    }
+
+    Ok(())
+}
+
+fn map_spool_error(error: std::io::Error) -> HostErrorEnvelope {
+    HostErrorEnvelope::persistence(format!("인화 대기열에 작업을 넣지 못했어요: {error}"))
+}
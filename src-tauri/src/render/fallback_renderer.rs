@@ -0,0 +1,193 @@
+use crate::preset::preset_bundle::PublishedPresetFallbackLook;
+
+use super::jpeg_codec::{decode_jpeg, downscale_to_fit, read_jpeg_frame_info, RgbImage};
+
+const MAX_EMBEDDED_PREVIEW_CANDIDATES: usize = 64;
+const MIN_EMBEDDED_PREVIEW_EDGE_PX: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FallbackSourceKind {
+    Jpeg,
+    EmbeddedPreview,
+}
+
+impl FallbackSourceKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            FallbackSourceKind::Jpeg => "jpeg",
+            FallbackSourceKind::EmbeddedPreview => "embedded-preview",
+        }
+    }
+}
+
+/// Decodes the source as a JPEG, or pulls the largest baseline JPEG preview a
+/// camera embedded in the RAW container. When `max_size` is set the result is
+/// fitted inside it, decoding at 1/8 scale when that still covers the target.
+pub fn load_fallback_source_image(
+    bytes: &[u8],
+    max_size: Option<(usize, usize)>,
+) -> Result<(RgbImage, FallbackSourceKind), String> {
+    let mut candidates = Vec::new();
+    let mut offset = 0;
+    while candidates.len() < MAX_EMBEDDED_PREVIEW_CANDIDATES {
+        let Some(found) = bytes[offset..]
+            .windows(3)
+            .position(|window| window == [0xFF, 0xD8, 0xFF])
+        else {
+            break;
+        };
+        let start = offset + found;
+        if let Some(info) = read_jpeg_frame_info(&bytes[start..]) {
+            if info.baseline
+                && info.width >= MIN_EMBEDDED_PREVIEW_EDGE_PX
+                && info.height >= MIN_EMBEDDED_PREVIEW_EDGE_PX
+            {
+                candidates.push((start, info));
+            }
+        }
+        offset = start + 3;
+    }
+
+    if candidates.is_empty() {
+        return Err("baseline JPEG 또는 내장 미리보기를 찾지 못했어요.".into());
+    }
+    candidates.sort_by_key(|(_, info)| std::cmp::Reverse(info.width * info.height));
+
+    let mut last_error = String::new();
+    for (start, info) in candidates {
+        let scale = match max_size {
+            Some((max_width, max_height)) => {
+                let (fitted_width, fitted_height) =
+                    fitted_size(info.width, info.height, max_width, max_height);
+                if info.width / 8 >= fitted_width && info.height / 8 >= fitted_height {
+                    8
+                } else {
+                    1
+                }
+            }
+            None => 1,
+        };
+
+        match decode_jpeg(&bytes[start..], scale) {
+            Ok(image) => {
+                let image = match max_size {
+                    Some((max_width, max_height)) => downscale_to_fit(image, max_width, max_height),
+                    None => image,
+                };
+                let kind = if start == 0 {
+                    FallbackSourceKind::Jpeg
+                } else {
+                    FallbackSourceKind::EmbeddedPreview
+                };
+
+                return Ok((image, kind));
+            }
+            Err(error) => last_error = error,
+        }
+    }
+
+    Err(format!("미리보기 JPEG를 해석하지 못했어요: {last_error}"))
+}
+
+/// Applies exposure and white balance in linear light, then the tone curve on
+/// display values, and finally collapses to Rec. 709 luma for monochrome.
+pub fn apply_fallback_look(mut image: RgbImage, look: &PublishedPresetFallbackLook) -> RgbImage {
+    let exposure_gain = 2f32.powf(look.exposure_ev);
+    let gains = [
+        look.white_balance.red,
+        look.white_balance.green,
+        look.white_balance.blue,
+    ];
+    let lookup_tables = gains.map(|gain| {
+        let mut table = [0u8; 256];
+        for (value, entry) in table.iter_mut().enumerate() {
+            let linear = srgb_to_linear(value as f32 / 255.0) * gain * exposure_gain;
+            let display = linear_to_srgb(linear.clamp(0.0, 1.0));
+            *entry = (evaluate_tone_curve(&look.tone_curve, display) * 255.0)
+                .round()
+                .clamp(0.0, 255.0) as u8;
+        }
+        table
+    });
+
+    for pixel in image.pixels.chunks_exact_mut(3) {
+        let red = lookup_tables[0][pixel[0] as usize];
+        let green = lookup_tables[1][pixel[1] as usize];
+        let blue = lookup_tables[2][pixel[2] as usize];
+
+        if look.monochrome {
+            let luma = (0.2126 * red as f32 + 0.7152 * green as f32 + 0.0722 * blue as f32)
+                .round()
+                .clamp(0.0, 255.0) as u8;
+            pixel.copy_from_slice(&[luma, luma, luma]);
+        } else {
+            pixel.copy_from_slice(&[red, green, blue]);
+        }
+    }
+
+    image
+}
+
+/// Short `key=value` list describing what the fallback applied, for render
+/// events and diagnostics.
+pub fn describe_fallback_look(look: &PublishedPresetFallbackLook) -> Vec<String> {
+    vec![
+        format!("exposureEv={}", look.exposure_ev),
+        format!(
+            "whiteBalance={}/{}/{}",
+            look.white_balance.red, look.white_balance.green, look.white_balance.blue
+        ),
+        format!("toneCurvePoints={}", look.tone_curve.len()),
+        format!("monochrome={}", look.monochrome),
+    ]
+}
+
+fn fitted_size(width: usize, height: usize, max_width: usize, max_height: usize) -> (usize, usize) {
+    if width <= max_width && height <= max_height {
+        return (width, height);
+    }
+
+    let ratio = (max_width as f64 / width as f64).min(max_height as f64 / height as f64);
+    (
+        ((width as f64 * ratio).round() as usize).max(1),
+        ((height as f64 * ratio).round() as usize).max(1),
+    )
+}
+
+fn evaluate_tone_curve(points: &[[f32; 2]], value: f32) -> f32 {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return value;
+    };
+    if value <= first[0] {
+        return first[1];
+    }
+    if value >= last[0] {
+        return last[1];
+    }
+
+    points
+        .windows(2)
+        .find(|pair| value <= pair[1][0])
+        .map(|pair| {
+            let [x0, y0] = pair[0];
+            let [x1, y1] = pair[1];
+            y0 + (y1 - y0) * (value - x0) / (x1 - x0)
+        })
+        .unwrap_or(value)
+}
+
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.040_45 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
@@ -0,0 +1,150 @@
+//! JPEG decode/encode for the built-in fallback renderer, backed by the
+//! `jpeg-decoder` and `jpeg-encoder` crates. Decoded images are normalized to
+//! interleaved RGB and written back as 4:4:4 baseline JPEGs.
+
+use jpeg_decoder::{CodingProcess, PixelFormat};
+use jpeg_encoder::{ColorType, Encoder, SamplingFactor};
+
+const MAX_DECODED_DIMENSION: usize = 16_384;
+
+/// Interleaved 8-bit RGB pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbImage {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JpegFrameInfo {
+    pub width: usize,
+    pub height: usize,
+    pub baseline: bool,
+}
+
+/// Reads the headers up to the frame without touching scan data, so
+/// candidate previews inside a RAW file can be ranked cheaply.
+pub fn read_jpeg_frame_info(bytes: &[u8]) -> Option<JpegFrameInfo> {
+    if !bytes.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+
+    let mut decoder = jpeg_decoder::Decoder::new(bytes);
+    decoder.read_info().ok()?;
+    let info = decoder.info()?;
+
+    Some(JpegFrameInfo {
+        width: info.width as usize,
+        height: info.height as usize,
+        baseline: info.coding_process == CodingProcess::DctSequential
+            && info.pixel_format != PixelFormat::L16,
+    })
+}
+
+/// Decodes a JPEG. `scale` may be 1 (full size) or 8, which uses the
+/// decoder's reduced IDCT for a cheap 1/8 size image.
+pub fn decode_jpeg(bytes: &[u8], scale: usize) -> Result<RgbImage, String> {
+    if scale != 1 && scale != 8 {
+        return Err(format!("unsupported decode scale {scale}"));
+    }
+    if !bytes.starts_with(&[0xFF, 0xD8]) {
+        return Err("missing SOI marker".into());
+    }
+
+    let mut decoder = jpeg_decoder::Decoder::new(bytes);
+    decoder.read_info().map_err(|error| error.to_string())?;
+    let info = decoder.info().ok_or("JPEG has no frame header")?;
+    let (frame_width, frame_height) = (info.width as usize, info.height as usize);
+    if frame_width > MAX_DECODED_DIMENSION || frame_height > MAX_DECODED_DIMENSION {
+        return Err(format!(
+            "JPEG frame {frame_width}x{frame_height} exceeds the decode limit"
+        ));
+    }
+    if scale == 8 {
+        decoder
+            .scale(
+                frame_width.div_ceil(8) as u16,
+                frame_height.div_ceil(8) as u16,
+            )
+            .map_err(|error| error.to_string())?;
+    }
+
+    let samples = decoder.decode().map_err(|error| error.to_string())?;
+    let info = decoder.info().ok_or("JPEG has no frame header")?;
+    let (width, height) = (info.width as usize, info.height as usize);
+    let pixels = match info.pixel_format {
+        PixelFormat::RGB24 => samples,
+        PixelFormat::L8 => samples.iter().flat_map(|&luma| [luma; 3]).collect(),
+        PixelFormat::L16 => samples
+            .chunks_exact(2)
+            .flat_map(|luma| [luma[0]; 3])
+            .collect(),
+        PixelFormat::CMYK32 => return Err("CMYK JPEG is not supported".into()),
+    };
+    if pixels.len() != width * height * 3 {
+        return Err("decoded JPEG has an unexpected sample count".into());
+    }
+
+    Ok(RgbImage {
+        width,
+        height,
+        pixels,
+    })
+}
+
+/// Writes a 4:4:4 baseline JPEG. `quality` follows the libjpeg 1..=100 scale.
+pub fn encode_jpeg(image: &RgbImage, quality: u8) -> Result<Vec<u8>, String> {
+    let width = u16::try_from(image.width)
+        .map_err(|_| format!("image width {} is too large for JPEG", image.width))?;
+    let height = u16::try_from(image.height)
+        .map_err(|_| format!("image height {} is too large for JPEG", image.height))?;
+    let mut output = Vec::new();
+    let mut encoder = Encoder::new(&mut output, quality.clamp(1, 100));
+    encoder.set_sampling_factor(SamplingFactor::R_4_4_4);
+    encoder
+        .encode(&image.pixels, width, height, ColorType::Rgb)
+        .map_err(|error| error.to_string())?;
+
+    Ok(output)
+}
+
+/// Area-averages `image` so it fits inside `max_width` x `max_height`. Images
+/// that already fit are returned unchanged; nothing is ever upscaled.
+pub fn downscale_to_fit(image: RgbImage, max_width: usize, max_height: usize) -> RgbImage {
+    if image.width <= max_width && image.height <= max_height {
+        return image;
+    }
+
+    let ratio =
+        (max_width as f64 / image.width as f64).min(max_height as f64 / image.height as f64);
+    let width = ((image.width as f64 * ratio).round() as usize).max(1);
+    let height = ((image.height as f64 * ratio).round() as usize).max(1);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+
+    for y in 0..height {
+        let source_top = y * image.height / height;
+        let source_bottom = ((y + 1) * image.height / height).max(source_top + 1);
+        for x in 0..width {
+            let source_left = x * image.width / width;
+            let source_right = ((x + 1) * image.width / width).max(source_left + 1);
+            let mut sums = [0u64; 3];
+            for source_y in source_top..source_bottom {
+                let row = source_y * image.width;
+                for source_x in source_left..source_right {
+                    let offset = (row + source_x) * 3;
+                    sums[0] += image.pixels[offset] as u64;
+                    sums[1] += image.pixels[offset + 1] as u64;
+                    sums[2] += image.pixels[offset + 2] as u64;
+                }
+            }
+            let count = ((source_bottom - source_top) * (source_right - source_left)) as u64;
+            pixels.extend(sums.iter().map(|sum| ((sum + count / 2) / count) as u8));
+        }
+    }
+
+    RgbImage {
+        width,
+        height,
+        pixels,
+    }
+}
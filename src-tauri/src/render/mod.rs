@@ -1,3 +1,11 @@
+mod fallback_renderer;
+mod jpeg_codec;
+pub mod overlay_compositor;
+pub mod png_codec;
+pub mod render_backend;
+pub mod render_job_queue;
+pub mod shot_layout_composer;
+
 use std::{
     collections::HashSet,
     fs::{self, OpenOptions},
@@ -10,11 +18,28 @@ use std::{
 };
 
 use crate::{
-    preset::preset_bundle::PublishedPresetRuntimeBundle,
+    branch_config::{
+        load_branch_display_name_in_dir,
+        operational_toggles::{
+            resolve_booth_operational_toggles_in_dir, RENDER_FINAL_JPEG_QUALITY,
+        },
+    },
+    preset::preset_bundle::{
+        OutputVariantFormat, PublishedPresetOutputVariant, PublishedPresetRuntimeBundle,
+    },
     preset::preset_catalog::{
         find_published_preset_runtime_bundle, resolve_published_preset_catalog_dir,
     },
-    session::{session_manifest::SessionCaptureRecord, session_paths::SessionPaths},
+    session::{
+        session_manifest::SessionCaptureRecord, session_paths::SessionPaths,
+        session_repository::read_session_manifest,
+    },
+};
+
+use overlay_compositor::{composite_overlay_layout, OverlayTextContext};
+use render_backend::{
+    render_with_backend_policy, resolve_render_backend_policy, RenderBackendOutput,
+    RenderBackendPolicy, RenderJob,
 };
 
 const PINNED_DARKTABLE_VERSION: &str = "5.4.1";
@@ -26,6 +51,9 @@ const RAW_PREVIEW_MAX_HEIGHT_PX: u32 = 384;
 const FAST_PREVIEW_RENDER_MAX_WIDTH_PX: u32 = 384;
 const FAST_PREVIEW_RENDER_MAX_HEIGHT_PX: u32 = 384;
 const DARKTABLE_APPLY_CUSTOM_PRESETS_DISABLED: &str = "false";
+const OVERLAY_PREVIEW_JPEG_QUALITY: u8 = 85;
+const OVERLAY_FINAL_JPEG_QUALITY: u8 = 92;
+const OVERLAY_FINAL_HIGH_JPEG_QUALITY: u8 = 97;
 const PREVIEW_RENDER_WARMUP_INPUT_PNG: &[u8] = &[
     0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52,
     0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1F, 0x15, 0xC4,
@@ -48,6 +76,7 @@ pub enum RenderIntent {
 pub struct RenderedCaptureAsset {
     pub asset_path: String,
     pub ready_at_ms: u64,
+    pub render_backend: &'static str,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -58,11 +87,21 @@ pub struct RenderWorkerError {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum PreviewRenderSourceKind {
+pub enum PreviewRenderSourceKind {
     RawOriginal,
     FastPreviewRaster,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedOutputVariant {
+    pub variant_id: String,
+    pub asset_path: String,
+    pub format: &'static str,
+    pub color_space: String,
+    pub purposes: Vec<String>,
+    pub ready_at_ms: u64,
+}
+
 pub struct PreparedPreviewRender {
     pub detail: String,
 }
@@ -138,31 +177,46 @@ fn render_capture_asset_with_forced_source_in_dir(
 
     let _ = fs::remove_file(&staging_output_path);
 
-    let invocation = build_darktable_invocation(
+    let render_source = resolve_preview_render_source(capture, &paths, intent, forced_source_kind);
+    let job = RenderJob {
         base_dir,
-        &bundle.darktable_version,
-        &bundle.xmp_template_path,
-        capture,
-        &paths,
-        &staging_output_path,
+        bundle: &bundle,
+        source_asset_path: Path::new(&render_source.asset_path),
+        source_kind: render_source.kind,
+        output_path: &staging_output_path,
         intent,
-        forced_source_kind,
-    );
+        variant: None,
+    };
     log::info!(
-        "render_job_started session={} capture_id={} stage={} binary={} source={} detail={}",
+        "render_job_started session={} capture_id={} stage={} detail={}",
         session_id,
         capture.capture_id,
         render_stage_label(intent),
-        invocation.binary,
-        invocation.binary_source,
-        render_invocation_detail_with_source(intent, Some(invocation.render_source_kind))
+        render_invocation_detail_with_source(intent, Some(render_source.kind))
     );
     let render_started = Instant::now();
-    let invocation_result = run_darktable_invocation(&invocation, intent)?;
+    let output = render_with_backend_policy(&job, resolve_render_backend_policy())?;
     if let Err(error) = validate_render_output(&staging_output_path, intent) {
         let _ = fs::remove_file(&staging_output_path);
         return Err(error);
     }
+    let overlay_context =
+        build_overlay_text_context(base_dir, &capture.booth_alias, capture.raw.persisted_at_ms);
+    if let Err(detail) = apply_overlay_layout(
+        &bundle,
+        &staging_output_path,
+        intent,
+        &overlay_context,
+        overlay_jpeg_quality(base_dir, intent),
+    ) {
+        log_overlay_skipped(
+            &paths,
+            &capture.capture_id,
+            Some(&capture.request_id),
+            intent,
+            &detail,
+        );
+    }
     if let Err(error) = promote_render_output(&staging_output_path, &output_path, intent) {
         let _ = fs::remove_file(&staging_output_path);
         return Err(error);
@@ -181,26 +235,180 @@ fn render_capture_asset_with_forced_source_in_dir(
         Some(&capture.request_id),
         intent,
         render_ready_event_name(intent),
-        Some(match intent {
-            RenderIntent::Preview => "preview-ready",
-            RenderIntent::Final => "final-ready",
+        Some(match (intent, output.is_degraded()) {
+            (RenderIntent::Preview, false) => "preview-ready",
+            (RenderIntent::Final, false) => "final-ready",
+            (RenderIntent::Preview, true) => "preview-ready-degraded",
+            (RenderIntent::Final, true) => "final-ready-degraded",
         }),
-        Some(&format!(
-            "presetId={};publishedVersion={};binary={};source={};elapsedMs={};detail={};args={};status={}",
-            bundle.preset_id,
-            bundle.published_version,
-            invocation.binary,
-            invocation.binary_source,
+        Some(&render_ready_detail(
+            &bundle,
+            &output,
             render_elapsed_ms,
-            render_invocation_detail_with_source(intent, Some(invocation.render_source_kind)),
-            invocation.arguments.join(" "),
-            invocation_result.exit_code
+            &render_invocation_detail_with_source(intent, Some(render_source.kind)),
         )),
     );
 
     Ok(RenderedCaptureAsset {
         asset_path: output_path.to_string_lossy().into_owned(),
         ready_at_ms,
+        render_backend: output.backend_id,
+    })
+}
+
+/// Renders every output variant the capture's bundle declares. A failed
+/// variant is logged and skipped; the canonical final is never affected.
+pub fn render_final_output_variants_in_dir(
+    base_dir: &Path,
+    session_id: &str,
+    capture: &SessionCaptureRecord,
+) -> Vec<RenderedOutputVariant> {
+    let Some(preset_id) = capture.active_preset_id.as_deref() else {
+        return Vec::new();
+    };
+    let Ok(bundle) = resolve_runtime_bundle_in_dir(
+        base_dir,
+        preset_id,
+        &capture.active_preset_version,
+        RenderIntent::Final,
+    ) else {
+        return Vec::new();
+    };
+
+    let paths = SessionPaths::new(base_dir, session_id);
+    let mut rendered = Vec::with_capacity(bundle.output_variants.len());
+    for variant in &bundle.output_variants {
+        match render_final_output_variant(base_dir, &paths, &bundle, capture, variant) {
+            Ok(output) => rendered.push(output),
+            Err(error) => {
+                log::warn!(
+                    "final_output_variant_failed session={} capture_id={} variant_id={} reason_code={} detail={}",
+                    session_id,
+                    capture.capture_id,
+                    variant.variant_id,
+                    error.reason_code,
+                    error.operator_detail
+                );
+                append_render_event(
+                    &paths,
+                    &capture.capture_id,
+                    Some(&capture.request_id),
+                    RenderIntent::Final,
+                    "final-variant-failed",
+                    Some(error.reason_code),
+                    Some(&format!("variantId={}", variant.variant_id)),
+                );
+            }
+        }
+    }
+
+    rendered
+}
+
+fn render_final_output_variant(
+    base_dir: &Path,
+    paths: &SessionPaths,
+    bundle: &PublishedPresetRuntimeBundle,
+    capture: &SessionCaptureRecord,
+    variant: &PublishedPresetOutputVariant,
+) -> Result<RenderedOutputVariant, RenderWorkerError> {
+    let _queue_guard = acquire_render_queue_slot()?;
+    let intent = RenderIntent::Final;
+    let extension = variant.format.extension();
+    let output_root = &paths.renders_finals_dir;
+    let output_path = output_root.join(format!(
+        "{}.{}.{extension}",
+        capture.capture_id, variant.variant_id
+    ));
+    let staging_output_path = output_root.join(format!(
+        "{}.{}.final-rendering.{extension}",
+        capture.capture_id, variant.variant_id
+    ));
+    fs::create_dir_all(output_root).map_err(|error| RenderWorkerError {
+        reason_code: "render-output-dir-unavailable",
+        customer_message: safe_render_failure_message(intent),
+        operator_detail: format!("render output directory를 준비하지 못했어요: {error}"),
+    })?;
+    let _ = fs::remove_file(&staging_output_path);
+
+    let render_source = resolve_preview_render_source(capture, paths, intent, None);
+    let job = RenderJob {
+        base_dir,
+        bundle,
+        source_asset_path: Path::new(&render_source.asset_path),
+        source_kind: render_source.kind,
+        output_path: &staging_output_path,
+        intent,
+        variant: Some(variant),
+    };
+    let render_started = Instant::now();
+    let output = render_with_backend_policy(&job, resolve_render_backend_policy())
+        .and_then(|output| validate_render_output(&staging_output_path, intent).map(|_| output))
+        .inspect(|_| {
+            if variant.format != OutputVariantFormat::Jpeg {
+                return;
+            }
+            let overlay_context = build_overlay_text_context(
+                base_dir,
+                &capture.booth_alias,
+                capture.raw.persisted_at_ms,
+            );
+            if let Err(detail) = apply_overlay_layout(
+                bundle,
+                &staging_output_path,
+                intent,
+                &overlay_context,
+                variant.quality,
+            ) {
+                log_overlay_skipped(
+                    paths,
+                    &capture.capture_id,
+                    Some(&capture.request_id),
+                    intent,
+                    &format!("variantId={};{detail}", variant.variant_id),
+                );
+            }
+        })
+        .and_then(|output| {
+            promote_render_output(&staging_output_path, &output_path, intent).map(|_| output)
+        })
+        .inspect_err(|_| {
+            let _ = fs::remove_file(&staging_output_path);
+        })?;
+    let ready_at_ms = current_time_ms().map_err(|error| RenderWorkerError {
+        reason_code: "render-clock-unavailable",
+        customer_message: safe_render_failure_message(intent),
+        operator_detail: error,
+    })?;
+
+    append_render_event(
+        paths,
+        &capture.capture_id,
+        Some(&capture.request_id),
+        intent,
+        "final-variant-ready",
+        None,
+        Some(&format!(
+            "variantId={};format={};colorSpace={};{}",
+            variant.variant_id,
+            variant.format.label(),
+            variant.color_space,
+            render_ready_detail(
+                bundle,
+                &output,
+                render_started.elapsed().as_millis(),
+                &render_invocation_detail_with_source(intent, Some(render_source.kind)),
+            )
+        )),
+    );
+
+    Ok(RenderedOutputVariant {
+        variant_id: variant.variant_id.clone(),
+        asset_path: output_path.to_string_lossy().into_owned(),
+        format: variant.format.label(),
+        color_space: variant.color_space.clone(),
+        purposes: variant.purposes.clone(),
+        ready_at_ms,
     })
 }
 
@@ -239,49 +447,173 @@ pub fn render_preview_asset_to_path_in_dir(
     })?;
     let _ = fs::remove_file(output_path);
 
-    let invocation = build_darktable_invocation_from_source(
+    let job = RenderJob {
         base_dir,
-        &bundle.darktable_version,
-        &bundle.xmp_template_path,
+        bundle: &bundle,
         source_asset_path,
+        source_kind: PreviewRenderSourceKind::FastPreviewRaster,
         output_path,
-        RenderIntent::Preview,
-        PreviewRenderSourceKind::FastPreviewRaster,
-    );
+        intent: RenderIntent::Preview,
+        variant: None,
+    };
     let render_detail = render_invocation_detail_with_source(
         RenderIntent::Preview,
-        Some(invocation.render_source_kind),
+        Some(PreviewRenderSourceKind::FastPreviewRaster),
     );
     log::info!(
-        "speculative_preview_render_started session={} capture_id={} request_id={} binary={} source={} detail={}",
+        "speculative_preview_render_started session={} capture_id={} request_id={} detail={}",
         session_id,
         capture_id,
         request_id,
-        invocation.binary,
-        invocation.binary_source,
         render_detail
     );
 
     let render_started = Instant::now();
-    let invocation_result = run_darktable_invocation(&invocation, RenderIntent::Preview)?;
+    let output = render_with_backend_policy(&job, resolve_render_backend_policy())?;
     validate_render_output(output_path, RenderIntent::Preview)?;
+    if bundle.overlay_layout.is_some() {
+        let paths = SessionPaths::new(base_dir, session_id);
+        let booth_alias = read_session_manifest(&paths.manifest_path)
+            .map(|manifest| manifest.booth_alias)
+            .unwrap_or_default();
+        let overlay_context = build_overlay_text_context(
+            base_dir,
+            &booth_alias,
+            current_time_ms().unwrap_or_default(),
+        );
+        if let Err(detail) = apply_overlay_layout(
+            &bundle,
+            output_path,
+            RenderIntent::Preview,
+            &overlay_context,
+            OVERLAY_PREVIEW_JPEG_QUALITY,
+        ) {
+            log_overlay_skipped(
+                &paths,
+                capture_id,
+                Some(request_id),
+                RenderIntent::Preview,
+                &detail,
+            );
+        }
+    }
     let render_elapsed_ms = render_started.elapsed().as_millis();
 
     Ok(PreparedPreviewRender {
-        detail: format!(
-            "presetId={};publishedVersion={};binary={};source={};elapsedMs={};detail={};args={};status={}",
-            bundle.preset_id,
-            bundle.published_version,
-            invocation.binary,
-            invocation.binary_source,
-            render_elapsed_ms,
-            render_detail,
-            invocation.arguments.join(" "),
-            invocation_result.exit_code
-        ),
+        detail: render_ready_detail(&bundle, &output, render_elapsed_ms, &render_detail),
     })
 }
 
+fn build_overlay_text_context(
+    base_dir: &Path,
+    booth_alias: &str,
+    captured_at_ms: u64,
+) -> OverlayTextContext {
+    OverlayTextContext {
+        booth_alias: booth_alias.into(),
+        branch_name: load_branch_display_name_in_dir(base_dir),
+        captured_at_ms,
+    }
+}
+
+/// Final quality follows the branch's `render-final-jpeg-quality` toggle.
+fn overlay_jpeg_quality(base_dir: &Path, intent: RenderIntent) -> u8 {
+    match intent {
+        RenderIntent::Preview => OVERLAY_PREVIEW_JPEG_QUALITY,
+        RenderIntent::Final => match resolve_booth_operational_toggles_in_dir(base_dir)
+            .enum_value(RENDER_FINAL_JPEG_QUALITY)
+        {
+            Some("high") => OVERLAY_FINAL_HIGH_JPEG_QUALITY,
+            _ => OVERLAY_FINAL_JPEG_QUALITY,
+        },
+    }
+}
+
+/// Composites the bundle's overlay layout onto a validated staging JPEG in
+/// place. An `Err` leaves the plain render untouched so the capture still
+/// gets an output.
+fn apply_overlay_layout(
+    bundle: &PublishedPresetRuntimeBundle,
+    output_path: &Path,
+    intent: RenderIntent,
+    context: &OverlayTextContext,
+    quality: u8,
+) -> Result<(), String> {
+    let Some(layout) = bundle.overlay_layout.as_ref() else {
+        return Ok(());
+    };
+    let applies = match intent {
+        RenderIntent::Preview => layout.applies_to_preview,
+        RenderIntent::Final => layout.applies_to_final,
+    };
+    if !applies {
+        return Ok(());
+    }
+    if !has_jpeg_signature(output_path) {
+        return Err("overlay는 JPEG render 결과에만 적용할 수 있어요.".into());
+    }
+
+    let bytes = fs::read(output_path).map_err(|error| error.to_string())?;
+    let image = jpeg_codec::decode_jpeg(&bytes, 1)?;
+    let composited = composite_overlay_layout(image, layout, context)?;
+    let encoded = jpeg_codec::encode_jpeg(&composited, quality)?;
+    fs::write(output_path, encoded)
+        .map_err(|error| format!("overlay 결과를 저장하지 못했어요: {error}"))
+}
+
+fn log_overlay_skipped(
+    paths: &SessionPaths,
+    capture_id: &str,
+    request_id: Option<&str>,
+    intent: RenderIntent,
+    detail: &str,
+) {
+    log::warn!(
+        "render_overlay_skipped capture_id={} stage={} detail={}",
+        capture_id,
+        render_stage_label(intent),
+        detail
+    );
+    append_render_event(
+        paths,
+        capture_id,
+        request_id,
+        intent,
+        "overlay-skipped",
+        Some("overlay-composite-failed"),
+        Some(detail),
+    );
+}
+
+/// Reads the backend back out of a render-ready detail string, for previews
+/// whose detail was parked on disk before the manifest was updated.
+pub fn render_backend_from_detail(detail: &str) -> Option<&str> {
+    detail
+        .split(';')
+        .find_map(|part| part.strip_prefix("backend="))
+        .filter(|backend| !backend.is_empty())
+}
+
+fn render_ready_detail(
+    bundle: &PublishedPresetRuntimeBundle,
+    output: &RenderBackendOutput,
+    render_elapsed_ms: u128,
+    render_detail: &str,
+) -> String {
+    format!(
+        "presetId={};publishedVersion={};backend={};binary={};source={};elapsedMs={};detail={};args={};status={}",
+        bundle.preset_id,
+        bundle.published_version,
+        output.backend_id,
+        output.binary,
+        output.binary_source,
+        render_elapsed_ms,
+        render_detail,
+        output.arguments.join(" "),
+        output.exit_code
+    )
+}
+
 pub fn schedule_preview_renderer_warmup_in_dir(
     base_dir: &Path,
     session_id: &str,
@@ -479,6 +811,10 @@ fn run_preview_renderer_warmup_in_dir(
         return Ok(());
     };
 
+    if resolve_render_backend_policy() == RenderBackendPolicy::FallbackOnly {
+        return Ok(());
+    }
+
     let bundle =
         resolve_runtime_bundle_in_dir(base_dir, preset_id, preset_version, RenderIntent::Preview)?;
     let warmup_source_path = ensure_preview_renderer_warmup_source(base_dir)?;
@@ -791,6 +1127,7 @@ impl Drop for RenderQueueGuard {
 struct DarktableInvocation {
     binary: String,
     binary_source: &'static str,
+    #[cfg_attr(not(test), allow(dead_code))]
     render_source_kind: PreviewRenderSourceKind,
     arguments: Vec<String>,
     working_directory: PathBuf,
@@ -836,6 +1173,7 @@ fn resolve_runtime_bundle_in_dir(
     Ok(bundle)
 }
 
+#[cfg(test)]
 fn build_darktable_invocation(
     base_dir: &Path,
     _darktable_version: &str,
@@ -1110,6 +1448,7 @@ fn run_darktable_invocation(
         .stderr(Stdio::from(stderr_log))
         .spawn()
         .map_err(|error| {
+            let _ = fs::remove_file(&stderr_log_path);
             let reason_code = if error.kind() == std::io::ErrorKind::NotFound {
                 "render-cli-missing"
             } else {
@@ -1260,7 +1599,16 @@ fn validate_render_output(
         });
     }
 
-    if !is_valid_render_preview_asset(output_path) {
+    let is_valid_output = match output_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("tif") | Some("tiff") => has_tiff_signature(output_path),
+        _ => is_valid_render_preview_asset(output_path),
+    };
+    if !is_valid_output {
         return Err(RenderWorkerError {
             reason_code: "render-output-invalid",
             customer_message: safe_render_failure_message(intent),
@@ -1290,6 +1638,14 @@ fn has_png_signature(path: &Path) -> bool {
     bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A])
 }
 
+fn has_tiff_signature(path: &Path) -> bool {
+    let Ok(bytes) = fs::read(path) else {
+        return false;
+    };
+
+    bytes.starts_with(b"II*\0") || bytes.starts_with(b"MM\0*")
+}
+
 fn sanitize_process_output(bytes: &[u8]) -> String {
     let text = String::from_utf8_lossy(bytes);
     let trimmed = text.trim();
@@ -1304,6 +1660,14 @@ fn sanitize_process_output(bytes: &[u8]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::preset::{
+        overlay_layout::{OverlayElement, OverlayLayout, OverlayRect, OverlayTextAnchor},
+        preset_bundle::{
+            OutputVariantCrop, OutputVariantFormat, PublishedPresetFallbackLook,
+            PublishedPresetRenderProfile, PublishedPresetShotLayout, PublishedPresetShotSlot,
+        },
+    };
+    use render_backend::{FallbackRenderBackend, RenderBackend};
 
     fn unique_temp_dir(label: &str) -> PathBuf {
         std::env::temp_dir().join(format!(
@@ -1395,8 +1759,10 @@ mod tests {
                 final_asset: crate::session::session_manifest::FinalCaptureAsset {
                     asset_path: None,
                     ready_at_ms: None,
+                    variants: Vec::new(),
                 },
                 render_status: "previewWaiting".into(),
+                render_backend: None,
                 post_end_state: "activeSession".into(),
                 timing: crate::session::session_manifest::CaptureTimingMetrics {
                     capture_acknowledged_at_ms: 100,
@@ -1407,6 +1773,7 @@ mod tests {
                     preview_budget_ms: 5000,
                     preview_budget_state: "pending".into(),
                 },
+                capture_program: None,
             },
             &paths,
             &temp_dir
@@ -1470,8 +1837,10 @@ mod tests {
                 final_asset: crate::session::session_manifest::FinalCaptureAsset {
                     asset_path: None,
                     ready_at_ms: None,
+                    variants: Vec::new(),
                 },
                 render_status: "previewWaiting".into(),
+                render_backend: None,
                 post_end_state: "activeSession".into(),
                 timing: crate::session::session_manifest::CaptureTimingMetrics {
                     capture_acknowledged_at_ms: 100,
@@ -1482,6 +1851,7 @@ mod tests {
                     preview_budget_ms: 5000,
                     preview_budget_state: "pending".into(),
                 },
+                capture_program: None,
             },
             &paths,
             &temp_dir.join("renders").join("finals").join("capture.jpg"),
@@ -1552,8 +1922,10 @@ mod tests {
                 final_asset: crate::session::session_manifest::FinalCaptureAsset {
                     asset_path: None,
                     ready_at_ms: None,
+                    variants: Vec::new(),
                 },
                 render_status: "previewWaiting".into(),
+                render_backend: None,
                 post_end_state: "activeSession".into(),
                 timing: crate::session::session_manifest::CaptureTimingMetrics {
                     capture_acknowledged_at_ms: 100,
@@ -1564,6 +1936,7 @@ mod tests {
                     preview_budget_ms: 5000,
                     preview_budget_state: "pending".into(),
                 },
+                capture_program: None,
             },
             &paths,
             &temp_dir
@@ -1637,8 +2010,10 @@ mod tests {
                 final_asset: crate::session::session_manifest::FinalCaptureAsset {
                     asset_path: None,
                     ready_at_ms: None,
+                    variants: Vec::new(),
                 },
                 render_status: "previewWaiting".into(),
+                render_backend: None,
                 post_end_state: "activeSession".into(),
                 timing: crate::session::session_manifest::CaptureTimingMetrics {
                     capture_acknowledged_at_ms: 100,
@@ -1649,6 +2024,7 @@ mod tests {
                     preview_budget_ms: 5000,
                     preview_budget_state: "pending".into(),
                 },
+                capture_program: None,
             },
             &paths,
             &temp_dir
@@ -1729,4 +2105,309 @@ mod tests {
 
         let _ = fs::remove_dir_all(temp_dir);
     }
+
+    fn fallback_test_bundle(monochrome: bool) -> PublishedPresetRuntimeBundle {
+        let profile = |profile_id: &str| PublishedPresetRenderProfile {
+            profile_id: profile_id.into(),
+            display_name: profile_id.into(),
+            output_color_space: "sRGB".into(),
+        };
+
+        PublishedPresetRuntimeBundle {
+            preset_id: "preset_test".into(),
+            display_name: "Test".into(),
+            published_version: "2026.03.20".into(),
+            darktable_version: "5.4.1".into(),
+            xmp_template_path: PathBuf::from("xmp/template.xmp"),
+            preview_profile: profile("preview"),
+            final_profile: profile("final"),
+            fallback_look: PublishedPresetFallbackLook {
+                exposure_ev: 0.5,
+                monochrome,
+                ..PublishedPresetFallbackLook::default()
+            },
+            output_variants: Vec::new(),
+            overlay_layout: None,
+            shot_layout: None,
+        }
+    }
+
+    fn gradient_jpeg(width: usize, height: usize) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(width * height * 3);
+        for y in 0..height {
+            for x in 0..width {
+                pixels.extend_from_slice(&[(x * 255 / width) as u8, (y * 255 / height) as u8, 96]);
+            }
+        }
+
+        jpeg_codec::encode_jpeg(
+            &jpeg_codec::RgbImage {
+                width,
+                height,
+                pixels,
+            },
+            90,
+        )
+        .expect("gradient jpeg should encode")
+    }
+
+    #[test]
+    fn corrupted_jpeg_streams_are_rejected_without_panicking() {
+        let source = gradient_jpeg(64, 48);
+        for offset in (2..source.len()).step_by(3) {
+            for replacement in [0x00, 0x7F, 0xFF] {
+                let mut corrupted = source.clone();
+                corrupted[offset] = replacement;
+                for scale in [1, 8] {
+                    if let Ok(image) = jpeg_codec::decode_jpeg(&corrupted, scale) {
+                        assert_eq!(image.pixels.len(), image.width * image.height * 3);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fallback_backend_renders_the_largest_embedded_raw_preview_with_the_bundle_look() {
+        let temp_dir = unique_temp_dir("fallback-embedded");
+        fs::create_dir_all(&temp_dir).expect("temp dir should exist");
+        let raw_path = temp_dir.join("capture_test.CR3");
+        let output_path = temp_dir.join("capture_test.preview-rendering.jpg");
+        let mut raw_bytes = b"ftypcrx \x00\x00raw-header".to_vec();
+        raw_bytes.extend(gradient_jpeg(80, 64));
+        raw_bytes.extend([0x00; 32]);
+        raw_bytes.extend(gradient_jpeg(320, 240));
+        raw_bytes.extend(b"sensor-data");
+        fs::write(&raw_path, raw_bytes).expect("raw fixture should be writable");
+        let bundle = fallback_test_bundle(true);
+
+        let output = render_with_backend_policy(
+            &RenderJob {
+                base_dir: &temp_dir,
+                bundle: &bundle,
+                source_asset_path: &raw_path,
+                source_kind: PreviewRenderSourceKind::RawOriginal,
+                output_path: &output_path,
+                intent: RenderIntent::Preview,
+                variant: None,
+            },
+            RenderBackendPolicy::FallbackOnly,
+        )
+        .expect("fallback render should succeed");
+
+        assert!(output.is_degraded());
+        assert!(output
+            .arguments
+            .contains(&"sourceKind=embedded-preview".to_string()));
+        assert!(output.arguments.contains(&"width=320".to_string()));
+        let rendered = jpeg_codec::decode_jpeg(
+            &fs::read(&output_path).expect("fallback output should be readable"),
+            1,
+        )
+        .expect("fallback output should be a baseline jpeg");
+        assert_eq!((rendered.width, rendered.height), (320, 240));
+        assert!(rendered
+            .pixels
+            .chunks_exact(3)
+            .all(|pixel| pixel[0].abs_diff(pixel[1]) <= 2 && pixel[1].abs_diff(pixel[2]) <= 2));
+        assert!(render_ready_detail(&bundle, &output, 12, "fallback")
+            .contains("backend=builtin-fallback"));
+
+        let _ = fs::remove_dir_all(temp_dir);
+    }
+
+    #[test]
+    fn fallback_backend_rejects_sources_without_a_baseline_jpeg() {
+        let temp_dir = unique_temp_dir("fallback-undecodable");
+        fs::create_dir_all(&temp_dir).expect("temp dir should exist");
+        let raw_path = temp_dir.join("capture_test.CR3");
+        fs::write(&raw_path, b"raw-without-preview").expect("raw fixture should be writable");
+        let output_path = temp_dir.join("capture_test.final-rendering.jpg");
+        let bundle = fallback_test_bundle(false);
+
+        let error = FallbackRenderBackend
+            .render(&RenderJob {
+                base_dir: &temp_dir,
+                bundle: &bundle,
+                source_asset_path: &raw_path,
+                source_kind: PreviewRenderSourceKind::RawOriginal,
+                output_path: &output_path,
+                intent: RenderIntent::Final,
+                variant: None,
+            })
+            .expect_err("a raw without an embedded preview should fail");
+
+        assert_eq!(error.reason_code, "fallback-source-undecodable");
+        assert!(!output_path.exists());
+
+        let _ = fs::remove_dir_all(temp_dir);
+    }
+
+    #[test]
+    fn fallback_backend_renders_square_jpeg_variants_and_rejects_tiff() {
+        let temp_dir = unique_temp_dir("fallback-variants");
+        fs::create_dir_all(&temp_dir).expect("temp dir should exist");
+        let source_path = temp_dir.join("capture_test.jpg");
+        fs::write(&source_path, gradient_jpeg(320, 240)).expect("source should be writable");
+        let output_path = temp_dir.join("capture_test.social.final-rendering.jpg");
+        let bundle = fallback_test_bundle(false);
+        let variant = PublishedPresetOutputVariant {
+            variant_id: "social".into(),
+            display_name: "Social".into(),
+            format: OutputVariantFormat::Jpeg,
+            bit_depth: 8,
+            max_width: Some(120),
+            max_height: None,
+            quality: 80,
+            color_space: "sRGB".into(),
+            crop: OutputVariantCrop::Square,
+            purposes: vec!["social".into()],
+        };
+        let job = |variant| RenderJob {
+            base_dir: &temp_dir,
+            bundle: &bundle,
+            source_asset_path: &source_path,
+            source_kind: PreviewRenderSourceKind::FastPreviewRaster,
+            output_path: &output_path,
+            intent: RenderIntent::Final,
+            variant: Some(variant),
+        };
+
+        let output = FallbackRenderBackend
+            .render(&job(&variant))
+            .expect("square jpeg variant should render");
+        assert!(output.arguments.contains(&"quality=80".to_string()));
+        let rendered = jpeg_codec::decode_jpeg(
+            &fs::read(&output_path).expect("variant output should be readable"),
+            1,
+        )
+        .expect("variant output should be a baseline jpeg");
+        assert_eq!((rendered.width, rendered.height), (120, 120));
+
+        let tiff_variant = PublishedPresetOutputVariant {
+            format: OutputVariantFormat::Tiff,
+            crop: OutputVariantCrop::None,
+            ..variant.clone()
+        };
+        let error = FallbackRenderBackend
+            .render(&job(&tiff_variant))
+            .expect_err("tiff variants need darktable");
+        assert_eq!(error.reason_code, "fallback-variant-unsupported");
+
+        let _ = fs::remove_dir_all(temp_dir);
+    }
+
+    #[test]
+    fn overlay_layout_stamps_text_on_finals_and_leaves_previews_alone() {
+        let temp_dir = unique_temp_dir("overlay-text");
+        fs::create_dir_all(&temp_dir).expect("temp dir should exist");
+        let output_path = temp_dir.join("capture_test.final-rendering.jpg");
+        fs::write(&output_path, gradient_jpeg(140, 100)).expect("render should be writable");
+        let bundle = PublishedPresetRuntimeBundle {
+            overlay_layout: Some(OverlayLayout {
+                applies_to_preview: false,
+                applies_to_final: true,
+                utc_offset_minutes: 540,
+                elements: vec![OverlayElement::Text {
+                    template: "{date}".into(),
+                    x: 0.5,
+                    y: 0.1,
+                    height: 0.14,
+                    color: [255, 255, 255],
+                    anchor: OverlayTextAnchor::Center,
+                }],
+            }),
+            ..fallback_test_bundle(false)
+        };
+        let context = OverlayTextContext {
+            booth_alias: "Kim 4821".into(),
+            branch_name: None,
+            captured_at_ms: 1_774_018_800_000,
+        };
+        let untouched = fs::read(&output_path).expect("render should be readable");
+
+        apply_overlay_layout(&bundle, &output_path, RenderIntent::Preview, &context, 85)
+            .expect("preview overlay should be a no-op");
+        assert_eq!(
+            fs::read(&output_path).expect("render should exist"),
+            untouched
+        );
+
+        apply_overlay_layout(&bundle, &output_path, RenderIntent::Final, &context, 92)
+            .expect("final overlay should composite");
+        let composited = jpeg_codec::decode_jpeg(
+            &fs::read(&output_path).expect("composited render should exist"),
+            1,
+        )
+        .expect("composited render should stay a JPEG");
+        assert_eq!((composited.width, composited.height), (140, 100));
+        let bright_pixels = composited
+            .pixels
+            .chunks_exact(3)
+            .filter(|pixel| pixel.iter().all(|channel| *channel > 200))
+            .count();
+        assert!(
+            bright_pixels > 50,
+            "text should be stamped: {bright_pixels}"
+        );
+        assert_eq!(
+            overlay_compositor::resolve_overlay_text("{date}", &context, 540),
+            "2026.03.21"
+        );
+
+        let _ = fs::remove_dir_all(temp_dir);
+    }
+
+    #[test]
+    fn shot_layout_canvas_cover_fits_each_slot_over_the_background() {
+        let temp_dir = unique_temp_dir("shot-layout");
+        fs::create_dir_all(&temp_dir).expect("temp dir should exist");
+        let wide_final = temp_dir.join("capture_001.jpg");
+        let tall_final = temp_dir.join("capture_002.jpg");
+        fs::write(&wide_final, gradient_jpeg(160, 90)).expect("final should be writable");
+        fs::write(&tall_final, gradient_jpeg(60, 120)).expect("final should be writable");
+        let slot = |slot_id: &str, y: f32| PublishedPresetShotSlot {
+            slot_id: slot_id.into(),
+            rect: OverlayRect {
+                x: 0.1,
+                y,
+                width: 0.8,
+                height: 0.4,
+            },
+        };
+        let definition = PublishedPresetShotLayout {
+            layout_id: "strip-2".into(),
+            display_name: "2컷".into(),
+            canvas_width: 100,
+            canvas_height: 200,
+            background_color: [255, 0, 0],
+            slots: vec![slot("top", 0.05), slot("bottom", 0.55)],
+        };
+
+        let canvas = shot_layout_composer::compose_canvas(
+            &definition,
+            &[
+                ("top", wide_final.as_path()),
+                ("bottom", tall_final.as_path()),
+            ],
+        )
+        .expect("layout should compose");
+
+        assert_eq!((canvas.width, canvas.height), (100, 200));
+        let pixel = |x: usize, y: usize| {
+            let offset = (y * canvas.width + x) * 3;
+            &canvas.pixels[offset..offset + 3]
+        };
+        assert_eq!(pixel(2, 2), &[255, 0, 0]);
+        assert_eq!(pixel(50, 100), &[255, 0, 0]);
+        for (x, y) in [(10, 10), (89, 89), (10, 110), (89, 189)] {
+            assert_ne!(
+                pixel(x, y),
+                &[255, 0, 0],
+                "slot pixel ({x},{y}) should be covered"
+            );
+        }
+
+        let _ = fs::remove_dir_all(temp_dir);
+    }
 }
@@ -0,0 +1,257 @@
+//! Composites a bundle's overlay layout (PNG frames/logos and text stamps)
+//! onto a rendered JPEG. Text uses a built-in 5x7 glyph font covering ASCII
+//! letters, digits and basic punctuation; other characters are left out.
+
+use std::fs;
+
+use crate::{
+    preset::overlay_layout::{OverlayElement, OverlayLayout, OverlayRect, OverlayTextAnchor},
+    session::session_manifest::unix_seconds_to_rfc3339,
+};
+
+use super::{jpeg_codec::RgbImage, png_codec::decode_png};
+
+const GLYPH_COLUMNS: usize = 5;
+const GLYPH_ROWS: usize = 7;
+const GLYPH_ADVANCE: usize = GLYPH_COLUMNS + 1;
+
+/// Runtime values substituted into text element templates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverlayTextContext {
+    pub booth_alias: String,
+    pub branch_name: Option<String>,
+    pub captured_at_ms: u64,
+}
+
+pub fn supports_overlay_text_char(ch: char) -> bool {
+    glyph_rows(ch).is_some()
+}
+
+/// Expands `{boothAlias}`, `{date}` (`YYYY.MM.DD` at the layout's UTC
+/// offset) and `{branchName}`; a missing branch name expands to nothing.
+pub fn resolve_overlay_text(
+    template: &str,
+    context: &OverlayTextContext,
+    utc_offset_minutes: i32,
+) -> String {
+    let local_seconds =
+        (context.captured_at_ms / 1_000) as i64 + i64::from(utc_offset_minutes) * 60;
+    let date = unix_seconds_to_rfc3339(local_seconds.max(0) as u64)[..10].replace('-', ".");
+
+    template
+        .replace("{boothAlias}", &context.booth_alias)
+        .replace("{date}", &date)
+        .replace("{branchName}", context.branch_name.as_deref().unwrap_or(""))
+        .trim()
+        .to_string()
+}
+
+pub fn composite_overlay_layout(
+    mut image: RgbImage,
+    layout: &OverlayLayout,
+    context: &OverlayTextContext,
+) -> Result<RgbImage, String> {
+    for element in &layout.elements {
+        match element {
+            OverlayElement::Image {
+                asset_path,
+                rect,
+                opacity,
+            } => {
+                let bytes = fs::read(asset_path).map_err(|error| {
+                    format!(
+                        "overlay asset를 읽지 못했어요: {} {error}",
+                        asset_path.to_string_lossy()
+                    )
+                })?;
+                let overlay = decode_png(&bytes)?;
+                blend_overlay_image(&mut image, &overlay, *rect, *opacity);
+            }
+            OverlayElement::Text {
+                template,
+                x,
+                y,
+                height,
+                color,
+                anchor,
+            } => {
+                let text = resolve_overlay_text(template, context, layout.utc_offset_minutes);
+                draw_text(&mut image, &text, *x, *y, *height, *color, *anchor);
+            }
+        }
+    }
+
+    Ok(image)
+}
+
+fn blend_overlay_image(
+    image: &mut RgbImage,
+    overlay: &super::png_codec::RgbaImage,
+    rect: OverlayRect,
+    opacity: f32,
+) {
+    let left = (rect.x * image.width as f32).round() as usize;
+    let top = (rect.y * image.height as f32).round() as usize;
+    let width = ((rect.width * image.width as f32).round() as usize)
+        .max(1)
+        .min(image.width.saturating_sub(left));
+    let height = ((rect.height * image.height as f32).round() as usize)
+        .max(1)
+        .min(image.height.saturating_sub(top));
+    let scale_x = overlay.width as f32 / width.max(1) as f32;
+    let scale_y = overlay.height as f32 / height.max(1) as f32;
+
+    for target_y in 0..height {
+        let source_y = ((target_y as f32 + 0.5) * scale_y - 0.5).max(0.0);
+        for target_x in 0..width {
+            let source_x = ((target_x as f32 + 0.5) * scale_x - 0.5).max(0.0);
+            let sample = sample_bilinear(overlay, source_x, source_y);
+            let alpha = sample[3] / 255.0 * opacity;
+            if alpha <= 0.0 {
+                continue;
+            }
+
+            let offset = ((top + target_y) * image.width + left + target_x) * 3;
+            for (target, value) in image.pixels[offset..offset + 3].iter_mut().zip(sample) {
+                *target = (value * alpha + *target as f32 * (1.0 - alpha)).round() as u8;
+            }
+        }
+    }
+}
+
+fn sample_bilinear(image: &super::png_codec::RgbaImage, x: f32, y: f32) -> [f32; 4] {
+    let x0 = (x.floor() as usize).min(image.width - 1);
+    let y0 = (y.floor() as usize).min(image.height - 1);
+    let x1 = (x0 + 1).min(image.width - 1);
+    let y1 = (y0 + 1).min(image.height - 1);
+    let fx = (x - x0 as f32).clamp(0.0, 1.0);
+    let fy = (y - y0 as f32).clamp(0.0, 1.0);
+    let pixel = |px: usize, py: usize, channel: usize| {
+        image.pixels[(py * image.width + px) * 4 + channel] as f32
+    };
+
+    let mut sample = [0.0; 4];
+    for (channel, value) in sample.iter_mut().enumerate() {
+        let top = pixel(x0, y0, channel) * (1.0 - fx) + pixel(x1, y0, channel) * fx;
+        let bottom = pixel(x0, y1, channel) * (1.0 - fx) + pixel(x1, y1, channel) * fx;
+        *value = top * (1.0 - fy) + bottom * fy;
+    }
+
+    sample
+}
+
+fn draw_text(
+    image: &mut RgbImage,
+    text: &str,
+    x: f32,
+    y: f32,
+    height: f32,
+    color: [u8; 3],
+    anchor: OverlayTextAnchor,
+) {
+    let glyphs = text.chars().filter_map(glyph_rows).collect::<Vec<_>>();
+    if glyphs.is_empty() {
+        return;
+    }
+
+    let scale = (height * image.height as f32 / GLYPH_ROWS as f32).max(1.0);
+    let text_width = (glyphs.len() * GLYPH_ADVANCE - 1) as f32 * scale;
+    let anchor_x = x * image.width as f32;
+    let origin_x = match anchor {
+        OverlayTextAnchor::Start => anchor_x,
+        OverlayTextAnchor::Center => anchor_x - text_width / 2.0,
+        OverlayTextAnchor::End => anchor_x - text_width,
+    };
+    let origin_y = y * image.height as f32;
+
+    for (index, rows) in glyphs.iter().enumerate() {
+        let glyph_x = origin_x + (index * GLYPH_ADVANCE) as f32 * scale;
+        for (row, bits) in rows.iter().enumerate() {
+            for column in 0..GLYPH_COLUMNS {
+                if bits & (1 << (GLYPH_COLUMNS - 1 - column)) == 0 {
+                    continue;
+                }
+                fill_rect(
+                    image,
+                    glyph_x + column as f32 * scale,
+                    origin_y + row as f32 * scale,
+                    scale,
+                    color,
+                );
+            }
+        }
+    }
+}
+
+fn fill_rect(image: &mut RgbImage, x: f32, y: f32, size: f32, color: [u8; 3]) {
+    let clamp_x = |value: f32| (value.round().max(0.0) as usize).min(image.width);
+    let clamp_y = |value: f32| (value.round().max(0.0) as usize).min(image.height);
+    let (left, right) = (clamp_x(x), clamp_x(x + size));
+    let (top, bottom) = (clamp_y(y), clamp_y(y + size));
+
+    for row in top..bottom {
+        for column in left..right {
+            let offset = (row * image.width + column) * 3;
+            image.pixels[offset..offset + 3].copy_from_slice(&color);
+        }
+    }
+}
+
+/// Rows top to bottom, five bits each with the leftmost column in bit 4.
+fn glyph_rows(ch: char) -> Option<[u8; GLYPH_ROWS]> {
+    Some(match ch.to_ascii_uppercase() {
+        ' ' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        'A' => [0x0E, 0x11, 0x11, 0x11, 0x1F, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08],
+        ':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        '_' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F],
+        '/' => [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00],
+        '\'' => [0x0C, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00],
+        '&' => [0x0C, 0x12, 0x14, 0x08, 0x15, 0x12, 0x0D],
+        '(' => [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02],
+        ')' => [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08],
+        '#' => [0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A],
+        '+' => [0x00, 0x04, 0x04, 0x1F, 0x04, 0x04, 0x00],
+        '!' => [0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x04],
+        '?' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04],
+        '@' => [0x0E, 0x11, 0x01, 0x0D, 0x15, 0x15, 0x0E],
+        _ => return None,
+    })
+}
@@ -0,0 +1,213 @@
+//! Minimal PNG decoder for bundle overlay assets: 8-bit, non-interlaced
+//! grey, grey+alpha, RGB, RGBA and palette images.
+
+use std::io::Read;
+
+use flate2::{read::ZlibDecoder, Crc};
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+const MAX_DECODED_DIMENSION: u32 = 8_192;
+
+/// Interleaved 8-bit RGBA pixels, straight (non-premultiplied) alpha.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PngHeader {
+    pub width: u32,
+    pub height: u32,
+    pub bit_depth: u8,
+    pub color_type: u8,
+    pub interlaced: bool,
+}
+
+impl PngHeader {
+    pub fn is_supported(&self) -> bool {
+        self.bit_depth == 8
+            && matches!(self.color_type, 0 | 2 | 3 | 4 | 6)
+            && !self.interlaced
+            && (1..=MAX_DECODED_DIMENSION).contains(&self.width)
+            && (1..=MAX_DECODED_DIMENSION).contains(&self.height)
+    }
+
+    fn channels(&self) -> usize {
+        match self.color_type {
+            0 | 3 => 1,
+            4 => 2,
+            2 => 3,
+            _ => 4,
+        }
+    }
+}
+
+pub fn read_png_header(bytes: &[u8]) -> Option<PngHeader> {
+    if !bytes.starts_with(&PNG_SIGNATURE) || bytes.len() < 33 || &bytes[12..16] != b"IHDR" {
+        return None;
+    }
+
+    let ihdr = &bytes[16..29];
+    Some(PngHeader {
+        width: u32::from_be_bytes([ihdr[0], ihdr[1], ihdr[2], ihdr[3]]),
+        height: u32::from_be_bytes([ihdr[4], ihdr[5], ihdr[6], ihdr[7]]),
+        bit_depth: ihdr[8],
+        color_type: ihdr[9],
+        interlaced: ihdr[12] != 0,
+    })
+}
+
+pub fn decode_png(bytes: &[u8]) -> Result<RgbaImage, String> {
+    let header = read_png_header(bytes).ok_or("PNG signature or IHDR is missing")?;
+    if !header.is_supported() {
+        return Err(format!(
+            "unsupported PNG layout: bitDepth={} colorType={} interlaced={} size={}x{}",
+            header.bit_depth, header.color_type, header.interlaced, header.width, header.height
+        ));
+    }
+
+    let mut compressed = Vec::new();
+    let mut palette: &[u8] = &[];
+    let mut transparency: &[u8] = &[];
+    let mut position = PNG_SIGNATURE.len();
+    loop {
+        let length_bytes = bytes
+            .get(position..position + 4)
+            .ok_or("PNG ended before IEND")?;
+        let length = u32::from_be_bytes([
+            length_bytes[0],
+            length_bytes[1],
+            length_bytes[2],
+            length_bytes[3],
+        ]) as usize;
+        let chunk_end = position
+            .checked_add(12 + length)
+            .filter(|end| *end <= bytes.len())
+            .ok_or("PNG chunk overruns the file")?;
+        let kind = &bytes[position + 4..position + 8];
+        let data = &bytes[position + 8..position + 8 + length];
+        let stored_crc = u32::from_be_bytes([
+            bytes[chunk_end - 4],
+            bytes[chunk_end - 3],
+            bytes[chunk_end - 2],
+            bytes[chunk_end - 1],
+        ]);
+        let mut crc = Crc::new();
+        crc.update(&bytes[position + 4..position + 8 + length]);
+        if crc.sum() != stored_crc {
+            return Err(format!(
+                "PNG chunk {} has a bad CRC",
+                String::from_utf8_lossy(kind)
+            ));
+        }
+
+        match kind {
+            b"PLTE" => palette = data,
+            b"tRNS" => transparency = data,
+            b"IDAT" => compressed.extend_from_slice(data),
+            b"IEND" => break,
+            _ => {}
+        }
+        position = chunk_end;
+    }
+
+    let channels = header.channels();
+    let width = header.width as usize;
+    let height = header.height as usize;
+    let stride = width * channels;
+    let mut filtered = Vec::with_capacity((stride + 1) * height);
+    ZlibDecoder::new(compressed.as_slice())
+        .take(((stride + 1) * height) as u64 + 1)
+        .read_to_end(&mut filtered)
+        .map_err(|error| format!("PNG image data could not be inflated: {error}"))?;
+    if filtered.len() != (stride + 1) * height {
+        return Err("PNG image data has the wrong length".into());
+    }
+
+    let mut raw = vec![0u8; stride * height];
+    for row in 0..height {
+        let filter = filtered[row * (stride + 1)];
+        let source = &filtered[row * (stride + 1) + 1..(row + 1) * (stride + 1)];
+        let (previous_rows, current_rows) = raw.split_at_mut(row * stride);
+        let previous = (row > 0).then(|| &previous_rows[(row - 1) * stride..]);
+        let current = &mut current_rows[..stride];
+        unfilter_row(filter, source, previous, current, channels)?;
+    }
+
+    if header.color_type == 3 && palette.is_empty() {
+        return Err("palette PNG has no PLTE chunk".into());
+    }
+
+    let mut pixels = Vec::with_capacity(width * height * 4);
+    for sample in raw.chunks_exact(channels) {
+        match header.color_type {
+            0 => pixels.extend_from_slice(&[sample[0], sample[0], sample[0], 255]),
+            4 => pixels.extend_from_slice(&[sample[0], sample[0], sample[0], sample[1]]),
+            2 => pixels.extend_from_slice(&[sample[0], sample[1], sample[2], 255]),
+            3 => {
+                let index = sample[0] as usize;
+                let entry = palette
+                    .get(index * 3..index * 3 + 3)
+                    .ok_or("PNG palette index is out of range")?;
+                let alpha = transparency.get(index).copied().unwrap_or(255);
+                pixels.extend_from_slice(&[entry[0], entry[1], entry[2], alpha]);
+            }
+            _ => pixels.extend_from_slice(sample),
+        }
+    }
+
+    Ok(RgbaImage {
+        width,
+        height,
+        pixels,
+    })
+}
+
+fn unfilter_row(
+    filter: u8,
+    source: &[u8],
+    previous: Option<&[u8]>,
+    current: &mut [u8],
+    channels: usize,
+) -> Result<(), String> {
+    for index in 0..source.len() {
+        let left = if index >= channels {
+            current[index - channels]
+        } else {
+            0
+        };
+        let up = previous.map_or(0, |row| row[index]);
+        let up_left = match previous {
+            Some(row) if index >= channels => row[index - channels],
+            _ => 0,
+        };
+        let predictor = match filter {
+            0 => 0,
+            1 => left,
+            2 => up,
+            3 => ((left as u16 + up as u16) / 2) as u8,
+            4 => paeth(left, up, up_left),
+            _ => return Err(format!("PNG row uses unknown filter type {filter}")),
+        };
+        current[index] = source[index].wrapping_add(predictor);
+    }
+
+    Ok(())
+}
+
+fn paeth(left: u8, up: u8, up_left: u8) -> u8 {
+    let estimate = left as i16 + up as i16 - up_left as i16;
+    let distance_left = (estimate - left as i16).abs();
+    let distance_up = (estimate - up as i16).abs();
+    let distance_up_left = (estimate - up_left as i16).abs();
+
+    if distance_left <= distance_up && distance_left <= distance_up_left {
+        left
+    } else if distance_up <= distance_up_left {
+        up
+    } else {
+        up_left
+    }
+}
@@ -0,0 +1,311 @@
+use std::{fs, path::Path};
+
+use crate::preset::preset_bundle::{
+    OutputVariantCrop, OutputVariantFormat, PublishedPresetOutputVariant,
+    PublishedPresetRuntimeBundle,
+};
+
+use super::{
+    build_darktable_invocation_from_source,
+    fallback_renderer::{apply_fallback_look, describe_fallback_look, load_fallback_source_image},
+    jpeg_codec::{decode_jpeg, downscale_to_fit, encode_jpeg, RgbImage},
+    preview_render_dimensions, run_darktable_invocation, safe_render_failure_message,
+    PreviewRenderSourceKind, RenderIntent, RenderWorkerError,
+};
+
+pub const RENDER_BACKEND_ENV: &str = "BOOTHY_RENDER_BACKEND";
+pub const DARKTABLE_CLI_RENDER_BACKEND_ID: &str = "darktable-cli";
+pub const FALLBACK_RENDER_BACKEND_ID: &str = "builtin-fallback";
+
+const FALLBACK_PREVIEW_JPEG_QUALITY: u8 = 85;
+const FALLBACK_FINAL_JPEG_QUALITY: u8 = 92;
+
+/// One render request, already resolved to a source asset and a staging path.
+pub struct RenderJob<'a> {
+    pub base_dir: &'a Path,
+    pub bundle: &'a PublishedPresetRuntimeBundle,
+    pub source_asset_path: &'a Path,
+    pub source_kind: PreviewRenderSourceKind,
+    pub output_path: &'a Path,
+    pub intent: RenderIntent,
+    /// Set when rendering one of the bundle's extra final outputs.
+    pub variant: Option<&'a PublishedPresetOutputVariant>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderBackendOutput {
+    pub backend_id: &'static str,
+    pub binary: String,
+    pub binary_source: &'static str,
+    pub arguments: Vec<String>,
+    pub exit_code: i32,
+}
+
+impl RenderBackendOutput {
+    pub fn is_degraded(&self) -> bool {
+        self.backend_id == FALLBACK_RENDER_BACKEND_ID
+    }
+}
+
+pub trait RenderBackend: Send + Sync {
+    fn backend_id(&self) -> &'static str;
+
+    fn render(&self, job: &RenderJob<'_>) -> Result<RenderBackendOutput, RenderWorkerError>;
+}
+
+/// Full-fidelity renders through the pinned darktable-cli and the bundle XMP.
+pub struct DarktableCliRenderBackend;
+
+impl RenderBackend for DarktableCliRenderBackend {
+    fn backend_id(&self) -> &'static str {
+        DARKTABLE_CLI_RENDER_BACKEND_ID
+    }
+
+    fn render(&self, job: &RenderJob<'_>) -> Result<RenderBackendOutput, RenderWorkerError> {
+        let mut invocation = build_darktable_invocation_from_source(
+            job.base_dir,
+            &job.bundle.darktable_version,
+            &job.bundle.xmp_template_path,
+            job.source_asset_path,
+            job.output_path,
+            job.intent,
+            job.source_kind,
+        );
+        if let Some(variant) = job.variant {
+            apply_output_variant_arguments(&mut invocation.arguments, variant);
+        }
+        let result = run_darktable_invocation(&invocation, job.intent)?;
+        if let Some(variant) = job
+            .variant
+            .filter(|variant| variant.crop == OutputVariantCrop::Square)
+        {
+            let image = read_rendered_jpeg(job)?;
+            write_jpeg_output(job, &center_square_crop(image), variant.quality)?;
+        }
+
+        Ok(RenderBackendOutput {
+            backend_id: DARKTABLE_CLI_RENDER_BACKEND_ID,
+            binary: invocation.binary,
+            binary_source: invocation.binary_source,
+            arguments: invocation.arguments,
+            exit_code: result.exit_code,
+        })
+    }
+}
+
+/// Degraded in-process renderer: decodes the JPEG or the RAW's embedded
+/// preview and applies the bundle's `fallbackLook` instead of the XMP.
+pub struct FallbackRenderBackend;
+
+impl RenderBackend for FallbackRenderBackend {
+    fn backend_id(&self) -> &'static str {
+        FALLBACK_RENDER_BACKEND_ID
+    }
+
+    fn render(&self, job: &RenderJob<'_>) -> Result<RenderBackendOutput, RenderWorkerError> {
+        let bytes = fs::read(job.source_asset_path).map_err(|error| RenderWorkerError {
+            reason_code: "fallback-source-unreadable",
+            customer_message: safe_render_failure_message(job.intent),
+            operator_detail: format!("fallback render source를 읽지 못했어요: {error}"),
+        })?;
+        let max_size = match job.intent {
+            RenderIntent::Preview => {
+                let (width, height) = preview_render_dimensions(job.source_kind);
+                Some((width as usize, height as usize))
+            }
+            RenderIntent::Final => None,
+        };
+        let (image, source_kind) =
+            load_fallback_source_image(&bytes, max_size).map_err(|detail| RenderWorkerError {
+                reason_code: "fallback-source-undecodable",
+                customer_message: safe_render_failure_message(job.intent),
+                operator_detail: format!(
+                    "fallback renderer가 source를 열지 못했어요: {detail} path={}",
+                    job.source_asset_path.to_string_lossy()
+                ),
+            })?;
+        let mut image = apply_fallback_look(image, &job.bundle.fallback_look);
+        let mut quality = match job.intent {
+            RenderIntent::Preview => FALLBACK_PREVIEW_JPEG_QUALITY,
+            RenderIntent::Final => FALLBACK_FINAL_JPEG_QUALITY,
+        };
+        if let Some(variant) = job.variant {
+            if variant.format != OutputVariantFormat::Jpeg || variant.color_space != "sRGB" {
+                return Err(RenderWorkerError {
+                    reason_code: "fallback-variant-unsupported",
+                    customer_message: safe_render_failure_message(job.intent),
+                    operator_detail: format!(
+                        "fallback renderer는 sRGB JPEG variant만 만들 수 있어요: variantId={} format={} colorSpace={}",
+                        variant.variant_id,
+                        variant.format.label(),
+                        variant.color_space
+                    ),
+                });
+            }
+            if variant.crop == OutputVariantCrop::Square {
+                image = center_square_crop(image);
+            }
+            image = downscale_to_fit(
+                image,
+                variant.max_width.unwrap_or(u32::MAX) as usize,
+                variant.max_height.unwrap_or(u32::MAX) as usize,
+            );
+            quality = variant.quality;
+        }
+        let (width, height) = (image.width, image.height);
+
+        write_jpeg_output(job, &image, quality)?;
+
+        let mut arguments = vec![
+            format!("sourceKind={}", source_kind.label()),
+            format!("width={width}"),
+            format!("height={height}"),
+            format!("quality={quality}"),
+        ];
+        arguments.extend(describe_fallback_look(&job.bundle.fallback_look));
+
+        Ok(RenderBackendOutput {
+            backend_id: FALLBACK_RENDER_BACKEND_ID,
+            binary: "builtin".into(),
+            binary_source: FALLBACK_RENDER_BACKEND_ID,
+            arguments,
+            exit_code: 0,
+        })
+    }
+}
+
+/// darktable-cli options go before `--core`; `--conf` overrides after it.
+fn apply_output_variant_arguments(
+    arguments: &mut Vec<String>,
+    variant: &PublishedPresetOutputVariant,
+) {
+    let mut options = Vec::new();
+    if let Some(max_width) = variant.max_width {
+        options.extend(["--width".to_string(), max_width.to_string()]);
+    }
+    if let Some(max_height) = variant.max_height {
+        options.extend(["--height".to_string(), max_height.to_string()]);
+    }
+    options.extend([
+        "--icc-type".to_string(),
+        match variant.color_space.as_str() {
+            "AdobeRGB" => "ADOBERGB",
+            _ => "SRGB",
+        }
+        .to_string(),
+    ]);
+
+    let core_index = arguments
+        .iter()
+        .position(|argument| argument == "--core")
+        .unwrap_or(arguments.len());
+    arguments.splice(core_index..core_index, options);
+    arguments.extend([
+        "--conf".to_string(),
+        match variant.format {
+            OutputVariantFormat::Jpeg => {
+                format!("plugins/imageio/format/jpeg/quality={}", variant.quality)
+            }
+            OutputVariantFormat::Tiff => {
+                format!("plugins/imageio/format/tiff/bpp={}", variant.bit_depth)
+            }
+        },
+    ]);
+}
+
+fn read_rendered_jpeg(job: &RenderJob<'_>) -> Result<RgbImage, RenderWorkerError> {
+    fs::read(job.output_path)
+        .map_err(|error| error.to_string())
+        .and_then(|bytes| decode_jpeg(&bytes, 1))
+        .map_err(|detail| RenderWorkerError {
+            reason_code: "render-variant-crop-failed",
+            customer_message: safe_render_failure_message(job.intent),
+            operator_detail: format!(
+                "square variant를 자르기 위해 render 결과를 열지 못했어요: {detail}"
+            ),
+        })
+}
+
+fn write_jpeg_output(
+    job: &RenderJob<'_>,
+    image: &RgbImage,
+    quality: u8,
+) -> Result<(), RenderWorkerError> {
+    let encoded = encode_jpeg(image, quality).map_err(|error| RenderWorkerError {
+        reason_code: "render-output-write-failed",
+        customer_message: safe_render_failure_message(job.intent),
+        operator_detail: format!("render 결과를 인코딩하지 못했어요: {error}"),
+    })?;
+    fs::write(job.output_path, encoded).map_err(|error| RenderWorkerError {
+        reason_code: "render-output-write-failed",
+        customer_message: safe_render_failure_message(job.intent),
+        operator_detail: format!("render 결과를 저장하지 못했어요: {error}"),
+    })
+}
+
+fn center_square_crop(image: RgbImage) -> RgbImage {
+    let side = image.width.min(image.height);
+    if image.width == image.height {
+        return image;
+    }
+
+    let left = (image.width - side) / 2;
+    let top = (image.height - side) / 2;
+    let mut pixels = Vec::with_capacity(side * side * 3);
+    for row in top..top + side {
+        let start = (row * image.width + left) * 3;
+        pixels.extend_from_slice(&image.pixels[start..start + side * 3]);
+    }
+
+    RgbImage {
+        width: side,
+        height: side,
+        pixels,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderBackendPolicy {
+    /// darktable-cli, falling back to the built-in renderer only when the
+    /// binary cannot be found.
+    Auto,
+    DarktableOnly,
+    FallbackOnly,
+}
+
+/// `BOOTHY_RENDER_BACKEND=darktable` disables the fallback and `fallback`
+/// skips darktable entirely; anything else is `Auto`.
+pub fn resolve_render_backend_policy() -> RenderBackendPolicy {
+    match std::env::var(RENDER_BACKEND_ENV)
+        .ok()
+        .map(|value| value.trim().to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("darktable") | Some("darktable-cli") => RenderBackendPolicy::DarktableOnly,
+        Some("fallback") | Some("builtin-fallback") => RenderBackendPolicy::FallbackOnly,
+        _ => RenderBackendPolicy::Auto,
+    }
+}
+
+pub fn render_with_backend_policy(
+    job: &RenderJob<'_>,
+    policy: RenderBackendPolicy,
+) -> Result<RenderBackendOutput, RenderWorkerError> {
+    match policy {
+        RenderBackendPolicy::DarktableOnly => DarktableCliRenderBackend.render(job),
+        RenderBackendPolicy::FallbackOnly => FallbackRenderBackend.render(job),
+        RenderBackendPolicy::Auto => match DarktableCliRenderBackend.render(job) {
+            Err(error) if error.reason_code == "render-cli-missing" => {
+                log::warn!(
+                    "render_backend_degraded backend={} reason_code={} detail={}",
+                    FALLBACK_RENDER_BACKEND_ID,
+                    error.reason_code,
+                    error.operator_detail
+                );
+                let _ = fs::remove_file(job.output_path);
+                FallbackRenderBackend.render(job)
+            }
+            result => result,
+        },
+    }
+}
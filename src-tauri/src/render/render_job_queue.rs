@@ -0,0 +1,467 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        LazyLock, Mutex,
+    },
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::contracts::dto::HostErrorEnvelope;
+
+use super::{RenderWorkerError, MAX_IN_FLIGHT_RENDER_JOBS};
+
+pub const RENDER_JOB_QUEUE_SCHEMA_VERSION: &str = "render-job-queue/v1";
+pub const MAX_RENDER_JOB_ATTEMPTS: u32 = 3;
+
+const RENDER_JOB_RETRY_BASE_DELAY_MS: u64 = 500;
+const RENDER_JOB_RETRY_MAX_DELAY_MS: u64 = 8_000;
+const RENDER_JOB_WORKER_MAX_SLEEP_MS: u64 = 250;
+
+static RENDER_JOB_STORE_LOCK: Mutex<()> = Mutex::new(());
+static RENDER_JOB_WORKERS: LazyLock<Mutex<HashMap<PathBuf, usize>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+static RENDER_JOB_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Runs one claimed job. Returning an error schedules a retry until
+/// `MAX_RENDER_JOB_ATTEMPTS` is reached, after which the job is dropped.
+pub type RenderJobExecutor = fn(&Path, &RenderJobRecord) -> Result<(), RenderWorkerError>;
+
+/// Declared in priority order: fast previews are claimed before refinements,
+/// and refinements before finals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RenderJobKind {
+    FastPreview,
+    PreviewRefinement,
+    Final,
+}
+
+impl RenderJobKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            RenderJobKind::FastPreview => "fast-preview",
+            RenderJobKind::PreviewRefinement => "preview-refinement",
+            RenderJobKind::Final => "final",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RenderJobStatus {
+    Queued,
+    Running,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderJobRecord {
+    pub job_id: String,
+    pub kind: RenderJobKind,
+    pub session_id: String,
+    pub capture_id: String,
+    pub request_id: String,
+    #[serde(default)]
+    pub preset_id: Option<String>,
+    #[serde(default)]
+    pub preset_version: Option<String>,
+    #[serde(default)]
+    pub source_asset_path: Option<String>,
+    pub status: RenderJobStatus,
+    pub attempts: u32,
+    pub enqueued_at_ms: u64,
+    pub next_attempt_at_ms: u64,
+    #[serde(default)]
+    pub last_reason_code: Option<String>,
+}
+
+impl RenderJobRecord {
+    /// True while running the attempt after which no retry will be scheduled.
+    pub fn is_last_attempt(&self) -> bool {
+        self.attempts >= MAX_RENDER_JOB_ATTEMPTS
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderJobRequest {
+    pub kind: RenderJobKind,
+    pub session_id: String,
+    pub capture_id: String,
+    pub request_id: String,
+    pub preset_id: Option<String>,
+    pub preset_version: Option<String>,
+    pub source_asset_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderJobSettlement {
+    Completed,
+    RetryScheduled { next_attempt_at_ms: u64 },
+    Exhausted,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RenderJobStore {
+    schema_version: String,
+    jobs: Vec<RenderJobRecord>,
+}
+
+pub fn resolve_render_job_queue_path(base_dir: &Path) -> PathBuf {
+    base_dir.join("render-queue").join("render-jobs.json")
+}
+
+/// Persists a job unless one of the same kind is already pending for the
+/// capture, in which case the existing record is returned.
+pub fn enqueue_render_job_in_dir(
+    base_dir: &Path,
+    request: RenderJobRequest,
+    now_ms: u64,
+) -> Result<RenderJobRecord, HostErrorEnvelope> {
+    with_render_job_store(base_dir, |store| {
+        if let Some(existing) = store.jobs.iter().find(|job| {
+            job.kind == request.kind
+                && job.session_id == request.session_id
+                && job.capture_id == request.capture_id
+        }) {
+            return Ok((existing.clone(), false));
+        }
+
+        let job = RenderJobRecord {
+            job_id: generate_render_job_id(request.kind, now_ms),
+            kind: request.kind,
+            session_id: request.session_id,
+            capture_id: request.capture_id,
+            request_id: request.request_id,
+            preset_id: request.preset_id,
+            preset_version: request.preset_version,
+            source_asset_path: request.source_asset_path,
+            status: RenderJobStatus::Queued,
+            attempts: 0,
+            enqueued_at_ms: now_ms,
+            next_attempt_at_ms: now_ms,
+            last_reason_code: None,
+        };
+        store.jobs.push(job.clone());
+
+        Ok((job, true))
+    })
+}
+
+/// Pending jobs in the order workers would claim them.
+pub fn list_render_jobs_in_dir(base_dir: &Path) -> Result<Vec<RenderJobRecord>, HostErrorEnvelope> {
+    let _store_guard = lock_render_job_store()?;
+    let mut jobs = read_render_job_store(base_dir)?.jobs;
+    jobs.sort_by_key(|job| (job.kind, job.enqueued_at_ms));
+
+    Ok(jobs)
+}
+
+/// Marks the highest-priority due job as running and counts the attempt.
+pub fn claim_next_render_job_in_dir(
+    base_dir: &Path,
+    now_ms: u64,
+) -> Result<Option<RenderJobRecord>, HostErrorEnvelope> {
+    with_render_job_store(base_dir, |store| {
+        let next_index = store
+            .jobs
+            .iter()
+            .enumerate()
+            .filter(|(_, job)| is_claimable(job, now_ms))
+            .min_by_key(|(_, job)| (job.kind, job.next_attempt_at_ms, job.enqueued_at_ms))
+            .map(|(index, _)| index);
+
+        Ok((
+            next_index.map(|index| start_attempt(&mut store.jobs[index])),
+            next_index.is_some(),
+        ))
+    })
+}
+
+/// Claims one specific job, for callers that run it inline instead of
+/// waiting for a worker. Returns `None` while it is running elsewhere or
+/// still backing off.
+pub fn claim_render_job_in_dir(
+    base_dir: &Path,
+    job_id: &str,
+    now_ms: u64,
+) -> Result<Option<RenderJobRecord>, HostErrorEnvelope> {
+    with_render_job_store(base_dir, |store| {
+        let claimed = store
+            .jobs
+            .iter_mut()
+            .find(|job| job.job_id == job_id && is_claimable(job, now_ms))
+            .map(start_attempt);
+        let changed = claimed.is_some();
+
+        Ok((claimed, changed))
+    })
+}
+
+/// Removes a finished job, or puts a failed one back with exponential backoff.
+pub fn settle_render_job_in_dir(
+    base_dir: &Path,
+    job_id: &str,
+    outcome: Result<(), &RenderWorkerError>,
+    now_ms: u64,
+) -> Result<RenderJobSettlement, HostErrorEnvelope> {
+    with_render_job_store(base_dir, |store| {
+        let Some(index) = store.jobs.iter().position(|job| job.job_id == job_id) else {
+            return Ok((RenderJobSettlement::Completed, false));
+        };
+
+        let error = match outcome {
+            Ok(()) => {
+                store.jobs.remove(index);
+                return Ok((RenderJobSettlement::Completed, true));
+            }
+            Err(error) => error,
+        };
+
+        if store.jobs[index].is_last_attempt() {
+            store.jobs.remove(index);
+            return Ok((RenderJobSettlement::Exhausted, true));
+        }
+
+        let job = &mut store.jobs[index];
+        job.status = RenderJobStatus::Queued;
+        job.next_attempt_at_ms = now_ms + render_job_retry_delay_ms(job.attempts);
+        job.last_reason_code = Some(error.reason_code.into());
+
+        Ok((
+            RenderJobSettlement::RetryScheduled {
+                next_attempt_at_ms: job.next_attempt_at_ms,
+            },
+            true,
+        ))
+    })
+}
+
+/// Requeues jobs left `running` by a previous process so they run again
+/// right away. Returns how many jobs are pending after the pass.
+pub fn resume_render_jobs_in_dir(base_dir: &Path, now_ms: u64) -> Result<usize, HostErrorEnvelope> {
+    with_render_job_store(base_dir, |store| {
+        let mut changed = false;
+        for job in store
+            .jobs
+            .iter_mut()
+            .filter(|job| job.status == RenderJobStatus::Running)
+        {
+            job.status = RenderJobStatus::Queued;
+            job.next_attempt_at_ms = now_ms;
+            changed = true;
+        }
+
+        Ok((store.jobs.len(), changed))
+    })
+}
+
+/// Claims, runs and settles a single job with the given executor.
+pub fn run_render_job(
+    base_dir: &Path,
+    job: &RenderJobRecord,
+    executor: RenderJobExecutor,
+) -> Result<RenderJobSettlement, HostErrorEnvelope> {
+    let outcome = executor(base_dir, job);
+    if let Err(error) = outcome.as_ref() {
+        log::warn!(
+            "render_job_failed kind={} session={} capture_id={} attempt={} reason_code={} detail={}",
+            job.kind.label(),
+            job.session_id,
+            job.capture_id,
+            job.attempts,
+            error.reason_code,
+            error.operator_detail
+        );
+    }
+
+    settle_render_job_in_dir(
+        base_dir,
+        &job.job_id,
+        outcome.as_ref().map(|_| ()),
+        current_time_ms(),
+    )
+}
+
+/// Starts background workers for the base dir until `MAX_IN_FLIGHT_RENDER_JOBS`
+/// are running. Workers exit once the queue is empty.
+pub fn ensure_render_job_workers_in_dir(base_dir: &Path, executor: RenderJobExecutor) {
+    let Ok(mut workers) = RENDER_JOB_WORKERS.lock() else {
+        return;
+    };
+    let running = workers.entry(base_dir.to_path_buf()).or_insert(0);
+
+    while *running < MAX_IN_FLIGHT_RENDER_JOBS {
+        *running += 1;
+        let base_dir = base_dir.to_path_buf();
+        thread::spawn(move || run_render_job_worker(base_dir, executor));
+    }
+}
+
+fn run_render_job_worker(base_dir: PathBuf, executor: RenderJobExecutor) {
+    loop {
+        let claimed = match claim_next_render_job_in_dir(&base_dir, current_time_ms()) {
+            Ok(claimed) => claimed,
+            Err(error) => {
+                log::warn!("render_job_claim_failed detail={}", error.message);
+                None
+            }
+        };
+
+        if let Some(job) = claimed {
+            if let Err(error) = run_render_job(&base_dir, &job, executor) {
+                log::warn!(
+                    "render_job_settle_failed job_id={} detail={}",
+                    job.job_id,
+                    error.message
+                );
+            }
+            continue;
+        }
+
+        // Check for remaining work while holding the worker registry so a job
+        // enqueued right now either sees this worker or gets a fresh one.
+        let Ok(mut workers) = RENDER_JOB_WORKERS.lock() else {
+            return;
+        };
+        let next_due_at_ms = list_render_jobs_in_dir(&base_dir)
+            .unwrap_or_default()
+            .iter()
+            .filter(|job| job.status == RenderJobStatus::Queued)
+            .map(|job| job.next_attempt_at_ms)
+            .min();
+        let Some(next_due_at_ms) = next_due_at_ms else {
+            if let Some(running) = workers.get_mut(&base_dir) {
+                *running = running.saturating_sub(1);
+                if *running == 0 {
+                    workers.remove(&base_dir);
+                }
+            }
+            return;
+        };
+        drop(workers);
+
+        let wait_ms = next_due_at_ms
+            .saturating_sub(current_time_ms())
+            .clamp(1, RENDER_JOB_WORKER_MAX_SLEEP_MS);
+        thread::sleep(Duration::from_millis(wait_ms));
+    }
+}
+
+fn is_claimable(job: &RenderJobRecord, now_ms: u64) -> bool {
+    job.status == RenderJobStatus::Queued && job.next_attempt_at_ms <= now_ms
+}
+
+fn start_attempt(job: &mut RenderJobRecord) -> RenderJobRecord {
+    job.status = RenderJobStatus::Running;
+    job.attempts += 1;
+
+    job.clone()
+}
+
+fn render_job_retry_delay_ms(attempts: u32) -> u64 {
+    RENDER_JOB_RETRY_BASE_DELAY_MS
+        .saturating_mul(1 << attempts.saturating_sub(1).min(16))
+        .min(RENDER_JOB_RETRY_MAX_DELAY_MS)
+}
+
+fn with_render_job_store<T>(
+    base_dir: &Path,
+    update: impl FnOnce(&mut RenderJobStore) -> Result<(T, bool), HostErrorEnvelope>,
+) -> Result<T, HostErrorEnvelope> {
+    let _store_guard = lock_render_job_store()?;
+    let mut store = read_render_job_store(base_dir)?;
+    let (value, changed) = update(&mut store)?;
+
+    if changed {
+        write_render_job_store(base_dir, &store)?;
+    }
+
+    Ok(value)
+}
+
+fn lock_render_job_store() -> Result<std::sync::MutexGuard<'static, ()>, HostErrorEnvelope> {
+    RENDER_JOB_STORE_LOCK.lock().map_err(|_| {
+        HostErrorEnvelope::persistence(
+            "렌더 작업 대기열을 잠그지 못했어요. 잠시 후 다시 시도해 주세요.",
+        )
+    })
+}
+
+fn read_render_job_store(base_dir: &Path) -> Result<RenderJobStore, HostErrorEnvelope> {
+    let path = resolve_render_job_queue_path(base_dir);
+    let bytes = match fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(RenderJobStore {
+                schema_version: RENDER_JOB_QUEUE_SCHEMA_VERSION.into(),
+                jobs: Vec::new(),
+            });
+        }
+        Err(error) => {
+            return Err(HostErrorEnvelope::persistence(format!(
+                "렌더 작업 대기열을 읽지 못했어요: {error}"
+            )));
+        }
+    };
+
+    match serde_json::from_slice::<RenderJobStore>(&bytes) {
+        Ok(store) if store.schema_version == RENDER_JOB_QUEUE_SCHEMA_VERSION => Ok(store),
+        _ => {
+            // A torn or foreign queue file must not wedge rendering; the
+            // affected captures are re-requested by readiness and handoff.
+            log::warn!(
+                "render_job_queue_reset path={} reason=unreadable-store",
+                path.to_string_lossy()
+            );
+            Ok(RenderJobStore {
+                schema_version: RENDER_JOB_QUEUE_SCHEMA_VERSION.into(),
+                jobs: Vec::new(),
+            })
+        }
+    }
+}
+
+fn write_render_job_store(
+    base_dir: &Path,
+    store: &RenderJobStore,
+) -> Result<(), HostErrorEnvelope> {
+    let path = resolve_render_job_queue_path(base_dir);
+    let map_error = |error: std::io::Error| {
+        HostErrorEnvelope::persistence(format!("렌더 작업 대기열을 저장하지 못했어요: {error}"))
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(map_error)?;
+    }
+
+    let bytes = serde_json::to_vec_pretty(store).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("렌더 작업 대기열을 직렬화하지 못했어요: {error}"))
+    })?;
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, bytes).map_err(map_error)?;
+    fs::rename(&temp_path, &path).map_err(|error| {
+        let _ = fs::remove_file(&temp_path);
+        map_error(error)
+    })
+}
+
+fn generate_render_job_id(kind: RenderJobKind, now_ms: u64) -> String {
+    format!(
+        "render_{}_{now_ms:x}{:04x}",
+        kind.label(),
+        RENDER_JOB_COUNTER.fetch_add(1, Ordering::Relaxed) & 0xFFFF
+    )
+}
+
+fn current_time_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
@@ -0,0 +1,237 @@
+//! Composes the finals of a multi-shot layout session onto the layout
+//! canvas. Each slot is cover-fitted: centre-cropped to the slot's aspect
+//! ratio, then resized.
+
+use std::{fs, path::Path};
+
+use crate::{
+    preset::preset_bundle::PublishedPresetShotLayout,
+    session::{
+        session_manifest::{ComposedLayoutFinal, SessionCaptureRecord, SessionShotLayout},
+        session_paths::SessionPaths,
+    },
+};
+
+use super::{
+    append_render_event, current_time_ms,
+    jpeg_codec::{decode_jpeg, downscale_to_fit, encode_jpeg, read_jpeg_frame_info, RgbImage},
+    promote_render_output, resolve_runtime_bundle_in_dir, safe_render_failure_message,
+    validate_render_output, RenderIntent, RenderWorkerError,
+};
+
+const COMPOSED_JPEG_QUALITY: u8 = 92;
+
+pub fn render_shot_layout_final_in_dir(
+    base_dir: &Path,
+    session_id: &str,
+    layout: &SessionShotLayout,
+    captures: &[SessionCaptureRecord],
+) -> Result<ComposedLayoutFinal, RenderWorkerError> {
+    let intent = RenderIntent::Final;
+    let bundle = resolve_runtime_bundle_in_dir(
+        base_dir,
+        &layout.preset_id,
+        &layout.published_version,
+        intent,
+    )?;
+    let definition = bundle
+        .shot_layout
+        .filter(|definition| definition.layout_id == layout.layout_id)
+        .ok_or_else(|| {
+            compose_error(
+                "shot-layout-unavailable",
+                "bundle에 세션의 shot layout 정의가 없어요.",
+            )
+        })?;
+
+    let mut slot_finals = Vec::with_capacity(layout.slots.len());
+    for slot in &layout.slots {
+        let final_path = slot
+            .capture_id
+            .as_deref()
+            .and_then(|capture_id| {
+                captures
+                    .iter()
+                    .find(|capture| capture.capture_id == capture_id)
+            })
+            .and_then(|capture| capture.final_asset.asset_path.as_deref())
+            .ok_or_else(|| {
+                compose_error(
+                    "shot-layout-slot-missing",
+                    &format!("slot {}의 final 사진이 아직 없어요.", slot.slot_id),
+                )
+            })?;
+        slot_finals.push((slot.slot_id.as_str(), Path::new(final_path)));
+    }
+
+    let canvas = compose_canvas(&definition, &slot_finals)?;
+    let paths = SessionPaths::new(base_dir, session_id);
+    let output_path = paths
+        .renders_finals_dir
+        .join(format!("layout-{}.jpg", layout.layout_id));
+    let staging_output_path = paths
+        .renders_finals_dir
+        .join(format!("layout-{}.final-rendering.jpg", layout.layout_id));
+    let encoded = encode_jpeg(&canvas, COMPOSED_JPEG_QUALITY).map_err(|error| {
+        compose_error(
+            "render-output-write-failed",
+            &format!("composed final을 인코딩하지 못했어요: {error}"),
+        )
+    })?;
+    fs::create_dir_all(&paths.renders_finals_dir)
+        .and_then(|_| fs::write(&staging_output_path, encoded))
+        .map_err(|error| {
+            compose_error(
+                "render-output-write-failed",
+                &format!("composed final을 저장하지 못했어요: {error}"),
+            )
+        })?;
+    validate_render_output(&staging_output_path, intent)
+        .and_then(|_| promote_render_output(&staging_output_path, &output_path, intent))
+        .inspect_err(|_| {
+            let _ = fs::remove_file(&staging_output_path);
+        })?;
+    let ready_at_ms =
+        current_time_ms().map_err(|error| compose_error("render-clock-unavailable", &error))?;
+
+    append_render_event(
+        &paths,
+        &format!("layout-{}", layout.layout_id),
+        None,
+        intent,
+        "layout-final-ready",
+        None,
+        Some(&format!(
+            "layoutId={};slots={};canvas={}x{}",
+            layout.layout_id,
+            layout.slots.len(),
+            definition.canvas_width,
+            definition.canvas_height
+        )),
+    );
+
+    Ok(ComposedLayoutFinal {
+        asset_path: output_path.to_string_lossy().into_owned(),
+        ready_at_ms,
+        capture_ids: layout.slot_capture_ids().map(str::to_string).collect(),
+    })
+}
+
+pub(super) fn compose_canvas(
+    definition: &PublishedPresetShotLayout,
+    slot_finals: &[(&str, &Path)],
+) -> Result<RgbImage, RenderWorkerError> {
+    let width = definition.canvas_width as usize;
+    let height = definition.canvas_height as usize;
+    let mut canvas = RgbImage {
+        width,
+        height,
+        pixels: definition
+            .background_color
+            .iter()
+            .copied()
+            .cycle()
+            .take(width * height * 3)
+            .collect(),
+    };
+
+    for (slot, (slot_id, final_path)) in definition.slots.iter().zip(slot_finals) {
+        let left = (slot.rect.x * width as f32).round() as usize;
+        let top = (slot.rect.y * height as f32).round() as usize;
+        let slot_width = ((slot.rect.width * width as f32).round() as usize)
+            .clamp(1, width.saturating_sub(left).max(1));
+        let slot_height = ((slot.rect.height * height as f32).round() as usize)
+            .clamp(1, height.saturating_sub(top).max(1));
+        let image = read_slot_final(final_path, slot_width, slot_height).map_err(|detail| {
+            compose_error(
+                "shot-layout-slot-unreadable",
+                &format!("slot {slot_id}의 final 사진을 읽지 못했어요: {detail}"),
+            )
+        })?;
+        let fitted = cover_fit(image, slot_width, slot_height);
+
+        for row in 0..slot_height.min(height - top) {
+            let visible = slot_width.min(width - left);
+            let target = ((top + row) * width + left) * 3;
+            let source = row * slot_width * 3;
+            canvas.pixels[target..target + visible * 3]
+                .copy_from_slice(&fitted.pixels[source..source + visible * 3]);
+        }
+    }
+
+    Ok(canvas)
+}
+
+/// Uses the cheap 1/8 decode when it still covers the slot.
+fn read_slot_final(path: &Path, slot_width: usize, slot_height: usize) -> Result<RgbImage, String> {
+    let bytes = fs::read(path).map_err(|error| error.to_string())?;
+    let frame = read_jpeg_frame_info(&bytes).ok_or("JPEG frame header is missing")?;
+    let scale = if frame.width / 8 >= slot_width && frame.height / 8 >= slot_height {
+        8
+    } else {
+        1
+    };
+
+    decode_jpeg(&bytes, scale)
+}
+
+fn cover_fit(image: RgbImage, width: usize, height: usize) -> RgbImage {
+    let source_ratio = image.width as f64 / image.height as f64;
+    let target_ratio = width as f64 / height as f64;
+    let (crop_width, crop_height) = if source_ratio > target_ratio {
+        (
+            ((image.height as f64 * target_ratio).round() as usize).max(1),
+            image.height,
+        )
+    } else {
+        (
+            image.width,
+            ((image.width as f64 / target_ratio).round() as usize).max(1),
+        )
+    };
+    let crop_left = (image.width - crop_width) / 2;
+    let crop_top = (image.height - crop_height) / 2;
+    let mut cropped = Vec::with_capacity(crop_width * crop_height * 3);
+    for row in crop_top..crop_top + crop_height {
+        let start = (row * image.width + crop_left) * 3;
+        cropped.extend_from_slice(&image.pixels[start..start + crop_width * 3]);
+    }
+
+    let reduced = downscale_to_fit(
+        RgbImage {
+            width: crop_width,
+            height: crop_height,
+            pixels: cropped,
+        },
+        width,
+        height,
+    );
+    if reduced.width == width && reduced.height == height {
+        return reduced;
+    }
+
+    // Upscales small finals and absorbs rounding from the box filter.
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        let source_y = (y * reduced.height / height).min(reduced.height - 1);
+        for x in 0..width {
+            let source_x = (x * reduced.width / width).min(reduced.width - 1);
+            let offset = (source_y * reduced.width + source_x) * 3;
+            pixels.extend_from_slice(&reduced.pixels[offset..offset + 3]);
+        }
+    }
+
+    RgbImage {
+        width,
+        height,
+        pixels,
+    }
+}
+
+fn compose_error(reason_code: &'static str, operator_detail: &str) -> RenderWorkerError {
+    RenderWorkerError {
+        reason_code,
+        customer_message: safe_render_failure_message(RenderIntent::Final),
+        operator_detail: operator_detail.into(),
+    }
+}
@@ -0,0 +1,373 @@
+pub mod reservation_source;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{LazyLock, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    branch_config::session_packages::{resolve_session_package_in_dir, SessionPackageTier},
+    contracts::dto::{HostErrorEnvelope, ReservationLookupInputDto, SessionStartInputDto},
+    session::{
+        session_manifest::{
+            current_timestamp, rfc3339_to_unix_seconds, validate_session_start_input,
+            SessionReservation,
+        },
+        session_paths::SessionPaths,
+        session_repository::{
+            generate_session_id, start_validated_session_in_dir, SessionStartResultDto,
+        },
+    },
+};
+
+use reservation_source::{
+    resolve_reservation_dir, resolve_reservation_source, ReservationRecord, ReservationSource,
+    RESERVATION_STATUS_BOOKED, RESERVATION_STATUS_CANCELLED, RESERVATION_STATUS_USED,
+};
+
+pub const RESERVATION_SUMMARY_SCHEMA_VERSION: &str = "reservation-summary/v1";
+
+const RESERVATION_LEDGER_SCHEMA_VERSION: &str = "reservation-redemption-ledger/v1";
+const RESERVATION_LEDGER_FILE: &str = "redeemed.json";
+const MAX_RESERVATION_ID_LENGTH: usize = 64;
+const REDEMPTION_STATUS_CLAIMED: &str = "claimed";
+const REDEMPTION_STATUS_REDEEMED: &str = "redeemed";
+
+static RESERVATION_LEDGER_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservationSummaryDto {
+    pub schema_version: String,
+    pub reservation_id: String,
+    pub source_id: String,
+    pub customer_name: String,
+    pub phone_last_four: String,
+    pub package: SessionPackageTier,
+    pub allowed_preset_ids: Vec<String>,
+    pub expires_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservationRedemption {
+    pub reservation_id: String,
+    pub source_id: String,
+    pub session_id: String,
+    pub redeemed_at: String,
+    /// `claimed` while the session is being created, `redeemed` once it
+    /// exists. Entries written before claims existed are redemptions.
+    #[serde(default = "default_redemption_status")]
+    pub status: String,
+}
+
+impl ReservationRedemption {
+    /// A claim left behind by a crash only counts if its session was created.
+    fn is_in_use(&self, base_dir: &Path) -> bool {
+        self.status == REDEMPTION_STATUS_REDEEMED
+            || SessionPaths::new(base_dir, &self.session_id)
+                .session_root
+                .exists()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReservationLedger {
+    schema_version: String,
+    #[serde(default)]
+    entries: Vec<ReservationRedemption>,
+}
+
+struct RedeemableReservation {
+    record: ReservationRecord,
+    validated_input: SessionStartInputDto,
+    package: SessionPackageTier,
+}
+
+pub fn lookup_reservation_in_dir(
+    base_dir: &Path,
+    input: ReservationLookupInputDto,
+) -> Result<ReservationSummaryDto, HostErrorEnvelope> {
+    let source = resolve_reservation_source(base_dir)?;
+
+    lookup_reservation_with_source(base_dir, input, source.as_ref(), SystemTime::now())
+}
+
+/// Checks a booking without using it so the booth can show who it is for.
+pub fn lookup_reservation_with_source(
+    base_dir: &Path,
+    input: ReservationLookupInputDto,
+    source: &dyn ReservationSource,
+    now: SystemTime,
+) -> Result<ReservationSummaryDto, HostErrorEnvelope> {
+    let redeemable = resolve_redeemable_reservation(base_dir, &input, source, now)?;
+
+    Ok(ReservationSummaryDto {
+        schema_version: RESERVATION_SUMMARY_SCHEMA_VERSION.into(),
+        reservation_id: redeemable.record.reservation_id,
+        source_id: source.source_id().into(),
+        customer_name: redeemable.validated_input.name,
+        phone_last_four: redeemable.validated_input.phone_last_four,
+        package: redeemable.package,
+        allowed_preset_ids: redeemable.record.allowed_preset_ids,
+        expires_at: redeemable.record.expires_at,
+    })
+}
+
+pub fn start_reserved_session_in_dir(
+    base_dir: &Path,
+    input: ReservationLookupInputDto,
+) -> Result<SessionStartResultDto, HostErrorEnvelope> {
+    let source = resolve_reservation_source(base_dir)?;
+
+    start_reserved_session_with_source(base_dir, input, source.as_ref(), SystemTime::now())
+}
+
+/// Starts a session pre-filled from a booking. The reservation is claimed in
+/// the booth ledger and at the source first, so a second tap on the same code
+/// is rejected instead of creating a twin session. The claim is confirmed once
+/// the session exists and released if creating it fails, so the customer can
+/// retry.
+pub fn start_reserved_session_with_source(
+    base_dir: &Path,
+    input: ReservationLookupInputDto,
+    source: &dyn ReservationSource,
+    now: SystemTime,
+) -> Result<SessionStartResultDto, HostErrorEnvelope> {
+    let _guard = RESERVATION_LEDGER_LOCK.lock().map_err(|_| {
+        HostErrorEnvelope::persistence("예약 사용 기록을 잠그지 못했어요. 다시 시도해 주세요.")
+    })?;
+    let redeemable = resolve_redeemable_reservation(base_dir, &input, source, now)?;
+    let reservation_id = redeemable.record.reservation_id.clone();
+    let session_id = generate_session_id();
+    let redeemed_at = current_timestamp(now)?;
+
+    let mut ledger = read_reservation_ledger(base_dir)?;
+    ledger
+        .entries
+        .retain(|entry| entry.reservation_id != reservation_id);
+    ledger.entries.push(ReservationRedemption {
+        reservation_id: reservation_id.clone(),
+        source_id: source.source_id().into(),
+        session_id: session_id.clone(),
+        redeemed_at: redeemed_at.clone(),
+        status: REDEMPTION_STATUS_CLAIMED.into(),
+    });
+    write_reservation_ledger(base_dir, &ledger)?;
+
+    if let Err(error) = source.mark_redeemed(&reservation_id, &session_id) {
+        drop_reservation_claim(base_dir, &reservation_id, &session_id);
+        return Err(error);
+    }
+
+    let started = start_validated_session_in_dir(
+        base_dir,
+        session_id.clone(),
+        redeemable.validated_input,
+        Some(SessionReservation {
+            reservation_id: reservation_id.clone(),
+            source_id: source.source_id().into(),
+            allowed_preset_ids: redeemable.record.allowed_preset_ids,
+            redeemed_at,
+        }),
+    );
+    let result = match started {
+        Ok(result) => result,
+        Err(error) => {
+            if let Err(release_error) = source.release_redemption(&reservation_id, &session_id) {
+                log::warn!(
+                    "reservation_release_failed reservation_id={reservation_id} source={} reason={}",
+                    source.source_id(),
+                    release_error.message
+                );
+            }
+            drop_reservation_claim(base_dir, &reservation_id, &session_id);
+            return Err(error);
+        }
+    };
+
+    // The session root now exists, so the claim already counts as used even
+    // if confirming it in the ledger fails.
+    confirm_reservation_claim(base_dir, &reservation_id, &session_id);
+    log::info!(
+        "reservation_redeemed reservation_id={reservation_id} source={} session={session_id}",
+        source.source_id()
+    );
+
+    Ok(result)
+}
+
+pub fn resolve_reservation_ledger_path(base_dir: &Path) -> PathBuf {
+    resolve_reservation_dir(base_dir).join(RESERVATION_LEDGER_FILE)
+}
+
+fn resolve_redeemable_reservation(
+    base_dir: &Path,
+    input: &ReservationLookupInputDto,
+    source: &dyn ReservationSource,
+    now: SystemTime,
+) -> Result<RedeemableReservation, HostErrorEnvelope> {
+    let reservation_id = input.reservation_id.trim();
+    if reservation_id.is_empty()
+        || reservation_id.len() > MAX_RESERVATION_ID_LENGTH
+        || !reservation_id
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_')
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "예약 번호를 다시 확인해 주세요.",
+        ));
+    }
+
+    let record = source.lookup(reservation_id)?.ok_or_else(|| {
+        HostErrorEnvelope::validation_message(
+            "예약을 찾지 못했어요. 예약 번호를 다시 확인해 주세요.",
+        )
+    })?;
+    match record.status.as_str() {
+        RESERVATION_STATUS_BOOKED => {}
+        RESERVATION_STATUS_USED => {
+            return Err(HostErrorEnvelope::validation_message(
+                "이미 사용한 예약이에요.",
+            ))
+        }
+        RESERVATION_STATUS_CANCELLED => {
+            return Err(HostErrorEnvelope::validation_message("취소된 예약이에요."))
+        }
+        _ => {
+            return Err(HostErrorEnvelope::validation_message(
+                "지금은 사용할 수 없는 예약이에요. 직원에게 문의해 주세요.",
+            ))
+        }
+    }
+
+    if read_reservation_ledger(base_dir)?
+        .entries
+        .iter()
+        .any(|entry| entry.reservation_id == record.reservation_id && entry.is_in_use(base_dir))
+    {
+        return Err(HostErrorEnvelope::validation_message(
+            "이미 사용한 예약이에요.",
+        ));
+    }
+
+    let now_seconds = now
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    if now_seconds >= rfc3339_to_unix_seconds(&record.expires_at)? {
+        return Err(HostErrorEnvelope::validation_message(
+            "예약 시간이 지났어요. 직원에게 문의해 주세요.",
+        ));
+    }
+    if let Some(valid_from) = record.valid_from.as_deref() {
+        if now_seconds < rfc3339_to_unix_seconds(valid_from)? {
+            return Err(HostErrorEnvelope::validation_message(
+                "아직 예약 시간이 아니에요. 잠시 후 다시 시도해 주세요.",
+            ));
+        }
+    }
+
+    let validated_input = validate_session_start_input(&SessionStartInputDto {
+        name: record.customer_name.clone(),
+        phone_last_four: record.phone_last_four.clone(),
+        package_id: record.package_id.clone(),
+    })
+    .map_err(|_| {
+        HostErrorEnvelope::validation_message(
+            "예약의 고객 정보가 올바르지 않아요. 직원에게 문의해 주세요.",
+        )
+    })?;
+    let package = resolve_session_package_in_dir(base_dir, validated_input.package_id.as_deref())?;
+
+    Ok(RedeemableReservation {
+        record,
+        validated_input,
+        package,
+    })
+}
+
+fn confirm_reservation_claim(base_dir: &Path, reservation_id: &str, session_id: &str) {
+    let confirmed = read_reservation_ledger(base_dir).and_then(|mut ledger| {
+        for entry in &mut ledger.entries {
+            if entry.reservation_id == reservation_id && entry.session_id == session_id {
+                entry.status = REDEMPTION_STATUS_REDEEMED.into();
+            }
+        }
+        write_reservation_ledger(base_dir, &ledger)
+    });
+
+    if let Err(error) = confirmed {
+        log::warn!(
+            "reservation_confirm_failed reservation_id={reservation_id} session={session_id} reason={}",
+            error.message
+        );
+    }
+}
+
+fn drop_reservation_claim(base_dir: &Path, reservation_id: &str, session_id: &str) {
+    let dropped = read_reservation_ledger(base_dir).and_then(|mut ledger| {
+        ledger.entries.retain(|entry| {
+            entry.reservation_id != reservation_id || entry.session_id != session_id
+        });
+        write_reservation_ledger(base_dir, &ledger)
+    });
+
+    if let Err(error) = dropped {
+        log::warn!(
+            "reservation_claim_release_failed reservation_id={reservation_id} session={session_id} reason={}",
+            error.message
+        );
+    }
+}
+
+fn read_reservation_ledger(base_dir: &Path) -> Result<ReservationLedger, HostErrorEnvelope> {
+    let ledger_path = resolve_reservation_ledger_path(base_dir);
+
+    if !ledger_path.is_file() {
+        return Ok(ReservationLedger {
+            schema_version: RESERVATION_LEDGER_SCHEMA_VERSION.into(),
+            entries: Vec::new(),
+        });
+    }
+
+    let bytes = fs::read_to_string(&ledger_path).map_err(map_ledger_error)?;
+
+    serde_json::from_str(&bytes).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("예약 사용 기록을 읽지 못했어요: {error}"))
+    })
+}
+
+fn write_reservation_ledger(
+    base_dir: &Path,
+    ledger: &ReservationLedger,
+) -> Result<(), HostErrorEnvelope> {
+    let ledger_path = resolve_reservation_ledger_path(base_dir);
+    let temp_path = ledger_path.with_extension("json.tmp");
+    let bytes = serde_json::to_vec_pretty(ledger).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("예약 사용 기록을 직렬화하지 못했어요: {error}"))
+    })?;
+
+    fs::create_dir_all(resolve_reservation_dir(base_dir)).map_err(map_ledger_error)?;
+    fs::write(&temp_path, bytes).map_err(map_ledger_error)?;
+    if let Err(error) = fs::rename(&temp_path, &ledger_path) {
+        let _ = fs::remove_file(&temp_path);
+
+        return Err(map_ledger_error(error));
+    }
+
+    Ok(())
+}
+
+fn default_redemption_status() -> String {
+    REDEMPTION_STATUS_REDEEMED.into()
+}
+
+fn map_ledger_error(error: std::io::Error) -> HostErrorEnvelope {
+    HostErrorEnvelope::persistence(format!("예약 사용 기록을 남기지 못했어요: {error}"))
+}
@@ -0,0 +1,342 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    branch_http::{send_branch_http_request, BranchHttpError},
+    contracts::dto::HostErrorEnvelope,
+};
+
+pub const RESERVATION_URL_ENV: &str = "BOOTHY_RESERVATION_URL";
+pub const LOCAL_FILE_RESERVATION_SOURCE_ID: &str = "local-file";
+pub const HTTP_RESERVATION_SOURCE_ID: &str = "http";
+pub const RESERVATION_STATUS_BOOKED: &str = "booked";
+pub const RESERVATION_STATUS_USED: &str = "used";
+pub const RESERVATION_STATUS_CANCELLED: &str = "cancelled";
+
+const RESERVATIONS_JSON_FILE: &str = "reservations.json";
+const RESERVATIONS_CSV_FILE: &str = "reservations.csv";
+const HTTP_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_HTTP_RESPONSE_BYTES: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservationRecord {
+    pub reservation_id: String,
+    pub customer_name: String,
+    pub phone_last_four: String,
+    #[serde(default)]
+    pub package_id: Option<String>,
+    #[serde(default)]
+    pub allowed_preset_ids: Vec<String>,
+    #[serde(default)]
+    pub valid_from: Option<String>,
+    pub expires_at: String,
+    #[serde(default = "default_reservation_status")]
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReservationFile {
+    #[serde(default)]
+    reservations: Vec<ReservationRecord>,
+}
+
+/// Where bookings come from. Sources only answer lookups and hear about
+/// redemptions; expiry and reuse rules live in the booth so every source is
+/// held to the same policy.
+pub trait ReservationSource: Send + Sync {
+    fn source_id(&self) -> &'static str;
+
+    fn lookup(&self, reservation_id: &str) -> Result<Option<ReservationRecord>, HostErrorEnvelope>;
+
+    fn mark_redeemed(
+        &self,
+        reservation_id: &str,
+        session_id: &str,
+    ) -> Result<(), HostErrorEnvelope>;
+
+    /// Undoes `mark_redeemed` when the booth could not create the session.
+    fn release_redemption(
+        &self,
+        reservation_id: &str,
+        session_id: &str,
+    ) -> Result<(), HostErrorEnvelope>;
+}
+
+/// Reads `reservations.json` or `reservations.csv` exported by the branch's
+/// booking tool. The CSV needs a header row using the JSON field names; preset
+/// ids are `;`-separated and quoting is not supported.
+#[derive(Debug, Clone)]
+pub struct LocalFileReservationSource {
+    reservation_dir: PathBuf,
+}
+
+impl LocalFileReservationSource {
+    pub fn new(reservation_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            reservation_dir: reservation_dir.into(),
+        }
+    }
+}
+
+impl ReservationSource for LocalFileReservationSource {
+    fn source_id(&self) -> &'static str {
+        LOCAL_FILE_RESERVATION_SOURCE_ID
+    }
+
+    fn lookup(&self, reservation_id: &str) -> Result<Option<ReservationRecord>, HostErrorEnvelope> {
+        let json_path = self.reservation_dir.join(RESERVATIONS_JSON_FILE);
+        let csv_path = self.reservation_dir.join(RESERVATIONS_CSV_FILE);
+        let reservations = if json_path.is_file() {
+            let bytes = fs::read_to_string(&json_path).map_err(map_reservation_file_error)?;
+            serde_json::from_str::<ReservationFile>(&bytes)
+                .map_err(|error| {
+                    HostErrorEnvelope::persistence(format!("예약 목록을 읽지 못했어요: {error}"))
+                })?
+                .reservations
+        } else if csv_path.is_file() {
+            parse_reservation_csv(
+                &fs::read_to_string(&csv_path).map_err(map_reservation_file_error)?,
+            )?
+        } else {
+            Vec::new()
+        };
+
+        Ok(reservations
+            .into_iter()
+            .find(|reservation| reservation.reservation_id == reservation_id))
+    }
+
+    fn mark_redeemed(
+        &self,
+        _reservation_id: &str,
+        _session_id: &str,
+    ) -> Result<(), HostErrorEnvelope> {
+        // Exported files are read-only snapshots; the booth ledger records use.
+        Ok(())
+    }
+
+    fn release_redemption(
+        &self,
+        _reservation_id: &str,
+        _session_id: &str,
+    ) -> Result<(), HostErrorEnvelope> {
+        Ok(())
+    }
+}
+
+/// Talks to a booking service over plain HTTP on the branch network:
+/// `GET <base>/reservations/<id>`, `POST <base>/reservations/<id>/redeem` and
+/// `POST <base>/reservations/<id>/release`. A 409 from the redeem call means
+/// another booth already used the booking.
+#[derive(Debug, Clone)]
+pub struct HttpReservationSource {
+    host: String,
+    port: u16,
+    path_prefix: String,
+}
+
+impl HttpReservationSource {
+    pub fn new(base_url: &str) -> Result<Self, HostErrorEnvelope> {
+        let invalid =
+            || HostErrorEnvelope::validation_message("예약 서버 주소는 http:// 로 시작해야 해요.");
+        let rest = base_url
+            .trim()
+            .strip_prefix("http://")
+            .ok_or_else(invalid)?;
+        let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse::<u16>().map_err(|_| invalid())?),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            host: host.into(),
+            port,
+            path_prefix: path.trim_end_matches('/').into(),
+        })
+    }
+
+    fn request(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&str>,
+    ) -> Result<(u16, String), HostErrorEnvelope> {
+        let response = send_branch_http_request(
+            method,
+            &format!(
+                "http://{}:{}{}{path}",
+                self.host, self.port, self.path_prefix
+            ),
+            "application/json",
+            body,
+            HTTP_TIMEOUT,
+            MAX_HTTP_RESPONSE_BYTES,
+        )
+        .map_err(|error| match error {
+            BranchHttpError::TooLarge { .. } => {
+                HostErrorEnvelope::persistence("예약 서버 응답이 너무 커서 읽지 않았어요.")
+            }
+            BranchHttpError::Transport(error) => {
+                HostErrorEnvelope::persistence(format!("예약 서버에 연결하지 못했어요: {error}"))
+            }
+        })?;
+
+        Ok((
+            response.status,
+            String::from_utf8_lossy(&response.body).into_owned(),
+        ))
+    }
+}
+
+impl ReservationSource for HttpReservationSource {
+    fn source_id(&self) -> &'static str {
+        HTTP_RESERVATION_SOURCE_ID
+    }
+
+    fn lookup(&self, reservation_id: &str) -> Result<Option<ReservationRecord>, HostErrorEnvelope> {
+        let (status, body) =
+            self.request("GET", &format!("/reservations/{reservation_id}"), None)?;
+
+        match status {
+            200 => serde_json::from_str(&body).map(Some).map_err(|error| {
+                HostErrorEnvelope::persistence(format!("예약 정보를 읽지 못했어요: {error}"))
+            }),
+            404 => Ok(None),
+            _ => Err(HostErrorEnvelope::persistence(format!(
+                "예약 서버가 조회를 거절했어요. (HTTP {status})"
+            ))),
+        }
+    }
+
+    fn mark_redeemed(
+        &self,
+        reservation_id: &str,
+        session_id: &str,
+    ) -> Result<(), HostErrorEnvelope> {
+        let body = serde_json::json!({ "sessionId": session_id }).to_string();
+        let (status, _) = self.request(
+            "POST",
+            &format!("/reservations/{reservation_id}/redeem"),
+            Some(&body),
+        )?;
+
+        match status {
+            200..=299 => Ok(()),
+            409 => Err(HostErrorEnvelope::validation_message(
+                "이미 사용한 예약이에요.",
+            )),
+            _ => Err(HostErrorEnvelope::persistence(format!(
+                "예약 사용 처리를 하지 못했어요. (HTTP {status})"
+            ))),
+        }
+    }
+
+    fn release_redemption(
+        &self,
+        reservation_id: &str,
+        session_id: &str,
+    ) -> Result<(), HostErrorEnvelope> {
+        let body = serde_json::json!({ "sessionId": session_id }).to_string();
+        let (status, _) = self.request(
+            "POST",
+            &format!("/reservations/{reservation_id}/release"),
+            Some(&body),
+        )?;
+
+        match status {
+            200..=299 | 404 => Ok(()),
+            _ => Err(HostErrorEnvelope::persistence(format!(
+                "예약 사용 취소를 하지 못했어요. (HTTP {status})"
+            ))),
+        }
+    }
+}
+
+pub fn resolve_reservation_dir(base_dir: &Path) -> PathBuf {
+    base_dir.join("reservations")
+}
+
+/// Uses the booking service when `BOOTHY_RESERVATION_URL` is set, otherwise the
+/// exported files under `reservations/`.
+pub fn resolve_reservation_source(
+    base_dir: &Path,
+) -> Result<Box<dyn ReservationSource>, HostErrorEnvelope> {
+    match env::var(RESERVATION_URL_ENV) {
+        Ok(url) if !url.trim().is_empty() => Ok(Box::new(HttpReservationSource::new(&url)?)),
+        _ => Ok(Box::new(LocalFileReservationSource::new(
+            resolve_reservation_dir(base_dir),
+        ))),
+    }
+}
+
+fn parse_reservation_csv(contents: &str) -> Result<Vec<ReservationRecord>, HostErrorEnvelope> {
+    let mut lines = contents
+        .lines()
+        .map(|line| line.trim_start_matches('\u{feff}').trim())
+        .filter(|line| !line.is_empty());
+    let Some(header) = lines.next() else {
+        return Ok(Vec::new());
+    };
+    let columns: Vec<&str> = header.split(',').map(str::trim).collect();
+
+    lines
+        .map(|line| {
+            let row: HashMap<&str, &str> = columns
+                .iter()
+                .copied()
+                .zip(line.split(',').map(str::trim))
+                .collect();
+            let optional = |column: &str| {
+                row.get(column)
+                    .filter(|value| !value.is_empty())
+                    .map(|value| value.to_string())
+            };
+            let required = |column: &str| {
+                optional(column).ok_or_else(|| {
+                    HostErrorEnvelope::persistence(format!(
+                        "예약 목록에 {column} 값이 비어 있어요."
+                    ))
+                })
+            };
+
+            Ok(ReservationRecord {
+                reservation_id: required("reservationId")?,
+                customer_name: required("customerName")?,
+                phone_last_four: required("phoneLastFour")?,
+                package_id: optional("packageId"),
+                allowed_preset_ids: optional("allowedPresetIds")
+                    .map(|ids| {
+                        ids.split(';')
+                            .map(str::trim)
+                            .filter(|id| !id.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                valid_from: optional("validFrom"),
+                expires_at: required("expiresAt")?,
+                status: optional("status").unwrap_or_else(default_reservation_status),
+            })
+        })
+        .collect()
+}
+
+fn default_reservation_status() -> String {
+    RESERVATION_STATUS_BOOKED.into()
+}
+
+fn map_reservation_file_error(error: std::io::Error) -> HostErrorEnvelope {
+    HostErrorEnvelope::persistence(format!("예약 목록을 읽지 못했어요: {error}"))
+}
@@ -0,0 +1,156 @@
+//! Versioned upgrades for `session.json`. Each step rewrites the raw JSON of
+//! one schema version into the next, so the typed manifest only ever has to
+//! understand the current shape. Manifests written by a newer build are
+//! refused rather than guessed at.
+
+use serde_json::{Map, Value};
+
+use crate::{contracts::dto::HostErrorEnvelope, session::session_manifest};
+
+const SESSION_MANIFEST_SCHEMA_PREFIX: &str = "session-manifest/v";
+const LEGACY_POST_END_EVALUATED_AT: &str = "1970-01-01T00:00:00Z";
+
+struct ManifestMigration {
+    from: &'static str,
+    to: &'static str,
+    migrate: fn(&mut Map<String, Value>),
+}
+
+/// Oldest first; every `to` is the next step's `from`, and the last `to` is
+/// the current schema version.
+const MANIFEST_MIGRATIONS: &[ManifestMigration] = &[ManifestMigration {
+    from: "session-manifest/v1",
+    to: "session-manifest/v2",
+    migrate: migrate_v1_to_v2,
+}];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestMigrationOutcome {
+    pub from_version: String,
+    pub to_version: String,
+    /// Versions passed through, starting with `from_version`.
+    pub steps: Vec<String>,
+}
+
+/// Upgrades `value` in place to the current schema. Returns `None` when the
+/// manifest was already current.
+pub fn migrate_session_manifest_value(
+    value: &mut Value,
+) -> Result<Option<ManifestMigrationOutcome>, HostErrorEnvelope> {
+    let manifest = value
+        .as_object_mut()
+        .ok_or_else(|| HostErrorEnvelope::persistence("세션 매니페스트 형식이 올바르지 않아요."))?;
+    let from_version = manifest
+        .get("schemaVersion")
+        .and_then(Value::as_str)
+        .ok_or_else(|| {
+            HostErrorEnvelope::persistence("세션 매니페스트의 schemaVersion을 확인하지 못했어요.")
+        })?
+        .to_string();
+    if from_version == session_manifest::SESSION_MANIFEST_SCHEMA_VERSION {
+        return Ok(None);
+    }
+    if is_newer_than_supported(&from_version) {
+        return Err(HostErrorEnvelope::persistence(format!(
+            "이 세션은 더 새로운 버전의 앱에서 저장돼 열 수 없어요. 앱을 업데이트해 주세요. ({from_version})"
+        )));
+    }
+
+    let mut steps = Vec::new();
+    let mut version = from_version.clone();
+    while version != session_manifest::SESSION_MANIFEST_SCHEMA_VERSION {
+        let migration = MANIFEST_MIGRATIONS
+            .iter()
+            .find(|migration| migration.from == version)
+            .ok_or_else(|| {
+                HostErrorEnvelope::persistence(format!(
+                    "지원하지 않는 세션 매니페스트 버전이에요: {version}"
+                ))
+            })?;
+        (migration.migrate)(manifest);
+        manifest.insert("schemaVersion".into(), Value::from(migration.to));
+        steps.push(version);
+        version = migration.to.into();
+    }
+
+    Ok(Some(ManifestMigrationOutcome {
+        from_version,
+        to_version: version,
+        steps,
+    }))
+}
+
+fn is_newer_than_supported(version: &str) -> bool {
+    let current = parse_schema_revision(session_manifest::SESSION_MANIFEST_SCHEMA_VERSION);
+    matches!(
+        (parse_schema_revision(version), current),
+        (Some(revision), Some(current)) if revision > current
+    )
+}
+
+fn parse_schema_revision(version: &str) -> Option<u32> {
+    version
+        .strip_prefix(SESSION_MANIFEST_SCHEMA_PREFIX)?
+        .parse()
+        .ok()
+}
+
+/// v2 makes two things explicit that v1 readers patched on every load:
+/// captures carry the preset identity they were taken with, and post-end
+/// truth always has `evaluatedAt`.
+fn migrate_v1_to_v2(manifest: &mut Map<String, Value>) {
+    let active_preset = manifest.get("activePreset").and_then(Value::as_object);
+    let fallback_preset_id = manifest
+        .get("activePresetId")
+        .filter(|value| value.is_string())
+        .or_else(|| active_preset.and_then(|preset| preset.get("presetId")))
+        .cloned()
+        .unwrap_or(Value::Null);
+    let fallback_published_version = active_preset
+        .and_then(|preset| preset.get("publishedVersion"))
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    let fallback_display_name = manifest
+        .get("activePresetDisplayName")
+        .cloned()
+        .unwrap_or(Value::Null);
+
+    if let Some(captures) = manifest.get_mut("captures").and_then(Value::as_array_mut) {
+        for capture in captures.iter_mut().filter_map(Value::as_object_mut) {
+            let matches_active_preset =
+                fallback_published_version
+                    .as_deref()
+                    .is_some_and(|published_version| {
+                        capture.get("activePresetVersion").and_then(Value::as_str)
+                            == Some(published_version)
+                    });
+            if !matches_active_preset {
+                continue;
+            }
+            if is_missing(capture.get("activePresetId")) {
+                capture.insert("activePresetId".into(), fallback_preset_id.clone());
+            }
+            if is_missing(capture.get("activePresetDisplayName"))
+                && capture.get("activePresetId") == Some(&fallback_preset_id)
+            {
+                capture.insert(
+                    "activePresetDisplayName".into(),
+                    fallback_display_name.clone(),
+                );
+            }
+        }
+    }
+
+    if let Some(post_end) = manifest.get_mut("postEnd").and_then(Value::as_object_mut) {
+        if is_missing(post_end.get("evaluatedAt")) {
+            post_end.insert(
+                "evaluatedAt".into(),
+                Value::from(LEGACY_POST_END_EVALUATED_AT),
+            );
+        }
+    }
+}
+
+fn is_missing(value: Option<&Value>) -> bool {
+    value.is_none_or(Value::is_null)
+}
@@ -1,3 +1,6 @@
+pub mod manifest_migration;
+pub mod session_journal;
 pub mod session_manifest;
 pub mod session_paths;
 pub mod session_repository;
+pub mod session_retention;
@@ -0,0 +1,704 @@
+//! Append-only event journal kept next to `session.json`. Every manifest
+//! mutation is committed here first and every entry holds enough state to be
+//! re-applied on its own, so replaying the journal from its first snapshot
+//! rebuilds the manifest after a crash, and the entries double as the session
+//! timeline. Capture events are journal-only until a snapshot is due;
+//! `session.json` records the journal cursor it covers and readers fold the
+//! entries after it.
+
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    contracts::dto::HostErrorEnvelope,
+    session::{
+        session_manifest::{
+            current_timestamp, ActivePresetBinding, SessionCaptureRecord, SessionCaptureSelection,
+            SessionManifest, SessionPostEnd, SessionShotLayout, SessionTiming,
+        },
+        session_paths::SessionPaths,
+        session_repository::{
+            is_retryable_manifest_write_error, take_injected_manifest_write_failure,
+            write_session_manifest, MANIFEST_WRITE_RETRY_ATTEMPTS, MANIFEST_WRITE_RETRY_DELAY_MS,
+        },
+    },
+};
+
+pub const SESSION_JOURNAL_ENTRY_SCHEMA_VERSION: &str = "session-journal-entry/v1";
+const SESSION_JOURNAL_FILE_NAME: &str = "session-journal.jsonl";
+/// Capture events allowed to pile up in the journal before `session.json` is
+/// rewritten.
+pub const SESSION_SNAPSHOT_INTERVAL: u32 = 8;
+
+/// Append position per journal file, so commits do not rescan it.
+static SESSION_JOURNAL_STATES: OnceLock<Mutex<HashMap<PathBuf, SessionJournalState>>> =
+    OnceLock::new();
+
+#[derive(Debug, Clone, Copy)]
+struct SessionJournalState {
+    next_sequence: u64,
+    length: u64,
+    entries_since_snapshot: u32,
+    /// A crash left a torn last line that the next append must not extend.
+    needs_newline: bool,
+    /// Length and mtime of the `session.json` written with the last snapshot.
+    /// Any other value means it was rewritten outside this module.
+    snapshot_stamp: Option<(u64, SystemTime)>,
+}
+
+/// Last journal entry already folded into a `session.json` snapshot, stored
+/// in the snapshot as `journalCursor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionJournalCursor {
+    pub sequence: u64,
+    /// Byte length of the journal right after that entry.
+    pub offset: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionJournalEntry {
+    pub schema_version: String,
+    pub session_id: String,
+    /// Starts at 1 and increases by one per entry.
+    pub sequence: u64,
+    pub occurred_at: String,
+    /// Manifest lifecycle stage once the event was applied.
+    pub lifecycle_stage: String,
+    #[serde(flatten)]
+    pub event: SessionJournalEvent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+pub enum SessionJournalEvent {
+    SessionStarted {
+        manifest: Box<SessionManifest>,
+    },
+    /// Written before the first event of a session whose journal is missing,
+    /// e.g. one started before journaling existed.
+    ManifestSnapshot {
+        manifest: Box<SessionManifest>,
+    },
+    #[serde(rename_all = "camelCase")]
+    PresetSelected {
+        active_preset: ActivePresetBinding,
+        active_preset_display_name: Option<String>,
+        catalog_revision: Option<u64>,
+        catalog_snapshot: Option<Vec<ActivePresetBinding>>,
+    },
+    #[serde(rename_all = "camelCase")]
+    CaptureSaved {
+        capture: Box<SessionCaptureRecord>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        shot_layout: Option<SessionShotLayout>,
+    },
+    PreviewReady {
+        capture: Box<SessionCaptureRecord>,
+    },
+    FinalReady {
+        capture: Box<SessionCaptureRecord>,
+    },
+    /// `stage` is `preview` or `final`.
+    RenderFailed {
+        stage: String,
+        capture: Box<SessionCaptureRecord>,
+    },
+    #[serde(rename_all = "camelCase")]
+    CaptureDeleted {
+        capture_id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        shot_layout: Option<SessionShotLayout>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        selection: Option<SessionCaptureSelection>,
+    },
+    TimingTransition {
+        timing: SessionTiming,
+    },
+    #[serde(rename_all = "camelCase")]
+    PostEndEvaluated {
+        post_end: SessionPostEnd,
+        capture_post_end_state: String,
+    },
+    /// Any other mutation, such as an operator recovery, a print job or a
+    /// selection change. Carries the whole manifest and replaces it on replay.
+    ManifestUpdated {
+        reason: String,
+        manifest: Box<SessionManifest>,
+    },
+}
+
+impl SessionJournalEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SessionStarted { .. } => "session-started",
+            Self::ManifestSnapshot { .. } => "manifest-snapshot",
+            Self::PresetSelected { .. } => "preset-selected",
+            Self::CaptureSaved { .. } => "capture-saved",
+            Self::PreviewReady { .. } => "preview-ready",
+            Self::FinalReady { .. } => "final-ready",
+            Self::RenderFailed { .. } => "render-failed",
+            Self::CaptureDeleted { .. } => "capture-deleted",
+            Self::TimingTransition { .. } => "timing-transition",
+            Self::PostEndEvaluated { .. } => "post-end-evaluated",
+            Self::ManifestUpdated { .. } => "manifest-updated",
+        }
+    }
+
+    /// Events on the per-capture hot path, which may skip the snapshot.
+    fn defers_snapshot(&self) -> bool {
+        matches!(
+            self,
+            Self::CaptureSaved { .. } | Self::PreviewReady { .. } | Self::FinalReady { .. }
+        )
+    }
+
+    pub fn capture_id(&self) -> Option<&str> {
+        match self {
+            Self::CaptureSaved { capture, .. }
+            | Self::PreviewReady { capture }
+            | Self::FinalReady { capture }
+            | Self::RenderFailed { capture, .. } => Some(&capture.capture_id),
+            Self::CaptureDeleted { capture_id, .. } => Some(capture_id),
+            _ => None,
+        }
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::ManifestUpdated { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+
+    pub fn capture_saved(manifest: &SessionManifest, capture: &SessionCaptureRecord) -> Self {
+        Self::CaptureSaved {
+            capture: Box::new(capture.clone()),
+            shot_layout: manifest.shot_layout.clone(),
+        }
+    }
+
+    pub fn preview_ready(capture: &SessionCaptureRecord) -> Self {
+        Self::PreviewReady {
+            capture: Box::new(capture.clone()),
+        }
+    }
+
+    pub fn final_ready(capture: &SessionCaptureRecord) -> Self {
+        Self::FinalReady {
+            capture: Box::new(capture.clone()),
+        }
+    }
+
+    pub fn render_failed(stage: &str, capture: &SessionCaptureRecord) -> Self {
+        Self::RenderFailed {
+            stage: stage.into(),
+            capture: Box::new(capture.clone()),
+        }
+    }
+
+    pub fn capture_deleted(manifest: &SessionManifest, capture_id: &str) -> Self {
+        Self::CaptureDeleted {
+            capture_id: capture_id.into(),
+            shot_layout: manifest.shot_layout.clone(),
+            selection: manifest.selection.clone(),
+        }
+    }
+
+    pub fn manifest_updated(reason: &str, manifest: &SessionManifest) -> Self {
+        Self::ManifestUpdated {
+            reason: reason.into(),
+            manifest: Box::new(manifest.clone()),
+        }
+    }
+}
+
+pub fn session_journal_path(manifest_path: &Path) -> PathBuf {
+    manifest_path.with_file_name(SESSION_JOURNAL_FILE_NAME)
+}
+
+/// Last time the session changed. Capture events only touch the journal, so
+/// the `session.json` mtime alone can lag behind.
+pub fn session_last_modified_at(manifest_path: &Path) -> Option<SystemTime> {
+    [
+        manifest_path.to_path_buf(),
+        session_journal_path(manifest_path),
+    ]
+    .iter()
+    .filter_map(|path| fs::metadata(path).ok()?.modified().ok())
+    .max()
+}
+
+/// Commits a mutation: `manifest` must already reflect `event`. Capture
+/// events are only appended until `SESSION_SNAPSHOT_INTERVAL` of them have
+/// piled up; every other event rewrites `session.json` first and then appends,
+/// so a failed manifest write leaves no entry behind. Journal failures fall
+/// back to a plain snapshot and never fail the state change.
+pub(crate) fn commit_session_manifest(
+    manifest_path: &Path,
+    manifest: &SessionManifest,
+    event: SessionJournalEvent,
+) -> Result<(), HostErrorEnvelope> {
+    let journal_path = session_journal_path(manifest_path);
+    let mut states = SESSION_JOURNAL_STATES
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .map_err(|_| HostErrorEnvelope::persistence("세션 기록을 잠그지 못했어요."))?;
+    let event_name = event.name();
+    let state = match load_journal_state(&states, manifest_path, &journal_path) {
+        Ok(state) => state,
+        Err(error) => {
+            log_journal_append_failure(manifest, event_name, &error.message);
+            states.remove(&journal_path);
+            return write_session_manifest(manifest_path, manifest, None);
+        }
+    };
+    let defers_snapshot = event.defers_snapshot()
+        && state.next_sequence > 1
+        && state.entries_since_snapshot < SESSION_SNAPSHOT_INTERVAL;
+
+    let mut lines = String::new();
+    if state.needs_newline {
+        lines.push('\n');
+    }
+    let mut sequence = state.next_sequence;
+    let occurred_at = current_timestamp(SystemTime::now())?;
+    if sequence == 1 && !matches!(event, SessionJournalEvent::SessionStarted { .. }) {
+        let snapshot = build_entry(
+            manifest,
+            sequence,
+            &occurred_at,
+            SessionJournalEvent::ManifestSnapshot {
+                manifest: Box::new(manifest.clone()),
+            },
+        );
+        lines.push_str(&serialize_entry(&snapshot)?);
+        sequence += 1;
+    }
+    lines.push_str(&serialize_entry(&build_entry(
+        manifest,
+        sequence,
+        &occurred_at,
+        event,
+    ))?);
+    let cursor = SessionJournalCursor {
+        sequence,
+        offset: state.length + lines.len() as u64,
+    };
+
+    if !defers_snapshot {
+        write_session_manifest(manifest_path, manifest, Some(&cursor))?;
+    }
+    match append_journal_lines(manifest_path, &journal_path, state.length, &lines) {
+        Ok(()) => {
+            states.insert(
+                journal_path,
+                SessionJournalState {
+                    next_sequence: sequence + 1,
+                    length: cursor.offset,
+                    entries_since_snapshot: if defers_snapshot {
+                        state.entries_since_snapshot + 1
+                    } else {
+                        0
+                    },
+                    needs_newline: false,
+                    snapshot_stamp: if defers_snapshot {
+                        state.snapshot_stamp
+                    } else {
+                        read_snapshot_stamp(manifest_path)
+                    },
+                },
+            );
+            Ok(())
+        }
+        Err(error) => {
+            log_journal_append_failure(manifest, event_name, &error.message);
+            states.remove(&journal_path);
+            if defers_snapshot {
+                write_session_manifest(manifest_path, manifest, None)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Applies the entries written after `cursor` on top of the snapshot that
+/// recorded it.
+pub(crate) fn fold_session_journal_tail(
+    manifest_path: &Path,
+    manifest: SessionManifest,
+    cursor: &SessionJournalCursor,
+) -> SessionManifest {
+    let journal_path = session_journal_path(manifest_path);
+    let tail = File::open(&journal_path).and_then(|mut file| {
+        if file.metadata()?.len() < cursor.offset {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "journal is shorter than the snapshot cursor",
+            ));
+        }
+        file.seek(SeekFrom::Start(cursor.offset))?;
+        let mut tail = Vec::new();
+        file.read_to_end(&mut tail)?;
+        Ok(String::from_utf8_lossy(&tail).into_owned())
+    });
+    let tail = match tail {
+        Ok(tail) => tail,
+        Err(error) => {
+            if journal_path.exists() {
+                log::warn!(
+                    "session_journal_tail_unreadable path={} reason={}",
+                    journal_path.display(),
+                    error
+                );
+            }
+            return manifest;
+        }
+    };
+
+    let mut folded = manifest;
+    for line in tail.lines().filter(|line| !line.trim().is_empty()) {
+        let Ok(entry) = serde_json::from_str::<SessionJournalEntry>(line) else {
+            continue;
+        };
+        if entry.sequence > cursor.sequence {
+            fold_journal_entry(&mut folded, &entry);
+        }
+    }
+
+    folded
+}
+
+pub fn read_session_journal_in_dir(
+    base_dir: &Path,
+    session_id: &str,
+) -> Result<Vec<SessionJournalEntry>, HostErrorEnvelope> {
+    let paths = SessionPaths::try_new(base_dir, session_id)?;
+
+    read_session_journal(&session_journal_path(&paths.manifest_path))
+}
+
+/// Unreadable lines, such as one torn by a crash mid-append, are skipped.
+pub fn read_session_journal(
+    journal_path: &Path,
+) -> Result<Vec<SessionJournalEntry>, HostErrorEnvelope> {
+    if !journal_path.is_file() {
+        return Ok(Vec::new());
+    }
+    let contents = fs::read_to_string(journal_path).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("세션 기록을 읽지 못했어요: {error}"))
+    })?;
+    let mut entries = Vec::new();
+    for (line_index, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<SessionJournalEntry>(line) {
+            Ok(entry) => entries.push(entry),
+            Err(error) => log::warn!(
+                "session_journal_line_skipped path={} line={} reason={}",
+                journal_path.display(),
+                line_index + 1,
+                error
+            ),
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Folds entries onto the most recent start or snapshot before them. Returns
+/// `None` when the journal has no snapshot to start from.
+pub fn replay_session_journal(entries: &[SessionJournalEntry]) -> Option<SessionManifest> {
+    let mut manifest: Option<SessionManifest> = None;
+
+    for entry in entries {
+        match manifest.as_mut() {
+            Some(manifest) => fold_journal_entry(manifest, entry),
+            None => manifest = journal_entry_snapshot(&entry.event).cloned(),
+        }
+    }
+
+    manifest
+}
+
+pub fn rebuild_session_manifest_from_journal_in_dir(
+    base_dir: &Path,
+    session_id: &str,
+) -> Result<SessionManifest, HostErrorEnvelope> {
+    replay_session_journal(&read_session_journal_in_dir(base_dir, session_id)?).ok_or_else(|| {
+        HostErrorEnvelope::session_not_found("세션 기록으로 매니페스트를 다시 만들 수 없어요.")
+    })
+}
+
+/// Used by the manifest reader when `session.json` and its backup are both
+/// unusable.
+pub(crate) fn recover_session_manifest_from_journal(
+    manifest_path: &Path,
+) -> Option<SessionManifest> {
+    let journal_path = session_journal_path(manifest_path);
+    let entries = read_session_journal(&journal_path).ok()?;
+    let manifest = replay_session_journal(&entries)?;
+    log::warn!(
+        "session_manifest_rebuilt_from_journal path={} entries={}",
+        manifest_path.display(),
+        entries.len()
+    );
+
+    Some(manifest)
+}
+
+fn journal_entry_snapshot(event: &SessionJournalEvent) -> Option<&SessionManifest> {
+    match event {
+        SessionJournalEvent::SessionStarted { manifest }
+        | SessionJournalEvent::ManifestSnapshot { manifest }
+        | SessionJournalEvent::ManifestUpdated { manifest, .. } => Some(manifest),
+        _ => None,
+    }
+}
+
+fn fold_journal_entry(manifest: &mut SessionManifest, entry: &SessionJournalEntry) {
+    if let Some(snapshot) = journal_entry_snapshot(&entry.event) {
+        *manifest = snapshot.clone();
+        return;
+    }
+    apply_journal_event(manifest, &entry.event);
+    manifest.lifecycle.stage = entry.lifecycle_stage.clone();
+    manifest.updated_at = entry.occurred_at.clone();
+}
+
+fn apply_journal_event(manifest: &mut SessionManifest, event: &SessionJournalEvent) {
+    match event {
+        SessionJournalEvent::SessionStarted { .. }
+        | SessionJournalEvent::ManifestSnapshot { .. }
+        | SessionJournalEvent::ManifestUpdated { .. } => {}
+        SessionJournalEvent::PresetSelected {
+            active_preset,
+            active_preset_display_name,
+            catalog_revision,
+            catalog_snapshot,
+        } => {
+            manifest.active_preset = Some(active_preset.clone());
+            manifest.active_preset_id = Some(active_preset.preset_id.clone());
+            manifest.active_preset_display_name = active_preset_display_name.clone();
+            if catalog_revision.is_some() {
+                manifest.catalog_revision = *catalog_revision;
+                manifest.catalog_snapshot = catalog_snapshot.clone();
+            }
+        }
+        SessionJournalEvent::CaptureSaved {
+            capture,
+            shot_layout,
+        } => {
+            if shot_layout.is_some() {
+                manifest.shot_layout = shot_layout.clone();
+            }
+            upsert_capture(manifest, capture);
+        }
+        SessionJournalEvent::PreviewReady { capture }
+        | SessionJournalEvent::FinalReady { capture }
+        | SessionJournalEvent::RenderFailed { capture, .. } => {
+            upsert_capture(manifest, capture);
+        }
+        SessionJournalEvent::CaptureDeleted {
+            capture_id,
+            shot_layout,
+            selection,
+        } => {
+            manifest
+                .captures
+                .retain(|capture| &capture.capture_id != capture_id);
+            if shot_layout.is_some() {
+                manifest.shot_layout = shot_layout.clone();
+            }
+            if selection.is_some() {
+                manifest.selection = selection.clone();
+            }
+        }
+        SessionJournalEvent::TimingTransition { timing } => {
+            manifest.timing = Some(timing.clone());
+        }
+        SessionJournalEvent::PostEndEvaluated {
+            post_end,
+            capture_post_end_state,
+        } => {
+            manifest.post_end = Some(post_end.clone());
+            for capture in &mut manifest.captures {
+                capture.post_end_state = capture_post_end_state.clone();
+            }
+        }
+    }
+}
+
+fn upsert_capture(manifest: &mut SessionManifest, capture: &SessionCaptureRecord) {
+    match manifest
+        .captures
+        .iter_mut()
+        .find(|existing| existing.capture_id == capture.capture_id)
+    {
+        Some(existing) => *existing = capture.clone(),
+        None => manifest.captures.push(capture.clone()),
+    }
+}
+
+fn build_entry(
+    manifest: &SessionManifest,
+    sequence: u64,
+    occurred_at: &str,
+    event: SessionJournalEvent,
+) -> SessionJournalEntry {
+    SessionJournalEntry {
+        schema_version: SESSION_JOURNAL_ENTRY_SCHEMA_VERSION.into(),
+        session_id: manifest.session_id.clone(),
+        sequence,
+        occurred_at: occurred_at.into(),
+        lifecycle_stage: manifest.lifecycle.stage.clone(),
+        event,
+    }
+}
+
+fn serialize_entry(entry: &SessionJournalEntry) -> Result<String, HostErrorEnvelope> {
+    let mut line = serde_json::to_string(entry).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("세션 기록을 직렬화하지 못했어요: {error}"))
+    })?;
+    line.push('\n');
+
+    Ok(line)
+}
+
+/// Reuses the cached append position while the journal still has the length
+/// it was left with; otherwise rescans it. Either a rescan or a `session.json`
+/// rewritten elsewhere forces the next commit to snapshot.
+fn load_journal_state(
+    states: &HashMap<PathBuf, SessionJournalState>,
+    manifest_path: &Path,
+    journal_path: &Path,
+) -> Result<SessionJournalState, HostErrorEnvelope> {
+    let length = match fs::metadata(journal_path) {
+        Ok(metadata) => metadata.len(),
+        Err(_) => {
+            return Ok(SessionJournalState {
+                next_sequence: 1,
+                length: 0,
+                entries_since_snapshot: 0,
+                needs_newline: false,
+                snapshot_stamp: None,
+            })
+        }
+    };
+    if let Some(state) = states
+        .get(journal_path)
+        .filter(|state| state.length == length)
+    {
+        let mut state = *state;
+        if state.snapshot_stamp != read_snapshot_stamp(manifest_path) {
+            state.entries_since_snapshot = SESSION_SNAPSHOT_INTERVAL;
+        }
+        return Ok(state);
+    }
+
+    let contents = fs::read(journal_path).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("세션 기록을 읽지 못했어요: {error}"))
+    })?;
+    let last_sequence = read_session_journal(journal_path)?
+        .last()
+        .map(|entry| entry.sequence)
+        .unwrap_or(0);
+
+    Ok(SessionJournalState {
+        next_sequence: last_sequence + 1,
+        length: contents.len() as u64,
+        entries_since_snapshot: SESSION_SNAPSHOT_INTERVAL,
+        needs_newline: contents.last().is_some_and(|byte| *byte != b'\n'),
+        snapshot_stamp: None,
+    })
+}
+
+fn read_snapshot_stamp(manifest_path: &Path) -> Option<(u64, SystemTime)> {
+    let metadata = fs::metadata(manifest_path).ok()?;
+
+    Some((metadata.len(), metadata.modified().ok()?))
+}
+
+/// Appends with the same retry budget as the snapshot write. A retry first
+/// cuts off whatever a failed attempt left past `length`. Deferred capture
+/// events live only here until the next snapshot, so the append is synced
+/// before it counts as committed.
+fn append_journal_lines(
+    manifest_path: &Path,
+    journal_path: &Path,
+    length: u64,
+    lines: &str,
+) -> Result<(), HostErrorEnvelope> {
+    let mut last_error = None;
+
+    for attempt in 0..MANIFEST_WRITE_RETRY_ATTEMPTS {
+        let result = match take_injected_manifest_write_failure(manifest_path) {
+            Some(error) => Err(error),
+            None => truncate_journal_to(journal_path, length).and_then(|()| {
+                let mut file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(journal_path)?;
+                file.write_all(lines.as_bytes())?;
+                file.sync_data()
+            }),
+        };
+        match result {
+            Ok(()) => return Ok(()),
+            Err(error) if is_retryable_manifest_write_error(&error) => {
+                log::warn!(
+                    "session_journal_append_retry path={} attempt={} reason={}",
+                    journal_path.display(),
+                    attempt + 1,
+                    error
+                );
+                last_error = Some(error);
+                if attempt + 1 < MANIFEST_WRITE_RETRY_ATTEMPTS {
+                    thread::sleep(Duration::from_millis(MANIFEST_WRITE_RETRY_DELAY_MS));
+                }
+            }
+            Err(error) => {
+                last_error = Some(error);
+                break;
+            }
+        }
+    }
+
+    Err(HostErrorEnvelope::persistence(format!(
+        "세션 기록을 남기지 못했어요: {}",
+        last_error.map_or_else(
+            || "retry budget exhausted".into(),
+            |error| error.to_string()
+        )
+    )))
+}
+
+fn truncate_journal_to(journal_path: &Path, length: u64) -> std::io::Result<()> {
+    match fs::metadata(journal_path) {
+        Ok(metadata) if metadata.len() > length => OpenOptions::new()
+            .write(true)
+            .open(journal_path)?
+            .set_len(length),
+        _ => Ok(()),
+    }
+}
+
+fn log_journal_append_failure(manifest: &SessionManifest, event_name: &str, message: &str) {
+    log::warn!(
+        "session_journal_append_failed session={} event={} message={}",
+        manifest.session_id,
+        event_name,
+        message
+    );
+}
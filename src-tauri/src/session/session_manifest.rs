@@ -2,9 +2,12 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use serde::{Deserialize, Serialize};
 
-use crate::contracts::dto::{HostErrorEnvelope, HostFieldErrors, SessionStartInputDto};
+use crate::{
+    branch_config::session_packages::{default_session_package, SessionPackageTier},
+    contracts::dto::{HostErrorEnvelope, HostFieldErrors, SessionStartInputDto},
+};
 
-pub const SESSION_MANIFEST_SCHEMA_VERSION: &str = "session-manifest/v1";
+pub const SESSION_MANIFEST_SCHEMA_VERSION: &str = "session-manifest/v2";
 pub const SESSION_TIMING_SCHEMA_VERSION: &str = "session-timing/v1";
 pub const SESSION_POST_END_EXPORT_WAITING: &str = "export-waiting";
 pub const SESSION_POST_END_COMPLETED: &str = "completed";
@@ -41,6 +44,8 @@ pub struct SessionTiming {
     pub approved_extension_audit_ref: Option<String>,
     pub warning_triggered_at: Option<String>,
     pub ended_triggered_at: Option<String>,
+    #[serde(default)]
+    pub package: Option<SessionPackageTier>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -212,6 +217,38 @@ pub struct PreviewCaptureAsset {
 pub struct FinalCaptureAsset {
     pub asset_path: Option<String>,
     pub ready_at_ms: Option<u64>,
+    /// Extra outputs declared by the preset bundle, next to `asset_path`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<FinalCaptureVariant>,
+}
+
+impl FinalCaptureAsset {
+    /// The first variant serving `purpose` in one of `formats`, in bundle
+    /// order. Callers fall back to `asset_path` when none matches.
+    pub fn variant_for(&self, purpose: &str, formats: &[&str]) -> Option<&FinalCaptureVariant> {
+        self.variants.iter().find(|variant| {
+            variant.purposes.iter().any(|value| value == purpose)
+                && formats.contains(&variant.format.as_str())
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.asset_path = None;
+        self.ready_at_ms = None;
+        self.variants.clear();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalCaptureVariant {
+    pub variant_id: String,
+    pub asset_path: String,
+    /// `jpeg` or `tiff`.
+    pub format: String,
+    pub color_space: String,
+    pub purposes: Vec<String>,
+    pub ready_at_ms: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -246,8 +283,125 @@ pub struct SessionCaptureRecord {
     #[serde(rename = "final")]
     pub final_asset: FinalCaptureAsset,
     pub render_status: String,
+    /// Renderer behind the latest preview/final asset. `builtin-fallback`
+    /// marks a degraded render made without darktable-cli.
+    #[serde(default)]
+    pub render_backend: Option<String>,
     pub post_end_state: String,
     pub timing: CaptureTimingMetrics,
+    /// Set when the capture was one shot of a countdown or burst program.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capture_program: Option<CaptureProgramShot>,
+}
+
+pub const CAPTURE_PROGRAM_MODE_COUNTDOWN: &str = "countdown";
+pub const CAPTURE_PROGRAM_MODE_BURST: &str = "burst";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureProgramShot {
+    pub program_id: String,
+    /// `countdown` or `burst`.
+    pub mode: String,
+    /// 1-based position of the shot within the program.
+    pub shot_index: u32,
+    pub shot_count: u32,
+}
+
+pub const SHOT_LAYOUT_STATUS_FILLING: &str = "filling";
+pub const SHOT_LAYOUT_STATUS_FILLED: &str = "filled";
+pub const SHOT_LAYOUT_STATUS_COMPOSED: &str = "composed";
+pub const SHOT_LAYOUT_STATUS_COMPOSE_FAILED: &str = "composeFailed";
+
+/// Slot assignments for a multi-shot layout session. Bound to the preset
+/// that was active when the first capture landed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionShotLayout {
+    pub layout_id: String,
+    pub preset_id: String,
+    pub published_version: String,
+    /// `filling`, `filled`, `composed` or `composeFailed`.
+    pub status: String,
+    pub slots: Vec<SessionShotSlot>,
+    /// Slot the next capture replaces instead of taking the first empty one.
+    #[serde(default)]
+    pub retake_slot_id: Option<String>,
+    #[serde(default)]
+    pub composed_final: Option<ComposedLayoutFinal>,
+}
+
+impl SessionShotLayout {
+    pub fn is_filled(&self) -> bool {
+        self.slots.iter().all(|slot| slot.capture_id.is_some())
+    }
+
+    pub fn slot_capture_ids(&self) -> impl Iterator<Item = &str> {
+        self.slots
+            .iter()
+            .filter_map(|slot| slot.capture_id.as_deref())
+    }
+
+    /// Drops the composed final and recomputes `status` after a slot change.
+    pub fn reset_composition(&mut self) {
+        self.composed_final = None;
+        self.status = if self.is_filled() {
+            SHOT_LAYOUT_STATUS_FILLED
+        } else {
+            SHOT_LAYOUT_STATUS_FILLING
+        }
+        .into();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionShotSlot {
+    pub slot_id: String,
+    #[serde(default)]
+    pub capture_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposedLayoutFinal {
+    pub asset_path: String,
+    pub ready_at_ms: u64,
+    /// Slot captures in slot order at composition time.
+    pub capture_ids: Vec<String>,
+}
+
+pub const SESSION_PRINT_JOB_SCHEMA_VERSION: &str = "session-print-job/v1";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionPrintJob {
+    pub schema_version: String,
+    pub job_id: String,
+    pub template_id: String,
+    pub capture_ids: Vec<String>,
+    pub copies: u32,
+    pub page_count: u32,
+    pub status: String,
+    pub queue_id: String,
+    #[serde(default)]
+    pub document_path: Option<String>,
+    pub submitted_at: String,
+    pub updated_at: String,
+    #[serde(default)]
+    pub detail_code: Option<String>,
+}
+
+/// Booking the session was started from. `allowed_preset_ids` is empty when the
+/// reservation does not restrict looks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionReservation {
+    pub reservation_id: String,
+    pub source_id: String,
+    #[serde(default)]
+    pub allowed_preset_ids: Vec<String>,
+    pub redeemed_at: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -276,40 +430,68 @@ pub struct SessionManifest {
     pub captures: Vec<SessionCaptureRecord>,
     #[serde(default)]
     pub post_end: Option<SessionPostEnd>,
+    #[serde(default)]
+    pub print_jobs: Vec<SessionPrintJob>,
+    #[serde(default)]
+    pub reservation: Option<SessionReservation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shot_layout: Option<SessionShotLayout>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selection: Option<SessionCaptureSelection>,
 }
 
-pub fn normalize_legacy_manifest(manifest: &mut SessionManifest) {
-    let fallback_active_preset_id = manifest.active_preset_id.clone().or_else(|| {
-        manifest
-            .active_preset
-            .as_ref()
-            .map(|preset| preset.preset_id.clone())
-    });
-    let fallback_active_preset_version = manifest
-        .active_preset
-        .as_ref()
-        .map(|preset| preset.published_version.clone());
-    let fallback_active_preset_display_name = manifest.active_preset_display_name.clone();
+/// Customer curation of the session's captures. Favourites are a marker for
+/// the booth UI; only the selected set changes what gets delivered.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionCaptureSelection {
+    #[serde(default)]
+    pub selected_capture_ids: Vec<String>,
+    #[serde(default)]
+    pub favorite_capture_ids: Vec<String>,
+}
 
-    for capture in &mut manifest.captures {
-        let matches_manifest_active_preset = fallback_active_preset_version
+impl SessionManifest {
+    /// Captures whose finals are delivered, in capture order. Sessions where
+    /// the customer never selected anything deliver the latest capture.
+    pub fn selected_captures(&self) -> Vec<&SessionCaptureRecord> {
+        let selected_ids = self
+            .selection
             .as_ref()
-            .map(|published_version| published_version == &capture.active_preset_version)
-            .unwrap_or(false);
-
-        if capture.active_preset_id.is_none() && matches_manifest_active_preset {
-            capture.active_preset_id = fallback_active_preset_id.clone();
+            .map(|selection| selection.selected_capture_ids.as_slice())
+            .unwrap_or_default();
+        if selected_ids.is_empty() {
+            return self.captures.last().into_iter().collect();
         }
 
-        if capture.active_preset_display_name.is_none()
-            && matches_manifest_active_preset
-            && capture.active_preset_id == fallback_active_preset_id
-        {
-            capture.active_preset_display_name = fallback_active_preset_display_name.clone();
-        }
+        self.captures
+            .iter()
+            .filter(|capture| selected_ids.contains(&capture.capture_id))
+            .collect()
+    }
+
+    pub fn max_selected_captures(&self) -> Option<u32> {
+        self.timing
+            .as_ref()
+            .and_then(|timing| timing.package.as_ref())
+            .and_then(|package| package.max_selected_captures)
     }
 }
 
+pub fn is_preset_allowed_for_session(manifest: &SessionManifest, preset_id: &str) -> bool {
+    manifest
+        .reservation
+        .as_ref()
+        .map(|reservation| {
+            reservation.allowed_preset_ids.is_empty()
+                || reservation
+                    .allowed_preset_ids
+                    .iter()
+                    .any(|allowed| allowed == preset_id)
+        })
+        .unwrap_or(true)
+}
+
 fn legacy_post_end_evaluated_at() -> String {
     "1970-01-01T00:00:00Z".into()
 }
@@ -324,10 +506,7 @@ pub fn validate_session_start_input(
     let normalized_name = normalize_customer_name(&input.name);
     let normalized_phone = input.phone_last_four.clone();
 
-    let mut field_errors = HostFieldErrors {
-        name: None,
-        phone_last_four: None,
-    };
+    let mut field_errors = HostFieldErrors::default();
 
     if normalized_name.is_empty() {
         field_errors.name = Some("이름을 입력해 주세요.".into());
@@ -344,6 +523,12 @@ pub fn validate_session_start_input(
     Ok(SessionStartInputDto {
         name: normalized_name,
         phone_last_four: normalized_phone,
+        package_id: input
+            .package_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|package_id| !package_id.is_empty())
+            .map(str::to_string),
     })
 }
 
@@ -362,10 +547,19 @@ pub fn build_session_manifest_at(
     session_id: String,
     input: SessionStartInputDto,
     now: SystemTime,
+) -> Result<SessionManifest, HostErrorEnvelope> {
+    build_session_manifest_for_package_at(session_id, input, &default_session_package(), now)
+}
+
+pub fn build_session_manifest_for_package_at(
+    session_id: String,
+    input: SessionStartInputDto,
+    package: &SessionPackageTier,
+    now: SystemTime,
 ) -> Result<SessionManifest, HostErrorEnvelope> {
     let timestamp = current_timestamp(now)?;
     let booth_alias = build_booth_alias(&input.name, &input.phone_last_four);
-    let timing = build_default_session_timing(session_id.clone(), &timestamp)?;
+    let timing = build_session_timing(session_id.clone(), &timestamp, package)?;
 
     Ok(SessionManifest {
         schema_version: SESSION_MANIFEST_SCHEMA_VERSION.into(),
@@ -389,21 +583,31 @@ pub fn build_session_manifest_at(
         timing: Some(timing),
         captures: Vec::new(),
         post_end: None,
+        print_jobs: Vec::new(),
+        reservation: None,
+        shot_layout: None,
+        selection: None,
     })
 }
 
 pub fn build_default_session_timing(
     session_id: String,
     started_at: &str,
+) -> Result<SessionTiming, HostErrorEnvelope> {
+    build_session_timing(session_id, started_at, &default_session_package())
+}
+
+pub fn build_session_timing(
+    session_id: String,
+    started_at: &str,
+    package: &SessionPackageTier,
 ) -> Result<SessionTiming, HostErrorEnvelope> {
     let started_at_seconds = rfc3339_to_unix_seconds(started_at)?;
-    let adjusted_end_at = unix_seconds_to_rfc3339(
-        started_at_seconds.saturating_add(DEFAULT_SESSION_DURATION_SECONDS),
-    );
+    let ends_at_seconds =
+        started_at_seconds.saturating_add(u64::from(package.duration_minutes) * 60);
+    let adjusted_end_at = unix_seconds_to_rfc3339(ends_at_seconds);
     let warning_at = unix_seconds_to_rfc3339(
-        started_at_seconds
-            .saturating_add(DEFAULT_SESSION_DURATION_SECONDS)
-            .saturating_sub(WARNING_LEAD_SECONDS),
+        ends_at_seconds.saturating_sub(u64::from(package.warning_lead_minutes) * 60),
     );
 
     Ok(SessionTiming {
@@ -417,6 +621,7 @@ pub fn build_default_session_timing(
         approved_extension_audit_ref: None,
         warning_triggered_at: None,
         ended_triggered_at: None,
+        package: Some(package.clone()),
     })
 }
 
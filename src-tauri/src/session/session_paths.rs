@@ -11,6 +11,7 @@ pub struct SessionPaths {
     pub captures_originals_dir: PathBuf,
     pub renders_previews_dir: PathBuf,
     pub renders_finals_dir: PathBuf,
+    pub renders_prints_dir: PathBuf,
     pub handoff_dir: PathBuf,
     pub diagnostics_dir: PathBuf,
 }
@@ -33,6 +34,7 @@ impl SessionPaths {
             captures_originals_dir: session_root.join("captures").join("originals"),
             renders_previews_dir: session_root.join("renders").join("previews"),
             renders_finals_dir: session_root.join("renders").join("finals"),
+            renders_prints_dir: session_root.join("renders").join("prints"),
             handoff_dir: session_root.join("handoff"),
             diagnostics_dir: session_root.join("diagnostics"),
             session_root,
@@ -12,6 +12,7 @@ use std::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    branch_config::session_packages::resolve_session_package_in_dir,
     capture::normalized_state::normalize_capture_readiness,
     contracts::dto::{
         validate_session_id, HostErrorEnvelope, PresetSelectionInputDto, PresetSelectionResultDto,
@@ -26,9 +27,15 @@ use crate::{
         preset_catalog_state::capture_live_catalog_snapshot,
     },
     session::{
+        manifest_migration::{migrate_session_manifest_value, ManifestMigrationOutcome},
+        session_journal::{
+            commit_session_manifest, fold_session_journal_tail,
+            recover_session_manifest_from_journal, SessionJournalCursor, SessionJournalEvent,
+        },
         session_manifest::{
-            build_session_manifest, current_timestamp, normalize_legacy_manifest,
-            validate_session_start_input, ActivePresetBinding, SessionManifest,
+            build_session_manifest_for_package_at, current_timestamp,
+            is_preset_allowed_for_session, validate_session_start_input, ActivePresetBinding,
+            SessionManifest, SessionReservation,
         },
         session_paths::SessionPaths,
     },
@@ -36,8 +43,9 @@ use crate::{
 };
 
 static SESSION_COUNTER: AtomicU64 = AtomicU64::new(0);
-const MANIFEST_WRITE_RETRY_ATTEMPTS: usize = 12;
-const MANIFEST_WRITE_RETRY_DELAY_MS: u64 = 25;
+pub(super) const MANIFEST_WRITE_RETRY_ATTEMPTS: usize = 12;
+pub(super) const MANIFEST_WRITE_RETRY_DELAY_MS: u64 = 25;
+const MANIFEST_JOURNAL_CURSOR_FIELD: &str = "journalCursor";
 
 #[derive(Debug, Default)]
 struct ManifestWriteFailureInjection {
@@ -60,11 +68,36 @@ pub fn start_session_in_dir(
     input: SessionStartInputDto,
 ) -> Result<SessionStartResultDto, HostErrorEnvelope> {
     let validated_input = validate_session_start_input(&input)?;
-    let session_id = generate_session_id();
+
+    start_validated_session_in_dir(base_dir, generate_session_id(), validated_input, None)
+}
+
+/// Creates the session root for input that already passed
+/// `validate_session_start_input`, optionally pinning the reservation it came from.
+pub(crate) fn start_validated_session_in_dir(
+    base_dir: &Path,
+    session_id: String,
+    validated_input: SessionStartInputDto,
+    reservation: Option<SessionReservation>,
+) -> Result<SessionStartResultDto, HostErrorEnvelope> {
+    let package = resolve_session_package_in_dir(base_dir, validated_input.package_id.as_deref())?;
     let paths = SessionPaths::new(base_dir, &session_id);
-    let manifest = build_session_manifest(session_id.clone(), validated_input)?;
+    let mut manifest = build_session_manifest_for_package_at(
+        session_id.clone(),
+        validated_input,
+        &package,
+        SystemTime::now(),
+    )?;
+    manifest.reservation = reservation;
 
     create_session_root(&paths, &manifest)?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::SessionStarted {
+            manifest: Box::new(manifest.clone()),
+        },
+    )?;
     try_append_operator_audit_record(
         base_dir,
         OperatorAuditRecordInput {
@@ -105,7 +138,11 @@ pub fn select_active_preset_in_dir(
         let (catalog_revision, catalog_snapshot) = capture_live_catalog_snapshot(base_dir)?;
         manifest.catalog_revision = Some(catalog_revision);
         manifest.catalog_snapshot = Some(catalog_snapshot);
-        write_session_manifest(&paths.manifest_path, &manifest)?;
+        commit_session_manifest(
+            &paths.manifest_path,
+            &manifest,
+            SessionJournalEvent::manifest_updated("catalog-pinned", &manifest),
+        )?;
     }
 
     if manifest.post_end.is_some()
@@ -117,6 +154,12 @@ pub fn select_active_preset_in_dir(
         ));
     }
 
+    if !is_preset_allowed_for_session(&manifest, &input.preset_id) {
+        return Err(HostErrorEnvelope::preset_not_available(
+            "예약에 포함되지 않은 프리셋이에요. 다른 프리셋을 골라 주세요.",
+        ));
+    }
+
     let catalog_root = resolve_published_preset_catalog_dir(base_dir);
     let selected_preset = find_selectable_published_preset_summary(
         &catalog_root,
@@ -157,7 +200,16 @@ pub fn select_active_preset_in_dir(
         }
 
         if changed {
-            write_session_manifest(&paths.manifest_path, &manifest)?;
+            commit_session_manifest(
+                &paths.manifest_path,
+                &manifest,
+                SessionJournalEvent::PresetSelected {
+                    active_preset: active_preset.clone(),
+                    active_preset_display_name: manifest.active_preset_display_name.clone(),
+                    catalog_revision: manifest.catalog_revision,
+                    catalog_snapshot: manifest.catalog_snapshot.clone(),
+                },
+            )?;
         }
 
         return Ok(PresetSelectionResultDto {
@@ -167,7 +219,7 @@ pub fn select_active_preset_in_dir(
         });
     }
 
-    manifest.active_preset = Some(active_preset);
+    manifest.active_preset = Some(active_preset.clone());
     manifest.active_preset_id = Some(selected_preset.preset_id.clone());
     manifest.active_preset_display_name = Some(selected_preset.display_name.clone());
     manifest.updated_at = current_timestamp(SystemTime::now())?;
@@ -175,7 +227,16 @@ pub fn select_active_preset_in_dir(
         manifest.lifecycle.stage = "preset-selected".into();
     }
 
-    write_session_manifest(&paths.manifest_path, &manifest)?;
+    commit_session_manifest(
+        &paths.manifest_path,
+        &manifest,
+        SessionJournalEvent::PresetSelected {
+            active_preset,
+            active_preset_display_name: manifest.active_preset_display_name.clone(),
+            catalog_revision: manifest.catalog_revision,
+            catalog_snapshot: manifest.catalog_snapshot.clone(),
+        },
+    )?;
 
     Ok(PresetSelectionResultDto {
         session_id: manifest.session_id.clone(),
@@ -235,6 +296,8 @@ pub(crate) fn read_session_manifest(
     if !manifest_path.is_file() {
         if backup_path.is_file() {
             fs::rename(&backup_path, manifest_path).map_err(map_fs_error)?;
+        } else if let Some(manifest) = recover_session_manifest_from_journal(manifest_path) {
+            return Ok(manifest);
         } else {
             return Err(HostErrorEnvelope::session_not_found(
                 "진행 중인 세션을 찾지 못했어요. 처음 화면에서 다시 시작해 주세요.",
@@ -249,22 +312,90 @@ pub(crate) fn read_session_manifest(
     }
 
     let manifest_bytes = fs::read_to_string(manifest_path).map_err(map_fs_error)?;
-    let mut manifest: SessionManifest = serde_json::from_str(&manifest_bytes).map_err(|error| {
+    let mut manifest_value: serde_json::Value = match serde_json::from_str(&manifest_bytes) {
+        Ok(value) => value,
+        Err(error) => {
+            // Like a migration, the rebuilt manifest stays in memory: reads run
+            // without `CAPTURE_PIPELINE_LOCK`, and the next locked write sees
+            // the changed `session.json` and snapshots over it.
+            return recover_session_manifest_from_journal(manifest_path).ok_or_else(|| {
+                HostErrorEnvelope::persistence(format!("세션 매니페스트를 읽지 못했어요: {error}"))
+            });
+        }
+    };
+    let journal_cursor = manifest_value
+        .as_object_mut()
+        .and_then(|fields| fields.remove(MANIFEST_JOURNAL_CURSOR_FIELD))
+        .and_then(|cursor| serde_json::from_value::<SessionJournalCursor>(cursor).ok());
+    let migration = migrate_session_manifest_value(&mut manifest_value)?;
+    let manifest: SessionManifest = serde_json::from_value(manifest_value).map_err(|error| {
         HostErrorEnvelope::persistence(format!("세션 매니페스트를 읽지 못했어요: {error}"))
     })?;
 
-    normalize_legacy_manifest(&mut manifest);
+    if let Some(migration) = migration {
+        keep_pre_migration_manifest(manifest_path, &manifest_bytes, &migration)?;
+    }
 
-    Ok(manifest)
+    Ok(match journal_cursor {
+        Some(cursor) => fold_session_journal_tail(manifest_path, manifest, &cursor),
+        None => manifest,
+    })
 }
 
-pub(crate) fn write_session_manifest(
+/// Current manifest of a session, including capture events still waiting in
+/// the journal for the next snapshot.
+pub fn read_session_manifest_in_dir(
+    base_dir: &Path,
+    session_id: &str,
+) -> Result<SessionManifest, HostErrorEnvelope> {
+    let paths = SessionPaths::try_new(base_dir, session_id)?;
+
+    read_session_manifest(&paths.manifest_path)
+}
+
+/// Keeps the first pre-migration copy next to the manifest. The upgraded shape
+/// stays in memory: reads run without `CAPTURE_PIPELINE_LOCK`, so it reaches
+/// disk with the next locked write instead of racing one here.
+fn keep_pre_migration_manifest(
+    manifest_path: &Path,
+    original_bytes: &str,
+    migration: &ManifestMigrationOutcome,
+) -> Result<(), HostErrorEnvelope> {
+    let pre_migration_path = manifest_pre_migration_path(manifest_path, &migration.from_version);
+    if pre_migration_path.exists() {
+        return Ok(());
+    }
+    fs::write(&pre_migration_path, original_bytes).map_err(map_fs_error)?;
+    log::info!(
+        "session_manifest_migrated path={} from={} to={} steps={}",
+        manifest_path.display(),
+        migration.from_version,
+        migration.to_version,
+        migration.steps.len()
+    );
+
+    Ok(())
+}
+
+/// Rewrites the `session.json` snapshot. Mutations go through
+/// `commit_session_manifest`, which journals them and decides when to call
+/// this.
+pub(super) fn write_session_manifest(
     manifest_path: &Path,
     manifest: &SessionManifest,
+    journal_cursor: Option<&SessionJournalCursor>,
 ) -> Result<(), HostErrorEnvelope> {
-    let manifest_bytes = serde_json::to_vec_pretty(manifest).map_err(|error| {
+    let serialization_error = |error: serde_json::Error| {
         HostErrorEnvelope::persistence(format!("세션 매니페스트를 직렬화하지 못했어요: {error}"))
-    })?;
+    };
+    let mut manifest_value = serde_json::to_value(manifest).map_err(serialization_error)?;
+    if let (Some(fields), Some(cursor)) = (manifest_value.as_object_mut(), journal_cursor) {
+        fields.insert(
+            MANIFEST_JOURNAL_CURSOR_FIELD.into(),
+            serde_json::to_value(cursor).map_err(serialization_error)?,
+        );
+    }
+    let manifest_bytes = serde_json::to_vec_pretty(&manifest_value).map_err(serialization_error)?;
     let temp_path = manifest_temp_path(manifest_path);
     let backup_path = manifest_backup_path(manifest_path);
 
@@ -354,7 +485,7 @@ fn write_session_manifest_once(
     Ok(())
 }
 
-fn is_retryable_manifest_write_error(error: &std::io::Error) -> bool {
+pub(super) fn is_retryable_manifest_write_error(error: &std::io::Error) -> bool {
     matches!(error.kind(), std::io::ErrorKind::PermissionDenied)
         || matches!(error.raw_os_error(), Some(32 | 33 | 5))
 }
@@ -389,7 +520,9 @@ pub fn set_manifest_write_retryable_failures_for_tests(manifest_path: &Path, fai
         .insert(manifest_path.to_path_buf(), failures);
 }
 
-fn take_injected_manifest_write_failure(manifest_path: &Path) -> Option<std::io::Error> {
+/// Test hook shared by the snapshot write and the journal append, which are
+/// both commit points for `manifest_path`.
+pub(super) fn take_injected_manifest_write_failure(manifest_path: &Path) -> Option<std::io::Error> {
     let injection = MANIFEST_WRITE_FAILURE_INJECTION
         .get_or_init(|| Mutex::new(ManifestWriteFailureInjection::default()));
     let mut guard = injection
@@ -423,7 +556,14 @@ fn manifest_backup_path(manifest_path: &Path) -> PathBuf {
     manifest_path.with_extension("json.bak")
 }
 
-fn generate_session_id() -> String {
+/// `session.json.v1.bak` for a manifest migrated from `session-manifest/v1`.
+pub fn manifest_pre_migration_path(manifest_path: &Path, from_version: &str) -> PathBuf {
+    let revision = from_version.rsplit('/').next().unwrap_or(from_version);
+
+    manifest_path.with_extension(format!("json.{revision}.bak"))
+}
+
+pub(crate) fn generate_session_id() -> String {
     let unix_nanos = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
@@ -0,0 +1,624 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+    time::SystemTime,
+};
+
+use flate2::{write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    capture::IN_FLIGHT_CAPTURE_SESSIONS,
+    contracts::dto::{is_valid_session_id, HostErrorEnvelope},
+    diagnostics::audit_log::{try_append_operator_audit_record, OperatorAuditRecordInput},
+    handoff::project_post_end_state_in_dir,
+    session::{
+        session_manifest::{
+            current_timestamp, rfc3339_to_unix_seconds, SessionManifest, SESSION_POST_END_COMPLETED,
+        },
+        session_paths::SessionPaths,
+        session_repository::read_session_manifest,
+    },
+    timing::project_session_timing,
+};
+
+const SESSION_RETENTION_POLICY_SCHEMA_VERSION: &str = "session-retention-policy/v1";
+const SESSION_RETENTION_REPORT_SCHEMA_VERSION: &str = "session-retention-report/v1";
+const DEFAULT_KEEP_DAYS: u32 = 30;
+const MAX_KEEP_DAYS: u32 = 365;
+const SECONDS_PER_DAY: u64 = 86_400;
+const ARCHIVE_GRACE_SECONDS: u64 = 60 * 60;
+const ARCHIVE_EXTENSION: &str = "tar.gz";
+
+/// Serialises retention passes so the startup pass and an operator-triggered
+/// run never archive or delete the same session twice.
+static SESSION_RETENTION_PASS_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionRetentionPolicy {
+    pub schema_version: String,
+    #[serde(default = "default_keep_days")]
+    pub keep_days: u32,
+    #[serde(default = "default_true")]
+    pub keep_until_handoff: bool,
+    #[serde(default = "default_true")]
+    pub archive_ended_sessions: bool,
+}
+
+impl Default for SessionRetentionPolicy {
+    fn default() -> Self {
+        Self {
+            schema_version: SESSION_RETENTION_POLICY_SCHEMA_VERSION.into(),
+            keep_days: DEFAULT_KEEP_DAYS,
+            keep_until_handoff: true,
+            archive_ended_sessions: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionRetentionEntryDto {
+    pub session_id: String,
+    pub reason: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archive_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionRetentionReportDto {
+    pub schema_version: String,
+    pub evaluated_at: String,
+    pub policy: SessionRetentionPolicy,
+    pub archived: Vec<SessionRetentionEntryDto>,
+    pub purged: Vec<SessionRetentionEntryDto>,
+    pub retained: Vec<SessionRetentionEntryDto>,
+}
+
+enum RetentionDecision {
+    Retain(&'static str),
+    Archive,
+    Purge,
+}
+
+pub fn load_session_retention_policy_in_dir(
+    base_dir: &Path,
+) -> Result<SessionRetentionPolicy, HostErrorEnvelope> {
+    let policy_path = resolve_session_retention_policy_path(base_dir);
+
+    if !policy_path.is_file() {
+        return Ok(SessionRetentionPolicy::default());
+    }
+
+    let bytes = fs::read_to_string(&policy_path).map_err(map_fs_error)?;
+    let mut policy: SessionRetentionPolicy = serde_json::from_str(&bytes).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("세션 보관 정책을 읽지 못했어요: {error}"))
+    })?;
+
+    if policy.schema_version != SESSION_RETENTION_POLICY_SCHEMA_VERSION {
+        policy.schema_version = SESSION_RETENTION_POLICY_SCHEMA_VERSION.into();
+    }
+
+    Ok(policy)
+}
+
+pub fn save_session_retention_policy_in_dir(
+    base_dir: &Path,
+    policy: SessionRetentionPolicy,
+) -> Result<SessionRetentionPolicy, HostErrorEnvelope> {
+    validate_session_retention_policy(&policy)?;
+
+    let policy = SessionRetentionPolicy {
+        schema_version: SESSION_RETENTION_POLICY_SCHEMA_VERSION.into(),
+        ..policy
+    };
+    let policy_path = resolve_session_retention_policy_path(base_dir);
+    let policy_dir = policy_path.parent().ok_or_else(|| {
+        HostErrorEnvelope::persistence("세션 보관 정책 경로를 준비하지 못했어요.")
+    })?;
+    fs::create_dir_all(policy_dir).map_err(map_fs_error)?;
+    let bytes = serde_json::to_vec_pretty(&policy).map_err(|error| {
+        HostErrorEnvelope::persistence(format!("세션 보관 정책을 직렬화하지 못했어요: {error}"))
+    })?;
+    write_json_bytes_atomically(&policy_path, &bytes)?;
+
+    Ok(policy)
+}
+
+pub fn validate_session_retention_policy(
+    policy: &SessionRetentionPolicy,
+) -> Result<(), HostErrorEnvelope> {
+    if !(1..=MAX_KEEP_DAYS).contains(&policy.keep_days) {
+        return Err(HostErrorEnvelope::validation_message(
+            "세션 보관 기간은 1일에서 365일 사이여야 해요.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Runs one retention pass on a background thread so archiving large RAW
+/// sessions never delays app start-up.
+pub fn spawn_session_retention_in_dir(base_dir: &Path) {
+    let base_dir = base_dir.to_path_buf();
+
+    thread::spawn(
+        move || match apply_session_retention_in_dir(&base_dir, SystemTime::now()) {
+            Ok(report) => log::info!(
+                "session_retention_completed archived={} purged={} retained={}",
+                report.archived.len(),
+                report.purged.len(),
+                report.retained.len()
+            ),
+            Err(error) => log::warn!("session_retention_failed reason={}", error.message),
+        },
+    );
+}
+
+/// Failures on one session are logged and reported as retained so the rest
+/// of the pass still runs.
+pub fn apply_session_retention_in_dir(
+    base_dir: &Path,
+    now: SystemTime,
+) -> Result<SessionRetentionReportDto, HostErrorEnvelope> {
+    let _pass_guard = SESSION_RETENTION_PASS_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let policy = load_session_retention_policy_in_dir(base_dir)?;
+    let evaluated_at = current_timestamp(now)?;
+    let now_seconds = rfc3339_to_unix_seconds(&evaluated_at)?;
+    let keep_seconds = u64::from(policy.keep_days) * SECONDS_PER_DAY;
+    let in_flight_session_ids = read_in_flight_session_ids();
+    let mut report = SessionRetentionReportDto {
+        schema_version: SESSION_RETENTION_REPORT_SCHEMA_VERSION.into(),
+        evaluated_at: evaluated_at.clone(),
+        policy: policy.clone(),
+        archived: Vec::new(),
+        purged: Vec::new(),
+        retained: Vec::new(),
+    };
+
+    for session_id in list_session_ids(base_dir)? {
+        if in_flight_session_ids.contains(&session_id) {
+            report
+                .retained
+                .push(retention_entry(&session_id, "active-session", None));
+            continue;
+        }
+
+        let paths = SessionPaths::new(base_dir, &session_id);
+        let manifest = match read_session_manifest(&paths.manifest_path)
+            .and_then(|manifest| project_session_timing(manifest, now))
+            .and_then(|manifest| project_post_end_state_in_dir(base_dir, manifest, now))
+        {
+            Ok(manifest) => manifest,
+            Err(_) => {
+                report
+                    .retained
+                    .push(retention_entry(&session_id, "manifest-unreadable", None));
+                continue;
+            }
+        };
+
+        match decide_session_retention(&policy, &manifest, now_seconds, keep_seconds) {
+            RetentionDecision::Retain(reason) => {
+                report
+                    .retained
+                    .push(retention_entry(&session_id, reason, None));
+            }
+            RetentionDecision::Purge => {
+                if let Err(error) = fs::remove_dir_all(&paths.session_root) {
+                    log::warn!(
+                        "session_purge_failed session={} reason={}",
+                        session_id,
+                        error
+                    );
+                    report
+                        .retained
+                        .push(retention_entry(&session_id, "purge-failed", None));
+                    continue;
+                }
+                append_session_purge_audit_record(base_dir, &evaluated_at, &manifest);
+                report
+                    .purged
+                    .push(retention_entry(&session_id, "retention-expired", None));
+            }
+            RetentionDecision::Archive => {
+                let archive_path = resolve_session_archive_path(base_dir, &manifest);
+                // An earlier pass archived and audited this session but could
+                // not remove its folder, so only the cleanup is retried.
+                if archive_path.is_file() {
+                    let archive_entry = Some(archive_path.to_string_lossy().into_owned());
+                    if let Err(error) = fs::remove_dir_all(&paths.session_root) {
+                        log::warn!(
+                            "session_archive_cleanup_failed session={} reason={}",
+                            session_id,
+                            error
+                        );
+                        report.retained.push(retention_entry(
+                            &session_id,
+                            "archive-cleanup-failed",
+                            archive_entry,
+                        ));
+                        continue;
+                    }
+                    report.archived.push(retention_entry(
+                        &session_id,
+                        "already-archived",
+                        archive_entry,
+                    ));
+                    continue;
+                }
+
+                if let Err(error) = archive_session_root(&paths, &manifest, &archive_path) {
+                    log::warn!(
+                        "session_archive_failed session={} reason={}",
+                        session_id,
+                        error.message
+                    );
+                    report
+                        .retained
+                        .push(retention_entry(&session_id, "archive-failed", None));
+                    continue;
+                }
+                append_session_archive_audit_record(
+                    base_dir,
+                    &evaluated_at,
+                    &manifest,
+                    &archive_path,
+                );
+                if let Err(error) = fs::remove_dir_all(&paths.session_root) {
+                    log::warn!(
+                        "session_archive_cleanup_failed session={} reason={}",
+                        session_id,
+                        error
+                    );
+                }
+                log::info!(
+                    "session_archived session={} archive={}",
+                    session_id,
+                    archive_path.display()
+                );
+                report.archived.push(retention_entry(
+                    &session_id,
+                    "session-ended",
+                    Some(archive_path.to_string_lossy().into_owned()),
+                ));
+            }
+        }
+    }
+
+    purge_expired_archives(
+        base_dir,
+        &evaluated_at,
+        now_seconds,
+        keep_seconds,
+        &mut report,
+    );
+
+    Ok(report)
+}
+
+pub fn resolve_session_archive_root(base_dir: &Path) -> PathBuf {
+    base_dir.join("session-archive")
+}
+
+fn decide_session_retention(
+    policy: &SessionRetentionPolicy,
+    manifest: &SessionManifest,
+    now_seconds: u64,
+    keep_seconds: u64,
+) -> RetentionDecision {
+    let timing_phase = manifest
+        .timing
+        .as_ref()
+        .map(|timing| timing.phase.as_str())
+        .unwrap_or("active");
+
+    if manifest.post_end.is_none() && timing_phase != "ended" {
+        return RetentionDecision::Retain("active-session");
+    }
+
+    let post_end_state = manifest.post_end.as_ref().map(|post_end| post_end.state());
+
+    if policy.keep_until_handoff && post_end_state != Some(SESSION_POST_END_COMPLETED) {
+        return RetentionDecision::Retain("awaiting-handoff");
+    }
+
+    let last_activity_seconds = rfc3339_to_unix_seconds(&manifest.updated_at)
+        .or_else(|_| rfc3339_to_unix_seconds(&manifest.created_at))
+        .unwrap_or(now_seconds);
+
+    let idle_seconds = now_seconds.saturating_sub(last_activity_seconds);
+
+    if idle_seconds >= keep_seconds {
+        return RetentionDecision::Purge;
+    }
+
+    if idle_seconds < ARCHIVE_GRACE_SECONDS {
+        return RetentionDecision::Retain("recently-ended");
+    }
+
+    if policy.archive_ended_sessions {
+        RetentionDecision::Archive
+    } else {
+        RetentionDecision::Retain("within-retention")
+    }
+}
+
+fn list_session_ids(base_dir: &Path) -> Result<Vec<String>, HostErrorEnvelope> {
+    let sessions_root = base_dir.join("sessions");
+
+    if !sessions_root.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut session_ids = fs::read_dir(&sessions_root)
+        .map_err(map_fs_error)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .filter(|session_id| is_valid_session_id(session_id))
+        .collect::<Vec<_>>();
+    session_ids.sort();
+
+    Ok(session_ids)
+}
+
+fn read_in_flight_session_ids() -> Vec<String> {
+    IN_FLIGHT_CAPTURE_SESSIONS
+        .lock()
+        .map(|sessions| sessions.values().cloned().collect())
+        .unwrap_or_default()
+}
+
+fn resolve_session_archive_path(base_dir: &Path, manifest: &SessionManifest) -> PathBuf {
+    resolve_session_archive_root(base_dir)
+        .join(archive_date_folder(&manifest.updated_at))
+        .join(format!("{}.{ARCHIVE_EXTENSION}", manifest.session_id))
+}
+
+fn archive_session_root(
+    paths: &SessionPaths,
+    manifest: &SessionManifest,
+    archive_path: &Path,
+) -> Result<(), HostErrorEnvelope> {
+    let archive_dir = archive_path.parent().ok_or_else(|| {
+        HostErrorEnvelope::persistence("세션 보관 파일 경로를 준비하지 못했어요.")
+    })?;
+    fs::create_dir_all(archive_dir).map_err(map_fs_error)?;
+
+    let temp_path = archive_dir.join(format!("{}.{ARCHIVE_EXTENSION}.tmp", manifest.session_id));
+
+    let write_result = (|| -> Result<(), std::io::Error> {
+        let archive_file = fs::File::create(&temp_path)?;
+        let mut builder = tar::Builder::new(GzEncoder::new(archive_file, Compression::default()));
+        builder.append_dir_all(&manifest.session_id, &paths.session_root)?;
+        builder.into_inner()?.finish()?.sync_all()?;
+        fs::rename(&temp_path, archive_path)
+    })();
+
+    if let Err(error) = write_result {
+        let _ = fs::remove_file(&temp_path);
+        return Err(HostErrorEnvelope::persistence(format!(
+            "세션 보관 파일을 만들지 못했어요: {error}"
+        )));
+    }
+
+    Ok(())
+}
+
+fn purge_expired_archives(
+    base_dir: &Path,
+    evaluated_at: &str,
+    now_seconds: u64,
+    keep_seconds: u64,
+    report: &mut SessionRetentionReportDto,
+) {
+    let Ok(date_dirs) = fs::read_dir(resolve_session_archive_root(base_dir)) else {
+        return;
+    };
+
+    for entry in date_dirs {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        let date_dir = entry.path();
+        let Some(archived_on) = entry.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+        let Ok(archived_on_seconds) = rfc3339_to_unix_seconds(&format!("{archived_on}T00:00:00Z"))
+        else {
+            continue;
+        };
+
+        if !date_dir.is_dir() || now_seconds.saturating_sub(archived_on_seconds) < keep_seconds {
+            continue;
+        }
+
+        let Ok(archives) = fs::read_dir(&date_dir) else {
+            continue;
+        };
+        for archive in archives {
+            let Ok(archive) = archive else {
+                continue;
+            };
+            let archive_path = archive.path();
+            let Some(session_id) = archive
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_suffix(&format!(".{ARCHIVE_EXTENSION}")))
+                .filter(|session_id| is_valid_session_id(session_id))
+                .map(str::to_string)
+            else {
+                continue;
+            };
+
+            if let Err(error) = fs::remove_file(&archive_path) {
+                log::warn!(
+                    "session_archive_purge_failed session={} reason={}",
+                    session_id,
+                    error
+                );
+                report.retained.push(retention_entry(
+                    &session_id,
+                    "purge-failed",
+                    Some(archive_path.to_string_lossy().into_owned()),
+                ));
+                continue;
+            }
+            try_append_operator_audit_record(
+                base_dir,
+                OperatorAuditRecordInput {
+                    occurred_at: evaluated_at.into(),
+                    session_id: Some(session_id.clone()),
+                    event_category: "session-lifecycle",
+                    event_type: "session-archive-purged",
+                    summary: "보관 기간이 지난 세션 보관 파일을 삭제했어요.".into(),
+                    detail: format!("{archived_on} 날짜로 보관된 세션 결과물을 정리했어요."),
+                    actor_id: None,
+                    source: "session-retention",
+                    capture_id: None,
+                    preset_id: None,
+                    published_version: None,
+                    reason_code: Some("retention-expired".into()),
+                },
+            );
+            report.purged.push(retention_entry(
+                &session_id,
+                "archive-expired",
+                Some(archive_path.to_string_lossy().into_owned()),
+            ));
+        }
+
+        if fs::read_dir(&date_dir)
+            .map(|mut entries| entries.next().is_none())
+            .unwrap_or(false)
+        {
+            let _ = fs::remove_dir(&date_dir);
+        }
+    }
+}
+
+fn append_session_archive_audit_record(
+    base_dir: &Path,
+    occurred_at: &str,
+    manifest: &SessionManifest,
+    archive_path: &Path,
+) {
+    try_append_operator_audit_record(
+        base_dir,
+        OperatorAuditRecordInput {
+            occurred_at: occurred_at.into(),
+            session_id: Some(manifest.session_id.clone()),
+            event_category: "session-lifecycle",
+            event_type: "session-archived",
+            summary: "끝난 세션 폴더를 보관 파일로 옮겼어요.".into(),
+            detail: format!(
+                "촬영 {}건과 결과물을 {}에 보관했어요.",
+                manifest.captures.len(),
+                archive_path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default()
+            ),
+            actor_id: None,
+            source: "session-retention",
+            capture_id: None,
+            preset_id: manifest.active_preset_id.clone(),
+            published_version: manifest
+                .active_preset
+                .as_ref()
+                .map(|preset| preset.published_version.clone()),
+            reason_code: Some("session-ended".into()),
+        },
+    );
+}
+
+fn append_session_purge_audit_record(
+    base_dir: &Path,
+    occurred_at: &str,
+    manifest: &SessionManifest,
+) {
+    try_append_operator_audit_record(
+        base_dir,
+        OperatorAuditRecordInput {
+            occurred_at: occurred_at.into(),
+            session_id: Some(manifest.session_id.clone()),
+            event_category: "session-lifecycle",
+            event_type: "session-purged",
+            summary: "보관 기간이 지난 세션 폴더를 삭제했어요.".into(),
+            detail: format!(
+                "촬영 {}건과 결과물을 보관 정책에 따라 정리했어요.",
+                manifest.captures.len()
+            ),
+            actor_id: None,
+            source: "session-retention",
+            capture_id: None,
+            preset_id: manifest.active_preset_id.clone(),
+            published_version: manifest
+                .active_preset
+                .as_ref()
+                .map(|preset| preset.published_version.clone()),
+            reason_code: Some("retention-expired".into()),
+        },
+    );
+}
+
+fn archive_date_folder(timestamp: &str) -> String {
+    timestamp
+        .get(..10)
+        .filter(|date| rfc3339_to_unix_seconds(&format!("{date}T00:00:00Z")).is_ok())
+        .unwrap_or("undated")
+        .to_string()
+}
+
+fn retention_entry(
+    session_id: &str,
+    reason: &str,
+    archive_path: Option<String>,
+) -> SessionRetentionEntryDto {
+    SessionRetentionEntryDto {
+        session_id: session_id.into(),
+        reason: reason.into(),
+        archive_path,
+    }
+}
+
+fn resolve_session_retention_policy_path(base_dir: &Path) -> PathBuf {
+    base_dir.join("session-retention").join("policy.json")
+}
+
+fn write_json_bytes_atomically(path: &Path, bytes: &[u8]) -> Result<(), HostErrorEnvelope> {
+    let temp_path = path.with_extension("json.tmp");
+
+    if temp_path.exists() {
+        fs::remove_file(&temp_path).map_err(map_fs_error)?;
+    }
+
+    fs::write(&temp_path, bytes).map_err(map_fs_error)?;
+
+    if let Err(error) = fs::rename(&temp_path, path) {
+        let _ = fs::remove_file(&temp_path);
+
+        return Err(map_fs_error(error));
+    }
+
+    Ok(())
+}
+
+fn default_keep_days() -> u32 {
+    DEFAULT_KEEP_DAYS
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn map_fs_error(error: std::io::Error) -> HostErrorEnvelope {
+    HostErrorEnvelope::persistence(format!("세션 보관 파일을 정리하지 못했어요: {error}"))
+}
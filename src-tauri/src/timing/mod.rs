@@ -1,14 +1,17 @@
 use std::{fs::OpenOptions, io::Write, path::Path, time::SystemTime};
 
 use crate::{
+    branch_config::operational_toggles::{
+        resolve_booth_operational_toggles_in_dir, TIMING_WARNING_PHASE_ENABLED,
+    },
     contracts::dto::HostErrorEnvelope,
     diagnostics::audit_log::{try_append_operator_audit_record, OperatorAuditRecordInput},
     session::{
+        session_journal::{commit_session_manifest, SessionJournalEvent},
         session_manifest::{
             current_timestamp, rfc3339_to_unix_seconds, SessionManifest, SessionTiming,
         },
         session_paths::SessionPaths,
-        session_repository::write_session_manifest,
     },
 };
 
@@ -48,7 +51,15 @@ pub fn sync_session_timing_in_dir(
         return Ok(manifest);
     };
 
-    let evaluated_phase = evaluate_phase(&current_timing, now)?;
+    let evaluated_phase = match evaluate_phase(&current_timing, now)? {
+        TimingPhase::Warning
+            if !resolve_booth_operational_toggles_in_dir(base_dir)
+                .bool_value(TIMING_WARNING_PHASE_ENABLED) =>
+        {
+            TimingPhase::Active
+        }
+        phase => phase,
+    };
     let mut next_timing = current_timing.clone();
     let mut should_persist = false;
 
@@ -136,9 +147,15 @@ pub fn sync_session_timing_in_dir(
         return Ok(manifest);
     }
 
-    manifest.timing = Some(next_timing);
+    manifest.timing = Some(next_timing.clone());
     manifest.updated_at = event_timestamp;
-    write_session_manifest(manifest_path, &manifest)?;
+    commit_session_manifest(
+        manifest_path,
+        &manifest,
+        SessionJournalEvent::TimingTransition {
+            timing: next_timing,
+        },
+    )?;
 
     Ok(manifest)
 }
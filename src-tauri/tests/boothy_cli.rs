@@ -0,0 +1,104 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::cli::{
+    run_cli, CLI_EXIT_CAPABILITY_DENIED, CLI_EXIT_SUCCESS, CLI_EXIT_USAGE, CLI_EXIT_VALIDATION,
+};
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-cli-{test_name}-{stamp}"))
+}
+
+fn invoke(args: &[&str]) -> (i32, String, String) {
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    let exit_code = run_cli(
+        args.iter().map(|arg| arg.to_string()).collect(),
+        &mut stdout,
+        &mut stderr,
+    );
+
+    (
+        exit_code,
+        String::from_utf8(stdout).expect("stdout should be utf-8"),
+        String::from_utf8(stderr).expect("stderr should be utf-8"),
+    )
+}
+
+#[test]
+fn cli_starts_a_session_and_prints_the_result_as_json() {
+    let base_dir = unique_test_root("start-session");
+    let base_dir_arg = base_dir.to_string_lossy().into_owned();
+
+    let (exit_code, stdout, _) = invoke(&[
+        "--base-dir",
+        &base_dir_arg,
+        "start-session",
+        "--input",
+        r#"{"name":"Kim","phoneLastFour":"4821"}"#,
+    ]);
+
+    assert_eq!(exit_code, CLI_EXIT_SUCCESS);
+    let result: serde_json::Value = serde_json::from_str(&stdout).expect("stdout should be json");
+    let session_id = result["sessionId"]
+        .as_str()
+        .expect("session id should be present");
+    assert!(base_dir
+        .join("sessions")
+        .join(session_id)
+        .join("session.json")
+        .is_file());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn cli_maps_host_errors_to_stable_exit_codes() {
+    let base_dir = unique_test_root("host-errors");
+    let base_dir_arg = base_dir.to_string_lossy().into_owned();
+
+    let (exit_code, stdout, _) = invoke(&[
+        "--base-dir",
+        &base_dir_arg,
+        "start-session",
+        "--input",
+        r#"{"name":"","phoneLastFour":"12"}"#,
+    ]);
+
+    assert_eq!(exit_code, CLI_EXIT_VALIDATION);
+    let error: serde_json::Value = serde_json::from_str(&stdout).expect("stdout should be json");
+    assert_eq!(error["code"], "validation-error");
+
+    let (exit_code, stdout, _) = invoke(&["--base-dir", &base_dir_arg, "session-summary"]);
+
+    assert_eq!(exit_code, CLI_EXIT_CAPABILITY_DENIED);
+    let error: serde_json::Value = serde_json::from_str(&stdout).expect("stdout should be json");
+    assert_eq!(error["code"], "capability-denied");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn cli_rejects_unknown_commands_and_missing_input_as_usage_errors() {
+    let (exit_code, stdout, stderr) = invoke(&["--base-dir", "unused", "open-the-pod-bay"]);
+
+    assert_eq!(exit_code, CLI_EXIT_USAGE);
+    assert!(stdout.is_empty());
+    assert!(stderr.contains("usage: boothy-cli"));
+
+    let (exit_code, _, _) = invoke(&["--base-dir", "unused", "branch-rollout"]);
+
+    assert_eq!(exit_code, CLI_EXIT_USAGE);
+
+    let (exit_code, _, _) = invoke(&[]);
+
+    assert_eq!(exit_code, CLI_EXIT_USAGE);
+}
@@ -0,0 +1,344 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    branch_config::{
+        apply_branch_rollout_in_dir,
+        local_settings::{
+            load_branch_local_settings_diff_in_dir, load_branch_local_settings_in_dir,
+            update_branch_local_settings_in_dir,
+        },
+    },
+    commands::runtime_commands::capability_snapshot_for_profile,
+    contracts::dto::{
+        BranchLocalSettingsDiffInputDto, BranchLocalSettingsDto, BranchLocalSettingsQueryInputDto,
+        BranchLocalSettingsUpdateInputDto, BranchRolloutInputDto, SessionStartInputDto,
+    },
+    handoff::sync_post_end_state_in_dir,
+    session::{
+        session_manifest::{SessionManifest, SessionPostEnd},
+        session_paths::SessionPaths,
+        session_repository::start_session_in_dir,
+    },
+};
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-branch-settings-{test_name}-{stamp}"))
+}
+
+fn update_input(
+    settings: BranchLocalSettingsDto,
+    expected_revision: Option<u32>,
+) -> BranchLocalSettingsUpdateInputDto {
+    BranchLocalSettingsUpdateInputDto {
+        branch_id: "gangnam-01".into(),
+        settings,
+        expected_revision,
+        actor_id: "ops-lee".into(),
+        actor_label: "Lee Ops".into(),
+    }
+}
+
+fn gangnam_settings() -> BranchLocalSettingsDto {
+    BranchLocalSettingsDto {
+        contact_phone: Some("02-555-0101".into()),
+        contact_email: Some("gangnam@boothy.local".into()),
+        contact_kakao: None,
+        support_hours: None,
+        operational_toggles: vec!["capture-countdown-seconds=4".into()],
+    }
+}
+
+#[test]
+fn edits_are_versioned_with_actor_and_survive_rollouts() {
+    let base_dir = unique_test_root("history");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir);
+
+    let loaded = load_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        BranchLocalSettingsQueryInputDto {
+            branch_id: "gangnam-01".into(),
+        },
+    )
+    .expect("settings should load");
+    assert_eq!(loaded.revision, 0);
+    assert_eq!(loaded.settings, gangnam_settings());
+    assert!(loaded.history.is_empty());
+
+    let updated = update_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        update_input(
+            BranchLocalSettingsDto {
+                contact_phone: Some(" 02-555-0199 ".into()),
+                support_hours: Some("10:00-22:00".into()),
+                contact_email: Some("".into()),
+                ..gangnam_settings()
+            },
+            Some(0),
+        ),
+    )
+    .expect("settings should update");
+    assert_eq!(updated.revision, 1);
+    assert_eq!(
+        updated.settings.contact_phone.as_deref(),
+        Some("02-555-0199")
+    );
+    assert_eq!(updated.settings.contact_email, None);
+    let entry = &updated.history[0];
+    assert_eq!(entry.actor_id, "ops-lee");
+    assert_eq!(entry.actor_label, "Lee Ops");
+    let fields: Vec<&str> = entry
+        .changes
+        .iter()
+        .map(|change| change.field.as_str())
+        .collect();
+    assert_eq!(fields, ["contact-phone", "contact-email", "support-hours"]);
+    assert_eq!(entry.changes[0].before.as_deref(), Some("02-555-0101"));
+    assert_eq!(entry.changes[0].after.as_deref(), Some("02-555-0199"));
+
+    let stale = update_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        update_input(gangnam_settings(), Some(0)),
+    )
+    .expect_err("stale revision should be rejected");
+    assert_eq!(stale.code, "validation-error");
+
+    update_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        update_input(
+            BranchLocalSettingsDto {
+                operational_toggles: Vec::new(),
+                ..updated.settings.clone()
+            },
+            Some(1),
+        ),
+    )
+    .expect("second update should save");
+
+    let diff = load_branch_local_settings_diff_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        BranchLocalSettingsDiffInputDto {
+            branch_id: "gangnam-01".into(),
+            from_revision: 0,
+            to_revision: None,
+        },
+    )
+    .expect("diff should load");
+    assert_eq!(diff.to_revision, 2);
+    let fields: Vec<&str> = diff
+        .changes
+        .iter()
+        .map(|change| change.field.as_str())
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            "contact-phone",
+            "contact-email",
+            "support-hours",
+            "bounded-operational-toggle"
+        ]
+    );
+
+    apply_branch_rollout_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        BranchRolloutInputDto {
+            branch_ids: vec!["gangnam-01".into()],
+            target_build_version: "boothy-2026.03.27.1".into(),
+            target_preset_stack_version: "catalog-2026.03.27".into(),
+            actor_id: "release-kim".into(),
+            actor_label: "Kim Release".into(),
+        },
+    )
+    .expect("rollout should apply");
+    let after_rollout = load_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        BranchLocalSettingsQueryInputDto {
+            branch_id: "gangnam-01".into(),
+        },
+    )
+    .expect("settings should load");
+    assert_eq!(after_rollout.revision, 2);
+    assert_eq!(
+        after_rollout.settings.support_hours.as_deref(),
+        Some("10:00-22:00")
+    );
+    assert_eq!(after_rollout.history.len(), 2);
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn invalid_fields_are_reported_individually_without_saving() {
+    let base_dir = unique_test_root("invalid");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir);
+
+    let error = update_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        update_input(
+            BranchLocalSettingsDto {
+                contact_phone: Some("555-0101".into()),
+                contact_email: Some("gangnam@boothy".into()),
+                contact_kakao: Some("@boothy gangnam".into()),
+                support_hours: Some("22:00-10:00".into()),
+                operational_toggles: vec!["Queue Badge".into()],
+            },
+            None,
+        ),
+    )
+    .expect_err("invalid settings should be rejected");
+    assert_eq!(error.code, "validation-error");
+    let field_errors = error.field_errors.expect("field errors should be present");
+    assert!(field_errors.contact_phone.is_some());
+    assert!(field_errors.contact_email.is_some());
+    assert!(field_errors.contact_kakao.is_some());
+    assert!(field_errors.support_hours.is_some());
+    assert!(field_errors.operational_toggles.is_some());
+    assert!(field_errors.name.is_none());
+
+    let denied = update_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot_for_profile("booth-only", false),
+        update_input(gangnam_settings(), None),
+    )
+    .expect_err("booth runtime should not edit settings");
+    assert_eq!(denied.code, "capability-denied");
+
+    let loaded = load_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        BranchLocalSettingsQueryInputDto {
+            branch_id: "gangnam-01".into(),
+        },
+    )
+    .expect("settings should load");
+    assert_eq!(loaded.settings, gangnam_settings());
+    assert_eq!(loaded.revision, 0);
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn phone_required_guidance_shows_the_booth_branch_contact() {
+    let base_dir = unique_test_root("phone-required");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir);
+    fs::write(
+        base_dir.join("branch-config").join("branch-profile.json"),
+        serde_json::to_vec_pretty(&serde_json::json!({
+            "schemaVersion": "branch-profile/v1",
+            "displayName": "강남 1호점",
+            "branchId": "gangnam-01"
+        }))
+        .expect("profile should serialize"),
+    )
+    .expect("profile should write");
+    update_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        update_input(
+            BranchLocalSettingsDto {
+                support_hours: Some("10:00-22:00".into()),
+                ..gangnam_settings()
+            },
+            None,
+        ),
+    )
+    .expect("settings should update");
+
+    let session_id = start_session_in_dir(
+        &base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+    )
+    .expect("session should start")
+    .session_id;
+    let manifest_path = SessionPaths::new(&base_dir, &session_id).manifest_path;
+    let mut manifest: SessionManifest = serde_json::from_str(
+        &fs::read_to_string(&manifest_path).expect("manifest should be readable"),
+    )
+    .expect("manifest should deserialize");
+    manifest
+        .timing
+        .as_mut()
+        .expect("session should have timing")
+        .phase = "ended".into();
+    manifest.lifecycle.stage = "phone-required".into();
+
+    let manifest =
+        sync_post_end_state_in_dir(&base_dir, &manifest_path, manifest, SystemTime::now())
+            .expect("post-end should evaluate");
+    let Some(SessionPostEnd::PhoneRequired(post_end)) = manifest.post_end else {
+        panic!("session should be phone-required");
+    };
+    assert_eq!(
+        post_end.support_action_label.as_deref(),
+        Some("지점 문의: 02-555-0101 (운영 10:00-22:00)")
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+fn seed_branch_store(base_dir: &Path) {
+    let branch_config_dir = base_dir.join("branch-config");
+    fs::create_dir_all(&branch_config_dir).expect("branch config directory should exist");
+    let baseline = |build_version: &str, preset_stack_version: &str| {
+        serde_json::json!({
+            "buildVersion": build_version,
+            "presetStackVersion": preset_stack_version,
+            "approvedAt": "2026-03-20T00:10:00.000Z",
+            "actorId": "release-kim",
+            "actorLabel": "Kim Release"
+        })
+    };
+
+    let store = serde_json::json!({
+        "schemaVersion": "branch-rollout-store/v1",
+        "approvedBaselines": [
+            baseline("boothy-2026.03.20.4", "catalog-2026.03.20"),
+            baseline("boothy-2026.03.27.1", "catalog-2026.03.27")
+        ],
+        "branches": [
+            {
+                "branchId": "gangnam-01",
+                "displayName": "강남 1호점",
+                "deploymentBaseline": baseline("boothy-2026.03.20.4", "catalog-2026.03.20"),
+                "rollbackBaseline": null,
+                "pendingBaseline": null,
+                "localSettings": {
+                    "contactPhone": "02-555-0101",
+                    "contactEmail": "gangnam@boothy.local",
+                    "operationalToggles": ["capture-countdown-seconds=4"]
+                },
+                "activeSession": null
+            }
+        ]
+    });
+
+    fs::write(
+        branch_config_dir.join("state.json"),
+        serde_json::to_vec_pretty(&store).expect("store should serialize"),
+    )
+    .expect("store should write");
+}
@@ -0,0 +1,297 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    branch_config::{
+        apply_branch_rollout_in_dir,
+        local_settings::update_branch_local_settings_in_dir,
+        operational_toggles::{
+            compare_build_versions, load_branch_operational_toggles_in_dir,
+            resolve_booth_operational_toggles_in_dir, CAPTURE_COUNTDOWN_SECONDS,
+            RENDER_FINAL_JPEG_QUALITY, TIMING_WARNING_PHASE_ENABLED,
+        },
+    },
+    commands::runtime_commands::capability_snapshot_for_profile,
+    contracts::dto::{
+        BranchLocalSettingsDto, BranchLocalSettingsQueryInputDto,
+        BranchLocalSettingsUpdateInputDto, BranchRolloutInputDto, SessionStartInputDto,
+    },
+    session::{
+        session_manifest::{rfc3339_to_unix_seconds, SessionManifest},
+        session_paths::SessionPaths,
+        session_repository::start_session_in_dir,
+    },
+    timing::sync_session_timing_in_dir,
+};
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-branch-toggles-{test_name}-{stamp}"))
+}
+
+fn toggles_update(
+    branch_id: &str,
+    operational_toggles: Vec<String>,
+) -> BranchLocalSettingsUpdateInputDto {
+    BranchLocalSettingsUpdateInputDto {
+        branch_id: branch_id.into(),
+        settings: BranchLocalSettingsDto {
+            contact_phone: Some("02-555-0101".into()),
+            operational_toggles,
+            ..BranchLocalSettingsDto::default()
+        },
+        expected_revision: None,
+        actor_id: "ops-lee".into(),
+        actor_label: "Lee Ops".into(),
+    }
+}
+
+#[test]
+fn toggles_are_validated_against_the_registry_and_deployed_build() {
+    let base_dir = unique_test_root("registry");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir);
+
+    for entries in [
+        vec!["unknown-toggle".to_string()],
+        vec!["capture-countdown-seconds=11".into()],
+        vec!["render-final-jpeg-quality".into()],
+        vec![
+            "timing-warning-phase-enabled".into(),
+            "timing-warning-phase-enabled=false".into(),
+        ],
+    ] {
+        let error = update_branch_local_settings_in_dir(
+            &base_dir,
+            &capability_snapshot,
+            toggles_update("gangnam-01", entries),
+        )
+        .expect_err("invalid toggles should be rejected");
+        assert!(error
+            .field_errors
+            .is_some_and(|field_errors| field_errors.operational_toggles.is_some()));
+    }
+
+    let unsupported = update_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        toggles_update("hongdae-02", vec!["render-final-jpeg-quality=high".into()]),
+    )
+    .expect_err("toggle newer than the deployed build should be rejected");
+    assert!(unsupported
+        .field_errors
+        .and_then(|field_errors| field_errors.operational_toggles)
+        .is_some_and(|message| message.contains("render-final-jpeg-quality")));
+
+    update_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        toggles_update(
+            "gangnam-01",
+            vec![
+                "capture-countdown-seconds=5".into(),
+                "render-final-jpeg-quality=high".into(),
+            ],
+        ),
+    )
+    .expect("supported toggles should save");
+
+    let result = load_branch_operational_toggles_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        BranchLocalSettingsQueryInputDto {
+            branch_id: "gangnam-01".into(),
+        },
+    )
+    .expect("toggles should load");
+    assert_eq!(result.registry.len(), result.toggles.len());
+    let countdown = result
+        .toggles
+        .iter()
+        .find(|toggle| toggle.name == CAPTURE_COUNTDOWN_SECONDS)
+        .expect("countdown toggle should resolve");
+    assert_eq!(countdown.value, "5");
+    assert_eq!(countdown.source, "branch");
+    let warning = result
+        .toggles
+        .iter()
+        .find(|toggle| toggle.name == TIMING_WARNING_PHASE_ENABLED)
+        .expect("warning toggle should resolve");
+    assert_eq!(warning.value, "true");
+    assert_eq!(warning.source, "default");
+    assert!(result.toggles.iter().all(|toggle| toggle.supported));
+
+    assert_eq!(
+        compare_build_versions("boothy-2026.03.20.10", "boothy-2026.03.20.9"),
+        Some(std::cmp::Ordering::Greater)
+    );
+    assert_eq!(
+        compare_build_versions("boothy-2026.03", "boothy-2026.03.20.9"),
+        None
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn rollout_rejects_a_target_build_older_than_an_enabled_toggle() {
+    let base_dir = unique_test_root("rollout");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir);
+    update_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        toggles_update("gangnam-01", vec!["render-final-jpeg-quality=high".into()]),
+    )
+    .expect("toggle should save");
+
+    let result = apply_branch_rollout_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        BranchRolloutInputDto {
+            branch_ids: vec!["gangnam-01".into(), "hongdae-02".into()],
+            target_build_version: "boothy-2026.03.20.4".into(),
+            target_preset_stack_version: "catalog-2026.03.20".into(),
+            actor_id: "release-kim".into(),
+            actor_label: "Kim Release".into(),
+        },
+    )
+    .expect("rollout should return typed outcomes");
+
+    assert_eq!(result.outcomes[0].result, "rejected");
+    let rejection = result.outcomes[0]
+        .rejection
+        .as_ref()
+        .expect("rejection should be present");
+    assert_eq!(rejection.code, "unsupported-operational-toggle");
+    assert!(rejection.message.contains(RENDER_FINAL_JPEG_QUALITY));
+    assert_eq!(
+        result.outcomes[0].effective_baseline.build_version,
+        "boothy-2026.03.27.1"
+    );
+    assert_eq!(result.outcomes[1].result, "applied");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn booth_timing_skips_the_warning_phase_when_the_branch_turns_it_off() {
+    let base_dir = unique_test_root("timing");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir);
+    fs::write(
+        base_dir.join("branch-config").join("branch-profile.json"),
+        serde_json::to_vec_pretty(&serde_json::json!({
+            "schemaVersion": "branch-profile/v1",
+            "displayName": "강남 1호점",
+            "branchId": "gangnam-01"
+        }))
+        .expect("profile should serialize"),
+    )
+    .expect("profile should write");
+    assert!(resolve_booth_operational_toggles_in_dir(&base_dir)
+        .bool_value(TIMING_WARNING_PHASE_ENABLED));
+
+    update_branch_local_settings_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        toggles_update(
+            "gangnam-01",
+            vec!["timing-warning-phase-enabled=false".into()],
+        ),
+    )
+    .expect("toggle should save");
+    assert!(!resolve_booth_operational_toggles_in_dir(&base_dir)
+        .bool_value(TIMING_WARNING_PHASE_ENABLED));
+
+    let session_id = start_session_in_dir(
+        &base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+    )
+    .expect("session should start")
+    .session_id;
+    let manifest_path = SessionPaths::new(&base_dir, &session_id).manifest_path;
+    let manifest: SessionManifest = serde_json::from_str(
+        &fs::read_to_string(&manifest_path).expect("manifest should be readable"),
+    )
+    .expect("manifest should deserialize");
+    let timing = manifest.timing.clone().expect("session should have timing");
+    let in_warning_window = UNIX_EPOCH
+        + Duration::from_secs(
+            rfc3339_to_unix_seconds(&timing.warning_at).expect("warning time should parse") + 1,
+        );
+
+    let manifest =
+        sync_session_timing_in_dir(&base_dir, &manifest_path, manifest, in_warning_window)
+            .expect("timing should sync");
+    let timing = manifest.timing.expect("session should have timing");
+    assert_eq!(timing.phase, "active");
+    assert!(timing.warning_triggered_at.is_none());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+fn seed_branch_store(base_dir: &Path) {
+    let branch_config_dir = base_dir.join("branch-config");
+    fs::create_dir_all(&branch_config_dir).expect("branch config directory should exist");
+    let baseline = |build_version: &str, preset_stack_version: &str| {
+        serde_json::json!({
+            "buildVersion": build_version,
+            "presetStackVersion": preset_stack_version,
+            "approvedAt": "2026-03-20T00:10:00.000Z",
+            "actorId": "release-kim",
+            "actorLabel": "Kim Release"
+        })
+    };
+    let branch = |branch_id: &str, display_name: &str, deployment_baseline: serde_json::Value| {
+        serde_json::json!({
+            "branchId": branch_id,
+            "displayName": display_name,
+            "deploymentBaseline": deployment_baseline,
+            "rollbackBaseline": null,
+            "pendingBaseline": null,
+            "localSettings": {
+                "contactPhone": "02-555-0101",
+                "operationalToggles": ["capture-countdown-seconds=4"]
+            },
+            "activeSession": null
+        })
+    };
+
+    let store = serde_json::json!({
+        "schemaVersion": "branch-rollout-store/v1",
+        "approvedBaselines": [
+            baseline("boothy-2026.03.20.4", "catalog-2026.03.20"),
+            baseline("boothy-2026.03.27.1", "catalog-2026.03.27")
+        ],
+        "branches": [
+            branch(
+                "gangnam-01",
+                "강남 1호점",
+                baseline("boothy-2026.03.27.1", "catalog-2026.03.27")
+            ),
+            branch(
+                "hongdae-02",
+                "홍대 2호점",
+                baseline("boothy-2026.03.20.4", "catalog-2026.03.20")
+            )
+        ]
+    });
+
+    fs::write(
+        branch_config_dir.join("state.json"),
+        serde_json::to_vec_pretty(&store).expect("store should serialize"),
+    )
+    .expect("store should write");
+}
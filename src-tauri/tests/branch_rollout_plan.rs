@@ -0,0 +1,528 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    branch_config::{
+        branch_health::push_branch_health_report_in_dir,
+        fleet_store::LocalDirectoryFleetStore,
+        load_branch_rollout_overview_in_dir,
+        rollout_plan::{
+            evaluate_branch_rollout_plans_with_store, rollback_branch_rollout_plan_in_dir,
+            start_branch_rollout_plan_in_dir,
+        },
+    },
+    commands::runtime_commands::capability_snapshot_for_profile,
+    contracts::dto::{
+        BranchRolloutOverviewResultDto, BranchRolloutPlanInputDto,
+        BranchRolloutPlanRollbackInputDto,
+    },
+    diagnostics::audit_log::{try_append_operator_audit_record, OperatorAuditRecordInput},
+    session::session_manifest::current_timestamp,
+};
+
+const ACTIVE_SESSION_ID: &str = "session_01hs6n1r8b8zc5v4ey2x7b9g1m";
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-branch-rollout-plan-{test_name}-{stamp}"))
+}
+
+fn plan_input(canary_branch_ids: &[&str], branch_ids: &[&str]) -> BranchRolloutPlanInputDto {
+    BranchRolloutPlanInputDto {
+        canary_branch_ids: canary_branch_ids.iter().map(|id| id.to_string()).collect(),
+        branch_ids: branch_ids.iter().map(|id| id.to_string()).collect(),
+        wave_percentages: if branch_ids.is_empty() {
+            Vec::new()
+        } else {
+            vec![50, 100]
+        },
+        target_build_version: "boothy-2026.03.27.1".into(),
+        target_preset_stack_version: "catalog-2026.03.27".into(),
+        actor_id: "release-kim".into(),
+        actor_label: "Kim Release".into(),
+        soak_minutes: Some(0),
+        health_thresholds: None,
+    }
+}
+
+fn fleet_store(base_dir: &Path) -> LocalDirectoryFleetStore {
+    LocalDirectoryFleetStore::new(base_dir.join("fleet-store"))
+}
+
+/// Runs the booth of `branch_id` in its own data directory next to the HQ
+/// one: it writes one `session-started` per session plus an optional critical
+/// failure on the first one, then publishes its health report to the store.
+fn record_branch_activity(
+    base_dir: &Path,
+    branch_id: &str,
+    session_ids: &[&str],
+    critical_failure: Option<&str>,
+) {
+    let booth_dir = base_dir.join("booths").join(branch_id);
+    let profile_path = booth_dir.join("branch-config").join("branch-profile.json");
+    fs::create_dir_all(profile_path.parent().expect("profile parent"))
+        .expect("booth branch config should exist");
+    fs::write(
+        &profile_path,
+        serde_json::to_vec_pretty(&serde_json::json!({
+            "schemaVersion": "branch-profile/v1",
+            "displayName": branch_id,
+            "branchId": branch_id
+        }))
+        .expect("profile should serialize"),
+    )
+    .expect("profile should write");
+
+    let occurred_at = current_timestamp(SystemTime::now()).expect("timestamp");
+    for session_id in session_ids {
+        try_append_operator_audit_record(
+            &booth_dir,
+            OperatorAuditRecordInput {
+                occurred_at: occurred_at.clone(),
+                session_id: Some(session_id.to_string()),
+                event_category: "session-lifecycle",
+                event_type: "session-started",
+                summary: "새 세션을 시작했어요.".into(),
+                detail: "단계별 배포 검증용 세션이에요.".into(),
+                actor_id: None,
+                source: "session-repository",
+                capture_id: None,
+                preset_id: None,
+                published_version: None,
+                reason_code: None,
+            },
+        );
+    }
+    if let Some(reason_code) = critical_failure {
+        try_append_operator_audit_record(
+            &booth_dir,
+            OperatorAuditRecordInput {
+                occurred_at,
+                session_id: session_ids.first().map(|session_id| session_id.to_string()),
+                event_category: "critical-failure",
+                event_type: "capture-round-trip-failed",
+                summary: "촬영 결과를 세션에 저장하지 못했어요.".into(),
+                detail: "helper 응답이 없었어요.".into(),
+                actor_id: None,
+                source: "capture-boundary",
+                capture_id: None,
+                preset_id: None,
+                published_version: None,
+                reason_code: Some(reason_code.into()),
+            },
+        );
+    }
+
+    let reported = push_branch_health_report_in_dir(&booth_dir, &fleet_store(base_dir))
+        .expect("health report should publish");
+    assert_eq!(reported.as_deref(), Some(branch_id));
+}
+
+fn deployed_build<'a>(overview: &'a BranchRolloutOverviewResultDto, branch_id: &str) -> &'a str {
+    &overview
+        .branches
+        .iter()
+        .find(|branch| branch.branch_id == branch_id)
+        .expect("branch should exist")
+        .deployment_baseline
+        .build_version
+}
+
+#[test]
+fn waves_are_promoted_one_at_a_time_while_health_stays_under_thresholds() {
+    let base_dir = unique_test_root("promote");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir, false);
+
+    let started = start_branch_rollout_plan_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        plan_input(&["gangnam-01"], &["hongdae-02", "itaewon-03"]),
+    )
+    .expect("plan should start");
+    let plan = &started.plan;
+    assert_eq!(plan.status, "in-progress");
+    let labels: Vec<&str> = plan.waves.iter().map(|wave| wave.label.as_str()).collect();
+    assert_eq!(labels, ["canary", "50%", "100%"]);
+    assert_eq!(plan.waves[0].status, "in-progress");
+    assert_eq!(plan.waves[0].outcomes[0].result, "applied");
+    assert_eq!(plan.waves[1].status, "pending");
+
+    // Too few sessions on the canary keep it soaking, and loading the
+    // overview never promotes.
+    record_branch_activity(
+        &base_dir,
+        "gangnam-01",
+        &["session_canary_one", "session_canary_two"],
+        None,
+    );
+    let overview = evaluate_branch_rollout_plans_with_store(
+        &base_dir,
+        &capability_snapshot,
+        &fleet_store(&base_dir),
+    )
+    .expect("plans should evaluate");
+    let plan = &overview.rollout_plans[0];
+    assert_eq!(plan.waves[0].status, "in-progress");
+    let health = plan.waves[0].health.as_ref().expect("health should exist");
+    assert_eq!(
+        health.observed_session_ids,
+        ["session_canary_one", "session_canary_two"]
+    );
+    record_branch_activity(&base_dir, "gangnam-01", &["session_canary_three"], None);
+    let overview =
+        load_branch_rollout_overview_in_dir(&base_dir, &capability_snapshot).expect("load state");
+    assert_eq!(overview.rollout_plans[0].waves[0].status, "in-progress");
+
+    let overview = evaluate_branch_rollout_plans_with_store(
+        &base_dir,
+        &capability_snapshot,
+        &fleet_store(&base_dir),
+    )
+    .expect("plans should evaluate");
+    let plan = &overview.rollout_plans[0];
+    assert_eq!(plan.waves[0].status, "promoted");
+    assert_eq!(plan.waves[1].status, "in-progress");
+    assert_eq!(plan.waves[1].branch_ids, ["hongdae-02"]);
+    assert_eq!(
+        deployed_build(&overview, "hongdae-02"),
+        "boothy-2026.03.27.1"
+    );
+    assert_eq!(
+        deployed_build(&overview, "itaewon-03"),
+        "boothy-2026.03.20.4"
+    );
+
+    // Sessions from a branch outside the wave do not count toward it.
+    record_branch_activity(
+        &base_dir,
+        "itaewon-03",
+        &[
+            "session_early_one",
+            "session_early_two",
+            "session_early_three",
+        ],
+        None,
+    );
+    let overview = evaluate_branch_rollout_plans_with_store(
+        &base_dir,
+        &capability_snapshot,
+        &fleet_store(&base_dir),
+    )
+    .expect("plans should evaluate");
+    assert_eq!(overview.rollout_plans[0].waves[1].status, "in-progress");
+
+    record_branch_activity(
+        &base_dir,
+        "hongdae-02",
+        &["session_wave_one", "session_wave_two", "session_wave_three"],
+        None,
+    );
+    evaluate_branch_rollout_plans_with_store(
+        &base_dir,
+        &capability_snapshot,
+        &fleet_store(&base_dir),
+    )
+    .expect("plans should evaluate");
+    record_branch_activity(
+        &base_dir,
+        "itaewon-03",
+        &["session_last_one", "session_last_two", "session_last_three"],
+        None,
+    );
+    let overview = evaluate_branch_rollout_plans_with_store(
+        &base_dir,
+        &capability_snapshot,
+        &fleet_store(&base_dir),
+    )
+    .expect("plans should evaluate");
+    let plan = &overview.rollout_plans[0];
+    assert_eq!(plan.status, "completed");
+    assert!(plan.waves.iter().all(|wave| wave.status == "promoted"));
+    assert_eq!(
+        deployed_build(&overview, "itaewon-03"),
+        "boothy-2026.03.27.1"
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn a_critical_failure_on_a_canary_session_pauses_the_plan_until_rollback() {
+    let base_dir = unique_test_root("pause");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir, true);
+    seed_active_session_manifest(&base_dir, ACTIVE_SESSION_ID);
+
+    let started = start_branch_rollout_plan_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        plan_input(&["gangnam-01", "hongdae-02"], &["itaewon-03"]),
+    )
+    .expect("plan should start");
+    let results: Vec<&str> = started.plan.waves[0]
+        .outcomes
+        .iter()
+        .map(|outcome| outcome.result.as_str())
+        .collect();
+    assert_eq!(results, ["applied", "deferred"]);
+
+    // Failures on a branch outside the wave are not the canary's.
+    record_branch_activity(
+        &base_dir,
+        "itaewon-03",
+        &["session_other_branch"],
+        Some("capture-file-missing"),
+    );
+    record_branch_activity(
+        &base_dir,
+        "hongdae-02",
+        &[ACTIVE_SESSION_ID],
+        Some("capture-file-missing"),
+    );
+
+    let overview = evaluate_branch_rollout_plans_with_store(
+        &base_dir,
+        &capability_snapshot,
+        &fleet_store(&base_dir),
+    )
+    .expect("plans should evaluate");
+    let plan = overview.rollout_plans[0].clone();
+    assert_eq!(plan.status, "paused");
+    assert_eq!(plan.waves[0].status, "paused");
+    assert_eq!(plan.waves[1].status, "pending");
+    assert!(plan.pause_reason.is_some());
+    let health = plan.waves[0].health.as_ref().expect("health should exist");
+    assert_eq!(health.observed_session_ids, [ACTIVE_SESSION_ID]);
+    assert_eq!(health.critical_failures, 1);
+    assert_eq!(
+        deployed_build(&overview, "itaewon-03"),
+        "boothy-2026.03.20.4"
+    );
+
+    // A paused plan stays paused on later evaluations.
+    let overview = evaluate_branch_rollout_plans_with_store(
+        &base_dir,
+        &capability_snapshot,
+        &fleet_store(&base_dir),
+    )
+    .expect("plans should evaluate");
+    assert_eq!(overview.rollout_plans[0].status, "paused");
+
+    let rolled_back = rollback_branch_rollout_plan_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        BranchRolloutPlanRollbackInputDto {
+            plan_id: plan.plan_id.clone(),
+            actor_id: "release-kim".into(),
+            actor_label: "Kim Release".into(),
+        },
+    )
+    .expect("plan should roll back");
+    assert_eq!(rolled_back.plan.status, "rolled-back");
+    assert_eq!(rolled_back.plan.waves[0].status, "rolled-back");
+    assert_eq!(rolled_back.plan.waves[1].status, "pending");
+    let action_result = rolled_back.action_result.expect("rollback should run");
+    assert_eq!(
+        action_result.requested_branch_ids,
+        ["gangnam-01", "hongdae-02"]
+    );
+
+    let overview =
+        load_branch_rollout_overview_in_dir(&base_dir, &capability_snapshot).expect("load state");
+    assert_eq!(
+        deployed_build(&overview, "gangnam-01"),
+        "boothy-2026.03.20.4"
+    );
+    let hongdae = overview
+        .branches
+        .iter()
+        .find(|branch| branch.branch_id == "hongdae-02")
+        .expect("hongdae branch should exist");
+    assert!(hongdae.pending_baseline.is_none());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn invalid_or_overlapping_plans_are_rejected_before_any_branch_changes() {
+    let base_dir = unique_test_root("invalid");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir, false);
+
+    let mut uneven = plan_input(&["gangnam-01"], &["hongdae-02"]);
+    uneven.wave_percentages = vec![50, 80];
+    let mut overlapping = plan_input(&["gangnam-01"], &["gangnam-01"]);
+    overlapping.wave_percentages = vec![100];
+    let mut unapproved = plan_input(&["gangnam-01"], &[]);
+    unapproved.target_build_version = "boothy-2026.04.01.1".into();
+    for input in [uneven, overlapping, unapproved] {
+        let error = start_branch_rollout_plan_in_dir(&base_dir, &capability_snapshot, input)
+            .expect_err("plan should be rejected");
+        assert_eq!(error.code, "validation-error");
+    }
+    let error = start_branch_rollout_plan_in_dir(
+        &base_dir,
+        &capability_snapshot_for_profile("booth-only", false),
+        plan_input(&["gangnam-01"], &[]),
+    )
+    .expect_err("booth runtime should not start plans");
+    assert_eq!(error.code, "capability-denied");
+
+    let overview =
+        load_branch_rollout_overview_in_dir(&base_dir, &capability_snapshot).expect("load state");
+    assert!(overview.rollout_plans.is_empty());
+    assert_eq!(
+        deployed_build(&overview, "gangnam-01"),
+        "boothy-2026.03.20.4"
+    );
+
+    let mut paused_canary = plan_input(&["gangnam-01"], &[]);
+    paused_canary.soak_minutes = Some(60);
+    start_branch_rollout_plan_in_dir(&base_dir, &capability_snapshot, paused_canary)
+        .expect("plan should start");
+    let error = start_branch_rollout_plan_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        plan_input(&["itaewon-03"], &["gangnam-01"]),
+    )
+    .expect_err("branches in an open plan should not join another");
+    assert_eq!(error.code, "validation-error");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+fn branch_record(
+    branch_id: &str,
+    display_name: &str,
+    active_session: serde_json::Value,
+) -> serde_json::Value {
+    serde_json::json!({
+        "branchId": branch_id,
+        "displayName": display_name,
+        "deploymentBaseline": {
+            "buildVersion": "boothy-2026.03.20.4",
+            "presetStackVersion": "catalog-2026.03.20",
+            "approvedAt": "2026-03-20T00:10:00.000Z",
+            "actorId": "release-kim",
+            "actorLabel": "Kim Release"
+        },
+        "rollbackBaseline": {
+            "buildVersion": "boothy-2026.03.13.2",
+            "presetStackVersion": "catalog-2026.03.13",
+            "approvedAt": "2026-03-13T00:10:00.000Z",
+            "actorId": "release-kim",
+            "actorLabel": "Kim Release"
+        },
+        "pendingBaseline": null,
+        "localSettings": {
+            "contactPhone": "02-555-0101",
+            "operationalToggles": []
+        },
+        "activeSession": active_session
+    })
+}
+
+fn seed_branch_store(base_dir: &Path, with_active_session: bool) {
+    let branch_config_dir = base_dir.join("branch-config");
+    fs::create_dir_all(&branch_config_dir).expect("branch config directory should exist");
+
+    let active_session = if with_active_session {
+        serde_json::json!({
+            "sessionId": ACTIVE_SESSION_ID,
+            "lockedBaseline": {
+                "buildVersion": "boothy-2026.03.20.4",
+                "presetStackVersion": "catalog-2026.03.20",
+                "approvedAt": "2026-03-20T00:10:00.000Z",
+                "actorId": "release-kim",
+                "actorLabel": "Kim Release"
+            },
+            "startedAt": "2026-03-27T00:00:00.000Z",
+            "safeTransition": "after-session-end"
+        })
+    } else {
+        serde_json::Value::Null
+    };
+
+    let store = serde_json::json!({
+        "schemaVersion": "branch-rollout-store/v1",
+        "approvedBaselines": [
+            {
+                "buildVersion": "boothy-2026.03.20.4",
+                "presetStackVersion": "catalog-2026.03.20",
+                "approvedAt": "2026-03-20T00:10:00.000Z",
+                "actorId": "release-kim",
+                "actorLabel": "Kim Release"
+            },
+            {
+                "buildVersion": "boothy-2026.03.27.1",
+                "presetStackVersion": "catalog-2026.03.27",
+                "approvedAt": "2026-03-27T00:10:00.000Z",
+                "actorId": "release-kim",
+                "actorLabel": "Kim Release"
+            }
+        ],
+        "branches": [
+            branch_record("gangnam-01", "강남 1호점", serde_json::Value::Null),
+            branch_record("hongdae-02", "홍대 2호점", active_session),
+            branch_record("itaewon-03", "이태원 3호점", serde_json::Value::Null)
+        ]
+    });
+
+    fs::write(
+        branch_config_dir.join("state.json"),
+        serde_json::to_vec_pretty(&store).expect("store should serialize"),
+    )
+    .expect("store should write");
+}
+
+fn seed_active_session_manifest(base_dir: &Path, session_id: &str) {
+    let session_root = base_dir.join("sessions").join(session_id);
+    fs::create_dir_all(&session_root).expect("session root should exist");
+
+    let manifest = serde_json::json!({
+        "schemaVersion": "session-manifest/v1",
+        "sessionId": session_id,
+        "boothAlias": "Kim 4821",
+        "customer": {
+            "name": "Kim",
+            "phoneLastFour": "4821"
+        },
+        "createdAt": "2026-03-27T00:00:00Z",
+        "updatedAt": "2026-03-27T00:00:00Z",
+        "lifecycle": {
+            "status": "active",
+            "stage": "capture-ready"
+        },
+        "activePreset": null,
+        "activePresetId": null,
+        "activePresetDisplayName": null,
+        "timing": {
+            "schemaVersion": "session-timing/v1",
+            "sessionId": session_id,
+            "adjustedEndAt": "2099-03-27T00:00:00Z",
+            "warningAt": "2099-03-26T23:55:00Z",
+            "phase": "active",
+            "captureAllowed": true,
+            "approvedExtensionMinutes": 0,
+            "approvedExtensionAuditRef": null,
+            "warningTriggeredAt": null,
+            "endedTriggeredAt": null
+        },
+        "captures": [],
+        "postEnd": null
+    });
+
+    fs::write(
+        session_root.join("session.json"),
+        serde_json::to_vec_pretty(&manifest).expect("manifest should serialize"),
+    )
+    .expect("manifest should write");
+}
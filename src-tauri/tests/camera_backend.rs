@@ -0,0 +1,119 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    capture::{
+        camera_backend::{
+            resolve_camera_backend, CameraCaptureRequest, CANON_HELPER_CAMERA_BACKEND_ID,
+        },
+        sidecar_client::{
+            read_capture_request_messages, CAMERA_HELPER_EVENTS_FILE_NAME,
+            CAMERA_HELPER_STATUS_FILE_NAME,
+        },
+    },
+    session::session_paths::SessionPaths,
+};
+
+const SESSION_ID: &str = "session_000000000000000000000000c1";
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-camera-backend-{test_name}-{stamp}"))
+}
+
+#[test]
+fn default_camera_backend_speaks_the_canon_helper_protocol() {
+    let base_dir = unique_test_root("canon-default");
+    let paths = SessionPaths::new(&base_dir, SESSION_ID);
+    fs::create_dir_all(&paths.diagnostics_dir).expect("diagnostics dir should exist");
+    fs::write(
+        paths.diagnostics_dir.join(CAMERA_HELPER_STATUS_FILE_NAME),
+        format!(
+            r#"{{"schemaVersion":"canon-helper-status/v1","sessionId":"{SESSION_ID}","observedAt":"2026-03-20T00:00:00Z","cameraState":"ready","helperState":"healthy","sequence":7}}"#
+        ),
+    )
+    .expect("status should be written");
+    let backend = resolve_camera_backend();
+
+    assert_eq!(backend.backend_id(), CANON_HELPER_CAMERA_BACKEND_ID);
+    assert_eq!(backend.live_truth_source(), "canon-helper-sidecar");
+
+    let status = backend
+        .read_status(&base_dir, SESSION_ID)
+        .expect("status should be readable")
+        .expect("status should exist");
+
+    assert_eq!(status.camera_state, "ready");
+    assert_eq!(status.helper_state, "healthy");
+    assert_eq!(status.sequence, Some(7));
+
+    let request = CameraCaptureRequest {
+        session_id: SESSION_ID.into(),
+        request_id: "request_000000000000000001".into(),
+        requested_at: "2026-03-20T00:00:01Z".into(),
+        active_preset_id: "preset_soft-glow".into(),
+        active_preset_version: "2026.03.20".into(),
+    };
+
+    assert!(!backend
+        .is_capture_request_known(&base_dir, SESSION_ID, &request.request_id)
+        .expect("request log should be readable"));
+    backend
+        .submit_capture_request(&base_dir, &request)
+        .expect("request should be written");
+
+    let requests =
+        read_capture_request_messages(&base_dir, SESSION_ID).expect("requests should load");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].message_type, "request-capture");
+    assert!(backend
+        .is_capture_request_known(&base_dir, SESSION_ID, &request.request_id)
+        .expect("request log should be readable"));
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn canon_backend_reports_retryable_helper_errors_as_faults() {
+    let base_dir = unique_test_root("canon-fault");
+    let paths = SessionPaths::new(&base_dir, SESSION_ID);
+    fs::create_dir_all(&paths.diagnostics_dir).expect("diagnostics dir should exist");
+    fs::write(
+        paths.diagnostics_dir.join(CAMERA_HELPER_EVENTS_FILE_NAME),
+        format!(
+            "{}\n",
+            serde_json::json!({
+                "schemaVersion": "canon-helper-error/v1",
+                "type": "helper-error",
+                "sessionId": SESSION_ID,
+                "observedAt": "2026-03-20T00:00:02Z",
+                "detailCode": "camera-busy",
+            })
+        ),
+    )
+    .expect("events should be written");
+    let backend = resolve_camera_backend();
+
+    assert_eq!(
+        backend
+            .capture_event_cursor(&base_dir, SESSION_ID)
+            .expect("events should be readable"),
+        1
+    );
+
+    let fault = backend
+        .latest_fault(&base_dir, SESSION_ID)
+        .expect("helper error should surface as a fault");
+
+    assert_eq!(fault.detail_code, "camera-busy");
+    assert!(fault.retryable);
+
+    let _ = fs::remove_dir_all(base_dir);
+}
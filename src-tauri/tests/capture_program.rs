@@ -0,0 +1,207 @@
+use std::{
+    path::PathBuf,
+    sync::Once,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    capture::{
+        capture_program::run_capture_program_in_dir, helper_simulator::start_simulated_helper,
+        normalized_state::get_capture_readiness_in_dir,
+    },
+    contracts::dto::{
+        CaptureProgramInputDto, CaptureProgramTickDto, CaptureReadinessInputDto,
+        PresetSelectionInputDto, SessionStartInputDto,
+    },
+    preset::default_catalog::ensure_default_preset_catalog_in_dir,
+    session::{
+        session_manifest::{current_timestamp, SessionManifest},
+        session_paths::SessionPaths,
+        session_repository::{
+            read_session_manifest_in_dir, select_active_preset_in_dir, start_session_in_dir,
+        },
+    },
+};
+
+static FAKE_DARKTABLE_SETUP: Once = Once::new();
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    FAKE_DARKTABLE_SETUP.call_once(|| {
+        let script_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("support")
+            .join("fake-darktable-cli.cmd");
+        std::env::set_var("BOOTHY_DARKTABLE_CLI_BIN", script_path);
+    });
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-capture-program-{test_name}-{stamp}"))
+}
+
+fn start_session_with_preset(base_dir: &PathBuf) -> String {
+    ensure_default_preset_catalog_in_dir(base_dir).expect("default catalog should seed");
+    let session = start_session_in_dir(
+        base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+    )
+    .expect("session should be created");
+    select_active_preset_in_dir(
+        base_dir,
+        PresetSelectionInputDto {
+            session_id: session.session_id.clone(),
+            preset_id: "preset_soft-glow".into(),
+            published_version: "2026.03.27".into(),
+        },
+    )
+    .expect("preset should become active");
+
+    session.session_id
+}
+
+fn program_input(
+    session_id: &str,
+    mode: &str,
+    countdown_seconds: Option<u32>,
+    shot_count: Option<u32>,
+    shot_interval_ms: Option<u64>,
+) -> CaptureProgramInputDto {
+    CaptureProgramInputDto {
+        session_id: session_id.into(),
+        mode: mode.into(),
+        countdown_seconds,
+        shot_count,
+        shot_interval_ms,
+    }
+}
+
+fn read_manifest(base_dir: &PathBuf, session_id: &str) -> SessionManifest {
+    read_session_manifest_in_dir(base_dir, session_id).expect("manifest should be readable")
+}
+
+#[test]
+fn burst_program_saves_one_linked_record_per_shot() {
+    let base_dir = unique_test_root("burst");
+    let session_id = start_session_with_preset(&base_dir);
+    let _helper = start_simulated_helper(&base_dir, &session_id).expect("simulator should start");
+    let mut ticks: Vec<CaptureProgramTickDto> = Vec::new();
+
+    let result = run_capture_program_in_dir(
+        &base_dir,
+        program_input(&session_id, "burst", Some(1), Some(3), Some(500)),
+        |_| {},
+        |tick| ticks.push(tick),
+    )
+    .expect("burst should complete");
+
+    assert_eq!(result.status, "program-completed");
+    assert_eq!(result.mode, "burst");
+    assert_eq!(result.captures.len(), 3);
+    for (index, capture) in result.captures.iter().enumerate() {
+        let shot = capture
+            .capture_program
+            .as_ref()
+            .expect("each shot should be linked to the program");
+        assert_eq!(shot.program_id, result.program_id);
+        assert_eq!(shot.shot_index, index as u32 + 1);
+        assert_eq!(shot.shot_count, 3);
+    }
+    let manifest = read_manifest(&base_dir, &session_id);
+    assert_eq!(
+        manifest
+            .captures
+            .iter()
+            .filter(|capture| {
+                capture
+                    .capture_program
+                    .as_ref()
+                    .is_some_and(|shot| shot.program_id == result.program_id)
+            })
+            .count(),
+        3
+    );
+
+    let phases: Vec<(&str, u32, u32)> = ticks
+        .iter()
+        .map(|tick| (tick.phase.as_str(), tick.remaining_seconds, tick.shot_index))
+        .collect();
+    assert_eq!(
+        phases,
+        vec![
+            ("countdown", 1, 1),
+            ("shot", 0, 1),
+            ("shot", 0, 2),
+            ("shot", 0, 3)
+        ]
+    );
+    assert!(ticks
+        .iter()
+        .all(|tick| tick.program_id == result.program_id));
+
+    let _ = std::fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn program_plans_outside_the_allowed_range_are_rejected_before_capturing() {
+    let base_dir = unique_test_root("invalid");
+    let session_id = start_session_with_preset(&base_dir);
+
+    for input in [
+        program_input(&session_id, "timelapse", None, None, None),
+        program_input(&session_id, "countdown", Some(0), None, None),
+        program_input(&session_id, "countdown", Some(3), Some(2), None),
+        program_input(&session_id, "burst", None, Some(9), None),
+        program_input(&session_id, "burst", None, Some(3), Some(100)),
+    ] {
+        let error = run_capture_program_in_dir(&base_dir, input, |_| {}, |_| {})
+            .expect_err("plan should be rejected");
+        assert_eq!(error.code, "validation-error");
+    }
+    assert!(read_manifest(&base_dir, &session_id).captures.is_empty());
+
+    let _ = std::fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn program_that_cannot_finish_before_the_session_ends_is_refused() {
+    let base_dir = unique_test_root("budget");
+    let session_id = start_session_with_preset(&base_dir);
+    let _helper = start_simulated_helper(&base_dir, &session_id).expect("simulator should start");
+    let readiness = get_capture_readiness_in_dir(
+        &base_dir,
+        CaptureReadinessInputDto {
+            session_id: session_id.clone(),
+        },
+    )
+    .expect("readiness should resolve");
+    assert!(readiness.can_capture);
+
+    let mut manifest = read_manifest(&base_dir, &session_id);
+    let timing = manifest.timing.as_mut().expect("timing should exist");
+    let end_at = SystemTime::now() + std::time::Duration::from_secs(6);
+    timing.adjusted_end_at = current_timestamp(end_at).expect("end time should format");
+    std::fs::write(
+        SessionPaths::new(&base_dir, &session_id).manifest_path,
+        serde_json::to_vec_pretty(&manifest).expect("manifest should serialize"),
+    )
+    .expect("manifest should be writable");
+
+    let error = run_capture_program_in_dir(
+        &base_dir,
+        program_input(&session_id, "burst", Some(3), Some(4), Some(1500)),
+        |_| {},
+        |_| {},
+    )
+    .expect_err("program longer than the remaining time should be refused");
+    assert_eq!(error.code, "capture-not-ready");
+    assert!(error.message.contains("남은 촬영 시간"));
+    assert!(read_manifest(&base_dir, &session_id).captures.is_empty());
+
+    let _ = std::fs::remove_dir_all(base_dir);
+}
@@ -1,3 +1,5 @@
+mod support;
+
 use std::{
     fs,
     path::PathBuf,
@@ -35,11 +37,12 @@ use boothy_lib::{
         session_manifest::{current_timestamp, CompletedPostEnd, SessionManifest, SessionPostEnd},
         session_paths::SessionPaths,
         session_repository::{
-            select_active_preset_in_dir, set_manifest_write_retryable_failures_for_tests,
-            start_session_in_dir,
+            read_session_manifest_in_dir, select_active_preset_in_dir,
+            set_manifest_write_retryable_failures_for_tests, start_session_in_dir,
         },
     },
 };
+use support::sign_fixture_bundle;
 
 static FAKE_DARKTABLE_SETUP: Once = Once::new();
 
@@ -83,6 +86,7 @@ fn readiness_requires_an_active_preset_before_capture_is_allowed() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -111,6 +115,7 @@ fn readiness_recovers_from_a_manifest_backup_left_during_an_atomic_swap_gap() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -143,6 +148,7 @@ fn readiness_stays_blocked_until_live_helper_truth_is_fresh() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -192,6 +198,7 @@ fn readiness_returns_ready_once_session_preset_and_live_helper_truth_are_valid()
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -243,6 +250,7 @@ fn readiness_accepts_utf8_bom_prefixed_helper_status_files() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -284,6 +292,7 @@ fn readiness_surfaces_camera_power_guidance_when_helper_reports_disconnected() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -335,6 +344,7 @@ fn readiness_surfaces_camera_connecting_guidance_when_helper_detects_powered_dev
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -391,6 +401,7 @@ fn readiness_stays_blocked_when_helper_truth_is_absent_even_in_runtime_dir() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -437,6 +448,7 @@ fn unknown_lifecycle_stages_do_not_fall_through_to_ready() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -480,6 +492,7 @@ fn helper_preparing_and_phone_required_states_block_capture_with_customer_safe_g
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -543,6 +556,7 @@ fn invalidated_active_preset_binding_returns_to_preset_missing() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -604,6 +618,7 @@ fn capture_flow_persists_raw_before_preview_waiting_and_only_exposes_preview_aft
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -725,6 +740,7 @@ fn capture_flow_ignores_sidecar_preview_placeholders_and_uses_bundle_render_outp
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -752,10 +768,7 @@ fn capture_flow_ignores_sidecar_preview_placeholders_and_uses_bundle_render_outp
         .join(format!("{capture_id}.cr3"));
     fs::write(&raw_cr3_path, b"helper-raw-cr3").expect("cr3 raw should be writable");
 
-    let mut manifest: SessionManifest = serde_json::from_str(
-        &fs::read_to_string(&manifest_path).expect("manifest should be readable"),
-    )
-    .expect("manifest should deserialize");
+    let mut manifest = read_manifest(&base_dir, &session.session_id);
     manifest.captures[0].raw.asset_path = raw_cr3_path.to_string_lossy().into_owned();
     fs::write(
         &manifest_path,
@@ -791,6 +804,7 @@ fn readiness_repairs_a_placeholder_svg_preview_when_a_raster_sidecar_exists() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -828,10 +842,7 @@ fn readiness_repairs_a_placeholder_svg_preview_when_a_raster_sidecar_exists() {
     write_test_jpeg(&jpg_preview_path);
 
     let manifest_path = session_paths.manifest_path.clone();
-    let mut manifest: SessionManifest = serde_json::from_str(
-        &fs::read_to_string(&manifest_path).expect("manifest should be readable"),
-    )
-    .expect("manifest should deserialize");
+    let mut manifest = read_manifest(&base_dir, &session.session_id);
     manifest.captures[0].preview.asset_path = Some(svg_preview_path.to_string_lossy().into_owned());
     fs::write(
         &manifest_path,
@@ -855,10 +866,7 @@ fn readiness_repairs_a_placeholder_svg_preview_when_a_raster_sidecar_exists() {
         Some(&jpg_preview_path.to_string_lossy().into_owned()),
     );
 
-    let repaired_manifest: SessionManifest = serde_json::from_str(
-        &fs::read_to_string(&manifest_path).expect("manifest should still be readable"),
-    )
-    .expect("manifest should deserialize after repair");
+    let repaired_manifest = read_manifest(&base_dir, &session.session_id);
     assert_eq!(
         repaired_manifest.captures[0].preview.asset_path.as_deref(),
         Some(jpg_preview_path.to_string_lossy().as_ref()),
@@ -875,6 +883,7 @@ fn preview_render_rejects_invalid_non_raster_output() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -901,10 +910,7 @@ fn preview_render_rejects_invalid_non_raster_output() {
     fs::write(&forced_raw_path, b"raw-marker").expect("raw marker should be writable");
 
     let manifest_path = session_paths.manifest_path.clone();
-    let mut manifest: SessionManifest = serde_json::from_str(
-        &fs::read_to_string(&manifest_path).expect("manifest should be readable"),
-    )
-    .expect("manifest should deserialize");
+    let mut manifest = read_manifest(&base_dir, &session.session_id);
     manifest.captures[0].raw.asset_path = forced_raw_path.to_string_lossy().into_owned();
     fs::write(
         &manifest_path,
@@ -937,6 +943,7 @@ fn capture_saved_keeps_a_fast_same_capture_thumbnail_visible_while_preview_rende
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -1038,11 +1045,7 @@ fn capture_saved_keeps_a_fast_same_capture_thumbnail_visible_while_preview_rende
     );
     assert_valid_jpeg(preview_path.to_string_lossy().as_ref());
 
-    let manifest_path = SessionPaths::new(&base_dir, &session.session_id).manifest_path;
-    let manifest: SessionManifest = serde_json::from_str(
-        &fs::read_to_string(&manifest_path).expect("manifest should be readable"),
-    )
-    .expect("manifest should deserialize");
+    let manifest = read_manifest(&base_dir, &session.session_id);
     assert_eq!(
         manifest.captures[0].preview.asset_path.as_deref(),
         Some(preview_path.to_string_lossy().as_ref()),
@@ -1060,6 +1063,7 @@ fn helper_fast_preview_handoff_promotes_to_the_canonical_preview_path_and_later_
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -1204,6 +1208,7 @@ fn fast_preview_updates_are_emitted_from_the_canonical_preview_path_before_captu
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -1347,6 +1352,7 @@ fn preview_render_can_finish_from_fast_preview_before_raw_handoff_closes() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -1536,6 +1542,7 @@ fn invalid_fast_preview_ready_events_are_discarded_without_breaking_capture_succ
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -1646,6 +1653,7 @@ fn readiness_promotes_a_late_canonical_fast_preview_without_marking_preview_read
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -1711,6 +1719,7 @@ fn readiness_allows_next_capture_once_same_capture_fast_preview_is_visible() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -1776,6 +1785,7 @@ fn client_supplied_request_id_is_preserved_for_the_helper_capture_round_trip() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -1820,6 +1830,7 @@ fn duplicate_client_supplied_request_id_is_rejected_before_helper_consumption()
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -1881,6 +1892,7 @@ fn fast_thumbnail_attempted_and_failed_events_do_not_break_capture_success() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -1990,6 +2002,7 @@ fn foreign_session_thumbnail_telemetry_is_ignored_without_breaking_capture_succe
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -2113,6 +2126,7 @@ fn invalid_fast_preview_handoffs_are_discarded_without_breaking_capture_success(
             SessionStartInputDto {
                 name: "Kim".into(),
                 phone_last_four: "4821".into(),
+                package_id: None,
             },
         )
         .expect("session should be created");
@@ -2309,6 +2323,7 @@ fn canonical_same_capture_preview_is_still_seeded_when_fast_preview_handoff_is_i
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -2436,6 +2451,7 @@ fn readiness_repairs_a_legacy_text_jpg_preview_by_rerendering_it() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -2464,10 +2480,7 @@ fn readiness_repairs_a_legacy_text_jpg_preview_by_rerendering_it() {
     fs::write(&preview_path, b"not-a-real-jpeg").expect("legacy invalid preview should exist");
 
     let manifest_path = session_paths.manifest_path.clone();
-    let mut manifest: SessionManifest = serde_json::from_str(
-        &fs::read_to_string(&manifest_path).expect("manifest should be readable"),
-    )
-    .expect("manifest should deserialize");
+    let mut manifest = read_manifest(&base_dir, &session.session_id);
     manifest.captures[0].preview.asset_path = Some(preview_path.to_string_lossy().into_owned());
     manifest.captures[0].preview.ready_at_ms = Some(1234);
     manifest.captures[0].render_status = "previewReady".into();
@@ -2507,6 +2520,7 @@ fn readiness_keeps_the_canonical_preview_path_even_when_newer_suffix_previews_ex
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -2562,10 +2576,7 @@ fn readiness_keeps_the_canonical_preview_path_even_when_newer_suffix_previews_ex
         Some(canonical_preview_path.to_string_lossy().as_ref())
     );
 
-    let manifest: SessionManifest = serde_json::from_str(
-        &fs::read_to_string(&session_paths.manifest_path).expect("manifest should be readable"),
-    )
-    .expect("manifest should deserialize");
+    let manifest = read_manifest(&base_dir, &session.session_id);
     assert_eq!(
         manifest.captures[0].preview.asset_path.as_deref(),
         Some(canonical_preview_path.to_string_lossy().as_ref())
@@ -2582,6 +2593,7 @@ fn capture_flow_legacy_published_bundle_without_render_profiles_still_prepares_p
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -2617,6 +2629,7 @@ fn capture_flow_legacy_published_bundle_without_render_profiles_still_prepares_p
         .expect("legacy bundle should serialize"),
     )
     .expect("legacy bundle should be writable");
+    sign_fixture_bundle(&bundle_dir);
 
     select_active_preset_in_dir(
         &base_dir,
@@ -2661,6 +2674,7 @@ fn complete_preview_render_finishes_without_waiting_for_a_delayed_sidecar_placeh
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -2688,10 +2702,7 @@ fn complete_preview_render_finishes_without_waiting_for_a_delayed_sidecar_placeh
         .join(format!("{capture_id}.cr3"));
     fs::write(&raw_cr3_path, b"helper-raw-cr3").expect("cr3 raw should be writable");
 
-    let mut manifest: SessionManifest = serde_json::from_str(
-        &fs::read_to_string(&manifest_path).expect("manifest should be readable"),
-    )
-    .expect("manifest should deserialize");
+    let mut manifest = read_manifest(&base_dir, &session.session_id);
     manifest.captures[0].raw.asset_path = raw_cr3_path.to_string_lossy().into_owned();
     fs::write(
         &manifest_path,
@@ -2737,6 +2748,7 @@ fn capture_flow_blocks_a_follow_up_capture_until_the_latest_preview_is_ready() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -2787,6 +2799,7 @@ fn capture_flow_times_out_when_helper_accepts_but_no_file_arrives() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -2867,6 +2880,7 @@ fn capture_flow_keeps_session_retryable_when_focus_is_not_locked() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -2968,6 +2982,7 @@ fn readiness_releases_phone_required_after_retryable_focus_failure_recovers() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3024,6 +3039,7 @@ fn capture_flow_retries_manifest_persist_after_a_transient_write_conflict() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3068,6 +3084,7 @@ fn capture_flow_escalates_when_manifest_persist_never_recovers() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3169,6 +3186,7 @@ fn capture_flow_rejects_file_arrivals_from_the_wrong_session() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3177,6 +3195,7 @@ fn capture_flow_rejects_file_arrivals_from_the_wrong_session() {
         SessionStartInputDto {
             name: "Lee".into(),
             phone_last_four: "1234".into(),
+            package_id: None,
         },
     )
     .expect("foreign session should be created");
@@ -3266,6 +3285,7 @@ fn capture_flow_rejects_file_arrivals_for_the_wrong_request() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3353,6 +3373,7 @@ fn capture_flow_ignores_duplicate_file_arrivals_after_persisting_once() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3444,6 +3465,7 @@ fn capture_flow_blocks_a_second_capture_while_the_first_round_trip_is_in_flight(
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3555,6 +3577,7 @@ fn switching_active_preset_only_changes_future_capture_bindings() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3595,10 +3618,7 @@ fn switching_active_preset_only_changes_future_capture_bindings() {
 
     let second_capture = request_capture_with_helper_success(&base_dir, &session.session_id);
 
-    let manifest_path = SessionPaths::new(&base_dir, &session.session_id).manifest_path;
-    let manifest_bytes = fs::read_to_string(&manifest_path).expect("manifest should be readable");
-    let manifest: SessionManifest =
-        serde_json::from_str(&manifest_bytes).expect("manifest should deserialize");
+    let manifest = read_manifest(&base_dir, &session.session_id);
 
     assert_eq!(
         manifest
@@ -3651,6 +3671,7 @@ fn preview_render_stays_bound_to_the_capture_version_after_active_preset_changes
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3685,10 +3706,7 @@ fn preview_render_stays_bound_to_the_capture_version_after_active_preset_changes
     let manifest_path = SessionPaths::new(&base_dir, &session.session_id)
         .manifest_path
         .clone();
-    let mut manifest: SessionManifest = serde_json::from_str(
-        &fs::read_to_string(&manifest_path).expect("manifest should be readable"),
-    )
-    .expect("manifest should deserialize");
+    let mut manifest = read_manifest(&base_dir, &session.session_id);
     manifest.active_preset = Some(boothy_lib::session::session_manifest::ActivePresetBinding {
         preset_id: "preset_soft-glow".into(),
         published_version: "2026.03.21".into(),
@@ -3736,6 +3754,7 @@ fn preview_render_failure_escalates_to_a_phone_required_boundary() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3762,10 +3781,7 @@ fn preview_render_failure_escalates_to_a_phone_required_boundary() {
     fs::write(&forced_raw_path, b"raw-marker").expect("raw marker should be writable");
 
     let manifest_path = session_paths.manifest_path.clone();
-    let mut manifest: SessionManifest = serde_json::from_str(
-        &fs::read_to_string(&manifest_path).expect("manifest should be readable"),
-    )
-    .expect("manifest should deserialize");
+    let mut manifest = read_manifest(&base_dir, &session.session_id);
     manifest.captures[0].raw.asset_path = forced_raw_path.to_string_lossy().into_owned();
     fs::write(
         &manifest_path,
@@ -3803,6 +3819,7 @@ fn completed_post_end_uses_handoff_guidance_when_final_handoff_metadata_is_prese
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3870,6 +3887,7 @@ fn handoff_ready_completion_preserves_variant_with_generic_destination_without_d
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -3939,6 +3957,7 @@ fn invalid_existing_handoff_ready_record_is_rebuilt_with_safe_destination_guidan
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -4017,6 +4036,7 @@ fn delete_capture_removes_only_the_selected_current_session_artifacts() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -4098,6 +4118,7 @@ fn delete_capture_blocks_preview_waiting_targets_and_preserves_files() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -4158,6 +4179,7 @@ fn delete_capture_preserves_manifest_when_asset_cleanup_cannot_be_staged() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -4181,9 +4203,7 @@ fn delete_capture_preserves_manifest_when_asset_cleanup_cannot_be_staged() {
         .expect("preview should complete");
 
     let manifest_path = SessionPaths::new(&base_dir, &session.session_id).manifest_path;
-    let manifest_bytes = fs::read_to_string(&manifest_path).expect("manifest should be readable");
-    let mut manifest: SessionManifest =
-        serde_json::from_str(&manifest_bytes).expect("manifest should deserialize");
+    let mut manifest = read_manifest(&base_dir, &session.session_id);
 
     manifest.captures[0].raw.asset_path = SessionPaths::new(&base_dir, &session.session_id)
         .diagnostics_dir
@@ -4207,10 +4227,7 @@ fn delete_capture_preserves_manifest_when_asset_cleanup_cannot_be_staged() {
 
     assert_eq!(error.code, "session-persistence-failed");
 
-    let persisted_manifest_bytes =
-        fs::read_to_string(&manifest_path).expect("manifest should still be readable");
-    let persisted_manifest: SessionManifest =
-        serde_json::from_str(&persisted_manifest_bytes).expect("manifest should deserialize");
+    let persisted_manifest = read_manifest(&base_dir, &session.session_id);
 
     assert_eq!(persisted_manifest.captures.len(), 1);
     assert_eq!(
@@ -4229,6 +4246,7 @@ fn warning_window_projects_warning_readiness_and_persists_a_warning_audit_log()
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -4295,6 +4313,7 @@ fn exact_end_blocks_capture_and_reserves_the_extension_audit_hook_in_logs() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -4402,6 +4421,7 @@ fn ended_readiness_does_not_fall_back_to_preset_missing_after_end() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -4463,6 +4483,7 @@ fn ended_preview_ready_capture_waits_for_final_render_and_promotes_to_handoff_re
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -4563,6 +4584,7 @@ fn readiness_recovers_after_a_legacy_default_bundle_is_upgraded_for_rendering()
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -4589,6 +4611,7 @@ fn readiness_recovers_after_a_legacy_default_bundle_is_upgraded_for_rendering()
         .expect("legacy bundle should serialize"),
     )
     .expect("legacy bundle should be writable");
+    sign_fixture_bundle(&legacy_bundle_dir);
 
     select_active_preset_in_dir(
         &base_dir,
@@ -4648,6 +4671,7 @@ fn post_end_render_failure_preserves_saved_capture_assets() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -4719,6 +4743,7 @@ fn preview_ready_capture_remains_scoped_to_its_own_session() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("first session should be created");
@@ -4727,6 +4752,7 @@ fn preview_ready_capture_remains_scoped_to_its_own_session() {
         SessionStartInputDto {
             name: "Lee".into(),
             phone_last_four: "1234".into(),
+            package_id: None,
         },
     )
     .expect("second session should be created");
@@ -4812,9 +4838,7 @@ fn preview_ready_capture_remains_scoped_to_its_own_session() {
 
 fn mark_capture_final_ready(base_dir: &PathBuf, session_id: &str, capture_id: &str) {
     let manifest_path = SessionPaths::new(base_dir, session_id).manifest_path;
-    let manifest_bytes = fs::read_to_string(&manifest_path).expect("manifest should be readable");
-    let mut manifest: SessionManifest =
-        serde_json::from_str(&manifest_bytes).expect("manifest should deserialize");
+    let mut manifest = read_manifest(base_dir, session_id);
     let capture = manifest
         .captures
         .iter_mut()
@@ -4858,9 +4882,7 @@ fn write_handoff_guidance(base_dir: &PathBuf, session_id: &str, payload: serde_j
 
 fn update_stage(base_dir: &PathBuf, session_id: &str, stage: &str) {
     let manifest_path = SessionPaths::new(base_dir, session_id).manifest_path;
-    let manifest_bytes = fs::read_to_string(&manifest_path).expect("manifest should be readable");
-    let mut manifest: SessionManifest =
-        serde_json::from_str(&manifest_bytes).expect("manifest should deserialize");
+    let mut manifest = read_manifest(base_dir, session_id);
 
     manifest.lifecycle.stage = stage.into();
 
@@ -4879,9 +4901,7 @@ fn update_timing(
     phase: &str,
 ) {
     let manifest_path = SessionPaths::new(base_dir, session_id).manifest_path;
-    let manifest_bytes = fs::read_to_string(&manifest_path).expect("manifest should be readable");
-    let mut manifest: SessionManifest =
-        serde_json::from_str(&manifest_bytes).expect("manifest should deserialize");
+    let mut manifest = read_manifest(base_dir, session_id);
 
     let timing = manifest
         .timing
@@ -4902,10 +4922,7 @@ fn update_timing(
 }
 
 fn read_manifest(base_dir: &PathBuf, session_id: &str) -> SessionManifest {
-    let manifest_path = SessionPaths::new(base_dir, session_id).manifest_path;
-    let manifest_bytes = fs::read_to_string(manifest_path).expect("manifest should be readable");
-
-    serde_json::from_str(&manifest_bytes).expect("manifest should deserialize")
+    read_session_manifest_in_dir(base_dir, session_id).expect("manifest should be readable")
 }
 
 fn timestamp_offset(offset_seconds: i64) -> String {
@@ -4984,6 +5001,7 @@ fn create_named_published_bundle(
         serde_json::to_vec_pretty(&bundle).expect("bundle should serialize"),
     )
     .expect("bundle should be writable");
+    sign_fixture_bundle(&bundle_dir);
 }
 
 fn request_capture_with_helper_success(
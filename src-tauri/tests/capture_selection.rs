@@ -0,0 +1,268 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    branch_config::session_packages::resolve_session_package_config_path,
+    capture::capture_selection::update_capture_selection_in_dir,
+    contracts::dto::{
+        CaptureSelectionInputDto, CaptureSelectionResultDto, HostErrorEnvelope,
+        SessionStartInputDto,
+    },
+    handoff::sync_post_end_state_in_dir,
+    session::{
+        session_manifest::{
+            CaptureTimingMetrics, FinalCaptureAsset, PreviewCaptureAsset, RawCaptureAsset,
+            SessionCaptureRecord, SessionManifest, SESSION_CAPTURE_SCHEMA_VERSION,
+        },
+        session_paths::SessionPaths,
+        session_repository::start_session_in_dir,
+    },
+};
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-capture-selection-{test_name}-{stamp}"))
+}
+
+fn start_session_with_captures(base_dir: &Path, render_statuses: &[&str]) -> SessionManifest {
+    let config_path = resolve_session_package_config_path(base_dir);
+    fs::create_dir_all(config_path.parent().expect("config dir should exist"))
+        .expect("config dir should be created");
+    fs::write(
+        &config_path,
+        serde_json::json!({
+            "schemaVersion": "session-package-config/v1",
+            "defaultPackageId": "pick-two",
+            "packages": [
+                {
+                    "packageId": "pick-two",
+                    "displayName": "2장 선택",
+                    "durationMinutes": 20,
+                    "warningLeadMinutes": 5,
+                    "maxSelectedCaptures": 2
+                }
+            ]
+        })
+        .to_string(),
+    )
+    .expect("package config should be written");
+    let session = start_session_in_dir(
+        base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+    )
+    .expect("session should start");
+    let paths = SessionPaths::new(base_dir, &session.session_id);
+    fs::create_dir_all(&paths.renders_finals_dir).expect("finals dir should exist");
+    let mut manifest = session.manifest;
+
+    for (index, render_status) in render_statuses.iter().enumerate() {
+        let capture_id = format!("capture_{:03}", index + 1);
+        let final_path = (*render_status == "finalReady").then(|| {
+            let final_path = paths.renders_finals_dir.join(format!("{capture_id}.jpg"));
+            fs::write(&final_path, [0xFF, 0xD8, 0xFF, 0xD9]).expect("final should be written");
+            final_path.to_string_lossy().into_owned()
+        });
+        manifest.captures.push(SessionCaptureRecord {
+            schema_version: SESSION_CAPTURE_SCHEMA_VERSION.into(),
+            session_id: manifest.session_id.clone(),
+            booth_alias: manifest.booth_alias.clone(),
+            active_preset_id: Some("preset_soft-glow".into()),
+            active_preset_version: "2026.03.20".into(),
+            active_preset_display_name: Some("Soft Glow".into()),
+            capture_id: capture_id.clone(),
+            request_id: format!("request_{:03}", index + 1),
+            raw: RawCaptureAsset {
+                asset_path: format!("{capture_id}.cr3"),
+                persisted_at_ms: 100,
+            },
+            preview: PreviewCaptureAsset {
+                asset_path: None,
+                enqueued_at_ms: Some(100),
+                ready_at_ms: Some(200),
+            },
+            final_asset: FinalCaptureAsset {
+                ready_at_ms: final_path.as_ref().map(|_| 300),
+                asset_path: final_path,
+                variants: Vec::new(),
+            },
+            render_status: (*render_status).into(),
+            render_backend: None,
+            post_end_state: "activeSession".into(),
+            timing: CaptureTimingMetrics {
+                capture_acknowledged_at_ms: 100,
+                preview_visible_at_ms: Some(200),
+                fast_preview_visible_at_ms: None,
+                xmp_preview_ready_at_ms: None,
+                capture_budget_ms: 1_000,
+                preview_budget_ms: 5_000,
+                preview_budget_state: "withinBudget".into(),
+            },
+            capture_program: None,
+        });
+    }
+    write_manifest(base_dir, &manifest);
+
+    manifest
+}
+
+fn write_manifest(base_dir: &Path, manifest: &SessionManifest) {
+    fs::write(
+        SessionPaths::new(base_dir, &manifest.session_id).manifest_path,
+        serde_json::to_vec_pretty(manifest).expect("manifest should serialize"),
+    )
+    .expect("manifest should be writable");
+}
+
+fn select(
+    base_dir: &Path,
+    session_id: &str,
+    capture_id: &str,
+    selected: Option<bool>,
+    favorite: Option<bool>,
+) -> Result<CaptureSelectionResultDto, HostErrorEnvelope> {
+    update_capture_selection_in_dir(
+        base_dir,
+        CaptureSelectionInputDto {
+            session_id: session_id.into(),
+            capture_id: capture_id.into(),
+            selected,
+            favorite,
+        },
+    )
+}
+
+#[test]
+fn selection_enforces_the_package_limit_and_favourites_are_unlimited() {
+    let base_dir = unique_test_root("limit");
+    let manifest = start_session_with_captures(
+        &base_dir,
+        &[
+            "previewReady",
+            "previewReady",
+            "previewReady",
+            "previewWaiting",
+        ],
+    );
+    let session_id = manifest.session_id.as_str();
+
+    select(&base_dir, session_id, "capture_001", Some(true), None)
+        .expect("first selection should fit");
+    select(&base_dir, session_id, "capture_002", Some(true), Some(true))
+        .expect("second selection should fit");
+    let error = select(&base_dir, session_id, "capture_003", Some(true), None)
+        .expect_err("third selection should exceed the package");
+    assert_eq!(error.code, "validation-error");
+    assert!(error.message.contains("최대 2장"));
+    let error = select(&base_dir, session_id, "capture_004", Some(true), None)
+        .expect_err("unrendered captures cannot be selected");
+    assert_eq!(error.code, "validation-error");
+
+    let favorite = select(&base_dir, session_id, "capture_003", None, Some(true))
+        .expect("favourites should not count against the limit");
+    let summary = favorite
+        .readiness
+        .selection
+        .expect("readiness should report the selection");
+    assert_eq!(
+        summary.selected_capture_ids,
+        vec!["capture_001", "capture_002"]
+    );
+    assert_eq!(
+        summary.favorite_capture_ids,
+        vec!["capture_002", "capture_003"]
+    );
+    assert_eq!(summary.max_selected_captures, Some(2));
+
+    select(&base_dir, session_id, "capture_001", Some(false), None)
+        .expect("deselecting should free a slot");
+    let swapped = select(&base_dir, session_id, "capture_003", Some(true), None)
+        .expect("freed slot should be reusable");
+    assert_eq!(
+        swapped
+            .manifest
+            .selection
+            .map(|selection| selection.selected_capture_ids),
+        Some(vec!["capture_002".to_string(), "capture_003".to_string()])
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn post_end_waits_on_every_selected_capture_instead_of_the_latest_one() {
+    let base_dir = unique_test_root("post-end");
+    let manifest =
+        start_session_with_captures(&base_dir, &["finalReady", "previewWaiting", "finalReady"]);
+    let session_id = manifest.session_id.clone();
+    let manifest_path = SessionPaths::new(&base_dir, &session_id).manifest_path;
+    select(&base_dir, &session_id, "capture_001", Some(true), None)
+        .expect("first capture should be selectable");
+    let error = select(&base_dir, &session_id, "capture_002", Some(true), None)
+        .expect_err("preview-waiting captures cannot be selected yet");
+    assert_eq!(error.code, "validation-error");
+
+    // Stands in for a capture selected once its preview landed, whose final
+    // is still rendering when the session ends.
+    let mut manifest: SessionManifest = serde_json::from_str(
+        &fs::read_to_string(&manifest_path).expect("manifest should be readable"),
+    )
+    .expect("manifest should deserialize");
+    manifest
+        .selection
+        .as_mut()
+        .expect("selection should exist")
+        .selected_capture_ids
+        .push("capture_002".into());
+    let timing = manifest.timing.as_mut().expect("timing should exist");
+    timing.phase = "ended".into();
+    timing.capture_allowed = false;
+    write_manifest(&base_dir, &manifest);
+
+    let waiting = sync_post_end_state_in_dir(
+        &base_dir,
+        &manifest_path,
+        manifest.clone(),
+        SystemTime::now(),
+    )
+    .expect("post-end should evaluate");
+    assert_eq!(
+        waiting.post_end.as_ref().map(|post_end| post_end.state()),
+        Some("export-waiting")
+    );
+
+    let mut failed = waiting.clone();
+    failed.captures[1].render_status = "renderFailed".into();
+    let failed = sync_post_end_state_in_dir(&base_dir, &manifest_path, failed, SystemTime::now())
+        .expect("post-end should evaluate");
+    assert_eq!(
+        failed.post_end.as_ref().map(|post_end| post_end.state()),
+        Some("phone-required")
+    );
+
+    let mut ready = waiting;
+    let final_path = SessionPaths::new(&base_dir, &session_id)
+        .renders_finals_dir
+        .join("capture_002.jpg");
+    fs::write(&final_path, [0xFF, 0xD8, 0xFF, 0xD9]).expect("final should be written");
+    ready.captures[1].render_status = "finalReady".into();
+    ready.captures[1].final_asset.asset_path = Some(final_path.to_string_lossy().into_owned());
+    let ready = sync_post_end_state_in_dir(&base_dir, &manifest_path, ready, SystemTime::now())
+        .expect("post-end should evaluate");
+    assert_eq!(
+        ready.post_end.as_ref().map(|post_end| post_end.state()),
+        Some("completed")
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
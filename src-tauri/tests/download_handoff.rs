@@ -0,0 +1,299 @@
+use std::{
+    fs,
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    contracts::dto::SessionStartInputDto,
+    handoff::{
+        download_handoff::{
+            prepare_download_handoff_in_dir, start_download_handoff_server, DOWNLOAD_HANDOFF_TTL,
+        },
+        qr_code::QrCode,
+    },
+    session::{
+        session_manifest::{
+            CaptureTimingMetrics, FinalCaptureAsset, PreviewCaptureAsset, RawCaptureAsset,
+            SessionCaptureRecord, SessionManifest, SESSION_CAPTURE_SCHEMA_VERSION,
+        },
+        session_paths::SessionPaths,
+        session_repository::start_session_in_dir,
+    },
+};
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-download-handoff-{test_name}-{stamp}"))
+}
+
+fn start_session_with_final(base_dir: &Path) -> String {
+    let session = start_session_in_dir(
+        base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+    )
+    .expect("session should start");
+    let paths = SessionPaths::new(base_dir, &session.session_id);
+    let mut manifest: SessionManifest = serde_json::from_str(
+        &fs::read_to_string(&paths.manifest_path).expect("manifest should be readable"),
+    )
+    .expect("manifest should deserialize");
+
+    fs::create_dir_all(&paths.renders_finals_dir).expect("finals dir should exist");
+    let final_path = paths.renders_finals_dir.join("capture_001.jpg");
+    fs::write(&final_path, [0xFF, 0xD8, 0xFF, 0xD9]).expect("final fixture should be written");
+    manifest.captures.push(SessionCaptureRecord {
+        schema_version: SESSION_CAPTURE_SCHEMA_VERSION.into(),
+        session_id: manifest.session_id.clone(),
+        booth_alias: manifest.booth_alias.clone(),
+        active_preset_id: Some("preset_soft-glow".into()),
+        active_preset_version: "2026.03.27".into(),
+        active_preset_display_name: Some("Soft Glow".into()),
+        capture_id: "capture_001".into(),
+        request_id: "request_001".into(),
+        raw: RawCaptureAsset {
+            asset_path: "capture_001.cr3".into(),
+            persisted_at_ms: 100,
+        },
+        preview: PreviewCaptureAsset {
+            asset_path: None,
+            enqueued_at_ms: Some(100),
+            ready_at_ms: Some(200),
+        },
+        final_asset: FinalCaptureAsset {
+            asset_path: Some(final_path.to_string_lossy().into_owned()),
+            ready_at_ms: Some(300),
+            variants: Vec::new(),
+        },
+        render_status: "finalReady".into(),
+        render_backend: None,
+        post_end_state: "activeSession".into(),
+        timing: CaptureTimingMetrics {
+            capture_acknowledged_at_ms: 100,
+            preview_visible_at_ms: Some(200),
+            fast_preview_visible_at_ms: None,
+            xmp_preview_ready_at_ms: None,
+            capture_budget_ms: 1_000,
+            preview_budget_ms: 5_000,
+            preview_budget_state: "withinBudget".into(),
+        },
+        capture_program: None,
+    });
+    fs::write(
+        &paths.manifest_path,
+        serde_json::to_vec_pretty(&manifest).expect("manifest should serialize"),
+    )
+    .expect("manifest should be written");
+
+    session.session_id
+}
+
+fn http_get(address: SocketAddr, path: &str) -> (String, Vec<u8>) {
+    let mut stream = TcpStream::connect(address).expect("loopback client should connect");
+    write!(stream, "GET {path} HTTP/1.1\r\nHost: booth\r\n\r\n").expect("request should send");
+    let mut response = Vec::new();
+    stream
+        .read_to_end(&mut response)
+        .expect("response should be readable");
+    let split = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .expect("response should have a header block");
+
+    (
+        String::from_utf8_lossy(&response[..split]).into_owned(),
+        response[split + 4..].to_vec(),
+    )
+}
+
+/// Retries while an earlier delivery still holds the token claim.
+fn http_get_after_delivery(address: SocketAddr, path: &str) -> (String, Vec<u8>) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    loop {
+        let (head, body) = http_get(address, path);
+        if !head.starts_with("HTTP/1.1 409") || Instant::now() > deadline {
+            return (head, body);
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+fn url_path(url: &str) -> &str {
+    let without_scheme = url.trim_start_matches("http://");
+
+    &without_scheme[without_scheme.find('/').expect("url should have a path")..]
+}
+
+#[test]
+fn download_token_serves_the_bundle_once_over_loopback() {
+    let base_dir = unique_test_root("one-time");
+    let session_id = start_session_with_final(&base_dir);
+    let server = start_download_handoff_server(&base_dir, "127.0.0.1:0", None)
+        .expect("server should start on loopback");
+
+    let handoff = prepare_download_handoff_in_dir(
+        &base_dir,
+        &session_id,
+        server.public_base_url(),
+        SystemTime::now(),
+    )
+    .expect("download handoff should be prepared");
+
+    assert!(handoff.url.starts_with(server.public_base_url()));
+    assert_eq!(handoff.file_count, 1);
+    assert!(handoff.qr_svg.starts_with("<svg"));
+
+    let (head, body) = http_get(server.local_addr(), url_path(&handoff.url));
+    assert!(head.starts_with("HTTP/1.1 200"), "{head}");
+    assert!(head.contains("application/zip"));
+    assert!(body.starts_with(b"PK\x03\x04"));
+    assert!(body
+        .windows(b"01-capture_001.jpg".len())
+        .any(|window| window == b"01-capture_001.jpg"));
+
+    let (head, _) = http_get_after_delivery(server.local_addr(), url_path(&handoff.url));
+    assert!(head.starts_with("HTTP/1.1 410"), "{head}");
+
+    let (head, _) = http_get(
+        server.local_addr(),
+        "/handoff/0123456789abcdef0123456789abcdef",
+    );
+    assert!(head.starts_with("HTTP/1.1 404"), "{head}");
+
+    server.stop();
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn stalled_client_does_not_block_other_downloads() {
+    let base_dir = unique_test_root("stalled");
+    let session_id = start_session_with_final(&base_dir);
+    let server = start_download_handoff_server(&base_dir, "127.0.0.1:0", None)
+        .expect("server should start on loopback");
+    let handoff = prepare_download_handoff_in_dir(
+        &base_dir,
+        &session_id,
+        server.public_base_url(),
+        SystemTime::now(),
+    )
+    .expect("download handoff should be prepared");
+
+    let stalled = TcpStream::connect(server.local_addr()).expect("stalled client should connect");
+    let (head, body) = http_get(server.local_addr(), url_path(&handoff.url));
+
+    assert!(head.starts_with("HTTP/1.1 200"), "{head}");
+    assert!(body.starts_with(b"PK\x03\x04"));
+
+    drop(stalled);
+    server.stop();
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn client_that_hangs_up_early_does_not_consume_the_token() {
+    let base_dir = unique_test_root("preview");
+    let session_id = start_session_with_final(&base_dir);
+    let server = start_download_handoff_server(&base_dir, "127.0.0.1:0", None)
+        .expect("server should start on loopback");
+    let handoff = prepare_download_handoff_in_dir(
+        &base_dir,
+        &session_id,
+        server.public_base_url(),
+        SystemTime::now(),
+    )
+    .expect("download handoff should be prepared");
+
+    let mut preview = TcpStream::connect(server.local_addr()).expect("preview should connect");
+    write!(
+        preview,
+        "GET {} HTTP/1.1\r\nHost: booth\r\n\r\n",
+        url_path(&handoff.url)
+    )
+    .expect("preview request should send");
+    // Let the whole small bundle land in the preview's receive buffer, so the
+    // server's writes all succeed before the preview hangs up unread.
+    thread::sleep(Duration::from_millis(200));
+    let mut status = [0u8; 12];
+    preview
+        .read_exact(&mut status)
+        .expect("preview should read the status line");
+    assert_eq!(&status, b"HTTP/1.1 200");
+    drop(preview);
+
+    let (head, body) = http_get_after_delivery(server.local_addr(), url_path(&handoff.url));
+    assert!(head.starts_with("HTTP/1.1 200"), "{head}");
+    assert!(body.starts_with(b"PK\x03\x04"));
+
+    let (head, _) = http_get_after_delivery(server.local_addr(), url_path(&handoff.url));
+    assert!(head.starts_with("HTTP/1.1 410"), "{head}");
+
+    server.stop();
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn expired_download_tokens_are_refused_and_reissued() {
+    let base_dir = unique_test_root("expired");
+    let session_id = start_session_with_final(&base_dir);
+    let server = start_download_handoff_server(&base_dir, "127.0.0.1:0", None)
+        .expect("server should start on loopback");
+
+    let expired = prepare_download_handoff_in_dir(
+        &base_dir,
+        &session_id,
+        server.public_base_url(),
+        SystemTime::now() - DOWNLOAD_HANDOFF_TTL * 2,
+    )
+    .expect("download handoff should be prepared");
+    let (head, _) = http_get(server.local_addr(), url_path(&expired.url));
+    assert!(head.starts_with("HTTP/1.1 410"), "{head}");
+
+    let reissued = prepare_download_handoff_in_dir(
+        &base_dir,
+        &session_id,
+        server.public_base_url(),
+        SystemTime::now(),
+    )
+    .expect("download handoff should be reissued");
+    assert_ne!(reissued.url, expired.url);
+
+    let (head, _) = http_get(server.local_addr(), url_path(&reissued.url));
+    assert!(head.starts_with("HTTP/1.1 200"), "{head}");
+
+    server.stop();
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn qr_code_picks_the_smallest_version_and_draws_finder_patterns() {
+    let short = QrCode::encode(b"http://192.168.0.10:48123/handoff/0123456789abcdef")
+        .expect("short url should encode");
+    assert_eq!(short.version(), 4);
+    assert_eq!(short.size(), 33);
+
+    for (x, y) in [(0, 0), (short.size() - 7, 0), (0, short.size() - 7)] {
+        assert!(short.is_dark(x, y));
+        assert!(short.is_dark(x + 6, y + 6));
+        assert!(!short.is_dark(x + 1, y + 1));
+        assert!(short.is_dark(x + 3, y + 3));
+    }
+    assert!(short.is_dark(8, short.size() - 8));
+
+    assert_eq!(
+        QrCode::encode(&[b'a'; 213]).map(|code| code.version()),
+        Some(10)
+    );
+    assert!(QrCode::encode(&[b'a'; 214]).is_none());
+}
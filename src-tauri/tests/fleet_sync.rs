@@ -0,0 +1,561 @@
+use std::{
+    fs,
+    io::{Read, Write},
+    net::TcpListener,
+    path::{Path, PathBuf},
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    branch_config::{
+        fleet_store::{FleetReleaseStore, HttpFleetStore, LocalDirectoryFleetStore},
+        fleet_sync::{
+            resolve_fleet_sync_state_path, resolve_fleet_trusted_keys_path,
+            sync_fleet_release_with_store,
+        },
+        preset_signing_keys::{resolve_preset_signing_keys_path, PresetSigningKey},
+    },
+    commands::runtime_commands::capability_snapshot_for_profile,
+    contracts::dto::FleetSyncInputDto,
+    integrity::{
+        decode_hex, ed25519_public_key, ed25519_sign, ed25519_verify, encode_hex, sha256_hex,
+    },
+    preset::{
+        bundle_signature::{sign_preset_bundle, PRESET_BUNDLE_SIGNATURE_FILE_NAME},
+        preset_bundle::load_published_preset_summary,
+        preset_catalog::resolve_published_preset_catalog_dir,
+    },
+};
+
+const HQ_SIGNING_SEED: [u8; 32] = [7; 32];
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-fleet-sync-{test_name}-{stamp}"))
+}
+
+fn sync_input() -> FleetSyncInputDto {
+    FleetSyncInputDto {
+        actor_id: "release-kim".into(),
+        actor_label: "Kim Release".into(),
+    }
+}
+
+#[test]
+fn local_directory_release_installs_bundles_and_baselines_once() {
+    let base_dir = unique_test_root("local");
+    let store_dir = base_dir.join("hq-store");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir);
+    fs::write(
+        base_dir.join("branch-config").join("branch-profile.json"),
+        r#"{"schemaVersion":"branch-profile/v1","displayName":"강남 1호점","branchId":"gangnam-01"}"#,
+    )
+    .expect("profile should write");
+    trust_signing_key(&base_dir, "hq-2026", &HQ_SIGNING_SEED);
+    publish_release(&store_dir, "hq-2026", &HQ_SIGNING_SEED);
+    let store = LocalDirectoryFleetStore::new(&store_dir);
+
+    let result =
+        sync_fleet_release_with_store(&base_dir, &capability_snapshot, sync_input(), &store)
+            .expect("sync should return a typed result");
+
+    assert_eq!(result.status, "synced");
+    assert_eq!(result.store_id, "local-directory");
+    assert_eq!(result.release_id.as_deref(), Some("release-2026-04-03"));
+    assert_eq!(result.added_baselines.len(), 1);
+    assert_eq!(result.preset_bundles[0].result, "installed");
+    let bundle_dir = resolve_published_preset_catalog_dir(&base_dir)
+        .join("preset_fleet-glow")
+        .join("2026.04.03");
+    let summary =
+        load_published_preset_summary(&bundle_dir).expect("synced bundle should be selectable");
+    assert_eq!(summary.display_name, "Fleet Glow");
+    assert!(!base_dir
+        .join("preset-catalog")
+        .join("fleet-staging")
+        .read_dir()
+        .is_ok_and(|mut entries| entries.next().is_some()));
+    let state = read_json(&base_dir.join("branch-config").join("state.json"));
+    assert!(state["approvedBaselines"]
+        .as_array()
+        .expect("baselines should be an array")
+        .iter()
+        .any(|baseline| baseline["buildVersion"] == "boothy-2026.04.03.1"));
+
+    let repeated =
+        sync_fleet_release_with_store(&base_dir, &capability_snapshot, sync_input(), &store)
+            .expect("repeated sync should return a typed result");
+    assert_eq!(repeated.status, "up-to-date");
+    assert!(repeated.added_baselines.is_empty());
+    assert_eq!(repeated.preset_bundles[0].result, "already-present");
+
+    let audit_entries = release_governance_audit_entries(&base_dir);
+    assert_eq!(audit_entries.len(), 2);
+    assert!(audit_entries
+        .iter()
+        .all(|entry| entry["eventType"] == "fleet-sync-completed"));
+    assert_eq!(audit_entries[1]["reasonCode"], "up-to-date");
+
+    // Each sync also hands the booth's health report back to HQ.
+    let health_report = read_json(&store_dir.join("health").join("gangnam-01.json"));
+    assert_eq!(health_report["schemaVersion"], "branch-health-report/v1");
+    assert_eq!(health_report["branchId"], "gangnam-01");
+    assert_eq!(
+        health_report["entries"]
+            .as_array()
+            .expect("entries should be an array")
+            .len(),
+        2
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn tampered_bundle_files_reject_the_whole_release() {
+    let base_dir = unique_test_root("tampered");
+    let store_dir = base_dir.join("hq-store");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir);
+    trust_signing_key(&base_dir, "hq-2026", &HQ_SIGNING_SEED);
+    publish_release(&store_dir, "hq-2026", &HQ_SIGNING_SEED);
+    fs::write(
+        store_dir.join("presets/preset_fleet-glow/2026.04.03/preview.jpg"),
+        "swapped",
+    )
+    .expect("tampered preview should write");
+
+    let result = sync_fleet_release_with_store(
+        &base_dir,
+        &capability_snapshot,
+        sync_input(),
+        &LocalDirectoryFleetStore::new(&store_dir),
+    )
+    .expect("sync should return a typed result");
+
+    assert_eq!(result.status, "rejected");
+    assert_eq!(result.rejection_code.as_deref(), Some("integrity-mismatch"));
+    assert!(result.message.contains("preview.jpg"));
+    assert!(!resolve_published_preset_catalog_dir(&base_dir)
+        .join("preset_fleet-glow")
+        .exists());
+    let state = read_json(&base_dir.join("branch-config").join("state.json"));
+    assert_eq!(
+        state["approvedBaselines"]
+            .as_array()
+            .expect("baselines should be an array")
+            .len(),
+        1
+    );
+    let audit_entries = release_governance_audit_entries(&base_dir);
+    assert_eq!(audit_entries[0]["eventType"], "fleet-sync-rejected");
+    assert_eq!(audit_entries[0]["reasonCode"], "integrity-mismatch");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn unsigned_untrusted_or_altered_manifests_are_rejected() {
+    let base_dir = unique_test_root("signature");
+    let store_dir = base_dir.join("hq-store");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir);
+    let store = LocalDirectoryFleetStore::new(&store_dir);
+    let sync = || {
+        sync_fleet_release_with_store(&base_dir, &capability_snapshot, sync_input(), &store)
+            .expect("sync should return a typed result")
+    };
+
+    publish_release(&store_dir, "hq-2026", &HQ_SIGNING_SEED);
+    assert_eq!(
+        sync().rejection_code.as_deref(),
+        Some("untrusted-signing-key")
+    );
+
+    trust_signing_key(&base_dir, "hq-2026", &HQ_SIGNING_SEED);
+    publish_release(&store_dir, "hq-2026", &[9; 32]);
+    assert_eq!(sync().rejection_code.as_deref(), Some("signature-invalid"));
+
+    publish_release(&store_dir, "hq-2026", &HQ_SIGNING_SEED);
+    let manifest_path = store_dir.join("release-manifest.json");
+    let altered = fs::read_to_string(&manifest_path)
+        .expect("manifest should be readable")
+        .replace("release-2026-04-03", "release-2026-04-04");
+    fs::write(&manifest_path, altered).expect("altered manifest should write");
+    assert_eq!(sync().rejection_code.as_deref(), Some("signature-invalid"));
+
+    fs::remove_file(store_dir.join("release-manifest.sig.json"))
+        .expect("signature should be removable");
+    assert_eq!(sync().rejection_code.as_deref(), Some("signature-missing"));
+
+    assert!(!resolve_published_preset_catalog_dir(&base_dir).exists());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn older_or_reissued_releases_are_rejected_after_a_newer_one_is_applied() {
+    let base_dir = unique_test_root("rollback");
+    let store_dir = base_dir.join("hq-store");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir);
+    trust_signing_key(&base_dir, "hq-2026", &HQ_SIGNING_SEED);
+    let store = LocalDirectoryFleetStore::new(&store_dir);
+    let sync = || {
+        sync_fleet_release_with_store(&base_dir, &capability_snapshot, sync_input(), &store)
+            .expect("sync should return a typed result")
+    };
+
+    publish_release(&store_dir, "hq-2026", &HQ_SIGNING_SEED);
+    assert_eq!(sync().status, "synced");
+    publish_release_as(
+        &store_dir,
+        "hq-2026",
+        &HQ_SIGNING_SEED,
+        "release-2026-04-05",
+        "2026-04-05T00:00:00.000Z",
+    );
+    assert_eq!(sync().status, "up-to-date");
+    let state = read_json(&resolve_fleet_sync_state_path(&base_dir));
+    assert_eq!(state["lastReleaseId"], "release-2026-04-05");
+    assert_eq!(state["lastIssuedAt"], "2026-04-05T00:00:00.000Z");
+
+    publish_release(&store_dir, "hq-2026", &HQ_SIGNING_SEED);
+    let rolled_back = sync();
+    assert_eq!(rolled_back.status, "rejected");
+    assert_eq!(
+        rolled_back.rejection_code.as_deref(),
+        Some("release-rollback")
+    );
+
+    publish_release_as(
+        &store_dir,
+        "hq-2026",
+        &HQ_SIGNING_SEED,
+        "release-2026-04-05-b",
+        "2026-04-05T00:00:00.000Z",
+    );
+    assert_eq!(sync().rejection_code.as_deref(), Some("release-replay"));
+    publish_release_as(
+        &store_dir,
+        "hq-2026",
+        &HQ_SIGNING_SEED,
+        "release-2026-04-05",
+        "2026-04-06T00:00:00.000Z",
+    );
+    assert_eq!(sync().rejection_code.as_deref(), Some("release-replay"));
+
+    let state = read_json(&resolve_fleet_sync_state_path(&base_dir));
+    assert_eq!(state["lastReleaseId"], "release-2026-04-05");
+    let audit_entries = release_governance_audit_entries(&base_dir);
+    assert_eq!(audit_entries[2]["reasonCode"], "release-rollback");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn http_store_rejects_objects_over_the_size_limit() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("stub should bind");
+    let address = listener.local_addr().expect("stub address should resolve");
+    let stub = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("stub should accept");
+        let mut buffer = [0u8; 4096];
+        let _ = stream.read(&mut buffer).expect("stub should read");
+        write!(
+            stream,
+            "HTTP/1.0 200 OK\r\nContent-Length: {}\r\n\r\n{{}}",
+            128 * 1024 * 1024
+        )
+        .expect("stub should respond");
+    });
+
+    let store =
+        HttpFleetStore::new(&format!("http://{address}/fleet")).expect("store url should parse");
+    let error = store
+        .fetch("release-manifest.json")
+        .expect_err("oversized objects should be rejected");
+    stub.join().expect("stub should finish");
+
+    assert!(error.message.contains("너무 커서"));
+}
+
+#[test]
+fn http_store_stub_serves_the_same_release() {
+    let base_dir = unique_test_root("http");
+    let store_dir = base_dir.join("hq-store");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    seed_branch_store(&base_dir);
+    trust_signing_key(&base_dir, "hq-2026", &HQ_SIGNING_SEED);
+    publish_release(&store_dir, "hq-2026", &HQ_SIGNING_SEED);
+
+    let listener = TcpListener::bind("127.0.0.1:0").expect("stub should bind");
+    let address = listener.local_addr().expect("stub address should resolve");
+    let served_dir = store_dir.clone();
+    let stub = thread::spawn(move || {
+        let mut requested_paths = Vec::new();
+        for _ in 0..5 {
+            let (mut stream, _) = listener.accept().expect("stub should accept");
+            let mut buffer = [0u8; 4096];
+            let read = stream.read(&mut buffer).expect("stub should read");
+            let request = String::from_utf8_lossy(&buffer[..read]).into_owned();
+            let path = request
+                .split_whitespace()
+                .nth(1)
+                .unwrap_or_default()
+                .trim_start_matches("/fleet/")
+                .to_string();
+            match fs::read(served_dir.join(&path)) {
+                Ok(body) => {
+                    write!(
+                        stream,
+                        "HTTP/1.0 200 OK\r\nContent-Length: {}\r\n\r\n",
+                        body.len()
+                    )
+                    .expect("stub should respond");
+                    stream.write_all(&body).expect("stub should send body");
+                }
+                Err(_) => {
+                    write!(stream, "HTTP/1.0 404 Not Found\r\n\r\n").expect("stub should respond")
+                }
+            }
+            requested_paths.push(path);
+        }
+        requested_paths
+    });
+
+    let store =
+        HttpFleetStore::new(&format!("http://{address}/fleet/")).expect("store url should parse");
+    let result =
+        sync_fleet_release_with_store(&base_dir, &capability_snapshot, sync_input(), &store)
+            .expect("sync should return a typed result");
+    let requested_paths = stub.join().expect("stub should finish");
+
+    assert_eq!(result.status, "synced");
+    assert_eq!(result.store_id, "http");
+    assert_eq!(requested_paths[0], "release-manifest.json");
+    assert_eq!(requested_paths[1], "release-manifest.sig.json");
+    assert!(requested_paths
+        .iter()
+        .any(|path| path == "presets/preset_fleet-glow/2026.04.03/bundle.json"));
+    assert!(HttpFleetStore::new("https://hq.example/fleet").is_err());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn integrity_primitives_match_published_test_vectors() {
+    assert_eq!(
+        sha256_hex(b"abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+
+    let seed = decode_hex::<32>("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+        .expect("seed should decode");
+    let public_key = ed25519_public_key(&seed);
+    assert_eq!(
+        encode_hex(&public_key),
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+    );
+    let signature = ed25519_sign(&seed, b"");
+    assert_eq!(
+        encode_hex(&signature),
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+    );
+    assert!(ed25519_verify(&public_key, b"", &signature));
+    assert!(!ed25519_verify(&public_key, b"x", &signature));
+    assert!(decode_hex::<1>("+f").is_none());
+}
+
+fn publish_release(store_dir: &Path, key_id: &str, seed: &[u8; 32]) {
+    publish_release_as(
+        store_dir,
+        key_id,
+        seed,
+        "release-2026-04-03",
+        "2026-04-03T00:00:00.000Z",
+    );
+}
+
+/// Writes a one-bundle release to `store_dir` the way HQ publishes it and
+/// signs the bundle and the manifest bytes with `seed`.
+fn publish_release_as(
+    store_dir: &Path,
+    key_id: &str,
+    seed: &[u8; 32],
+    release_id: &str,
+    issued_at: &str,
+) {
+    let bundle_dir = store_dir.join("presets/preset_fleet-glow/2026.04.03");
+    fs::create_dir_all(&bundle_dir).expect("store bundle directory should exist");
+    let bundle = serde_json::to_vec_pretty(&serde_json::json!({
+        "schemaVersion": "published-preset-bundle/v1",
+        "presetId": "preset_fleet-glow",
+        "displayName": "Fleet Glow",
+        "publishedVersion": "2026.04.03",
+        "lifecycleStatus": "published",
+        "boothStatus": "booth-safe",
+        "preview": {
+            "kind": "preview-tile",
+            "assetPath": "preview.jpg",
+            "altText": "Fleet Glow preview"
+        }
+    }))
+    .expect("bundle should serialize");
+    let preview = b"preview".to_vec();
+    fs::write(bundle_dir.join("bundle.json"), &bundle).expect("bundle should write");
+    fs::write(bundle_dir.join("preview.jpg"), &preview).expect("preview should write");
+    sign_preset_bundle(
+        &bundle_dir,
+        "preset_fleet-glow",
+        "2026.04.03",
+        &PresetSigningKey {
+            key_id: key_id.into(),
+            seed: *seed,
+        },
+        "2026-04-03T00:00:00.000Z",
+    )
+    .expect("bundle should be signed");
+    let bundle_signature = fs::read(bundle_dir.join(PRESET_BUNDLE_SIGNATURE_FILE_NAME))
+        .expect("bundle signature should be readable");
+
+    let file_entry = |path: &str, bytes: &[u8]| {
+        serde_json::json!({
+            "path": path,
+            "sha256": sha256_hex(bytes),
+            "sizeBytes": bytes.len()
+        })
+    };
+    let manifest = serde_json::to_vec_pretty(&serde_json::json!({
+        "schemaVersion": "fleet-release-manifest/v1",
+        "releaseId": release_id,
+        "issuedAt": issued_at,
+        "approvedBaselines": [{
+            "buildVersion": "boothy-2026.04.03.1",
+            "presetStackVersion": "catalog-2026.04.03",
+            "approvedAt": "2026-04-03T00:00:00.000Z",
+            "actorId": "release-kim",
+            "actorLabel": "Kim Release"
+        }],
+        "presetBundles": [{
+            "presetId": "preset_fleet-glow",
+            "publishedVersion": "2026.04.03",
+            "files": [
+                file_entry("bundle.json", &bundle),
+                file_entry(PRESET_BUNDLE_SIGNATURE_FILE_NAME, &bundle_signature),
+                file_entry("preview.jpg", &preview)
+            ]
+        }]
+    }))
+    .expect("manifest should serialize");
+    fs::write(store_dir.join("release-manifest.json"), &manifest).expect("manifest should write");
+    fs::write(
+        store_dir.join("release-manifest.sig.json"),
+        serde_json::to_vec_pretty(&serde_json::json!({
+            "schemaVersion": "fleet-release-signature/v1",
+            "keyId": key_id,
+            "algorithm": "ed25519",
+            "signature": encode_hex(&ed25519_sign(seed, &manifest))
+        }))
+        .expect("signature should serialize"),
+    )
+    .expect("signature should write");
+}
+
+/// Trusts the HQ key for both release manifests and the bundles they carry.
+fn trust_signing_key(base_dir: &Path, key_id: &str, seed: &[u8; 32]) {
+    let public_key = encode_hex(&ed25519_public_key(seed));
+    let preset_keys_path = resolve_preset_signing_keys_path(base_dir);
+    fs::create_dir_all(
+        preset_keys_path
+            .parent()
+            .expect("keys path should have a parent"),
+    )
+    .expect("branch config directory should exist");
+    fs::write(
+        preset_keys_path,
+        serde_json::to_vec_pretty(&serde_json::json!({
+            "schemaVersion": "preset-signing-keys/v1",
+            "activeKeyId": null,
+            "keys": [{
+                "keyId": key_id,
+                "publicKey": public_key,
+                "origin": "imported",
+                "addedAt": "2026-04-03T00:00:00.000Z",
+                "actorId": "release-kim"
+            }]
+        }))
+        .expect("preset signing keys should serialize"),
+    )
+    .expect("preset signing keys should write");
+
+    let keys_path = resolve_fleet_trusted_keys_path(base_dir);
+    fs::create_dir_all(keys_path.parent().expect("keys path should have a parent"))
+        .expect("branch config directory should exist");
+    fs::write(
+        keys_path,
+        serde_json::to_vec_pretty(&serde_json::json!({
+            "schemaVersion": "fleet-trusted-keys/v1",
+            "keys": [{
+                "keyId": key_id,
+                "publicKey": public_key
+            }]
+        }))
+        .expect("trusted keys should serialize"),
+    )
+    .expect("trusted keys should write");
+}
+
+fn release_governance_audit_entries(base_dir: &Path) -> Vec<serde_json::Value> {
+    read_json(&base_dir.join("diagnostics").join("operator-audit-log.json"))["entries"]
+        .as_array()
+        .expect("operator audit entries should be an array")
+        .iter()
+        .filter(|entry| entry["eventCategory"] == "release-governance")
+        .cloned()
+        .collect()
+}
+
+fn read_json(path: &Path) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(path).expect("json file should be readable"))
+        .expect("json file should deserialize")
+}
+
+fn seed_branch_store(base_dir: &Path) {
+    let branch_config_dir = base_dir.join("branch-config");
+    fs::create_dir_all(&branch_config_dir).expect("branch config directory should exist");
+    let baseline = serde_json::json!({
+        "buildVersion": "boothy-2026.03.20.4",
+        "presetStackVersion": "catalog-2026.03.20",
+        "approvedAt": "2026-03-20T00:10:00.000Z",
+        "actorId": "release-kim",
+        "actorLabel": "Kim Release"
+    });
+    let store = serde_json::json!({
+        "schemaVersion": "branch-rollout-store/v1",
+        "approvedBaselines": [baseline.clone()],
+        "branches": [{
+            "branchId": "gangnam-01",
+            "displayName": "강남 1호점",
+            "deploymentBaseline": baseline,
+            "rollbackBaseline": null,
+            "pendingBaseline": null,
+            "localSettings": {
+                "contactPhone": "02-555-0101",
+                "operationalToggles": []
+            },
+            "activeSession": null
+        }]
+    });
+
+    fs::write(
+        branch_config_dir.join("state.json"),
+        serde_json::to_vec_pretty(&store).expect("store should serialize"),
+    )
+    .expect("store should write");
+}
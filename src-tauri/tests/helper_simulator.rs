@@ -0,0 +1,220 @@
+use std::{
+    fs,
+    path::PathBuf,
+    sync::Once,
+    thread,
+    time::Duration,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    capture::{
+        helper_simulator::{
+            save_simulated_helper_script_in_dir, start_simulated_helper, SimulatedHelperFault,
+            SimulatedHelperScript, SIMULATED_HELPER_FAST_PREVIEW_KIND,
+        },
+        normalized_state::{get_capture_readiness_in_dir, request_capture_in_dir},
+    },
+    contracts::dto::{
+        CaptureReadinessDto, CaptureReadinessInputDto, CaptureRequestInputDto,
+        PresetSelectionInputDto, SessionStartInputDto,
+    },
+    preset::default_catalog::ensure_default_preset_catalog_in_dir,
+    session::{
+        session_manifest::SessionManifest,
+        session_paths::SessionPaths,
+        session_repository::{select_active_preset_in_dir, start_session_in_dir},
+    },
+};
+
+static FAKE_DARKTABLE_SETUP: Once = Once::new();
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    FAKE_DARKTABLE_SETUP.call_once(|| {
+        let script_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("support")
+            .join("fake-darktable-cli.cmd");
+        std::env::set_var("BOOTHY_DARKTABLE_CLI_BIN", script_path);
+    });
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-helper-simulator-{test_name}-{stamp}"))
+}
+
+fn start_session_with_preset(base_dir: &PathBuf) -> String {
+    ensure_default_preset_catalog_in_dir(base_dir).expect("default catalog should seed");
+    let session = start_session_in_dir(
+        base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+    )
+    .expect("session should be created");
+    select_active_preset_in_dir(
+        base_dir,
+        PresetSelectionInputDto {
+            session_id: session.session_id.clone(),
+            preset_id: "preset_soft-glow".into(),
+            published_version: "2026.03.27".into(),
+        },
+    )
+    .expect("preset should become active");
+
+    session.session_id
+}
+
+fn capture_input(session_id: &str) -> CaptureRequestInputDto {
+    CaptureRequestInputDto {
+        session_id: session_id.into(),
+        request_id: None,
+    }
+}
+
+fn live_readiness(base_dir: &PathBuf, session_id: &str) -> CaptureReadinessDto {
+    get_capture_readiness_in_dir(
+        base_dir,
+        CaptureReadinessInputDto {
+            session_id: session_id.into(),
+        },
+    )
+    .expect("readiness should resolve")
+}
+
+#[test]
+fn simulator_completes_a_capture_after_a_scripted_focus_failure() {
+    let base_dir = unique_test_root("focus-then-success");
+    let session_id = start_session_with_preset(&base_dir);
+    save_simulated_helper_script_in_dir(
+        &base_dir,
+        &SimulatedHelperScript {
+            capture_faults: vec![SimulatedHelperFault::FocusFailure],
+            ..SimulatedHelperScript::default()
+        },
+    )
+    .expect("script should be saved");
+    let _helper = start_simulated_helper(&base_dir, &session_id).expect("simulator should start");
+
+    assert!(live_readiness(&base_dir, &session_id).can_capture);
+
+    let error = request_capture_in_dir(&base_dir, capture_input(&session_id))
+        .expect_err("focus failure should reject the first capture");
+    assert_eq!(error.code, "capture-not-ready");
+    assert_eq!(
+        error
+            .readiness
+            .as_ref()
+            .expect("retry readiness should be attached")
+            .reason_code,
+        "capture-retry-required"
+    );
+
+    let result = request_capture_in_dir(&base_dir, capture_input(&session_id))
+        .expect("second capture should be saved");
+    let paths = SessionPaths::new(&base_dir, &session_id);
+
+    assert_eq!(result.status, "capture-saved");
+    assert!(
+        PathBuf::from(&result.capture.raw.asset_path).starts_with(&paths.captures_originals_dir)
+    );
+    assert!(PathBuf::from(&result.capture.raw.asset_path).is_file());
+    assert_eq!(
+        result.capture.preview.asset_path.as_deref(),
+        Some(
+            paths
+                .renders_previews_dir
+                .join(format!("{}.jpg", result.capture.capture_id))
+                .to_string_lossy()
+                .as_ref()
+        )
+    );
+    assert!(
+        fs::read_to_string(paths.diagnostics_dir.join("timing-events.log"))
+            .unwrap_or_default()
+            .contains(SIMULATED_HELPER_FAST_PREVIEW_KIND)
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn simulator_timeout_locks_the_session_into_phone_required() {
+    let base_dir = unique_test_root("timeout");
+    let session_id = start_session_with_preset(&base_dir);
+    fs::write(base_dir.join(".camera-helper-capture-timeout-ms"), "300")
+        .expect("timeout override should be written");
+    save_simulated_helper_script_in_dir(
+        &base_dir,
+        &SimulatedHelperScript {
+            capture_faults: vec![SimulatedHelperFault::Timeout],
+            ..SimulatedHelperScript::default()
+        },
+    )
+    .expect("script should be saved");
+    let _helper = start_simulated_helper(&base_dir, &session_id).expect("simulator should start");
+
+    let error = request_capture_in_dir(&base_dir, capture_input(&session_id))
+        .expect_err("capture should time out");
+    let manifest: SessionManifest = serde_json::from_str(
+        &fs::read_to_string(SessionPaths::new(&base_dir, &session_id).manifest_path)
+            .expect("manifest should be readable"),
+    )
+    .expect("manifest should deserialize");
+
+    assert_eq!(error.code, "capture-not-ready");
+    assert_eq!(manifest.lifecycle.stage, "phone-required");
+    assert!(manifest.captures.is_empty());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn simulator_disconnect_blocks_capture_until_the_camera_returns() {
+    let base_dir = unique_test_root("disconnect");
+    let session_id = start_session_with_preset(&base_dir);
+    save_simulated_helper_script_in_dir(
+        &base_dir,
+        &SimulatedHelperScript {
+            capture_faults: vec![SimulatedHelperFault::Disconnect { duration_ms: 400 }],
+            ..SimulatedHelperScript::default()
+        },
+    )
+    .expect("script should be saved");
+    let _helper = start_simulated_helper(&base_dir, &session_id).expect("simulator should start");
+
+    request_capture_in_dir(&base_dir, capture_input(&session_id))
+        .expect_err("disconnect should reject the capture");
+    thread::sleep(Duration::from_millis(60));
+
+    let readiness = live_readiness(&base_dir, &session_id);
+    assert_eq!(
+        readiness
+            .live_capture_truth
+            .as_ref()
+            .expect("live truth should be projected")
+            .camera_state,
+        "disconnected"
+    );
+    assert!(!readiness.can_capture);
+
+    thread::sleep(Duration::from_millis(700));
+
+    let readiness = live_readiness(&base_dir, &session_id);
+    assert_eq!(
+        readiness
+            .live_capture_truth
+            .as_ref()
+            .expect("live truth should be projected")
+            .camera_state,
+        "ready"
+    );
+    // A mid-capture disconnect is not retryable, so staff still have to clear it.
+    assert_eq!(readiness.customer_state, "Phone Required");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
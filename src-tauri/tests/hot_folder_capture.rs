@@ -0,0 +1,187 @@
+use std::{
+    fs,
+    path::PathBuf,
+    sync::Once,
+    thread,
+    time::Duration,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    capture::{
+        camera_backend::{try_ensure_camera_backend_running, CAMERA_BACKEND_ENV},
+        hot_folder_backend::{
+            read_hot_folder_ingest_records, resolve_hot_folder_dir, HOT_FOLDER_CAMERA_BACKEND_ID,
+        },
+        normalized_state::{get_capture_readiness_in_dir, request_capture_in_dir},
+    },
+    contracts::dto::{
+        CaptureReadinessInputDto, CaptureRequestInputDto, PresetSelectionInputDto,
+        SessionStartInputDto,
+    },
+    preset::default_catalog::ensure_default_preset_catalog_in_dir,
+    session::{
+        session_paths::SessionPaths,
+        session_repository::{select_active_preset_in_dir, start_session_in_dir},
+    },
+};
+
+static HOT_FOLDER_SETUP: Once = Once::new();
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    HOT_FOLDER_SETUP.call_once(|| {
+        let script_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("support")
+            .join("fake-darktable-cli.cmd");
+        std::env::set_var("BOOTHY_DARKTABLE_CLI_BIN", script_path);
+        std::env::set_var(CAMERA_BACKEND_ENV, HOT_FOLDER_CAMERA_BACKEND_ID);
+    });
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-hot-folder-{test_name}-{stamp}"))
+}
+
+fn start_ready_session(base_dir: &PathBuf) -> String {
+    ensure_default_preset_catalog_in_dir(base_dir).expect("default catalog should seed");
+    let session = start_session_in_dir(
+        base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+    )
+    .expect("session should be created");
+    select_active_preset_in_dir(
+        base_dir,
+        PresetSelectionInputDto {
+            session_id: session.session_id.clone(),
+            preset_id: "preset_soft-glow".into(),
+            published_version: "2026.03.27".into(),
+        },
+    )
+    .expect("preset should become active");
+    try_ensure_camera_backend_running(base_dir, &session.session_id);
+
+    session.session_id
+}
+
+#[test]
+fn hot_folder_claims_a_dropped_raw_and_promotes_its_jpeg_sidecar() {
+    let base_dir = unique_test_root("raw-plus-jpeg");
+    let session_id = start_ready_session(&base_dir);
+    let hot_folder_dir = resolve_hot_folder_dir(&base_dir);
+
+    let readiness = get_capture_readiness_in_dir(
+        &base_dir,
+        CaptureReadinessInputDto {
+            session_id: session_id.clone(),
+        },
+    )
+    .expect("readiness should resolve");
+    assert!(readiness.can_capture);
+    assert_eq!(
+        readiness
+            .live_capture_truth
+            .as_ref()
+            .expect("live truth should be projected")
+            .source,
+        "hot-folder"
+    );
+
+    let dropper_dir = hot_folder_dir.clone();
+    let dropper = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(150));
+        fs::write(dropper_dir.join("IMG_0001.JPG"), [0xFF, 0xD8, 0xFF, 0xD9])
+            .expect("jpeg fixture should be copied");
+        fs::write(dropper_dir.join("IMG_0001.CR3"), b"tethered-raw-bytes")
+            .expect("raw fixture should be copied");
+    });
+
+    let result = request_capture_in_dir(
+        &base_dir,
+        CaptureRequestInputDto {
+            session_id: session_id.clone(),
+            request_id: None,
+        },
+    )
+    .expect("hot folder capture should be saved");
+    dropper.join().expect("dropper thread should finish");
+
+    let paths = SessionPaths::new(&base_dir, &session_id);
+    let raw_path = PathBuf::from(&result.capture.raw.asset_path);
+    assert!(raw_path.starts_with(&paths.captures_originals_dir));
+    assert_eq!(
+        raw_path.extension().and_then(|value| value.to_str()),
+        Some("cr3")
+    );
+    assert_eq!(
+        fs::read(&raw_path).expect("raw should be readable"),
+        b"tethered-raw-bytes"
+    );
+    assert_eq!(
+        result.capture.preview.asset_path.as_deref(),
+        Some(
+            paths
+                .renders_previews_dir
+                .join(format!("{}.jpg", result.capture.capture_id))
+                .to_string_lossy()
+                .as_ref()
+        )
+    );
+    assert!(result.capture.timing.fast_preview_visible_at_ms.is_some());
+    assert_eq!(
+        fs::read_dir(&hot_folder_dir)
+            .expect("hot folder should be readable")
+            .count(),
+        0
+    );
+
+    let records =
+        read_hot_folder_ingest_records(&base_dir, &session_id).expect("ingest log should load");
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].capture_id, result.capture.capture_id);
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn hot_folder_ignores_empty_and_stale_files_until_the_capture_times_out() {
+    let base_dir = unique_test_root("stale-and-empty");
+    let session_id = start_ready_session(&base_dir);
+    let hot_folder_dir = resolve_hot_folder_dir(&base_dir);
+    fs::write(base_dir.join(".camera-helper-capture-timeout-ms"), "400")
+        .expect("timeout override should be written");
+
+    let stale_path = hot_folder_dir.join("OLD_0001.CR3");
+    fs::write(&stale_path, b"yesterday").expect("stale fixture should be written");
+    fs::File::options()
+        .write(true)
+        .open(&stale_path)
+        .and_then(|file| file.set_modified(SystemTime::now() - Duration::from_secs(3_600)))
+        .expect("stale fixture mtime should be rewound");
+    let empty_path = hot_folder_dir.join("IMG_0002.CR3");
+    fs::write(&empty_path, b"").expect("empty fixture should be written");
+
+    let error = request_capture_in_dir(
+        &base_dir,
+        CaptureRequestInputDto {
+            session_id: session_id.clone(),
+            request_id: None,
+        },
+    )
+    .expect_err("capture should time out without a settled file");
+
+    assert_eq!(error.code, "capture-not-ready");
+    assert!(stale_path.is_file());
+    assert!(empty_path.is_file());
+    assert!(read_hot_folder_ingest_records(&base_dir, &session_id)
+        .expect("ingest log should load")
+        .is_empty());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
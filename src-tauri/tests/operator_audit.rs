@@ -1,14 +1,17 @@
+mod support;
+
 use std::{
     collections::HashSet,
     fs,
     path::{Path, PathBuf},
-    sync::{Arc, Barrier},
+    sync::{Arc, Barrier, Once},
     thread,
     time::Duration,
     time::{SystemTime, UNIX_EPOCH},
 };
 
 use boothy_lib::{
+    branch_config::preset_signing_keys::PRESET_SIGNING_KEY_DIR_ENV,
     capture::{
         normalized_state::get_capture_readiness_in_dir,
         sidecar_client::{
@@ -41,11 +44,17 @@ use boothy_lib::{
     session::{
         session_manifest::{current_timestamp, SessionManifest},
         session_paths::SessionPaths,
-        session_repository::{select_active_preset_in_dir, start_session_in_dir},
+        session_repository::{
+            read_session_manifest_in_dir, select_active_preset_in_dir, start_session_in_dir,
+        },
     },
 };
+use support::sign_fixture_bundle;
+
+static SIGNING_KEY_DIR_SETUP: Once = Once::new();
 
 fn unique_test_root(test_name: &str) -> PathBuf {
+    ensure_signing_key_dir();
     let stamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
@@ -54,6 +63,17 @@ fn unique_test_root(test_name: &str) -> PathBuf {
     std::env::temp_dir().join(format!("boothy-operator-audit-{test_name}-{stamp}"))
 }
 
+/// Branch private keys go to a folder outside every test base dir.
+fn ensure_signing_key_dir() {
+    SIGNING_KEY_DIR_SETUP.call_once(|| {
+        let key_dir = std::env::temp_dir().join(format!(
+            "boothy-operator-audit-signing-keys-{}",
+            std::process::id()
+        ));
+        std::env::set_var(PRESET_SIGNING_KEY_DIR_ENV, key_dir);
+    });
+}
+
 #[test]
 fn operator_audit_records_lifecycle_timing_post_end_and_operator_intervention_history() {
     let base_dir = unique_test_root("runtime-history");
@@ -310,6 +330,7 @@ fn operator_audit_write_failures_do_not_break_current_booth_flow() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session start should not fail when audit storage is unavailable");
@@ -568,6 +589,7 @@ fn create_preview_waiting_session(base_dir: &Path, published_version: &str) -> S
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -595,10 +617,7 @@ fn create_preview_waiting_session(base_dir: &Path, published_version: &str) -> S
 }
 
 fn read_manifest(base_dir: &Path, session_id: &str) -> SessionManifest {
-    let manifest_path = SessionPaths::new(base_dir, session_id).manifest_path;
-    let manifest_bytes = fs::read_to_string(manifest_path).expect("manifest should be readable");
-
-    serde_json::from_str(&manifest_bytes).expect("manifest should deserialize")
+    read_session_manifest_in_dir(base_dir, session_id).expect("manifest should be readable")
 }
 
 fn request_capture_with_helper_success(
@@ -711,9 +730,7 @@ fn update_timing(
     phase: &str,
 ) {
     let manifest_path = SessionPaths::new(base_dir, session_id).manifest_path;
-    let manifest_bytes = fs::read_to_string(&manifest_path).expect("manifest should be readable");
-    let mut manifest: SessionManifest =
-        serde_json::from_str(&manifest_bytes).expect("manifest should deserialize");
+    let mut manifest = read_manifest(base_dir, session_id);
 
     let timing = manifest
         .timing
@@ -735,9 +752,7 @@ fn update_timing(
 
 fn mark_latest_capture_render_failed(base_dir: &Path, session_id: &str) {
     let manifest_path = SessionPaths::new(base_dir, session_id).manifest_path;
-    let manifest_bytes = fs::read_to_string(&manifest_path).expect("manifest should be readable");
-    let mut manifest: SessionManifest =
-        serde_json::from_str(&manifest_bytes).expect("manifest should deserialize");
+    let mut manifest = read_manifest(base_dir, session_id);
 
     let latest_capture = manifest.captures.last_mut().expect("capture should exist");
     latest_capture.render_status = "renderFailed".into();
@@ -758,6 +773,7 @@ fn sample_draft_payload(preset_id: &str, display_name: &str) -> DraftPresetEditP
         darktable_version: "5.4.1".into(),
         darktable_project_path: "darktable/soft-glow.dtpreset".into(),
         xmp_template_path: "xmp/soft-glow.xmp".into(),
+        overlay_layout_path: None,
         preview_profile: render_profile("preview-standard", "Preview Standard"),
         final_profile: render_profile("final-standard", "Final Standard"),
         noise_policy: DraftNoisePolicyDto {
@@ -831,6 +847,7 @@ fn create_published_bundle(
         serde_json::to_vec_pretty(&bundle).expect("bundle should serialize"),
     )
     .expect("bundle should write");
+    sign_fixture_bundle(&bundle_dir);
 }
 
 fn timestamp_offset(offset_seconds: i64) -> String {
@@ -559,6 +559,7 @@ fn operator_diagnostics_projects_post_end_without_persisting_a_read_side_effect(
             approved_extension_audit_ref: None,
             warning_triggered_at: Some("2026-03-26T00:00:30Z".into()),
             ended_triggered_at: Some("2026-03-26T00:01:00Z".into()),
+            package: None,
         }),
         captures: vec![preview_waiting_capture(session_id)],
         ..base_manifest(session_id)
@@ -656,6 +657,10 @@ fn base_manifest(session_id: &str) -> SessionManifest {
         timing: None,
         captures: Vec::new(),
         post_end: None,
+        print_jobs: Vec::new(),
+        reservation: None,
+        shot_layout: None,
+        selection: None,
     }
 }
 
@@ -681,8 +686,10 @@ fn preview_waiting_capture(session_id: &str) -> SessionCaptureRecord {
         final_asset: FinalCaptureAsset {
             asset_path: None,
             ready_at_ms: None,
+            variants: Vec::new(),
         },
         render_status: "previewWaiting".into(),
+        render_backend: None,
         post_end_state: "activeSession".into(),
         timing: CaptureTimingMetrics {
             capture_acknowledged_at_ms: 100,
@@ -693,6 +700,7 @@ fn preview_waiting_capture(session_id: &str) -> SessionCaptureRecord {
             preview_budget_ms: 5_000,
             preview_budget_state: "pending".into(),
         },
+        capture_program: None,
     }
 }
 
@@ -1,3 +1,5 @@
+mod support;
+
 use std::{
     fs,
     path::PathBuf,
@@ -8,6 +10,7 @@ use std::{
 };
 
 use boothy_lib::{
+    branch_config::session_packages::SessionPackageTier,
     capture::{
         normalized_state::get_capture_readiness_in_dir,
         sidecar_client::{
@@ -23,11 +26,14 @@ use boothy_lib::{
         execute_operator_recovery_action_in_dir, load_operator_recovery_summary_in_dir,
     },
     session::{
-        session_manifest::{current_timestamp, SessionManifest},
+        session_manifest::{current_timestamp, rfc3339_to_unix_seconds, SessionManifest},
         session_paths::SessionPaths,
-        session_repository::{select_active_preset_in_dir, start_session_in_dir},
+        session_repository::{
+            read_session_manifest_in_dir, select_active_preset_in_dir, start_session_in_dir,
+        },
     },
 };
+use support::sign_fixture_bundle;
 
 fn unique_test_root(test_name: &str) -> PathBuf {
     ensure_fake_darktable_cli();
@@ -176,6 +182,68 @@ fn operator_recovery_time_extension_reopens_an_ended_session_within_policy_bound
     let _ = fs::remove_dir_all(base_dir);
 }
 
+#[test]
+fn operator_recovery_time_extension_follows_the_session_package_tier() {
+    let base_dir = unique_test_root("time-extension-package");
+    let capability_snapshot = capability_snapshot_for_profile("operator-enabled", true);
+    let session_id = create_preview_waiting_session(&base_dir);
+    let extend = || {
+        update_timing(
+            &base_dir,
+            &session_id,
+            &timestamp_offset(-60),
+            &timestamp_offset(-10),
+            "active",
+        );
+        execute_operator_recovery_action_in_dir(
+            &base_dir,
+            &capability_snapshot,
+            OperatorRecoveryActionInputDto {
+                session_id: session_id.clone(),
+                action: "approved-time-extension".into(),
+            },
+        )
+        .expect("time extension should return a typed result")
+    };
+
+    set_session_package(&base_dir, &session_id, 0, 2);
+    let result = extend();
+    assert_eq!(result.status, "rejected");
+    assert_eq!(
+        result.rejection_reason.as_deref(),
+        Some("extension-limit-reached")
+    );
+
+    set_session_package(&base_dir, &session_id, 7, 2);
+    let result = extend();
+    assert_eq!(result.status, "applied");
+    let timing = read_manifest(&base_dir, &session_id)
+        .timing
+        .expect("timing should remain available");
+    assert_eq!(timing.approved_extension_minutes, 5);
+    assert_eq!(
+        rfc3339_to_unix_seconds(&timing.adjusted_end_at).expect("end should parse")
+            - rfc3339_to_unix_seconds(&timing.warning_at).expect("warning should parse"),
+        120
+    );
+
+    let result = extend();
+    assert_eq!(result.status, "applied");
+    let timing = read_manifest(&base_dir, &session_id)
+        .timing
+        .expect("timing should remain available");
+    assert_eq!(timing.approved_extension_minutes, 7);
+
+    let result = extend();
+    assert_eq!(result.status, "rejected");
+    assert_eq!(
+        result.rejection_reason.as_deref(),
+        Some("extension-limit-reached")
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
 #[test]
 fn operator_recovery_rejects_time_extension_when_the_session_still_cannot_reopen() {
     let base_dir = unique_test_root("time-extension-too-late");
@@ -329,6 +397,7 @@ fn create_preview_waiting_session(base_dir: &PathBuf) -> String {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created");
@@ -362,6 +431,7 @@ fn create_unconfigured_session(base_dir: &PathBuf) -> String {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should be created")
@@ -369,10 +439,7 @@ fn create_unconfigured_session(base_dir: &PathBuf) -> String {
 }
 
 fn read_manifest(base_dir: &PathBuf, session_id: &str) -> SessionManifest {
-    let manifest_path = SessionPaths::new(base_dir, session_id).manifest_path;
-    let manifest_bytes = fs::read_to_string(manifest_path).expect("manifest should be readable");
-
-    serde_json::from_str(&manifest_bytes).expect("manifest should deserialize")
+    read_session_manifest_in_dir(base_dir, session_id).expect("manifest should be readable")
 }
 
 fn request_capture_with_helper_success(
@@ -485,9 +552,7 @@ fn update_timing(
     phase: &str,
 ) {
     let manifest_path = SessionPaths::new(base_dir, session_id).manifest_path;
-    let manifest_bytes = fs::read_to_string(&manifest_path).expect("manifest should be readable");
-    let mut manifest: SessionManifest =
-        serde_json::from_str(&manifest_bytes).expect("manifest should deserialize");
+    let mut manifest = read_manifest(base_dir, session_id);
 
     let timing = manifest
         .timing
@@ -507,6 +572,34 @@ fn update_timing(
     .expect("manifest should be writable");
 }
 
+fn set_session_package(
+    base_dir: &PathBuf,
+    session_id: &str,
+    max_extension_minutes: u32,
+    warning_lead_minutes: u32,
+) {
+    let manifest_path = SessionPaths::new(base_dir, session_id).manifest_path;
+    let mut manifest = read_manifest(base_dir, session_id);
+    manifest
+        .timing
+        .as_mut()
+        .expect("session timing should exist")
+        .package = Some(SessionPackageTier {
+        package_id: "short".into(),
+        display_name: "짧은 촬영".into(),
+        duration_minutes: 10,
+        warning_lead_minutes,
+        max_extension_minutes,
+        max_selected_captures: None,
+    });
+
+    fs::write(
+        manifest_path,
+        serde_json::to_vec_pretty(&manifest).expect("manifest should serialize"),
+    )
+    .expect("manifest should be writable");
+}
+
 fn timestamp_offset(offset_seconds: i64) -> String {
     let now = SystemTime::now();
     let shifted = if offset_seconds >= 0 {
@@ -579,6 +672,7 @@ fn create_named_published_bundle(
         serde_json::to_vec_pretty(&bundle).expect("bundle should serialize"),
     )
     .expect("bundle should be writable");
+    sign_fixture_bundle(&bundle_dir);
 }
 
 fn write_ready_helper_status(base_dir: &PathBuf, session_id: &str) {
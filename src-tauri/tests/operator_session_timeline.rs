@@ -0,0 +1,205 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Once,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    capture::{helper_simulator::start_simulated_helper, normalized_state::request_capture_in_dir},
+    commands::runtime_commands::capability_snapshot_for_profile,
+    contracts::dto::{
+        CaptureRequestInputDto, OperatorSessionTimelineInputDto, PresetSelectionInputDto,
+        SessionStartInputDto,
+    },
+    diagnostics::session_timeline::load_operator_session_timeline_in_dir,
+    preset::default_catalog::ensure_default_preset_catalog_in_dir,
+    session::{
+        session_manifest::{current_timestamp, rfc3339_to_unix_seconds},
+        session_paths::SessionPaths,
+        session_repository::{select_active_preset_in_dir, start_session_in_dir},
+    },
+};
+
+static FAKE_DARKTABLE_SETUP: Once = Once::new();
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    FAKE_DARKTABLE_SETUP.call_once(|| {
+        let script_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("support")
+            .join("fake-darktable-cli.cmd");
+        std::env::set_var("BOOTHY_DARKTABLE_CLI_BIN", script_path);
+    });
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-operator-timeline-{test_name}-{stamp}"))
+}
+
+fn start_session_with_preset(base_dir: &Path) -> String {
+    ensure_default_preset_catalog_in_dir(base_dir).expect("default catalog should seed");
+    let session = start_session_in_dir(
+        base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+    )
+    .expect("session should be created");
+    select_active_preset_in_dir(
+        base_dir,
+        PresetSelectionInputDto {
+            session_id: session.session_id.clone(),
+            preset_id: "preset_soft-glow".into(),
+            published_version: "2026.03.27".into(),
+        },
+    )
+    .expect("preset should become active");
+
+    session.session_id
+}
+
+fn append_timing_log_line(base_dir: &Path, session_id: &str, line: &str) {
+    let diagnostics_dir = SessionPaths::new(base_dir, session_id).diagnostics_dir;
+    fs::create_dir_all(&diagnostics_dir).expect("diagnostics dir should exist");
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(diagnostics_dir.join("timing-events.log"))
+        .expect("timing log should open");
+    writeln!(file, "{line}").expect("timing log should be writable");
+}
+
+#[test]
+fn timeline_merges_every_source_in_order_with_capture_latencies() {
+    let base_dir = unique_test_root("merged");
+    let session_id = start_session_with_preset(&base_dir);
+    let _helper = start_simulated_helper(&base_dir, &session_id).expect("simulator should start");
+    let capture = request_capture_in_dir(
+        &base_dir,
+        CaptureRequestInputDto {
+            session_id: session_id.clone(),
+            request_id: None,
+        },
+    )
+    .expect("capture should save")
+    .capture;
+    let now = current_timestamp(SystemTime::now()).expect("timestamp should serialize");
+    append_timing_log_line(
+        &base_dir,
+        &session_id,
+        &format!(
+            "{now}\tsession={session_id}\tcapture={}\trequest={}\tevent=render-failed\tstage=preview\treason=render-cli-missing\tdetail=none",
+            capture.capture_id, capture.request_id
+        ),
+    );
+    append_timing_log_line(&base_dir, &session_id, "not a timing line");
+
+    let timeline = load_operator_session_timeline_in_dir(
+        &base_dir,
+        &capability_snapshot_for_profile("operator-enabled", true),
+        OperatorSessionTimelineInputDto {
+            session_id: Some(session_id.clone()),
+        },
+    )
+    .expect("timeline should load");
+
+    assert_eq!(timeline.schema_version, "operator-session-timeline/v1");
+    assert_eq!(timeline.session_id, session_id);
+    for source in [
+        "operator-audit",
+        "session-journal",
+        "camera-helper",
+        "render",
+    ] {
+        assert!(
+            timeline.entries.iter().any(|entry| entry.source == source),
+            "timeline should include {source} entries"
+        );
+    }
+    let seconds: Vec<u64> = timeline
+        .entries
+        .iter()
+        .map(|entry| rfc3339_to_unix_seconds(&entry.occurred_at).expect("entries are timestamped"))
+        .collect();
+    assert!(seconds.windows(2).all(|pair| pair[0] <= pair[1]));
+
+    let render_failure = timeline
+        .entries
+        .iter()
+        .find(|entry| entry.source == "render" && entry.event == "render-failed")
+        .expect("render failure should be on the timeline");
+    assert_eq!(
+        render_failure.capture_id.as_deref(),
+        Some(capture.capture_id.as_str())
+    );
+    assert_eq!(render_failure.stage.as_deref(), Some("preview"));
+    assert_eq!(
+        render_failure.reason_code.as_deref(),
+        Some("render-cli-missing")
+    );
+    assert_eq!(render_failure.detail, None);
+    assert!(timeline.entries.iter().any(|entry| {
+        entry.source == "session-journal"
+            && entry.event == "capture-saved"
+            && entry.capture_id.as_deref() == Some(capture.capture_id.as_str())
+    }));
+
+    assert_eq!(timeline.capture_latencies.len(), 1);
+    let latency = &timeline.capture_latencies[0];
+    assert_eq!(latency.capture_id, capture.capture_id);
+    assert_eq!(
+        latency.capture_acknowledged_at_ms,
+        capture.timing.capture_acknowledged_at_ms
+    );
+    assert_eq!(
+        latency.raw_persisted_ms,
+        Some(
+            capture
+                .raw
+                .persisted_at_ms
+                .saturating_sub(capture.timing.capture_acknowledged_at_ms)
+        )
+    );
+    assert_eq!(latency.preview_budget_ms, capture.timing.preview_budget_ms);
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn timeline_defaults_to_the_current_session_and_requires_operator_access() {
+    let base_dir = unique_test_root("current");
+    let session_id = start_session_with_preset(&base_dir);
+
+    let timeline = load_operator_session_timeline_in_dir(
+        &base_dir,
+        &capability_snapshot_for_profile("operator-enabled", true),
+        OperatorSessionTimelineInputDto::default(),
+    )
+    .expect("current session timeline should load");
+    assert_eq!(timeline.session_id, session_id);
+    assert!(timeline.capture_latencies.is_empty());
+    assert_eq!(
+        timeline
+            .entries
+            .iter()
+            .find(|entry| entry.source == "session-journal")
+            .map(|entry| entry.event.as_str()),
+        Some("session-started")
+    );
+
+    let error = load_operator_session_timeline_in_dir(
+        &base_dir,
+        &capability_snapshot_for_profile("booth-only", false),
+        OperatorSessionTimelineInputDto::default(),
+    )
+    .expect_err("booth-only runtime should not see the timeline");
+    assert_eq!(error.code, "capability-denied");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
@@ -1,13 +1,20 @@
+mod support;
+
 use std::{
     fs,
+    io::Write,
     path::{Path, PathBuf},
+    sync::Once,
     time::{SystemTime, UNIX_EPOCH},
 };
 
+use flate2::{write::ZlibEncoder, Compression, Crc};
+
 #[cfg(windows)]
 use std::os::windows::fs::symlink_file;
 
 use boothy_lib::{
+    branch_config::preset_signing_keys::PRESET_SIGNING_KEY_DIR_ENV,
     commands::runtime_commands::capability_snapshot_for_profile,
     contracts::dto::{
         DraftNoisePolicyDto, DraftPresetEditPayloadDto, DraftPresetPreviewReferenceDto,
@@ -34,8 +41,12 @@ use boothy_lib::{
         session_repository::{select_active_preset_in_dir, start_session_in_dir},
     },
 };
+use support::sign_fixture_bundle;
+
+static SIGNING_KEY_DIR_SETUP: Once = Once::new();
 
 fn unique_test_root(test_name: &str) -> PathBuf {
+    ensure_signing_key_dir();
     let stamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
@@ -44,6 +55,17 @@ fn unique_test_root(test_name: &str) -> PathBuf {
     std::env::temp_dir().join(format!("boothy-authoring-{test_name}-{stamp}"))
 }
 
+/// Branch private keys go to a folder outside every test base dir.
+fn ensure_signing_key_dir() {
+    SIGNING_KEY_DIR_SETUP.call_once(|| {
+        let key_dir = std::env::temp_dir().join(format!(
+            "boothy-authoring-signing-keys-{}",
+            std::process::id()
+        ));
+        std::env::set_var(PRESET_SIGNING_KEY_DIR_ENV, key_dir);
+    });
+}
+
 #[test]
 fn draft_authoring_round_trips_through_a_separate_workspace_root() {
     let base_dir = unique_test_root("round-trip");
@@ -320,6 +342,7 @@ fn draft_validation_returns_actionable_findings_without_mutating_catalog_or_acti
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should start before preset selection");
@@ -894,6 +917,7 @@ fn validated_draft_publishes_an_immutable_bundle_and_future_sessions_can_select_
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("active session should start");
@@ -998,6 +1022,7 @@ fn validated_draft_publishes_an_immutable_bundle_and_future_sessions_can_select_
         SessionStartInputDto {
             name: "Lee".into(),
             phone_last_four: "1932".into(),
+            package_id: None,
         },
     )
     .expect("future session should start");
@@ -1047,6 +1072,7 @@ fn default_catalog_bootstraps_first_run_booth_presets() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should start after default catalog bootstrap");
@@ -1086,6 +1112,7 @@ fn default_catalog_bootstrap_does_not_overwrite_existing_published_presets() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should start against existing catalog");
@@ -1155,6 +1182,7 @@ fn rollback_switches_only_future_sessions_to_the_selected_approved_version() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("active session should start against the current live catalog");
@@ -1241,6 +1269,7 @@ fn rollback_switches_only_future_sessions_to_the_selected_approved_version() {
         SessionStartInputDto {
             name: "Lee".into(),
             phone_last_four: "1932".into(),
+            package_id: None,
         },
     )
     .expect("future session should start after rollback");
@@ -1297,6 +1326,7 @@ fn rollback_rejection_keeps_catalog_state_and_active_session_unchanged() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("active session should start");
@@ -1418,6 +1448,7 @@ fn malformed_catalog_state_does_not_silently_reset_the_live_catalog() {
         SessionStartInputDto {
             name: "Lee".into(),
             phone_last_four: "1932".into(),
+            package_id: None,
         },
     )
     .expect("future session should still start before loading the catalog");
@@ -1512,6 +1543,7 @@ fn publication_rejection_keeps_catalog_and_active_session_unchanged_and_records_
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("active session should start");
@@ -1754,6 +1786,172 @@ fn publication_rejects_workspace_symlink_escapes_without_creating_a_bundle() {
     let _ = fs::remove_dir_all(base_dir);
 }
 
+#[test]
+fn draft_validation_reports_overlay_layout_findings_with_field_paths() {
+    let base_dir = unique_test_root("overlay-invalid");
+    let capability_snapshot = capability_snapshot_for_profile("authoring-enabled", true);
+    create_draft_preset_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        DraftPresetEditPayloadDto {
+            overlay_layout_path: Some("overlay/layout.json".into()),
+            ..sample_draft_payload("preset_soft-glow-draft", "Soft Glow Draft")
+        },
+    )
+    .expect("draft creation should succeed");
+    scaffold_valid_draft_assets(&base_dir, "preset_soft-glow-draft");
+    let overlay_dir = resolve_draft_authoring_root(&base_dir)
+        .join("preset_soft-glow-draft")
+        .join("overlay");
+    fs::create_dir_all(&overlay_dir).expect("overlay directory should exist");
+    fs::write(
+        overlay_dir.join("layout.json"),
+        r##"{
+          "schemaVersion": "overlay-layout/v1",
+          "elements": [
+            { "kind": "image", "assetPath": "frame.png", "x": 0.5, "y": 0.0, "width": 0.8, "height": 1.0 },
+            { "kind": "image", "assetPath": "../logo.png", "x": 0.0, "y": 0.0, "width": 0.1, "height": 0.1 },
+            { "kind": "text", "template": "{boothAlias} {storeName}", "x": 0.05, "y": 0.9, "height": 0.04 },
+            { "kind": "text", "template": "{date} 서울", "x": 0.95, "y": 0.9, "height": 0.04, "anchor": "end" }
+          ]
+        }"##,
+    )
+    .expect("layout should write");
+
+    let result = validate_draft_preset_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        ValidateDraftPresetInputDto {
+            preset_id: "preset_soft-glow-draft".into(),
+        },
+    )
+    .expect("validation should return a report");
+
+    assert_eq!(result.report.status, "failed");
+    let findings = result
+        .report
+        .findings
+        .iter()
+        .map(|finding| {
+            (
+                finding.rule_code.as_str(),
+                finding.severity.as_str(),
+                finding.field_path.as_deref().unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert!(findings.contains(&(
+        "overlay-element-bounds",
+        "error",
+        "overlayLayoutPath.elements[0]"
+    )));
+    assert!(findings.contains(&(
+        "overlay-asset-missing",
+        "error",
+        "overlayLayoutPath.elements[0].assetPath"
+    )));
+    assert!(findings.contains(&(
+        "overlay-asset-unsupported",
+        "error",
+        "overlayLayoutPath.elements[1].assetPath"
+    )));
+    assert!(findings.contains(&(
+        "overlay-text-token",
+        "error",
+        "overlayLayoutPath.elements[2].template"
+    )));
+    assert!(findings.contains(&(
+        "overlay-text-glyphs",
+        "warning",
+        "overlayLayoutPath.elements[3].template"
+    )));
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn validated_overlay_layout_is_published_with_its_assets_and_loaded_at_runtime() {
+    let base_dir = unique_test_root("overlay-publish");
+    let capability_snapshot = capability_snapshot_for_profile("authoring-enabled", true);
+    create_draft_preset_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        DraftPresetEditPayloadDto {
+            overlay_layout_path: Some("overlay/layout.json".into()),
+            ..sample_draft_payload("preset_soft-glow-draft", "Soft Glow Draft")
+        },
+    )
+    .expect("draft creation should succeed");
+    scaffold_valid_draft_assets(&base_dir, "preset_soft-glow-draft");
+    let overlay_dir = resolve_draft_authoring_root(&base_dir)
+        .join("preset_soft-glow-draft")
+        .join("overlay");
+    fs::create_dir_all(&overlay_dir).expect("overlay directory should exist");
+    write_rgba_png(&overlay_dir.join("frame.png"), 4, 6, [255, 255, 255, 128]);
+    fs::write(
+        overlay_dir.join("layout.json"),
+        r##"{
+          "schemaVersion": "overlay-layout/v1",
+          "appliesTo": ["final"],
+          "utcOffsetMinutes": 540,
+          "elements": [
+            { "kind": "image", "assetPath": "frame.png", "x": 0.0, "y": 0.0, "width": 1.0, "height": 1.0 },
+            { "kind": "text", "template": "{boothAlias} {date}", "x": 0.5, "y": 0.9, "height": 0.04, "color": "#1A1A1A", "anchor": "center" }
+          ]
+        }"##,
+    )
+    .expect("layout should write");
+
+    let validation_result = validate_draft_preset_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        ValidateDraftPresetInputDto {
+            preset_id: "preset_soft-glow-draft".into(),
+        },
+    )
+    .expect("validation should pass");
+    assert_eq!(validation_result.report.status, "passed");
+    assert!(validation_result.report.findings.is_empty());
+
+    let publish_result = publish_validated_preset_in_dir(
+        &base_dir,
+        &capability_snapshot,
+        PublishValidatedPresetInputDto {
+            preset_id: "preset_soft-glow-draft".into(),
+            draft_version: validation_result.draft.draft_version,
+            validation_checked_at: validation_result.report.checked_at.clone(),
+            expected_display_name: "Soft Glow Draft".into(),
+            published_version: "2026.03.26".into(),
+            actor_id: "manager-kim".into(),
+            actor_label: "Kim Manager".into(),
+            scope: "future-sessions-only".into(),
+            review_note: None,
+        },
+    )
+    .expect("publish should succeed");
+    assert!(matches!(
+        publish_result,
+        PublishValidatedPresetResultDto::Published { .. }
+    ));
+
+    let bundle_dir = resolve_published_preset_catalog_dir(&base_dir)
+        .join("preset_soft-glow-draft")
+        .join("2026.03.26");
+    assert!(bundle_dir.join("overlay").join("layout.json").is_file());
+    assert!(bundle_dir.join("overlay").join("frame.png").is_file());
+    let runtime_bundle =
+        load_published_preset_runtime_bundle(&bundle_dir).expect("runtime bundle should load");
+    let overlay_layout = runtime_bundle
+        .overlay_layout
+        .expect("overlay layout should be loaded");
+    assert!(!overlay_layout.applies_to_preview);
+    assert!(overlay_layout.applies_to_final);
+    assert_eq!(overlay_layout.utc_offset_minutes, 540);
+    assert_eq!(overlay_layout.elements.len(), 2);
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
 fn sample_draft_payload(preset_id: &str, display_name: &str) -> DraftPresetEditPayloadDto {
     DraftPresetEditPayloadDto {
         preset_id: preset_id.into(),
@@ -1762,6 +1960,7 @@ fn sample_draft_payload(preset_id: &str, display_name: &str) -> DraftPresetEditP
         darktable_version: "5.4.1".into(),
         darktable_project_path: "darktable/soft-glow.dtpreset".into(),
         xmp_template_path: "xmp/soft-glow.xmp".into(),
+        overlay_layout_path: None,
         preview_profile: render_profile("preview-standard", "Preview Standard"),
         final_profile: render_profile("final-standard", "Final Standard"),
         noise_policy: DraftNoisePolicyDto {
@@ -1808,6 +2007,43 @@ fn scaffold_valid_draft_assets(base_dir: &Path, preset_id: &str) {
     fs::write(draft_root.join("samples/soft-glow-cut.jpg"), "sample").expect("sample should write");
 }
 
+fn write_rgba_png(path: &Path, width: u32, height: u32, rgba: [u8; 4]) {
+    let mut scanlines = Vec::new();
+    for _ in 0..height {
+        scanlines.push(0);
+        for _ in 0..width {
+            scanlines.extend_from_slice(&rgba);
+        }
+    }
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(&scanlines)
+        .expect("png scanlines should compress");
+    let compressed = encoder.finish().expect("png data should finish");
+
+    let mut ihdr = Vec::new();
+    ihdr.extend_from_slice(&width.to_be_bytes());
+    ihdr.extend_from_slice(&height.to_be_bytes());
+    ihdr.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    let mut bytes = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    for (kind, data) in [
+        (b"IHDR", ihdr.as_slice()),
+        (b"IDAT", compressed.as_slice()),
+        (b"IEND", &[][..]),
+    ] {
+        let mut crc = Crc::new();
+        crc.update(kind);
+        crc.update(data);
+        bytes.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(kind);
+        bytes.extend_from_slice(data);
+        bytes.extend_from_slice(&crc.sum().to_be_bytes());
+    }
+
+    fs::write(path, bytes).expect("png should write");
+}
+
 fn scaffold_invalid_render_assets(base_dir: &Path, preset_id: &str) {
     let draft_root = resolve_draft_authoring_root(base_dir).join(preset_id);
 
@@ -1936,6 +2172,7 @@ fn create_published_bundle(
         serde_json::to_vec_pretty(&bundle).expect("bundle should serialize"),
     )
     .expect("bundle should write");
+    sign_fixture_bundle(&bundle_dir);
 }
 
 fn snapshot_tree(root: &Path) -> Vec<(String, String)> {
@@ -0,0 +1,531 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    branch_config::preset_signing_keys::{
+        generate_preset_signing_key_in_dir, load_preset_signing_keys_in_dir,
+        resign_unsigned_preset_bundles_in_dir, resolve_preset_signing_keys_path,
+        revoke_preset_signing_key_in_dir, trust_preset_signing_key_in_dir, PresetSigningKey,
+        PRESET_SIGNING_KEY_DIR_ENV,
+    },
+    commands::runtime_commands::capability_snapshot_for_profile,
+    contracts::dto::{
+        DraftNoisePolicyDto, DraftPresetEditPayloadDto, DraftPresetPreviewReferenceDto,
+        DraftRenderProfileDto, OperatorAuditQueryFilterDto, PresetBundleResignInputDto,
+        PresetSigningKeyGenerateInputDto, PresetSigningKeyRevokeInputDto,
+        PresetSigningKeyTrustInputDto, PublishValidatedPresetInputDto, ValidateDraftPresetInputDto,
+    },
+    diagnostics::audit_log::load_operator_audit_history_in_dir,
+    integrity::{ed25519_public_key, encode_hex},
+    preset::{
+        authoring_pipeline::{
+            create_draft_preset_in_dir, publish_validated_preset_in_dir,
+            resolve_draft_authoring_root, validate_draft_preset_in_dir,
+        },
+        bundle_signature::{
+            check_published_bundle_signature, sign_preset_bundle, PRESET_BUNDLE_SIGNATURE_FILE_NAME,
+        },
+        default_catalog::ensure_default_preset_catalog_in_dir,
+        preset_bundle::load_published_preset_runtime_bundle,
+        preset_catalog::{
+            find_selectable_published_preset_summary, resolve_published_preset_catalog_dir,
+        },
+    },
+    session::session_manifest::ActivePresetBinding,
+};
+
+const PRESET_ID: &str = "preset_soft-glow-draft";
+const HQ_SIGNING_SEED: [u8; 32] = [11; 32];
+
+static SIGNING_KEY_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    signing_key_dir();
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-bundle-signing-{test_name}-{stamp}"))
+}
+
+/// Branch private keys go to a folder outside every test base dir.
+fn signing_key_dir() -> &'static Path {
+    SIGNING_KEY_DIR.get_or_init(|| {
+        let key_dir =
+            std::env::temp_dir().join(format!("boothy-bundle-signing-keys-{}", std::process::id()));
+        std::env::set_var(PRESET_SIGNING_KEY_DIR_ENV, &key_dir);
+        key_dir
+    })
+}
+
+#[test]
+fn published_bundle_is_signed_and_tampering_is_rejected_with_audit() {
+    let base_dir = unique_test_root("tamper");
+    let bundle_dir = publish_sample_preset(&base_dir, "2026.04.01");
+    let catalog_root = resolve_published_preset_catalog_dir(&base_dir);
+    let snapshot = catalog_snapshot("2026.04.01");
+
+    assert!(bundle_dir.join(PRESET_BUNDLE_SIGNATURE_FILE_NAME).is_file());
+    let keys_path = resolve_preset_signing_keys_path(&base_dir);
+    assert!(!fs::read_to_string(&keys_path)
+        .expect("signing keys should be readable")
+        .contains("privateKey"));
+    let key_id = load_preset_signing_keys_in_dir(
+        &base_dir,
+        &capability_snapshot_for_profile("operator-enabled", true),
+    )
+    .expect("keys should load")
+    .active_key_id
+    .expect("publish should create an active key");
+    assert!(signing_key_dir().join(format!("{key_id}.key")).is_file());
+    assert!(load_published_preset_runtime_bundle(&bundle_dir).is_some());
+    assert!(find_selectable_published_preset_summary(
+        &catalog_root,
+        PRESET_ID,
+        "2026.04.01",
+        &snapshot
+    )
+    .expect("selection input should be valid")
+    .is_some());
+
+    let xmp_path = fs::read_dir(bundle_dir.join("xmp"))
+        .expect("bundle should keep its xmp directory")
+        .flatten()
+        .map(|entry| entry.path())
+        .next()
+        .expect("bundle should include an xmp template");
+    // Same size and modification time, different bytes: the cached verdict
+    // must not survive it.
+    let modified_at = fs::metadata(&xmp_path)
+        .and_then(|metadata| metadata.modified())
+        .expect("xmp should have a modification time");
+    let mut xmp = fs::read(&xmp_path).expect("xmp should be readable");
+    let last = xmp.len() - 1;
+    xmp[last] = if xmp[last] == b' ' { b'\t' } else { b' ' };
+    fs::write(&xmp_path, &xmp).expect("xmp should be writable");
+    fs::File::options()
+        .write(true)
+        .open(&xmp_path)
+        .and_then(|file| file.set_modified(modified_at))
+        .expect("xmp modification time should be restorable");
+
+    let finding =
+        check_published_bundle_signature(&bundle_dir).expect_err("tampered bundle should fail");
+    assert_eq!(finding.rule_code, "bundle-content-mismatch");
+    assert!(load_published_preset_runtime_bundle(&bundle_dir).is_none());
+    assert!(find_selectable_published_preset_summary(
+        &catalog_root,
+        PRESET_ID,
+        "2026.04.01",
+        &snapshot
+    )
+    .expect("selection input should be valid")
+    .is_none());
+    assert!(load_published_preset_runtime_bundle(&bundle_dir).is_none());
+
+    assert_eq!(
+        bundle_rejections(&base_dir),
+        vec!["bundle-content-mismatch".to_string()]
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn unsigned_bundles_are_rejected_when_the_key_store_is_missing_or_empty() {
+    let base_dir = unique_test_root("unsigned");
+    let catalog_root = resolve_published_preset_catalog_dir(&base_dir);
+    let unsigned_dir = write_unsigned_bundle(&catalog_root, "preset_unsigned", "2026.03.01");
+
+    assert!(!resolve_preset_signing_keys_path(&base_dir).exists());
+    let finding =
+        check_published_bundle_signature(&unsigned_dir).expect_err("unsigned bundle should fail");
+    assert_eq!(finding.rule_code, "bundle-signature-missing");
+    assert!(load_published_preset_runtime_bundle(&unsigned_dir).is_none());
+    assert!(load_published_preset_runtime_bundle(&unsigned_dir).is_none());
+    assert_eq!(
+        bundle_rejections(&base_dir),
+        vec!["bundle-signature-missing".to_string()]
+    );
+
+    let hq_key = PresetSigningKey {
+        key_id: "hq-2026".into(),
+        seed: HQ_SIGNING_SEED,
+    };
+    sign_preset_bundle(
+        &unsigned_dir,
+        "preset_unsigned",
+        "2026.03.01",
+        &hq_key,
+        "2026-03-01T00:00:00Z",
+    )
+    .expect("bundle should be signed");
+    fs::create_dir_all(base_dir.join("branch-config")).expect("branch config should exist");
+    fs::write(
+        resolve_preset_signing_keys_path(&base_dir),
+        r#"{"schemaVersion":"preset-signing-keys/v1","activeKeyId":null,"keys":[]}"#,
+    )
+    .expect("empty key store should write");
+    let finding = check_published_bundle_signature(&unsigned_dir)
+        .expect_err("an empty key store should trust nothing");
+    assert_eq!(finding.rule_code, "bundle-signing-key-untrusted");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn seeded_default_bundles_load_without_keys_until_they_are_changed() {
+    let base_dir = unique_test_root("seeded");
+    ensure_default_preset_catalog_in_dir(&base_dir).expect("default catalog should seed");
+    let bundle_dir = resolve_published_preset_catalog_dir(&base_dir)
+        .join("preset_soft-glow")
+        .join("2026.03.27");
+
+    assert!(!bundle_dir.join(PRESET_BUNDLE_SIGNATURE_FILE_NAME).exists());
+    assert!(load_published_preset_runtime_bundle(&bundle_dir).is_some());
+
+    fs::write(bundle_dir.join("preview.svg"), "<svg/>").expect("preview should be writable");
+    let finding =
+        check_published_bundle_signature(&bundle_dir).expect_err("changed seed should fail");
+    assert_eq!(finding.rule_code, "bundle-signature-missing");
+    assert!(load_published_preset_runtime_bundle(&bundle_dir).is_none());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn bundles_published_before_signing_are_resigned_on_request() {
+    let base_dir = unique_test_root("resign");
+    let operator_capability = capability_snapshot_for_profile("operator-enabled", true);
+    let catalog_root = resolve_published_preset_catalog_dir(&base_dir);
+    ensure_default_preset_catalog_in_dir(&base_dir).expect("default catalog should seed");
+    let legacy_dir = write_unsigned_bundle(&catalog_root, "preset_legacy", "2026.02.01");
+    let tampered_dir = write_unsigned_bundle(&catalog_root, "preset_tampered", "2026.02.01");
+    sign_preset_bundle(
+        &tampered_dir,
+        "preset_tampered",
+        "2026.02.01",
+        &PresetSigningKey {
+            key_id: "hq-2026".into(),
+            seed: HQ_SIGNING_SEED,
+        },
+        "2026-02-01T00:00:00Z",
+    )
+    .expect("bundle should be signed");
+    fs::write(tampered_dir.join("preview.svg"), "<svg/>").expect("preview should be writable");
+    assert!(load_published_preset_runtime_bundle(&legacy_dir).is_none());
+
+    let resign_input = || PresetBundleResignInputDto {
+        actor_id: "manager-kim".into(),
+        actor_label: "Kim Manager".into(),
+    };
+    let error = resign_unsigned_preset_bundles_in_dir(
+        &base_dir,
+        &capability_snapshot_for_profile("booth-only", false),
+        resign_input(),
+    )
+    .expect_err("booth runtime should not re-sign bundles");
+    assert_eq!(error.code, "capability-denied");
+
+    let resigned =
+        resign_unsigned_preset_bundles_in_dir(&base_dir, &operator_capability, resign_input())
+            .expect("unsigned bundles should be re-signed");
+    let signed: Vec<&str> = resigned
+        .signed_bundles
+        .iter()
+        .map(|bundle| bundle.preset_id.as_str())
+        .collect();
+    assert_eq!(signed, ["preset_legacy"]);
+    assert!(load_published_preset_runtime_bundle(&legacy_dir).is_some());
+    let finding = check_published_bundle_signature(&tampered_dir)
+        .expect_err("a broken signature should not be replaced");
+    assert_eq!(finding.rule_code, "bundle-signing-key-untrusted");
+    assert!(!catalog_root
+        .join("preset_soft-glow")
+        .join("2026.03.27")
+        .join(PRESET_BUNDLE_SIGNATURE_FILE_NAME)
+        .exists());
+
+    let repeated =
+        resign_unsigned_preset_bundles_in_dir(&base_dir, &operator_capability, resign_input())
+            .expect("repeat should succeed");
+    assert!(repeated.signed_bundles.is_empty());
+    assert_eq!(repeated.key_id, resigned.key_id);
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn key_rotation_revocation_and_imported_keys_control_bundle_trust() {
+    let base_dir = unique_test_root("keys");
+    let operator_capability = capability_snapshot_for_profile("operator-enabled", true);
+    let bundle_dir = publish_sample_preset(&base_dir, "2026.04.01");
+    let first_key_id = load_preset_signing_keys_in_dir(&base_dir, &operator_capability)
+        .expect("keys should load")
+        .active_key_id
+        .expect("publish should create an active key");
+
+    let rotated = generate_preset_signing_key_in_dir(
+        &base_dir,
+        &operator_capability,
+        PresetSigningKeyGenerateInputDto {
+            actor_id: "manager-kim".into(),
+            actor_label: "Kim Manager".into(),
+        },
+    )
+    .expect("rotation should succeed");
+    assert_eq!(rotated.keys.len(), 2);
+    assert_ne!(
+        rotated.active_key_id.as_deref(),
+        Some(first_key_id.as_str())
+    );
+    assert!(load_published_preset_runtime_bundle(&bundle_dir).is_some());
+
+    let revoked = revoke_preset_signing_key_in_dir(
+        &base_dir,
+        &operator_capability,
+        PresetSigningKeyRevokeInputDto {
+            key_id: first_key_id.clone(),
+            actor_id: "manager-kim".into(),
+            actor_label: "Kim Manager".into(),
+        },
+    )
+    .expect("revocation should succeed");
+    assert!(revoked
+        .keys
+        .iter()
+        .any(|key| key.key_id == first_key_id && key.revoked_at.is_some() && !key.active));
+    let finding =
+        check_published_bundle_signature(&bundle_dir).expect_err("revoked key should fail");
+    assert_eq!(finding.rule_code, "bundle-signing-key-untrusted");
+
+    let hq_key = PresetSigningKey {
+        key_id: "hq-2026".into(),
+        seed: HQ_SIGNING_SEED,
+    };
+    sign_preset_bundle(
+        &bundle_dir,
+        PRESET_ID,
+        "2026.04.01",
+        &hq_key,
+        "2026-04-02T00:00:00Z",
+    )
+    .expect("bundle should be re-signed");
+    assert!(check_published_bundle_signature(&bundle_dir).is_err());
+
+    let trusted = trust_preset_signing_key_in_dir(
+        &base_dir,
+        &operator_capability,
+        PresetSigningKeyTrustInputDto {
+            key_id: "hq-2026".into(),
+            public_key: encode_hex(&ed25519_public_key(&HQ_SIGNING_SEED)),
+            actor_id: "manager-kim".into(),
+            actor_label: "Kim Manager".into(),
+        },
+    )
+    .expect("imported key should be trusted");
+    assert!(trusted
+        .keys
+        .iter()
+        .any(|key| key.key_id == "hq-2026" && key.origin == "imported"));
+    assert!(load_published_preset_runtime_bundle(&bundle_dir).is_some());
+
+    let listed = serde_json::to_string(
+        &load_preset_signing_keys_in_dir(&base_dir, &operator_capability)
+            .expect("keys should load"),
+    )
+    .expect("keys should serialize");
+    assert!(!listed.contains("privateKey"));
+
+    let history = load_operator_audit_history_in_dir(
+        &base_dir,
+        &operator_capability,
+        OperatorAuditQueryFilterDto {
+            session_id: None,
+            event_categories: Vec::new(),
+            limit: Some(50),
+        },
+    )
+    .expect("audit history should load");
+    for event_type in [
+        "preset-signing-key-generated",
+        "preset-signing-key-revoked",
+        "preset-signing-key-trusted",
+    ] {
+        assert!(history
+            .events
+            .iter()
+            .any(|entry| entry.event_type == event_type));
+    }
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+fn publish_sample_preset(base_dir: &Path, published_version: &str) -> PathBuf {
+    let authoring_capability = capability_snapshot_for_profile("authoring-enabled", true);
+
+    create_draft_preset_in_dir(base_dir, &authoring_capability, sample_draft_payload())
+        .expect("draft creation should succeed");
+    scaffold_valid_draft_assets(base_dir);
+    let validation = validate_draft_preset_in_dir(
+        base_dir,
+        &authoring_capability,
+        ValidateDraftPresetInputDto {
+            preset_id: PRESET_ID.into(),
+        },
+    )
+    .expect("validation should succeed");
+    publish_validated_preset_in_dir(
+        base_dir,
+        &authoring_capability,
+        PublishValidatedPresetInputDto {
+            preset_id: PRESET_ID.into(),
+            draft_version: validation.draft.draft_version,
+            validation_checked_at: validation.report.checked_at.clone(),
+            expected_display_name: "Soft Glow Draft".into(),
+            published_version: published_version.into(),
+            actor_id: "manager-kim".into(),
+            actor_label: "Kim Manager".into(),
+            scope: "future-sessions-only".into(),
+            review_note: None,
+        },
+    )
+    .expect("publication should succeed");
+
+    let bundle_dir = resolve_published_preset_catalog_dir(base_dir)
+        .join(PRESET_ID)
+        .join(published_version);
+    assert!(bundle_dir.join("bundle.json").is_file());
+
+    bundle_dir
+}
+
+fn catalog_snapshot(published_version: &str) -> Vec<ActivePresetBinding> {
+    vec![ActivePresetBinding {
+        preset_id: PRESET_ID.into(),
+        published_version: published_version.into(),
+    }]
+}
+
+fn bundle_rejections(base_dir: &Path) -> Vec<String> {
+    let operator_capability = capability_snapshot_for_profile("operator-enabled", true);
+
+    load_operator_audit_history_in_dir(
+        base_dir,
+        &operator_capability,
+        OperatorAuditQueryFilterDto {
+            session_id: None,
+            event_categories: Vec::new(),
+            limit: Some(50),
+        },
+    )
+    .expect("audit history should load")
+    .events
+    .into_iter()
+    .filter(|entry| entry.event_type == "preset-bundle-rejected")
+    .filter_map(|entry| entry.reason_code)
+    .collect()
+}
+
+fn write_unsigned_bundle(catalog_root: &Path, preset_id: &str, published_version: &str) -> PathBuf {
+    let bundle_dir = catalog_root.join(preset_id).join(published_version);
+    fs::create_dir_all(&bundle_dir).expect("bundle directory should exist");
+    fs::write(bundle_dir.join("preview.jpg"), "preview").expect("preview should write");
+    fs::write(
+        bundle_dir.join("look.xmp"),
+        "<darktable><history><item operation=\"exposure\"></item></history></darktable>",
+    )
+    .expect("xmp should write");
+    let bundle = serde_json::json!({
+      "schemaVersion": "published-preset-bundle/v1",
+      "presetId": preset_id,
+      "displayName": "Legacy Look",
+      "publishedVersion": published_version,
+      "lifecycleStatus": "published",
+      "boothStatus": "booth-safe",
+      "darktableVersion": "5.4.1",
+      "xmpTemplatePath": "look.xmp",
+      "previewProfile": {
+        "profileId": "preview-standard",
+        "displayName": "Preview Standard",
+        "outputColorSpace": "sRGB"
+      },
+      "finalProfile": {
+        "profileId": "final-standard",
+        "displayName": "Final Standard",
+        "outputColorSpace": "sRGB"
+      },
+      "preview": {
+        "kind": "preview-tile",
+        "assetPath": "preview.jpg",
+        "altText": "Legacy Look preview",
+      }
+    });
+    fs::write(
+        bundle_dir.join("bundle.json"),
+        serde_json::to_vec_pretty(&bundle).expect("bundle should serialize"),
+    )
+    .expect("bundle should write");
+
+    bundle_dir
+}
+
+fn sample_draft_payload() -> DraftPresetEditPayloadDto {
+    DraftPresetEditPayloadDto {
+        preset_id: PRESET_ID.into(),
+        display_name: "Soft Glow Draft".into(),
+        lifecycle_state: "draft".into(),
+        darktable_version: "5.4.1".into(),
+        darktable_project_path: "darktable/soft-glow.dtpreset".into(),
+        xmp_template_path: "xmp/soft-glow.xmp".into(),
+        overlay_layout_path: None,
+        preview_profile: render_profile("preview-standard", "Preview Standard"),
+        final_profile: render_profile("final-standard", "Final Standard"),
+        noise_policy: DraftNoisePolicyDto {
+            policy_id: "balanced-noise".into(),
+            display_name: "Balanced Noise".into(),
+            reduction_mode: "balanced".into(),
+        },
+        preview: DraftPresetPreviewReferenceDto {
+            asset_path: "previews/soft-glow.jpg".into(),
+            alt_text: "Soft Glow draft portrait".into(),
+        },
+        sample_cut: DraftPresetPreviewReferenceDto {
+            asset_path: "samples/soft-glow-cut.jpg".into(),
+            alt_text: "Soft Glow sample cut".into(),
+        },
+        description: None,
+        notes: None,
+    }
+}
+
+fn render_profile(profile_id: &str, display_name: &str) -> DraftRenderProfileDto {
+    DraftRenderProfileDto {
+        profile_id: profile_id.into(),
+        display_name: display_name.into(),
+        output_color_space: "sRGB".into(),
+    }
+}
+
+fn scaffold_valid_draft_assets(base_dir: &Path) {
+    let draft_root = resolve_draft_authoring_root(base_dir).join(PRESET_ID);
+
+    fs::create_dir_all(draft_root.join("darktable")).expect("darktable directory should exist");
+    fs::create_dir_all(draft_root.join("xmp")).expect("xmp directory should exist");
+    fs::create_dir_all(draft_root.join("previews")).expect("preview directory should exist");
+    fs::create_dir_all(draft_root.join("samples")).expect("sample directory should exist");
+    fs::write(draft_root.join("darktable/soft-glow.dtpreset"), "project")
+        .expect("project should write");
+    fs::write(
+        draft_root.join("xmp/soft-glow.xmp"),
+        "<darktable><history><item operation=\"exposure\"></item></history></darktable>",
+    )
+    .expect("xmp should write");
+    fs::write(draft_root.join("previews/soft-glow.jpg"), "preview").expect("preview should write");
+    fs::write(draft_root.join("samples/soft-glow-cut.jpg"), "sample").expect("sample should write");
+}
@@ -0,0 +1,342 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    commands::runtime_commands::capability_snapshot_for_profile,
+    contracts::dto::{PrintJobInputDto, SessionStartInputDto},
+    diagnostics::load_operator_session_summary_in_dir,
+    print::{
+        load_print_templates_in_dir,
+        print_document::read_jpeg_dimensions,
+        print_queue::{resolve_print_spool_dir, PrintSpoolTicket},
+        refresh_print_jobs_in_dir, resolve_print_template_config_path, submit_print_job_in_dir,
+        PRINT_JOB_STATUS_FAILED, PRINT_JOB_STATUS_PRINTED, PRINT_JOB_STATUS_SPOOLED,
+    },
+    session::{
+        session_manifest::{
+            CaptureTimingMetrics, FinalCaptureAsset, FinalCaptureVariant, PreviewCaptureAsset,
+            RawCaptureAsset, SessionCaptureRecord, SessionManifest, SESSION_CAPTURE_SCHEMA_VERSION,
+        },
+        session_paths::SessionPaths,
+        session_repository::start_session_in_dir,
+    },
+};
+
+// SOI, a 32x16 three-component SOF0 frame header, EOI.
+const FRAME_JPEG: [u8; 23] = [
+    0xFF, 0xD8, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x00, 0x10, 0x00, 0x20, 0x03, 0x01, 0x22, 0x00, 0x02,
+    0x11, 0x01, 0x03, 0x11, 0x01, 0xFF, 0xD9,
+];
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-print-delivery-{test_name}-{stamp}"))
+}
+
+fn start_session_with_finals(base_dir: &Path, capture_count: usize) -> String {
+    let session = start_session_in_dir(
+        base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+    )
+    .expect("session should start");
+    let paths = SessionPaths::new(base_dir, &session.session_id);
+    let mut manifest = read_manifest(&paths);
+
+    fs::create_dir_all(&paths.renders_finals_dir).expect("finals dir should exist");
+    for index in 0..capture_count {
+        let capture_id = format!("capture_{index:03}");
+        let final_path = paths.renders_finals_dir.join(format!("{capture_id}.jpg"));
+        fs::write(&final_path, FRAME_JPEG).expect("final fixture should be written");
+        manifest.captures.push(final_ready_capture(
+            &manifest,
+            &capture_id,
+            final_path.to_string_lossy().into_owned(),
+        ));
+    }
+    fs::write(
+        &paths.manifest_path,
+        serde_json::to_vec_pretty(&manifest).expect("manifest should serialize"),
+    )
+    .expect("manifest should be written");
+
+    session.session_id
+}
+
+fn final_ready_capture(
+    manifest: &SessionManifest,
+    capture_id: &str,
+    final_path: String,
+) -> SessionCaptureRecord {
+    SessionCaptureRecord {
+        schema_version: SESSION_CAPTURE_SCHEMA_VERSION.into(),
+        session_id: manifest.session_id.clone(),
+        booth_alias: manifest.booth_alias.clone(),
+        active_preset_id: Some("preset_soft-glow".into()),
+        active_preset_version: "2026.03.27".into(),
+        active_preset_display_name: Some("Soft Glow".into()),
+        capture_id: capture_id.into(),
+        request_id: format!("request_{capture_id}"),
+        raw: RawCaptureAsset {
+            asset_path: format!("{capture_id}.cr3"),
+            persisted_at_ms: 100,
+        },
+        preview: PreviewCaptureAsset {
+            asset_path: None,
+            enqueued_at_ms: Some(100),
+            ready_at_ms: Some(200),
+        },
+        final_asset: FinalCaptureAsset {
+            asset_path: Some(final_path),
+            ready_at_ms: Some(300),
+            variants: Vec::new(),
+        },
+        render_status: "finalReady".into(),
+        render_backend: None,
+        post_end_state: "activeSession".into(),
+        timing: CaptureTimingMetrics {
+            capture_acknowledged_at_ms: 100,
+            preview_visible_at_ms: Some(200),
+            fast_preview_visible_at_ms: None,
+            xmp_preview_ready_at_ms: None,
+            capture_budget_ms: 1_000,
+            preview_budget_ms: 5_000,
+            preview_budget_state: "withinBudget".into(),
+        },
+        capture_program: None,
+    }
+}
+
+fn read_manifest(paths: &SessionPaths) -> SessionManifest {
+    serde_json::from_str(
+        &fs::read_to_string(&paths.manifest_path).expect("manifest should be readable"),
+    )
+    .expect("manifest should deserialize")
+}
+
+#[test]
+fn strip_print_job_renders_a_pdf_spools_it_and_tracks_completion() {
+    let base_dir = unique_test_root("strip");
+    let session_id = start_session_with_finals(&base_dir, 3);
+
+    let result = submit_print_job_in_dir(
+        &base_dir,
+        PrintJobInputDto {
+            session_id: session_id.clone(),
+            template_id: "strip".into(),
+            capture_ids: Vec::new(),
+            copies: 2,
+        },
+    )
+    .expect("print job should be submitted");
+
+    assert_eq!(result.job.status, PRINT_JOB_STATUS_SPOOLED);
+    assert_eq!(result.job.page_count, 1);
+    assert_eq!(result.job.capture_ids.len(), 3);
+    let document_path = PathBuf::from(
+        result
+            .job
+            .document_path
+            .clone()
+            .expect("document path should be recorded"),
+    );
+    let paths = SessionPaths::new(&base_dir, &session_id);
+    assert!(document_path.starts_with(&paths.renders_prints_dir));
+    let document = fs::read(&document_path).expect("print document should exist");
+    assert!(document.starts_with(b"%PDF-1.4"));
+    assert_eq!(
+        document
+            .windows(b"/Subtype /Image".len())
+            .filter(|window| *window == b"/Subtype /Image")
+            .count(),
+        3
+    );
+
+    let spool_dir = resolve_print_spool_dir(&base_dir);
+    let ticket: PrintSpoolTicket = serde_json::from_str(
+        &fs::read_to_string(spool_dir.join(format!("{}.json", result.job.job_id)))
+            .expect("spool ticket should exist"),
+    )
+    .expect("spool ticket should deserialize");
+    assert_eq!(ticket.copies, 2);
+    assert_eq!(ticket.template_id, "strip");
+    assert!(spool_dir.join(&ticket.document_file).is_file());
+
+    let operator = capability_snapshot_for_profile("operator-enabled", true);
+    let summary = load_operator_session_summary_in_dir(&base_dir, &operator)
+        .expect("operator summary should load");
+    assert_eq!(summary.print_boundary.status, "clear");
+    assert_eq!(summary.print_boundary.title, "인화 진행 중");
+
+    fs::write(spool_dir.join(format!("{}.done", result.job.job_id)), b"")
+        .expect("done marker should be written");
+    let jobs = refresh_print_jobs_in_dir(&base_dir, &session_id).expect("jobs should refresh");
+
+    assert_eq!(jobs.jobs[0].status, PRINT_JOB_STATUS_PRINTED);
+    assert_eq!(
+        read_manifest(&paths).print_jobs[0].status,
+        PRINT_JOB_STATUS_PRINTED
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn failed_print_jobs_block_the_operator_print_boundary() {
+    let base_dir = unique_test_root("failed");
+    let session_id = start_session_with_finals(&base_dir, 1);
+
+    let result = submit_print_job_in_dir(
+        &base_dir,
+        PrintJobInputDto {
+            session_id: session_id.clone(),
+            template_id: "4x6".into(),
+            capture_ids: vec!["capture_000".into()],
+            copies: 1,
+        },
+    )
+    .expect("print job should be submitted");
+    fs::write(
+        resolve_print_spool_dir(&base_dir).join(format!("{}.failed", result.job.job_id)),
+        "paper-out",
+    )
+    .expect("failed marker should be written");
+
+    let jobs = refresh_print_jobs_in_dir(&base_dir, &session_id).expect("jobs should refresh");
+    assert_eq!(jobs.jobs[0].status, PRINT_JOB_STATUS_FAILED);
+    assert_eq!(jobs.jobs[0].detail_code.as_deref(), Some("paper-out"));
+
+    let summary = load_operator_session_summary_in_dir(
+        &base_dir,
+        &capability_snapshot_for_profile("operator-enabled", true),
+    )
+    .expect("operator summary should load");
+    assert_eq!(summary.print_boundary.status, "blocked");
+
+    let error = submit_print_job_in_dir(
+        &base_dir,
+        PrintJobInputDto {
+            session_id,
+            template_id: "4x6".into(),
+            capture_ids: vec!["capture_missing".into()],
+            copies: 1,
+        },
+    )
+    .expect_err("unknown captures should be rejected");
+    assert_eq!(error.code, "validation-error");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn print_jobs_prefer_a_jpeg_print_variant_over_the_canonical_final() {
+    let base_dir = unique_test_root("variant");
+    let session_id = start_session_with_finals(&base_dir, 1);
+    let paths = SessionPaths::new(&base_dir, &session_id);
+    let mut manifest = read_manifest(&paths);
+    let print_variant_path = paths.renders_finals_dir.join("capture_000.print.jpg");
+    let mut square_jpeg = FRAME_JPEG;
+    square_jpeg[9..11].copy_from_slice(&[0x00, 0x10]);
+    fs::write(&print_variant_path, square_jpeg).expect("print variant should be written");
+    let variant = |variant_id: &str, asset_path: &Path, format: &str| FinalCaptureVariant {
+        variant_id: variant_id.into(),
+        asset_path: asset_path.to_string_lossy().into_owned(),
+        format: format.into(),
+        color_space: "sRGB".into(),
+        purposes: vec!["print".into()],
+        ready_at_ms: 310,
+    };
+    manifest.captures[0].final_asset.variants = vec![
+        variant(
+            "print-tiff",
+            &paths.renders_finals_dir.join("capture_000.print-tiff.tif"),
+            "tiff",
+        ),
+        variant("print", &print_variant_path, "jpeg"),
+    ];
+    fs::write(
+        &paths.manifest_path,
+        serde_json::to_vec_pretty(&manifest).expect("manifest should serialize"),
+    )
+    .expect("manifest should be written");
+
+    let result = submit_print_job_in_dir(
+        &base_dir,
+        PrintJobInputDto {
+            session_id,
+            template_id: "4x6".into(),
+            capture_ids: Vec::new(),
+            copies: 1,
+        },
+    )
+    .expect("print job should be submitted");
+
+    let document = fs::read(
+        result
+            .job
+            .document_path
+            .expect("document path should exist"),
+    )
+    .expect("print document should exist");
+    let document = String::from_utf8_lossy(&document);
+    assert!(document.contains("/Width 16 /Height 16"));
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn branch_templates_override_builtins_and_invalid_entries_are_skipped() {
+    let base_dir = unique_test_root("templates");
+    let config_path = resolve_print_template_config_path(&base_dir);
+    fs::create_dir_all(config_path.parent().expect("config dir should exist"))
+        .expect("config dir should be created");
+    fs::write(
+        &config_path,
+        serde_json::json!({
+            "schemaVersion": "print-template-config/v1",
+            "templates": [
+                {
+                    "templateId": "4x6",
+                    "displayName": "4x6 여백",
+                    "paperWidthMm": 152.4,
+                    "paperHeightMm": 101.6,
+                    "slots": [{ "xMm": 5.0, "yMm": 5.0, "widthMm": 142.4, "heightMm": 91.6 }]
+                },
+                {
+                    "templateId": "oversized",
+                    "displayName": "잘못된 템플릿",
+                    "paperWidthMm": 50.0,
+                    "paperHeightMm": 50.0,
+                    "slots": [{ "xMm": 0.0, "yMm": 0.0, "widthMm": 80.0, "heightMm": 50.0 }]
+                }
+            ]
+        })
+        .to_string(),
+    )
+    .expect("template config should be written");
+
+    let templates = load_print_templates_in_dir(&base_dir).expect("templates should load");
+    let ids: Vec<_> = templates
+        .iter()
+        .map(|template| template.template_id.as_str())
+        .collect();
+
+    assert_eq!(ids, vec!["4x6", "strip", "2-up"]);
+    assert_eq!(templates[0].display_name, "4x6 여백");
+    assert_eq!(
+        read_jpeg_dimensions(&FRAME_JPEG).map(|dimensions| (dimensions.width, dimensions.height)),
+        Some((32, 16))
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
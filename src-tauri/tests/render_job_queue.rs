@@ -0,0 +1,182 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::render::{
+    render_job_queue::{
+        claim_next_render_job_in_dir, claim_render_job_in_dir, enqueue_render_job_in_dir,
+        list_render_jobs_in_dir, resolve_render_job_queue_path, resume_render_jobs_in_dir,
+        settle_render_job_in_dir, RenderJobKind, RenderJobRecord, RenderJobRequest,
+        RenderJobSettlement, RenderJobStatus, MAX_RENDER_JOB_ATTEMPTS,
+    },
+    RenderWorkerError,
+};
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-render-queue-{test_name}-{stamp}"))
+}
+
+fn enqueue(base_dir: &Path, kind: RenderJobKind, capture_id: &str, now_ms: u64) -> RenderJobRecord {
+    enqueue_render_job_in_dir(
+        base_dir,
+        RenderJobRequest {
+            kind,
+            session_id: "session_queue".into(),
+            capture_id: capture_id.into(),
+            request_id: format!("request_{capture_id}"),
+            preset_id: None,
+            preset_version: None,
+            source_asset_path: None,
+        },
+        now_ms,
+    )
+    .expect("job should be enqueued")
+}
+
+fn render_failure() -> RenderWorkerError {
+    RenderWorkerError {
+        reason_code: "render-process-failed",
+        customer_message: "결과를 준비하지 못했어요.".into(),
+        operator_detail: "exit code 1".into(),
+    }
+}
+
+#[test]
+fn jobs_are_claimed_by_priority_and_deduplicated_per_capture() {
+    let base_dir = unique_test_root("priority");
+
+    let final_job = enqueue(&base_dir, RenderJobKind::Final, "capture_a", 1_000);
+    enqueue(
+        &base_dir,
+        RenderJobKind::PreviewRefinement,
+        "capture_a",
+        1_001,
+    );
+    enqueue(&base_dir, RenderJobKind::FastPreview, "capture_b", 1_002);
+    let duplicate = enqueue(&base_dir, RenderJobKind::Final, "capture_a", 1_003);
+
+    assert_eq!(duplicate.job_id, final_job.job_id);
+    assert_eq!(
+        list_render_jobs_in_dir(&base_dir)
+            .expect("jobs should list")
+            .len(),
+        3
+    );
+
+    let claimed_kinds = (0..3)
+        .map(|_| {
+            claim_next_render_job_in_dir(&base_dir, 2_000)
+                .expect("claim should succeed")
+                .expect("a job should be due")
+                .kind
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        claimed_kinds,
+        vec![
+            RenderJobKind::FastPreview,
+            RenderJobKind::PreviewRefinement,
+            RenderJobKind::Final
+        ]
+    );
+    assert!(claim_next_render_job_in_dir(&base_dir, 2_000)
+        .expect("claim should succeed")
+        .is_none());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn failed_jobs_back_off_and_are_dropped_after_the_last_attempt() {
+    let base_dir = unique_test_root("backoff");
+    let job = enqueue(&base_dir, RenderJobKind::Final, "capture_a", 1_000);
+    let error = render_failure();
+    let mut now_ms = 1_000;
+    let mut delays = Vec::new();
+
+    for attempt in 1..=MAX_RENDER_JOB_ATTEMPTS {
+        let claimed = claim_render_job_in_dir(&base_dir, &job.job_id, now_ms)
+            .expect("claim should succeed")
+            .expect("job should be due");
+        assert_eq!(claimed.attempts, attempt);
+        assert!(claim_render_job_in_dir(&base_dir, &job.job_id, now_ms)
+            .expect("claim should succeed")
+            .is_none());
+
+        match settle_render_job_in_dir(&base_dir, &job.job_id, Err(&error), now_ms)
+            .expect("settle should succeed")
+        {
+            RenderJobSettlement::RetryScheduled { next_attempt_at_ms } => {
+                assert!(
+                    claim_next_render_job_in_dir(&base_dir, next_attempt_at_ms - 1)
+                        .expect("claim should succeed")
+                        .is_none()
+                );
+                delays.push(next_attempt_at_ms - now_ms);
+                now_ms = next_attempt_at_ms;
+            }
+            RenderJobSettlement::Exhausted => {
+                assert_eq!(attempt, MAX_RENDER_JOB_ATTEMPTS);
+            }
+            RenderJobSettlement::Completed => panic!("a failed job should not complete"),
+        }
+    }
+
+    assert_eq!(delays, vec![500, 1_000]);
+    assert!(list_render_jobs_in_dir(&base_dir)
+        .expect("jobs should list")
+        .is_empty());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn running_jobs_left_by_a_crash_are_resumed_from_disk() {
+    let base_dir = unique_test_root("resume");
+    enqueue(
+        &base_dir,
+        RenderJobKind::PreviewRefinement,
+        "capture_a",
+        1_000,
+    );
+    let interrupted = claim_next_render_job_in_dir(&base_dir, 1_000)
+        .expect("claim should succeed")
+        .expect("job should be due");
+    assert_eq!(interrupted.status, RenderJobStatus::Running);
+    assert!(claim_next_render_job_in_dir(&base_dir, 1_000)
+        .expect("claim should succeed")
+        .is_none());
+
+    let pending = resume_render_jobs_in_dir(&base_dir, 5_000).expect("resume should succeed");
+    assert_eq!(pending, 1);
+    let resumed = claim_next_render_job_in_dir(&base_dir, 5_000)
+        .expect("claim should succeed")
+        .expect("interrupted job should be claimable again");
+    assert_eq!(resumed.job_id, interrupted.job_id);
+    assert_eq!(resumed.attempts, 2);
+
+    assert_eq!(
+        settle_render_job_in_dir(&base_dir, &resumed.job_id, Ok(()), 5_100)
+            .expect("settle should succeed"),
+        RenderJobSettlement::Completed
+    );
+    assert!(list_render_jobs_in_dir(&base_dir)
+        .expect("jobs should list")
+        .is_empty());
+
+    fs::write(resolve_render_job_queue_path(&base_dir), b"{ torn")
+        .expect("queue file should be writable");
+    assert_eq!(
+        resume_render_jobs_in_dir(&base_dir, 6_000).expect("a torn queue should not block startup"),
+        0
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
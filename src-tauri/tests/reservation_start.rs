@@ -0,0 +1,345 @@
+use std::{
+    fs,
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    contracts::dto::{HostErrorEnvelope, PresetSelectionInputDto, ReservationLookupInputDto},
+    reservation::{
+        lookup_reservation_with_source,
+        reservation_source::{
+            resolve_reservation_dir, HttpReservationSource, LocalFileReservationSource,
+            ReservationRecord, ReservationSource,
+        },
+        resolve_reservation_ledger_path, start_reserved_session_with_source,
+    },
+    session::{
+        session_manifest::current_timestamp, session_repository::select_active_preset_in_dir,
+    },
+};
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-reservation-{test_name}-{stamp}"))
+}
+
+fn timestamp_from_now(offset_seconds: i64) -> String {
+    let now = SystemTime::now();
+    let shifted = if offset_seconds >= 0 {
+        now + Duration::from_secs(offset_seconds as u64)
+    } else {
+        now - Duration::from_secs(offset_seconds.unsigned_abs())
+    };
+
+    current_timestamp(shifted).expect("timestamp should format")
+}
+
+fn lookup(reservation_id: &str) -> ReservationLookupInputDto {
+    ReservationLookupInputDto {
+        reservation_id: reservation_id.into(),
+    }
+}
+
+#[test]
+fn local_json_reservation_prefills_the_session_and_can_only_be_used_once() {
+    let base_dir = unique_test_root("json");
+    let reservation_dir = resolve_reservation_dir(&base_dir);
+    fs::create_dir_all(&reservation_dir).expect("reservation dir should be created");
+    fs::write(
+        reservation_dir.join("reservations.json"),
+        serde_json::json!({
+            "reservations": [
+                {
+                    "reservationId": "R-100",
+                    "customerName": " Kim  Noah ",
+                    "phoneLastFour": "4821",
+                    "allowedPresetIds": ["preset_soft-glow"],
+                    "expiresAt": timestamp_from_now(3_600)
+                },
+                {
+                    "reservationId": "R-101",
+                    "customerName": "Lee",
+                    "phoneLastFour": "1234",
+                    "expiresAt": timestamp_from_now(-60)
+                },
+                {
+                    "reservationId": "R-102",
+                    "customerName": "Park",
+                    "phoneLastFour": "5678",
+                    "expiresAt": timestamp_from_now(3_600),
+                    "status": "used"
+                }
+            ]
+        })
+        .to_string(),
+    )
+    .expect("reservations should be written");
+    let source = LocalFileReservationSource::new(&reservation_dir);
+
+    let summary =
+        lookup_reservation_with_source(&base_dir, lookup(" R-100 "), &source, SystemTime::now())
+            .expect("reservation should be found");
+    assert_eq!(summary.customer_name, "Kim Noah");
+    assert_eq!(summary.package.package_id, "standard");
+
+    let result =
+        start_reserved_session_with_source(&base_dir, lookup("R-100"), &source, SystemTime::now())
+            .expect("reserved session should start");
+    assert_eq!(result.booth_alias, "Kim Noah 4821");
+    let reservation = result
+        .manifest
+        .reservation
+        .clone()
+        .expect("reservation should be recorded");
+    assert_eq!(reservation.reservation_id, "R-100");
+    assert_eq!(reservation.source_id, "local-file");
+    assert!(resolve_reservation_ledger_path(&base_dir).is_file());
+
+    let reused =
+        start_reserved_session_with_source(&base_dir, lookup("R-100"), &source, SystemTime::now())
+            .expect_err("a redeemed reservation should be rejected");
+    assert_eq!(reused.code, "validation-error");
+    assert_eq!(reused.message, "이미 사용한 예약이에요.");
+
+    for reservation_id in ["R-101", "R-102", "R-999", "../R-100"] {
+        let error = lookup_reservation_with_source(
+            &base_dir,
+            lookup(reservation_id),
+            &source,
+            SystemTime::now(),
+        )
+        .expect_err("unusable reservations should be rejected");
+        assert_eq!(error.code, "validation-error", "{reservation_id}");
+    }
+
+    let denied = select_active_preset_in_dir(
+        &base_dir,
+        PresetSelectionInputDto {
+            session_id: result.session_id,
+            preset_id: "preset_mono-pop".into(),
+            published_version: "2026.03.20".into(),
+        },
+    )
+    .expect_err("presets outside the reservation should be rejected");
+    assert_eq!(denied.code, "preset-not-available");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+/// Serves one booking from memory and records redeem/release calls.
+struct RecordingReservationSource {
+    record: ReservationRecord,
+    calls: Mutex<Vec<String>>,
+}
+
+impl ReservationSource for RecordingReservationSource {
+    fn source_id(&self) -> &'static str {
+        "recording"
+    }
+
+    fn lookup(&self, reservation_id: &str) -> Result<Option<ReservationRecord>, HostErrorEnvelope> {
+        Ok((reservation_id == self.record.reservation_id).then(|| self.record.clone()))
+    }
+
+    fn mark_redeemed(
+        &self,
+        reservation_id: &str,
+        _session_id: &str,
+    ) -> Result<(), HostErrorEnvelope> {
+        self.calls
+            .lock()
+            .expect("calls should lock")
+            .push(format!("redeem:{reservation_id}"));
+        Ok(())
+    }
+
+    fn release_redemption(
+        &self,
+        reservation_id: &str,
+        _session_id: &str,
+    ) -> Result<(), HostErrorEnvelope> {
+        self.calls
+            .lock()
+            .expect("calls should lock")
+            .push(format!("release:{reservation_id}"));
+        Ok(())
+    }
+}
+
+#[test]
+fn failed_session_start_releases_the_reservation_for_a_retry() {
+    let base_dir = unique_test_root("release");
+    let source = RecordingReservationSource {
+        record: ReservationRecord {
+            reservation_id: "R-500".into(),
+            customer_name: "Jung".into(),
+            phone_last_four: "7788".into(),
+            package_id: None,
+            allowed_preset_ids: Vec::new(),
+            valid_from: None,
+            expires_at: timestamp_from_now(3_600),
+            status: "booked".into(),
+        },
+        calls: Mutex::new(Vec::new()),
+    };
+    fs::create_dir_all(&base_dir).expect("base dir should be created");
+    fs::write(base_dir.join("sessions"), "not a directory")
+        .expect("blocking file should be written");
+
+    start_reserved_session_with_source(&base_dir, lookup("R-500"), &source, SystemTime::now())
+        .expect_err("session creation should fail while sessions/ is a file");
+    assert_eq!(
+        *source.calls.lock().expect("calls should lock"),
+        vec!["redeem:R-500".to_string(), "release:R-500".to_string()]
+    );
+    lookup_reservation_with_source(&base_dir, lookup("R-500"), &source, SystemTime::now())
+        .expect("a failed start should leave the reservation usable");
+
+    fs::remove_file(base_dir.join("sessions")).expect("blocking file should be removed");
+    let result =
+        start_reserved_session_with_source(&base_dir, lookup("R-500"), &source, SystemTime::now())
+            .expect("retry should start the session");
+    assert_eq!(result.manifest.customer.name, "Jung");
+    let reused =
+        start_reserved_session_with_source(&base_dir, lookup("R-500"), &source, SystemTime::now())
+            .expect_err("a redeemed reservation should be rejected");
+    assert_eq!(reused.message, "이미 사용한 예약이에요.");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn local_csv_reservations_are_read_with_header_columns() {
+    let base_dir = unique_test_root("csv");
+    let reservation_dir = resolve_reservation_dir(&base_dir);
+    fs::create_dir_all(&reservation_dir).expect("reservation dir should be created");
+    fs::write(
+        reservation_dir.join("reservations.csv"),
+        format!(
+            "reservationId,phoneLastFour,customerName,allowedPresetIds,expiresAt,status\n\
+             R-200,4821,Kim,preset_soft-glow;preset_mono-pop,{},booked\n\
+             R-201,1234,Lee,,{},cancelled\n",
+            timestamp_from_now(3_600),
+            timestamp_from_now(3_600)
+        ),
+    )
+    .expect("reservations should be written");
+    let source = LocalFileReservationSource::new(&reservation_dir);
+
+    let summary =
+        lookup_reservation_with_source(&base_dir, lookup("R-200"), &source, SystemTime::now())
+            .expect("csv reservation should be found");
+    assert_eq!(summary.phone_last_four, "4821");
+    assert_eq!(
+        summary.allowed_preset_ids,
+        vec!["preset_soft-glow", "preset_mono-pop"]
+    );
+
+    let cancelled =
+        lookup_reservation_with_source(&base_dir, lookup("R-201"), &source, SystemTime::now())
+            .expect_err("cancelled reservations should be rejected");
+    assert_eq!(cancelled.message, "취소된 예약이에요.");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn http_reservation_source_looks_up_and_redeems_against_a_local_stub() {
+    let base_dir = unique_test_root("http");
+    let listener = TcpListener::bind("127.0.0.1:0").expect("stub should bind");
+    let address = listener.local_addr().expect("stub address should resolve");
+    let requests = Arc::new(Mutex::new(Vec::<String>::new()));
+    let recorded = Arc::clone(&requests);
+    let expires_at = timestamp_from_now(3_600);
+
+    let stub = thread::spawn(move || {
+        for _ in 0..3 {
+            let (mut stream, _) = listener.accept().expect("stub should accept");
+            let request = read_stub_request(&mut stream);
+            let request_line = request.lines().next().unwrap_or_default().to_string();
+            let (status, body) = if request_line.starts_with("GET /api/reservations/R-300 ") {
+                (
+                    "200 OK",
+                    serde_json::json!({
+                        "reservationId": "R-300",
+                        "customerName": "Choi",
+                        "phoneLastFour": "9012",
+                        "expiresAt": expires_at,
+                    })
+                    .to_string(),
+                )
+            } else if request_line.starts_with("POST /api/reservations/R-300/redeem ") {
+                ("204 No Content", String::new())
+            } else {
+                ("404 Not Found", String::new())
+            };
+            recorded.lock().expect("requests should lock").push(request);
+            write!(
+                stream,
+                "HTTP/1.0 {status}\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            )
+            .expect("stub should respond");
+        }
+    });
+    let source = HttpReservationSource::new(&format!("http://{address}/api/"))
+        .expect("source url should parse");
+
+    let missing =
+        lookup_reservation_with_source(&base_dir, lookup("R-404"), &source, SystemTime::now())
+            .expect_err("unknown reservations should be rejected");
+    assert_eq!(missing.code, "validation-error");
+
+    let result =
+        start_reserved_session_with_source(&base_dir, lookup("R-300"), &source, SystemTime::now())
+            .expect("reserved session should start");
+    stub.join().expect("stub should finish");
+
+    assert_eq!(result.manifest.customer.name, "Choi");
+    assert_eq!(
+        result
+            .manifest
+            .reservation
+            .as_ref()
+            .map(|reservation| reservation.source_id.as_str()),
+        Some("http")
+    );
+    let requests = requests.lock().expect("requests should lock");
+    assert!(requests[2].contains(&result.session_id));
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+/// Reads the request head and, when present, the `Content-Length` body, which
+/// clients may send in a separate write.
+fn read_stub_request(stream: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buffer = [0u8; 4096];
+    loop {
+        let read = stream.read(&mut buffer).expect("stub should read");
+        request.extend_from_slice(&buffer[..read]);
+        let text = String::from_utf8_lossy(&request).into_owned();
+        let Some((head, body)) = text.split_once("\r\n\r\n") else {
+            assert!(read > 0, "request head should arrive");
+            continue;
+        };
+        let content_length = head
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+            .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+        if body.len() >= content_length || read == 0 {
+            return text;
+        }
+    }
+}
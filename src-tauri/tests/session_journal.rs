@@ -0,0 +1,291 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Once,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    capture::{
+        helper_simulator::start_simulated_helper,
+        normalized_state::{get_capture_readiness_in_dir, request_capture_in_dir},
+    },
+    contracts::dto::{
+        CaptureReadinessInputDto, CaptureRequestInputDto, PresetSelectionInputDto,
+        SessionStartInputDto,
+    },
+    preset::default_catalog::ensure_default_preset_catalog_in_dir,
+    session::{
+        session_journal::{
+            read_session_journal_in_dir, rebuild_session_manifest_from_journal_in_dir,
+            replay_session_journal, session_journal_path, SESSION_JOURNAL_ENTRY_SCHEMA_VERSION,
+            SESSION_SNAPSHOT_INTERVAL,
+        },
+        session_manifest::SessionManifest,
+        session_paths::SessionPaths,
+        session_repository::{
+            read_session_manifest_in_dir, select_active_preset_in_dir, start_session_in_dir,
+        },
+    },
+};
+
+static FAKE_DARKTABLE_SETUP: Once = Once::new();
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    FAKE_DARKTABLE_SETUP.call_once(|| {
+        let script_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("support")
+            .join("fake-darktable-cli.cmd");
+        std::env::set_var("BOOTHY_DARKTABLE_CLI_BIN", script_path);
+    });
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-session-journal-{test_name}-{stamp}"))
+}
+
+fn start_session(base_dir: &Path) -> String {
+    ensure_default_preset_catalog_in_dir(base_dir).expect("default catalog should seed");
+    start_session_in_dir(
+        base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+    )
+    .expect("session should be created")
+    .session_id
+}
+
+fn select_preset(base_dir: &Path, session_id: &str) {
+    select_active_preset_in_dir(
+        base_dir,
+        PresetSelectionInputDto {
+            session_id: session_id.into(),
+            preset_id: "preset_soft-glow".into(),
+            published_version: "2026.03.27".into(),
+        },
+    )
+    .expect("preset should become active");
+}
+
+fn capture_once(base_dir: &Path, session_id: &str) -> String {
+    request_capture_in_dir(
+        base_dir,
+        CaptureRequestInputDto {
+            session_id: session_id.into(),
+            request_id: None,
+        },
+    )
+    .expect("capture should save")
+    .capture
+    .capture_id
+}
+
+fn manifest_path(base_dir: &Path, session_id: &str) -> PathBuf {
+    SessionPaths::new(base_dir, session_id).manifest_path
+}
+
+fn read_manifest(base_dir: &Path, session_id: &str) -> SessionManifest {
+    read_session_manifest_in_dir(base_dir, session_id).expect("manifest should be readable")
+}
+
+fn read_snapshot(base_dir: &Path, session_id: &str) -> serde_json::Value {
+    serde_json::from_str(
+        &fs::read_to_string(manifest_path(base_dir, session_id))
+            .expect("manifest should be readable"),
+    )
+    .expect("manifest should deserialize")
+}
+
+fn capture_ids(manifest: &SessionManifest) -> Vec<String> {
+    manifest
+        .captures
+        .iter()
+        .map(|capture| capture.capture_id.clone())
+        .collect()
+}
+
+fn load_session(base_dir: &Path, session_id: &str) {
+    get_capture_readiness_in_dir(
+        base_dir,
+        CaptureReadinessInputDto {
+            session_id: session_id.into(),
+        },
+    )
+    .expect("session should load");
+}
+
+#[test]
+fn replaying_the_journal_reproduces_the_manifest_after_captures() {
+    let base_dir = unique_test_root("replay");
+    let session_id = start_session(&base_dir);
+    select_preset(&base_dir, &session_id);
+    let _helper = start_simulated_helper(&base_dir, &session_id).expect("simulator should start");
+    let first_capture_id = capture_once(&base_dir, &session_id);
+    let second_capture_id = capture_once(&base_dir, &session_id);
+
+    let entries =
+        read_session_journal_in_dir(&base_dir, &session_id).expect("journal should be readable");
+    let events: Vec<&str> = entries.iter().map(|entry| entry.event.name()).collect();
+    assert_eq!(
+        events[..3],
+        ["session-started", "manifest-updated", "preset-selected"]
+    );
+    assert_eq!(
+        events
+            .iter()
+            .filter(|event| **event == "capture-saved")
+            .count(),
+        2
+    );
+    for (index, entry) in entries.iter().enumerate() {
+        assert_eq!(entry.schema_version, SESSION_JOURNAL_ENTRY_SCHEMA_VERSION);
+        assert_eq!(entry.session_id, session_id);
+        assert_eq!(entry.sequence, index as u64 + 1);
+    }
+
+    let manifest = read_manifest(&base_dir, &session_id);
+    let replayed = replay_session_journal(&entries).expect("journal should replay");
+    assert_eq!(
+        capture_ids(&replayed),
+        vec![first_capture_id, second_capture_id]
+    );
+    assert_eq!(capture_ids(&replayed), capture_ids(&manifest));
+    for (replayed_capture, capture) in replayed.captures.iter().zip(&manifest.captures) {
+        assert_eq!(replayed_capture.render_status, capture.render_status);
+        assert_eq!(replayed_capture.post_end_state, capture.post_end_state);
+    }
+    assert_eq!(replayed.active_preset, manifest.active_preset);
+    assert_eq!(replayed.lifecycle.stage, manifest.lifecycle.stage);
+    assert_eq!(
+        replayed
+            .timing
+            .as_ref()
+            .map(|timing| timing.adjusted_end_at.clone()),
+        manifest
+            .timing
+            .as_ref()
+            .map(|timing| timing.adjusted_end_at.clone())
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn a_lost_or_torn_manifest_is_rebuilt_from_the_journal() {
+    let base_dir = unique_test_root("rebuild");
+    let session_id = start_session(&base_dir);
+    select_preset(&base_dir, &session_id);
+    let _helper = start_simulated_helper(&base_dir, &session_id).expect("simulator should start");
+    capture_once(&base_dir, &session_id);
+    let expected = read_manifest(&base_dir, &session_id);
+    let path = manifest_path(&base_dir, &session_id);
+
+    fs::remove_file(&path).expect("manifest should be removable");
+    load_session(&base_dir, &session_id);
+    let rebuilt = read_manifest(&base_dir, &session_id);
+    assert_eq!(capture_ids(&rebuilt), capture_ids(&expected));
+    assert_eq!(rebuilt.active_preset, expected.active_preset);
+
+    let torn = b"{\"schemaVersion\": \"session-manifest/v2\", \"sess";
+    fs::write(&path, torn).expect("torn manifest should be writable");
+    load_session(&base_dir, &session_id);
+    assert_eq!(
+        capture_ids(&read_manifest(&base_dir, &session_id)),
+        capture_ids(&expected)
+    );
+    // Reads leave the file alone; the next locked write replaces it.
+    assert_eq!(fs::read(&path).expect("manifest should exist"), torn);
+    capture_once(&base_dir, &session_id);
+    let snapshot = read_snapshot(&base_dir, &session_id);
+    assert_eq!(
+        snapshot["captures"].as_array().map(Vec::len),
+        Some(expected.captures.len() + 1)
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn a_torn_last_line_is_skipped_and_older_sessions_are_seeded_with_a_snapshot() {
+    let base_dir = unique_test_root("torn");
+    let session_id = start_session(&base_dir);
+    let journal_path = session_journal_path(&manifest_path(&base_dir, &session_id));
+
+    // Sessions created before the journal existed have no start entry.
+    fs::remove_file(&journal_path).expect("journal should be removable");
+    select_preset(&base_dir, &session_id);
+    let entries =
+        read_session_journal_in_dir(&base_dir, &session_id).expect("journal should be readable");
+    let events: Vec<&str> = entries.iter().map(|entry| entry.event.name()).collect();
+    assert_eq!(
+        events,
+        ["manifest-snapshot", "manifest-updated", "preset-selected"]
+    );
+
+    OpenOptions::new()
+        .append(true)
+        .open(&journal_path)
+        .and_then(|mut file| {
+            file.write_all(b"{\"schemaVersion\":\"session-journal-entry/v1\",\"seq")
+        })
+        .expect("torn line should be appendable");
+    let rebuilt = rebuild_session_manifest_from_journal_in_dir(&base_dir, &session_id)
+        .expect("journal should still replay");
+    assert_eq!(
+        rebuilt.active_preset,
+        read_manifest(&base_dir, &session_id).active_preset
+    );
+    assert_eq!(
+        read_session_journal_in_dir(&base_dir, &session_id)
+            .expect("journal should be readable")
+            .len(),
+        3
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn capture_events_stay_in_the_journal_until_a_snapshot_is_due() {
+    let base_dir = unique_test_root("deferred");
+    let session_id = start_session(&base_dir);
+    select_preset(&base_dir, &session_id);
+    let _helper = start_simulated_helper(&base_dir, &session_id).expect("simulator should start");
+    let mut capture_ids_so_far = Vec::new();
+    let mut saw_deferred_entries = false;
+
+    for _ in 0..6 {
+        capture_ids_so_far.push(capture_once(&base_dir, &session_id));
+
+        let entries = read_session_journal_in_dir(&base_dir, &session_id)
+            .expect("journal should be readable");
+        let cursor_sequence = read_snapshot(&base_dir, &session_id)["journalCursor"]["sequence"]
+            .as_u64()
+            .expect("snapshot should record its journal cursor");
+        let pending: Vec<&str> = entries
+            .iter()
+            .filter(|entry| entry.sequence > cursor_sequence)
+            .map(|entry| entry.event.name())
+            .collect();
+        assert!(pending.len() <= SESSION_SNAPSHOT_INTERVAL as usize);
+        assert!(pending
+            .iter()
+            .all(|event| matches!(*event, "capture-saved" | "preview-ready" | "final-ready")));
+        saw_deferred_entries |= !pending.is_empty();
+
+        assert_eq!(
+            capture_ids(&read_manifest(&base_dir, &session_id)),
+            capture_ids_so_far
+        );
+    }
+    assert!(saw_deferred_entries);
+
+    let _ = fs::remove_dir_all(base_dir);
+}
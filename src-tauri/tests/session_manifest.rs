@@ -1,3 +1,5 @@
+mod support;
+
 use std::{
     fs,
     path::PathBuf,
@@ -5,20 +7,26 @@ use std::{
 };
 
 use boothy_lib::{
+    branch_config::session_packages::{
+        load_session_packages_in_dir, resolve_session_package_config_path,
+        DEFAULT_SESSION_PACKAGE_ID,
+    },
     contracts::dto::{LoadPresetCatalogInputDto, PresetSelectionInputDto, SessionStartInputDto},
     preset::{
         preset_bundle::load_published_preset_runtime_bundle,
         preset_catalog::{load_preset_catalog_in_dir, resolve_published_preset_catalog_dir},
     },
     session::{
+        manifest_migration::migrate_session_manifest_value,
         session_manifest::{
-            build_session_manifest_at, current_timestamp, normalize_legacy_manifest,
-            rfc3339_to_unix_seconds, SessionManifest, SESSION_MANIFEST_SCHEMA_VERSION,
+            build_session_manifest_at, current_timestamp, rfc3339_to_unix_seconds, SessionManifest,
+            SESSION_MANIFEST_SCHEMA_VERSION,
         },
         session_paths::SessionPaths,
         session_repository::{select_active_preset_in_dir, start_session_in_dir},
     },
 };
+use support::sign_fixture_bundle;
 
 fn unique_test_root(test_name: &str) -> PathBuf {
     let stamp = SystemTime::now()
@@ -37,6 +45,7 @@ fn creates_session_root_and_manifest_for_valid_input() {
         SessionStartInputDto {
             name: " Kim  Noah ".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("valid input should create a session");
@@ -75,6 +84,7 @@ fn rejects_invalid_input_without_creating_durable_session_artifacts() {
         SessionStartInputDto {
             name: "   ".into(),
             phone_last_four: "12a4".into(),
+            package_id: None,
         },
     );
 
@@ -95,6 +105,7 @@ fn rejects_phone_suffix_with_surrounding_whitespace() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: " 4821 ".into(),
+            package_id: None,
         },
     );
 
@@ -121,6 +132,7 @@ fn fails_manifest_build_when_system_time_precedes_unix_epoch() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
         before_epoch,
     )
@@ -140,6 +152,108 @@ fn parses_rfc3339_timestamps_with_explicit_utc_offsets() {
     assert_eq!(from_offset, from_z);
 }
 
+#[test]
+fn session_start_records_the_selected_branch_package_in_timing() {
+    let base_dir = unique_test_root("package-tier");
+    let config_path = resolve_session_package_config_path(&base_dir);
+    fs::create_dir_all(config_path.parent().expect("config dir should exist"))
+        .expect("config dir should be created");
+    fs::write(
+        &config_path,
+        serde_json::json!({
+            "schemaVersion": "session-package-config/v1",
+            "defaultPackageId": "express",
+            "packages": [
+                {
+                    "packageId": "express",
+                    "displayName": "10분 촬영",
+                    "durationMinutes": 10,
+                    "warningLeadMinutes": 2
+                },
+                {
+                    "packageId": "premium",
+                    "displayName": "30분 촬영",
+                    "durationMinutes": 30,
+                    "warningLeadMinutes": 10,
+                    "maxExtensionMinutes": 15
+                },
+                {
+                    "packageId": "broken",
+                    "displayName": "잘못된 이용권",
+                    "durationMinutes": 5,
+                    "warningLeadMinutes": 5
+                }
+            ]
+        })
+        .to_string(),
+    )
+    .expect("package config should be written");
+
+    let catalog = load_session_packages_in_dir(&base_dir).expect("packages should load");
+    let ids: Vec<_> = catalog
+        .packages
+        .iter()
+        .map(|package| package.package_id.as_str())
+        .collect();
+    assert_eq!(ids, vec![DEFAULT_SESSION_PACKAGE_ID, "express", "premium"]);
+    assert_eq!(catalog.default_package_id, "express");
+
+    let premium = start_session_in_dir(
+        &base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: Some(" premium ".into()),
+        },
+    )
+    .expect("premium session should start");
+    let timing = premium.manifest.timing.expect("timing should exist");
+    let started_at =
+        rfc3339_to_unix_seconds(&premium.manifest.created_at).expect("created_at should parse");
+    assert_eq!(
+        rfc3339_to_unix_seconds(&timing.adjusted_end_at).expect("end should parse") - started_at,
+        30 * 60
+    );
+    assert_eq!(
+        rfc3339_to_unix_seconds(&timing.warning_at).expect("warning should parse") - started_at,
+        20 * 60
+    );
+    let package = timing.package.expect("package should be recorded");
+    assert_eq!(package.package_id, "premium");
+    assert_eq!(package.max_extension_minutes, 15);
+
+    let default = start_session_in_dir(
+        &base_dir,
+        SessionStartInputDto {
+            name: "Lee".into(),
+            phone_last_four: "1234".into(),
+            package_id: None,
+        },
+    )
+    .expect("default session should start");
+    assert_eq!(
+        default
+            .manifest
+            .timing
+            .and_then(|timing| timing.package)
+            .map(|package| package.package_id),
+        Some("express".into())
+    );
+
+    let error = start_session_in_dir(
+        &base_dir,
+        SessionStartInputDto {
+            name: "Park".into(),
+            phone_last_four: "5678".into(),
+            package_id: Some("broken".into()),
+        },
+    )
+    .expect_err("skipped packages should not be selectable");
+    assert_eq!(error.code, "validation-error");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
 #[test]
 fn published_preset_catalog_only_returns_booth_safe_published_entries_and_limits_to_six() {
     let base_dir = unique_test_root("preset-catalog");
@@ -148,6 +262,7 @@ fn published_preset_catalog_only_returns_booth_safe_published_entries_and_limits
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before loading the preset catalog");
@@ -352,6 +467,7 @@ fn loading_the_preset_catalog_pins_the_current_catalog_revision_and_snapshot() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should start before the first catalog load");
@@ -402,6 +518,7 @@ fn selecting_a_preset_persists_the_binding_in_session_manifest() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before selecting a preset");
@@ -473,6 +590,7 @@ fn selecting_a_preset_recovers_from_a_manifest_backup_left_by_an_interrupted_wri
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before selecting a preset");
@@ -539,7 +657,7 @@ fn legacy_v1_manifest_with_only_active_preset_id_still_deserializes() {
 
 #[test]
 fn legacy_v1_manifest_with_existing_captures_backfills_capture_preset_identity() {
-    let mut manifest: SessionManifest = serde_json::from_value(serde_json::json!({
+    let mut legacy_manifest = serde_json::json!({
       "schemaVersion": "session-manifest/v1",
       "sessionId": "session_01hs6n1r8b8zc5v4ey2x7b9g1m",
       "boothAlias": "Kim 4821",
@@ -590,10 +708,11 @@ fn legacy_v1_manifest_with_existing_captures_backfills_capture_preset_identity()
         }
       }],
       "postEnd": null
-    }))
-    .expect("legacy manifest with captures should deserialize");
+    });
 
-    normalize_legacy_manifest(&mut manifest);
+    migrate_session_manifest_value(&mut legacy_manifest).expect("legacy manifest should migrate");
+    let manifest: SessionManifest = serde_json::from_value(legacy_manifest)
+        .expect("legacy manifest with captures should deserialize");
 
     assert_eq!(
         manifest.captures[0].active_preset_id.as_deref(),
@@ -647,6 +766,7 @@ fn selecting_a_preset_preserves_a_later_lifecycle_stage() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before selecting a preset");
@@ -705,6 +825,7 @@ fn selecting_a_preset_is_blocked_once_exact_end_has_projected_post_end_truth() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before selecting a preset");
@@ -812,6 +933,7 @@ fn selecting_the_same_preset_twice_keeps_the_existing_manifest_timestamp() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before selecting a preset");
@@ -879,6 +1001,7 @@ fn selecting_the_same_preset_backfills_missing_display_name_without_bumping_time
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before selecting a preset");
@@ -1036,6 +1159,7 @@ fn published_runtime_bundle_loader_accepts_legacy_bundles_without_render_profile
         .expect("legacy bundle should serialize"),
     )
     .expect("legacy bundle should write");
+    sign_fixture_bundle(&bundle_dir);
 
     let bundle = load_published_preset_runtime_bundle(&bundle_dir)
         .expect("legacy runtime bundle should load with default render profiles");
@@ -1051,6 +1175,58 @@ fn published_runtime_bundle_loader_accepts_legacy_bundles_without_render_profile
     let _ = fs::remove_dir_all(base_dir);
 }
 
+#[test]
+fn published_runtime_bundle_loader_reads_output_variants_and_skips_invalid_ones() {
+    let base_dir = unique_test_root("published-runtime-bundle-variants");
+    let catalog_root = resolve_published_preset_catalog_dir(&base_dir);
+    create_published_bundle(
+        &catalog_root,
+        "preset_soft-glow",
+        "2026.03.20",
+        "Soft Glow",
+        "published",
+        true,
+        None,
+        None,
+    );
+    let bundle_dir = catalog_root.join("preset_soft-glow").join("2026.03.20");
+    let bundle_path = bundle_dir.join("bundle.json");
+    let mut bundle_json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&bundle_path).expect("bundle should be readable"))
+            .expect("bundle should parse");
+    bundle_json["outputVariants"] = serde_json::json!([
+        { "variantId": "web", "displayName": "Web", "format": "jpeg", "maxWidth": 2048, "maxHeight": 2048, "quality": 85 },
+        { "variantId": "print-tiff", "displayName": "Print TIFF", "format": "tiff", "bitDepth": 16, "colorSpace": "AdobeRGB", "purposes": ["print"] },
+        { "variantId": "social", "displayName": "Social", "format": "jpeg", "maxWidth": 1080, "crop": "square", "purposes": ["social", "download"] },
+        { "variantId": "web", "displayName": "Duplicate", "format": "jpeg" },
+        { "variantId": "tiff-square", "displayName": "Bad crop", "format": "tiff", "crop": "square" },
+        { "variantId": "deep-jpeg", "displayName": "Bad depth", "format": "jpeg", "bitDepth": 16 },
+        { "variantId": "wide", "displayName": "Bad color", "format": "jpeg", "colorSpace": "ProPhoto" }
+    ]);
+    fs::write(
+        &bundle_path,
+        serde_json::to_vec_pretty(&bundle_json).expect("bundle should serialize"),
+    )
+    .expect("bundle should be written");
+    sign_fixture_bundle(&bundle_dir);
+
+    let bundle =
+        load_published_preset_runtime_bundle(&bundle_dir).expect("runtime bundle should load");
+    let variant_ids = bundle
+        .output_variants
+        .iter()
+        .map(|variant| variant.variant_id.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(variant_ids, vec!["web", "print-tiff", "social"]);
+    assert_eq!(bundle.output_variants[0].purposes, vec!["download"]);
+    assert_eq!(bundle.output_variants[1].bit_depth, 16);
+    assert_eq!(bundle.output_variants[1].color_space, "AdobeRGB");
+    assert!(bundle.output_variants[2].serves("social"));
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
 #[test]
 fn published_preset_catalog_skips_malformed_bundle_fields_without_failing_the_whole_catalog() {
     let base_dir = unique_test_root("preset-catalog-malformed-fields");
@@ -1059,6 +1235,7 @@ fn published_preset_catalog_skips_malformed_bundle_fields_without_failing_the_wh
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before loading the preset catalog");
@@ -1118,6 +1295,7 @@ fn published_preset_catalog_rejects_preview_assets_outside_the_bundle_directory(
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before loading the preset catalog");
@@ -1157,6 +1335,7 @@ fn published_preset_catalog_skips_bundles_whose_parent_folder_does_not_match_pre
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before loading the preset catalog");
@@ -1206,6 +1385,7 @@ fn selecting_a_preset_rejects_entries_outside_the_customer_visible_top_six() {
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before selecting a preset");
@@ -1255,6 +1435,7 @@ fn published_preset_catalog_uses_stable_tie_breaking_for_duplicate_display_names
         SessionStartInputDto {
             name: "Kim".into(),
             phone_last_four: "4821".into(),
+            package_id: None,
         },
     )
     .expect("session should exist before loading the preset catalog");
@@ -1391,4 +1572,5 @@ fn create_published_bundle(
         serde_json::to_vec_pretty(&bundle).expect("bundle should serialize"),
     )
     .expect("bundle should be written");
+    sign_fixture_bundle(&bundle_dir);
 }
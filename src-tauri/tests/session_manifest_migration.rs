@@ -0,0 +1,185 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    capture::normalized_state::get_capture_readiness_in_dir,
+    contracts::dto::CaptureReadinessInputDto,
+    session::{
+        manifest_migration::migrate_session_manifest_value,
+        session_manifest::{SessionManifest, SESSION_MANIFEST_SCHEMA_VERSION},
+        session_paths::SessionPaths,
+        session_repository::manifest_pre_migration_path,
+    },
+};
+
+const CORPUS_SESSION_ID: &str = "session_01hs6n1r8b8zc5v4ey2x7b9g1m";
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-manifest-migration-{test_name}-{stamp}"))
+}
+
+fn corpus_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("support")
+        .join("session-manifests")
+}
+
+fn read_corpus_manifest(file_name: &str) -> String {
+    fs::read_to_string(corpus_dir().join(file_name)).expect("corpus manifest should be readable")
+}
+
+fn install_manifest(base_dir: &Path, manifest_json: &str) -> PathBuf {
+    let paths = SessionPaths::new(base_dir, CORPUS_SESSION_ID);
+    fs::create_dir_all(&paths.session_root).expect("session root should be created");
+    fs::write(&paths.manifest_path, manifest_json).expect("manifest should be written");
+
+    paths.manifest_path
+}
+
+fn load_session(base_dir: &Path) -> Result<(), String> {
+    get_capture_readiness_in_dir(
+        base_dir,
+        CaptureReadinessInputDto {
+            session_id: CORPUS_SESSION_ID.into(),
+        },
+    )
+    .map(|_| ())
+    .map_err(|error| error.message)
+}
+
+#[test]
+fn every_historical_manifest_in_the_corpus_migrates_to_the_current_schema() {
+    let mut corpus: Vec<PathBuf> = fs::read_dir(corpus_dir())
+        .expect("corpus dir should exist")
+        .map(|entry| entry.expect("corpus entry should be readable").path())
+        .collect();
+    corpus.sort();
+    assert!(corpus.len() >= 4);
+
+    for path in corpus {
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .expect("corpus file name should be utf-8")
+            .to_string();
+        let mut value: serde_json::Value =
+            serde_json::from_str(&read_corpus_manifest(&file_name)).expect("corpus should parse");
+        let revision = file_name
+            .split('-')
+            .next()
+            .expect("corpus files are prefixed with their schema revision");
+        assert_eq!(
+            value["schemaVersion"],
+            format!("session-manifest/{revision}"),
+            "{file_name} should be stored under its own schema revision"
+        );
+
+        let outcome = migrate_session_manifest_value(&mut value)
+            .unwrap_or_else(|error| panic!("{file_name} should migrate: {}", error.message));
+        let manifest: SessionManifest = serde_json::from_value(value)
+            .unwrap_or_else(|error| panic!("{file_name} should deserialize: {error}"));
+
+        assert_eq!(manifest.schema_version, SESSION_MANIFEST_SCHEMA_VERSION);
+        match outcome {
+            Some(outcome) => {
+                assert_eq!(outcome.steps.first(), Some(&outcome.from_version));
+                assert_eq!(outcome.to_version, SESSION_MANIFEST_SCHEMA_VERSION);
+            }
+            None => assert_eq!(
+                format!("session-manifest/{revision}"),
+                SESSION_MANIFEST_SCHEMA_VERSION
+            ),
+        }
+        if let Some(post_end) = manifest.post_end.as_ref() {
+            assert!(!post_end.evaluated_at().is_empty());
+        }
+    }
+}
+
+#[test]
+fn v1_captures_gain_their_preset_identity_and_post_end_gains_evaluated_at() {
+    let mut captures: serde_json::Value = serde_json::from_str(&read_corpus_manifest(
+        "v1-capture-without-preset-identity.json",
+    ))
+    .expect("corpus should parse");
+    migrate_session_manifest_value(&mut captures).expect("v1 should migrate");
+    assert_eq!(
+        captures["captures"][0]["activePresetId"],
+        "preset_soft-glow"
+    );
+    assert_eq!(
+        captures["captures"][0]["activePresetDisplayName"],
+        "Soft Glow"
+    );
+
+    let mut phone_required: serde_json::Value = serde_json::from_str(&read_corpus_manifest(
+        "v1-phone-required-without-evaluated-at.json",
+    ))
+    .expect("corpus should parse");
+    migrate_session_manifest_value(&mut phone_required).expect("v1 should migrate");
+    assert_eq!(
+        phone_required["postEnd"]["evaluatedAt"],
+        "1970-01-01T00:00:00Z"
+    );
+}
+
+#[test]
+fn loading_a_v1_session_keeps_a_pre_migration_backup_without_rewriting_the_manifest() {
+    let base_dir = unique_test_root("backup");
+    let original = read_corpus_manifest("v1-session-started.json");
+    let manifest_path = install_manifest(&base_dir, &original);
+    let backup_path = manifest_pre_migration_path(&manifest_path, "session-manifest/v1");
+
+    load_session(&base_dir).expect("v1 session should load");
+
+    assert_eq!(
+        fs::read_to_string(&backup_path).expect("pre-migration backup should exist"),
+        original
+    );
+    assert_eq!(
+        fs::read_to_string(&manifest_path).expect("manifest should be readable"),
+        original,
+        "reads migrate in memory and leave the write to the next locked mutation"
+    );
+
+    load_session(&base_dir).expect("migrated session should load again");
+    assert_eq!(
+        fs::read_to_string(&backup_path).expect("backup should survive later loads"),
+        original
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn manifests_written_by_a_newer_build_are_refused_and_left_untouched() {
+    let base_dir = unique_test_root("newer");
+    let future = read_corpus_manifest("v2-timed-session.json")
+        .replace(SESSION_MANIFEST_SCHEMA_VERSION, "session-manifest/v99");
+    let manifest_path = install_manifest(&base_dir, &future);
+
+    let message = load_session(&base_dir).expect_err("newer manifest should be refused");
+
+    assert!(message.contains("session-manifest/v99"));
+    assert_eq!(
+        fs::read_to_string(&manifest_path).expect("manifest should still exist"),
+        future
+    );
+    assert!(!manifest_pre_migration_path(&manifest_path, "session-manifest/v99").exists());
+
+    let mut unknown: serde_json::Value =
+        serde_json::from_str(&future.replace("session-manifest/v99", "session-manifest/legacy"))
+            .expect("manifest should parse");
+    assert!(migrate_session_manifest_value(&mut unknown).is_err());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
@@ -0,0 +1,312 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    commands::runtime_commands::capability_snapshot_for_profile,
+    contracts::dto::{OperatorAuditQueryFilterDto, SessionStartInputDto},
+    diagnostics::audit_log::load_operator_audit_history_in_dir,
+    session::{
+        session_manifest::{
+            build_session_manifest_at, current_timestamp, SessionManifest, SessionPostEnd,
+        },
+        session_paths::SessionPaths,
+        session_retention::{
+            apply_session_retention_in_dir, load_session_retention_policy_in_dir,
+            resolve_session_archive_root, save_session_retention_policy_in_dir,
+            SessionRetentionPolicy,
+        },
+    },
+};
+
+const DAY: Duration = Duration::from_secs(86_400);
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-session-retention-{test_name}-{stamp}"))
+}
+
+fn write_session(base_dir: &Path, session_id: &str, started_at: SystemTime) -> SessionManifest {
+    let manifest = build_session_manifest_at(
+        session_id.into(),
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+        started_at,
+    )
+    .expect("manifest should build");
+    persist_manifest(base_dir, &manifest);
+
+    manifest
+}
+
+fn persist_manifest(base_dir: &Path, manifest: &SessionManifest) {
+    let paths = SessionPaths::new(base_dir, &manifest.session_id);
+    fs::create_dir_all(&paths.captures_originals_dir).expect("captures dir should exist");
+    fs::create_dir_all(&paths.renders_finals_dir).expect("finals dir should exist");
+    fs::create_dir_all(&paths.handoff_dir).expect("handoff dir should exist");
+    fs::write(
+        paths.captures_originals_dir.join("capture_001.cr3"),
+        b"raw-bytes",
+    )
+    .expect("raw fixture should be written");
+    fs::write(
+        &paths.manifest_path,
+        serde_json::to_vec_pretty(manifest).expect("manifest should serialize"),
+    )
+    .expect("manifest should be written");
+}
+
+fn mark_completed(base_dir: &Path, manifest: &mut SessionManifest, completed_at: SystemTime) {
+    let timestamp = current_timestamp(completed_at).expect("timestamp should format");
+    manifest.post_end = Some(SessionPostEnd::completed(
+        timestamp.clone(),
+        "local-deliverable-ready".into(),
+        "안내가 끝났어요. 천천히 이동해 주세요.".into(),
+        None,
+        false,
+        None,
+    ));
+    manifest.lifecycle.stage = "completed".into();
+    manifest.updated_at = timestamp;
+    persist_manifest(base_dir, manifest);
+}
+
+#[test]
+fn retention_purges_expired_completed_sessions_and_records_an_audit_entry() {
+    let base_dir = unique_test_root("purge");
+    let now = SystemTime::now();
+    let mut manifest = write_session(
+        &base_dir,
+        "session_000000000000000000000000a1",
+        now - DAY * 40,
+    );
+    mark_completed(&base_dir, &mut manifest, now - DAY * 40);
+
+    let report =
+        apply_session_retention_in_dir(&base_dir, now).expect("retention pass should succeed");
+
+    assert_eq!(report.purged.len(), 1);
+    assert_eq!(report.purged[0].session_id, manifest.session_id);
+    assert_eq!(report.purged[0].reason, "retention-expired");
+    assert!(!SessionPaths::new(&base_dir, &manifest.session_id)
+        .session_root
+        .exists());
+
+    let history = load_operator_audit_history_in_dir(
+        &base_dir,
+        &capability_snapshot_for_profile("operator-enabled", true),
+        OperatorAuditQueryFilterDto {
+            session_id: Some(manifest.session_id.clone()),
+            event_categories: vec!["session-lifecycle".into()],
+            limit: None,
+        },
+    )
+    .expect("audit history should load");
+
+    assert!(history
+        .events
+        .iter()
+        .any(|event| event.event_type == "session-purged"
+            && event.source == "session-retention"
+            && event.reason_code.as_deref() == Some("retention-expired")));
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn retention_archives_completed_sessions_into_a_dated_bundle() {
+    let base_dir = unique_test_root("archive");
+    let now = SystemTime::now();
+    let mut manifest = write_session(
+        &base_dir,
+        "session_000000000000000000000000a2",
+        now - DAY * 2,
+    );
+    mark_completed(&base_dir, &mut manifest, now - DAY * 2);
+
+    let report =
+        apply_session_retention_in_dir(&base_dir, now).expect("retention pass should succeed");
+
+    assert_eq!(report.archived.len(), 1);
+    let archive_path = PathBuf::from(
+        report.archived[0]
+            .archive_path
+            .clone()
+            .expect("archive path should be reported"),
+    );
+    assert!(archive_path.is_file());
+    assert!(archive_path
+        .starts_with(resolve_session_archive_root(&base_dir).join(&manifest.updated_at[..10])));
+    assert!(!SessionPaths::new(&base_dir, &manifest.session_id)
+        .session_root
+        .exists());
+
+    let history = load_operator_audit_history_in_dir(
+        &base_dir,
+        &capability_snapshot_for_profile("operator-enabled", true),
+        OperatorAuditQueryFilterDto {
+            session_id: Some(manifest.session_id.clone()),
+            event_categories: vec!["session-lifecycle".into()],
+            limit: None,
+        },
+    )
+    .expect("audit history should load");
+
+    assert!(history
+        .events
+        .iter()
+        .any(|event| event.event_type == "session-archived"
+            && event.reason_code.as_deref() == Some("session-ended")));
+
+    let later = now + DAY * 31;
+    let report = apply_session_retention_in_dir(&base_dir, later)
+        .expect("second retention pass should succeed");
+
+    assert_eq!(report.purged.len(), 1);
+    assert_eq!(report.purged[0].reason, "archive-expired");
+    assert!(!archive_path.exists());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn retention_retries_only_the_cleanup_of_an_already_archived_session() {
+    let base_dir = unique_test_root("archive-cleanup");
+    let now = SystemTime::now();
+    let mut manifest = write_session(
+        &base_dir,
+        "session_000000000000000000000000a4",
+        now - DAY * 2,
+    );
+    mark_completed(&base_dir, &mut manifest, now - DAY * 2);
+
+    // A pass that wrote the archive but failed to remove the session folder.
+    let archive_path = resolve_session_archive_root(&base_dir)
+        .join(&manifest.updated_at[..10])
+        .join(format!("{}.tar.gz", manifest.session_id));
+    fs::create_dir_all(archive_path.parent().expect("archive dir"))
+        .expect("archive dir should be created");
+    fs::write(&archive_path, b"earlier archive").expect("archive should be written");
+
+    let report =
+        apply_session_retention_in_dir(&base_dir, now).expect("retention pass should succeed");
+
+    assert_eq!(report.archived.len(), 1);
+    assert_eq!(report.archived[0].reason, "already-archived");
+    assert_eq!(
+        fs::read(&archive_path).expect("archive should remain"),
+        b"earlier archive"
+    );
+    assert!(!SessionPaths::new(&base_dir, &manifest.session_id)
+        .session_root
+        .exists());
+
+    let history = load_operator_audit_history_in_dir(
+        &base_dir,
+        &capability_snapshot_for_profile("operator-enabled", true),
+        OperatorAuditQueryFilterDto {
+            session_id: Some(manifest.session_id.clone()),
+            event_categories: vec!["session-lifecycle".into()],
+            limit: None,
+        },
+    )
+    .expect("audit history should load");
+
+    assert!(!history
+        .events
+        .iter()
+        .any(|event| event.event_type == "session-archived"));
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn retention_never_touches_active_or_handoff_pending_sessions() {
+    let base_dir = unique_test_root("active");
+    let now = SystemTime::now();
+    let mut active = write_session(
+        &base_dir,
+        "session_000000000000000000000000a3",
+        now - DAY * 90,
+    );
+    let mut timing = active.timing.clone().expect("timing should exist");
+    timing.adjusted_end_at =
+        current_timestamp(now + Duration::from_secs(600)).expect("timestamp should format");
+    timing.warning_at = timing.adjusted_end_at.clone();
+    active.timing = Some(timing);
+    persist_manifest(&base_dir, &active);
+    let pending = write_session(
+        &base_dir,
+        "session_000000000000000000000000a4",
+        now - DAY * 90,
+    );
+
+    let report =
+        apply_session_retention_in_dir(&base_dir, now).expect("retention pass should succeed");
+
+    assert!(report.archived.is_empty());
+    assert!(report.purged.is_empty());
+    assert!(report
+        .retained
+        .iter()
+        .any(|entry| entry.session_id == active.session_id && entry.reason == "active-session"));
+    assert!(report
+        .retained
+        .iter()
+        .any(|entry| entry.session_id == pending.session_id && entry.reason == "awaiting-handoff"));
+    assert!(SessionPaths::new(&base_dir, &active.session_id)
+        .manifest_path
+        .is_file());
+    assert!(SessionPaths::new(&base_dir, &pending.session_id)
+        .manifest_path
+        .is_file());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn retention_policy_round_trips_and_rejects_out_of_range_keep_days() {
+    let base_dir = unique_test_root("policy");
+
+    assert_eq!(
+        load_session_retention_policy_in_dir(&base_dir).expect("default policy should load"),
+        SessionRetentionPolicy::default()
+    );
+
+    let saved = save_session_retention_policy_in_dir(
+        &base_dir,
+        SessionRetentionPolicy {
+            keep_days: 7,
+            archive_ended_sessions: false,
+            ..SessionRetentionPolicy::default()
+        },
+    )
+    .expect("valid policy should save");
+
+    assert_eq!(
+        load_session_retention_policy_in_dir(&base_dir).expect("saved policy should load"),
+        saved
+    );
+
+    let error = save_session_retention_policy_in_dir(
+        &base_dir,
+        SessionRetentionPolicy {
+            keep_days: 0,
+            ..SessionRetentionPolicy::default()
+        },
+    )
+    .expect_err("zero keep days should be rejected");
+
+    assert_eq!(error.code, "validation-error");
+
+    let _ = fs::remove_dir_all(base_dir);
+}
@@ -0,0 +1,370 @@
+mod support;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use boothy_lib::{
+    capture::shot_layout::{
+        assign_capture_to_shot_layout, release_shot_layout_capture, retake_shot_layout_slot_in_dir,
+    },
+    contracts::dto::{SessionStartInputDto, ShotLayoutRetakeInputDto},
+    handoff::sync_post_end_state_in_dir,
+    preset::preset_catalog::{
+        find_published_preset_runtime_bundle, resolve_published_preset_catalog_dir,
+    },
+    session::{
+        session_manifest::{
+            CaptureTimingMetrics, ComposedLayoutFinal, FinalCaptureAsset, PreviewCaptureAsset,
+            RawCaptureAsset, SessionCaptureRecord, SessionManifest, SESSION_CAPTURE_SCHEMA_VERSION,
+            SHOT_LAYOUT_STATUS_COMPOSED, SHOT_LAYOUT_STATUS_FILLED, SHOT_LAYOUT_STATUS_FILLING,
+        },
+        session_paths::SessionPaths,
+        session_repository::start_session_in_dir,
+    },
+};
+use support::sign_fixture_bundle;
+
+const PRESET_ID: &str = "preset_photo-strip";
+const PUBLISHED_VERSION: &str = "2026.04.02";
+
+fn unique_test_root(test_name: &str) -> PathBuf {
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    std::env::temp_dir().join(format!("boothy-shot-layout-{test_name}-{stamp}"))
+}
+
+fn write_strip_bundle(base_dir: &Path, shot_layout: serde_json::Value) {
+    let bundle_dir = resolve_published_preset_catalog_dir(base_dir)
+        .join(PRESET_ID)
+        .join(PUBLISHED_VERSION);
+    fs::create_dir_all(bundle_dir.join("xmp")).expect("bundle directory should exist");
+    fs::write(bundle_dir.join("preview.jpg"), b"preview").expect("preview should exist");
+    fs::write(
+        bundle_dir.join("xmp").join("template.xmp"),
+        b"<x:xmpmeta />",
+    )
+    .expect("xmp template should exist");
+    let bundle = serde_json::json!({
+        "schemaVersion": "published-preset-bundle/v1",
+        "presetId": PRESET_ID,
+        "displayName": "Photo Strip",
+        "publishedVersion": PUBLISHED_VERSION,
+        "lifecycleStatus": "published",
+        "boothStatus": "booth-safe",
+        "darktableVersion": "5.4.1",
+        "xmpTemplatePath": "xmp/template.xmp",
+        "previewProfile": {
+            "profileId": "photo-strip-preview",
+            "displayName": "Photo Strip Preview",
+            "outputColorSpace": "sRGB",
+        },
+        "finalProfile": {
+            "profileId": "photo-strip-final",
+            "displayName": "Photo Strip Final",
+            "outputColorSpace": "sRGB",
+        },
+        "preview": {
+            "kind": "preview-tile",
+            "assetPath": "preview.jpg",
+            "altText": "Photo Strip sample portrait",
+        },
+        "shotLayout": shot_layout,
+    });
+    fs::write(
+        bundle_dir.join("bundle.json"),
+        serde_json::to_vec_pretty(&bundle).expect("bundle should serialize"),
+    )
+    .expect("bundle should be writable");
+    sign_fixture_bundle(&bundle_dir);
+}
+
+fn strip_layout() -> serde_json::Value {
+    serde_json::json!({
+        "layoutId": "strip-3",
+        "displayName": "3컷 스트립",
+        "canvasWidth": 600,
+        "canvasHeight": 1800,
+        "backgroundColor": "#FFFFFF",
+        "slots": [
+            { "slotId": "top", "x": 0.05, "y": 0.02, "width": 0.9, "height": 0.3 },
+            { "slotId": "middle", "x": 0.05, "y": 0.35, "width": 0.9, "height": 0.3 },
+            { "slotId": "bottom", "x": 0.05, "y": 0.68, "width": 0.9, "height": 0.3 },
+        ],
+    })
+}
+
+fn start_session(base_dir: &Path) -> String {
+    start_session_in_dir(
+        base_dir,
+        SessionStartInputDto {
+            name: "Kim".into(),
+            phone_last_four: "4821".into(),
+            package_id: None,
+        },
+    )
+    .expect("session should start")
+    .session_id
+}
+
+fn read_manifest(base_dir: &Path, session_id: &str) -> SessionManifest {
+    let manifest_path = SessionPaths::new(base_dir, session_id).manifest_path;
+
+    serde_json::from_str(&fs::read_to_string(manifest_path).expect("manifest should be readable"))
+        .expect("manifest should deserialize")
+}
+
+fn write_manifest(base_dir: &Path, manifest: &SessionManifest) {
+    fs::write(
+        SessionPaths::new(base_dir, &manifest.session_id).manifest_path,
+        serde_json::to_vec_pretty(manifest).expect("manifest should serialize"),
+    )
+    .expect("manifest should be writable");
+}
+
+fn capture_record(manifest: &SessionManifest, index: usize) -> SessionCaptureRecord {
+    SessionCaptureRecord {
+        schema_version: SESSION_CAPTURE_SCHEMA_VERSION.into(),
+        session_id: manifest.session_id.clone(),
+        booth_alias: manifest.booth_alias.clone(),
+        active_preset_id: Some(PRESET_ID.into()),
+        active_preset_version: PUBLISHED_VERSION.into(),
+        active_preset_display_name: Some("Photo Strip".into()),
+        capture_id: format!("capture_{index:03}"),
+        request_id: format!("request_{index:03}"),
+        raw: RawCaptureAsset {
+            asset_path: format!("capture_{index:03}.cr3"),
+            persisted_at_ms: 100,
+        },
+        preview: PreviewCaptureAsset {
+            asset_path: None,
+            enqueued_at_ms: Some(100),
+            ready_at_ms: Some(200),
+        },
+        final_asset: FinalCaptureAsset {
+            asset_path: None,
+            ready_at_ms: None,
+            variants: Vec::new(),
+        },
+        render_status: "previewReady".into(),
+        render_backend: None,
+        post_end_state: "activeSession".into(),
+        timing: CaptureTimingMetrics {
+            capture_acknowledged_at_ms: 100,
+            preview_visible_at_ms: Some(200),
+            fast_preview_visible_at_ms: None,
+            xmp_preview_ready_at_ms: None,
+            capture_budget_ms: 1_000,
+            preview_budget_ms: 5_000,
+            preview_budget_state: "withinBudget".into(),
+        },
+        capture_program: None,
+    }
+}
+
+fn assign_next_capture(base_dir: &Path, manifest: &mut SessionManifest) -> Option<String> {
+    let capture = capture_record(manifest, manifest.captures.len() + 1);
+    let slot_id = assign_capture_to_shot_layout(base_dir, manifest, &capture);
+    manifest.captures.push(capture);
+
+    slot_id
+}
+
+fn end_session(manifest: &mut SessionManifest) {
+    let timing = manifest
+        .timing
+        .as_mut()
+        .expect("session timing should exist");
+    timing.phase = "ended".into();
+    timing.capture_allowed = false;
+}
+
+#[test]
+fn shot_layout_bundle_is_loaded_and_invalid_layouts_are_ignored() {
+    let base_dir = unique_test_root("bundle");
+    let catalog_root = resolve_published_preset_catalog_dir(&base_dir);
+    write_strip_bundle(&base_dir, strip_layout());
+
+    let bundle = find_published_preset_runtime_bundle(&catalog_root, PRESET_ID, PUBLISHED_VERSION)
+        .expect("bundle should load");
+    let layout = bundle.shot_layout.expect("shot layout should be loaded");
+    assert_eq!(layout.layout_id, "strip-3");
+    assert_eq!((layout.canvas_width, layout.canvas_height), (600, 1800));
+    assert_eq!(layout.background_color, [255, 255, 255]);
+    assert_eq!(
+        layout
+            .slots
+            .iter()
+            .map(|slot| slot.slot_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["top", "middle", "bottom"]
+    );
+
+    let mut overflowing = strip_layout();
+    overflowing["slots"][2]["height"] = serde_json::json!(0.5);
+    write_strip_bundle(&base_dir, overflowing);
+    let bundle = find_published_preset_runtime_bundle(&catalog_root, PRESET_ID, PUBLISHED_VERSION)
+        .expect("bundle should still load without its layout");
+    assert!(bundle.shot_layout.is_none());
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn captures_fill_slots_in_order_and_a_retake_replaces_only_the_armed_slot() {
+    let base_dir = unique_test_root("retake");
+    write_strip_bundle(&base_dir, strip_layout());
+    let session_id = start_session(&base_dir);
+    let mut manifest = read_manifest(&base_dir, &session_id);
+
+    assert_eq!(
+        assign_next_capture(&base_dir, &mut manifest).as_deref(),
+        Some("top")
+    );
+    assert_eq!(
+        manifest
+            .shot_layout
+            .as_ref()
+            .map(|layout| layout.status.as_str()),
+        Some(SHOT_LAYOUT_STATUS_FILLING)
+    );
+    assert_eq!(
+        assign_next_capture(&base_dir, &mut manifest).as_deref(),
+        Some("middle")
+    );
+    assert_eq!(
+        assign_next_capture(&base_dir, &mut manifest).as_deref(),
+        Some("bottom")
+    );
+    assert_eq!(
+        manifest
+            .shot_layout
+            .as_ref()
+            .map(|layout| layout.status.as_str()),
+        Some(SHOT_LAYOUT_STATUS_FILLED)
+    );
+    assert_eq!(assign_next_capture(&base_dir, &mut manifest), None);
+    write_manifest(&base_dir, &manifest);
+
+    let error = retake_shot_layout_slot_in_dir(
+        &base_dir,
+        ShotLayoutRetakeInputDto {
+            session_id: session_id.clone(),
+            slot_id: "side".into(),
+        },
+    )
+    .expect_err("unknown slots should be rejected");
+    assert_eq!(error.code, "validation-error");
+
+    let retake = retake_shot_layout_slot_in_dir(
+        &base_dir,
+        ShotLayoutRetakeInputDto {
+            session_id: session_id.clone(),
+            slot_id: "middle".into(),
+        },
+    )
+    .expect("a filled slot should be armed for retake");
+    assert_eq!(retake.status, "retake-armed");
+    let mut manifest = retake.manifest;
+    assert_eq!(
+        manifest
+            .shot_layout
+            .as_ref()
+            .and_then(|layout| layout.retake_slot_id.as_deref()),
+        Some("middle")
+    );
+
+    assert_eq!(
+        assign_next_capture(&base_dir, &mut manifest).as_deref(),
+        Some("middle")
+    );
+    let layout = manifest
+        .shot_layout
+        .as_ref()
+        .expect("layout should persist");
+    assert_eq!(layout.retake_slot_id, None);
+    assert_eq!(
+        layout.slot_capture_ids().collect::<Vec<_>>(),
+        vec!["capture_001", "capture_005", "capture_003"]
+    );
+
+    release_shot_layout_capture(&mut manifest, "capture_001");
+    let layout = manifest
+        .shot_layout
+        .as_ref()
+        .expect("layout should persist");
+    assert_eq!(layout.status, SHOT_LAYOUT_STATUS_FILLING);
+    assert_eq!(layout.slots[0].capture_id, None);
+    assert_eq!(
+        assign_next_capture(&base_dir, &mut manifest).as_deref(),
+        Some("top")
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
+
+#[test]
+fn post_end_waits_on_the_composed_layout_final_instead_of_the_latest_capture() {
+    let base_dir = unique_test_root("post-end");
+    write_strip_bundle(&base_dir, strip_layout());
+    let session_id = start_session(&base_dir);
+    let manifest_path = SessionPaths::new(&base_dir, &session_id).manifest_path;
+    let mut manifest = read_manifest(&base_dir, &session_id);
+    assign_next_capture(&base_dir, &mut manifest);
+    assign_next_capture(&base_dir, &mut manifest);
+    end_session(&mut manifest);
+    write_manifest(&base_dir, &manifest);
+
+    let unfilled = sync_post_end_state_in_dir(
+        &base_dir,
+        &manifest_path,
+        manifest.clone(),
+        SystemTime::now(),
+    )
+    .expect("post-end should evaluate");
+    assert_eq!(
+        unfilled.post_end.as_ref().map(|post_end| post_end.state()),
+        Some("phone-required")
+    );
+
+    assign_next_capture(&base_dir, &mut manifest);
+    let paths = SessionPaths::new(&base_dir, &session_id);
+    fs::create_dir_all(&paths.renders_finals_dir).expect("finals dir should exist");
+    let composed_path = paths.renders_finals_dir.join("layout-strip-3.jpg");
+    fs::write(&composed_path, [0xFF, 0xD8, 0xFF, 0xD9]).expect("composed final should exist");
+    let layout = manifest.shot_layout.as_mut().expect("layout should exist");
+    layout.status = SHOT_LAYOUT_STATUS_COMPOSED.into();
+    layout.composed_final = Some(ComposedLayoutFinal {
+        asset_path: composed_path.to_string_lossy().into_owned(),
+        ready_at_ms: 400,
+        capture_ids: layout.slot_capture_ids().map(str::to_string).collect(),
+    });
+    manifest
+        .captures
+        .last_mut()
+        .expect("capture should exist")
+        .render_status = "previewWaiting".into();
+    write_manifest(&base_dir, &manifest);
+
+    let completed =
+        sync_post_end_state_in_dir(&base_dir, &manifest_path, manifest, SystemTime::now())
+            .expect("post-end should evaluate");
+    assert_eq!(
+        completed.post_end.as_ref().map(|post_end| post_end.state()),
+        Some("completed")
+    );
+    assert_eq!(
+        completed
+            .post_end
+            .as_ref()
+            .and_then(|post_end| post_end.completion_variant()),
+        Some("handoff-ready")
+    );
+
+    let _ = fs::remove_dir_all(base_dir);
+}
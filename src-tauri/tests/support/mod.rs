@@ -0,0 +1,79 @@
+//! Helpers shared by the integration tests that publish preset bundles.
+
+use std::{fs, path::Path};
+
+use boothy_lib::{
+    branch_config::preset_signing_keys::{resolve_preset_signing_keys_path, PresetSigningKey},
+    integrity::{ed25519_public_key, encode_hex},
+    preset::bundle_signature::sign_preset_bundle,
+};
+
+const FIXTURE_SIGNING_KEY_ID: &str = "hq-fixture";
+const FIXTURE_SIGNING_SEED: [u8; 32] = [21; 32];
+
+/// Signs a fixture bundle with the HQ fixture key and trusts that key in the
+/// booth the bundle was written into. The ids come from the bundle's folders.
+pub fn sign_fixture_bundle(bundle_dir: &Path) {
+    let folder_name = |path: &Path| {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .expect("bundle folders should be named")
+            .to_string()
+    };
+    let preset_dir = bundle_dir
+        .parent()
+        .expect("bundle should sit in a preset folder");
+    let base_dir = bundle_dir
+        .ancestors()
+        .nth(4)
+        .expect("bundle should sit under the published catalog");
+    trust_fixture_signing_key(base_dir);
+    sign_preset_bundle(
+        bundle_dir,
+        &folder_name(preset_dir),
+        &folder_name(bundle_dir),
+        &PresetSigningKey {
+            key_id: FIXTURE_SIGNING_KEY_ID.into(),
+            seed: FIXTURE_SIGNING_SEED,
+        },
+        "2026-03-20T00:00:00.000Z",
+    )
+    .expect("fixture bundle should be signed");
+}
+
+fn trust_fixture_signing_key(base_dir: &Path) {
+    let keys_path = resolve_preset_signing_keys_path(base_dir);
+    let mut store = fs::read_to_string(&keys_path)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+        .unwrap_or_else(|| {
+            serde_json::json!({
+              "schemaVersion": "preset-signing-keys/v1",
+              "activeKeyId": null,
+              "keys": []
+            })
+        });
+    let keys = store["keys"]
+        .as_array_mut()
+        .expect("signing key store should list keys");
+    if keys
+        .iter()
+        .any(|key| key["keyId"] == FIXTURE_SIGNING_KEY_ID)
+    {
+        return;
+    }
+    keys.push(serde_json::json!({
+      "keyId": FIXTURE_SIGNING_KEY_ID,
+      "publicKey": encode_hex(&ed25519_public_key(&FIXTURE_SIGNING_SEED)),
+      "origin": "imported",
+      "addedAt": "2026-03-20T00:00:00.000Z",
+      "actorId": "hq-release"
+    }));
+    fs::create_dir_all(keys_path.parent().expect("keys path should have a parent"))
+        .expect("branch config directory should exist");
+    fs::write(
+        &keys_path,
+        serde_json::to_vec_pretty(&store).expect("signing keys should serialize"),
+    )
+    .expect("signing keys should write");
+}
@@ -1,10 +1,20 @@
-import type { CompletedPostEndRecord } from '../../shared-contracts'
+import {
+  downloadHandoffSchema,
+  type CompletedPostEndRecord,
+} from '../../shared-contracts'
 
 type HandoffReadyPanelProps = {
   boothAlias: string | null
   guidance: CompletedPostEndRecord
 }
 
+function formatExpiryTime(expiresAt: string) {
+  return new Intl.DateTimeFormat('ko-KR', {
+    hour: 'numeric',
+    minute: '2-digit',
+  }).format(new Date(expiresAt))
+}
+
 export function HandoffReadyPanel({
   boothAlias,
   guidance,
@@ -15,6 +25,7 @@ export function HandoffReadyPanel({
     guidance.approvedRecipientLabel != null
       ? '승인된 수령 대상'
       : '다음 이동 위치'
+  const download = downloadHandoffSchema.safeParse(guidance.handoff)
 
   return (
     <article className="surface-card handoff-ready-panel">
@@ -35,6 +46,22 @@ export function HandoffReadyPanel({
           <p>{boothAlias}</p>
         </div>
       ) : null}
+      {download.success ? (
+        <div className="handoff-ready-panel__section handoff-ready-panel__download">
+          <h2>휴대폰으로 받기</h2>
+          <img
+            className="handoff-ready-panel__qr"
+            src={`data:image/svg+xml;charset=utf-8,${encodeURIComponent(download.data.qrSvg)}`}
+            alt="사진 내려받기 QR 코드"
+          />
+          <p>
+            카메라로 QR 코드를 찍으면 사진 {download.data.fileCount}장을 받을 수
+            있어요. 한 번만 받을 수 있고 {formatExpiryTime(download.data.expiresAt)}
+            까지 유효해요.
+          </p>
+          <p className="handoff-ready-panel__url">{download.data.url}</p>
+        </div>
+      ) : null}
     </article>
   )
 }
@@ -113,7 +113,7 @@ function renderCaptureScreen(
     timing: createTimingSnapshot(),
   }
   const defaultManifest: SessionManifest = {
-    schemaVersion: 'session-manifest/v1',
+    schemaVersion: 'session-manifest/v2',
     sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
     boothAlias: 'Kim 4821',
     customer: {
@@ -198,7 +198,7 @@ function renderCaptureScreen(
       captureId: 'capture_01hs6n1r8b8zc5v4ey2x7b9g1m',
       status: 'capture-deleted',
       manifest: {
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
         boothAlias: 'Kim 4821',
         customer: {
@@ -439,7 +439,7 @@ describe('CaptureScreen', () => {
           },
         },
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -533,7 +533,7 @@ describe('CaptureScreen', () => {
           },
         },
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -670,7 +670,7 @@ describe('CaptureScreen', () => {
           publishedVersion: '2026.03.19',
         },
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -729,7 +729,7 @@ describe('CaptureScreen', () => {
       {
         presetCatalog: [],
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -847,7 +847,7 @@ describe('CaptureScreen', () => {
           }),
         },
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -1007,7 +1007,7 @@ describe('CaptureScreen', () => {
           }),
         },
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -1110,7 +1110,7 @@ describe('CaptureScreen', () => {
           }),
         },
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -1214,7 +1214,7 @@ describe('CaptureScreen', () => {
       {},
       {
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -1379,7 +1379,7 @@ describe('CaptureScreen', () => {
           }),
         },
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -1491,7 +1491,7 @@ describe('CaptureScreen', () => {
           kind: 'camera-thumbnail',
         },
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -1617,7 +1617,7 @@ describe('CaptureScreen', () => {
           kind: 'camera-thumbnail',
         },
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -1703,7 +1703,7 @@ describe('CaptureScreen', () => {
           },
         ],
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -1752,7 +1752,7 @@ describe('CaptureScreen', () => {
       {},
       {
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -1822,7 +1822,7 @@ describe('CaptureScreen', () => {
       },
       {
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
           boothAlias: 'Kim 4821',
           customer: {
@@ -80,7 +80,7 @@ describe('SessionStartScreen', () => {
       sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
       boothAlias: 'Kim 4821',
       manifest: {
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
         boothAlias: 'Kim 4821',
         customer: {
@@ -141,7 +141,7 @@ describe('SessionStartScreen', () => {
       sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
       boothAlias: 'Kim 4821',
       manifest: {
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
         boothAlias: 'Kim 4821',
         customer: {
@@ -131,6 +131,7 @@ export function createBrowserBranchRolloutGateway(): BranchRolloutGateway {
         approvedBaselines: [],
         branches: [],
         recentHistory: [],
+        rolloutPlans: [],
       }
     },
     async applyRollout() {
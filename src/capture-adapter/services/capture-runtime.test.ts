@@ -120,7 +120,7 @@ describe('capture runtime adapter', () => {
           captureId: 'capture_01hs6n1r8b8zc5v4ey2x7b9g1m',
           status: 'capture-deleted',
           manifest: {
-            schemaVersion: 'session-manifest/v1',
+            schemaVersion: 'session-manifest/v2',
             sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
             boothAlias: 'Kim 4821',
             customer: {
@@ -534,7 +534,7 @@ describe('capture runtime adapter', () => {
           captureId: 'capture_01hs6n1r8b8zc5v4ey2x7b9g1m',
           status: 'capture-deleted',
           manifest: {
-            schemaVersion: 'session-manifest/v1',
+            schemaVersion: 'session-manifest/v2',
             sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1n',
             boothAlias: 'Kim 4821',
             customer: {
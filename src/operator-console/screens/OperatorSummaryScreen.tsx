@@ -720,6 +720,12 @@ export function OperatorSummaryScreen() {
                 label="Completion Boundary"
                 boundary={summary.completionBoundary}
               />
+              {summary.printBoundary ? (
+                <BoundaryCard
+                  label="Print Boundary"
+                  boundary={summary.printBoundary}
+                />
+              ) : null}
             </section>
           </>
         )}
@@ -45,7 +45,7 @@ function createManifest(
   sessionId = 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
 ): SessionManifest {
   return {
-    schemaVersion: 'session-manifest/v1',
+    schemaVersion: 'session-manifest/v2',
     sessionId,
     boothAlias: 'Kim 4821',
     customer: {
@@ -38,7 +38,7 @@ describe('active preset service', () => {
               publishedVersion: '2026.03.21',
             },
             manifest: {
-              schemaVersion: 'session-manifest/v1',
+              schemaVersion: 'session-manifest/v2',
               sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1n',
               boothAlias: 'Kim 4821',
               customer: {
@@ -106,7 +106,7 @@ describe('active preset service', () => {
         publishedVersion: '2026.03.20',
       },
       manifest: {
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
         boothAlias: 'Kim 4821',
         customer: {
@@ -153,7 +153,7 @@ describe('active preset service', () => {
         publishedVersion: '2026.03.20',
       },
       manifest: {
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
         boothAlias: 'Kim 4821',
         customer: {
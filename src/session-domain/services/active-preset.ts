@@ -91,7 +91,7 @@ export function createBrowserActivePresetGateway(): ActivePresetGateway {
         sessionId: input.sessionId,
         activePreset: input.preset,
         manifest: {
-          schemaVersion: 'session-manifest/v1',
+          schemaVersion: 'session-manifest/v2',
           sessionId: input.sessionId,
           boothAlias: 'Preview Session',
           customer: {
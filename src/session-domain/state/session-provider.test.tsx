@@ -40,7 +40,7 @@ function createSessionStartResult(
     sessionId,
     boothAlias,
     manifest: {
-      schemaVersion: 'session-manifest/v1',
+      schemaVersion: 'session-manifest/v2',
       sessionId,
       boothAlias,
       customer: {
@@ -190,7 +190,7 @@ function createCaptureDeleteResult(
     captureId: 'capture_01hs6n1r8b8zc5v4ey2x7b9g1m',
     status: 'capture-deleted',
     manifest: {
-      schemaVersion: 'session-manifest/v1',
+      schemaVersion: 'session-manifest/v2',
       sessionId,
       boothAlias: 'Kim 4821',
       customer: {
@@ -4805,7 +4805,7 @@ describe('SessionProvider', () => {
       sessionId,
       boothAlias: 'Kim 4821',
       manifest: {
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId,
         boothAlias: 'Kim 4821',
         customer: {
@@ -4855,7 +4855,7 @@ describe('SessionProvider', () => {
           sessionId,
           captureId: latestCaptureId,
           manifest: {
-            schemaVersion: 'session-manifest/v1',
+            schemaVersion: 'session-manifest/v2',
             sessionId,
             boothAlias: 'Kim 4821',
             customer: {
@@ -5084,7 +5084,7 @@ describe('SessionProvider', () => {
       sessionId,
       boothAlias: 'Kim 4821',
       manifest: {
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId,
         boothAlias: 'Kim 4821',
         customer: {
@@ -5124,7 +5124,7 @@ describe('SessionProvider', () => {
           sessionId,
           captureId,
           manifest: {
-            schemaVersion: 'session-manifest/v1',
+            schemaVersion: 'session-manifest/v2',
             sessionId,
             boothAlias: 'Kim 4821',
             customer: {
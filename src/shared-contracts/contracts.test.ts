@@ -375,9 +375,9 @@ describe('shared contracts baseline', () => {
     ).toThrow()
   })
 
-  it('parses the session manifest v1 baseline', () => {
+  it('parses the session manifest v2 baseline', () => {
     const parsed = sessionManifestSchema.parse({
-      schemaVersion: 'session-manifest/v1',
+      schemaVersion: 'session-manifest/v2',
       sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
       boothAlias: 'Kim 4821',
       customer: {
@@ -406,7 +406,7 @@ describe('shared contracts baseline', () => {
 
   it('accepts later lifecycle stages so follow-up stories can preserve session progress', () => {
     const parsed = sessionManifestSchema.parse({
-      schemaVersion: 'session-manifest/v1',
+      schemaVersion: 'session-manifest/v2',
       sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
       boothAlias: 'Kim 4821',
       customer: {
@@ -443,7 +443,7 @@ describe('shared contracts baseline', () => {
   it('requires a completion variant before post-end completed truth can be claimed', () => {
     expect(() =>
       sessionManifestSchema.parse({
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
         boothAlias: 'Kim 4821',
         customer: {
@@ -474,7 +474,7 @@ describe('shared contracts baseline', () => {
       sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
       boothAlias: 'Kim 4821',
       manifest: {
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
         boothAlias: 'Kim 4821',
         customer: {
@@ -511,7 +511,7 @@ describe('shared contracts baseline', () => {
   it('rejects partially pinned catalog metadata in the session manifest', () => {
     expect(() =>
       sessionManifestSchema.parse({
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
         boothAlias: 'Kim 4821',
         customer: {
@@ -1433,7 +1433,7 @@ describe('shared contracts baseline', () => {
 
   it('parses capture deletion results with the updated manifest and readiness', () => {
     const manifest = sessionManifestSchema.parse({
-      schemaVersion: 'session-manifest/v1',
+      schemaVersion: 'session-manifest/v2',
       sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
       boothAlias: 'Kim 4821',
       customer: {
@@ -1498,7 +1498,7 @@ describe('shared contracts baseline', () => {
 
   it('parses handoff-ready post-end guidance with handoff metadata', () => {
     const manifest = sessionManifestSchema.parse({
-      schemaVersion: 'session-manifest/v1',
+      schemaVersion: 'session-manifest/v2',
       sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
       boothAlias: 'Kim 4821',
       customer: {
@@ -1540,7 +1540,7 @@ describe('shared contracts baseline', () => {
   it('rejects completed post-end guidance without a completion variant', () => {
     expect(() =>
       sessionManifestSchema.parse({
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
         boothAlias: 'Kim 4821',
         customer: {
@@ -1566,7 +1566,7 @@ describe('shared contracts baseline', () => {
   it('rejects handoff-ready post-end guidance without destination details', () => {
     expect(() =>
       sessionManifestSchema.parse({
-        schemaVersion: 'session-manifest/v1',
+        schemaVersion: 'session-manifest/v2',
         sessionId: 'session_01hs6n1r8b8zc5v4ey2x7b9g1m',
         boothAlias: 'Kim 4821',
         customer: {
@@ -6,8 +6,18 @@ import {
   branchCompatibilityVerdictSchema,
   branchDisplayNameSchema,
   branchIdSchema,
+  branchLocalSettingsChangeSchema,
+  branchLocalSettingsDiffInputSchema,
+  branchLocalSettingsDiffSchema,
   branchLocalSettingsFieldSchema,
+  branchLocalSettingsHistoryEntrySchema,
   branchLocalSettingsPreservationSchema,
+  branchLocalSettingsQueryInputSchema,
+  branchLocalSettingsResultSchema,
+  branchLocalSettingsSchema,
+  branchLocalSettingsUpdateInputSchema,
+  branchOperationalToggleStateSchema,
+  branchOperationalTogglesResultSchema,
   branchReleaseBaselineSchema,
   branchRollbackInputSchema,
   branchRolloutActionResultSchema,
@@ -15,11 +25,35 @@ import {
   branchRolloutAuditEntrySchema,
   branchRolloutBranchResultSchema,
   branchRolloutBranchStateSchema,
+  branchRolloutHealthSignalsSchema,
+  branchRolloutHealthThresholdsSchema,
   branchRolloutInputSchema,
   branchRolloutOverviewResultSchema,
+  branchRolloutPlanInputSchema,
+  branchRolloutPlanResultSchema,
+  branchRolloutPlanRollbackInputSchema,
+  branchRolloutPlanSchema,
+  branchRolloutPlanStatusSchema,
   branchRolloutRejectionCodeSchema,
   branchRolloutRejectionSchema,
+  branchRolloutWaveSchema,
+  branchRolloutWaveStatusSchema,
   branchSafeTransitionSchema,
+  fleetSyncBundleOutcomeSchema,
+  fleetSyncInputSchema,
+  fleetSyncRejectionCodeSchema,
+  fleetSyncResultSchema,
+  operationalToggleDefinitionSchema,
+  operationalToggleKindSchema,
+  presetBundleSignatureRuleCodeSchema,
+  presetBundleRefSchema,
+  presetBundleResignInputSchema,
+  presetBundleResignResultSchema,
+  presetSigningKeyGenerateInputSchema,
+  presetSigningKeyRevokeInputSchema,
+  presetSigningKeySchema,
+  presetSigningKeysResultSchema,
+  presetSigningKeyTrustInputSchema,
 } from '../schemas'
 
 export type BranchId = z.infer<typeof branchIdSchema>
@@ -63,3 +97,87 @@ export type BranchRollbackInput = z.infer<typeof branchRollbackInputSchema>
 export type BranchRolloutActionResult = z.infer<
   typeof branchRolloutActionResultSchema
 >
+export type BranchRolloutHealthThresholds = z.infer<
+  typeof branchRolloutHealthThresholdsSchema
+>
+export type BranchRolloutHealthSignals = z.infer<
+  typeof branchRolloutHealthSignalsSchema
+>
+export type BranchRolloutWaveStatus = z.infer<
+  typeof branchRolloutWaveStatusSchema
+>
+export type BranchRolloutWave = z.infer<typeof branchRolloutWaveSchema>
+export type BranchRolloutPlanStatus = z.infer<
+  typeof branchRolloutPlanStatusSchema
+>
+export type BranchRolloutPlan = z.infer<typeof branchRolloutPlanSchema>
+export type BranchRolloutPlanInput = z.infer<typeof branchRolloutPlanInputSchema>
+export type BranchRolloutPlanRollbackInput = z.infer<
+  typeof branchRolloutPlanRollbackInputSchema
+>
+export type BranchRolloutPlanResult = z.infer<
+  typeof branchRolloutPlanResultSchema
+>
+export type BranchLocalSettings = z.infer<typeof branchLocalSettingsSchema>
+export type BranchLocalSettingsQueryInput = z.infer<
+  typeof branchLocalSettingsQueryInputSchema
+>
+export type BranchLocalSettingsUpdateInput = z.infer<
+  typeof branchLocalSettingsUpdateInputSchema
+>
+export type BranchLocalSettingsDiffInput = z.infer<
+  typeof branchLocalSettingsDiffInputSchema
+>
+export type BranchLocalSettingsChange = z.infer<
+  typeof branchLocalSettingsChangeSchema
+>
+export type BranchLocalSettingsHistoryEntry = z.infer<
+  typeof branchLocalSettingsHistoryEntrySchema
+>
+export type BranchLocalSettingsResult = z.infer<
+  typeof branchLocalSettingsResultSchema
+>
+export type BranchLocalSettingsDiff = z.infer<
+  typeof branchLocalSettingsDiffSchema
+>
+export type OperationalToggleKind = z.infer<typeof operationalToggleKindSchema>
+export type OperationalToggleDefinition = z.infer<
+  typeof operationalToggleDefinitionSchema
+>
+export type BranchOperationalToggleState = z.infer<
+  typeof branchOperationalToggleStateSchema
+>
+export type BranchOperationalTogglesResult = z.infer<
+  typeof branchOperationalTogglesResultSchema
+>
+export type FleetSyncInput = z.infer<typeof fleetSyncInputSchema>
+export type FleetSyncBundleOutcome = z.infer<
+  typeof fleetSyncBundleOutcomeSchema
+>
+export type FleetSyncRejectionCode = z.infer<
+  typeof fleetSyncRejectionCodeSchema
+>
+export type FleetSyncResult = z.infer<typeof fleetSyncResultSchema>
+export type PresetSigningKeyGenerateInput = z.infer<
+  typeof presetSigningKeyGenerateInputSchema
+>
+export type PresetSigningKeyTrustInput = z.infer<
+  typeof presetSigningKeyTrustInputSchema
+>
+export type PresetSigningKeyRevokeInput = z.infer<
+  typeof presetSigningKeyRevokeInputSchema
+>
+export type PresetSigningKey = z.infer<typeof presetSigningKeySchema>
+export type PresetSigningKeysResult = z.infer<
+  typeof presetSigningKeysResultSchema
+>
+export type PresetBundleResignInput = z.infer<
+  typeof presetBundleResignInputSchema
+>
+export type PresetBundleRef = z.infer<typeof presetBundleRefSchema>
+export type PresetBundleResignResult = z.infer<
+  typeof presetBundleResignResultSchema
+>
+export type PresetBundleSignatureRuleCode = z.infer<
+  typeof presetBundleSignatureRuleCodeSchema
+>
@@ -8,9 +8,18 @@ import {
   liveCaptureTruthSchema,
   captureReadinessUpdateSchema,
   captureFastPreviewUpdateSchema,
+  captureProgramInputSchema,
+  captureProgramResultSchema,
+  captureProgramShotSchema,
+  captureProgramTickSchema,
   captureRequestInputSchema,
   captureRequestResultSchema,
+  captureSelectionInputSchema,
+  captureSelectionResultSchema,
+  captureSelectionSummarySchema,
   sessionCaptureRecordSchema,
+  shotLayoutRetakeInputSchema,
+  shotLayoutRetakeResultSchema,
 } from '../schemas'
 
 export type CaptureReadinessSnapshot = z.infer<
@@ -27,3 +36,18 @@ export type CaptureDeleteResult = z.infer<typeof captureDeleteResultSchema>
 export type CaptureRequestInput = z.infer<typeof captureRequestInputSchema>
 export type CaptureRequestResult = z.infer<typeof captureRequestResultSchema>
 export type SessionCaptureRecord = z.infer<typeof sessionCaptureRecordSchema>
+export type CaptureSelectionInput = z.infer<typeof captureSelectionInputSchema>
+export type CaptureSelectionResult = z.infer<
+  typeof captureSelectionResultSchema
+>
+export type CaptureSelectionSummary = z.infer<
+  typeof captureSelectionSummarySchema
+>
+export type ShotLayoutRetakeInput = z.infer<typeof shotLayoutRetakeInputSchema>
+export type ShotLayoutRetakeResult = z.infer<
+  typeof shotLayoutRetakeResultSchema
+>
+export type CaptureProgramShot = z.infer<typeof captureProgramShotSchema>
+export type CaptureProgramInput = z.infer<typeof captureProgramInputSchema>
+export type CaptureProgramTick = z.infer<typeof captureProgramTickSchema>
+export type CaptureProgramResult = z.infer<typeof captureProgramResultSchema>
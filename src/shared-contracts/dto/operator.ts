@@ -13,6 +13,7 @@ import {
   operatorBoundarySummarySchema,
   operatorCameraConnectionStateSchema,
   operatorCameraConnectionSummarySchema,
+  operatorCaptureLatencySchema,
   operatorRecentFailureSummarySchema,
   operatorRecoveryActionRejectionReasonSchema,
   operatorRecoveryActionRequestSchema,
@@ -24,7 +25,11 @@ import {
   operatorRecoveryNextStateSchema,
   operatorRecoverySummarySchema,
   operatorSessionSummarySchema,
+  operatorSessionTimelineInputSchema,
+  operatorSessionTimelineSchema,
   operatorSummaryStateSchema,
+  operatorTimelineEntrySchema,
+  operatorTimelineSourceSchema,
 } from '../schemas'
 
 export type OperatorAuditEventCategory = z.infer<
@@ -83,3 +88,10 @@ export type OperatorRecoveryNextState = z.infer<
 export type OperatorRecoveryActionResult = z.infer<
   typeof operatorRecoveryActionResultSchema
 >
+export type OperatorTimelineSource = z.infer<typeof operatorTimelineSourceSchema>
+export type OperatorTimelineEntry = z.infer<typeof operatorTimelineEntrySchema>
+export type OperatorCaptureLatency = z.infer<typeof operatorCaptureLatencySchema>
+export type OperatorSessionTimelineInput = z.infer<
+  typeof operatorSessionTimelineInputSchema
+>
+export type OperatorSessionTimeline = z.infer<typeof operatorSessionTimelineSchema>
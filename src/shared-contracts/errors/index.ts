@@ -22,6 +22,11 @@ export const hostErrorEnvelopeSchema = z.object({
     .object({
       name: z.string().min(1).optional(),
       phoneLastFour: z.string().min(1).optional(),
+      contactPhone: z.string().min(1).optional(),
+      contactEmail: z.string().min(1).optional(),
+      contactKakao: z.string().min(1).optional(),
+      supportHours: z.string().min(1).optional(),
+      operationalToggles: z.string().min(1).optional(),
     })
     .partial()
     .optional(),
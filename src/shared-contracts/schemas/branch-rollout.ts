@@ -1,5 +1,7 @@
 import { z } from 'zod'
 
+import { presetIdSchema, publishedVersionSchema } from './preset-core'
+
 const branchIdPattern = /^[a-z0-9][a-z0-9-]{1,47}$/i
 const safeCopySchema = z.string().trim().min(1).max(240)
 const actorIdSchema = z
@@ -85,6 +87,7 @@ export const branchRolloutRejectionCodeSchema = z.enum([
   'unapproved-target-baseline',
   'missing-rollback-baseline',
   'compatibility-check-failed',
+  'unsupported-operational-toggle',
   'audit-write-failed',
 ])
 
@@ -126,11 +129,67 @@ export const branchRolloutAuditEntrySchema = z.object({
   notedAt: z.string().datetime(),
 })
 
+export const branchRolloutHealthThresholdsSchema = z.object({
+  maxCriticalFailures: z.number().int().nonnegative(),
+  maxRenderFailures: z.number().int().nonnegative(),
+  maxCaptureTimeouts: z.number().int().nonnegative(),
+  minObservedSessions: z.number().int().nonnegative(),
+})
+
+export const branchRolloutHealthSignalsSchema = z.object({
+  evaluatedAt: z.string().datetime(),
+  observedSessionIds: z.array(z.string().trim().min(1)),
+  criticalFailures: z.number().int().nonnegative(),
+  renderFailures: z.number().int().nonnegative(),
+  captureTimeouts: z.number().int().nonnegative(),
+})
+
+export const branchRolloutWaveStatusSchema = z.enum([
+  'pending',
+  'in-progress',
+  'promoted',
+  'paused',
+  'rolled-back',
+])
+
+export const branchRolloutWaveSchema = z.object({
+  waveIndex: z.number().int().nonnegative(),
+  label: z.string().trim().min(1).max(20),
+  branchIds: z.array(branchIdSchema).min(1).max(20),
+  status: branchRolloutWaveStatusSchema,
+  startedAt: z.string().datetime().nullable(),
+  completedAt: z.string().datetime().nullable(),
+  health: branchRolloutHealthSignalsSchema.nullable(),
+  outcomes: z.array(branchRolloutBranchResultSchema).max(20),
+})
+
+export const branchRolloutPlanStatusSchema = z.enum([
+  'in-progress',
+  'paused',
+  'completed',
+  'rolled-back',
+])
+
+export const branchRolloutPlanSchema = z.object({
+  schemaVersion: z.literal('branch-rollout-plan/v1'),
+  planId: z.string().trim().min(1).max(80),
+  targetBaseline: branchReleaseBaselineSchema,
+  approval: branchRolloutApprovalSchema,
+  status: branchRolloutPlanStatusSchema,
+  soakMinutes: z.number().int().min(0).max(1440),
+  healthThresholds: branchRolloutHealthThresholdsSchema,
+  waves: z.array(branchRolloutWaveSchema).min(1).max(6),
+  pauseReason: safeCopySchema.nullable(),
+  createdAt: z.string().datetime(),
+  updatedAt: z.string().datetime(),
+})
+
 export const branchRolloutOverviewResultSchema = z.object({
   schemaVersion: z.literal('branch-rollout-overview/v1'),
   approvedBaselines: z.array(branchReleaseBaselineSchema).max(20),
   branches: z.array(branchRolloutBranchStateSchema).max(50),
   recentHistory: z.array(branchRolloutAuditEntrySchema).max(20),
+  rolloutPlans: z.array(branchRolloutPlanSchema).default([]),
 })
 
 export const branchRolloutInputSchema = z
@@ -199,3 +258,277 @@ export const branchRolloutActionResultSchema = z
       })
     }
   })
+
+export const branchRolloutPlanInputSchema = z
+  .object({
+    canaryBranchIds: z.array(branchIdSchema).min(1).max(5),
+    branchIds: z.array(branchIdSchema).max(20).default([]),
+    wavePercentages: z
+      .array(z.number().int().min(1).max(100))
+      .max(5)
+      .default([]),
+    targetBuildVersion: z
+      .string()
+      .trim()
+      .regex(buildVersionPattern, '승인된 build version 형식이 아니에요.'),
+    targetPresetStackVersion: z
+      .string()
+      .trim()
+      .regex(
+        presetStackVersionPattern,
+        '승인된 preset stack version 형식이 아니에요.',
+      ),
+    actorId: actorIdSchema,
+    actorLabel: actorLabelSchema,
+    soakMinutes: z.number().int().min(0).max(1440).nullable().optional(),
+    healthThresholds: branchRolloutHealthThresholdsSchema.nullable().optional(),
+  })
+  .superRefine((value, context) => {
+    const allBranchIds = [...value.canaryBranchIds, ...value.branchIds]
+
+    if (new Set(allBranchIds).size !== allBranchIds.length) {
+      context.addIssue({
+        code: z.ZodIssueCode.custom,
+        message: '같은 지점을 중복해서 선택할 수 없어요.',
+        path: ['branchIds'],
+      })
+    }
+
+    if (allBranchIds.length > 20) {
+      context.addIssue({
+        code: z.ZodIssueCode.custom,
+        message: '배포 대상 지점 수를 다시 확인해 주세요.',
+        path: ['branchIds'],
+      })
+    }
+
+    const percentages = value.wavePercentages
+    const percentagesAreValid =
+      value.branchIds.length === 0
+        ? percentages.length === 0
+        : percentages.at(-1) === 100 &&
+          percentages.every(
+            (percentage, index) =>
+              index === 0 || percentages[index - 1] < percentage,
+          )
+
+    if (!percentagesAreValid) {
+      context.addIssue({
+        code: z.ZodIssueCode.custom,
+        message: '단계별 배포 비율을 다시 확인해 주세요.',
+        path: ['wavePercentages'],
+      })
+    }
+  })
+
+export const branchRolloutPlanRollbackInputSchema = z.object({
+  planId: z.string().trim().min(1).max(80),
+  actorId: actorIdSchema,
+  actorLabel: actorLabelSchema,
+})
+
+export const branchRolloutPlanResultSchema = z.object({
+  schemaVersion: z.literal('branch-rollout-plan-result/v1'),
+  plan: branchRolloutPlanSchema,
+  actionResult: branchRolloutActionResultSchema.nullable(),
+  message: safeCopySchema,
+})
+
+const optionalSettingSchema = z.string().trim().min(1).max(120).nullable()
+
+export const branchLocalSettingsSchema = z.object({
+  contactPhone: optionalSettingSchema,
+  contactEmail: optionalSettingSchema,
+  contactKakao: optionalSettingSchema,
+  supportHours: optionalSettingSchema,
+  operationalToggles: z.array(z.string().trim().min(1).max(40)).max(10),
+})
+
+export const branchLocalSettingsQueryInputSchema = z.object({
+  branchId: branchIdSchema,
+})
+
+export const branchLocalSettingsUpdateInputSchema = z.object({
+  branchId: branchIdSchema,
+  settings: branchLocalSettingsSchema,
+  expectedRevision: z.number().int().nonnegative().nullable().optional(),
+  actorId: actorIdSchema,
+  actorLabel: actorLabelSchema,
+})
+
+export const branchLocalSettingsDiffInputSchema = z.object({
+  branchId: branchIdSchema,
+  fromRevision: z.number().int().nonnegative(),
+  toRevision: z.number().int().nonnegative().nullable().optional(),
+})
+
+export const branchLocalSettingsChangeSchema = z.object({
+  field: branchLocalSettingsFieldSchema,
+  before: z.string().nullable(),
+  after: z.string().nullable(),
+})
+
+export const branchLocalSettingsHistoryEntrySchema = z.object({
+  schemaVersion: z.literal('branch-settings-history-entry/v1'),
+  branchId: branchIdSchema,
+  revision: z.number().int().positive(),
+  changedAt: z.string().datetime(),
+  actorId: actorIdSchema,
+  actorLabel: actorLabelSchema,
+  changes: z.array(branchLocalSettingsChangeSchema).min(1).max(5),
+  previousSettings: branchLocalSettingsSchema,
+  settings: branchLocalSettingsSchema,
+})
+
+export const branchLocalSettingsResultSchema = z.object({
+  schemaVersion: z.literal('branch-local-settings/v1'),
+  branchId: branchIdSchema,
+  displayName: branchDisplayNameSchema,
+  revision: z.number().int().nonnegative(),
+  settings: branchLocalSettingsSchema,
+  history: z.array(branchLocalSettingsHistoryEntrySchema).max(20),
+})
+
+export const branchLocalSettingsDiffSchema = z.object({
+  schemaVersion: z.literal('branch-local-settings-diff/v1'),
+  branchId: branchIdSchema,
+  fromRevision: z.number().int().nonnegative(),
+  toRevision: z.number().int().nonnegative(),
+  changes: z.array(branchLocalSettingsChangeSchema).max(5),
+})
+
+export const operationalToggleKindSchema = z.enum(['bool', 'int', 'enum'])
+
+export const operationalToggleDefinitionSchema = z.object({
+  name: z.string().trim().min(1).max(40),
+  kind: operationalToggleKindSchema,
+  defaultValue: z.string().min(1),
+  minValue: z.number().int().nullable(),
+  maxValue: z.number().int().nullable(),
+  allowedValues: z.array(z.string().min(1)).default([]),
+  description: safeCopySchema,
+  minBuildVersion: z
+    .string()
+    .trim()
+    .regex(buildVersionPattern, '승인된 build version 형식이 아니에요.'),
+})
+
+export const branchOperationalToggleStateSchema = z.object({
+  name: z.string().trim().min(1).max(40),
+  value: z.string().min(1),
+  source: z.enum(['branch', 'default']),
+  supported: z.boolean(),
+})
+
+export const branchOperationalTogglesResultSchema = z.object({
+  schemaVersion: z.literal('branch-operational-toggles/v1'),
+  branchId: branchIdSchema,
+  displayName: branchDisplayNameSchema,
+  buildVersion: z.string().trim().regex(buildVersionPattern),
+  registry: z.array(operationalToggleDefinitionSchema),
+  toggles: z.array(branchOperationalToggleStateSchema),
+})
+
+export const fleetSyncInputSchema = z.object({
+  actorId: actorIdSchema,
+  actorLabel: actorLabelSchema,
+})
+
+export const fleetSyncBundleOutcomeSchema = z.object({
+  presetId: presetIdSchema,
+  publishedVersion: publishedVersionSchema,
+  result: z.enum(['installed', 'already-present']),
+})
+
+export const fleetSyncRejectionCodeSchema = z.enum([
+  'store-unreachable',
+  'signature-missing',
+  'untrusted-signing-key',
+  'signature-invalid',
+  'manifest-invalid',
+  'integrity-mismatch',
+  'bundle-invalid',
+  'bundle-conflict',
+  'release-rollback',
+  'release-replay',
+  'staging-failed',
+])
+
+export const fleetSyncResultSchema = z.object({
+  schemaVersion: z.literal('fleet-sync-result/v1'),
+  status: z.enum(['synced', 'up-to-date', 'rejected']),
+  storeId: z.enum(['local-directory', 'http']),
+  releaseId: z.string().trim().min(1).max(80).nullable(),
+  syncedAt: z.string().datetime(),
+  addedBaselines: z.array(branchReleaseBaselineSchema),
+  presetBundles: z.array(fleetSyncBundleOutcomeSchema),
+  rejectionCode: fleetSyncRejectionCodeSchema.nullable(),
+  message: safeCopySchema,
+})
+
+export const presetSigningKeyIdSchema = z
+  .string()
+  .regex(/^[A-Za-z0-9][A-Za-z0-9-]{1,63}$/)
+
+export const presetSigningPublicKeySchema = z
+  .string()
+  .regex(/^[0-9a-fA-F]{64}$/)
+
+export const presetSigningKeyGenerateInputSchema = z.object({
+  actorId: actorIdSchema,
+  actorLabel: actorLabelSchema,
+})
+
+export const presetSigningKeyTrustInputSchema = z.object({
+  keyId: presetSigningKeyIdSchema,
+  publicKey: presetSigningPublicKeySchema,
+  actorId: actorIdSchema,
+  actorLabel: actorLabelSchema,
+})
+
+export const presetSigningKeyRevokeInputSchema = z.object({
+  keyId: presetSigningKeyIdSchema,
+  actorId: actorIdSchema,
+  actorLabel: actorLabelSchema,
+})
+
+export const presetSigningKeySchema = z.object({
+  keyId: presetSigningKeyIdSchema,
+  publicKey: presetSigningPublicKeySchema,
+  origin: z.enum(['branch', 'imported']),
+  addedAt: z.string().datetime(),
+  actorId: actorIdSchema,
+  revokedAt: z.string().datetime().nullable(),
+  active: z.boolean(),
+})
+
+export const presetSigningKeysResultSchema = z.object({
+  schemaVersion: z.literal('preset-signing-keys-result/v1'),
+  branchId: branchIdSchema.nullable(),
+  activeKeyId: presetSigningKeyIdSchema.nullable(),
+  keys: z.array(presetSigningKeySchema),
+})
+
+export const presetBundleResignInputSchema = z.object({
+  actorId: actorIdSchema,
+  actorLabel: actorLabelSchema,
+})
+
+export const presetBundleRefSchema = z.object({
+  presetId: presetIdSchema,
+  publishedVersion: publishedVersionSchema,
+})
+
+export const presetBundleResignResultSchema = z.object({
+  schemaVersion: z.literal('preset-bundle-resign-result/v1'),
+  keyId: presetSigningKeyIdSchema,
+  signedBundles: z.array(presetBundleRefSchema),
+  message: safeCopySchema,
+})
+
+export const presetBundleSignatureRuleCodeSchema = z.enum([
+  'bundle-signature-missing',
+  'bundle-signature-invalid',
+  'bundle-signing-key-untrusted',
+  'bundle-content-mismatch',
+])
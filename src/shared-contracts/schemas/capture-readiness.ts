@@ -6,12 +6,17 @@ import {
   captureFastPreviewUpdateSchemaVersion,
   captureIdSchema,
   captureDeleteResultSchemaVersion,
+  captureProgramModeSchema,
+  captureProgramResultSchemaVersion,
+  captureProgramTickSchemaVersion,
   captureReadinessSchemaVersion,
   captureReadinessUpdateSchemaVersion,
   captureRequestIdSchema,
   captureRequestResultSchemaVersion,
+  captureSelectionResultSchemaVersion,
   captureSurfaceStateSchema,
   sessionCaptureRecordSchema,
+  shotLayoutRetakeResultSchemaVersion,
 } from './session-capture'
 import { sessionManifestSchema, sessionPostEndSchema } from './session-manifest'
 import { sessionTimingSnapshotSchema } from './session-timing'
@@ -30,6 +35,7 @@ export const customerReadinessStateSchema = z.enum([
 
 export const liveCaptureTruthSourceSchema = z.enum([
   'canon-helper-sidecar',
+  'hot-folder',
   'browser-preview',
   'fixture',
   'unknown',
@@ -127,6 +133,12 @@ function inferSurfaceState(input: {
   return input.canCapture ? 'captureReady' : 'blocked'
 }
 
+export const captureSelectionSummarySchema = z.object({
+  selectedCaptureIds: z.array(captureIdSchema),
+  favoriteCaptureIds: z.array(captureIdSchema),
+  maxSelectedCaptures: z.number().int().positive().nullable(),
+})
+
 const captureReadinessSnapshotInputSchema = z.object({
   schemaVersion: z.literal(captureReadinessSchemaVersion).optional(),
   sessionId: sessionIdSchema.optional(),
@@ -141,6 +153,7 @@ const captureReadinessSnapshotInputSchema = z.object({
   liveCaptureTruth: liveCaptureTruthSchema.optional(),
   postEnd: sessionPostEndSchema.nullable().optional(),
   timing: sessionTimingSnapshotSchema.nullable().optional(),
+  selection: captureSelectionSummarySchema.optional(),
 })
 
 export const captureReadinessSnapshotSchema = captureReadinessSnapshotInputSchema.transform(
@@ -162,6 +175,7 @@ export const captureReadinessSnapshotSchema = captureReadinessSnapshotInputSchem
       liveCaptureTruth?: z.infer<typeof liveCaptureTruthSchema>
       postEnd?: z.infer<typeof sessionPostEndSchema> | null
       timing?: z.infer<typeof sessionTimingSnapshotSchema> | null
+      selection?: z.infer<typeof captureSelectionSummarySchema>
     } = {
       schemaVersion: snapshot.schemaVersion ?? captureReadinessSchemaVersion,
       sessionId,
@@ -193,6 +207,10 @@ export const captureReadinessSnapshotSchema = captureReadinessSnapshotInputSchem
       normalized.timing = snapshot.timing
     }
 
+    if (snapshot.selection !== undefined) {
+      normalized.selection = snapshot.selection
+    }
+
     return normalized
   },
 )
@@ -268,3 +286,66 @@ export const captureDeleteResultSchema = captureDeleteResultInputSchema.transfor
     readiness: result.readiness,
   }),
 )
+
+export const captureSelectionInputSchema = z
+  .object({
+    sessionId: sessionIdSchema,
+    captureId: captureIdSchema,
+    selected: z.boolean().optional(),
+    favorite: z.boolean().optional(),
+  })
+  .refine(
+    (input) => input.selected !== undefined || input.favorite !== undefined,
+    { message: '바꿀 선택 항목이 없어요.' },
+  )
+
+export const captureSelectionResultSchema = z.object({
+  schemaVersion: z.literal(captureSelectionResultSchemaVersion),
+  sessionId: sessionIdSchema,
+  captureId: captureIdSchema,
+  status: z.literal('selection-updated'),
+  manifest: sessionManifestSchema,
+  readiness: captureReadinessSnapshotSchema,
+})
+
+export const shotLayoutRetakeInputSchema = z.object({
+  sessionId: sessionIdSchema,
+  slotId: z.string().trim().min(1),
+})
+
+export const shotLayoutRetakeResultSchema = z.object({
+  schemaVersion: z.literal(shotLayoutRetakeResultSchemaVersion),
+  sessionId: sessionIdSchema,
+  slotId: z.string().trim().min(1),
+  status: z.literal('retake-armed'),
+  manifest: sessionManifestSchema,
+})
+
+export const captureProgramInputSchema = z.object({
+  sessionId: sessionIdSchema,
+  mode: captureProgramModeSchema,
+  countdownSeconds: z.number().int().min(0).max(10).optional(),
+  shotCount: z.number().int().min(1).max(8).optional(),
+  shotIntervalMs: z.number().int().min(500).max(10000).optional(),
+})
+
+export const captureProgramTickSchema = z.object({
+  schemaVersion: z.literal(captureProgramTickSchemaVersion),
+  sessionId: sessionIdSchema,
+  programId: z.string().trim().min(1),
+  phase: z.enum(['countdown', 'shot']),
+  remainingSeconds: z.number().int().nonnegative(),
+  shotIndex: z.number().int().positive(),
+  shotCount: z.number().int().positive(),
+  readiness: captureReadinessSnapshotSchema,
+})
+
+export const captureProgramResultSchema = z.object({
+  schemaVersion: z.literal(captureProgramResultSchemaVersion),
+  sessionId: sessionIdSchema,
+  programId: z.string().trim().min(1),
+  mode: captureProgramModeSchema,
+  status: z.enum(['program-completed', 'program-stopped']),
+  captures: z.array(sessionCaptureRecordSchema),
+  readiness: captureReadinessSnapshotSchema,
+})
@@ -5,6 +5,7 @@ export * from './ids'
 export * from './operator-diagnostics'
 export * from './operator-audit'
 export * from './operator-recovery'
+export * from './operator-timeline'
 export * from './preset-authoring'
 export * from './presets'
 export * from './session-capture'
@@ -26,6 +26,9 @@ export const operatorAuditEventTypeSchema = z.enum([
   'session-started',
   'warning-triggered',
   'session-ended',
+  'session-archived',
+  'session-purged',
+  'session-archive-purged',
   'post-end-export-waiting',
   'post-end-completed',
   'post-end-phone-required',
@@ -43,6 +46,12 @@ export const operatorAuditEventTypeSchema = z.enum([
   'branch-rollback-applied',
   'branch-rollback-deferred',
   'branch-rollback-rejected',
+  'fleet-sync-completed',
+  'fleet-sync-rejected',
+  'preset-signing-key-generated',
+  'preset-signing-key-trusted',
+  'preset-signing-key-revoked',
+  'preset-bundle-rejected',
 ])
 
 export const operatorAuditEntrySchema = z.object({
@@ -50,6 +59,7 @@ export const operatorAuditEntrySchema = z.object({
   eventId: z.string().trim().min(1).max(64),
   occurredAt: z.string().datetime(),
   sessionId: sessionIdSchema.nullable(),
+  branchId: z.string().trim().min(1).max(48).nullable().optional(),
   eventCategory: operatorAuditEventCategorySchema,
   eventType: operatorAuditEventTypeSchema,
   summary: operatorSafeCopySchema,
@@ -65,5 +65,6 @@ export const operatorSessionSummarySchema = z.object({
   captureBoundary: operatorBoundarySummarySchema,
   previewRenderBoundary: operatorBoundarySummarySchema,
   completionBoundary: operatorBoundarySummarySchema,
+  printBoundary: operatorBoundarySummarySchema.optional(),
   liveCaptureTruth: liveCaptureTruthSchema.optional(),
 })
@@ -0,0 +1,51 @@
+import { z } from 'zod'
+
+import { sessionIdSchema } from './ids'
+import { captureIdSchema } from './session-capture'
+
+export const operatorTimelineSourceSchema = z.enum([
+  'operator-audit',
+  'session-journal',
+  'timing-log',
+  'render',
+  'fast-preview',
+  'camera-helper',
+])
+
+export const operatorSessionTimelineInputSchema = z.object({
+  sessionId: sessionIdSchema.nullable().optional(),
+})
+
+export const operatorTimelineEntrySchema = z.object({
+  occurredAt: z.string().min(1),
+  source: operatorTimelineSourceSchema,
+  event: z.string().min(1),
+  captureId: captureIdSchema.nullable(),
+  requestId: z.string().min(1).nullable(),
+  stage: z.string().min(1).nullable(),
+  reasonCode: z.string().min(1).nullable(),
+  detail: z.string().min(1).nullable(),
+})
+
+const latencyMsSchema = z.number().int().nonnegative()
+
+export const operatorCaptureLatencySchema = z.object({
+  captureId: captureIdSchema,
+  requestId: z.string().min(1),
+  renderStatus: z.string().min(1),
+  captureAcknowledgedAtMs: latencyMsSchema,
+  rawPersistedMs: latencyMsSchema.nullable(),
+  fastPreviewVisibleMs: latencyMsSchema.nullable(),
+  xmpPreviewReadyMs: latencyMsSchema.nullable(),
+  previewVisibleMs: latencyMsSchema.nullable(),
+  finalReadyMs: latencyMsSchema.nullable(),
+  previewBudgetMs: latencyMsSchema,
+  previewBudgetState: z.string().min(1),
+})
+
+export const operatorSessionTimelineSchema = z.object({
+  schemaVersion: z.literal('operator-session-timeline/v1'),
+  sessionId: sessionIdSchema,
+  entries: z.array(operatorTimelineEntrySchema),
+  captureLatencies: z.array(operatorCaptureLatencySchema),
+})
@@ -47,6 +47,11 @@ const workspaceReferenceSchema = z
   .min(1, '작업공간 참조 경로를 입력해 주세요.')
   .refine(isSafeWorkspaceReference, '작업공간 바깥 경로는 저장할 수 없어요.')
 
+const optionalWorkspaceReferenceSchema = workspaceReferenceSchema
+  .nullable()
+  .optional()
+  .transform((value) => value ?? null)
+
 const draftFolderNameSchema = z
   .string()
   .trim()
@@ -377,6 +382,8 @@ export const draftPresetSummarySchema = z
       .regex(/^\d+\.\d+\.\d+$/, 'darktable version 형식이 올바르지 않아요.'),
     darktableProjectPath: workspaceReferenceSchema,
     xmpTemplatePath: workspaceReferenceSchema,
+  overlayLayoutPath: optionalWorkspaceReferenceSchema,
+    overlayLayoutPath: optionalWorkspaceReferenceSchema,
     previewProfile: draftRenderProfileSchema,
     finalProfile: draftRenderProfileSchema,
     noisePolicy: draftNoisePolicySchema,
@@ -17,6 +17,14 @@ export const captureRequestResultSchemaVersion =
   'capture-request-result/v1' as const
 export const captureDeleteResultSchemaVersion =
   'capture-delete-result/v1' as const
+export const captureSelectionResultSchemaVersion =
+  'capture-selection-result/v1' as const
+export const shotLayoutRetakeResultSchemaVersion =
+  'shot-layout-retake-result/v1' as const
+export const captureProgramTickSchemaVersion =
+  'capture-program-tick/v1' as const
+export const captureProgramResultSchemaVersion =
+  'capture-program-result/v1' as const
 
 export const captureIdSchema = z.string().trim().min(1)
 export const captureRequestIdSchema = z.string().trim().min(1)
@@ -70,9 +78,19 @@ export const previewCaptureAssetSchema = z.object({
   readyAtMs: captureEventTimeMsSchema.nullable(),
 })
 
+export const finalCaptureVariantSchema = z.object({
+  variantId: z.string().trim().min(1),
+  assetPath: z.string().trim().min(1),
+  format: z.enum(['jpeg', 'tiff']),
+  colorSpace: z.string().trim().min(1),
+  purposes: z.array(z.enum(['download', 'print', 'social'])),
+  readyAtMs: captureEventTimeMsSchema,
+})
+
 export const finalCaptureAssetSchema = z.object({
   assetPath: z.string().trim().min(1).nullable(),
   readyAtMs: captureEventTimeMsSchema.nullable(),
+  variants: z.array(finalCaptureVariantSchema).optional(),
 })
 
 export const captureTimingMetricsSchema = z.object({
@@ -85,6 +103,15 @@ export const captureTimingMetricsSchema = z.object({
   previewBudgetState: previewBudgetStateSchema,
 })
 
+export const captureProgramModeSchema = z.enum(['countdown', 'burst'])
+
+export const captureProgramShotSchema = z.object({
+  programId: z.string().trim().min(1),
+  mode: captureProgramModeSchema,
+  shotIndex: z.number().int().positive(),
+  shotCount: z.number().int().positive(),
+})
+
 export const sessionCaptureRecordSchema = z.object({
   schemaVersion: z.literal(sessionCaptureSchemaVersion),
   sessionId: sessionIdSchema,
@@ -98,8 +125,10 @@ export const sessionCaptureRecordSchema = z.object({
   preview: previewCaptureAssetSchema,
   final: finalCaptureAssetSchema,
   renderStatus: captureRenderStatusSchema,
+  renderBackend: z.string().trim().min(1).nullable().optional(),
   postEndState: capturePostEndStateSchema,
   timing: captureTimingMetricsSchema,
+  captureProgram: captureProgramShotSchema.optional(),
 })
 
 export const captureSurfaceStateSchema = z.enum([
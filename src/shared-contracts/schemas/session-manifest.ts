@@ -9,7 +9,7 @@ import {
 import { sessionCaptureRecordSchema } from './session-capture'
 import { sessionTimingSnapshotSchema } from './session-timing'
 
-export const sessionManifestSchemaVersion = 'session-manifest/v1' as const
+export const sessionManifestSchemaVersion = 'session-manifest/v2' as const
 const postEndTimestampSchema = z.string().datetime()
 const postEndLabelSchema = z.string().trim().min(1).max(80)
 const postEndCopySchema = z.string().trim().min(1).max(120)
@@ -29,6 +29,15 @@ export const exportWaitingPostEndSchema = z.object({
   evaluatedAt: postEndTimestampSchema,
 })
 
+export const downloadHandoffSchema = z.object({
+  schemaVersion: z.literal('download-handoff/v1'),
+  kind: z.literal('download-qr'),
+  url: z.string().url(),
+  expiresAt: postEndTimestampSchema,
+  fileCount: z.number().int().positive(),
+  qrSvg: z.string().startsWith('<svg'),
+})
+
 export const completedPostEndSchema = z
   .object({
     state: z.literal('completed'),
@@ -85,6 +94,55 @@ export const phoneLastFourSchema = z
   .string()
   .regex(/^\d{4}$/, '휴대전화 뒤 4자리는 숫자 4자리여야 해요.')
 
+export const sessionPrintJobSchema = z.object({
+  schemaVersion: z.literal('session-print-job/v1'),
+  jobId: z.string().trim().min(1),
+  templateId: z.string().trim().min(1),
+  captureIds: z.array(z.string().trim().min(1)),
+  copies: z.number().int().min(1),
+  pageCount: z.number().int().min(0),
+  status: z.enum(['spooled', 'printed', 'failed']),
+  queueId: z.string().trim().min(1),
+  documentPath: z.string().nullable().optional(),
+  submittedAt: z.string().datetime(),
+  updatedAt: z.string().datetime(),
+  detailCode: z.string().nullable().optional(),
+})
+
+export const sessionReservationSchema = z.object({
+  reservationId: z.string().trim().min(1).max(64),
+  sourceId: z.string().trim().min(1),
+  allowedPresetIds: z.array(z.string().trim().min(1)),
+  redeemedAt: z.string().datetime(),
+})
+
+export const sessionShotLayoutSchema = z.object({
+  layoutId: z.string().trim().min(1),
+  presetId: z.string().trim().min(1),
+  publishedVersion: z.string().trim().min(1),
+  status: z.enum(['filling', 'filled', 'composed', 'composeFailed']),
+  slots: z.array(
+    z.object({
+      slotId: z.string().trim().min(1),
+      captureId: z.string().trim().min(1).nullable().optional(),
+    }),
+  ),
+  retakeSlotId: z.string().trim().min(1).nullable().optional(),
+  composedFinal: z
+    .object({
+      assetPath: z.string().trim().min(1),
+      readyAtMs: z.number().int().nonnegative(),
+      captureIds: z.array(z.string().trim().min(1)),
+    })
+    .nullable()
+    .optional(),
+})
+
+export const sessionCaptureSelectionSchema = z.object({
+  selectedCaptureIds: z.array(z.string().trim().min(1)),
+  favoriteCaptureIds: z.array(z.string().trim().min(1)),
+})
+
 export const sessionManifestSchema = z
   .object({
     schemaVersion: z.literal(sessionManifestSchemaVersion),
@@ -112,6 +170,10 @@ export const sessionManifestSchema = z
     timing: sessionTimingSnapshotSchema.nullable().optional(),
     captures: z.array(sessionCaptureRecordSchema),
     postEnd: sessionPostEndSchema.nullable(),
+    printJobs: z.array(sessionPrintJobSchema).optional(),
+    reservation: sessionReservationSchema.nullable().optional(),
+    shotLayout: sessionShotLayoutSchema.nullable().optional(),
+    selection: sessionCaptureSelectionSchema.nullable().optional(),
   })
   .superRefine((manifest, context) => {
     const hasCatalogRevision = manifest.catalogRevision != null
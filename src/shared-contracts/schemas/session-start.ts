@@ -1,9 +1,32 @@
 import { z } from 'zod'
 
 import { customerNameSchema, phoneLastFourSchema } from './session-manifest'
+import {
+  sessionPackageIdSchema,
+  sessionPackageTierSchema,
+} from './session-timing'
 
 export const sessionStartInputSchema = z.object({
   name: customerNameSchema,
   phoneLastFour: phoneLastFourSchema,
+  packageId: sessionPackageIdSchema.optional(),
 })
 
+
+export const reservationLookupInputSchema = z.object({
+  reservationId: z
+    .string()
+    .trim()
+    .regex(/^[A-Za-z0-9_-]{1,64}$/, '예약 번호를 다시 확인해 주세요.'),
+})
+
+export const reservationSummarySchema = z.object({
+  schemaVersion: z.literal('reservation-summary/v1'),
+  reservationId: z.string().trim().min(1),
+  sourceId: z.string().trim().min(1),
+  customerName: customerNameSchema,
+  phoneLastFour: phoneLastFourSchema,
+  package: sessionPackageTierSchema,
+  allowedPresetIds: z.array(z.string().trim().min(1)),
+  expiresAt: z.string().datetime(),
+})
@@ -6,6 +6,25 @@ export const sessionTimingSchemaVersion = 'session-timing/v1' as const
 
 export const sessionTimingPhaseSchema = z.enum(['active', 'warning', 'ended'])
 
+export const sessionPackageIdSchema = z
+  .string()
+  .regex(/^[a-z0-9-]{1,40}$/, '이용권 식별자 형식이 올바르지 않아요.')
+
+export const sessionPackageTierSchema = z.object({
+  packageId: sessionPackageIdSchema,
+  displayName: z.string().trim().min(1).max(40),
+  durationMinutes: z.number().int().positive(),
+  warningLeadMinutes: z.number().int().nonnegative(),
+  maxExtensionMinutes: z.number().int().nonnegative(),
+  maxSelectedCaptures: z.number().int().min(1).max(99).optional(),
+})
+
+export const sessionPackageCatalogSchema = z.object({
+  schemaVersion: z.literal('session-package-config/v1'),
+  defaultPackageId: sessionPackageIdSchema,
+  packages: z.array(sessionPackageTierSchema).min(1),
+})
+
 export const sessionTimingSnapshotSchema = z.object({
   schemaVersion: z.literal(sessionTimingSchemaVersion),
   sessionId: sessionIdSchema,
@@ -17,4 +36,5 @@ export const sessionTimingSnapshotSchema = z.object({
   approvedExtensionAuditRef: z.string().trim().min(1).nullable(),
   warningTriggeredAt: z.string().datetime().nullable(),
   endedTriggeredAt: z.string().datetime().nullable(),
+  package: sessionPackageTierSchema.nullable().optional(),
 })